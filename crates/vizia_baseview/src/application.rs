@@ -205,6 +205,8 @@ impl ApplicationRunner {
             self.cx.send_event(event);
         }
 
+        self.cx.emit_scheduled_events();
+
         // Events
         self.event_manager.flush_events(self.cx.context(), |window_event| match window_event {
             // For some reason calling window.close() crashes baseview on macos
@@ -360,26 +362,20 @@ impl ApplicationRunner {
                 baseview::MouseEvent::WheelScrolled { delta, modifiers } => {
                     update_modifiers(modifiers);
 
-                    let (lines_x, lines_y) = match delta {
-                        baseview::ScrollDelta::Lines { x, y } => (x, y),
-                        baseview::ScrollDelta::Pixels { x, y } => (
-                            if x < 0.0 {
-                                -1.0
-                            } else if x > 1.0 {
-                                1.0
-                            } else {
-                                0.0
-                            },
-                            if y < 0.0 {
-                                -1.0
-                            } else if y > 1.0 {
-                                1.0
-                            } else {
-                                0.0
-                            },
-                        ),
+                    let line_height = self.cx.context().interaction_settings().scroll_line_height;
+                    // Pixel deltas are converted to fractions of a line so that views which scroll by lines
+                    // handle them too.
+                    let (lines_x, lines_y, scroll_unit) = match delta {
+                        baseview::ScrollDelta::Lines { x, y } => (x, y, ScrollUnit::Lines),
+                        baseview::ScrollDelta::Pixels { x, y } => {
+                            (x / line_height, y / line_height, ScrollUnit::Pixels)
+                        }
                     };
 
+                    if self.cx.context().mouse.scroll_unit != scroll_unit {
+                        self.cx.emit_origin(WindowEvent::ScrollUnitChanged(scroll_unit));
+                    }
+
                     self.cx.emit_origin(WindowEvent::MouseScroll(lines_x, lines_y));
                 }
 
@@ -1,9 +1,10 @@
 use crate::prelude::*;
 
-use vizia_style::{BorderWidth, Property};
+use vizia_style::{BorderWidth, LayoutUnits, Property};
 
 pub struct AnimationBuilder<'a> {
     pub(crate) keyframes: Vec<KeyframeBuilder<'a>>,
+    pub(crate) timing_function: Option<EasingFunction>,
 }
 
 impl Default for AnimationBuilder<'_> {
@@ -14,7 +15,17 @@ impl Default for AnimationBuilder<'_> {
 
 impl AnimationBuilder<'_> {
     pub fn new() -> Self {
-        Self { keyframes: Vec::new() }
+        Self { keyframes: Vec::new(), timing_function: None }
+    }
+
+    /// Sets the easing function applied between each pair of keyframes, which is linear by default.
+    ///
+    /// With a [spring](EasingFunction::Spring), the spring drives the animation from the first keyframe to the last
+    /// and the duration the animation is played with is ignored.
+    pub fn timing_function(mut self, timing_function: EasingFunction) -> Self {
+        self.timing_function = Some(timing_function);
+
+        self
     }
 
     pub fn keyframe(
@@ -60,6 +71,20 @@ impl<'a> KeyframeBuilder<'a> {
         self
     }
 
+    // FILTER
+
+    pub fn filter(mut self, val: impl Into<Vec<Filter>>) -> Self {
+        self.properties.push(Property::Filter(val.into()));
+
+        self
+    }
+
+    pub fn backdrop_filter(mut self, val: impl Into<Vec<Filter>>) -> Self {
+        self.properties.push(Property::BackdropFilter(val.into()));
+
+        self
+    }
+
     // TRANSFORM
 
     pub fn transform(mut self, val: impl Into<Vec<Transform>>) -> Self {
@@ -100,7 +125,7 @@ impl<'a> KeyframeBuilder<'a> {
         self
     }
 
-    pub fn border_color(mut self, val: impl Into<Color>) -> Self {
+    pub fn border_color(mut self, val: impl Into<ColorOrGradient>) -> Self {
         self.properties.push(Property::BorderColor(val.into()));
 
         self
@@ -138,7 +163,7 @@ impl<'a> KeyframeBuilder<'a> {
         self
     }
 
-    pub fn outline_color(mut self, val: impl Into<Color>) -> Self {
+    pub fn outline_color(mut self, val: impl Into<ColorOrGradient>) -> Self {
         self.properties.push(Property::OutlineColor(val.into()));
 
         self
@@ -164,6 +189,12 @@ impl<'a> KeyframeBuilder<'a> {
         self
     }
 
+    pub fn background_position(mut self, val: impl Into<Vec<Position>>) -> Self {
+        self.properties.push(Property::BackgroundPosition(val.into()));
+
+        self
+    }
+
     pub fn background_size(mut self, val: impl Into<Vec<BackgroundSize>>) -> Self {
         self.properties.push(Property::BackgroundSize(val.into()));
 
@@ -178,9 +209,15 @@ impl<'a> KeyframeBuilder<'a> {
         self
     }
 
+    pub fn elevation(mut self, val: impl Into<Elevation>) -> Self {
+        self.properties.push(Property::Elevation(val.into()));
+
+        self
+    }
+
     // TEXT
 
-    pub fn color(mut self, val: impl Into<Color>) -> Self {
+    pub fn color(mut self, val: impl Into<ColorOrGradient>) -> Self {
         self.properties.push(Property::FontColor(val.into()));
 
         self
@@ -192,6 +229,18 @@ impl<'a> KeyframeBuilder<'a> {
         self
     }
 
+    pub fn letter_spacing(mut self, val: impl Into<LetterSpacing>) -> Self {
+        self.properties.push(Property::LetterSpacing(val.into()));
+
+        self
+    }
+
+    pub fn word_spacing(mut self, val: impl Into<WordSpacing>) -> Self {
+        self.properties.push(Property::WordSpacing(val.into()));
+
+        self
+    }
+
     pub fn caret_color(mut self, val: impl Into<Color>) -> Self {
         self.properties.push(Property::CaretColor(val.into()));
 
@@ -207,25 +256,25 @@ impl<'a> KeyframeBuilder<'a> {
     // SPACE
 
     pub fn left(mut self, val: impl Into<Units>) -> Self {
-        self.properties.push(Property::Left(val.into()));
+        self.properties.push(Property::Left(LayoutUnits::Units(val.into())));
 
         self
     }
 
     pub fn right(mut self, val: impl Into<Units>) -> Self {
-        self.properties.push(Property::Right(val.into()));
+        self.properties.push(Property::Right(LayoutUnits::Units(val.into())));
 
         self
     }
 
     pub fn top(mut self, val: impl Into<Units>) -> Self {
-        self.properties.push(Property::Top(val.into()));
+        self.properties.push(Property::Top(LayoutUnits::Units(val.into())));
 
         self
     }
 
     pub fn bottom(mut self, val: impl Into<Units>) -> Self {
-        self.properties.push(Property::Bottom(val.into()));
+        self.properties.push(Property::Bottom(LayoutUnits::Units(val.into())));
 
         self
     }
@@ -233,25 +282,25 @@ impl<'a> KeyframeBuilder<'a> {
     // PADDING
 
     pub fn padding_left(mut self, val: impl Into<Units>) -> Self {
-        self.properties.push(Property::PaddingLeft(val.into()));
+        self.properties.push(Property::PaddingLeft(LayoutUnits::Units(val.into())));
 
         self
     }
 
     pub fn padding_right(mut self, val: impl Into<Units>) -> Self {
-        self.properties.push(Property::PaddingRight(val.into()));
+        self.properties.push(Property::PaddingRight(LayoutUnits::Units(val.into())));
 
         self
     }
 
     pub fn padding_top(mut self, val: impl Into<Units>) -> Self {
-        self.properties.push(Property::PaddingTop(val.into()));
+        self.properties.push(Property::PaddingTop(LayoutUnits::Units(val.into())));
 
         self
     }
 
     pub fn padding_bottom(mut self, val: impl Into<Units>) -> Self {
-        self.properties.push(Property::PaddingBottom(val.into()));
+        self.properties.push(Property::PaddingBottom(LayoutUnits::Units(val.into())));
 
         self
     }
@@ -271,38 +320,38 @@ impl<'a> KeyframeBuilder<'a> {
     // SIZE
 
     pub fn width(mut self, val: impl Into<Units>) -> Self {
-        self.properties.push(Property::Width(val.into()));
+        self.properties.push(Property::Width(LayoutUnits::Units(val.into())));
 
         self
     }
 
     pub fn height(mut self, val: impl Into<Units>) -> Self {
-        self.properties.push(Property::Height(val.into()));
+        self.properties.push(Property::Height(LayoutUnits::Units(val.into())));
 
         self
     }
 
     // SIZE CONSTRAINTS
     pub fn min_width(mut self, val: impl Into<Units>) -> Self {
-        self.properties.push(Property::MinWidth(val.into()));
+        self.properties.push(Property::MinWidth(LayoutUnits::Units(val.into())));
 
         self
     }
 
     pub fn max_width(mut self, val: impl Into<Units>) -> Self {
-        self.properties.push(Property::MaxWidth(val.into()));
+        self.properties.push(Property::MaxWidth(LayoutUnits::Units(val.into())));
 
         self
     }
 
     pub fn min_height(mut self, val: impl Into<Units>) -> Self {
-        self.properties.push(Property::MinHeight(val.into()));
+        self.properties.push(Property::MinHeight(LayoutUnits::Units(val.into())));
 
         self
     }
 
     pub fn max_height(mut self, val: impl Into<Units>) -> Self {
-        self.properties.push(Property::MaxHeight(val.into()));
+        self.properties.push(Property::MaxHeight(LayoutUnits::Units(val.into())));
 
         self
     }
@@ -0,0 +1,10 @@
+use super::Animation;
+
+/// Events sent to a view by the animations played on it with
+/// [`play_animation`](crate::context::EventContext::play_animation), which propagate up the tree.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnimationEvent {
+    /// The animation has finished playing. An animation which is stopped, or which repeats forever, doesn't
+    /// finish.
+    Finished(Animation),
+}
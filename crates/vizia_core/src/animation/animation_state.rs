@@ -3,7 +3,7 @@ use hashbrown::HashSet;
 
 use crate::prelude::*;
 
-use super::TimingFunction;
+use super::{Spring, TimingFunction};
 
 /// A keyframe in an animation state.
 #[derive(Debug, Clone)]
@@ -30,6 +30,14 @@ pub(crate) struct AnimationState<T: Interpolator> {
     pub output: Option<T>,
     /// Whether the animation should persist after finishing.
     pub persistent: bool,
+    /// Whether the first keyframe applies during the delay of the animation.
+    pub fill_backwards: bool,
+    /// The number of times the animation repeats, which is infinite to repeat until it's stopped.
+    pub iterations: f32,
+    /// The direction the keyframes are played in.
+    pub direction: AnimationDirection,
+    /// The spring which drives the progress of the animation in place of its duration.
+    pub spring: Option<Spring>,
     /// How far through the animation between 0.0 and 1.0.
     pub t: f32,
 
@@ -41,6 +49,10 @@ pub(crate) struct AnimationState<T: Interpolator> {
     pub from_rule: usize,
     /// For tansitions. The ending rule for this transition.
     pub to_rule: usize,
+    /// The color space used to interpolate colors, or `None` to use the application default.
+    pub color_space: Option<ColorSpace>,
+    /// The time the animation was paused at, or `None` if it's playing.
+    pub paused_at: Option<Instant>,
 
     /// List of entities connected to this animation (used when animation is removed from active list)
     pub entities: HashSet<Entity>,
@@ -60,12 +72,18 @@ where
             keyframes: Vec::new(),
             output: None,
             persistent: false,
+            fill_backwards: true,
+            iterations: 1.0,
+            direction: AnimationDirection::Normal,
+            spring: None,
             t: 0.0,
             dt: 0.0,
             active: false,
             entities: HashSet::new(),
             from_rule: usize::MAX,
             to_rule: usize::MAX,
+            color_space: None,
+            paused_at: None,
         }
     }
 
@@ -81,6 +99,18 @@ where
         self
     }
 
+    pub(crate) fn with_color_space(mut self, color_space: Option<ColorSpace>) -> Self {
+        self.color_space = color_space;
+
+        self
+    }
+
+    pub(crate) fn with_spring(mut self, spring: Option<Spring>) -> Self {
+        self.spring = spring;
+
+        self
+    }
+
     pub(crate) fn with_keyframe(mut self, key: Keyframe<T>) -> Self {
         self.keyframes.push(key);
 
@@ -95,12 +125,191 @@ where
         self.active = true;
         self.t = 0.0;
         self.start_time = Instant::now();
+        self.paused_at = None;
         self.entities.insert(entity);
     }
 
+    /// Holds the animation at its values at the given time until it's resumed.
+    pub(crate) fn pause(&mut self, time: Instant) {
+        self.paused_at.get_or_insert(time);
+    }
+
+    /// Continues a paused animation from where it was paused, as though the time it was paused for didn't pass.
+    pub(crate) fn resume(&mut self, time: Instant) {
+        let Some(paused_at) = self.paused_at.take() else {
+            return;
+        };
+
+        let paused_for = time.saturating_duration_since(paused_at);
+        self.start_time += paused_for;
+        if let Some(spring) = &mut self.spring {
+            spring.time = spring.time.map(|time| time + paused_for);
+        }
+    }
+
+    /// Moves the animation to the given time after its delay, or to the time it was paused at if it's paused, and
+    /// updates its output to the values at that time.
+    ///
+    /// An animation driven by a spring can't be moved, as its motion depends on how it got to where it is.
+    pub(crate) fn seek(&mut self, position: Duration, time: Instant, color_space: ColorSpace)
+    where
+        T: Clone,
+    {
+        if self.spring.is_some() {
+            return;
+        }
+
+        let time = self.paused_at.unwrap_or(time);
+        self.start_time = time.checked_sub(self.delay + position).unwrap_or(time);
+        self.dt = self.delay.as_secs_f32() / self.duration.as_secs_f32();
+        self.t = 0.0;
+        self.update(time, color_space);
+    }
+
+    /// Updates the progress and output of the animation to the given time.
+    pub(crate) fn update(&mut self, time: Instant, color_space: ColorSpace)
+    where
+        T: Clone,
+    {
+        if self.keyframes.len() == 1 {
+            self.output = Some(self.keyframes[0].value.clone());
+            return;
+        }
+
+        let elapsed_time = time.duration_since(self.start_time);
+
+        let reversed = self.is_reversed(0.0);
+        let normalised_time = if let Some(spring) = &mut self.spring {
+            // A spring drives a single iteration once the delay has passed, until it comes to rest.
+            if spring.time.is_none() {
+                if elapsed_time < self.delay {
+                    if !self.fill_backwards {
+                        self.output = None;
+                    }
+                    return;
+                }
+
+                spring.time = Some(self.start_time + self.delay);
+            }
+
+            spring.advance(time);
+            self.t = if spring.is_at_rest() { 1.0 } else { spring.position.clamp(0.0, 0.99) };
+
+            if reversed {
+                1.0 - spring.position
+            } else {
+                spring.position
+            }
+        } else {
+            let progress = (elapsed_time.as_secs_f32() / self.duration.as_secs_f32()) - self.dt;
+
+            // An animation which doesn't fill backwards shows the underlying value during its delay.
+            if progress < 0.0 && !self.fill_backwards {
+                self.output = None;
+                return;
+            }
+
+            // The progress counts iterations, so the animation finishes at the end of the last iteration,
+            // in which the normalised time is 1.0 rather than 0.0 of the iteration after it.
+            let progress = progress.clamp(0.0, self.iterations);
+            let iteration = if progress == self.iterations {
+                (progress.ceil() - 1.0).max(0.0)
+            } else {
+                progress.floor()
+            };
+
+            self.t = if progress == self.iterations { 1.0 } else { progress / self.iterations };
+
+            let normalised_time = (progress - iteration).min(1.0);
+            if self.is_reversed(iteration) {
+                1.0 - normalised_time
+            } else {
+                normalised_time
+            }
+        };
+
+        let mut i = 0;
+        while i < self.keyframes.len() - 1 && self.keyframes[i + 1].time < normalised_time {
+            i += 1;
+        }
+        let start = &self.keyframes[i];
+        let end = &self.keyframes[i + 1];
+
+        let normalised_elapsed_time = (normalised_time - start.time) / (end.time - start.time);
+
+        // The motion of a spring isn't eased again, and it may overshoot the keyframes at either end.
+        let timing_t = if self.spring.is_some() {
+            normalised_elapsed_time
+        } else {
+            start.timing_function.value(normalised_elapsed_time)
+        };
+        self.output = Some(T::interpolate_in(
+            &start.value,
+            &end.value,
+            timing_t,
+            self.color_space.unwrap_or(color_space),
+        ));
+    }
+
     pub(crate) fn is_transition(&self) -> bool {
         !(self.from_rule == usize::MAX && self.to_rule == usize::MAX)
     }
+
+    /// Returns true if the given iteration of the animation plays its keyframes backwards.
+    pub(crate) fn is_reversed(&self, iteration: f32) -> bool {
+        let odd = iteration % 2.0 == 1.0;
+        match self.direction {
+            AnimationDirection::Normal => false,
+            AnimationDirection::Reverse => true,
+            AnimationDirection::Alternate => odd,
+            AnimationDirection::AlternateReverse => !odd,
+        }
+    }
+}
+
+/// How an animation plays when it's started for an entity.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AnimationPlayback {
+    /// The duration of each iteration of the animation.
+    pub duration: Duration,
+    /// The delay before the animation starts.
+    pub delay: Duration,
+    /// The number of times the animation repeats, which is infinite to repeat until it's stopped.
+    pub iterations: f32,
+    /// The direction the keyframes are played in.
+    pub direction: AnimationDirection,
+    /// Whether the values of the animation apply during its delay and after it finishes.
+    pub fill_mode: AnimationFillMode,
+    /// The timing function applied between each pair of keyframes, or `None` to keep those of the keyframes.
+    pub timing_function: Option<TimingFunction>,
+    /// The spring which drives the animation, or `None` to keep that of the animation.
+    pub spring: Option<Spring>,
+}
+
+/// A change to how an animation which is playing for an entity plays.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum PlaybackControl {
+    /// Holds the animation at its current values.
+    Pause,
+    /// Continues a paused animation from where it was paused.
+    Resume,
+    /// Moves the animation to the given time after its delay.
+    Seek(Duration),
+}
+
+impl AnimationPlayback {
+    /// Creates a playback which plays the keyframes once, showing the first keyframe during the delay.
+    pub(crate) fn new(duration: Duration, delay: Duration) -> Self {
+        Self {
+            duration,
+            delay,
+            iterations: 1.0,
+            direction: AnimationDirection::Normal,
+            fill_mode: AnimationFillMode::Backwards,
+            timing_function: None,
+            spring: None,
+        }
+    }
 }
 
 impl<Prop> Default for AnimationState<Prop>
@@ -116,12 +325,18 @@ where
             keyframes: Vec::new(),
             output: None,
             persistent: true,
+            fill_backwards: true,
+            iterations: 1.0,
+            direction: AnimationDirection::Normal,
+            spring: None,
             t: 0.0,
             dt: 0.0,
             active: false,
             entities: HashSet::new(),
             from_rule: usize::MAX,
             to_rule: usize::MAX,
+            color_space: None,
+            paused_at: None,
         }
     }
 }
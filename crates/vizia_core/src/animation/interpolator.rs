@@ -1,17 +1,37 @@
 use morphorm::Units;
 use vizia_style::{
-    Angle, BackgroundSize, ClipPath, Color, ColorStop, Display, Filter, FontSize, Gradient, Length,
-    LengthOrPercentage, LengthPercentageOrAuto, LengthValue, LineDirection, LinearGradient,
-    Opacity, PercentageOrNumber, Rect, Scale, Shadow, Transform, Translate, RGBA,
+    Angle, AngleOrPercentage, BackgroundSize, ClipPath, Color, ColorOrGradient, ColorSpace,
+    ColorStop, ConicGradient, CornerRadiusValue, DimensionPercentage, Display, Elevation, Filter,
+    FontSize, FontVariation, Gradient, Length, LengthOrPercentage, LengthPercentage,
+    LengthPercentageOrAuto, LengthValue, LetterSpacing, LineDirection, LinearGradient, Opacity,
+    Percentage, PercentageOrNumber, RadialGradient, Rect, Scale, Shadow, Transform, Translate,
+    WordSpacing, RGBA,
 };
 
-use skia_safe::Matrix;
+use skia_safe::{font_arguments::variation_position::Coordinate, Matrix};
 
 use crate::style::ImageOrGradient;
 
 /// A trait which describes how a property is interpolated for animations.
 pub(crate) trait Interpolator {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self;
+
+    /// Interpolates between two values, mixing any colors they contain in the given color space.
+    fn interpolate_in(start: &Self, end: &Self, t: f32, _color_space: ColorSpace) -> Self
+    where
+        Self: Sized,
+    {
+        Self::interpolate(start, end, t)
+    }
+
+    /// Returns the value with no effect which a list is padded with where it's shorter than the list it's
+    /// interpolated with, or `None` if the longer list is truncated instead.
+    fn neutral(_other: &Self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
 }
 
 // Implementations of `Interpolator` for various properties.
@@ -61,6 +81,12 @@ impl Interpolator for Display {
     }
 }
 
+impl Interpolator for Elevation {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        Elevation(start.0 + (end.0 - start.0) * t)
+    }
+}
+
 impl Interpolator for Opacity {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
         Opacity(start.0 + (end.0 - start.0) * t)
@@ -69,32 +95,74 @@ impl Interpolator for Opacity {
 
 impl Interpolator for Color {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
-        let r = (end.r() as f64 - start.r() as f64).mul_add(t as f64, start.r() as f64) as u8;
-        let g = (end.g() as f64 - start.g() as f64).mul_add(t as f64, start.g() as f64) as u8;
-        let b = (end.b() as f64 - start.b() as f64).mul_add(t as f64, start.b() as f64) as u8;
-        let a = (end.a() as f64 - start.a() as f64).mul_add(t as f64, start.a() as f64) as u8;
-        Color::rgba(r, g, b, a)
+        start.mix(end, t, ColorSpace::Srgb)
+    }
+
+    fn interpolate_in(start: &Self, end: &Self, t: f32, color_space: ColorSpace) -> Self {
+        start.mix(end, t, color_space)
     }
 }
 
 impl Interpolator for RGBA {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
-        let r = (end.r() as f64 - start.r() as f64).mul_add(t as f64, start.r() as f64) as u8;
-        let g = (end.g() as f64 - start.g() as f64).mul_add(t as f64, start.g() as f64) as u8;
-        let b = (end.b() as f64 - start.b() as f64).mul_add(t as f64, start.b() as f64) as u8;
-        let a = (end.a() as f64 - start.a() as f64).mul_add(t as f64, start.a() as f64) as u8;
-        RGBA::rgba(r, g, b, a)
+        start.mix(end, t, ColorSpace::Srgb)
+    }
+
+    fn interpolate_in(start: &Self, end: &Self, t: f32, color_space: ColorSpace) -> Self {
+        start.mix(end, t, color_space)
     }
 }
 
 impl Interpolator for Filter {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        Self::interpolate_in(start, end, t, ColorSpace::Srgb)
+    }
+
+    fn interpolate_in(start: &Self, end: &Self, t: f32, color_space: ColorSpace) -> Self {
         match (start, end) {
             (Filter::Blur(start), Filter::Blur(end)) => {
                 Filter::Blur(Length::interpolate(start, end, t))
             }
+            (Filter::Brightness(start), Filter::Brightness(end)) => {
+                Filter::Brightness(f32::interpolate(start, end, t))
+            }
+            (Filter::Contrast(start), Filter::Contrast(end)) => {
+                Filter::Contrast(f32::interpolate(start, end, t))
+            }
+            (Filter::Grayscale(start), Filter::Grayscale(end)) => {
+                Filter::Grayscale(f32::interpolate(start, end, t))
+            }
+            (Filter::Saturate(start), Filter::Saturate(end)) => {
+                Filter::Saturate(f32::interpolate(start, end, t))
+            }
+            (Filter::HueRotate(start), Filter::HueRotate(end)) => {
+                Filter::HueRotate(Angle::interpolate(start, end, t))
+            }
+            (Filter::DropShadow(start), Filter::DropShadow(end)) => {
+                Filter::DropShadow(Shadow::interpolate_in(start, end, t, color_space))
+            }
+            _ => end.clone(),
         }
     }
+
+    fn neutral(other: &Self) -> Option<Self> {
+        Some(match other {
+            Filter::Blur(_) => Filter::Blur(Length::px(0.0)),
+            Filter::Brightness(_) => Filter::Brightness(1.0),
+            Filter::Contrast(_) => Filter::Contrast(1.0),
+            Filter::Grayscale(_) => Filter::Grayscale(0.0),
+            Filter::Saturate(_) => Filter::Saturate(1.0),
+            Filter::HueRotate(_) => Filter::HueRotate(Angle::Deg(0.0)),
+            Filter::DropShadow(_) => Filter::DropShadow(Shadow::new(
+                Length::px(0.0),
+                Length::px(0.0),
+                None,
+                None,
+                Some(Color::transparent()),
+                false,
+            )),
+        })
+    }
 }
 
 impl Interpolator for LengthValue {
@@ -133,7 +201,64 @@ impl Interpolator for LengthOrPercentage {
                 LengthOrPercentage::Percentage(end_val),
             ) => LengthOrPercentage::Percentage(f32::interpolate(start_val, end_val, t)),
 
-            _ => LengthOrPercentage::default(),
+            // Lengths mixed with percentages or calculations are interpolated as a calculation of both.
+            (start, end) => match (length_percentage(start), length_percentage(end)) {
+                (Some(start), Some(end)) => {
+                    LengthOrPercentage::Calc(LengthPercentage::interpolate(&start, &end, t))
+                }
+                _ => LengthOrPercentage::default(),
+            },
+        }
+    }
+}
+
+fn length_percentage(value: &LengthOrPercentage) -> Option<LengthPercentage> {
+    match value {
+        LengthOrPercentage::Length(Length::Value(value)) => {
+            Some(DimensionPercentage::Dimension(*value))
+        }
+        LengthOrPercentage::Length(Length::Calc(_)) => None,
+        LengthOrPercentage::Percentage(percentage) => {
+            Some(DimensionPercentage::Percentage(Percentage(*percentage)))
+        }
+        LengthOrPercentage::Calc(length) => Some(length.clone()),
+    }
+}
+
+impl Interpolator for LengthPercentage {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        match (start, end) {
+            (
+                DimensionPercentage::Dimension(LengthValue::Px(start_val)),
+                DimensionPercentage::Dimension(LengthValue::Px(end_val)),
+            ) => DimensionPercentage::Dimension(LengthValue::Px(f32::interpolate(
+                start_val, end_val, t,
+            ))),
+
+            (
+                DimensionPercentage::Percentage(start_val),
+                DimensionPercentage::Percentage(end_val),
+            ) => DimensionPercentage::Percentage(Percentage(f32::interpolate(
+                &start_val.0,
+                &end_val.0,
+                t,
+            ))),
+
+            _ if t <= 0.0 => start.clone(),
+            _ if t >= 1.0 => end.clone(),
+
+            // The calculation resolves to the interpolation of the pixels the values resolve to, even when they
+            // contain functions such as `clamp()` which aren't linear.
+            _ => start.clone() * (1.0 - t) + end.clone() * t,
+        }
+    }
+}
+
+impl Interpolator for CornerRadiusValue {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        CornerRadiusValue {
+            x: LengthOrPercentage::interpolate(&start.x, &end.x, t),
+            y: LengthOrPercentage::interpolate(&start.y, &end.y, t),
         }
     }
 }
@@ -193,6 +318,22 @@ impl Interpolator for Angle {
     }
 }
 
+impl Interpolator for AngleOrPercentage {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        match (start, end) {
+            (AngleOrPercentage::Angle(start_angle), AngleOrPercentage::Angle(end_angle)) => {
+                AngleOrPercentage::Angle(Angle::interpolate(start_angle, end_angle, t))
+            }
+
+            _ => AngleOrPercentage::Percentage(f32::interpolate(
+                &(start.to_turns() * 100.0),
+                &(end.to_turns() * 100.0),
+                t,
+            )),
+        }
+    }
+}
+
 impl Interpolator for Transform {
     fn interpolate(_start: &Self, end: &Self, _t: f32) -> Self {
         end.clone()
@@ -210,6 +351,9 @@ impl Interpolator for Matrix {
         transform[3] = f32::interpolate(&start[3], &end[3], t);
         transform[4] = f32::interpolate(&start[4], &end[4], t);
         transform[5] = f32::interpolate(&start[5], &end[5], t);
+        transform[6] = f32::interpolate(&start[6], &end[6], t);
+        transform[7] = f32::interpolate(&start[7], &end[7], t);
+        transform[8] = f32::interpolate(&start[8], &end[8], t);
 
         transform
     }
@@ -217,21 +361,41 @@ impl Interpolator for Matrix {
 
 impl<T: Interpolator> Interpolator for Vec<T> {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
-        start
-            .iter()
-            .zip(end.iter())
-            .map(|(start, end)| T::interpolate(start, end, t))
+        Self::interpolate_in(start, end, t, ColorSpace::Srgb)
+    }
+
+    fn interpolate_in(start: &Self, end: &Self, t: f32, color_space: ColorSpace) -> Self {
+        (0..start.len().max(end.len()))
+            .filter_map(|index| match (start.get(index), end.get(index)) {
+                (Some(start), Some(end)) => Some(T::interpolate_in(start, end, t, color_space)),
+                (Some(start), None) => {
+                    T::neutral(start).map(|end| T::interpolate_in(start, &end, t, color_space))
+                }
+                (None, Some(end)) => {
+                    T::neutral(end).map(|start| T::interpolate_in(&start, end, t, color_space))
+                }
+                (None, None) => None,
+            })
             .collect::<Vec<T>>()
     }
 }
 
 impl Interpolator for ImageOrGradient {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        Self::interpolate_in(start, end, t, ColorSpace::Srgb)
+    }
+
+    fn interpolate_in(start: &Self, end: &Self, t: f32, color_space: ColorSpace) -> Self {
         match (start, end) {
             (
                 ImageOrGradient::Gradient(gradient_start),
                 ImageOrGradient::Gradient(gradient_end),
-            ) => ImageOrGradient::Gradient(Gradient::interpolate(gradient_start, gradient_end, t)),
+            ) => ImageOrGradient::Gradient(Gradient::interpolate_in(
+                gradient_start,
+                gradient_end,
+                t,
+                color_space,
+            )),
             _ => end.clone(),
         }
     }
@@ -256,9 +420,45 @@ impl Interpolator for BackgroundSize {
 
 impl Interpolator for Gradient {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        Self::interpolate_in(start, end, t, ColorSpace::Srgb)
+    }
+
+    fn interpolate_in(start: &Self, end: &Self, t: f32, color_space: ColorSpace) -> Self {
         match (start, end) {
-            (Gradient::Linear(start_gradient), Gradient::Linear(end_gradient)) => {
-                Gradient::Linear(LinearGradient::interpolate(start_gradient, end_gradient, t))
+            (Gradient::Linear(start_gradient), Gradient::Linear(end_gradient)) => Gradient::Linear(
+                LinearGradient::interpolate_in(start_gradient, end_gradient, t, color_space),
+            ),
+
+            (Gradient::Radial(start_gradient), Gradient::Radial(end_gradient))
+                if start_gradient.stops.len() == end_gradient.stops.len() =>
+            {
+                Gradient::Radial(RadialGradient {
+                    position: end_gradient.position.clone(),
+                    stops: interpolate_stops(
+                        &start_gradient.stops,
+                        &end_gradient.stops,
+                        t,
+                        color_space,
+                        LengthOrPercentage::Percentage,
+                    ),
+                })
+            }
+
+            (Gradient::Conic(start_gradient), Gradient::Conic(end_gradient))
+                if start_gradient.stops.len() == end_gradient.stops.len() =>
+            {
+                Gradient::Conic(ConicGradient {
+                    from: Angle::interpolate(&start_gradient.from, &end_gradient.from, t),
+                    position: end_gradient.position.clone(),
+                    stops: interpolate_stops(
+                        &start_gradient.stops,
+                        &end_gradient.stops,
+                        t,
+                        color_space,
+                        AngleOrPercentage::Percentage,
+                    ),
+                    repeating: end_gradient.repeating,
+                })
             }
 
             _ => end.clone(),
@@ -280,32 +480,20 @@ impl Interpolator for LineDirection {
 
 impl Interpolator for LinearGradient {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        Self::interpolate_in(start, end, t, ColorSpace::Srgb)
+    }
+
+    fn interpolate_in(start: &Self, end: &Self, t: f32, color_space: ColorSpace) -> Self {
         if start.stops.len() == end.stops.len() {
             LinearGradient {
                 direction: LineDirection::interpolate(&start.direction, &end.direction, t),
-                stops: start
-                    .stops
-                    .iter()
-                    .zip(end.stops.iter())
-                    .enumerate()
-                    .map(|(index, (start_stop, end_stop))| {
-                        let num_stops = start.stops.len();
-                        let start_pos =
-                            start_stop.position.clone().unwrap_or(LengthOrPercentage::Percentage(
-                                index as f32 / (num_stops - 1) as f32 * 100.0,
-                            ));
-                        let end_pos =
-                            end_stop.position.clone().unwrap_or(LengthOrPercentage::Percentage(
-                                index as f32 / (num_stops - 1) as f32 * 100.0,
-                            ));
-                        ColorStop {
-                            color: Color::interpolate(&start_stop.color, &end_stop.color, t),
-                            position: Some(LengthOrPercentage::interpolate(
-                                &start_pos, &end_pos, t,
-                            )),
-                        }
-                    })
-                    .collect::<Vec<_>>(),
+                stops: interpolate_stops(
+                    &start.stops,
+                    &end.stops,
+                    t,
+                    color_space,
+                    LengthOrPercentage::Percentage,
+                ),
             }
         } else {
             end.clone()
@@ -313,14 +501,111 @@ impl Interpolator for LinearGradient {
     }
 }
 
+// Interpolates between two lists of gradient stops of the same length, giving stops without a position their
+// implicit position, which is created from a percentage.
+fn interpolate_stops<D: Interpolator + Clone>(
+    start: &[ColorStop<D>],
+    end: &[ColorStop<D>],
+    t: f32,
+    color_space: ColorSpace,
+    percentage: fn(f32) -> D,
+) -> Vec<ColorStop<D>> {
+    let num_stops = start.len();
+    start
+        .iter()
+        .zip(end.iter())
+        .enumerate()
+        .map(|(index, (start_stop, end_stop))| {
+            let implicit_position = percentage(index as f32 / (num_stops - 1) as f32 * 100.0);
+            let start_pos = start_stop.position.clone().unwrap_or(implicit_position.clone());
+            let end_pos = end_stop.position.clone().unwrap_or(implicit_position);
+            ColorStop {
+                color: Color::interpolate_in(&start_stop.color, &end_stop.color, t, color_space),
+                position: Some(D::interpolate(&start_pos, &end_pos, t)),
+            }
+        })
+        .collect()
+}
+
+impl Interpolator for ColorOrGradient {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        Self::interpolate_in(start, end, t, ColorSpace::Srgb)
+    }
+
+    fn interpolate_in(start: &Self, end: &Self, t: f32, color_space: ColorSpace) -> Self {
+        match (start, end) {
+            (ColorOrGradient::Color(start_color), ColorOrGradient::Color(end_color)) => {
+                ColorOrGradient::Color(Color::interpolate_in(
+                    start_color,
+                    end_color,
+                    t,
+                    color_space,
+                ))
+            }
+
+            (
+                ColorOrGradient::Gradient(start_gradient),
+                ColorOrGradient::Gradient(end_gradient),
+            ) => Gradient::interpolate_in(start_gradient, end_gradient, t, color_space).into(),
+
+            // A color cross-fades with a gradient by fading every stop of the gradient from or to the color.
+            (ColorOrGradient::Color(color), ColorOrGradient::Gradient(gradient)) => {
+                Gradient::interpolate_in(
+                    &solid_gradient(gradient, *color),
+                    gradient,
+                    t,
+                    color_space,
+                )
+                .into()
+            }
+
+            (ColorOrGradient::Gradient(gradient), ColorOrGradient::Color(color)) => {
+                Gradient::interpolate_in(
+                    gradient,
+                    &solid_gradient(gradient, *color),
+                    t,
+                    color_space,
+                )
+                .into()
+            }
+        }
+    }
+}
+
+// Returns a copy of the gradient with every stop set to the given color.
+fn solid_gradient(gradient: &Gradient, color: Color) -> Gradient {
+    let mut gradient = gradient.clone();
+    let stops = match &mut gradient {
+        Gradient::Linear(linear_gradient) => &mut linear_gradient.stops,
+        Gradient::Radial(radial_gradient) => &mut radial_gradient.stops,
+        Gradient::Conic(conic_gradient) => {
+            for stop in conic_gradient.stops.iter_mut() {
+                stop.color = color;
+            }
+            return gradient;
+        }
+        Gradient::None => return gradient,
+    };
+
+    for stop in stops.iter_mut() {
+        stop.color = color;
+    }
+
+    gradient
+}
+
 impl Interpolator for Shadow {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        Self::interpolate_in(start, end, t, ColorSpace::Srgb)
+    }
+
+    fn interpolate_in(start: &Self, end: &Self, t: f32, color_space: ColorSpace) -> Self {
         Shadow {
             x_offset: Length::interpolate(&start.x_offset, &end.x_offset, t),
             y_offset: Length::interpolate(&start.y_offset, &end.y_offset, t),
             blur_radius: Option::interpolate(&start.blur_radius, &end.blur_radius, t),
             spread_radius: Option::interpolate(&start.spread_radius, &end.spread_radius, t),
-            color: Option::interpolate(&start.color, &end.color, t),
+            color: Option::interpolate_in(&start.color, &end.color, t, color_space),
             inset: end.inset,
         }
     }
@@ -328,10 +613,14 @@ impl Interpolator for Shadow {
 
 impl<T: Interpolator + Clone + Default> Interpolator for Option<T> {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        Self::interpolate_in(start, end, t, ColorSpace::Srgb)
+    }
+
+    fn interpolate_in(start: &Self, end: &Self, t: f32, color_space: ColorSpace) -> Self {
         match (start, end) {
-            (Some(s), Some(e)) => Some(T::interpolate(s, e, t)),
-            (None, Some(e)) => Some(T::interpolate(&T::default(), e, t)),
-            (Some(s), None) => Some(T::interpolate(s, &T::default(), t)),
+            (Some(s), Some(e)) => Some(T::interpolate_in(s, e, t, color_space)),
+            (None, Some(e)) => Some(T::interpolate_in(&T::default(), e, t, color_space)),
+            (Some(s), None) => Some(T::interpolate_in(s, &T::default(), t, color_space)),
             _ => end.clone(),
         }
     }
@@ -343,6 +632,32 @@ impl Interpolator for FontSize {
     }
 }
 
+// The value of an axis is only interpolated with the value of the same axis, and otherwise changes halfway through.
+impl Interpolator for FontVariation {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        if start.0.axis != end.0.axis {
+            return if t < 0.5 { *start } else { *end };
+        }
+
+        FontVariation(Coordinate {
+            axis: end.0.axis,
+            value: f32::interpolate(&start.0.value, &end.0.value, t),
+        })
+    }
+}
+
+impl Interpolator for LetterSpacing {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        LetterSpacing(Length::interpolate(&start.0, &end.0, t))
+    }
+}
+
+impl Interpolator for WordSpacing {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        WordSpacing(Length::interpolate(&start.0, &end.0, t))
+    }
+}
+
 impl<T: Interpolator> Interpolator for Rect<T> {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
         Rect(
@@ -362,3 +677,22 @@ impl Interpolator for ClipPath {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colors_are_rounded_in_every_color_space() {
+        let (black, white) = (Color::black(), Color::white());
+        assert_eq!(Color::interpolate(&black, &white, 0.5), Color::rgb(128, 128, 128));
+        assert_eq!(
+            Color::interpolate_in(&black, &white, 0.5, ColorSpace::Srgb),
+            Color::interpolate(&black, &white, 0.5)
+        );
+        assert_eq!(
+            RGBA::interpolate(&RGBA::rgba(0, 0, 0, 0), &RGBA::rgba(0, 0, 0, 255), 0.999),
+            RGBA::rgba(0, 0, 0, 255)
+        );
+    }
+}
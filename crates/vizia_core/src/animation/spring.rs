@@ -0,0 +1,161 @@
+use vizia_style::EasingFunction;
+
+use crate::prelude::*;
+
+// The largest step the spring is advanced by, so it stays stable however rarely it's ticked.
+const MAX_STEP: f32 = 1.0 / 240.0;
+// The distance from the end and the speed below which the spring is at rest.
+const REST_THRESHOLD: f32 = 0.001;
+
+/// A damped spring with a mass of one which drives the progress of an animation from 0.0 to 1.0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Spring {
+    stiffness: f32,
+    damping: f32,
+    /// The progress of the animation, which overshoots 1.0 if the spring is underdamped.
+    pub position: f32,
+    /// The rate of change of the progress per second.
+    pub velocity: f32,
+    /// The time the spring has been advanced to, or `None` if it hasn't started moving.
+    pub time: Option<Instant>,
+}
+
+impl Spring {
+    pub(crate) fn new(stiffness: f32, damping: f32, velocity: f32) -> Self {
+        Self { stiffness, damping, position: 0.0, velocity, time: None }
+    }
+
+    /// Returns the spring of an easing function, or `None` if it isn't a spring.
+    pub(crate) fn from_easing(easing: EasingFunction) -> Option<Self> {
+        match easing {
+            EasingFunction::Spring { stiffness, damping, velocity } => {
+                Some(Self::new(stiffness, damping, velocity))
+            }
+            _ => None,
+        }
+    }
+
+    /// Advances the spring to the given time, integrating its motion in small steps.
+    pub(crate) fn advance(&mut self, time: Instant) {
+        let Some(previous) = self.time.replace(time) else {
+            return;
+        };
+
+        let mut remaining = time.saturating_duration_since(previous).as_secs_f32();
+        while remaining > 0.0 {
+            let step = remaining.min(MAX_STEP);
+            let acceleration =
+                -self.stiffness * (self.position - 1.0) - self.damping * self.velocity;
+            self.velocity += acceleration * step;
+            self.position += self.velocity * step;
+            remaining -= step;
+        }
+
+        if self.is_at_rest() {
+            self.position = 1.0;
+            self.velocity = 0.0;
+        }
+    }
+
+    pub(crate) fn is_at_rest(&self) -> bool {
+        (self.position - 1.0).abs() < REST_THRESHOLD && self.velocity.abs() < REST_THRESHOLD
+    }
+
+    /// Moves the spring towards a new end from where it is, keeping its velocity so that it doesn't stop abruptly.
+    ///
+    /// The velocity is a rate of the distance between the values, so it's only preserved exactly when the spring
+    /// is reversed with [`reverse`](Spring::reverse).
+    pub(crate) fn retarget(&mut self) {
+        self.position = 0.0;
+    }
+
+    /// Swaps the start and end of the spring, which keeps the value and velocity it's moving with.
+    pub(crate) fn reverse(&mut self) {
+        self.position = 1.0 - self.position;
+        self.velocity = -self.velocity;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::style_system;
+
+    fn run(spring: &mut Spring, start: Instant, seconds: f32) {
+        spring.time.get_or_insert(start);
+        spring.advance(start + Duration::from_secs_f32(seconds));
+    }
+
+    #[test]
+    fn underdamped_spring_overshoots_and_settles() {
+        let start = Instant::now();
+        let mut spring = Spring::from_easing(EasingFunction::spring(170.0, 10.0)).unwrap();
+
+        run(&mut spring, start, 0.2);
+        assert!(spring.position > 1.0);
+
+        run(&mut spring, start, 5.0);
+        assert!(spring.is_at_rest());
+        assert_eq!(spring.position, 1.0);
+    }
+
+    #[test]
+    fn reversing_keeps_the_motion() {
+        let start = Instant::now();
+        let mut spring = Spring::new(170.0, 26.0, 0.0);
+        run(&mut spring, start, 0.1);
+        let (position, velocity) = (spring.position, spring.velocity);
+        assert!(velocity > 0.0);
+
+        spring.reverse();
+        assert_eq!(spring.position, 1.0 - position);
+        assert_eq!(spring.velocity, -velocity);
+
+        spring.retarget();
+        assert_eq!(spring.position, 0.0);
+        assert_eq!(spring.velocity, -velocity);
+    }
+
+    #[test]
+    fn interrupted_transition_keeps_its_velocity() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(
+            r#"
+            element { opacity: 0; transition: opacity 1s spring(170, 26); }
+            element.shown { opacity: 1; transition: opacity 1s spring(170, 26); }
+            "#,
+        )
+        .unwrap();
+
+        let element = Element::new(&mut cx).entity();
+        style_system(&mut cx);
+
+        let toggle_shown = |cx: &mut Context, shown: bool| {
+            cx.with_current(element, |cx| cx.toggle_class("shown", shown));
+            style_system(cx);
+        };
+        let tick = |cx: &mut Context, time: Instant| {
+            cx.style.opacity.tick(time, ColorSpace::default());
+            let opacity = cx.style.opacity.get(element).unwrap().0;
+            let spring = cx.style.opacity.get_active_animation(element).unwrap().spring.unwrap();
+            (opacity, spring)
+        };
+
+        let start = Instant::now();
+        toggle_shown(&mut cx, true);
+        tick(&mut cx, start);
+        let (opacity, spring) = tick(&mut cx, start + Duration::from_millis(100));
+        assert!(opacity > 0.0 && opacity < 1.0);
+        assert!(spring.velocity > 0.0);
+
+        // Hiding the element part way reverses the spring, which keeps moving up before it turns back.
+        toggle_shown(&mut cx, false);
+        let (reversed_opacity, reversed) = tick(&mut cx, start + Duration::from_millis(101));
+        assert!(reversed_opacity > opacity);
+        assert!((reversed.velocity + spring.velocity).abs() < spring.velocity * 0.1);
+
+        let (opacity, _) = tick(&mut cx, start + Duration::from_secs(5));
+        assert_eq!(opacity, 0.0);
+        assert_eq!(cx.style.opacity.get_active_animation(element).unwrap().t, 1.0);
+    }
+}
@@ -1,9 +1,9 @@
+use vizia_style::{EasingFunction, StepPosition};
+
 #[derive(Debug, Clone, Copy)]
-pub(crate) struct TimingFunction {
-    x1: f32,
-    x2: f32,
-    y1: f32,
-    y2: f32,
+pub(crate) enum TimingFunction {
+    CubicBezier { x1: f32, y1: f32, x2: f32, y2: f32 },
+    Steps { count: u32, position: StepPosition },
 }
 
 impl Default for TimingFunction {
@@ -30,18 +30,67 @@ impl TimingFunction {
     }
 }
 
+impl From<EasingFunction> for TimingFunction {
+    fn from(easing: EasingFunction) -> Self {
+        match easing {
+            EasingFunction::Linear => TimingFunction::linear(),
+            EasingFunction::Ease => TimingFunction::ease(),
+            EasingFunction::EaseIn => TimingFunction::ease_in(),
+            EasingFunction::EaseOut => TimingFunction::ease_out(),
+            EasingFunction::EaseInOut => TimingFunction::ease_in_out(),
+            EasingFunction::CubicBezier(x1, y1, x2, y2) => TimingFunction::new(x1, y1, x2, y2),
+            EasingFunction::Steps(count, position) => TimingFunction::steps(count, position),
+            // The progress of an animation with a spring is driven by the spring itself.
+            EasingFunction::Spring { .. } => TimingFunction::linear(),
+        }
+    }
+}
+
 impl TimingFunction {
     pub fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
-        Self { x1, y1, x2, y2 }
+        Self::CubicBezier { x1, y1, x2, y2 }
+    }
+
+    pub fn steps(count: u32, position: StepPosition) -> Self {
+        Self::Steps { count, position }
     }
 
     pub fn value(&self, x: f32) -> f32 {
-        // Linear
-        if self.x1 == self.y1 && self.x2 == self.y2 {
-            return x;
+        match *self {
+            Self::CubicBezier { x1, y1, x2, y2 } => {
+                // Linear
+                if x1 == y1 && x2 == y2 {
+                    return x;
+                }
+
+                Self::calc_bezier(Self::find_t_for_x(x, x1, x2), y1, y2)
+            }
+
+            Self::Steps { count, position } => Self::calc_steps(x, count, position),
+        }
+    }
+
+    // The output of a step function, following the CSS easing specification.
+    fn calc_steps(x: f32, count: u32, position: StepPosition) -> f32 {
+        let mut step = (x * count as f32).floor();
+        if matches!(position, StepPosition::JumpStart | StepPosition::JumpBoth) {
+            step += 1.0;
         }
 
-        Self::calc_bezier(self.find_t_for_x(x), self.y1, self.y2)
+        let jumps = match position {
+            StepPosition::JumpStart | StepPosition::JumpEnd => count,
+            StepPosition::JumpNone => count.saturating_sub(1).max(1),
+            StepPosition::JumpBoth => count + 1,
+        } as f32;
+
+        if x >= 0.0 && step < 0.0 {
+            step = 0.0;
+        }
+        if x <= 1.0 && step > jumps {
+            step = jumps;
+        }
+
+        step / jumps
     }
 
     fn calc_bezier(t: f32, a1: f32, a2: f32) -> f32 {
@@ -60,29 +109,51 @@ impl TimingFunction {
         3.0 * a(a1, a2) * t * t + 2.0 * b(a1, a2) * t + c(a1)
     }
 
-    fn find_t_for_x(&self, x: f32) -> f32 {
+    fn find_t_for_x(x: f32, x1: f32, x2: f32) -> f32 {
         let mut guess = x;
-        let mut error = f32::MAX;
         for _ in 0..8 {
-            let pos = Self::calc_bezier(guess, self.x1, self.x2);
-            error = pos - x;
+            let error = Self::calc_bezier(guess, x1, x2) - x;
             if error.abs() <= 0.0000001 {
                 return guess;
             }
-            let slope = Self::calc_bezier_slope(guess, self.x1, self.x2);
+            let slope = Self::calc_bezier_slope(guess, x1, x2);
+            if slope.abs() < 0.000001 {
+                break;
+            }
             guess -= error / slope;
         }
-        if error.abs() <= 0.0000001 {
-            guess
-        } else {
-            x
+
+        // Newton's method doesn't converge where the curve is nearly flat in x, so fall back to bisection, which
+        // always does because x increases with t for control points between 0 and 1.
+        let (mut low, mut high) = (0.0, 1.0);
+        let mut guess = x.clamp(0.0, 1.0);
+        for _ in 0..32 {
+            let error = Self::calc_bezier(guess, x1, x2) - x;
+            if error.abs() <= 0.0000001 {
+                break;
+            }
+            if error > 0.0 {
+                high = guess;
+            } else {
+                low = guess;
+            }
+            guess = (low + high) / 2.0;
         }
+        guess
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::TimingFunction;
+    use vizia_style::StepPosition;
+
+    fn assert_samples(timing_func: TimingFunction, samples: &[(f32, f32)]) {
+        for (x, expected) in samples {
+            let value = timing_func.value(*x);
+            assert!((value - expected).abs() < 0.0005, "value({x}) = {value}, expected {expected}");
+        }
+    }
 
     #[test]
     fn linear() {
@@ -95,4 +166,45 @@ mod tests {
         let timing_func = TimingFunction::ease();
         assert_eq!(timing_func.value(0.25), 0.4085106);
     }
+
+    #[test]
+    fn cubic_bezier() {
+        // Reference values from the parametric curve, sampled at t = 0.25, 0.5 and 0.75.
+        assert_samples(
+            TimingFunction::new(0.4, 0.0, 0.2, 1.0),
+            &[(0.0, 0.0), (0.2125, 0.15625), (0.35, 0.5), (0.5625, 0.84375), (1.0, 1.0)],
+        );
+
+        // Overshooting curves go outside of 0 to 1 in y.
+        assert_samples(
+            TimingFunction::new(0.5, -0.5, 0.5, 1.5),
+            &[(0.296875, 0.015625), (0.5, 0.5), (0.703125, 0.984375)],
+        );
+    }
+
+    #[test]
+    fn cubic_bezier_with_flat_middle() {
+        // Time barely moves along the curve in the middle, where Newton's method doesn't converge.
+        assert_samples(
+            TimingFunction::new(1.0, 0.0, 0.0, 1.0),
+            &[(0.3, 0.0474), (0.45, 0.17688), (0.49, 0.30142), (0.51, 0.69858)],
+        );
+    }
+
+    #[test]
+    fn steps() {
+        let samples = [0.0, 0.1, 0.25, 0.3, 0.5, 0.8, 0.99, 1.0];
+        let expected = [
+            (StepPosition::JumpEnd, [0.0, 0.0, 0.25, 0.25, 0.5, 0.75, 0.75, 1.0]),
+            (StepPosition::JumpStart, [0.25, 0.25, 0.5, 0.5, 0.75, 1.0, 1.0, 1.0]),
+            (StepPosition::JumpNone, [0.0, 0.0, 1.0 / 3.0, 1.0 / 3.0, 2.0 / 3.0, 1.0, 1.0, 1.0]),
+            (StepPosition::JumpBoth, [0.2, 0.2, 0.4, 0.4, 0.6, 0.8, 0.8, 1.0]),
+        ];
+
+        for (position, values) in expected {
+            let timing_func = TimingFunction::steps(4, position);
+            let samples = samples.iter().copied().zip(values).collect::<Vec<_>>();
+            assert_samples(timing_func, &samples);
+        }
+    }
 }
@@ -1,5 +1,6 @@
 use hashbrown::{HashMap, HashSet};
-use std::any::TypeId;
+use log::warn;
+use std::any::{type_name, TypeId};
 
 use crate::binding::{get_storeid, BasicStore, Store, StoreId};
 use crate::context::{CURRENT, MAPS, MAP_MANAGER};
@@ -54,8 +55,94 @@ where
 
         CURRENT.with_borrow_mut(|f| *f = id);
 
-        let ancestors = cx.current().parent_iter(&cx.tree).collect::<HashSet<_>>();
-        let new_ancestors = id.parent_iter(&cx.tree).collect::<Vec<_>>();
+        let attached = binding.attach(cx);
+        if cfg!(debug_assertions) && !attached {
+            warn!("{}", missing_source_message(cx, &binding.lens, current));
+        }
+
+        cx.bindings.insert(id, Box::new(binding));
+
+        cx.with_current(id, |cx| {
+            // Call the body of the binding
+            if let Some(mut binding) = cx.bindings.remove(&id) {
+                binding.update(cx);
+                cx.bindings.insert(id, binding);
+            }
+        });
+
+        let _: Handle<Self> =
+            Handle { current: id, entity: id, p: Default::default(), cx }.ignore();
+    }
+}
+
+// Describes a binding which found no model or view to observe, and the view which it was built in.
+fn missing_source_message<L: Lens>(cx: &Context, lens: &L, view: Entity) -> String {
+    let element = cx.views.get(&view).and_then(|view| view.element()).unwrap_or("<unnamed>");
+    let id = cx.style.ids.get(view).map(|id| format!(" #{}", id)).unwrap_or_default();
+
+    format!(
+        "Binding to {:?} found no {} on the ancestors of {} ({}{}). The model must be built on an ancestor of the view before the binding.",
+        lens,
+        type_name::<L::Source>(),
+        view,
+        element,
+        id
+    )
+}
+
+pub(crate) trait BindingHandler {
+    fn update(&mut self, cx: &mut Context);
+    /// Registers the binding as an observer of the closest source of its lens, returning false if there is none.
+    fn attach(&self, cx: &mut Context) -> bool;
+    fn remove(&self, cx: &mut Context);
+    fn debug(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result;
+}
+
+impl<L> BindingHandler for Binding<L>
+where
+    L: 'static + Lens<Source: 'static, Target: Data>,
+{
+    fn update(&mut self, cx: &mut Context) {
+        // Models built by the previous content are only kept if the new content builds them again.
+        let owned_models = cx.release_models(self.entity);
+
+        #[cfg(debug_assertions)]
+        let removed = cx.leak_checks.then(|| {
+            vizia_storage::TreeIterator::subtree(&cx.tree, self.entity).skip(1).collect::<Vec<_>>()
+        });
+
+        cx.remove_children(cx.current());
+
+        #[cfg(debug_assertions)]
+        if let Some(removed) = removed {
+            cx.assert_removed(&removed);
+        }
+
+        MAP_MANAGER.with_borrow_mut(|manager| {
+            MAPS.with_borrow_mut(|maps| {
+                maps.retain(|id, (e, _)| {
+                    if *e == self.entity {
+                        manager.destroy(*id);
+                        false
+                    } else {
+                        true
+                    }
+                });
+            });
+        });
+
+        if let Some(builder) = &self.content {
+            CURRENT.with_borrow_mut(|f| *f = self.entity);
+            (builder)(cx, self.lens);
+        }
+
+        cx.remove_unclaimed_models(self.entity, owned_models);
+    }
+
+    fn attach(&self, cx: &mut Context) -> bool {
+        let parent = cx.tree.get_data_parent(self.entity).unwrap_or(Entity::root());
+        let ancestors = parent.data_parent_iter(&cx.tree).collect::<HashSet<_>>();
+        let new_ancestors = self.entity.data_parent_iter(&cx.tree).collect::<Vec<_>>();
 
         fn insert_store<L>(
             ancestors: &HashSet<Entity>,
@@ -98,11 +185,11 @@ where
                         &ancestors,
                         &mut model_data_store.stores,
                         ModelOrView::Model(model_data.as_ref()),
-                        lens,
-                        id,
+                        self.lens,
+                        self.entity,
                     );
 
-                    break;
+                    return true;
                 }
 
                 // Check for view store
@@ -112,62 +199,21 @@ where
                             &ancestors,
                             &mut model_data_store.stores,
                             ModelOrView::View(view_handler.as_ref()),
-                            lens,
-                            id,
+                            self.lens,
+                            self.entity,
                         );
 
-                        break;
+                        return true;
                     }
                 }
             }
         }
 
-        cx.bindings.insert(id, Box::new(binding));
-
-        cx.with_current(id, |cx| {
-            // Call the body of the binding
-            if let Some(mut binding) = cx.bindings.remove(&id) {
-                binding.update(cx);
-                cx.bindings.insert(id, binding);
-            }
-        });
-
-        let _: Handle<Self> =
-            Handle { current: id, entity: id, p: Default::default(), cx }.ignore();
-    }
-}
-
-pub(crate) trait BindingHandler {
-    fn update(&mut self, cx: &mut Context);
-    fn remove(&self, cx: &mut Context);
-    fn debug(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result;
-}
-
-impl<L: 'static + Lens> BindingHandler for Binding<L> {
-    fn update(&mut self, cx: &mut Context) {
-        cx.remove_children(cx.current());
-
-        MAP_MANAGER.with_borrow_mut(|manager| {
-            MAPS.with_borrow_mut(|maps| {
-                maps.retain(|id, (e, _)| {
-                    if *e == self.entity {
-                        manager.destroy(*id);
-                        false
-                    } else {
-                        true
-                    }
-                });
-            });
-        });
-
-        if let Some(builder) = &self.content {
-            CURRENT.with_borrow_mut(|f| *f = self.entity);
-            (builder)(cx, self.lens);
-        }
+        false
     }
 
     fn remove(&self, cx: &mut Context) {
-        for entity in self.entity.parent_iter(&cx.tree) {
+        for entity in self.entity.data_parent_iter(&cx.tree) {
             if let Some(model_data_store) = cx.data.get_mut(&entity) {
                 let key = get_storeid(&self.lens);
 
@@ -212,3 +258,55 @@ impl std::fmt::Debug for dyn BindingHandler {
         self.debug(f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use log::{Level, LevelFilter, Log, Metadata, Record};
+
+    use super::*;
+
+    thread_local! {
+        static WARNINGS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    // Records the warnings logged on the current thread, so that tests running in parallel don't see each other's.
+    struct WarningLogger;
+
+    impl Log for WarningLogger {
+        fn enabled(&self, metadata: &Metadata) -> bool {
+            metadata.level() <= Level::Warn
+        }
+
+        fn log(&self, record: &Record) {
+            if self.enabled(record.metadata()) {
+                WARNINGS.with_borrow_mut(|warnings| warnings.push(record.args().to_string()));
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    #[derive(Lens)]
+    struct MissingData {
+        value: i32,
+    }
+
+    impl Model for MissingData {}
+
+    #[test]
+    fn binding_without_model_logs_warning() {
+        let _ = log::set_logger(&WarningLogger);
+        log::set_max_level(LevelFilter::Warn);
+
+        let mut cx = Context::default();
+        let panel = Element::new(&mut cx).id("panel").entity();
+        cx.with_current(panel, |cx| Binding::new(cx, MissingData::value, |_, _| {}));
+
+        let warnings = WARNINGS.with_borrow_mut(std::mem::take);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("MissingData"));
+        assert!(warnings[0].contains("element #panel"));
+    }
+}
@@ -74,10 +74,17 @@ impl_data_simple!(KeyChord);
 impl_data_simple!(FamilyOwned);
 impl_data_simple!(FontWeight);
 impl_data_simple!(TextAlign);
+impl_data_simple!(TextTransform);
+impl_data_simple!(TextRendering);
 impl_data_simple!(LengthOrPercentage);
 impl_data_simple!(CornerShape);
 impl_data_simple!(Shadow);
 impl_data_simple!(TextDecorationLine);
+impl_data_simple!(Role);
+impl_data_simple!(DefaultActionVerb);
+impl_data_simple!(Live);
+impl_data_simple!(Layer);
+impl_data_simple!(BoundingBox);
 
 impl Data for &'static str {
     fn same(&self, other: &Self) -> bool {
@@ -3,7 +3,7 @@ use std::borrow::Borrow;
 use std::fmt::{Debug, Formatter};
 use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
-use std::ops::{BitAnd, BitOr, Deref};
+use std::ops::{BitAnd, BitOr, Deref, DerefMut};
 use std::rc::Rc;
 
 use crate::context::{CURRENT, MAPS, MAP_MANAGER};
@@ -20,6 +20,23 @@ pub trait Lens: 'static + Copy + Debug + Hash {
     type Target;
 
     fn view<'a>(&self, source: &'a Self::Source) -> Option<LensValue<'a, Self::Target>>;
+
+    /// Writes a value to the target of the lens within the source, returning whether the value was written.
+    ///
+    /// Lenses are read-only by default. A lens which also writes to its source can be created with
+    /// [`two_way()`](LensExt::two_way).
+    #[allow(unused_variables)]
+    fn write(&self, source: &mut Self::Source, value: Self::Target) -> bool {
+        false
+    }
+}
+
+/// A lens which can also construct a mutable reference to a piece of some data.
+///
+/// The `Lens` derive macro implements this trait for the lens of each field of a struct, and for the lens of each
+/// variant of an enum.
+pub trait LensMut: Lens {
+    fn view_mut<'a>(&self, source: &'a mut Self::Source) -> Option<&'a mut Self::Target>;
 }
 
 /// A type returned by `Lens::view()` which contains either a reference to model data or an owned value.
@@ -130,6 +147,34 @@ pub trait LensExt: Lens {
         MapRef { id, lens: self, o: PhantomData }
     }
 
+    /// Returns the target of the lens within the data of the context, borrowed from the model or view which owns
+    /// it where possible. Unlike [`get()`](ResGet::get), the target isn't cloned and isn't required to implement
+    /// `Clone`. Returns `None` if the source of the lens isn't found.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Lens)]
+    /// # struct AppData {
+    /// #     items: Vec<String>,
+    /// # }
+    /// #
+    /// # impl Model for AppData {}
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// # AppData { items: vec![String::from("Vizia")] }.build(cx);
+    /// #
+    /// let longest = AppData::items
+    ///     .view_in(cx)
+    ///     .and_then(|items| items.iter().map(String::len).max())
+    ///     .unwrap_or_default();
+    /// ```
+    fn view_in<'a>(&self, cx: &'a impl DataContext) -> Option<LensValue<'a, Self::Target>> {
+        self.view(cx.data()?)
+    }
+
     fn unwrap<T: 'static>(self) -> Then<Self, UnwrapLens<T>>
     where
         Self: Lens<Target = Option<T>>,
@@ -137,6 +182,36 @@ pub trait LensExt: Lens {
         self.then(UnwrapLens::new())
     }
 
+    /// Creates a lens which both reads from and writes to the target of this lens.
+    ///
+    /// Views which edit a value, such as [`Textbox`](crate::views::Textbox), [`Checkbox`](crate::views::Checkbox),
+    /// [`Slider`](crate::views::Slider), and [`Switch`](crate::views::Switch), write changes to the data through a
+    /// two-way lens, without the need for an event and a model handler.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Lens)]
+    /// # struct AppData {
+    /// #     name: String,
+    /// # }
+    /// #
+    /// # impl Model for AppData {}
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// # AppData { name: String::from("Vizia") }.build(cx);
+    /// #
+    /// Textbox::new(cx, AppData::name.two_way());
+    /// ```
+    fn two_way(self) -> TwoWay<Self>
+    where
+        Self: LensMut,
+    {
+        TwoWay::new(self)
+    }
+
     fn into_lens<T: 'static>(self) -> Then<Self, IntoLens<Self::Target, T>>
     where
         Self::Target: Clone + Into<T>,
@@ -284,6 +359,16 @@ where
     }
 }
 
+impl<A, B> LensMut for Then<A, B>
+where
+    A: LensMut,
+    B: LensMut<Source = A::Target>,
+{
+    fn view_mut<'a>(&self, source: &'a mut Self::Source) -> Option<&'a mut Self::Target> {
+        self.a.view_mut(source).and_then(|val| self.b.view_mut(val))
+    }
+}
+
 impl<T: Clone, U: Clone> Clone for Then<T, U> {
     fn clone(&self) -> Self {
         Self { a: self.a.clone(), b: self.b.clone() }
@@ -351,6 +436,16 @@ where
     }
 }
 
+impl<L, T> LensMut for Index<L, T>
+where
+    L: LensMut<Target: DerefMut<Target = [T]>>,
+    T: 'static + Clone,
+{
+    fn view_mut<'a>(&self, source: &'a mut Self::Source) -> Option<&'a mut Self::Target> {
+        self.lens.view_mut(source).and_then(|v| v.get_mut(self.index))
+    }
+}
+
 pub struct StaticLens<T: 'static> {
     data: &'static T,
 }
@@ -421,6 +516,12 @@ impl<T: 'static> Lens for UnwrapLens<T> {
     }
 }
 
+impl<T: 'static> LensMut for UnwrapLens<T> {
+    fn view_mut<'a>(&self, source: &'a mut Self::Source) -> Option<&'a mut Self::Target> {
+        source.as_mut()
+    }
+}
+
 impl<T: 'static> Debug for UnwrapLens<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.write_str("unwrap")
@@ -553,6 +654,16 @@ impl<L: Lens> Lens for Wrapper<L> {
     fn view<'a>(&self, source: &'a Self::Source) -> Option<LensValue<'a, Self::Target>> {
         self.0.view(source)
     }
+
+    fn write(&self, source: &mut Self::Source, value: Self::Target) -> bool {
+        self.0.write(source, value)
+    }
+}
+
+impl<L: LensMut> LensMut for Wrapper<L> {
+    fn view_mut<'a>(&self, source: &'a mut Self::Source) -> Option<&'a mut Self::Target> {
+        self.0.view_mut(source)
+    }
 }
 
 impl<L: Lens> Debug for Wrapper<L> {
@@ -561,6 +672,51 @@ impl<L: Lens> Debug for Wrapper<L> {
     }
 }
 
+/// `Lens` which writes values to its source, created with [`two_way()`](LensExt::two_way).
+#[derive(Hash, Clone, Copy)]
+pub struct TwoWay<L> {
+    lens: L,
+}
+
+impl<L> TwoWay<L> {
+    pub fn new(lens: L) -> Self
+    where
+        L: LensMut,
+    {
+        Self { lens }
+    }
+}
+
+impl<L: LensMut> Lens for TwoWay<L> {
+    type Source = L::Source;
+    type Target = L::Target;
+
+    fn view<'a>(&self, source: &'a Self::Source) -> Option<LensValue<'a, Self::Target>> {
+        self.lens.view(source)
+    }
+
+    fn write(&self, source: &mut Self::Source, value: Self::Target) -> bool {
+        if let Some(target) = self.lens.view_mut(source) {
+            *target = value;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<L: LensMut> LensMut for TwoWay<L> {
+    fn view_mut<'a>(&self, source: &'a mut Self::Source) -> Option<&'a mut Self::Target> {
+        self.lens.view_mut(source)
+    }
+}
+
+impl<L: Lens> Debug for TwoWay<L> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{:?}.two_way()", self.lens))
+    }
+}
+
 impl<L1: Lens<Target = bool>, L2: Lens<Target = bool>> BitOr<L2> for Wrapper<L1>
 where
     L1: Lens<Source = L2::Source>,
@@ -694,3 +850,123 @@ where
         Some(LensValue::Borrowed(*self))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use crate::events::EventManager;
+    use crate::prelude::*;
+    use crate::systems::binding_system;
+
+    #[derive(Lens)]
+    struct Settings {
+        name: String,
+        enabled: bool,
+        volume: f32,
+    }
+
+    enum SettingsEvent {
+        SetName(String),
+    }
+
+    impl Model for Settings {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|settings_event, _| match settings_event {
+                SettingsEvent::SetName(name) => self.name.clone_from(name),
+            });
+        }
+    }
+
+    fn build_settings(cx: &mut Context) {
+        Settings { name: String::from("Alice"), enabled: false, volume: 0.5 }.build(cx);
+    }
+
+    fn settings(cx: &Context) -> &Settings {
+        cx.data::<Settings>().unwrap()
+    }
+
+    fn emit(
+        cx: &mut Context,
+        event_manager: &mut EventManager,
+        target: Entity,
+        message: impl Any + Send,
+    ) {
+        cx.emit_custom(Event::new(message).target(target).origin(target));
+        event_manager.flush_events(cx, |_| {});
+        binding_system(cx);
+    }
+
+    fn text(cx: &Context, entity: Entity) -> Option<&str> {
+        cx.style.text.get(entity).map(String::as_str)
+    }
+
+    #[test]
+    fn two_way_textbox_writes_submitted_text() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+        build_settings(&mut cx);
+        let textbox = Textbox::new(&mut cx, Settings::name.two_way()).entity();
+        assert_eq!(text(&cx, textbox), Some("Alice"));
+
+        emit(&mut cx, &mut event_manager, textbox, TextEvent::StartEdit);
+        emit(&mut cx, &mut event_manager, textbox, TextEvent::InsertText(String::from("Bob")));
+        assert_eq!(settings(&cx).name, "Alice");
+
+        emit(&mut cx, &mut event_manager, textbox, TextEvent::Submit(true));
+        assert_eq!(settings(&cx).name, "Bob");
+
+        emit(&mut cx, &mut event_manager, textbox, TextEvent::EndEdit);
+        assert_eq!(text(&cx, textbox), Some("Bob"));
+    }
+
+    #[test]
+    fn two_way_textbox_defers_changes_while_editing() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+        build_settings(&mut cx);
+        let textbox = Textbox::new(&mut cx, Settings::name.two_way()).entity();
+
+        emit(&mut cx, &mut event_manager, textbox, TextEvent::StartEdit);
+        emit(&mut cx, &mut event_manager, textbox, TextEvent::InsertText(String::from("Bob")));
+
+        let name = SettingsEvent::SetName(String::from("Carol"));
+        emit(&mut cx, &mut event_manager, Entity::root(), name);
+        assert_eq!(text(&cx, textbox), Some("Bob"));
+
+        emit(&mut cx, &mut event_manager, textbox, TextEvent::EndEdit);
+        assert_eq!(text(&cx, textbox), Some("Carol"));
+        assert_eq!(settings(&cx).name, "Carol");
+    }
+
+    #[test]
+    fn two_way_checkbox_and_switch_toggle_data() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+        build_settings(&mut cx);
+        let one_way = Checkbox::new(&mut cx, Settings::enabled).entity();
+        let checkbox = Checkbox::new(&mut cx, Settings::enabled.two_way()).entity();
+        let switch = Switch::new(&mut cx, Settings::enabled.two_way()).entity();
+
+        emit(&mut cx, &mut event_manager, one_way, WindowEvent::Press { mouse: false });
+        assert!(!settings(&cx).enabled);
+
+        emit(&mut cx, &mut event_manager, checkbox, WindowEvent::Press { mouse: false });
+        assert!(settings(&cx).enabled);
+
+        cx.focused = switch;
+        emit(&mut cx, &mut event_manager, switch, WindowEvent::Press { mouse: false });
+        assert!(!settings(&cx).enabled);
+    }
+
+    #[test]
+    fn two_way_slider_writes_value() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+        build_settings(&mut cx);
+        let slider = Slider::new(&mut cx, Settings::volume.two_way()).entity();
+
+        emit(&mut cx, &mut event_manager, slider, WindowEvent::KeyDown(Code::ArrowUp, None));
+        assert_eq!(settings(&cx).volume, 0.5 + 0.01);
+    }
+}
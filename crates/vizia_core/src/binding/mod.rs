@@ -195,6 +195,10 @@
 //!
 //! Note that even though the `count` value is `i32`, the label accepts a lens to this data because it implements `ToString` and is converted internally.
 //! If the data is the wrong type and cannot be converted internally, use the [`map()`](crate::binding::LensExt::map) method on the lens.
+//!
+//! Views which edit a value, such as a [`Textbox`](crate::views::Textbox), can also write changes directly to the data
+//! when bound with a [`two_way()`](crate::binding::LensExt::two_way) lens, which avoids declaring an event and handling it
+//! in the model for each field of a form.
 mod lens;
 pub use lens::*;
 
@@ -109,6 +109,8 @@ impl_res_simple!(f32);
 impl_res_simple!(f64);
 impl_res_simple!(CursorIcon);
 impl_res_simple!(Overflow);
+impl_res_simple!(BlendMode);
+impl_res_simple!(AspectRatio);
 impl_res_simple!(LengthValue);
 impl_res_simple!(FontWeight);
 impl_res_simple!(FontWeightKeyword);
@@ -116,26 +118,45 @@ impl_res_simple!(FontSlant);
 impl_res_simple!(CornerShape);
 impl_res_simple!(Angle);
 impl_res_simple!(TextAlign);
+impl_res_simple!(VerticalAlign);
 impl_res_simple!(TextOverflow);
+impl_res_simple!(WhiteSpace);
+impl_res_simple!(TextTransform);
+impl_res_simple!(TextRendering);
 impl_res_simple!(LineClamp);
+impl_res_clone!(LineHeight);
 impl_res_clone!(Shadow);
 impl_res_clone!(LinearGradientBuilder);
 impl_res_clone!(ShadowBuilder);
 impl_res_simple!(FontVariation);
+impl_res_simple!(FontFeature);
+impl_res_clone!(FontFeatureSettings);
 impl_res_clone!(Filter);
 impl_res_simple!(Opacity);
+impl_res_simple!(Elevation);
 impl_res_simple!(FontWidth);
 impl_res_clone!(Translate);
 impl_res_clone!(Scale);
 impl_res_clone!(Position);
+impl_res_clone!(BackgroundSize);
+impl_res_simple!(BackgroundRepeat);
+impl_res_simple!(BackgroundRepeatKeyword);
 impl_res_simple!(PointerEvents);
+impl_res_simple!(PressEffect);
 impl_res_simple!(ButtonVariant);
 impl_res_simple!(AvatarVariant);
+impl_res_simple!(Orientation);
 impl_res_clone!(FamilyOwned);
+impl_res_clone!(ColorOrGradient);
 impl_res_simple!(TextDecorationLine);
+impl_res_clone!(TextDecoration);
 impl_res_clone!(TextStroke);
 impl_res_clone!(TextStrokeStyle);
 impl_res_simple!(Alignment);
+impl_res_simple!(Role);
+impl_res_simple!(DefaultActionVerb);
+impl_res_simple!(Live);
+impl_res_simple!(Layer);
 
 impl<'i> ResGet<FontFamily<'i>> for FontFamily<'i> {
     fn get_ref<'a>(&'a self, _: &'a impl DataContext) -> Option<LensValue<'a, Self>> {
@@ -233,6 +254,18 @@ impl ResGet<LinearGradient> for LinearGradient {
 
 impl Res<LinearGradient> for LinearGradient {}
 
+impl ResGet<ConicGradient> for ConicGradient {
+    fn get_ref<'a>(&'a self, _: &'a impl DataContext) -> Option<LensValue<'a, Self>> {
+        Some(LensValue::Borrowed(self))
+    }
+
+    fn get(&self, _: &impl DataContext) -> ConicGradient {
+        self.clone()
+    }
+}
+
+impl Res<ConicGradient> for ConicGradient {}
+
 impl ResGet<Units> for Units {
     fn get_ref<'a>(&'a self, _: &'a impl DataContext) -> Option<LensValue<'a, Self>> {
         Some(LensValue::Borrowed(self))
@@ -2,6 +2,8 @@
 //! results. The main type here is CachedData, usually accessed via `cx.cache`.
 
 use crate::prelude::*;
+use crate::style::CornerRadii;
+use hashbrown::HashSet;
 use skia_safe::Matrix;
 use vizia_storage::SparseSet;
 
@@ -17,6 +19,17 @@ pub struct CachedData {
     pub(crate) geo_changed: SparseSet<GeoChanged>,
     pub(crate) transform: SparseSet<Matrix>,
     pub(crate) clip_path: SparseSet<BoundingBox>,
+    // The resolved corners of the views with rounded corners, which the cursor is hit-tested against.
+    pub(crate) corner_radii: SparseSet<CornerRadii>,
+    // The bounds containing each view and its descendants as they're drawn, in window coordinates, which the hover
+    // system uses to skip the subtrees away from the cursor. They're computed with the transforms and cleared when
+    // the layout changes.
+    pub(crate) hover_bounds: SparseSet<BoundingBox>,
+    // The views which were over the cursor when it was last hit-tested.
+    pub(crate) over: Vec<Entity>,
+    pub(crate) entities_laid_out: usize,
+    // The entities whose bounds changed since the anchored views were last positioned.
+    pub(crate) geometry_changed: HashSet<Entity>,
 }
 
 impl CachedData {
@@ -34,6 +47,13 @@ impl CachedData {
         self.geo_changed.remove(entity);
         self.transform.remove(entity);
         self.clip_path.remove(entity);
+        self.corner_radii.remove(entity);
+        self.hover_bounds.remove(entity);
+    }
+
+    /// Returns the number of entities which were laid out during the last layout pass.
+    pub fn entities_laid_out(&self) -> usize {
+        self.entities_laid_out
     }
 
     /// Returns the bounding box of the entity, determined by the layout system.
@@ -111,4 +111,40 @@ impl AccessNode {
     pub fn set_max_numeric_value(&mut self, value: f64) {
         self.node_builder.set_max_numeric_value(value);
     }
+
+    /// Adds an action which assistive technologies can request, received as a [`WindowEvent::ActionRequest`].
+    pub fn add_action(&mut self, action: Action) {
+        self.node_builder.add_action(action);
+    }
+
+    /// Sets whether the node is selected, such as the selected tab of a tab view.
+    pub fn set_selected(&mut self, selected: bool) {
+        self.node_builder.set_selected(selected);
+    }
+
+    /// Sets the horizontal scroll offset of the node.
+    pub fn set_scroll_x(&mut self, value: f64) {
+        self.node_builder.set_scroll_x(value);
+    }
+
+    pub fn set_scroll_x_min(&mut self, value: f64) {
+        self.node_builder.set_scroll_x_min(value);
+    }
+
+    pub fn set_scroll_x_max(&mut self, value: f64) {
+        self.node_builder.set_scroll_x_max(value);
+    }
+
+    /// Sets the vertical scroll offset of the node.
+    pub fn set_scroll_y(&mut self, value: f64) {
+        self.node_builder.set_scroll_y(value);
+    }
+
+    pub fn set_scroll_y_min(&mut self, value: f64) {
+        self.node_builder.set_scroll_y_min(value);
+    }
+
+    pub fn set_scroll_y_max(&mut self, value: f64) {
+        self.node_builder.set_scroll_y_max(value);
+    }
 }
@@ -127,6 +127,7 @@ impl BackendContext {
     /// Sets the scale factor used by the application.
     pub fn set_scale_factor(&mut self, scale: f64) {
         self.0.style.dpi_factor = scale;
+        self.0.text_context.clear_measurements();
     }
 
     /// Sets the size of the window.
@@ -227,6 +228,19 @@ impl BackendContext {
 
     // Returns true if animations are playing
     pub fn process_animations(&mut self) -> bool {
+        // Keep redrawing while the focus ring scales in.
+        if self.0.focus_ring.is_animating() {
+            animation_system(&mut self.0);
+            return true;
+        }
+
+        // Keep redrawing while the update overlay fades out.
+        #[cfg(debug_assertions)]
+        if self.0.update_overlay.is_fading() {
+            animation_system(&mut self.0);
+            return true;
+        }
+
         animation_system(&mut self.0)
     }
 
@@ -254,6 +268,11 @@ impl BackendContext {
         );
     }
 
+    /// Sends a [`WindowEvent::CloseRequested`] event to every window, as with [`EventContext::request_quit`].
+    pub fn request_quit(&mut self) {
+        EventContext::new(&mut self.0).request_quit();
+    }
+
     pub fn needs_refresh(&mut self, window_entity: Entity) {
         self.0.style.system_flags = SystemFlags::all();
         self.0.needs_redraw(window_entity);
@@ -0,0 +1,223 @@
+use std::fmt::{self, Debug, Write};
+
+use vizia_storage::ParentIterator;
+
+use crate::prelude::*;
+
+/// A formatter which describes an entity's view, style, and layout, created with [`Context::entity_debug`].
+///
+/// The alternate `{:#?}` format prints one field per line. Style properties are listed in a fixed order and only
+/// include properties which have been set on the entity, either inline or by a matched style rule.
+pub struct EntityDebug<'a> {
+    cx: &'a Context,
+    entity: Entity,
+}
+
+impl EntityDebug<'_> {
+    fn element(&self, entity: Entity) -> Option<&'static str> {
+        self.cx.views.get(&entity).and_then(|view| view.element())
+    }
+
+    fn classes(&self) -> Vec<&str> {
+        // Sort the classes so that the output is stable between runs.
+        let mut classes = self
+            .cx
+            .style
+            .classes
+            .get(self.entity)
+            .map(|classes| classes.iter().map(|class| class.as_str()).collect::<Vec<_>>())
+            .unwrap_or_default();
+        classes.sort_unstable();
+        classes
+    }
+
+    fn parents(&self) -> Vec<String> {
+        ParentIterator::new(&self.cx.tree, Some(self.entity))
+            .skip(1)
+            .map(|parent| match self.element(parent) {
+                Some(element) => format!("{} {}", parent, element),
+                None => parent.to_string(),
+            })
+            .collect()
+    }
+
+    fn properties(&self) -> Vec<(&'static str, String)> {
+        let mut properties = Vec::new();
+        self.cx.style.for_each_property(self.entity, |name, value| {
+            properties.push((name, format!("{:?}", value)));
+        });
+        properties
+    }
+
+    /// Returns the description of the entity as a JSON object.
+    pub fn to_json(&self) -> String {
+        let bounds = self.cx.cache.get_bounds(self.entity);
+
+        let mut json = String::from("{");
+        let _ = write!(json, "\"entity\":{}", json_string(&self.entity.to_string()));
+        let _ = write!(json, ",\"element\":{}", json_option(self.element(self.entity)));
+        let _ = write!(
+            json,
+            ",\"id\":{}",
+            json_option(self.cx.style.ids.get(self.entity).map(|id| id.as_str()))
+        );
+        let _ = write!(json, ",\"classes\":{}", json_array(self.classes()));
+        let _ = write!(
+            json,
+            ",\"pseudo_classes\":{}",
+            json_string(&format!("{:?}", self.cx.style.pseudo_classes.get(self.entity)))
+        );
+        let _ = write!(
+            json,
+            ",\"abilities\":{}",
+            json_string(&format!("{:?}", self.cx.style.abilities.get(self.entity)))
+        );
+        let _ = write!(json, ",\"parents\":{}", json_array(self.parents()));
+        let _ = write!(
+            json,
+            ",\"bounds\":{{\"x\":{},\"y\":{},\"w\":{},\"h\":{}}}",
+            json_number(bounds.x),
+            json_number(bounds.y),
+            json_number(bounds.w),
+            json_number(bounds.h)
+        );
+        let _ = write!(
+            json,
+            ",\"z_index\":{}",
+            self.cx.style.z_index.get(self.entity).copied().unwrap_or_default()
+        );
+        json.push_str(",\"properties\":{");
+        for (index, (name, value)) in self.properties().into_iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            let _ = write!(json, "{}:{}", json_string(name), json_string(&value));
+        }
+        json.push_str("}}");
+
+        json
+    }
+}
+
+impl Debug for EntityDebug<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct Properties(Vec<(&'static str, String)>);
+
+        impl Debug for Properties {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_map()
+                    .entries(self.0.iter().map(|(name, value)| (name, Raw(value))))
+                    .finish()
+            }
+        }
+
+        // Prints a string without quotes, so that property values appear as they were formatted.
+        struct Raw<'a>(&'a str);
+
+        impl Debug for Raw<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.0)
+            }
+        }
+
+        f.debug_struct("Entity")
+            .field("entity", &self.entity)
+            .field("element", &self.element(self.entity))
+            .field("id", &self.cx.style.ids.get(self.entity))
+            .field("classes", &self.classes())
+            .field("pseudo_classes", &self.cx.style.pseudo_classes.get(self.entity))
+            .field("abilities", &self.cx.style.abilities.get(self.entity))
+            .field("parents", &self.parents())
+            .field("bounds", &self.cx.cache.get_bounds(self.entity))
+            .field("z_index", &self.cx.style.z_index.get(self.entity).copied().unwrap_or_default())
+            .field("properties", &Properties(self.properties()))
+            .finish()
+    }
+}
+
+impl Context {
+    /// Returns a formatter describing the given entity, for use when debugging or filing bug reports.
+    pub fn entity_debug(&self, entity: Entity) -> EntityDebug<'_> {
+        EntityDebug { cx: self, entity }
+    }
+
+    /// Returns a readable description of the element name, id, classes, pseudo-classes, abilities, parents,
+    /// computed bounds, z-index, and set style properties of the given entity.
+    pub fn debug_entity(&self, entity: Entity) -> String {
+        format!("{:#?}", self.entity_debug(entity))
+    }
+
+    /// Returns the same description of the entity as [`debug_entity`](Self::debug_entity), formatted as JSON.
+    pub fn debug_entity_json(&self, entity: Entity) -> String {
+        self.entity_debug(entity).to_json()
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn json_option(value: Option<&str>) -> String {
+    value.map_or_else(|| String::from("null"), json_string)
+}
+
+fn json_array<S: AsRef<str>>(values: Vec<S>) -> String {
+    let values = values.iter().map(|value| json_string(value.as_ref())).collect::<Vec<_>>();
+    format!("[{}]", values.join(","))
+}
+
+// JSON has no representation for infinite values, which unbounded views may have.
+fn json_number(value: f32) -> String {
+    if value.is_finite() {
+        value.to_string()
+    } else {
+        String::from("null")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug_entity_lists_set_properties() {
+        let mut cx = Context::default();
+
+        let entity =
+            Element::new(&mut cx).id("target").class("b").class("a").width(Pixels(50.0)).entity();
+
+        let debug = cx.debug_entity(entity);
+        assert!(debug.contains("\"target\""));
+        assert!(debug.contains("\"a\",\n        \"b\""));
+        assert!(debug.contains("\"width\": Pixels(50.0)"));
+        assert!(!debug.contains("\"height\""));
+
+        let json = cx.debug_entity_json(entity);
+        assert!(json.contains("\"id\":\"target\""));
+        assert!(json.contains("\"classes\":[\"a\",\"b\"]"));
+        assert!(json.contains("\"width\":\"Pixels(50.0)\""));
+    }
+
+    #[test]
+    fn json_strings_are_escaped() {
+        assert_eq!(json_string("a\"b\\c\n"), "\"a\\\"b\\\\c\\n\"");
+        assert_eq!(json_number(f32::MAX), f32::MAX.to_string());
+        assert_eq!(json_number(f32::INFINITY), "null");
+    }
+}
@@ -1,4 +1,4 @@
-use skia_safe::canvas::SaveLayerRec;
+use skia_safe::canvas::{SaveLayerRec, SrcRectConstraint};
 use skia_safe::gradient_shader::GradientShaderColors;
 use skia_safe::path::ArcSize;
 use skia_safe::rrect::Corner;
@@ -13,13 +13,14 @@ use vizia_style::LengthPercentageOrAuto;
 
 use hashbrown::HashMap;
 
-use crate::animation::Interpolator;
 use crate::cache::CachedData;
 use crate::events::ViewHandler;
+use crate::layout::baseline::{content_insets, text_top};
 use crate::model::ModelDataStore;
 use crate::prelude::*;
 use crate::resource::{ImageOrSvg, ResourceManager};
-use crate::text::TextContext;
+use crate::style::{resolve_corner_radius, scrollbars};
+use crate::text::{TextContext, TextLayout};
 use vizia_input::MouseState;
 
 /// A context used when drawing a view.
@@ -75,12 +76,8 @@ macro_rules! get_units_property {
     ) => {
         $(#[$meta])*
         pub fn $name(&self) -> Units {
-            let result = self.style.$name.get(self.current);
-            if let Some(Units::Pixels(p)) = result {
-                Units::Pixels(self.logical_to_physical(*p))
-            } else {
-                result.copied().unwrap_or_default()
-            }
+            // Layout resolves the units in the same way, including lengths such as `calc()` expressions.
+            morphorm::Node::$name(&self.current, self.style).unwrap_or_default()
         }
     };
 }
@@ -93,7 +90,23 @@ macro_rules! get_color_property {
         $(#[$meta])*
         pub fn $name(&self) -> Color {
             if let Some(col) = self.style.$name.get(self.current) {
-                Color::rgba(col.r(), col.g(), col.b(), col.a())
+                self.resolve_color(*col)
+            } else {
+                Color::rgba(0, 0, 0, 0)
+            }
+        }
+    };
+}
+
+macro_rules! get_color_or_gradient_property {
+    (
+        $(#[$meta:meta])*
+        $name:ident
+    ) => {
+        $(#[$meta])*
+        pub fn $name(&self) -> Color {
+            if let Some(col) = self.style.$name.get(self.current).map(ColorOrGradient::color) {
+                self.resolve_color(col)
             } else {
                 Color::rgba(0, 0, 0, 0)
             }
@@ -120,7 +133,40 @@ macro_rules! get_length_property {
     };
 }
 
+macro_rules! get_corner_radius_property {
+    (
+        $(#[$meta:meta])*
+        $name:ident
+    ) => {
+        $(#[$meta])*
+        pub fn $name(&self) -> (f32, f32) {
+            // Radii which overlap are scaled down when the path is built.
+            if let Some(radius) = self.style.$name.get(self.current) {
+                return resolve_corner_radius(radius, self.bounds(), self.scale_factor());
+            }
+
+            (0.0, 0.0)
+        }
+    };
+}
+
 impl DrawContext<'_> {
+    /// Returns the color resolved to an RGBA color, with `currentcolor` resolved to the font color of the current
+    /// view, which is inherited from its ancestors, and `accent-color` resolved to the accent color of the operating
+    /// system.
+    fn resolve_color(&self, color: Color) -> Color {
+        let color = match color {
+            Color::CurrentColor => self
+                .style
+                .font_color
+                .get(self.current)
+                .map_or(Color::CurrentColor, ColorOrGradient::color),
+            color => color,
+        };
+
+        Color::RGBA(self.style.resolve_accent_color(color).get_rgba())
+    }
+
     /// Returns the bounds of the current view.
     pub fn bounds(&self) -> BoundingBox {
         self.cache.get_bounds(self.current)
@@ -181,21 +227,21 @@ impl DrawContext<'_> {
 
         let clip_bounds = match (overflowx, overflowy) {
             (Overflow::Visible, Overflow::Visible) => return None,
-            (Overflow::Hidden, Overflow::Visible) => {
+            (_, Overflow::Visible) => {
                 let left = clip_bounds.left();
                 let right = clip_bounds.right();
                 let top = root_bounds.top();
                 let bottom = root_bounds.bottom();
                 BoundingBox::from_min_max(left, top, right, bottom)
             }
-            (Overflow::Visible, Overflow::Hidden) => {
+            (Overflow::Visible, _) => {
                 let left = root_bounds.left();
                 let right = root_bounds.right();
                 let top = clip_bounds.top();
                 let bottom = clip_bounds.bottom();
                 BoundingBox::from_min_max(left, top, right, bottom)
             }
-            (Overflow::Hidden, Overflow::Hidden) => clip_bounds,
+            _ => clip_bounds,
         };
 
         Some(self.build_path(clip_bounds, (0.0, 0.0)))
@@ -203,64 +249,7 @@ impl DrawContext<'_> {
 
     /// Returns the 2D transform of the current view.
     pub fn transform(&self) -> Matrix {
-        let bounds = self.bounds();
-        let scale_factor = self.scale_factor();
-
-        // Apply transform origin.
-        let mut origin = self
-            .style
-            .transform_origin
-            .get(self.current)
-            .map(|transform_origin| {
-                let mut origin = Matrix::translate(bounds.top_left());
-                let offset = transform_origin.as_transform(bounds, scale_factor);
-                origin = offset * origin;
-                origin
-            })
-            .unwrap_or(Matrix::translate(bounds.center()));
-
-        let mut transform = origin;
-        origin = origin.invert().unwrap();
-
-        // Apply translation.
-        if let Some(translate) = self.style.translate.get(self.current) {
-            transform = transform * translate.as_transform(bounds, scale_factor);
-        }
-
-        // Apply rotation.
-        if let Some(rotate) = self.style.rotate.get(self.current) {
-            transform = transform * rotate.as_transform(bounds, scale_factor);
-        }
-
-        // Apply scaling.
-        if let Some(scale) = self.style.scale.get(self.current) {
-            transform = transform * scale.as_transform(bounds, scale_factor);
-        }
-
-        // Apply transform functions.
-        if let Some(transforms) = self.style.transform.get(self.current) {
-            // Check if the transform is currently animating
-            // Get the animation state
-            // Manually interpolate the value to get the overall transform for the current frame
-            if let Some(animation_state) = self.style.transform.get_active_animation(self.current) {
-                if let Some(start) = animation_state.keyframes.first() {
-                    if let Some(end) = animation_state.keyframes.last() {
-                        let start_transform = start.value.as_transform(bounds, scale_factor);
-                        let end_transform = end.value.as_transform(bounds, scale_factor);
-                        let t = animation_state.t;
-                        let animated_transform =
-                            Matrix::interpolate(&start_transform, &end_transform, t);
-                        transform = transform * animated_transform;
-                    }
-                }
-            } else {
-                transform = transform * transforms.as_transform(bounds, scale_factor);
-            }
-        }
-
-        transform = transform * origin;
-
-        transform
+        self.style.local_transform(self.current, self.bounds())
     }
 
     /// Returns the visibility of the current view.
@@ -310,6 +299,11 @@ impl DrawContext<'_> {
         self.style.font_variation_settings.get(self.current).map(Vec::as_slice).unwrap_or_default()
     }
 
+    /// Returns the font features of the current view.
+    pub fn font_feature_settings(&self) -> &[FontFeature] {
+        self.style.font_feature_settings.get(self.current).map_or(&[], |settings| &settings.0)
+    }
+
     /// Function to convert logical points to physical pixels.
     pub fn logical_to_physical(&self, logical: f32) -> f32 {
         self.style.logical_to_physical(logical)
@@ -321,12 +315,27 @@ impl DrawContext<'_> {
     }
 
     get_length_property!(
-        /// Returns the border width of the current view in physical pixels.
-        border_width
+        /// Returns the width of the top border of the current view in physical pixels.
+        border_top_width
     );
 
-    get_color_property!(
-        /// Returns the outline color of the current view.
+    get_length_property!(
+        /// Returns the width of the right border of the current view in physical pixels.
+        border_right_width
+    );
+
+    get_length_property!(
+        /// Returns the width of the bottom border of the current view in physical pixels.
+        border_bottom_width
+    );
+
+    get_length_property!(
+        /// Returns the width of the left border of the current view in physical pixels.
+        border_left_width
+    );
+
+    get_color_or_gradient_property!(
+        /// Returns the outline color of the current view, or the color of its first stop if the outline is a gradient.
         outline_color
     );
 
@@ -340,23 +349,56 @@ impl DrawContext<'_> {
         outline_offset
     );
 
-    get_length_property!(
-        /// Returns the corner radius for the top-left corner of the current view.
+    /// Returns the outline style of the current view.
+    pub fn outline_style(&self) -> BorderStyleKeyword {
+        self.style.outline_style.get(self.current).copied().unwrap_or_default()
+    }
+
+    /// Returns the focus ring color of the current view, which defaults to the accent color.
+    pub fn focus_ring_color(&self) -> Color {
+        self.resolve_color(
+            self.style.focus_ring_color.get(self.current).copied().unwrap_or(Color::AccentColor),
+        )
+    }
+
+    /// Returns the focus ring width of the current view in physical pixels, which defaults to 2px.
+    pub fn focus_ring_width(&self) -> f32 {
+        self.focus_ring_length(self.style.focus_ring_width.get(self.current))
+    }
+
+    /// Returns the focus ring offset of the current view in physical pixels, which defaults to 2px.
+    pub fn focus_ring_offset(&self) -> f32 {
+        self.focus_ring_length(self.style.focus_ring_offset.get(self.current))
+    }
+
+    fn focus_ring_length(&self, length: Option<&LengthOrPercentage>) -> f32 {
+        match length {
+            Some(length) => {
+                let bounds = self.bounds();
+                length.to_pixels(bounds.w.min(bounds.h), self.scale_factor()).round()
+            }
+
+            None => self.logical_to_physical(2.0).round(),
+        }
+    }
+
+    get_corner_radius_property!(
+        /// Returns the horizontal and vertical corner radii for the top-left corner of the current view in physical pixels.
         corner_top_left_radius
     );
 
-    get_length_property!(
-        /// Returns the corner radius for the top-right corner of the current view.
+    get_corner_radius_property!(
+        /// Returns the horizontal and vertical corner radii for the top-right corner of the current view in physical pixels.
         corner_top_right_radius
     );
 
-    get_length_property!(
-        /// Returns the corner radius for the bottom-left corner of the current view.
+    get_corner_radius_property!(
+        /// Returns the horizontal and vertical corner radii for the bottom-left corner of the current view in physical pixels.
         corner_bottom_left_radius
     );
 
-    get_length_property!(
-        /// Returns the corner radius for the bottom-right corner of the current view.
+    get_corner_radius_property!(
+        /// Returns the horizontal and vertical corner radii for the bottom-right corner of the current view in physical pixels.
         corner_bottom_right_radius
     );
 
@@ -425,20 +467,90 @@ impl DrawContext<'_> {
     }
 
     get_color_property!(background_color);
-    get_color_property!(border_color);
+    get_color_or_gradient_property!(
+        /// Returns the color of the top border of the current view, or the color of its first stop if it's a gradient.
+        border_top_color
+    );
+
+    get_color_or_gradient_property!(
+        /// Returns the color of the right border of the current view, or the color of its first stop if it's a gradient.
+        border_right_color
+    );
 
-    /// Returns the border style of the current view.
-    pub fn border_style(&self) -> BorderStyleKeyword {
-        self.style.border_style.get(self.current).copied().unwrap_or_default()
+    get_color_or_gradient_property!(
+        /// Returns the color of the bottom border of the current view, or the color of its first stop if it's a gradient.
+        border_bottom_color
+    );
+
+    get_color_or_gradient_property!(
+        /// Returns the color of the left border of the current view, or the color of its first stop if it's a gradient.
+        border_left_color
+    );
+
+    /// Returns the style of the top border of the current view.
+    pub fn border_top_style(&self) -> BorderStyleKeyword {
+        self.style.border_top_style.get(self.current).copied().unwrap_or_default()
+    }
+
+    /// Returns the style of the right border of the current view.
+    pub fn border_right_style(&self) -> BorderStyleKeyword {
+        self.style.border_right_style.get(self.current).copied().unwrap_or_default()
+    }
+
+    /// Returns the style of the bottom border of the current view.
+    pub fn border_bottom_style(&self) -> BorderStyleKeyword {
+        self.style.border_bottom_style.get(self.current).copied().unwrap_or_default()
+    }
+
+    /// Returns the style of the left border of the current view.
+    pub fn border_left_style(&self) -> BorderStyleKeyword {
+        self.style.border_left_style.get(self.current).copied().unwrap_or_default()
+    }
+
+    get_color_property!(
+        /// Returns the fill color of the current view, used by views which draw vector shapes.
+        fill
+    );
+
+    get_color_property!(
+        /// Returns the stroke color of the current view, used by views which draw vector shapes.
+        stroke
+    );
+
+    /// Returns the stroke width of the current view in physical pixels.
+    pub fn stroke_width(&self) -> f32 {
+        self.style
+            .stroke_width
+            .get(self.current)
+            .and_then(Length::to_px)
+            .map_or(0.0, |width| self.logical_to_physical(width))
+    }
+
+    /// Returns the shape drawn at the ends of open subpaths of the current view when they are stroked.
+    pub fn stroke_linecap(&self) -> StrokeLinecap {
+        self.style.stroke_linecap.get(self.current).copied().unwrap_or_default()
+    }
+
+    /// Returns the shape drawn at the corners of paths of the current view when they are stroked.
+    pub fn stroke_linejoin(&self) -> StrokeLinejoin {
+        self.style.stroke_linejoin.get(self.current).copied().unwrap_or_default()
     }
 
     get_color_property!(selection_color);
     get_color_property!(caret_color);
-    get_color_property!(font_color);
+    get_color_or_gradient_property!(
+        /// Returns the font color of the current view, or the color of its first stop if the text is a gradient.
+        font_color
+    );
 
     /// Returns whether the current view should have its text wrapped.
     pub fn text_wrap(&self) -> bool {
-        self.style.text_wrap.get(self.current).copied().unwrap_or(true)
+        self.white_space().wraps()
+    }
+
+    /// Returns how line breaks are handled and whether lines wrap in the text of the current view.
+    pub fn white_space(&self) -> WhiteSpace {
+        self.style.white_space.get(self.current).copied().unwrap_or_default()
     }
 
     /// Returns the text alignment of the current view.
@@ -446,6 +558,16 @@ impl DrawContext<'_> {
         self.style.text_align.get(self.current).copied().unwrap_or_default()
     }
 
+    /// Returns the vertical text alignment of the current view, if it's set.
+    pub fn vertical_align(&self) -> Option<VerticalAlign> {
+        self.style.vertical_align.get(self.current).copied()
+    }
+
+    /// Returns whether text of the current view which is too small to read is greeked.
+    pub fn text_rendering(&self) -> TextRendering {
+        self.style.text_rendering.get(self.current).copied().unwrap_or_default()
+    }
+
     /// Returns the text overflow preference of the current view.
     pub fn text_overflow(&self) -> TextOverflow {
         self.style.text_overflow.get(self.current).copied().unwrap_or_default()
@@ -461,7 +583,17 @@ impl DrawContext<'_> {
         self.style.shadow.get(self.current)
     }
 
-    pub fn backdrop_filter(&self) -> Option<&Filter> {
+    /// Returns the elevation of the current view.
+    pub fn elevation(&self) -> Option<Elevation> {
+        self.style.elevation.get(self.current).copied()
+    }
+
+    /// Returns the filters of the current view.
+    pub fn filter(&self) -> Option<&Vec<Filter>> {
+        self.style.filter.get(self.current)
+    }
+
+    pub fn backdrop_filter(&self) -> Option<&Vec<Filter>> {
         self.style.backdrop_filter.get(self.current)
     }
 
@@ -469,10 +601,18 @@ impl DrawContext<'_> {
         self.style.background_image.get(self.current)
     }
 
+    pub fn background_position(&self) -> Vec<Translate> {
+        self.style.background_position.get(self.current).cloned().unwrap_or_default()
+    }
+
     pub fn background_size(&self) -> Vec<BackgroundSize> {
         self.style.background_size.get(self.current).cloned().unwrap_or_default()
     }
 
+    pub fn background_repeat(&self) -> Vec<BackgroundRepeat> {
+        self.style.background_repeat.get(self.current).cloned().unwrap_or_default()
+    }
+
     /// Get the vector path of the current view.
     pub fn build_path(&self, bounds: BoundingBox, outset: (f32, f32)) -> Path {
         let corner_top_left_radius = self.corner_top_left_radius();
@@ -492,13 +632,15 @@ impl DrawContext<'_> {
 
         let rect: Rect = bounds.into();
 
+        // When the radii of adjacent corners add up to more than the length of a side, all of the radii are scaled
+        // down by the same factor so that they fit.
         let mut rr = RRect::new_rect_radii(
             rect,
             &[
-                Point::new(corner_top_left_radius, corner_top_left_radius),
-                Point::new(corner_top_right_radius, corner_top_right_radius),
-                Point::new(corner_bottom_right_radius, corner_bottom_right_radius),
-                Point::new(corner_bottom_left_radius, corner_bottom_left_radius),
+                Point::new(corner_top_left_radius.0, corner_top_left_radius.1),
+                Point::new(corner_top_right_radius.0, corner_top_right_radius.1),
+                Point::new(corner_bottom_right_radius.0, corner_bottom_right_radius.1),
+                Point::new(corner_bottom_left_radius.0, corner_bottom_left_radius.1),
             ],
         );
 
@@ -512,22 +654,19 @@ impl DrawContext<'_> {
         //TODO: Cache the path and regenerate if the bounds change
         let mut path = Path::new();
 
-        if width == height
-            && corner_bottom_left_radius == width / 2.0
-            && corner_bottom_right_radius == width / 2.0
-            && corner_top_left_radius == height / 2.0
-            && corner_top_right_radius == height / 2.0
-        {
-            path.add_circle((width / 2.0, bounds.h / 2.0), width / 2.0, PathDirection::CW);
+        if rr.is_oval() {
+            path.add_oval(Rect::from_wh(width, height), None);
         } else {
-            let top_right = rr.radii(Corner::UpperRight).x;
-
-            if top_right > 0.0 {
+            // Each elliptical corner is computed as a circle with the horizontal radius and then scaled vertically
+            // by the ratio of the vertical radius to the horizontal radius.
+            let top_right = rr.radii(Corner::UpperRight);
+            if top_right.x > 0.0 && top_right.y > 0.0 {
+                let k = top_right.y / top_right.x;
                 let (a, b, c, d, l, p, radius) = compute_smooth_corner(
-                    top_right,
+                    top_right.x,
                     corner_top_right_smoothing,
-                    bounds.width(),
-                    bounds.height(),
+                    width,
+                    height / k,
                 );
 
                 path.move_to((f32::max(width / 2.0, width - p), 0.0));
@@ -535,22 +674,22 @@ impl DrawContext<'_> {
                     path.cubic_to(
                         (width - (p - a), 0.0),
                         (width - (p - a - b), 0.0),
-                        (width - (p - a - b - c), d),
+                        (width - (p - a - b - c), d * k),
                     )
                     .r_arc_to_rotated(
-                        (radius, radius),
+                        (radius, radius * k),
                         0.0,
                         ArcSize::Small,
                         PathDirection::CW,
-                        (l, l),
+                        (l, l * k),
                     )
                     .cubic_to(
-                        (width, p - a - b),
-                        (width, p - a),
-                        (width, f32::min(height / 2.0, p)),
+                        (width, (p - a - b) * k),
+                        (width, (p - a) * k),
+                        (width, f32::min(height / 2.0, p * k)),
                     );
                 } else {
-                    path.line_to((width, f32::min(height / 2.0, p)));
+                    path.line_to((width, f32::min(height / 2.0, p * k)));
                 }
             } else {
                 path.move_to((width / 2.0, 0.0))
@@ -558,28 +697,29 @@ impl DrawContext<'_> {
                     .line_to((width, height / 2.0));
             }
 
-            let bottom_right = rr.radii(Corner::LowerRight).x;
-            if bottom_right > 0.0 {
+            let bottom_right = rr.radii(Corner::LowerRight);
+            if bottom_right.x > 0.0 && bottom_right.y > 0.0 {
+                let k = bottom_right.y / bottom_right.x;
                 let (a, b, c, d, l, p, radius) = compute_smooth_corner(
-                    bottom_right,
+                    bottom_right.x,
                     corner_bottom_right_smoothing,
                     width,
-                    height,
+                    height / k,
                 );
 
-                path.line_to((width, f32::max(height / 2.0, height - p)));
+                path.line_to((width, f32::max(height / 2.0, height - p * k)));
                 if corner_bottom_right_shape == CornerShape::Round {
                     path.cubic_to(
-                        (width, height - (p - a)),
-                        (width, height - (p - a - b)),
-                        (width - d, height - (p - a - b - c)),
+                        (width, height - (p - a) * k),
+                        (width, height - (p - a - b) * k),
+                        (width - d, height - (p - a - b - c) * k),
                     )
                     .r_arc_to_rotated(
-                        (radius, radius),
+                        (radius, radius * k),
                         0.0,
                         ArcSize::Small,
                         PathDirection::CW,
-                        (-l, l),
+                        (-l, l * k),
                     )
                     .cubic_to(
                         (width - (p - a - b), height),
@@ -593,53 +733,67 @@ impl DrawContext<'_> {
                 path.line_to((width, height)).line_to((width / 2.0, height));
             }
 
-            let bottom_left = rr.radii(Corner::LowerLeft).x;
-            if bottom_left > 0.0 {
-                let (a, b, c, d, l, p, radius) =
-                    compute_smooth_corner(bottom_left, corner_bottom_left_smoothing, width, height);
+            let bottom_left = rr.radii(Corner::LowerLeft);
+            if bottom_left.x > 0.0 && bottom_left.y > 0.0 {
+                let k = bottom_left.y / bottom_left.x;
+                let (a, b, c, d, l, p, radius) = compute_smooth_corner(
+                    bottom_left.x,
+                    corner_bottom_left_smoothing,
+                    width,
+                    height / k,
+                );
 
                 path.line_to((f32::min(width / 2.0, p), height));
                 if corner_bottom_left_shape == CornerShape::Round {
                     path.cubic_to(
                         (p - a, height),
                         (p - a - b, height),
-                        (p - a - b - c, height - d),
+                        (p - a - b - c, height - d * k),
                     )
                     .r_arc_to_rotated(
-                        (radius, radius),
+                        (radius, radius * k),
                         0.0,
                         ArcSize::Small,
                         PathDirection::CW,
-                        (-l, -l),
+                        (-l, -l * k),
                     )
                     .cubic_to(
-                        (0.0, height - (p - a - b)),
-                        (0.0, height - (p - a)),
-                        (0.0, f32::max(height / 2.0, height - p)),
+                        (0.0, height - (p - a - b) * k),
+                        (0.0, height - (p - a) * k),
+                        (0.0, f32::max(height / 2.0, height - p * k)),
                     );
                 } else {
-                    path.line_to((0.0, f32::max(height / 2.0, height - p)));
+                    path.line_to((0.0, f32::max(height / 2.0, height - p * k)));
                 }
             } else {
                 path.line_to((0.0, height)).line_to((0.0, height / 2.0));
             }
 
-            let top_left = rr.radii(Corner::UpperLeft).x;
-            if top_left > 0.0 {
+            let top_left = rr.radii(Corner::UpperLeft);
+            if top_left.x > 0.0 && top_left.y > 0.0 {
+                let k = top_left.y / top_left.x;
                 let (a, b, c, d, l, p, radius) =
-                    compute_smooth_corner(top_left, corner_top_left_smoothing, width, height);
+                    compute_smooth_corner(top_left.x, corner_top_left_smoothing, width, height / k);
 
-                path.line_to((0.0, f32::min(height / 2.0, p)));
+                path.line_to((0.0, f32::min(height / 2.0, p * k)));
                 if corner_top_left_shape == CornerShape::Round {
-                    path.cubic_to((0.0, p - a), (0.0, p - a - b), (d, p - a - b - c))
-                        .r_arc_to_rotated(
-                            (radius, radius),
-                            0.0,
-                            ArcSize::Small,
-                            PathDirection::CW,
-                            (l, -l),
-                        )
-                        .cubic_to((p - a - b, 0.0), (p - a, 0.0), (f32::min(width / 2.0, p), 0.0));
+                    path.cubic_to(
+                        (0.0, (p - a) * k),
+                        (0.0, (p - a - b) * k),
+                        (d, (p - a - b - c) * k),
+                    )
+                    .r_arc_to_rotated(
+                        (radius, radius * k),
+                        0.0,
+                        ArcSize::Small,
+                        PathDirection::CW,
+                        (l, -l * k),
+                    )
+                    .cubic_to(
+                        (p - a - b, 0.0),
+                        (p - a, 0.0),
+                        (f32::min(width / 2.0, p), 0.0),
+                    );
                 } else {
                     path.line_to((f32::min(width / 2.0, p), 0.0));
                 }
@@ -657,7 +811,7 @@ impl DrawContext<'_> {
 
     /// Draw background color or background image (including gradients) for the current view.
     pub fn draw_background(&mut self, canvas: &Canvas) {
-        let border_width = self.border_width();
+        let border_width = self.border_widths().into_iter().fold(f32::INFINITY, f32::min);
         let path = self.build_path(self.bounds(), (-border_width / 2.0, -border_width / 2.0));
         let background_color = self.background_color();
         if background_color.a() != 0 {
@@ -675,49 +829,176 @@ impl DrawContext<'_> {
         self.draw_background_images(canvas, &path);
     }
 
+    /// Returns the widths of the top, right, bottom and left borders of the current view in physical pixels.
+    pub fn border_widths(&self) -> [f32; 4] {
+        [
+            self.border_top_width(),
+            self.border_right_width(),
+            self.border_bottom_width(),
+            self.border_left_width(),
+        ]
+    }
+
     /// Draw the border of the current view.
     pub fn draw_border(&mut self, canvas: &Canvas) {
-        let border_color = self.border_color();
-        let border_width = self.border_width();
-        let border_style = self.border_style();
+        let bounds = self.bounds();
+        let widths = self.border_widths();
+        let styles = [
+            self.border_top_style(),
+            self.border_right_style(),
+            self.border_bottom_style(),
+            self.border_left_style(),
+        ];
+        let colors = [
+            self.style.border_top_color.get(self.current),
+            self.style.border_right_color.get(self.current),
+            self.style.border_bottom_color.get(self.current),
+            self.style.border_left_color.get(self.current),
+        ];
+
+        // A border which is the same on every side is stroked as a single path, so that dashes run around the
+        // corners.
+        if widths.iter().all(|width| *width == widths[0])
+            && styles.iter().all(|style| *style == styles[0])
+            && colors.iter().all(|color| *color == colors[0])
+        {
+            let border_width = widths[0];
+            if let Some(paint) = self.border_paint(border_width, styles[0], colors[0], bounds) {
+                let path = self.build_path(bounds, (-border_width / 2.0, -border_width / 2.0));
+                canvas.draw_path(&path, &paint);
+            }
 
-        if border_width > 0.0 && border_color.a() > 0 && border_style != BorderStyleKeyword::None {
-            let bounds = self.bounds();
-            let path = self.build_path(bounds, (-border_width / 2.0, -border_width / 2.0));
-            let mut paint = Paint::default();
-            paint.set_style(PaintStyle::Stroke);
-            paint.set_color(border_color);
-            paint.set_stroke_width(border_width);
-            match border_style {
-                BorderStyleKeyword::Dashed => {
-                    paint.set_path_effect(PathEffect::dash(
-                        &[border_width * 2.0, border_width],
-                        0.0,
-                    ));
-                }
+            return;
+        }
 
-                BorderStyleKeyword::Dotted => {
-                    paint.set_path_effect(PathEffect::dash(&[0.0, border_width * 2.0], 0.0));
-                    paint.set_stroke_cap(skia_safe::PaintCap::Round);
-                }
+        // Otherwise each side is drawn within the part of the border between the diagonals from the outer to the
+        // inner corners at each end of it, so that sides of different widths meet along the corner radii.
+        let outer = self.build_path(bounds, (0.0, 0.0));
+        let inner = self.inset_rrect(bounds, widths);
+        let center = self.inset_rrect(bounds, widths.map(|width| width / 2.0));
+
+        let (left, top, right, bottom) =
+            (bounds.left(), bounds.top(), bounds.right(), bounds.bottom());
+        let inner_rect = inner.rect();
+        let middle = Point::new(bounds.center().0, bounds.center().1);
+        let corners = [
+            (Point::new(left, top), Point::new(inner_rect.left, inner_rect.top)),
+            (Point::new(right, top), Point::new(inner_rect.right, inner_rect.top)),
+            (Point::new(right, bottom), Point::new(inner_rect.right, inner_rect.bottom)),
+            (Point::new(left, bottom), Point::new(inner_rect.left, inner_rect.bottom)),
+        ];
+
+        canvas.save();
+        canvas.clip_path(&outer, ClipOp::Intersect, true);
+        canvas.clip_rrect(&inner, ClipOp::Difference, true);
+        for side in 0..4 {
+            let Some(mut paint) =
+                self.border_paint(widths[side], styles[side], colors[side], bounds)
+            else {
+                continue;
+            };
 
-                _ => {}
+            let (start, inner_start) = corners[side];
+            let (end, inner_end) = corners[(side + 1) % 4];
+            let mut wedge = Path::new();
+            wedge
+                .move_to(start)
+                .line_to(end)
+                .line_to(inner_end)
+                .line_to(middle)
+                .line_to(inner_start)
+                .close();
+
+            canvas.save();
+            canvas.clip_path(&wedge, ClipOp::Intersect, true);
+            if styles[side] == BorderStyleKeyword::Solid {
+                paint.set_style(PaintStyle::Fill);
+                canvas.draw_path(&outer, &paint);
+            } else {
+                canvas.draw_rrect(&center, &paint);
             }
+            canvas.restore();
+        }
+        canvas.restore();
+    }
 
-            paint.set_anti_alias(true);
-            canvas.draw_path(&path, &paint);
+    // Returns the paint which strokes a border of the given width, style and color along its center, or `None` if
+    // the border isn't visible.
+    fn border_paint(
+        &self,
+        border_width: f32,
+        border_style: BorderStyleKeyword,
+        border_color: Option<&ColorOrGradient>,
+        bounds: BoundingBox,
+    ) -> Option<Paint> {
+        if border_width <= 0.0 || border_style == BorderStyleKeyword::None {
+            return None;
+        }
+
+        let mut paint = Paint::default();
+        if !self.set_color_or_gradient(&mut paint, border_color?, bounds) {
+            return None;
         }
+
+        paint.set_style(PaintStyle::Stroke);
+        paint.set_stroke_width(border_width);
+        set_line_style(&mut paint, border_style, border_width);
+        paint.set_anti_alias(true);
+        Some(paint)
+    }
+
+    // Returns the rounded rectangle of the bounds inset by the given widths of the top, right, bottom and left sides,
+    // with the radius of each corner reduced by the widths of the sides which meet at it.
+    fn inset_rrect(&self, bounds: BoundingBox, [top, right, bottom, left]: [f32; 4]) -> RRect {
+        let outer = RRect::new_rect_radii(
+            Rect::from(bounds),
+            &[
+                self.corner_top_left_radius(),
+                self.corner_top_right_radius(),
+                self.corner_bottom_right_radius(),
+                self.corner_bottom_left_radius(),
+            ]
+            .map(|(x, y)| Point::new(x, y)),
+        );
+
+        let radius = |corner: Corner, x: f32, y: f32| {
+            let radius = outer.radii(corner);
+            Point::new((radius.x - x).max(0.0), (radius.y - y).max(0.0))
+        };
+
+        RRect::new_rect_radii(
+            Rect::new(
+                bounds.left() + left,
+                bounds.top() + top,
+                bounds.right() - right,
+                bounds.bottom() - bottom,
+            ),
+            &[
+                radius(Corner::UpperLeft, left, top),
+                radius(Corner::UpperRight, right, top),
+                radius(Corner::LowerRight, right, bottom),
+                radius(Corner::LowerLeft, left, bottom),
+            ],
+        )
     }
 
     /// Draw the outline of the current view.
     pub fn draw_outline(&mut self, canvas: &Canvas) {
         let outline_width = self.outline_width();
-        let outline_color = self.outline_color();
+        let outline_style = self.outline_style();
+
+        if outline_width > 0.0 && outline_style != BorderStyleKeyword::None {
+            let Some(outline_color) = self.style.outline_color.get(self.current) else {
+                return;
+            };
 
-        if outline_width > 0.0 && outline_color.a() != 0 {
             let outline_offset = self.outline_offset();
 
             let bounds = self.bounds();
+            let mut outline_paint = Paint::default();
+            if !self.set_color_or_gradient(&mut outline_paint, outline_color, bounds) {
+                return;
+            }
 
             let half_outline_width = outline_width / 2.0;
             let outline_path = self.build_path(
@@ -725,18 +1006,99 @@ impl DrawContext<'_> {
                 (half_outline_width + outline_offset, half_outline_width + outline_offset),
             );
 
-            let mut outline_paint = Paint::default();
-            outline_paint.set_color(outline_color);
             outline_paint.set_stroke_width(outline_width);
             outline_paint.set_style(PaintStyle::Stroke);
+            set_line_style(&mut outline_paint, outline_style, outline_width);
             outline_paint.set_anti_alias(true);
             canvas.draw_path(&outline_path, &outline_paint);
         }
     }
 
-    /// Draw shadows for the current view.
+    /// Draws the focus ring around the current view, moved outwards by the given distance in physical pixels while it
+    /// scales in.
+    pub(crate) fn draw_focus_ring(&mut self, canvas: &Canvas, expand: f32) {
+        let width = self.focus_ring_width();
+        if width <= 0.0 {
+            return;
+        }
+
+        let outset = width / 2.0 + self.focus_ring_offset() + expand;
+        let path = self.build_path(self.bounds(), (outset, outset));
+
+        let mut paint = Paint::default();
+        paint.set_color(self.focus_ring_color());
+        paint.set_stroke_width(width);
+        paint.set_style(PaintStyle::Stroke);
+        paint.set_anti_alias(true);
+        canvas.draw_path(&path, &paint);
+    }
+
+    /// Draws the ripples of presses on the current view, clipped to its shape.
+    pub(crate) fn draw_ripples(&mut self, canvas: &Canvas) {
+        let Some(ripples) = self.style.ripples.get(self.current) else {
+            return;
+        };
+
+        let bounds = self.bounds();
+        let color = self.style.ripple_color.get(self.current).map_or_else(
+            || {
+                let font_color = self.font_color();
+                Color::rgba(font_color.r(), font_color.g(), font_color.b(), font_color.a() / 5)
+            },
+            |color| self.resolve_color(*color),
+        );
+        let duration = self.style.ripple_duration(self.current);
+        let time = Instant::now();
+
+        canvas.save();
+        canvas.clip_path(&self.build_path(bounds, (0.0, 0.0)), ClipOp::Intersect, true);
+        for ripple in ripples {
+            // The ripple expands to cover the view from wherever it was pressed.
+            let (x, y) = ripple.center;
+            let max_radius = [bounds.left(), bounds.right()]
+                .into_iter()
+                .flat_map(|corner_x| {
+                    [bounds.top(), bounds.bottom()]
+                        .map(|corner_y| (corner_x - x).hypot(corner_y - y))
+                })
+                .fold(0.0, f32::max);
+
+            let (radius, opacity) = ripple.frame(time, duration);
+            let mut paint = Paint::default();
+            paint.set_color(color);
+            paint.set_alpha_f(paint.alpha_f() * opacity);
+            paint.set_anti_alias(true);
+            canvas.draw_circle((x, y), radius * max_radius, &paint);
+        }
+        canvas.restore();
+    }
+
+    /// Draws the thumbs of the scrollbars of the current view if it scrolls its overflow, in the font color of the view
+    /// at reduced opacity.
+    pub(crate) fn draw_scrollbars(&mut self, canvas: &Canvas) {
+        let scrollbars = scrollbars(self.style, self.cache, self.tree, self.current);
+        if scrollbars.is_empty() {
+            return;
+        }
+
+        let font_color = self.font_color();
+        let mut paint = Paint::default();
+        paint.set_color(Color::rgba(
+            font_color.r(),
+            font_color.g(),
+            font_color.b(),
+            (font_color.a() as f32 * 0.4) as u8,
+        ));
+        paint.set_anti_alias(true);
+        for scrollbar in scrollbars {
+            let radius = scrollbar.track.w.min(scrollbar.track.h) / 2.0;
+            canvas.draw_round_rect(Rect::from(scrollbar.thumb), radius, radius, &paint);
+        }
+    }
+
+    /// Draw shadows for the current view, which is done after its background so that inset shadows are drawn over it.
     pub fn draw_shadows(&mut self, canvas: &Canvas) {
-        if let Some(shadows) = self.shadows() {
+        if let Some(shadows) = self.style.shadows(self.current) {
             if shadows.is_empty() {
                 return;
             }
@@ -746,7 +1108,8 @@ impl DrawContext<'_> {
             let path = self.build_path(bounds, (0.0, 0.0));
 
             for shadow in shadows.iter().rev() {
-                let shadow_color = shadow.color.unwrap_or_default();
+                let shadow_color =
+                    shadow.color.map(|color| self.resolve_color(color)).unwrap_or_default();
 
                 let shadow_x_offset = shadow.x_offset.to_px().unwrap_or(0.0) * self.scale_factor();
                 let shadow_y_offset = shadow.y_offset.to_px().unwrap_or(0.0) * self.scale_factor();
@@ -755,7 +1118,8 @@ impl DrawContext<'_> {
                         * self.scale_factor();
 
                 let blur_radius =
-                    shadow.blur_radius.as_ref().and_then(|br| br.to_px()).unwrap_or(0.0);
+                    shadow.blur_radius.as_ref().and_then(|br| br.to_px()).unwrap_or(0.0)
+                        * self.scale_factor();
 
                 if shadow_color.a() == 0
                     || (shadow_x_offset == 0.0
@@ -786,8 +1150,16 @@ impl DrawContext<'_> {
 
                 shadow_path.offset((shadow_x_offset, shadow_y_offset));
 
+                // An inset shadow is cast by everything outside of the offset path, rather than just the part of it
+                // within the view, so that it isn't lighter along the edges of the view once it's blurred.
                 if shadow.inset {
-                    shadow_path = path.op(&shadow_path, skia_safe::PathOp::Difference).unwrap();
+                    let extent = blur_radius * 2.0
+                        + spread_radius.abs()
+                        + shadow_x_offset.abs()
+                        + shadow_y_offset.abs();
+                    let outside =
+                        Path::rect(Rect::from(bounds).with_outset((extent, extent)), None);
+                    shadow_path = outside.op(&shadow_path, skia_safe::PathOp::Difference).unwrap();
                 }
 
                 canvas.save();
@@ -802,320 +1174,420 @@ impl DrawContext<'_> {
         }
     }
 
-    /// Draw background images (including gradients) for the current view.
-    fn draw_background_images(&mut self, canvas: &Canvas, path: &Path) {
-        let bounds = self.bounds();
-
-        if let Some(images) = self.background_images() {
-            let image_sizes = self.background_size();
+    /// Sets the paint to the color, or to a shader which paints the gradient over the given bounds. Returns false if
+    /// the paint would be invisible.
+    fn set_color_or_gradient(
+        &self,
+        paint: &mut Paint,
+        color_or_gradient: &ColorOrGradient,
+        bounds: BoundingBox,
+    ) -> bool {
+        match color_or_gradient {
+            ColorOrGradient::Color(color) => {
+                let color = self.resolve_color(*color);
+                paint.set_color(color);
+                color.a() > 0
+            }
 
-            for (index, image) in images.iter().enumerate() {
-                match image {
-                    ImageOrGradient::Gradient(gradient) => match gradient {
-                        Gradient::Linear(linear_gradient) => {
-                            let (start, end, parent_length) = match linear_gradient.direction {
-                                LineDirection::Horizontal(horizontal_keyword) => {
-                                    match horizontal_keyword {
-                                        HorizontalPositionKeyword::Left => (
-                                            bounds.center_right(),
-                                            bounds.center_left(),
-                                            bounds.width(),
-                                        ),
-
-                                        HorizontalPositionKeyword::Right => (
-                                            bounds.center_left(),
-                                            bounds.center_right(),
-                                            bounds.width(),
-                                        ),
-                                    }
-                                }
+            ColorOrGradient::Gradient(gradient) => {
+                let shader = self.gradient_shader(gradient, bounds);
+                let visible = shader.is_some();
+                paint.set_shader(shader);
+                visible
+            }
+        }
+    }
 
-                                LineDirection::Vertical(vertical_keyword) => match vertical_keyword
-                                {
-                                    VerticalPositionKeyword::Top => (
-                                        bounds.center_bottom(),
-                                        bounds.center_top(),
-                                        bounds.height(),
-                                    ),
-
-                                    VerticalPositionKeyword::Bottom => (
-                                        bounds.center_top(),
-                                        bounds.center_bottom(),
-                                        bounds.height(),
-                                    ),
-                                },
-
-                                LineDirection::Corner { horizontal, vertical } => {
-                                    match (horizontal, vertical) {
-                                        (
-                                            HorizontalPositionKeyword::Right,
-                                            VerticalPositionKeyword::Bottom,
-                                        ) => (
-                                            bounds.top_left(),
-                                            bounds.bottom_right(),
-                                            bounds.diagonal(),
-                                        ),
-
-                                        (
-                                            HorizontalPositionKeyword::Right,
-                                            VerticalPositionKeyword::Top,
-                                        ) => (
-                                            bounds.bottom_left(),
-                                            bounds.top_right(),
-                                            bounds.diagonal(),
-                                        ),
-
-                                        _ => (bounds.top_left(), bounds.bottom_right(), 0.0),
-                                    }
-                                }
+    /// Returns a shader which paints the gradient over the given bounds.
+    fn gradient_shader(&self, gradient: &Gradient, bounds: BoundingBox) -> Option<Shader> {
+        match gradient {
+            Gradient::Linear(linear_gradient) => {
+                let (start, end, parent_length) = match linear_gradient.direction {
+                    LineDirection::Horizontal(horizontal_keyword) => match horizontal_keyword {
+                        HorizontalPositionKeyword::Left => {
+                            (bounds.center_right(), bounds.center_left(), bounds.width())
+                        }
 
-                                LineDirection::Angle(angle) => {
-                                    let angle_rad = angle.to_radians();
-                                    let start_x =
-                                        bounds.x + ((angle_rad.sin() * bounds.w) - bounds.w) / -2.0;
-                                    let end_x =
-                                        bounds.x + ((angle_rad.sin() * bounds.w) + bounds.w) / 2.0;
-                                    let start_y =
-                                        bounds.y + ((angle_rad.cos() * bounds.h) + bounds.h) / 2.0;
-                                    let end_y =
-                                        bounds.y + ((angle_rad.cos() * bounds.h) - bounds.h) / -2.0;
+                        HorizontalPositionKeyword::Right => {
+                            (bounds.center_left(), bounds.center_right(), bounds.width())
+                        }
+                    },
 
-                                    let x = (end_x - start_x).abs();
-                                    let y = (end_y - start_y).abs();
+                    LineDirection::Vertical(vertical_keyword) => match vertical_keyword {
+                        VerticalPositionKeyword::Top => {
+                            (bounds.center_bottom(), bounds.center_top(), bounds.height())
+                        }
 
-                                    let dist = (x * x + y * y).sqrt();
+                        VerticalPositionKeyword::Bottom => {
+                            (bounds.center_top(), bounds.center_bottom(), bounds.height())
+                        }
+                    },
 
-                                    ((start_x, start_y), (end_x, end_y), dist)
-                                }
-                            };
-
-                            let num_stops = linear_gradient.stops.len();
-
-                            let mut stops = linear_gradient
-                                .stops
-                                .iter()
-                                .enumerate()
-                                .map(|(index, stop)| {
-                                    let pos = if let Some(pos) = &stop.position {
-                                        pos.to_pixels(parent_length, self.scale_factor())
-                                            / parent_length
-                                    } else {
-                                        index as f32 / (num_stops - 1) as f32
-                                    };
-                                    (pos, skia_safe::Color::from(stop.color))
-                                })
-                                .collect::<Vec<_>>();
-
-                            // Insert a stop at the front if the first stop is not at 0.
-                            if let Some(first) = stops.first() {
-                                if first.0 != 0.0 {
-                                    stops.insert(0, (0.0, first.1));
-                                }
+                    LineDirection::Corner { horizontal, vertical } => {
+                        match (horizontal, vertical) {
+                            (HorizontalPositionKeyword::Right, VerticalPositionKeyword::Bottom) => {
+                                (bounds.top_left(), bounds.bottom_right(), bounds.diagonal())
                             }
 
-                            // Insert a stop at the end if the last stop is not at 1.0.
-                            if let Some(last) = stops.last() {
-                                if last.0 != 1.0 {
-                                    stops.push((1.0, last.1));
-                                }
+                            (HorizontalPositionKeyword::Right, VerticalPositionKeyword::Top) => {
+                                (bounds.bottom_left(), bounds.top_right(), bounds.diagonal())
                             }
 
-                            let (offsets, colors): (Vec<f32>, Vec<skia_safe::Color>) =
-                                stops.into_iter().unzip();
+                            _ => (bounds.top_left(), bounds.bottom_right(), 0.0),
+                        }
+                    }
+
+                    LineDirection::Angle(angle) => {
+                        let angle_rad = angle.to_radians();
+                        let start_x = bounds.x + ((angle_rad.sin() * bounds.w) - bounds.w) / -2.0;
+                        let end_x = bounds.x + ((angle_rad.sin() * bounds.w) + bounds.w) / 2.0;
+                        let start_y = bounds.y + ((angle_rad.cos() * bounds.h) + bounds.h) / 2.0;
+                        let end_y = bounds.y + ((angle_rad.cos() * bounds.h) - bounds.h) / -2.0;
+
+                        let x = (end_x - start_x).abs();
+                        let y = (end_y - start_y).abs();
 
-                            let shader = Shader::linear_gradient(
-                                (Point::from(start), Point::from(end)),
-                                GradientShaderColors::Colors(&colors[..]),
-                                Some(&offsets[..]),
-                                TileMode::Clamp,
-                                None,
-                                None,
-                            );
+                        let dist = (x * x + y * y).sqrt();
 
-                            let mut paint = Paint::default();
-                            paint.set_shader(shader);
+                        ((start_x, start_y), (end_x, end_y), dist)
+                    }
+                };
+
+                let num_stops = linear_gradient.stops.len();
+
+                let mut stops = linear_gradient
+                    .stops
+                    .iter()
+                    .enumerate()
+                    .map(|(index, stop)| {
+                        let pos = if let Some(pos) = &stop.position {
+                            pos.to_pixels(parent_length, self.scale_factor()) / parent_length
+                        } else {
+                            index as f32 / (num_stops - 1) as f32
+                        };
+                        (pos, skia_safe::Color::from(self.resolve_color(stop.color)))
+                    })
+                    .collect::<Vec<_>>();
+
+                // Insert a stop at the front if the first stop is not at 0.
+                if let Some(first) = stops.first() {
+                    if first.0 != 0.0 {
+                        stops.insert(0, (0.0, first.1));
+                    }
+                }
+
+                // Insert a stop at the end if the last stop is not at 1.0.
+                if let Some(last) = stops.last() {
+                    if last.0 != 1.0 {
+                        stops.push((1.0, last.1));
+                    }
+                }
+
+                let (offsets, colors): (Vec<f32>, Vec<skia_safe::Color>) =
+                    stops.into_iter().unzip();
+
+                Shader::linear_gradient(
+                    (Point::from(start), Point::from(end)),
+                    GradientShaderColors::Colors(&colors[..]),
+                    Some(&offsets[..]),
+                    TileMode::Clamp,
+                    None,
+                    None,
+                )
+            }
+
+            Gradient::Radial(radial_gradient) => {
+                let num_stops = radial_gradient.stops.len();
+
+                let mut stops = radial_gradient
+                    .stops
+                    .iter()
+                    .enumerate()
+                    .map(|(index, stop)| {
+                        let pos = if let Some(pos) = &stop.position {
+                            pos.to_pixels(bounds.width(), self.scale_factor()) / bounds.width()
+                        } else {
+                            index as f32 / (num_stops - 1) as f32
+                        };
+
+                        (pos, skia_safe::Color::from(self.resolve_color(stop.color)))
+                    })
+                    .collect::<Vec<_>>();
+
+                // Insert a stop at the front if the first stop is not at 0.
+                if let Some(first) = stops.first() {
+                    if first.0 != 0.0 {
+                        stops.insert(0, (0.0, first.1));
+                    }
+                }
+
+                // Insert a stop at the end if the last stop is not at 1.0.
+                if let Some(last) = stops.last() {
+                    if last.0 != 1.0 {
+                        stops.push((1.0, last.1));
+                    }
+                }
 
-                            canvas.draw_path(path, &paint);
+                let (offsets, colors): (Vec<f32>, Vec<skia_safe::Color>) =
+                    stops.into_iter().unzip();
+
+                Shader::radial_gradient(
+                    Point::from(bounds.center()),
+                    bounds.w.max(bounds.h),
+                    GradientShaderColors::Colors(&colors[..]),
+                    Some(&offsets[..]),
+                    TileMode::Clamp,
+                    None,
+                    None,
+                )
+            }
+
+            Gradient::Conic(conic_gradient) => {
+                let scale = self.scale_factor();
+                let center = Point::new(
+                    bounds.x
+                        + conic_gradient
+                            .position
+                            .x
+                            .to_length_or_percentage()
+                            .to_pixels(bounds.w, scale),
+                    bounds.y
+                        + conic_gradient
+                            .position
+                            .y
+                            .to_length_or_percentage()
+                            .to_pixels(bounds.h, scale),
+                );
+
+                let num_stops = conic_gradient.stops.len();
+
+                // Stop positions are in turns, starting from the from-angle.
+                let mut stops = conic_gradient
+                    .stops
+                    .iter()
+                    .enumerate()
+                    .map(|(index, stop)| {
+                        let pos = if let Some(pos) = &stop.position {
+                            pos.to_turns()
+                        } else {
+                            index as f32 / (num_stops - 1) as f32
+                        };
+
+                        (pos, skia_safe::Color::from(self.resolve_color(stop.color)))
+                    })
+                    .collect::<Vec<_>>();
+
+                let (tile_mode, angles) = if conic_gradient.repeating {
+                    // The stops repeat every first to last stop, so skia is given the offsets within that range.
+                    let first = stops.first().map(|stop| stop.0).unwrap_or_default();
+                    let last = stops.last().map(|stop| stop.0).unwrap_or_default();
+                    if last <= first {
+                        return None;
+                    }
+
+                    for stop in stops.iter_mut() {
+                        stop.0 = (stop.0 - first) / (last - first);
+                    }
+
+                    (TileMode::Repeat, Some((first * 360.0, last * 360.0)))
+                } else {
+                    // Insert a stop at the front if the first stop is not at 0.
+                    if let Some(first) = stops.first() {
+                        if first.0 != 0.0 {
+                            stops.insert(0, (0.0, first.1));
                         }
+                    }
 
-                        Gradient::Radial(radial_gradient) => {
-                            let num_stops = radial_gradient.stops.len();
-
-                            let mut stops = radial_gradient
-                                .stops
-                                .iter()
-                                .enumerate()
-                                .map(|(index, stop)| {
-                                    let pos = if let Some(pos) = &stop.position {
-                                        pos.to_pixels(bounds.width(), self.scale_factor())
-                                            / bounds.width()
-                                    } else {
-                                        index as f32 / (num_stops - 1) as f32
-                                    };
-
-                                    (pos, skia_safe::Color::from(stop.color))
-                                })
-                                .collect::<Vec<_>>();
-
-                            // Insert a stop at the front if the first stop is not at 0.
-                            if let Some(first) = stops.first() {
-                                if first.0 != 0.0 {
-                                    stops.insert(0, (0.0, first.1));
-                                }
-                            }
+                    // Insert a stop at the end if the last stop is not at 1.0.
+                    if let Some(last) = stops.last() {
+                        if last.0 != 1.0 {
+                            stops.push((1.0, last.1));
+                        }
+                    }
 
-                            // Insert a stop at the end if the last stop is not at 1.0.
-                            if let Some(last) = stops.last() {
-                                if last.0 != 1.0 {
-                                    stops.push((1.0, last.1));
-                                }
-                            }
+                    (TileMode::Clamp, None)
+                };
+
+                let (offsets, colors): (Vec<f32>, Vec<skia_safe::Color>) =
+                    stops.into_iter().unzip();
+
+                // Skia sweeps clockwise from the positive x axis, whereas CSS starts at the top.
+                let matrix =
+                    Matrix::rotate_deg_pivot(conic_gradient.from.to_degrees() - 90.0, center);
+
+                Shader::sweep_gradient(
+                    center,
+                    GradientShaderColors::Colors(&colors[..]),
+                    Some(&offsets[..]),
+                    tile_mode,
+                    angles,
+                    None,
+                    Some(&matrix),
+                )
+            }
+
+            Gradient::None => None,
+        }
+    }
+
+    /// Draw background images (including gradients) for the current view.
+    ///
+    /// The first image is the top layer, so the images are drawn from last to first. Each image is drawn with the
+    /// background position, size and repeat at the same index, which are repeated if there are fewer of them than
+    /// images.
+    fn draw_background_images(&mut self, canvas: &Canvas, path: &Path) {
+        let bounds = self.bounds();
 
-                            let (offsets, colors): (Vec<f32>, Vec<skia_safe::Color>) =
-                                stops.into_iter().unzip();
-
-                            let shader = Shader::radial_gradient(
-                                Point::from(bounds.center()),
-                                bounds.w.max(bounds.h),
-                                GradientShaderColors::Colors(&colors[..]),
-                                Some(&offsets[..]),
-                                TileMode::Clamp,
-                                None,
-                                None,
-                            );
-
-                            let mut paint = Paint::default();
-                            paint.set_shader(shader);
-                            canvas.draw_path(path, &paint);
+        if let Some(images) = self.background_images() {
+            let positions = self.background_position();
+            let sizes = self.background_size();
+            let repeats = self.background_repeat();
+
+            for (index, image) in images.iter().enumerate().rev() {
+                let position = layer_value(&positions, index);
+                let size = layer_value(&sizes, index);
+                let repeat = layer_value(&repeats, index).copied().unwrap_or_default();
+
+                match image {
+                    ImageOrGradient::Gradient(gradient) => {
+                        let tile = background_tile(
+                            bounds,
+                            None,
+                            size,
+                            position,
+                            repeat,
+                            self.scale_factor(),
+                        );
+                        if tile.w <= 0.0 || tile.h <= 0.0 {
+                            continue;
                         }
 
-                        _ => {}
-                    },
+                        // Gradients have no intrinsic repetition, so each tile is drawn separately.
+                        let mut paint = Paint::default();
+                        canvas.save();
+                        canvas.clip_path(path, ClipOp::Intersect, true);
+                        for x in tile_range(bounds.x, bounds.w, tile.x, tile.w, repeat.x) {
+                            for y in tile_range(bounds.y, bounds.h, tile.y, tile.h, repeat.y) {
+                                let tile = BoundingBox { x, y, w: tile.w, h: tile.h };
+                                if let Some(shader) = self.gradient_shader(gradient, tile) {
+                                    paint.set_shader(shader);
+                                    canvas.draw_rect(Rect::from_xywh(x, y, tile.w, tile.h), &paint);
+                                }
+                            }
+                        }
+                        canvas.restore();
+                    }
 
                     ImageOrGradient::Image(image_name) => {
-                        if let Some(image_id) = self.resource_manager.image_ids.get(image_name) {
-                            if let Some(image) = self.resource_manager.images.get(image_id) {
-                                match &image.image {
-                                    ImageOrSvg::Image(image) => {
-                                        let image_width = image.width();
-                                        let image_height = image.height();
-                                        let (width, height) = if let Some(background_size) =
-                                            image_sizes.get(index)
+                        let Some(image) = self
+                            .resource_manager
+                            .image_ids
+                            .get(image_name)
+                            .and_then(|image_id| self.resource_manager.images.get(image_id))
+                        else {
+                            continue;
+                        };
+
+                        match &image.image {
+                            ImageOrSvg::Image(image) => {
+                                let image_size = (image.width() as f32, image.height() as f32);
+                                let tile = background_tile(
+                                    bounds,
+                                    Some(image_size),
+                                    size,
+                                    position,
+                                    repeat,
+                                    self.scale_factor(),
+                                );
+                                if tile.w <= 0.0 || tile.h <= 0.0 {
+                                    continue;
+                                }
+
+                                let image_rect = Rect::new(0.0, 0.0, image_size.0, image_size.1);
+                                let mut paint = Paint::default();
+                                paint.set_anti_alias(true);
+
+                                // Spaced images aren't evenly repeated, so each image is drawn separately.
+                                if repeat.x == BackgroundRepeatKeyword::Space
+                                    || repeat.y == BackgroundRepeatKeyword::Space
+                                {
+                                    canvas.save();
+                                    canvas.clip_path(path, ClipOp::Intersect, true);
+                                    for x in
+                                        tile_range(bounds.x, bounds.w, tile.x, tile.w, repeat.x)
+                                    {
+                                        for y in
+                                            tile_range(bounds.y, bounds.h, tile.y, tile.h, repeat.y)
                                         {
-                                            match background_size {
-                                                BackgroundSize::Explicit { width, height } => {
-                                                    let w = match width {
-                                                LengthPercentageOrAuto::LengthPercentage(
-                                                    length,
-                                                ) => {
-                                                    length.to_pixels(bounds.w, self.scale_factor())
-                                                }
-                                                LengthPercentageOrAuto::Auto => image_width as f32,
-                                            };
-
-                                                    let h = match height {
-                                                LengthPercentageOrAuto::LengthPercentage(
-                                                    length,
-                                                ) => {
-                                                    length.to_pixels(bounds.h, self.scale_factor())
-                                                }
-                                                LengthPercentageOrAuto::Auto => image_height as f32,
-                                            };
-
-                                                    (w, h)
-                                                }
-
-                                                BackgroundSize::Contain => {
-                                                    let image_ratio =
-                                                        image_width as f32 / image_height as f32;
-                                                    let container_ratio = bounds.w / bounds.h;
-
-                                                    let (w, h) = if image_ratio > container_ratio {
-                                                        (bounds.w, bounds.w / image_ratio)
-                                                    } else {
-                                                        (bounds.h * image_ratio, bounds.h)
-                                                    };
-
-                                                    (w, h)
-                                                }
-
-                                                BackgroundSize::Cover => {
-                                                    let image_ratio =
-                                                        image_width as f32 / image_height as f32;
-                                                    let container_ratio = bounds.w / bounds.h;
-
-                                                    let (w, h) = if image_ratio < container_ratio {
-                                                        (bounds.w, bounds.w / image_ratio)
-                                                    } else {
-                                                        (bounds.h * image_ratio, bounds.h)
-                                                    };
-
-                                                    (w, h)
-                                                }
-                                            }
-                                        } else {
-                                            (image_width as f32, image_height as f32)
-                                        };
-
-                                        let matrix = Matrix::rect_to_rect(
-                                            Rect::new(
-                                                0.0,
-                                                0.0,
-                                                image.width() as f32,
-                                                image.height() as f32,
-                                            ),
-                                            Rect::new(
-                                                bounds.left(),
-                                                bounds.top(),
-                                                bounds.left() + width,
-                                                bounds.top() + height,
-                                            ),
-                                            None,
-                                        );
-
-                                        let mut paint = Paint::default();
-                                        paint.set_anti_alias(true);
-                                        paint.set_shader(image.to_shader(
-                                            (TileMode::Repeat, TileMode::Repeat),
-                                            SamplingOptions::default(),
-                                            &matrix,
-                                        ));
-
-                                        canvas.draw_path(path, &paint);
+                                            canvas.draw_image_rect_with_sampling_options(
+                                                image,
+                                                Some((&image_rect, SrcRectConstraint::Fast)),
+                                                Rect::from_xywh(x, y, tile.w, tile.h),
+                                                SamplingOptions::default(),
+                                                &paint,
+                                            );
+                                        }
                                     }
+                                    canvas.restore();
+                                    continue;
+                                }
 
-                                    ImageOrSvg::Svg(svg) => {
-                                        canvas.save_layer(&SaveLayerRec::default());
-                                        canvas.translate((bounds.x, bounds.y));
-                                        let (scale_x, scale_y) = (
-                                            bounds.width() / svg.inner().fContainerSize.fWidth,
-                                            bounds.height() / svg.inner().fContainerSize.fHeight,
-                                        );
-
-                                        if scale_x.is_finite() && scale_y.is_finite() {
-                                            canvas.scale((scale_x, scale_y));
-                                        } else {
-                                            svg.clone().set_container_size((
-                                                bounds.width(),
-                                                bounds.height(),
-                                            ));
-                                        }
+                                let matrix = Matrix::rect_to_rect(
+                                    image_rect,
+                                    Rect::from_xywh(tile.x, tile.y, tile.w, tile.h),
+                                    None,
+                                );
 
-                                        svg.render(canvas);
+                                paint.set_shader(image.to_shader(
+                                    (repeat.x.into(), repeat.y.into()),
+                                    SamplingOptions::default(),
+                                    &matrix,
+                                ));
 
-                                        if let Some(color) =
-                                            self.style.fill.get(self.current).copied()
-                                        {
-                                            let mut paint = Paint::default();
+                                canvas.draw_path(path, &paint);
+                            }
 
-                                            paint.set_anti_alias(true);
-                                            paint.set_blend_mode(skia_safe::BlendMode::SrcIn);
-                                            paint.set_color(color);
-                                            canvas.draw_paint(&paint);
-                                        }
-                                        canvas.restore();
-                                    }
+                            // Svgs are scaled to the background unless they are given a size.
+                            ImageOrSvg::Svg(svg) => {
+                                let tile = background_tile(
+                                    bounds,
+                                    None,
+                                    size,
+                                    position,
+                                    repeat,
+                                    self.scale_factor(),
+                                );
+
+                                canvas.save_layer(&SaveLayerRec::default());
+                                canvas.clip_path(path, ClipOp::Intersect, true);
+                                canvas.translate((tile.x, tile.y));
+                                let (scale_x, scale_y) = (
+                                    tile.w / svg.inner().fContainerSize.fWidth,
+                                    tile.h / svg.inner().fContainerSize.fHeight,
+                                );
+
+                                if scale_x.is_finite() && scale_y.is_finite() {
+                                    canvas.scale((scale_x, scale_y));
+                                } else {
+                                    svg.clone().set_container_size((tile.w, tile.h));
+                                }
+
+                                svg.render(canvas);
+
+                                if let Some(color) = self
+                                    .style
+                                    .fill
+                                    .get(self.current)
+                                    .map(|color| self.resolve_color(*color))
+                                {
+                                    let mut paint = Paint::default();
+
+                                    paint.set_anti_alias(true);
+                                    paint.set_blend_mode(skia_safe::BlendMode::SrcIn);
+                                    paint.set_color(color);
+                                    canvas.draw_paint(&paint);
                                 }
+                                canvas.restore();
                             }
                         }
                     }
@@ -1126,43 +1598,81 @@ impl DrawContext<'_> {
 
     /// Draw any text for the current view.
     pub fn draw_text(&mut self, canvas: &Canvas) {
-        if let Some(paragraph) = self.text_context.text_paragraphs.get(self.current) {
+        if let Some(text) = self.text_context.text_layout(self.current) {
             let bounds = self.bounds();
 
-            let alignment = self.alignment();
-
-            let (mut top, _) = match alignment {
-                Alignment::TopLeft => (0.0, 0.0),
-                Alignment::TopCenter => (0.0, 0.5),
-                Alignment::TopRight => (0.0, 1.0),
-                Alignment::Left => (0.5, 0.0),
-                Alignment::Center => (0.5, 0.5),
-                Alignment::Right => (0.5, 1.0),
-                Alignment::BottomLeft => (1.0, 0.0),
-                Alignment::BottomCenter => (1.0, 0.5),
-                Alignment::BottomRight => (1.0, 1.0),
-            };
+            // The text is placed within the padding and border of the view, as it is when measured during layout.
+            let (inset_left, inset_top, _, inset_bottom) =
+                content_insets(self.style, self.current, bounds);
 
-            let padding_top = match self.padding_top() {
-                Units::Pixels(val) => val,
-                _ => 0.0,
-            };
+            let top =
+                text_top(self.style, self.current, text, bounds.height(), inset_top, inset_bottom);
 
-            let padding_bottom = match self.padding_bottom() {
-                Units::Pixels(val) => val,
-                _ => 0.0,
-            };
+            let origin = ((bounds.x + inset_left).round(), (bounds.y + inset_top + top).round());
 
-            top *= bounds.height() - padding_top - padding_bottom - paragraph.height();
+            // The font size is compared in logical pixels, after the transform of the canvas, which has no single
+            // scale under a perspective transform.
+            let scale = canvas.local_to_device_as_3x3().max_scale();
+            if scale > 0.0
+                && self
+                    .text_rendering()
+                    .should_greek(self.font_size() * scale / self.scale_factor())
+            {
+                self.draw_greeked_text(canvas, text, origin);
+                return;
+            }
 
-            let padding_left = match self.padding_left() {
-                Units::Pixels(val) => val,
-                _ => 0.0,
-            };
+            // Gradient text is painted into a layer the size of the lines of text, which the gradient is then drawn
+            // over, keeping the alpha of the glyphs.
+            if let Some(gradient) =
+                self.style.font_color.get(self.current).and_then(ColorOrGradient::gradient)
+            {
+                let (left, right) =
+                    text.lines().iter().fold((f32::MAX, f32::MIN), |(left, right), line| {
+                        (left.min(line.left), right.max(line.left + line.width))
+                    });
+
+                let run_bounds = BoundingBox {
+                    x: origin.0 + left.min(right),
+                    y: origin.1,
+                    w: (right - left).max(0.0),
+                    h: text.height(),
+                };
+
+                if let Some(shader) = self.gradient_shader(gradient, run_bounds) {
+                    let layer_bounds = Rect::from(run_bounds);
+                    canvas.save_layer(&SaveLayerRec::default().bounds(&layer_bounds));
+                    text.paint(canvas, origin);
+                    let mut paint = Paint::default();
+                    paint.set_shader(shader);
+                    paint.set_blend_mode(skia_safe::BlendMode::SrcIn);
+                    canvas.draw_paint(&paint);
+                    canvas.restore();
+                    return;
+                }
+            }
+
+            text.paint(canvas, origin);
+        }
+    }
 
-            paragraph.paint(
-                canvas,
-                ((bounds.x + padding_left).round(), (bounds.y + padding_top + top).round()),
+    // Draws a bar across the x-height of each line of text, at half the opacity of the font color, in place
+    // of its glyphs.
+    fn draw_greeked_text(&self, canvas: &Canvas, text: &dyn TextLayout, origin: (f32, f32)) {
+        let mut paint = Paint::default();
+        paint.set_color(self.font_color());
+        paint.set_alpha_f(paint.alpha_f() * 0.5);
+
+        for line in text.lines() {
+            let x_height = line.ascent * 0.5;
+            canvas.draw_rect(
+                Rect::from_xywh(
+                    origin.0 + line.left,
+                    origin.1 + line.baseline - x_height,
+                    line.width,
+                    x_height,
+                ),
+                &paint,
             );
         }
     }
@@ -1175,7 +1685,7 @@ impl DataContext for DrawContext<'_> {
             return Some(t);
         }
 
-        for entity in self.current.parent_iter(self.tree) {
+        for entity in self.current.data_parent_iter(self.tree) {
             // Return model data.
             if let Some(model_data_store) = self.data.get(&entity) {
                 if let Some(model) = model_data_store.models.get(&TypeId::of::<T>()) {
@@ -1231,3 +1741,762 @@ fn compute_smooth_corner(
 
     (a, b, c, d, l, p, corner_radius)
 }
+
+// Sets the dash pattern of a paint which strokes a dashed or dotted line of the given width, scaling the dashes and
+// gaps with the width.
+fn set_line_style(paint: &mut Paint, style: BorderStyleKeyword, width: f32) {
+    match style {
+        BorderStyleKeyword::Dashed => {
+            paint.set_path_effect(PathEffect::dash(&[width * 2.0, width], 0.0));
+        }
+
+        BorderStyleKeyword::Dotted => {
+            paint.set_path_effect(PathEffect::dash(&[0.0, width * 2.0], 0.0));
+            paint.set_stroke_cap(skia_safe::PaintCap::Round);
+        }
+
+        _ => {}
+    }
+}
+
+// Returns the value of a background property for a layer, repeating the values if there are fewer of them than
+// layers.
+fn layer_value<T>(values: &[T], layer: usize) -> Option<&T> {
+    (!values.is_empty()).then(|| &values[layer % values.len()])
+}
+
+// Returns the bounds of the tile of a background layer which is placed by its background position. The size of the
+// tile is given by the background size, using the intrinsic size of the image, if it has one, for an `auto` size,
+// and is then scaled to fit a whole number of tiles along any axis which is rounded.
+fn background_tile(
+    bounds: BoundingBox,
+    intrinsic_size: Option<(f32, f32)>,
+    size: Option<&BackgroundSize>,
+    position: Option<&Translate>,
+    repeat: BackgroundRepeat,
+    scale_factor: f32,
+) -> BoundingBox {
+    let (mut w, mut h) = match size.cloned().unwrap_or_default() {
+        BackgroundSize::Explicit { width, height } => {
+            let width = match width {
+                LengthPercentageOrAuto::LengthPercentage(length) => {
+                    Some(length.to_pixels(bounds.w, scale_factor))
+                }
+                LengthPercentageOrAuto::Auto => None,
+            };
+
+            let height = match height {
+                LengthPercentageOrAuto::LengthPercentage(length) => {
+                    Some(length.to_pixels(bounds.h, scale_factor))
+                }
+                LengthPercentageOrAuto::Auto => None,
+            };
+
+            // An `auto` size keeps the aspect ratio of the image.
+            match (width, height, intrinsic_size) {
+                (Some(w), Some(h), _) => (w, h),
+                (Some(w), None, Some((image_w, image_h))) => (w, w * image_h / image_w),
+                (None, Some(h), Some((image_w, image_h))) => (h * image_w / image_h, h),
+                (None, None, Some(image_size)) => image_size,
+                (w, h, None) => (w.unwrap_or(bounds.w), h.unwrap_or(bounds.h)),
+            }
+        }
+
+        BackgroundSize::Contain | BackgroundSize::Cover => {
+            if let Some((image_w, image_h)) = intrinsic_size {
+                let image_ratio = image_w / image_h;
+                let container_ratio = bounds.w / bounds.h;
+                let fit_width = (image_ratio > container_ratio)
+                    == matches!(size, Some(BackgroundSize::Contain));
+
+                if fit_width {
+                    (bounds.w, bounds.w / image_ratio)
+                } else {
+                    (bounds.h * image_ratio, bounds.h)
+                }
+            } else {
+                (bounds.w, bounds.h)
+            }
+        }
+    };
+
+    // Rounding one axis also scales the other axis if its size is `auto`, to keep the aspect ratio of the image.
+    let (auto_width, auto_height) = match size {
+        Some(BackgroundSize::Explicit { width, height }) => {
+            (*width == LengthPercentageOrAuto::Auto, *height == LengthPercentageOrAuto::Auto)
+        }
+        Some(BackgroundSize::Contain | BackgroundSize::Cover) => (false, false),
+        None => (true, true),
+    };
+
+    let round_x = repeat.x == BackgroundRepeatKeyword::Round && w > 0.0;
+    let round_y = repeat.y == BackgroundRepeatKeyword::Round && h > 0.0;
+
+    if round_x {
+        let rounded = bounds.w / (bounds.w / w).round().max(1.0);
+        if !round_y && auto_height {
+            h *= rounded / w;
+        }
+        w = rounded;
+    }
+
+    if round_y {
+        let rounded = bounds.h / (bounds.h / h).round().max(1.0);
+        if !round_x && auto_width {
+            w *= rounded / h;
+        }
+        h = rounded;
+    }
+
+    // A percentage position aligns the same point of the tile and the background.
+    let (x, y) = position
+        .map(|position| {
+            (
+                position.x.to_pixels(bounds.w - w, scale_factor),
+                position.y.to_pixels(bounds.h - h, scale_factor),
+            )
+        })
+        .unwrap_or_default();
+
+    BoundingBox { x: bounds.x + x, y: bounds.y + y, w, h }
+}
+
+// Returns the start of each tile of a background layer along an axis which overlaps the background, or just the
+// start of the placed tile if the layer isn't repeated along the axis.
+fn tile_range(
+    start: f32,
+    length: f32,
+    tile_start: f32,
+    tile_length: f32,
+    repeat: BackgroundRepeatKeyword,
+) -> impl Iterator<Item = f32> {
+    let (first, last, origin, stride) = match repeat {
+        BackgroundRepeatKeyword::Repeat | BackgroundRepeatKeyword::Round => (
+            ((start - tile_start) / tile_length).floor() as i32,
+            ((start + length - tile_start) / tile_length).ceil() as i32,
+            tile_start,
+            tile_length,
+        ),
+
+        BackgroundRepeatKeyword::NoRepeat => (0, 1, tile_start, tile_length),
+
+        // Spaced tiles start at the start of the background rather than at the placed tile, unless only one tile fits,
+        // in which case it's placed like a tile which isn't repeated.
+        BackgroundRepeatKeyword::Space => {
+            let count = (length / tile_length).floor() as i32;
+            if count < 2 {
+                (0, 1, tile_start, tile_length)
+            } else {
+                let gap = (length - count as f32 * tile_length) / (count - 1) as f32;
+                (0, count, start, tile_length + gap)
+            }
+        }
+    };
+
+    (first..last).map(move |index| origin + index as f32 * stride)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::{layout_system, style_system};
+
+    const BOUNDS: BoundingBox = BoundingBox { x: 10.0, y: 20.0, w: 200.0, h: 100.0 };
+    const REPEAT: BackgroundRepeat =
+        BackgroundRepeat { x: BackgroundRepeatKeyword::Repeat, y: BackgroundRepeatKeyword::Repeat };
+
+    #[test]
+    fn layer_values_repeat_for_extra_layers() {
+        assert_eq!(layer_value(&[1, 2], 3), Some(&2));
+        assert_eq!(layer_value::<i32>(&[], 0), None);
+    }
+
+    #[test]
+    fn auto_sized_tiles_use_the_image_size_and_ratio() {
+        let image_size = Some((50.0, 25.0));
+        assert_eq!(
+            background_tile(BOUNDS, image_size, None, None, REPEAT, 1.0),
+            BoundingBox { x: 10.0, y: 20.0, w: 50.0, h: 25.0 }
+        );
+
+        let size = BackgroundSize::Explicit {
+            width: LengthPercentageOrAuto::LengthPercentage(LengthOrPercentage::Percentage(50.0)),
+            height: LengthPercentageOrAuto::Auto,
+        };
+        assert_eq!(
+            background_tile(BOUNDS, image_size, Some(&size), None, REPEAT, 1.0),
+            BoundingBox { x: 10.0, y: 20.0, w: 100.0, h: 50.0 }
+        );
+
+        // Gradients fill the background by default.
+        assert_eq!(background_tile(BOUNDS, None, None, None, REPEAT, 1.0), BOUNDS);
+    }
+
+    #[test]
+    fn contain_and_cover_fit_the_image_ratio() {
+        let image_size = Some((50.0, 50.0));
+        assert_eq!(
+            background_tile(BOUNDS, image_size, Some(&BackgroundSize::Contain), None, REPEAT, 1.0),
+            BoundingBox { x: 10.0, y: 20.0, w: 100.0, h: 100.0 }
+        );
+        assert_eq!(
+            background_tile(BOUNDS, image_size, Some(&BackgroundSize::Cover), None, REPEAT, 1.0),
+            BoundingBox { x: 10.0, y: 20.0, w: 200.0, h: 200.0 }
+        );
+    }
+
+    #[test]
+    fn percentage_positions_align_the_tile_within_the_background() {
+        let position = Translate::new(
+            LengthOrPercentage::Percentage(100.0),
+            LengthOrPercentage::Percentage(50.0),
+        );
+        assert_eq!(
+            background_tile(BOUNDS, Some((50.0, 20.0)), None, Some(&position), REPEAT, 1.0),
+            BoundingBox { x: 160.0, y: 60.0, w: 50.0, h: 20.0 }
+        );
+    }
+
+    #[test]
+    fn repeated_tiles_cover_the_background() {
+        let tiles = tile_range(10.0, 200.0, 60.0, 80.0, BackgroundRepeatKeyword::Repeat);
+        assert_eq!(tiles.collect::<Vec<_>>(), vec![-20.0, 60.0, 140.0]);
+
+        let tiles = tile_range(10.0, 200.0, 60.0, 80.0, BackgroundRepeatKeyword::NoRepeat);
+        assert_eq!(tiles.collect::<Vec<_>>(), vec![60.0]);
+    }
+
+    #[test]
+    fn spaced_tiles_touch_both_edges_of_the_background() {
+        let tiles = tile_range(10.0, 200.0, 60.0, 60.0, BackgroundRepeatKeyword::Space);
+        assert_eq!(tiles.collect::<Vec<_>>(), vec![10.0, 80.0, 150.0]);
+
+        // A single tile which fits is placed like a tile which isn't repeated.
+        let tiles = tile_range(10.0, 200.0, 60.0, 120.0, BackgroundRepeatKeyword::Space);
+        assert_eq!(tiles.collect::<Vec<_>>(), vec![60.0]);
+    }
+
+    #[test]
+    fn rounded_tiles_fit_a_whole_number_of_times() {
+        let round = BackgroundRepeat::new(BackgroundRepeatKeyword::Round);
+        assert_eq!(
+            background_tile(BOUNDS, Some((70.0, 40.0)), None, None, round, 1.0),
+            BoundingBox { x: 10.0, y: 20.0, w: 66.666664, h: 33.333332 }
+        );
+
+        // Rounding one axis keeps the aspect ratio of an `auto` sized image.
+        let round_x = BackgroundRepeat {
+            x: BackgroundRepeatKeyword::Round,
+            y: BackgroundRepeatKeyword::NoRepeat,
+        };
+        assert_eq!(
+            background_tile(BOUNDS, Some((128.0, 32.0)), None, None, round_x, 1.0),
+            BoundingBox { x: 10.0, y: 20.0, w: 100.0, h: 25.0 }
+        );
+    }
+
+    fn text_context() -> Context {
+        let mut cx = Context::default();
+        cx.style.dpi_factor = 1.0;
+        cx.style.width.insert(Entity::root(), Units::Pixels(400.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(200.0));
+        cx
+    }
+
+    // Draws the entities onto a raster surface which is scaled by the given factor.
+    fn draw(
+        cx: &mut Context,
+        entities: &[Entity],
+        scale: f32,
+        draw: impl Fn(&mut DrawContext, &Canvas),
+    ) -> skia_safe::Surface {
+        let mut surface = skia_safe::surfaces::raster_n32_premul((400, 200)).unwrap();
+        let canvas = surface.canvas();
+        canvas.scale((scale, scale));
+        for &entity in entities {
+            let mut draw_cx = DrawContext {
+                current: entity,
+                style: &cx.style,
+                cache: &cx.cache,
+                tree: &cx.tree,
+                data: &cx.data,
+                views: &mut cx.views,
+                resource_manager: &cx.resource_manager,
+                text_context: &mut cx.text_context,
+                modifiers: &cx.modifiers,
+                mouse: &cx.mouse,
+                windows: &mut cx.windows,
+            };
+            draw(&mut draw_cx, canvas);
+        }
+        surface
+    }
+
+    fn draw_text(cx: &mut Context, entities: &[Entity], scale: f32) -> skia_safe::Surface {
+        draw(cx, entities, scale, |cx, canvas| cx.draw_text(canvas))
+    }
+
+    fn pixels(surface: &mut skia_safe::Surface) -> Vec<u8> {
+        surface.image_snapshot().peek_pixels().unwrap().bytes().unwrap().to_vec()
+    }
+
+    // Draws the text of an entity with its text rendering reset, restoring it afterwards.
+    fn text_rendering_auto(cx: &mut Context, entity: Entity, scale: f32) -> skia_safe::Surface {
+        let text_rendering = cx.style.text_rendering.get(entity).copied();
+        cx.style.text_rendering.insert(entity, TextRendering::Auto);
+        let surface = draw_text(cx, &[entity], scale);
+        if let Some(text_rendering) = text_rendering {
+            cx.style.text_rendering.insert(entity, text_rendering);
+        }
+        surface
+    }
+
+    #[test]
+    fn text_below_the_threshold_is_greeked() {
+        let mut cx = text_context();
+        let label =
+            Label::new(&mut cx, "Greeked text").font_size(8.0).color(Color::black()).entity();
+        style_system(&mut cx);
+        layout_system(&mut cx);
+        let bounds = cx.cache.get_bounds(label);
+
+        let glyphs = pixels(&mut draw_text(&mut cx, &[label], 0.5));
+
+        cx.style.text_rendering.insert(label, TextRendering::GreekBelow(6.0));
+        style_system(&mut cx);
+        layout_system(&mut cx);
+        assert_eq!(cx.cache.get_bounds(label), bounds);
+
+        // At full size the text is above the threshold, while at half size it's painted as bars at half opacity.
+        assert_eq!(
+            pixels(&mut draw_text(&mut cx, &[label], 1.0)),
+            pixels(&mut text_rendering_auto(&mut cx, label, 1.0))
+        );
+        let greeked = pixels(&mut draw_text(&mut cx, &[label], 0.5));
+        assert_ne!(greeked, glyphs);
+        assert!(greeked.chunks(4).any(|pixel| pixel[3] > 0));
+        assert!(greeked.chunks(4).all(|pixel| pixel[3] == 0 || pixel[3] == 128));
+    }
+
+    #[test]
+    fn never_greek_overrides_an_inherited_threshold() {
+        let mut cx = text_context();
+        let (mut greeked, mut label) = (Entity::null(), Entity::null());
+        VStack::new(&mut cx, |cx| {
+            greeked = Label::new(cx, "Small text").entity();
+            label = Label::new(cx, "Small text").text_rendering(TextRendering::NeverGreek).entity();
+        })
+        .font_size(8.0)
+        .color(Color::black())
+        .text_rendering(TextRendering::GreekBelow(12.0));
+        style_system(&mut cx);
+        layout_system(&mut cx);
+
+        assert_ne!(
+            pixels(&mut draw_text(&mut cx, &[greeked], 1.0)),
+            pixels(&mut text_rendering_auto(&mut cx, greeked, 1.0))
+        );
+        assert_eq!(
+            pixels(&mut draw_text(&mut cx, &[label], 1.0)),
+            pixels(&mut text_rendering_auto(&mut cx, label, 1.0))
+        );
+    }
+
+    #[test]
+    fn borders_are_drawn_per_side() {
+        let mut cx = text_context();
+        let (mut divider, mut focused) = (Entity::null(), Entity::null());
+        VStack::new(&mut cx, |cx| {
+            divider = Element::new(cx)
+                .size(Pixels(100.0))
+                .border_bottom_width(Pixels(4.0))
+                .border_bottom_color(Color::black())
+                .entity();
+            focused = Element::new(cx)
+                .size(Pixels(100.0))
+                .border_width(Pixels(2.0))
+                .border_color(Color::black())
+                .border_top_style(BorderStyleKeyword::Dashed)
+                .entity();
+        });
+        style_system(&mut cx);
+        layout_system(&mut cx);
+
+        let mut surface =
+            draw(&mut cx, &[divider, focused], 1.0, |cx, canvas| cx.draw_border(canvas));
+        let pixels = pixels(&mut surface);
+        let alpha = |x: usize, y: usize| pixels[(y * 400 + x) * 4 + 3];
+
+        // Only the bottom of the divider is drawn.
+        assert!((10..90).all(|x| alpha(x, 97) == 255));
+        assert!((0..100).all(|x| alpha(x, 50) == 0 && alpha(x, 1) == 0));
+        assert!((0..90).all(|y| alpha(1, y) == 0 && alpha(98, y) == 0));
+
+        // The dashed top of the second view has gaps, while its other sides are solid.
+        assert!((10..90).any(|x| alpha(x, 101) == 255) && (10..90).any(|x| alpha(x, 101) == 0));
+        assert!((110..190).all(|y| alpha(1, y) == 255 && alpha(98, y) == 255));
+        assert!((10..90).all(|x| alpha(x, 198) == 255));
+    }
+
+    #[test]
+    fn inset_shadows_are_drawn_over_the_background() {
+        let mut cx = text_context();
+        let element = Element::new(&mut cx)
+            .size(Pixels(100.0))
+            .left(Pixels(50.0))
+            .top(Pixels(50.0))
+            .background_color(Color::white())
+            .shadows(vec![
+                Shadow::from("inset 0 0 0 4px red"),
+                Shadow::from("inset 0 0 0 8px blue"),
+                Shadow::from("0 0 0 4px lime"),
+                Shadow::from("inset 0 0 20px black"),
+            ])
+            .entity();
+        style_system(&mut cx);
+        layout_system(&mut cx);
+
+        let mut surface = draw(&mut cx, &[element], 1.0, |cx, canvas| {
+            cx.draw_background(canvas);
+            cx.draw_shadows(canvas);
+        });
+        let pixels = surface.image_snapshot().peek_pixels().unwrap();
+        let color = |x: i32, y: i32| pixels.get_color((x, y));
+
+        // The first shadow is drawn on top, and outset shadows are only drawn outside of the view.
+        assert_eq!(color(100, 52), skia_safe::Color::RED);
+        assert_eq!(color(100, 56), skia_safe::Color::BLUE);
+        assert_eq!(color(100, 48), skia_safe::Color::from_rgb(0, 255, 0));
+
+        // A blurred shadow without an offset or spread darkens the inside of the edges of the view.
+        let center = color(100, 100);
+        let inside = color(100, 60);
+        assert!(center.r() > inside.r() && inside.r() > 0);
+    }
+
+    #[test]
+    fn dashed_outline_follows_the_corner_radius() {
+        let mut cx = text_context();
+        cx.add_stylesheet(
+            r#"
+            .ring { outline-width: 2px; outline-color: black; outline-offset: 4px; }
+            .ring:focus-visible { outline-style: dashed; }
+            .ring.hidden { outline-style: none; }
+            "#,
+        )
+        .unwrap();
+        let element = Element::new(&mut cx)
+            .size(Pixels(100.0))
+            .left(Pixels(50.0))
+            .top(Pixels(50.0))
+            .corner_radius(Pixels(20.0))
+            .focusable(true)
+            .class("ring")
+            .entity();
+        style_system(&mut cx);
+        layout_system(&mut cx);
+
+        let draw_outline = |cx: &mut Context| {
+            let mut surface = draw(cx, &[element], 1.0, |cx, canvas| cx.draw_outline(canvas));
+            pixels(&mut surface)
+        };
+        let alpha = |pixels: &[u8], x: usize, y: usize| pixels[(y * 400 + x) * 4 + 3];
+
+        // The outline is drawn 4px outside of the view, from 45px to 47px, along the rounded corners.
+        let solid = draw_outline(&mut cx);
+        assert!((70..130).all(|x| alpha(&solid, x, 45) == 255));
+        assert_eq!(alpha(&solid, 46, 46), 0);
+
+        cx.with_current(element, |cx| cx.focus_with_visibility(true));
+        style_system(&mut cx);
+        let dashed = draw_outline(&mut cx);
+        assert!((70..130).any(|x| alpha(&dashed, x, 45) == 0));
+        assert!((70..130).any(|x| alpha(&dashed, x, 45) == 255));
+
+        cx.with_current(element, |cx| cx.toggle_class("hidden", true));
+        style_system(&mut cx);
+        assert!(draw_outline(&mut cx).iter().all(|byte| *byte == 0));
+    }
+
+    #[test]
+    fn gradients_paint_borders_and_outlines() {
+        let mut cx = text_context();
+        cx.add_stylesheet(
+            r#"
+            .framed {
+                border-width: 4px;
+                border-color: linear-gradient(to right, red, blue);
+                outline-width: 4px;
+                outline-color: linear-gradient(to bottom, lime, blue);
+            }
+            "#,
+        )
+        .unwrap();
+        let element = Element::new(&mut cx)
+            .size(Pixels(100.0))
+            .left(Pixels(50.0))
+            .top(Pixels(50.0))
+            .class("framed")
+            .entity();
+        style_system(&mut cx);
+        layout_system(&mut cx);
+
+        let mut surface = draw(&mut cx, &[element], 1.0, |cx, canvas| {
+            cx.draw_border(canvas);
+            cx.draw_outline(canvas);
+        });
+        let pixels = surface.image_snapshot().peek_pixels().unwrap();
+        let color = |x: i32, y: i32| pixels.get_color((x, y));
+
+        // The border runs from red on the left of the view to blue on the right.
+        let (left, right) = (color(51, 100), color(148, 100));
+        assert_eq!((left.a(), right.a()), (255, 255));
+        assert!(left.r() > 200 && left.b() < 50);
+        assert!(right.b() > 200 && right.r() < 50);
+
+        // The outline, outside of the view, runs from green at the top to blue at the bottom.
+        let (top, bottom) = (color(100, 47), color(100, 152));
+        assert_eq!((top.a(), bottom.a()), (255, 255));
+        assert!(top.g() > 200 && top.b() < 50);
+        assert!(bottom.b() > 200 && bottom.g() < 50);
+
+        // Inside the border nothing is drawn.
+        assert_eq!(color(100, 100).a(), 0);
+    }
+
+    #[test]
+    fn conic_gradients_sweep_clockwise_from_the_top() {
+        let mut cx = text_context();
+        cx.add_stylesheet(
+            r#"
+            .conic {
+                background-image: conic-gradient(red 25%, blue 25%, blue 50%, lime 50%, lime 75%, yellow 75%);
+            }
+            .conic.rotated {
+                background-image: conic-gradient(from 90deg, red 25%, blue 25%, blue 50%, lime 50%, lime 75%, yellow 75%);
+            }
+            .conic.repeating { background-image: repeating-conic-gradient(red 0%, red 25%, blue 25%, blue 50%); }
+            "#,
+        )
+        .unwrap();
+        let element = Element::new(&mut cx)
+            .size(Pixels(100.0))
+            .left(Pixels(50.0))
+            .top(Pixels(50.0))
+            .class("conic")
+            .entity();
+        style_system(&mut cx);
+        layout_system(&mut cx);
+
+        // Returns the colors of the top right, bottom right, bottom left and top left quarters of the view.
+        let quarters = |cx: &mut Context| {
+            let mut surface = draw(cx, &[element], 1.0, |cx, canvas| cx.draw_background(canvas));
+            let pixels = surface.image_snapshot().peek_pixels().unwrap();
+            [(125, 75), (125, 125), (75, 125), (75, 75)].map(|point| pixels.get_color(point))
+        };
+        let lime = skia_safe::Color::from_rgb(0, 255, 0);
+
+        assert_eq!(
+            quarters(&mut cx),
+            [skia_safe::Color::RED, skia_safe::Color::BLUE, lime, skia_safe::Color::YELLOW]
+        );
+
+        // The from-angle rotates the gradient clockwise, so that it starts on the right.
+        cx.with_current(element, |cx| cx.toggle_class("rotated", true));
+        style_system(&mut cx);
+        assert_eq!(
+            quarters(&mut cx),
+            [skia_safe::Color::YELLOW, skia_safe::Color::RED, skia_safe::Color::BLUE, lime]
+        );
+
+        // A repeating gradient repeats its stops around the rest of the circle.
+        cx.with_current(element, |cx| {
+            cx.toggle_class("rotated", false);
+            cx.toggle_class("repeating", true);
+        });
+        style_system(&mut cx);
+        assert_eq!(
+            quarters(&mut cx),
+            [
+                skia_safe::Color::RED,
+                skia_safe::Color::BLUE,
+                skia_safe::Color::RED,
+                skia_safe::Color::BLUE
+            ]
+        );
+    }
+
+    #[test]
+    fn gradient_text_is_painted_across_its_lines() {
+        let mut cx = text_context();
+        let label = Label::new(&mut cx, "MMMMMM")
+            .font_size(40.0)
+            .color(ColorOrGradient::from("linear-gradient(to right, red, blue)"))
+            .entity();
+        style_system(&mut cx);
+        layout_system(&mut cx);
+        let bounds = cx.cache.get_bounds(label);
+
+        let mut surface = draw_text(&mut cx, &[label], 1.0);
+        let pixels = surface.image_snapshot().peek_pixels().unwrap();
+        let glyphs: Vec<(i32, skia_safe::Color)> = (0..400)
+            .flat_map(|x| (0..200).map(move |y| (x, y)))
+            .map(|(x, y)| (x, pixels.get_color((x, y))))
+            .filter(|(_, color)| color.a() == 255)
+            .collect();
+        assert!(!glyphs.is_empty());
+
+        // The glyphs are only drawn within the view, and are red at the start of the line and blue at the end.
+        assert!(glyphs
+            .iter()
+            .all(|(x, _)| (*x as f32) >= bounds.x && (*x as f32) < bounds.right()));
+        let first = glyphs.iter().min_by_key(|(x, _)| *x).unwrap().1;
+        let last = glyphs.iter().max_by_key(|(x, _)| *x).unwrap().1;
+        assert!(first.r() > first.b());
+        assert!(last.b() > last.r());
+    }
+
+    #[test]
+    fn percentage_corner_radius_rounds_a_button_into_a_pill() {
+        let mut cx = text_context();
+        let button = Button::new(&mut cx, |cx| Label::new(cx, ""))
+            .width(Pixels(200.0))
+            .height(Pixels(40.0))
+            .left(Pixels(50.0))
+            .top(Pixels(50.0))
+            .background_color(Color::black())
+            .corner_radius("50%")
+            .entity();
+        style_system(&mut cx);
+        layout_system(&mut cx);
+
+        let draw_background = |cx: &mut Context| {
+            let mut surface = draw(cx, &[button], 1.0, |cx, canvas| cx.draw_background(canvas));
+            pixels(&mut surface)
+        };
+        let alpha = |pixels: &[u8], x: usize, y: usize| pixels[(y * 400 + x) * 4 + 3];
+
+        draw(&mut cx, &[button], 1.0, |cx, _| {
+            assert_eq!(cx.corner_top_left_radius(), (20.0, 20.0));
+        });
+
+        // Both ends are semicircles with the radius of half the height, rather than ellipses across half the width.
+        let pill = draw_background(&mut cx);
+        for (x, y) in [(51, 51), (248, 51), (51, 88), (248, 88)] {
+            assert_eq!(alpha(&pill, x, y), 0);
+        }
+        for (x, y) in [(51, 70), (248, 70), (75, 51), (225, 88), (150, 70)] {
+            assert_eq!(alpha(&pill, x, y), 255);
+        }
+
+        // Radii which overlap are scaled down in proportion until they fit, which makes the same pill.
+        cx.style.corner_top_left_radius.insert(button, "100%".into());
+        cx.style.corner_top_right_radius.insert(button, "100%".into());
+        cx.style.corner_bottom_right_radius.insert(button, "100%".into());
+        cx.style.corner_bottom_left_radius.insert(button, "100%".into());
+        assert_eq!(draw_background(&mut cx), pill);
+    }
+
+    #[test]
+    fn percentage_corner_radius_pairs_resolve_per_axis() {
+        let mut cx = text_context();
+        let button = Button::new(&mut cx, |cx| Label::new(cx, ""))
+            .width(Pixels(200.0))
+            .height(Pixels(40.0))
+            .left(Pixels(50.0))
+            .top(Pixels(50.0))
+            .background_color(Color::black())
+            .entity();
+        let radius = CornerRadiusValue::new("50%", "25%");
+        cx.style.corner_top_left_radius.insert(button, radius.clone());
+        cx.style.corner_top_right_radius.insert(button, radius.clone());
+        cx.style.corner_bottom_right_radius.insert(button, radius.clone());
+        cx.style.corner_bottom_left_radius.insert(button, radius);
+        style_system(&mut cx);
+        layout_system(&mut cx);
+
+        draw(&mut cx, &[button], 1.0, |cx, _| {
+            assert_eq!(cx.corner_top_left_radius(), (100.0, 10.0));
+        });
+
+        // The horizontal radius is half the width and the vertical radius a quarter of the height, so each side is
+        // an ellipse which is flatter than the ends of a pill.
+        let mut surface = draw(&mut cx, &[button], 1.0, |cx, canvas| cx.draw_background(canvas));
+        let pixels = pixels(&mut surface);
+        let alpha = |x: usize, y: usize| pixels[(y * 400 + x) * 4 + 3];
+        for (x, y) in [(51, 51), (60, 52), (239, 87), (248, 88)] {
+            assert_eq!(alpha(x, y), 0);
+        }
+        for (x, y) in [(140, 51), (160, 88), (51, 70), (248, 70), (150, 70)] {
+            assert_eq!(alpha(x, y), 255);
+        }
+    }
+
+    // Run with `cargo test --release -p vizia_core -- --ignored --nocapture tiny_labels`.
+    #[test]
+    #[ignore]
+    fn tiny_labels_benchmark() {
+        let mut cx = text_context();
+        let mut labels = Vec::new();
+        VStack::new(&mut cx, |cx| {
+            for i in 0..5000 {
+                labels.push(
+                    Label::new(cx, &format!("Label {i}"))
+                        .font_size(10.0)
+                        .position_type(PositionType::Absolute)
+                        .left(Pixels((i % 50) as f32 * 80.0))
+                        .top(Pixels((i / 50) as f32 * 20.0))
+                        .entity(),
+                );
+            }
+        });
+        style_system(&mut cx);
+        layout_system(&mut cx);
+
+        // Zoomed out to a tenth of their size, the grid of labels fills the surface with text 1px tall.
+        const FRAMES: u32 = 20;
+        for (name, text_rendering) in
+            [("Glyphs", TextRendering::NeverGreek), ("Greeked", TextRendering::GreekBelow(6.0))]
+        {
+            for &label in labels.iter() {
+                cx.style.text_rendering.insert(label, text_rendering);
+            }
+
+            let start = Instant::now();
+            for _ in 0..FRAMES {
+                draw_text(&mut cx, &labels, 0.1);
+            }
+            println!("{name}: {:?} per frame", start.elapsed() / FRAMES);
+        }
+    }
+
+    #[test]
+    fn accent_color_changes_reach_styled_views() {
+        let mut cx = text_context();
+        let mut event_manager = crate::events::EventManager::new();
+        cx.windows.insert(Entity::root(), WindowState::default());
+        cx.add_stylesheet(".accent { background-color: accent-color; }").unwrap();
+
+        let element = Element::new(&mut cx).class("accent").entity();
+        style_system(&mut cx);
+        draw(&mut cx, &[element], 1.0, |cx, _| {
+            assert_eq!(cx.background_color(), Color::RGBA(Color::DEFAULT_ACCENT));
+        });
+
+        cx.emit_custom(
+            Event::new(WindowEvent::AccentColorChanged(Color::red()))
+                .target(Entity::root())
+                .origin(Entity::root()),
+        );
+        event_manager.flush_events(&mut cx, |_| {});
+
+        // Views which are already styled are restyled and redrawn with the new accent color.
+        assert!(cx.style.restyle.contains(element));
+        assert!(cx.windows[&Entity::root()].redraw_list.contains(&Entity::root()));
+
+        style_system(&mut cx);
+        draw(&mut cx, &[element], 1.0, |cx, _| {
+            assert_eq!(cx.background_color(), Color::red());
+        });
+    }
+}
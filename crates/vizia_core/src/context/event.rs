@@ -6,11 +6,12 @@ use std::rc::Rc;
 
 use hashbrown::{HashMap, HashSet};
 use vizia_storage::{LayoutTreeIterator, TreeIterator};
+use vizia_style::ColorScheme;
 use vizia_window::WindowPosition;
 
-use crate::animation::{AnimId, Interpolator};
+use crate::animation::{AnimId, PlaybackControl};
 use crate::cache::CachedData;
-use crate::events::{TimedEvent, TimedEventHandle, TimerState, ViewHandler};
+use crate::events::{Subscriptions, TimedEvent, TimedEventHandle, TimerState, ViewHandler};
 use crate::model::ModelDataStore;
 use crate::prelude::*;
 use crate::resource::ResourceManager;
@@ -71,6 +72,7 @@ pub struct EventContext<'a> {
     pub(crate) views: &'a mut HashMap<Entity, Box<dyn ViewHandler>>,
     pub(crate) listeners:
         &'a mut HashMap<Entity, Box<dyn Fn(&mut dyn ViewHandler, &mut EventContext, &mut Event)>>,
+    pub(crate) subscriptions: &'a mut Subscriptions,
     pub(crate) resource_manager: &'a mut ResourceManager,
     pub(crate) text_context: &'a mut TextContext,
     pub(crate) modifiers: &'a Modifiers,
@@ -86,6 +88,7 @@ pub struct EventContext<'a> {
     pub(crate) event_proxy: &'a mut Option<Box<dyn crate::context::EventProxy>>,
     pub(crate) ignore_default_theme: &'a bool,
     pub(crate) drop_data: &'a mut Option<DropData>,
+    pub(crate) interaction_settings: &'a mut InteractionSettings,
     pub windows: &'a mut HashMap<Entity, WindowState>,
 }
 
@@ -123,6 +126,7 @@ impl<'a> EventContext<'a> {
             data: &mut cx.data,
             views: &mut cx.views,
             listeners: &mut cx.listeners,
+            subscriptions: &mut cx.subscriptions,
             resource_manager: &mut cx.resource_manager,
             text_context: &mut cx.text_context,
             modifiers: &cx.modifiers,
@@ -138,6 +142,7 @@ impl<'a> EventContext<'a> {
             event_proxy: &mut cx.event_proxy,
             ignore_default_theme: &cx.ignore_default_theme,
             drop_data: &mut cx.drop_data,
+            interaction_settings: &mut cx.interaction_settings,
             windows: &mut cx.windows,
         }
     }
@@ -156,6 +161,7 @@ impl<'a> EventContext<'a> {
             data: &mut cx.data,
             views: &mut cx.views,
             listeners: &mut cx.listeners,
+            subscriptions: &mut cx.subscriptions,
             resource_manager: &mut cx.resource_manager,
             text_context: &mut cx.text_context,
             modifiers: &cx.modifiers,
@@ -171,6 +177,7 @@ impl<'a> EventContext<'a> {
             event_proxy: &mut cx.event_proxy,
             ignore_default_theme: &cx.ignore_default_theme,
             drop_data: &mut cx.drop_data,
+            interaction_settings: &mut cx.interaction_settings,
             windows: &mut cx.windows,
         }
     }
@@ -185,12 +192,66 @@ impl<'a> EventContext<'a> {
         self.views.get(&entity).and_then(|view| view.downcast_ref::<V>())
     }
 
+    /// Writes a value through a lens to the model or view data it targets, returning whether the value was written.
+    ///
+    /// The data is found in the same way as when reading the lens, and only lenses created with
+    /// [`two_way()`](crate::binding::LensExt::two_way) write to it. The value is written directly, so the event
+    /// handler of the model isn't called, but any bindings to the data are updated.
+    pub fn write_lens<L: Lens>(&mut self, lens: L, value: L::Target) -> bool {
+        for entity in self.current.data_parent_iter(self.tree) {
+            if let Some(model_data_store) = self.data.get_mut(&entity) {
+                if let Some(model) = model_data_store.models.get_mut(&TypeId::of::<L::Source>()) {
+                    return model
+                        .downcast_mut::<L::Source>()
+                        .is_some_and(|source| lens.write(source, value));
+                }
+            }
+
+            if let Some(view_handler) = self.views.get_mut(&entity) {
+                if let Some(source) = view_handler.downcast_mut::<L::Source>() {
+                    return lens.write(source, value);
+                }
+            }
+        }
+
+        false
+    }
+
     pub fn close_window(&mut self) {
         if let Some(state) = self.windows.get_mut(&self.current) {
             state.should_close = true;
         }
     }
 
+    /// Requests that the window containing the current view closes.
+    ///
+    /// Unlike emitting a [`WindowEvent::WindowClose`] event, the window first receives a
+    /// [`WindowEvent::CloseRequested`] event, which can be cancelled with [`WindowEvent::CancelClose`].
+    pub fn request_close(&mut self) {
+        let window_entity = self.parent_window().unwrap_or(Entity::root());
+        self.event_queue.push_back(
+            Event::new(WindowEvent::CloseRequested)
+                .target(window_entity)
+                .origin(window_entity)
+                .propagate(Propagation::Up),
+        );
+    }
+
+    /// Requests that every window closes, which quits the application once they have all closed.
+    ///
+    /// Each window receives a [`WindowEvent::CloseRequested`] event. Emitting [`WindowEvent::CancelClose`] in
+    /// response cancels the request for that window, which stays open.
+    pub fn request_quit(&mut self) {
+        for window_entity in self.windows.keys() {
+            self.event_queue.push_back(
+                Event::new(WindowEvent::CloseRequested)
+                    .target(*window_entity)
+                    .origin(*window_entity)
+                    .propagate(Propagation::Up),
+            );
+        }
+    }
+
     pub fn window_position(&self) -> WindowPosition {
         let parent_window = self.parent_window().unwrap_or(Entity::root());
         if let Some(state) = self.windows.get(&parent_window) {
@@ -221,6 +282,17 @@ impl<'a> EventContext<'a> {
         self.modifiers
     }
 
+    /// Returns the distance in physical pixels from the top of the current view to its first baseline, which is the
+    /// baseline of the first line of its text, or its bottom edge if it has no text.
+    pub fn baseline(&self) -> f32 {
+        crate::layout::baseline::baseline(
+            self.style,
+            self.text_context,
+            self.current,
+            self.cache.get_height(self.current),
+        )
+    }
+
     /// Returns a reference to the mouse state.
     pub fn mouse(&self) -> &MouseState<Entity> {
         self.mouse
@@ -234,6 +306,28 @@ impl<'a> EventContext<'a> {
         self.tree.get_last_child(self.current).copied()
     }
 
+    /// Returns the first descendant of the current view with the given id.
+    pub fn descendant_with_id(&self, id: &str) -> Option<Entity> {
+        crate::tree::descendant_with_id(self.tree, self.style, self.current, id)
+    }
+
+    /// Returns the first descendant of the current view which is of the view type `T`.
+    pub fn first_descendant<T: View>(&self) -> Option<Entity> {
+        crate::tree::first_descendant::<T>(self.tree, self.views, self.current)
+    }
+
+    /// Returns the first descendant of the current view with the given element name.
+    pub fn descendant_with_element(&self, element: &str) -> Option<Entity> {
+        crate::tree::descendant_with_element(self.tree, self.views, self.current, element)
+    }
+
+    /// Moves keyboard focus to the descendant of the current view with the given id, if it exists.
+    pub fn focus_descendant(&mut self, id: &str) {
+        if let Some(descendant) = self.descendant_with_id(id) {
+            self.with_current(descendant, |cx| cx.focus());
+        }
+    }
+
     pub fn with_current<T>(&mut self, entity: Entity, f: impl FnOnce(&mut Self) -> T) -> T {
         let prev = self.current();
         self.current = entity;
@@ -301,87 +395,37 @@ impl<'a> EventContext<'a> {
 
         match (overflowx, overflowy) {
             (Overflow::Visible, Overflow::Visible) => root_bounds,
-            (Overflow::Hidden, Overflow::Visible) => {
+            (_, Overflow::Visible) => {
                 let left = clip_bounds.left();
                 let right = clip_bounds.right();
                 let top = root_bounds.top();
                 let bottom = root_bounds.bottom();
                 BoundingBox::from_min_max(left, top, right, bottom)
             }
-            (Overflow::Visible, Overflow::Hidden) => {
+            (Overflow::Visible, _) => {
                 let left = root_bounds.left();
                 let right = root_bounds.right();
                 let top = clip_bounds.top();
                 let bottom = clip_bounds.bottom();
                 BoundingBox::from_min_max(left, top, right, bottom)
             }
-            (Overflow::Hidden, Overflow::Hidden) => clip_bounds,
+            _ => clip_bounds,
         }
     }
 
     /// Returns the 2D transform of the current view.
     pub fn transform(&self) -> Matrix {
-        let bounds = self.bounds();
-        let scale_factor = self.scale_factor();
-
-        // Apply transform origin.
-        let mut origin = self
-            .style
-            .transform_origin
-            .get(self.current)
-            .map(|transform_origin| {
-                let mut origin = Matrix::translate(bounds.top_left());
-                let offset = transform_origin.as_transform(bounds, scale_factor);
-                origin = offset * origin;
-                origin
-            })
-            .unwrap_or(Matrix::translate(bounds.center()));
-        // transform = origin * transform;
-        let mut transform = origin;
-        origin = origin.invert().unwrap();
-
-        // Apply translation.
-        if let Some(translate) = self.style.translate.get(self.current) {
-            transform = transform * translate.as_transform(bounds, scale_factor);
-        }
-
-        // Apply rotation.
-        if let Some(rotate) = self.style.rotate.get(self.current) {
-            transform = transform * rotate.as_transform(bounds, scale_factor);
-        }
-
-        // Apply scaling.
-        if let Some(scale) = self.style.scale.get(self.current) {
-            transform = transform * scale.as_transform(bounds, scale_factor);
-        }
-
-        // Apply transform functions.
-        if let Some(transforms) = self.style.transform.get(self.current) {
-            // Check if the transform is currently animating
-            // Get the animation state
-            // Manually interpolate the value to get the overall transform for the current frame
-            if let Some(animation_state) = self.style.transform.get_active_animation(self.current) {
-                if let Some(start) = animation_state.keyframes.first() {
-                    if let Some(end) = animation_state.keyframes.last() {
-                        let start_transform = start.value.as_transform(bounds, scale_factor);
-                        let end_transform = end.value.as_transform(bounds, scale_factor);
-                        let t = animation_state.t;
-                        let animated_transform =
-                            Matrix::interpolate(&start_transform, &end_transform, t);
-                        transform = transform * animated_transform;
-                    }
-                }
-            } else {
-                transform = transform * transforms.as_transform(bounds, scale_factor);
-            }
-        }
-
-        transform = transform * origin;
+        self.style.local_transform(self.current, self.bounds())
+    }
 
-        transform
+    /// Adds an animation built with an [`AnimationBuilder`], returning its id, which can be used to play the
+    /// animation on views.
+    pub fn add_animation(&mut self, animation: AnimationBuilder) -> Animation {
+        self.style.add_animation(animation)
     }
 
-    /// Trigger an animation with the given id to play on the current view.
+    /// Trigger an animation with the given id to play on the current view, which is sent an
+    /// [`AnimationEvent::Finished`] once the animation finishes.
     pub fn play_animation(&mut self, anim_id: impl AnimId, duration: Duration, delay: Duration) {
         if let Some(animation_id) = anim_id.get(self) {
             self.style.enqueue_animation(self.current, animation_id, duration, delay);
@@ -412,6 +456,54 @@ impl<'a> EventContext<'a> {
         false
     }
 
+    /// Pauses an animation playing on the current view, which holds the values it has reached until it's resumed.
+    pub fn pause_animation(&mut self, anim_id: impl AnimId) {
+        self.control_animation(anim_id, PlaybackControl::Pause);
+    }
+
+    /// Resumes a paused animation on the current view from where it was paused.
+    pub fn resume_animation(&mut self, anim_id: impl AnimId) {
+        self.control_animation(anim_id, PlaybackControl::Resume);
+    }
+
+    /// Moves an animation playing on the current view to the given time after its delay, such as to scrub through
+    /// it while it's paused. An animation driven by a spring can't be moved.
+    pub fn seek_animation(&mut self, anim_id: impl AnimId, time: Duration) {
+        self.control_animation(anim_id, PlaybackControl::Seek(time));
+    }
+
+    /// Stops an animation playing on the current view, which shows its values without the animation again. A
+    /// stopped animation doesn't send [`AnimationEvent::Finished`].
+    pub fn stop_animation(&mut self, anim_id: impl AnimId) {
+        let Some(animation_id) = anim_id.get(self) else {
+            return;
+        };
+
+        let entity = self.current;
+        self.style
+            .pending_animations
+            .retain(|(pending, animation, ..)| *pending != entity || *animation != animation_id);
+        self.style.played_animations.retain(|played| *played != (entity, animation_id));
+        self.style.stop_animation(entity, animation_id);
+        self.needs_animation_update();
+    }
+
+    fn control_animation(&mut self, anim_id: impl AnimId, control: PlaybackControl) {
+        if let Some(animation_id) = anim_id.get(self) {
+            // An animation played by the same event handler is started first, so it can be controlled at once.
+            self.style.play_pending_animations();
+            self.style.control_animation(self.current, animation_id, control);
+            self.needs_animation_update();
+        }
+    }
+
+    // The animation system doesn't tick paused or stopped animations, so the current view is updated here.
+    fn needs_animation_update(&mut self) {
+        self.needs_redraw();
+        self.needs_relayout();
+        self.style.needs_text_update(self.current);
+    }
+
     /// Add a listener to an entity.
     ///
     /// A listener can be used to handle events which would not normally propagate to the entity.
@@ -432,6 +524,14 @@ impl<'a> EventContext<'a> {
         );
     }
 
+    /// Uses the preferred font families of a locale for the root view and as the first fallbacks for text, then
+    /// restyles the application so that text is shown with the new fonts.
+    pub(crate) fn apply_locale_fonts(&mut self, locale: &LanguageIdentifier) {
+        self.resource_manager.locale_theme = self.style.locale_fonts.root_style(locale);
+        self.style.fallback_fonts = self.style.locale_fonts.fallback_families(locale);
+        self.reload_styles().unwrap();
+    }
+
     /// Sets the language used by the application for localization.
     pub fn set_language(&mut self, lang: LanguageIdentifier) {
         if let Some(mut model_data_store) = self.data.remove(&Entity::root()) {
@@ -602,6 +702,16 @@ impl<'a> EventContext<'a> {
         self.focused() == self.current
     }
 
+    /// Returns true if the current view is focused and its focus is visible, such as when it was focused with the
+    /// keyboard.
+    pub fn is_focus_visible(&self) -> bool {
+        if let Some(pseudo_classes) = self.style.pseudo_classes.get(self.current) {
+            pseudo_classes.contains(PseudoClassFlags::FOCUS_VISIBLE)
+        } else {
+            false
+        }
+    }
+
     pub fn is_draggable(&self) -> bool {
         self.style
             .abilities
@@ -707,8 +817,30 @@ impl<'a> EventContext<'a> {
         self.data::<Environment>().unwrap()
     }
 
+    /// Returns the [InteractionSettings] used to interpret user input.
+    pub fn interaction_settings(&self) -> &InteractionSettings {
+        self.interaction_settings
+    }
+
+    /// Returns a mutable reference to the [InteractionSettings], which can be used to override the values
+    /// provided by the platform.
+    pub fn interaction_settings_mut(&mut self) -> &mut InteractionSettings {
+        self.interaction_settings
+    }
+
+    /// Returns the type of pointer which produced the last pointer event, which views can use to adapt to touch
+    /// input, for example with larger hit targets.
+    pub fn primary_pointer_type(&self) -> PointerType {
+        self.mouse.pointer_type
+    }
+
     /// Sets the current [theme mode](ThemeMode).
     pub fn set_theme_mode(&mut self, theme_mode: ThemeMode) {
+        self.style.color_scheme = match theme_mode {
+            ThemeMode::LightMode => ColorScheme::Light,
+            ThemeMode::DarkMode => ColorScheme::Dark,
+        };
+
         if !self.ignore_default_theme {
             match theme_mode {
                 ThemeMode::LightMode => {
@@ -732,7 +864,7 @@ impl<'a> EventContext<'a> {
 
     /// Marks the current view as needing a layout computation.
     pub fn needs_relayout(&mut self) {
-        self.style.needs_relayout();
+        self.style.needs_relayout_entity(self.current);
         self.needs_redraw();
     }
 
@@ -751,12 +883,49 @@ impl<'a> EventContext<'a> {
         self.style.needs_restyle(self.current);
     }
 
-    /// Reloads the stylesheets linked to the application.
-    pub fn reload_styles(&mut self) -> Result<(), std::io::Error> {
-        if self.resource_manager.themes.is_empty() && self.resource_manager.styles.is_empty() {
-            return Ok(());
+    /// Removes a stylesheet added with [`add_stylesheet`](Context::add_stylesheet) and restyles the application
+    /// without its rules.
+    ///
+    /// Animations of keyframes which are no longer declared by any stylesheet are stopped. Returns false if the
+    /// stylesheet has already been removed.
+    pub fn remove_stylesheet(&mut self, handle: StyleSheetHandle) -> bool {
+        let count = self.resource_manager.styles.len();
+        self.resource_manager.styles.retain(|(stylesheet, _)| *stylesheet != handle);
+        if self.resource_manager.styles.len() == count {
+            return false;
         }
 
+        self.reload_styles().expect("Failed to reload styles");
+
+        true
+    }
+
+    /// Replaces the rules of a stylesheet added with [`add_stylesheet`](Context::add_stylesheet), such as to switch
+    /// between themes, and restyles the application.
+    ///
+    /// The stylesheet keeps its place among the others, so its rules still take precedence over those of the
+    /// stylesheets added before it.
+    pub fn replace_stylesheet(
+        &mut self,
+        handle: StyleSheetHandle,
+        style: impl IntoCssStr,
+    ) -> Result<(), std::io::Error> {
+        let Some((_, stylesheet)) =
+            self.resource_manager.styles.iter_mut().find(|(stylesheet, _)| *stylesheet == handle)
+        else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "The stylesheet has been removed",
+            ));
+        };
+
+        *stylesheet = Box::new(style);
+
+        self.reload_styles()
+    }
+
+    /// Reloads the stylesheets linked to the application.
+    pub fn reload_styles(&mut self) -> Result<(), std::io::Error> {
         self.style.remove_rules();
 
         self.style.clear_style_rules();
@@ -768,14 +937,19 @@ impl<'a> EventContext<'a> {
             overall_theme += theme;
         }
 
-        for style_string in self.resource_manager.styles.iter().flat_map(|style| style.get_style())
+        overall_theme += &self.resource_manager.locale_theme;
+
+        for style_string in
+            self.resource_manager.styles.iter().flat_map(|(_, style)| style.get_style())
         {
             overall_theme += &style_string;
         }
 
         self.style.parse_theme(&overall_theme);
+        self.style.reinsert_state_rules();
 
         for entity in self.tree.into_iter() {
+            self.style.stop_undeclared_animations(entity);
             self.style.needs_restyle(entity);
             self.style.needs_relayout();
             //self.style.needs_redraw(entity);
@@ -869,6 +1043,22 @@ impl<'a> EventContext<'a> {
         self.needs_restyle();
     }
 
+    /// Sets the dragging state for the current view.
+    ///
+    /// Views which are being dragged can be selected with the `:dragging` CSS pseudo-class selector:
+    /// ```css
+    /// element:dragging {
+    ///     opacity: 0.8;
+    /// }
+    /// ```
+    pub fn set_dragging(&mut self, dragging: bool) {
+        if let Some(pseudo_classes) = self.style.pseudo_classes.get_mut(self.current) {
+            pseudo_classes.set(PseudoClassFlags::DRAGGING, dragging);
+        }
+
+        self.needs_restyle();
+    }
+
     pub fn set_read_only(&mut self, flag: bool) {
         let current = self.current();
         if let Some(pseudo_classes) = self.style.pseudo_classes.get_mut(current) {
@@ -999,6 +1189,7 @@ impl<'a> EventContext<'a> {
     /// A display value of `Display::None` causes the view to be ignored by both layout and rendering.
     pub fn set_display(&mut self, display: Display) {
         self.style.display.insert(self.current, display);
+        self.needs_relayout();
     }
 
     /// Sets the visibility of the current view.
@@ -1006,6 +1197,7 @@ impl<'a> EventContext<'a> {
     /// The layout system will still compute the size and position of an invisible (hidden) view.
     pub fn set_visibility(&mut self, visibility: Visibility) {
         self.style.visibility.insert(self.current, visibility);
+        self.needs_redraw();
     }
 
     /// Sets the opacity of the current view.
@@ -1013,26 +1205,37 @@ impl<'a> EventContext<'a> {
     /// Expects a number between 0.0 (transparent) and 1.0 (opaque).
     pub fn set_opacity(&mut self, opacity: f32) {
         self.style.opacity.insert(self.current, Opacity(opacity));
+        self.needs_redraw();
     }
 
     /// Sets the z-index of the current view.
     pub fn set_z_index(&mut self, z_index: i32) {
         self.style.z_index.insert(self.current, z_index);
+        self.needs_redraw();
+    }
+
+    /// Sets the layer of the current view.
+    pub fn set_layer(&mut self, layer: Layer) {
+        self.style.layer.insert(self.current, layer);
+        self.needs_redraw();
     }
 
     /// Sets the clip path of the current view.
     pub fn set_clip_path(&mut self, clip_path: ClipPath) {
         self.style.clip_path.insert(self.current, clip_path);
+        self.needs_redraw();
     }
 
     /// Sets the overflow type on the horizontal axis of the current view.
     pub fn set_overflowx(&mut self, overflowx: impl Into<Overflow>) {
         self.style.overflowx.insert(self.current, overflowx.into());
+        self.needs_redraw();
     }
 
     /// Sets the overflow type on the vertical axis of the current view.
     pub fn set_overflowy(&mut self, overflowy: impl Into<Overflow>) {
         self.style.overflowy.insert(self.current, overflowy.into());
+        self.needs_redraw();
     }
 
     // TRANSFORM
@@ -1040,33 +1243,45 @@ impl<'a> EventContext<'a> {
     /// Sets the transform of the current view.
     pub fn set_transform(&mut self, transform: impl Into<Vec<Transform>>) {
         self.style.transform.insert(self.current, transform.into());
+        self.needs_redraw();
     }
 
     /// Sets the transform origin of the current view.
     pub fn set_transform_origin(&mut self, transform_origin: Translate) {
         self.style.transform_origin.insert(self.current, transform_origin);
+        self.needs_redraw();
     }
 
     /// Sets the translation of the current view.
     pub fn set_translate(&mut self, translate: impl Into<Translate>) {
         self.style.translate.insert(self.current, translate.into());
+        self.needs_redraw();
     }
 
     /// Sets the rotation of the current view.
     pub fn set_rotate(&mut self, angle: impl Into<Angle>) {
         self.style.rotate.insert(self.current, angle.into());
+        self.needs_redraw();
     }
 
     /// Sets the scale of the current view.
     pub fn set_scale(&mut self, scale: impl Into<Scale>) {
         self.style.scale.insert(self.current, scale.into());
+        self.needs_redraw();
     }
 
     // FILTER
 
-    /// Sets the backdrop filter of the current view.
-    pub fn set_backdrop_filter(&mut self, filter: Filter) {
-        self.style.backdrop_filter.insert(self.current, filter);
+    /// Sets the filters of the current view.
+    pub fn set_filter(&mut self, filter: impl Into<Vec<Filter>>) {
+        self.style.filter.insert(self.current, filter.into());
+        self.needs_redraw();
+    }
+
+    /// Sets the backdrop filters of the current view.
+    pub fn set_backdrop_filter(&mut self, filter: impl Into<Vec<Filter>>) {
+        self.style.backdrop_filter.insert(self.current, filter.into());
+        self.needs_redraw();
     }
 
     // BOX SHADOW
@@ -1142,8 +1357,23 @@ impl<'a> EventContext<'a> {
 
     // GETTERS
     get_length_property!(
-        /// Returns the border width of the current view in physical pixels.
-        border_width
+        /// Returns the width of the top border of the current view in physical pixels.
+        border_top_width
+    );
+
+    get_length_property!(
+        /// Returns the width of the right border of the current view in physical pixels.
+        border_right_width
+    );
+
+    get_length_property!(
+        /// Returns the width of the bottom border of the current view in physical pixels.
+        border_bottom_width
+    );
+
+    get_length_property!(
+        /// Returns the width of the left border of the current view in physical pixels.
+        border_left_width
     );
 
     /// Returns the font-size of the current view in physical pixels.
@@ -1228,16 +1458,18 @@ impl<'a> EventContext<'a> {
 
     /// Modifies the state of an existing timer with the provided `Timer` id.
     pub fn modify_timer(&mut self, timer: Timer, timer_function: impl Fn(&mut TimerState)) {
-        while let Some(next_timer_state) = self.running_timers.peek() {
-            if next_timer_state.id == timer {
-                let mut timer_state = self.running_timers.pop().unwrap();
-
-                (timer_function)(&mut timer_state);
+        if self.timer_is_running(timer) {
+            // The heap is rebuilt as the modified timer may be due at a different time.
+            let mut running_timers = std::mem::take(self.running_timers).into_vec();
+            for timer_state in
+                running_timers.iter_mut().filter(|timer_state| timer_state.id == timer)
+            {
+                (timer_function)(timer_state);
+            }
 
-                self.running_timers.push(timer_state);
+            *self.running_timers = running_timers.into();
 
-                return;
-            }
+            return;
         }
 
         for pending_timer in self.timers.iter_mut() {
@@ -1252,16 +1484,10 @@ impl<'a> EventContext<'a> {
         timer: Timer,
         timer_function: impl Fn(&TimerState) -> T,
     ) -> Option<T> {
-        while let Some(next_timer_state) = self.running_timers.peek() {
-            if next_timer_state.id == timer {
-                let timer_state = self.running_timers.pop().unwrap();
-
-                let t = (timer_function)(&timer_state);
-
-                self.running_timers.push(timer_state);
-
-                return Some(t);
-            }
+        if let Some(timer_state) =
+            self.running_timers.iter().find(|timer_state| timer_state.id == timer)
+        {
+            return Some((timer_function)(timer_state));
         }
 
         for pending_timer in self.timers.iter() {
@@ -1310,7 +1536,7 @@ impl DataContext for EventContext<'_> {
             return Some(t);
         }
 
-        for entity in self.current.parent_iter(self.tree) {
+        for entity in self.current.data_parent_iter(self.tree) {
             // Return model data.
             if let Some(model_data_store) = self.data.get(&entity) {
                 if let Some(model) = model_data_store.models.get(&TypeId::of::<T>()) {
@@ -3,10 +3,12 @@
 mod access;
 #[doc(hidden)]
 pub mod backend;
+mod debug;
 mod draw;
 mod event;
 mod proxy;
 mod resource;
+mod stats;
 
 use log::debug;
 use skia_safe::{
@@ -29,12 +31,16 @@ use copypasta::{nop_clipboard::NopClipboardContext, ClipboardProvider};
 use hashbrown::{hash_map::Entry, HashMap, HashSet};
 
 pub use access::*;
+pub use debug::*;
 pub use draw::*;
 pub use event::*;
 pub use proxy::*;
 pub use resource::*;
+pub use stats::*;
 
-use crate::events::{TimedEvent, TimedEventHandle, TimerState, ViewHandler};
+#[cfg(feature = "description")]
+use crate::description::{default_view_builders, ViewBuilder};
+use crate::events::{Subscriptions, TimedEvent, TimedEventHandle, TimerState, ViewHandler};
 
 use crate::{
     binding::{BindingHandler, MapId},
@@ -42,9 +48,13 @@ use crate::{
 };
 use crate::{cache::CachedData, resource::ImageOrSvg};
 
-use crate::model::ModelDataStore;
+use crate::model::{ModelData, ModelDataStore, ModelOrView};
+use crate::modifiers::Anchor;
 use crate::prelude::*;
 use crate::resource::ResourceManager;
+#[cfg(debug_assertions)]
+use crate::systems::UpdateOverlay;
+use crate::systems::{remove_anchors, FocusRing};
 use crate::text::TextContext;
 use vizia_input::MouseState;
 use vizia_storage::{ChildIterator, LayoutTreeIterator};
@@ -77,6 +87,7 @@ pub struct WindowState {
     pub owner: Option<Entity>,
     pub is_modal: bool,
     pub should_close: bool,
+    pub close_requested: bool,
     pub position: WindowPosition,
 }
 
@@ -89,15 +100,22 @@ pub struct Context {
     pub(crate) views: Views,
     pub(crate) data: Models,
     pub(crate) bindings: Bindings,
+    // Bindings which need to find the source of their data again and rebuild, after a model was removed or replaced.
+    pub(crate) rebind: HashSet<Entity>,
+    // The bindings created by the modifiers of each view, so that a modifier which is applied again replaces its binding.
+    pub(crate) modifier_bindings: HashMap<Entity, HashMap<u64, Entity>>,
     pub(crate) event_queue: VecDeque<Event>,
     pub(crate) event_schedule: BinaryHeap<TimedEvent>,
     pub(crate) next_event_id: usize,
     pub(crate) timers: Vec<TimerState>,
     pub(crate) running_timers: BinaryHeap<TimerState>,
     pub(crate) tree_updates: Vec<Option<accesskit::TreeUpdate>>,
+    // The number of nodes sent in the last accessibility update.
+    pub(crate) access_nodes_updated: usize,
     pub(crate) listeners:
         HashMap<Entity, Box<dyn Fn(&mut dyn ViewHandler, &mut EventContext, &mut Event)>>,
     pub(crate) global_listeners: Vec<Box<dyn Fn(&mut EventContext, &mut Event)>>,
+    pub(crate) subscriptions: Subscriptions,
     pub(crate) style: Style,
     pub(crate) cache: CachedData,
     pub windows: HashMap<Entity, WindowState>,
@@ -125,11 +143,32 @@ pub struct Context {
     pub(crate) clicks: usize,
     pub(crate) click_pos: (f32, f32),
     pub(crate) click_button: MouseButton,
+    pub(crate) interaction_settings: InteractionSettings,
 
     pub ignore_default_theme: bool,
     pub window_has_focus: bool,
 
     pub(crate) drop_data: Option<DropData>,
+
+    // The views which are anchored to other views, keyed by the anchored view.
+    pub(crate) anchors: HashMap<Entity, Anchor>,
+
+    pub(crate) focus_ring: FocusRing,
+
+    // The view types which can be built from a view description, keyed by name.
+    #[cfg(feature = "description")]
+    pub(crate) view_builders: HashMap<String, ViewBuilder>,
+
+    // Whether to log events which propagated without being mapped by any model or view, in debug builds.
+    #[cfg_attr(not(debug_assertions), allow(dead_code))]
+    pub(crate) log_unhandled_events: bool,
+
+    // Whether to check that the entities removed by a binding rebuild leave no data behind, in debug builds.
+    #[cfg_attr(not(debug_assertions), allow(dead_code))]
+    pub(crate) leak_checks: bool,
+
+    #[cfg(debug_assertions)]
+    pub(crate) update_overlay: UpdateOverlay,
 }
 
 impl Default for Context {
@@ -152,6 +191,8 @@ impl Context {
             views: HashMap::default(),
             data: HashMap::default(),
             bindings: HashMap::default(),
+            rebind: HashSet::default(),
+            modifier_bindings: HashMap::default(),
             style: Style::default(),
             cache,
             windows: HashMap::new(),
@@ -161,8 +202,10 @@ impl Context {
             timers: Vec::new(),
             running_timers: BinaryHeap::new(),
             tree_updates: Vec::new(),
+            access_nodes_updated: 0,
             listeners: HashMap::default(),
             global_listeners: Vec::new(),
+            subscriptions: Subscriptions::default(),
             mouse: MouseState::default(),
             modifiers: Modifiers::empty(),
             captured: Entity::null(),
@@ -189,6 +232,10 @@ impl Context {
                     asset_provider,
                     text_bounds: Default::default(),
                     text_paragraphs: Default::default(),
+                    text_chunks: Default::default(),
+                    chunks_built: 0,
+                    text_measurements: Default::default(),
+                    measure_count: 0,
                 }
             },
 
@@ -209,11 +256,25 @@ impl Context {
             clicks: 0,
             click_pos: (0.0, 0.0),
             click_button: MouseButton::Left,
+            interaction_settings: InteractionSettings::default(),
 
             ignore_default_theme: false,
             window_has_focus: true,
 
             drop_data: None,
+
+            anchors: HashMap::new(),
+
+            focus_ring: FocusRing::default(),
+
+            #[cfg(feature = "description")]
+            view_builders: default_view_builders(),
+
+            log_unhandled_events: false,
+            leak_checks: false,
+
+            #[cfg(debug_assertions)]
+            update_overlay: UpdateOverlay::default(),
         };
 
         result.tree.set_window(Entity::root(), true);
@@ -225,6 +286,9 @@ impl Context {
         // Build the environment model at the root.
         Environment::new(&mut result).build(&mut result);
 
+        let locale = result.environment().locale.clone();
+        EventContext::new(&mut result).apply_locale_fonts(&locale);
+
         result.entity_manager.create();
 
         result.style.role.insert(Entity::root(), Role::Window);
@@ -254,6 +318,23 @@ impl Context {
         self.data::<Environment>().unwrap()
     }
 
+    /// Returns the [InteractionSettings] used to interpret user input.
+    pub fn interaction_settings(&self) -> &InteractionSettings {
+        &self.interaction_settings
+    }
+
+    /// Returns a mutable reference to the [InteractionSettings], which can be used to override the values
+    /// provided by the platform.
+    pub fn interaction_settings_mut(&mut self) -> &mut InteractionSettings {
+        &mut self.interaction_settings
+    }
+
+    /// Returns the type of pointer which produced the last pointer event, which views can use to adapt to touch
+    /// input, for example with larger hit targets.
+    pub fn primary_pointer_type(&self) -> PointerType {
+        self.mouse.pointer_type
+    }
+
     pub fn parent_window(&self) -> Entity {
         self.tree.get_parent_window(self.current).unwrap_or(Entity::root())
     }
@@ -293,11 +374,30 @@ impl Context {
         self.style.needs_relayout();
     }
 
+    /// Returns the number of entities which were laid out during the last layout pass.
+    pub fn entities_laid_out(&self) -> usize {
+        self.cache.entities_laid_out()
+    }
+
+    /// Returns the number of entities whose style rules were matched during the last style pass.
+    pub fn entities_matched(&self) -> usize {
+        self.style.entities_matched
+    }
+
+    /// Returns the number of nodes which were sent in the last accessibility update.
+    pub fn access_nodes_updated(&self) -> usize {
+        self.access_nodes_updated
+    }
+
     pub(crate) fn set_system_flags(&mut self, entity: Entity, system_flags: SystemFlags) {
         if system_flags.contains(SystemFlags::RESTYLE) {
             self.needs_restyle(entity);
         }
 
+        if system_flags.contains(SystemFlags::RELAYOUT) {
+            self.style.needs_relayout_entity(entity);
+        }
+
         if system_flags.contains(SystemFlags::REDRAW) {
             self.needs_redraw(entity);
         }
@@ -305,6 +405,10 @@ impl Context {
         if system_flags.contains(SystemFlags::REFLOW) {
             self.style.needs_text_update(entity);
         }
+
+        if system_flags.contains(SystemFlags::REPAINT) {
+            self.style.needs_text_repaint(entity);
+        }
     }
 
     /// Enables or disables PseudoClasses for the focus of an entity
@@ -393,9 +497,27 @@ impl Context {
     pub fn remove(&mut self, entity: Entity) {
         let delete_list = entity.branch_iter(&self.tree).collect::<Vec<_>>();
 
+        // The content of a portal is built within its host, so it's removed first, while it can still resolve its
+        // data through the portal.
+        let portal_contents = delete_list
+            .iter()
+            .filter_map(|entity| self.views.get(entity)?.downcast_ref::<Portal>())
+            .map(|portal| portal.content())
+            .filter(|content| {
+                self.entity_manager.is_alive(*content) && !delete_list.contains(content)
+            })
+            .collect::<Vec<_>>();
+
+        for content in portal_contents {
+            self.remove(content);
+        }
+
+        remove_anchors(self, &delete_list);
+
         if !delete_list.is_empty() {
             self.style.needs_restyle(self.current);
-            self.style.needs_relayout();
+            let parent = self.tree.get_layout_parent(entity).unwrap_or(Entity::root());
+            self.style.needs_relayout_entity(parent);
             self.needs_redraw(self.current);
         }
 
@@ -411,8 +533,13 @@ impl Context {
 
             if let Some(binding) = self.bindings.remove(entity) {
                 binding.remove(self);
+            }
 
-                self.bindings.insert(*entity, binding);
+            self.modifier_bindings.remove(entity);
+            self.subscriptions.remove_entity(*entity);
+
+            for type_id in self.release_models(*entity) {
+                self.take_model(*entity, type_id);
             }
 
             for image in self.resource_manager.images.values_mut() {
@@ -457,6 +584,7 @@ impl Context {
 
             if let Some(parent) = self.tree.get_layout_parent(*entity) {
                 self.style.needs_access_update(parent);
+                self.style.needs_children_restyle(parent);
             }
 
             let mut stopped_timers = Vec::new();
@@ -499,10 +627,137 @@ impl Context {
             self.views.remove(entity);
             self.text_context.text_bounds.remove(*entity);
             self.text_context.text_paragraphs.remove(*entity);
+            self.text_context.text_chunks.remove(*entity);
+            self.text_context.text_measurements.remove(*entity);
             self.entity_manager.destroy(*entity);
         }
     }
 
+    /// Returns the entity which models built with the current entity are stored on.
+    ///
+    /// Bindings are not part of the layout tree, so models built within a binding are stored on its layout parent.
+    /// Likewise, models built at the top of the content of a portal are stored on the portal.
+    pub(crate) fn model_entity(&self) -> Entity {
+        if self.tree.is_ignored(self.current) {
+            self.tree.get_data_parent(self.current).unwrap()
+        } else {
+            self.current
+        }
+    }
+
+    /// Removes the model of type `M` from the current entity, returning it.
+    ///
+    /// Views bound to the removed model are rebuilt against the closest ancestor model of the same type.
+    /// If there is no such model, the contents of the bindings are removed until they are rebuilt by an ancestor.
+    ///
+    /// # Example
+    /// ```ignore
+    /// if let Some(document) = cx.remove_model::<Document>() {
+    ///     document.save();
+    /// }
+    /// ```
+    pub fn remove_model<M: Model>(&mut self) -> Option<M> {
+        let entity = self.model_entity();
+        let model = self.take_model_from(entity, TypeId::of::<M>())?;
+        model.into_any().downcast::<M>().ok().map(|model| *model)
+    }
+
+    /// Replaces the model of type `M` on the current entity, returning the previous model.
+    ///
+    /// Unlike building a new model, every view bound to the model is rebuilt, even if the bound data is unchanged.
+    /// If the current entity has no model of type `M`, the model is built as with [`Model::build`].
+    pub fn replace_model<M: Model>(&mut self, model: M) -> Option<M> {
+        let entity = self.model_entity();
+
+        let Some(model_data_store) = self.data.get_mut(&entity) else {
+            model.build(self);
+            return None;
+        };
+
+        let Some(existing) = model_data_store.models.get_mut(&TypeId::of::<M>()) else {
+            model.build(self);
+            return None;
+        };
+
+        let previous = std::mem::replace(existing, Box::new(model));
+        let model = ModelOrView::Model(existing.as_ref());
+
+        for store in model_data_store.stores.values_mut() {
+            if store.contains_source(model) {
+                store.update(model);
+                self.rebind.extend(store.observers().iter().copied());
+            }
+        }
+
+        previous.into_any().downcast::<M>().ok().map(|model| *model)
+    }
+
+    /// Removes a model built by the given binding, marking the views bound to it for rebinding.
+    pub(crate) fn take_model(&mut self, owner: Entity, type_id: TypeId) {
+        if let Some(entity) = self.tree.get_layout_parent(owner) {
+            self.take_model_from(entity, type_id);
+        }
+    }
+
+    fn take_model_from(&mut self, entity: Entity, type_id: TypeId) -> Option<Box<dyn ModelData>> {
+        let model_data_store = self.data.get_mut(&entity)?;
+        let model = model_data_store.models.remove(&type_id)?;
+        model_data_store.owners.remove(&type_id);
+
+        let source = ModelOrView::Model(model.as_ref());
+        model_data_store.stores.retain(|_, store| {
+            if store.contains_source(source) {
+                self.rebind.extend(store.observers().iter().copied());
+                false
+            } else {
+                true
+            }
+        });
+
+        Some(model)
+    }
+
+    /// Releases ownership of the models built by the given binding, returning their types.
+    pub(crate) fn release_models(&mut self, owner: Entity) -> Vec<TypeId> {
+        let Some(entity) = self.tree.get_layout_parent(owner) else {
+            return Vec::new();
+        };
+
+        let Some(model_data_store) = self.data.get_mut(&entity) else {
+            return Vec::new();
+        };
+
+        let released = model_data_store
+            .owners
+            .iter()
+            .filter_map(|(type_id, model_owner)| (*model_owner == owner).then_some(*type_id))
+            .collect::<Vec<_>>();
+
+        for type_id in released.iter() {
+            model_data_store.owners.remove(type_id);
+        }
+
+        released
+    }
+
+    /// Removes the released models which were not built again by the given binding.
+    pub(crate) fn remove_unclaimed_models(&mut self, owner: Entity, released: Vec<TypeId>) {
+        let Some(entity) = self.tree.get_layout_parent(owner) else {
+            return;
+        };
+
+        for type_id in released {
+            let claimed = self
+                .data
+                .get(&entity)
+                .is_some_and(|model_data_store| model_data_store.owners.contains_key(&type_id));
+
+            if !claimed {
+                self.take_model_from(entity, type_id);
+            }
+        }
+    }
+
     /// Add a listener to an entity.
     ///
     /// A listener can be used to handle events which would not normally propagate to the entity.
@@ -553,6 +808,7 @@ impl Context {
             self.text_context.default_font_manager.new_from_data(data.as_ref(), None).unwrap(),
             None,
         );
+        self.text_context.clear_measurements();
     }
 
     /// Sets the global default font for the application.
@@ -564,6 +820,42 @@ impl Context {
             .collect();
     }
 
+    /// Sets the preferred font families for a language, given as a language subtag such as `ja` or a language and
+    /// script such as `zh-Hant`, replacing the built-in families for the language. An empty list of families stops
+    /// the language from changing the fonts of the application.
+    ///
+    /// See [`LocaleFonts`] for how the families are used.
+    pub fn set_locale_fonts(&mut self, language: &str, families: &[&str]) {
+        self.style.locale_fonts.set(language, families);
+        let locale = self.environment().locale.clone();
+        EventContext::new(self).apply_locale_fonts(&locale);
+    }
+
+    /// Sets the color space used to interpolate colors in transitions and animations.
+    ///
+    /// Individual transitions can override this with a color space hint, e.g. `transition: background-color 200ms ease oklab;`.
+    pub fn set_color_interpolation(&mut self, color_space: ColorSpace) {
+        self.style.color_space = color_space;
+    }
+
+    /// Sets whether to log a warning for each event which finishes propagating without any model or view
+    /// having mapped it, for example an event which was emitted with no model built on an ancestor to handle it.
+    ///
+    /// Events are only tracked in debug builds, so this has no effect in release builds.
+    pub fn log_unhandled_events(&mut self, enabled: bool) {
+        self.log_unhandled_events = enabled;
+    }
+
+    /// Sets whether to flash an overlay over the views which are rebuilt by a binding, restyled, or relaid out, and
+    /// to log the number of each per frame. The overlay can also be toggled with `ctrl + shift + alt + U`.
+    ///
+    /// Updates are only tracked in debug builds, so this has no effect in release builds.
+    #[cfg_attr(not(debug_assertions), allow(unused_variables))]
+    pub fn show_update_overlay(&mut self, enabled: bool) {
+        #[cfg(debug_assertions)]
+        self.update_overlay.set_enabled(enabled);
+    }
+
     /// Add a style string to the application.
     pub(crate) fn add_theme(&mut self, theme: &str) {
         self.resource_manager.themes.push(theme.to_owned());
@@ -571,12 +863,32 @@ impl Context {
         EventContext::new(self).reload_styles().expect("Failed to reload styles");
     }
 
-    pub fn add_stylesheet(&mut self, style: impl IntoCssStr) -> Result<(), std::io::Error> {
-        self.resource_manager.styles.push(Box::new(style));
+    /// Adds a stylesheet to the application, returning a handle which can be used to remove or replace it.
+    pub fn add_stylesheet(
+        &mut self,
+        style: impl IntoCssStr,
+    ) -> Result<StyleSheetHandle, std::io::Error> {
+        let handle = self.resource_manager.add_stylesheet(Box::new(style));
 
         EventContext::new(self).reload_styles().expect("Failed to reload styles");
 
-        Ok(())
+        Ok(handle)
+    }
+
+    /// Removes a stylesheet added with [`add_stylesheet`](Self::add_stylesheet) and restyles the application without
+    /// its rules. Returns false if the stylesheet has already been removed.
+    pub fn remove_stylesheet(&mut self, handle: StyleSheetHandle) -> bool {
+        EventContext::new(self).remove_stylesheet(handle)
+    }
+
+    /// Replaces the rules of a stylesheet added with [`add_stylesheet`](Self::add_stylesheet), such as to switch
+    /// between themes, and restyles the application.
+    pub fn replace_stylesheet(
+        &mut self,
+        handle: StyleSheetHandle,
+        style: impl IntoCssStr,
+    ) -> Result<(), std::io::Error> {
+        EventContext::new(self).replace_stylesheet(handle, style)
     }
 
     /// Remove all user themes from the application.
@@ -681,16 +993,18 @@ impl Context {
 
     /// Modifies the state of an existing timer with the provided `Timer` id.
     pub fn modify_timer(&mut self, timer: Timer, timer_function: impl Fn(&mut TimerState)) {
-        while let Some(next_timer_state) = self.running_timers.peek() {
-            if next_timer_state.id == timer {
-                let mut timer_state = self.running_timers.pop().unwrap();
-
-                (timer_function)(&mut timer_state);
+        if self.timer_is_running(timer) {
+            // The heap is rebuilt as the modified timer may be due at a different time.
+            let mut running_timers = std::mem::take(&mut self.running_timers).into_vec();
+            for timer_state in
+                running_timers.iter_mut().filter(|timer_state| timer_state.id == timer)
+            {
+                (timer_function)(timer_state);
+            }
 
-                self.running_timers.push(timer_state);
+            self.running_timers = running_timers.into();
 
-                return;
-            }
+            return;
         }
 
         for pending_timer in self.timers.iter_mut() {
@@ -738,20 +1052,18 @@ impl Context {
                 let mut timer_state = self.running_timers.pop().unwrap();
 
                 if timer_state.end_time().unwrap_or_else(|| now + Duration::from_secs(1)) >= now {
-                    if !timer_state.ticking {
-                        (timer_state.callback)(
-                            &mut EventContext::new_with_current(self, timer_state.entity),
-                            TimerAction::Start,
-                        );
-                        timer_state.ticking = true;
+                    let action = if timer_state.ticking {
+                        TimerAction::Tick(now - timer_state.time)
                     } else {
-                        (timer_state.callback)(
-                            &mut EventContext::new_with_current(self, timer_state.entity),
-                            TimerAction::Tick(now - timer_state.time),
-                        );
-                    }
+                        timer_state.ticking = true;
+                        TimerAction::Start
+                    };
                     timer_state.time = now + timer_state.interval - (now - timer_state.time);
+
+                    // The timer is rescheduled before its callback is called so that the callback can stop it.
+                    let (callback, entity) = (timer_state.callback.clone(), timer_state.entity);
                     self.running_timers.push(timer_state);
+                    (callback)(&mut EventContext::new_with_current(self, entity), action);
                 } else {
                     (timer_state.callback)(
                         &mut EventContext::new_with_current(self, timer_state.entity),
@@ -853,6 +1165,21 @@ impl Context {
         self.entity_identifiers.get(identity).cloned()
     }
 
+    /// Returns the first descendant of the current view with the given id.
+    pub fn descendant_with_id(&self, id: &str) -> Option<Entity> {
+        crate::tree::descendant_with_id(&self.tree, &self.style, self.current, id)
+    }
+
+    /// Returns the first descendant of the current view which is of the view type `T`.
+    pub fn first_descendant<T: View>(&self) -> Option<Entity> {
+        crate::tree::first_descendant::<T>(&self.tree, &self.views, self.current)
+    }
+
+    /// Returns the first descendant of the current view with the given element name.
+    pub fn descendant_with_element(&self, element: &str) -> Option<Entity> {
+        crate::tree::descendant_with_element(&self.tree, &self.views, self.current, element)
+    }
+
     /// Toggles the addition/removal of a class name for the current view.
     ///
     /// # Example
@@ -882,6 +1209,9 @@ impl Context {
 
 pub(crate) enum InternalEvent {
     Redraw,
+    /// Releases the view pressed with the mouse or a trigger key without pressing it, such as when the key was used
+    /// for something else.
+    CancelPress,
     LoadImage { path: String, image: Mutex<Option<skia_safe::Image>>, policy: ImageRetentionPolicy },
 }
 
@@ -1050,7 +1380,7 @@ impl DataContext for Context {
             return Some(t);
         }
 
-        for entity in self.current.parent_iter(&self.tree) {
+        for entity in self.current.data_parent_iter(&self.tree) {
             // Return any model data.
             if let Some(model_data_store) = self.data.get(&entity) {
                 if let Some(model) = model_data_store.models.get(&TypeId::of::<T>()) {
@@ -1081,7 +1411,7 @@ impl DataContext for LocalizationContext<'_> {
             return Some(t);
         }
 
-        for entity in self.current.parent_iter(self.tree) {
+        for entity in self.current.data_parent_iter(self.tree) {
             // Return any model data.
             if let Some(model_data_store) = self.data.get(&entity) {
                 if let Some(model) = model_data_store.models.get(&TypeId::of::<T>()) {
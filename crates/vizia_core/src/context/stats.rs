@@ -0,0 +1,163 @@
+use hashbrown::HashMap;
+use vizia_storage::TreeIterator;
+
+use crate::prelude::*;
+
+/// Counts of the entities, views, models and bindings of an application, created with [`Context::tree_stats`].
+///
+/// Counting is linear in the number of views, so the statistics can be collected periodically, for example to
+/// report telemetry or to find views which are never removed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TreeStats {
+    /// The number of entities which are alive, including bindings.
+    pub entities: usize,
+    /// The number of views which are stored.
+    pub views: usize,
+    /// The number of views with each element name. Views without an element name are not included.
+    pub elements: HashMap<&'static str, usize>,
+    /// The number of models built on all entities.
+    pub models: usize,
+    /// The number of bindings.
+    pub bindings: usize,
+}
+
+impl Context {
+    /// Returns the number of entities which are alive, including bindings.
+    pub fn entity_count(&self) -> usize {
+        self.entity_manager.alive_count()
+    }
+
+    /// Returns the number of entities in the subtree of the given entity, including the entity itself.
+    pub fn subtree_count(&self, entity: Entity) -> usize {
+        TreeIterator::subtree(&self.tree, entity).count()
+    }
+
+    /// Returns counts of the entities, views, models and bindings of the application.
+    pub fn tree_stats(&self) -> TreeStats {
+        let mut elements = HashMap::new();
+        for element in self.views.values().filter_map(|view| view.element()) {
+            *elements.entry(element).or_insert(0) += 1;
+        }
+
+        TreeStats {
+            entities: self.entity_count(),
+            views: self.views.len(),
+            elements,
+            models: self.data.values().map(|store| store.models.len()).sum(),
+            bindings: self.bindings.len(),
+        }
+    }
+
+    /// Sets whether to check that the entities removed when a binding rebuilds its content leave no data behind in
+    /// the views, models, bindings or style stores of the application, panicking with the stores which still hold
+    /// data for an entity.
+    ///
+    /// The checks are only made in debug builds, so this has no effect in release builds.
+    pub fn set_leak_checks(&mut self, enabled: bool) {
+        self.leak_checks = enabled;
+    }
+
+    // Panics if any of the removed entities is still alive or has data in a store.
+    #[cfg(debug_assertions)]
+    pub(crate) fn assert_removed(&self, removed: &[Entity]) {
+        for entity in removed {
+            let mut stores = self.style.stores_with_data(*entity);
+            let other_stores = [
+                ("entity manager", self.entity_manager.is_alive(*entity)),
+                ("views", self.views.contains_key(entity)),
+                ("models", self.data.contains_key(entity)),
+                ("bindings", self.bindings.contains_key(entity)),
+                ("modifier bindings", self.modifier_bindings.contains_key(entity)),
+                ("subscriptions", self.subscriptions.contains_entity(*entity)),
+                ("cache", self.cache.bounds.contains(*entity)),
+            ];
+            stores.extend(
+                other_stores.iter().filter(|(_, contains)| *contains).map(|(name, _)| *name),
+            );
+
+            assert!(
+                stores.is_empty(),
+                "{} was removed by a binding but still has data in: {}",
+                entity,
+                stores.join(", ")
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::systems::binding_system;
+
+    #[derive(Lens)]
+    struct List {
+        items: Vec<u32>,
+    }
+
+    impl Model for List {}
+
+    struct Counter;
+
+    impl Model for Counter {}
+
+    #[test]
+    fn counts_return_to_baseline_after_rebuilds() {
+        let mut cx = Context::default();
+        cx.set_leak_checks(true);
+
+        List { items: Vec::new() }.build(&mut cx);
+        let list = VStack::new(&mut cx, |cx| {
+            Binding::new(cx, List::items, |cx, items| {
+                for item in items.get(cx) {
+                    HStack::new(cx, |cx| {
+                        Counter.build(cx);
+                        Label::new(cx, item.to_string());
+                        Element::new(cx).class("row").on_press(|_| {});
+                    });
+                }
+            });
+        })
+        .entity();
+
+        let baseline = cx.tree_stats();
+        let baseline_subtree = cx.subtree_count(list);
+
+        cx.replace_model(List { items: vec![1, 2, 3] });
+        binding_system(&mut cx);
+
+        let stats = cx.tree_stats();
+        assert_eq!(stats.entities, baseline.entities + 9);
+        assert_eq!(stats.views, baseline.views + 9);
+        assert_eq!(stats.models, baseline.models + 3);
+        assert_eq!(stats.elements.get("label"), Some(&3));
+        assert_eq!(cx.subtree_count(list), baseline_subtree + 9);
+
+        cx.replace_model(List { items: vec![4] });
+        binding_system(&mut cx);
+        assert_eq!(cx.tree_stats().entities, baseline.entities + 3);
+
+        cx.replace_model(List { items: Vec::new() });
+        binding_system(&mut cx);
+        assert_eq!(cx.tree_stats(), baseline);
+        assert_eq!(cx.subtree_count(list), baseline_subtree);
+    }
+
+    #[test]
+    fn removed_bindings_are_dropped() {
+        let mut cx = Context::default();
+        List { items: vec![1] }.build(&mut cx);
+
+        let baseline = cx.tree_stats();
+        let stack = VStack::new(&mut cx, |cx| {
+            Binding::new(cx, List::items, |cx, items| {
+                Label::new(cx, items.map(|items| items.len().to_string()));
+            });
+        })
+        .entity();
+        assert!(cx.tree_stats().bindings > baseline.bindings);
+
+        cx.remove(stack);
+        assert_eq!(cx.tree_stats(), baseline);
+    }
+}
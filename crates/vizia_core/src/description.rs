@@ -0,0 +1,408 @@
+//! Building a tree of views from a serialized description.
+//!
+//! A [`ViewDescription`] describes a tree of views, along with their ids, classes, text and a limited set of style
+//! properties, and can be deserialized from any format supported by serde, such as RON or JSON. The description is
+//! built with [`Context::build_from_description`], which looks up the view types by name in the view types registered
+//! with the context. The built-in `VStack`, `HStack`, `Label`, `Button`, `Image` and `Element` views are registered by
+//! default, and an app can register its own views with [`Context::register_view_type`].
+//!
+//! Descriptions don't contain any event handling. Instead, views can be given an id in the description and then
+//! found with [`Context::resolve_entity_identifier`] once the description has been built.
+//!
+//! # Example
+//! ```
+//! # use vizia_core::prelude::*;
+//! # let cx = &mut Context::default();
+//! let description: ViewDescription = serde_json::from_str(
+//!     r#"{
+//!         "type": "VStack",
+//!         "classes": ["login"],
+//!         "style": { "gap": "8px", "padding": "12px" },
+//!         "children": [
+//!             { "type": "Label", "text": "Username" },
+//!             { "type": "Button", "id": "submit", "text": "Log in" }
+//!         ]
+//!     }"#,
+//! )
+//! .unwrap();
+//!
+//! cx.build_from_description(&description).unwrap();
+//! let submit = cx.resolve_entity_identifier("submit").unwrap();
+//! ```
+
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+use vizia_style::{LayoutUnits, Property};
+
+use crate::prelude::*;
+
+/// A serializable description of a view and its children, which is built with
+/// [`Context::build_from_description`].
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ViewDescription {
+    /// The name of the type of the view, such as `VStack` or `Label`.
+    #[serde(rename = "type")]
+    pub view_type: String,
+    /// The id of the view, used to find the view once it has been built and to style it with an id selector.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The classes of the view.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub classes: Vec<String>,
+    /// The text of a `Label` or `Button`, or the name or path of the image shown by an `Image`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Inline style properties of the view, keyed by their CSS name, with values written as they would be in a
+    /// stylesheet. See [`STYLE_PROPERTIES`] for the properties which can be set.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub style: BTreeMap<String, String>,
+    /// The children of the view.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<ViewDescription>,
+}
+
+/// The style properties which can be set by a [`ViewDescription`].
+pub const STYLE_PROPERTIES: [&str; 10] = [
+    "width",
+    "height",
+    "padding",
+    "gap",
+    "layout-type",
+    "display",
+    "opacity",
+    "background-color",
+    "color",
+    "font-size",
+];
+
+/// An error caused by a [`ViewDescription`] which can't be built.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DescriptionError {
+    /// A view type which hasn't been registered with the context.
+    UnknownViewType(String),
+    /// Children given to a view which doesn't have any content, such as a `Label`.
+    UnexpectedChildren(String),
+    /// A style property which can't be set by a description.
+    UnsupportedStyleProperty(String),
+    /// A style property with a value which couldn't be parsed.
+    InvalidStyleValue {
+        /// The name of the property.
+        property: String,
+        /// The value of the property.
+        value: String,
+    },
+}
+
+impl std::fmt::Display for DescriptionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DescriptionError::UnknownViewType(view_type) => {
+                write!(f, "Unknown view type `{}`", view_type)
+            }
+            DescriptionError::UnexpectedChildren(view_type) => {
+                write!(f, "Views of type `{}` can't have children", view_type)
+            }
+            DescriptionError::UnsupportedStyleProperty(property) => {
+                write!(f, "The `{}` style property can't be set by a description", property)
+            }
+            DescriptionError::InvalidStyleValue { property, value } => {
+                write!(f, "Invalid value `{}` for the `{}` style property", value, property)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DescriptionError {}
+
+// Builds a view from a description, calling the given closure to build its children.
+pub(crate) type ViewBuilder =
+    Rc<dyn Fn(&mut Context, &ViewDescription, &mut dyn FnMut(&mut Context)) -> Entity>;
+
+// The builders of the built-in view types.
+pub(crate) fn default_view_builders() -> HashMap<String, ViewBuilder> {
+    let mut builders = HashMap::new();
+
+    insert_builder(&mut builders, "VStack", |cx, _, children| VStack::new(cx, children).entity());
+    insert_builder(&mut builders, "HStack", |cx, _, children| HStack::new(cx, children).entity());
+
+    insert_builder(&mut builders, "Label", |cx, description, _| {
+        Label::new(cx, description.text.clone().unwrap_or_default()).entity()
+    });
+
+    insert_builder(&mut builders, "Button", |cx, description, _| {
+        let text = description.text.clone().unwrap_or_default();
+        Button::new(cx, |cx| Label::new(cx, text)).entity()
+    });
+
+    insert_builder(&mut builders, "Image", |cx, description, _| {
+        Image::new(cx, description.text.clone().unwrap_or_default()).entity()
+    });
+
+    insert_builder(&mut builders, "Element", |cx, _, _| Element::new(cx).entity());
+
+    builders
+}
+
+fn insert_builder<F>(
+    builders: &mut HashMap<String, ViewBuilder>,
+    name: impl Into<String>,
+    builder: F,
+) where
+    F: 'static + Fn(&mut Context, &ViewDescription, &mut dyn FnMut(&mut Context)) -> Entity,
+{
+    builders.insert(name.into(), Rc::new(builder));
+}
+
+impl Context {
+    /// Registers a view type which can be built from a [`ViewDescription`], replacing any view type with the same name.
+    ///
+    /// The builder is given the description of the view and a closure which builds its children, which should be
+    /// called within the content of the view. Views which aren't given children in a description don't need to call
+    /// it. The id, classes and style of the description are applied to the built view.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// cx.register_view_type("Overlay", |cx, _, children| ZStack::new(cx, children).entity());
+    /// ```
+    pub fn register_view_type<F>(&mut self, name: impl Into<String>, builder: F)
+    where
+        F: 'static + Fn(&mut Context, &ViewDescription, &mut dyn FnMut(&mut Context)) -> Entity,
+    {
+        insert_builder(&mut self.view_builders, name, builder);
+    }
+
+    /// Builds the views of a [`ViewDescription`] as a child of the current view, returning the entity of the root view
+    /// of the description.
+    ///
+    /// If any part of the description can't be built, none of it is added and the first error found is returned.
+    pub fn build_from_description(
+        &mut self,
+        description: &ViewDescription,
+    ) -> Result<Entity, DescriptionError> {
+        build_view(self, description)
+    }
+}
+
+// Builds a view and its children, removing the view again if it or any of its children couldn't be built.
+fn build_view(cx: &mut Context, description: &ViewDescription) -> Result<Entity, DescriptionError> {
+    let builder = cx
+        .view_builders
+        .get(&description.view_type)
+        .cloned()
+        .ok_or_else(|| DescriptionError::UnknownViewType(description.view_type.clone()))?;
+
+    let mut result = Ok(());
+    let mut built_children = false;
+    let entity = (builder)(cx, description, &mut |cx| {
+        built_children = true;
+        for child in description.children.iter() {
+            if let Err(error) = build_view(cx, child) {
+                result = Err(error);
+                break;
+            }
+        }
+    });
+
+    if !built_children && !description.children.is_empty() {
+        result = Err(DescriptionError::UnexpectedChildren(description.view_type.clone()));
+    }
+
+    match result.and_then(|_| apply_description(cx, entity, description)) {
+        Ok(()) => Ok(entity),
+        Err(error) => {
+            cx.remove(entity);
+            Err(error)
+        }
+    }
+}
+
+// Applies the id, classes and style of a description to a built view.
+fn apply_description(
+    cx: &mut Context,
+    entity: Entity,
+    description: &ViewDescription,
+) -> Result<(), DescriptionError> {
+    let mut handle =
+        Handle::<Element> { current: entity, entity, p: Default::default(), cx: &mut *cx };
+
+    if let Some(id) = &description.id {
+        handle = handle.id(id.clone());
+    }
+
+    for class in description.classes.iter() {
+        handle = handle.class(class);
+    }
+
+    for (property, value) in description.style.iter() {
+        handle = apply_style_property(handle, property, value)?;
+    }
+
+    Ok(())
+}
+
+// Sets an inline style property of a view with the modifier for the property.
+fn apply_style_property<'a>(
+    handle: Handle<'a, Element>,
+    property: &str,
+    value: &str,
+) -> Result<Handle<'a, Element>, DescriptionError> {
+    if !STYLE_PROPERTIES.contains(&property) {
+        return Err(DescriptionError::UnsupportedStyleProperty(property.to_string()));
+    }
+
+    let invalid_value = || DescriptionError::InvalidStyleValue {
+        property: property.to_string(),
+        value: value.to_string(),
+    };
+
+    Ok(match Property::parse_declaration(property, value).ok_or_else(invalid_value)? {
+        Property::Width(LayoutUnits::Units(width)) => handle.width(width),
+        Property::Height(LayoutUnits::Units(height)) => handle.height(height),
+        Property::Padding(LayoutUnits::Units(padding)) => handle.padding(padding),
+        // Lengths such as `calc()` expressions can only be set by style rules.
+        Property::Width(_) | Property::Height(_) | Property::Padding(_) => {
+            return Err(invalid_value())
+        }
+        Property::Gap(gap) => handle.row_gap(gap.row).col_gap(gap.column),
+        Property::LayoutType(layout_type) => handle.layout_type(layout_type),
+        Property::Display(display) => handle.display(display),
+        Property::Opacity(opacity) => handle.opacity(opacity),
+        Property::BackgroundColor(color) => handle.background_color(color),
+        Property::FontColor(color) => handle.color(color),
+        Property::FontSize(font_size) => handle.font_size(font_size.0),
+        _ => return Err(DescriptionError::UnsupportedStyleProperty(property.to_string())),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A representative layout, with nested stacks, each of the built-in views and each kind of field.
+    const LAYOUT: &str = r##"{
+        "type": "VStack",
+        "id": "settings",
+        "classes": ["panel", "settings"],
+        "style": {
+            "background-color": "#202020",
+            "gap": "8px",
+            "padding": "12px",
+            "width": "1s"
+        },
+        "children": [
+            {
+                "type": "Label",
+                "classes": ["title"],
+                "text": "Settings",
+                "style": { "color": "white", "font-size": "20" }
+            },
+            {
+                "type": "HStack",
+                "style": { "height": "auto", "gap": "4px" },
+                "children": [
+                    { "type": "Image", "text": "avatar.png", "style": { "width": "32px", "height": "32px" } },
+                    { "type": "Label", "id": "username", "text": "Username" }
+                ]
+            },
+            { "type": "Element", "classes": ["divider"], "style": { "height": "1px", "opacity": "0.5" } },
+            { "type": "Button", "id": "save", "text": "Save" }
+        ]
+    }"##;
+
+    fn parse(json: &str) -> ViewDescription {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn descriptions_round_trip_through_serde() {
+        let description = parse(LAYOUT);
+        assert_eq!(description.view_type, "VStack");
+        assert_eq!(description.children.len(), 4);
+        assert_eq!(description.children[1].children[1].id.as_deref(), Some("username"));
+
+        let serialized = serde_json::to_string(&description).unwrap();
+        assert_eq!(parse(&serialized), description);
+
+        // Empty fields are left out.
+        assert_eq!(
+            serde_json::to_string(&description.children[3]).unwrap(),
+            r#"{"type":"Button","id":"save","text":"Save"}"#
+        );
+    }
+
+    #[test]
+    fn descriptions_build_views_with_ids_classes_and_style() {
+        let mut cx = Context::default();
+        let root = cx.build_from_description(&parse(LAYOUT)).unwrap();
+
+        assert_eq!(cx.resolve_entity_identifier("settings"), Some(root));
+        assert_eq!(cx.tree.get_num_children(root), Some(4));
+        assert_eq!(cx.style.width.get(root), Some(&Units::Stretch(1.0)));
+        assert_eq!(cx.style.background_color.get(root), Some(&Color::rgb(32, 32, 32)));
+        assert!(cx.style.classes.get(root).unwrap().contains("settings"));
+
+        let username = cx.resolve_entity_identifier("username").unwrap();
+        let row = cx.tree.get_parent(username).unwrap();
+        assert_eq!(cx.tree.get_parent(row), Some(root));
+        assert_eq!(cx.style.height.get(row), Some(&Units::Auto));
+
+        let save = cx.resolve_entity_identifier("save").unwrap();
+        assert_eq!(cx.views.get(&save).and_then(|view| view.element()), Some("button"));
+    }
+
+    #[test]
+    fn registered_view_types_can_be_built() {
+        let mut cx = Context::default();
+        cx.register_view_type("Overlay", |cx, _, children| ZStack::new(cx, children).entity());
+
+        let root = cx
+            .build_from_description(&parse(
+                r#"{ "type": "Overlay", "children": [{ "type": "Label", "id": "inner" }] }"#,
+            ))
+            .unwrap();
+
+        let inner = cx.resolve_entity_identifier("inner").unwrap();
+        assert_eq!(cx.tree.get_parent(inner), Some(root));
+    }
+
+    #[test]
+    fn invalid_descriptions_are_reported_and_not_built() {
+        let mut cx = Context::default();
+        let num_children = cx.tree.get_num_children(Entity::root());
+
+        let errors = [
+            (
+                r#"{ "type": "VStack", "children": [{ "type": "Slider", "id": "slider" }] }"#,
+                DescriptionError::UnknownViewType("Slider".to_string()),
+            ),
+            (
+                r#"{ "type": "Label", "children": [{ "type": "Label" }] }"#,
+                DescriptionError::UnexpectedChildren("Label".to_string()),
+            ),
+            (
+                r#"{ "type": "Element", "style": { "transform": "rotate(45deg)" } }"#,
+                DescriptionError::UnsupportedStyleProperty("transform".to_string()),
+            ),
+            (
+                r#"{ "type": "VStack", "id": "stack", "style": { "width": "wide" } }"#,
+                DescriptionError::InvalidStyleValue {
+                    property: "width".to_string(),
+                    value: "wide".to_string(),
+                },
+            ),
+        ];
+
+        for (json, error) in errors {
+            assert_eq!(cx.build_from_description(&parse(json)), Err(error));
+        }
+
+        assert_eq!(cx.tree.get_num_children(Entity::root()), num_children);
+        assert_eq!(cx.resolve_entity_identifier("stack"), None);
+    }
+}
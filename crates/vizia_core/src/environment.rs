@@ -3,7 +3,6 @@ use crate::prelude::*;
 
 use unic_langid::LanguageIdentifier;
 use vizia_derive::Lens;
-use web_time::Duration;
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum ThemeMode {
@@ -54,6 +53,8 @@ pub struct Environment {
     pub locale: LanguageIdentifier,
     /// Current application and system theme.
     pub theme: Theme,
+    /// The accent color of the operating system, which the `accent-color` keyword resolves to in stylesheets.
+    pub accent_color: Color,
     /// The timer used to blink the caret of a textbox.
     pub(crate) caret_timer: Timer,
 }
@@ -61,12 +62,18 @@ pub struct Environment {
 impl Environment {
     pub fn new(cx: &mut Context) -> Self {
         let locale = sys_locale::get_locale().and_then(|l| l.parse().ok()).unwrap_or_default();
-        let caret_timer = cx.add_timer(Duration::from_millis(530), None, |cx, action| {
-            if matches!(action, TimerAction::Tick(_)) {
-                cx.emit(TextEvent::ToggleCaret);
-            }
-        });
-        Self { locale, theme: Theme::default(), caret_timer }
+        let caret_timer =
+            cx.add_timer(cx.interaction_settings().caret_blink_interval, None, |cx, action| {
+                if matches!(action, TimerAction::Tick(_)) {
+                    cx.emit(TextEvent::ToggleCaret);
+                }
+            });
+        Self {
+            locale,
+            theme: Theme::default(),
+            accent_color: Color::DEFAULT_ACCENT.into(),
+            caret_timer,
+        }
     }
 }
 
@@ -88,6 +95,7 @@ impl Model for Environment {
         event.take(|event, _| match event {
             EnvironmentEvent::SetLocale(locale) => {
                 self.locale = locale;
+                cx.apply_locale_fonts(&self.locale);
             }
 
             EnvironmentEvent::SetThemeMode(theme) => {
@@ -100,6 +108,7 @@ impl Model for Environment {
             EnvironmentEvent::UseSystemLocale => {
                 self.locale =
                     sys_locale::get_locale().map(|l| l.parse().unwrap()).unwrap_or_default();
+                cx.apply_locale_fonts(&self.locale);
             }
 
             EnvironmentEvent::ToggleThemeMode => {
@@ -123,6 +132,18 @@ impl Model for Environment {
                     cx.reload_styles().unwrap();
                 }
             }
+
+            WindowEvent::AccentColorChanged(accent_color) => {
+                if self.accent_color != *accent_color {
+                    self.accent_color = *accent_color;
+                    cx.style.accent_color = Some(accent_color.get_rgba());
+                    // The keyword is resolved when views are drawn, so the styled views only need to be restyled and
+                    // redrawn rather than the stylesheets reloaded.
+                    cx.needs_restyle();
+                    cx.needs_redraw();
+                }
+            }
+
             _ => (),
         })
     }
@@ -24,6 +24,11 @@ pub struct Event {
     pub(crate) meta: EventMeta,
     /// The message of the event
     pub(crate) message: Option<Box<dyn Any + Send>>,
+    // The type of the message and whether a handler has mapped it, used to report unhandled events.
+    #[cfg(debug_assertions)]
+    pub(crate) message_type: &'static str,
+    #[cfg(debug_assertions)]
+    pub(crate) mapped: bool,
 }
 
 impl Debug for Event {
@@ -38,7 +43,14 @@ impl Event {
     where
         M: Any + Send,
     {
-        Event { meta: Default::default(), message: Some(Box::new(message)) }
+        Event {
+            meta: Default::default(),
+            message: Some(Box::new(message)),
+            #[cfg(debug_assertions)]
+            message_type: std::any::type_name::<M>(),
+            #[cfg(debug_assertions)]
+            mapped: false,
+        }
     }
 
     /// Sets the target of the event.
@@ -107,6 +119,10 @@ impl Event {
     {
         if let Some(message) = &self.message {
             if let Some(message) = message.as_ref().downcast_ref() {
+                #[cfg(debug_assertions)]
+                {
+                    self.mapped = true;
+                }
                 (f)(message, &mut self.meta);
             }
         }
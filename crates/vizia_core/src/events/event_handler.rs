@@ -14,6 +14,8 @@ pub(crate) trait ViewHandler: Any {
 
     fn accessibility(&self, cx: &mut AccessContext, node: &mut AccessNode);
 
+    fn hit_test(&self, bounds: BoundingBox, x: f32, y: f32) -> bool;
+
     fn as_any_ref(&self) -> &dyn Any;
 
     fn as_any_mut(&mut self) -> &mut dyn Any;
@@ -1,20 +1,21 @@
 use crate::context::{InternalEvent, ResourceContext};
-use crate::events::EventMeta;
+use crate::events::{EventMeta, Publication};
 use crate::prelude::*;
+use crate::style::{scroll_offset, scroll_range, scrollbar_at, scrollbars};
 #[cfg(debug_assertions)]
 use crate::systems::compute_matched_rules;
 use crate::systems::{binding_system, hover_system};
 use crate::tree::{focus_backward, focus_forward, is_navigatable};
+use crate::views::{catch_panic, recover_from_panic, scroll_axes, scroll_distance};
 #[cfg(debug_assertions)]
-use log::debug;
+use log::{debug, warn};
+use skia_safe::Matrix;
 use std::any::Any;
 use vizia_storage::LayoutParentIterator;
 #[cfg(debug_assertions)]
 use vizia_storage::ParentIterator;
 use vizia_storage::TreeIterator;
 
-const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
-
 /// Dispatches events to views and models.
 ///
 /// The [EventManager] is responsible for taking the events in the event queue in cx
@@ -51,11 +52,30 @@ impl EventManager {
             // mutably to the view when handling events.
             self.event_queue.extend(cx.event_queue.drain(0..));
 
+            // The window whose close request is being handled, and the number of events queued before it was.
+            let mut closing = None;
+
             // Loop over the events in the event queue.
             'events: for event in self.event_queue.iter_mut() {
+                target_cancelled_close(cx, closing.take());
+                event.map(|window_event, meta| {
+                    if let WindowEvent::CloseRequested = window_event {
+                        closing = Some((meta.origin, cx.event_queue.len()));
+                    }
+                });
+
                 // Handle internal events.
                 event.take(|internal_event, _| match internal_event {
                     InternalEvent::Redraw => cx.needs_redraw(Entity::root()),
+                    InternalEvent::CancelPress => {
+                        if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(cx.triggered)
+                        {
+                            pseudo_classes.set(PseudoClassFlags::ACTIVE, false);
+                        }
+                        cx.needs_restyle(cx.triggered);
+                        release_ripple(cx, cx.triggered);
+                        cx.triggered = Entity::null();
+                    }
                     InternalEvent::LoadImage { path, image, policy } => {
                         if let Some(image) = image.lock().unwrap().take() {
                             ResourceContext::new(cx).load_image(path, image, policy);
@@ -63,6 +83,34 @@ impl EventManager {
                     }
                 });
 
+                // Deliver published topics to their subscribers rather than propagating them.
+                let origin = event.meta.origin;
+                let mut published = false;
+                event.take(|publication: Publication, _| {
+                    publication.deliver(cx, origin);
+                    published = true;
+                });
+
+                if published {
+                    continue 'events;
+                }
+
+                // A cancelled close applies to the window containing the target of the event, which is the window being
+                // closed when the event was emitted in response to its close request.
+                event.map(|window_event, meta| {
+                    if let WindowEvent::CancelClose = window_event {
+                        let window = if cx.windows.contains_key(&meta.target) {
+                            meta.target
+                        } else {
+                            cx.tree.get_parent_window(meta.target).unwrap_or(Entity::root())
+                        };
+
+                        if let Some(window_state) = cx.windows.get_mut(&window) {
+                            window_state.close_requested = false;
+                        }
+                    }
+                });
+
                 // Send events to any global listeners.
                 let mut global_listeners = vec![];
                 std::mem::swap(&mut cx.global_listeners, &mut global_listeners);
@@ -109,6 +157,9 @@ impl EventManager {
                     continue 'events;
                 }
 
+                #[cfg(debug_assertions)]
+                let log_unhandled_events = cx.log_unhandled_events;
+
                 let cx = &mut EventContext::new(cx);
 
                 // Copy the target to prevent multiple mutable borrows error.
@@ -154,19 +205,69 @@ impl EventManager {
                     }
                 }
 
+                // Window events are also handled by the window, so only other events are reported.
+                #[cfg(debug_assertions)]
+                if log_unhandled_events
+                    && !event.mapped
+                    && !event
+                        .message
+                        .as_ref()
+                        .is_some_and(|message| message.as_ref().is::<WindowEvent>())
+                {
+                    warn!(
+                        "Event {} from {} was not handled by any model or view on its path from {}",
+                        event.message_type, event.meta.origin, target
+                    );
+                }
+
                 event.map(|window_event: &WindowEvent, _| {
                     (window_event_callback)(window_event);
                 });
             }
 
+            target_cancelled_close(cx, closing);
+
             binding_system(cx);
 
+            // Close the windows whose close request has not been cancelled once all resulting events have been handled.
+            if cx.event_queue.is_empty() {
+                close_requested_windows(cx);
+            }
+
             // Return true if there are new events in the queue.
             !cx.event_queue.is_empty()
         } {}
     }
 }
 
+// Targets the cancelled closes emitted while the close request of a window was handled at that window, as they may
+// have been emitted by a model or view outside of it, such as a model of the main window cancelling the close of
+// each window when quitting.
+fn target_cancelled_close(cx: &mut Context, closing: Option<(Entity, usize)>) {
+    if let Some((window, queued)) = closing {
+        for event in cx.event_queue.iter_mut().skip(queued) {
+            event.map(|window_event, meta| {
+                if let WindowEvent::CancelClose = window_event {
+                    meta.target = window;
+                }
+            });
+        }
+    }
+}
+
+fn close_requested_windows(cx: &mut Context) {
+    for (window_entity, window_state) in cx.windows.iter_mut() {
+        if std::mem::take(&mut window_state.close_requested) {
+            cx.event_queue.push_back(
+                Event::new(WindowEvent::WindowClose)
+                    .target(*window_entity)
+                    .origin(*window_entity)
+                    .propagate(Propagation::Up),
+            );
+        }
+    }
+}
+
 fn visit_entity(cx: &mut EventContext, entity: Entity, event: &mut Event) {
     // Send event to models attached to the entity
     if let Some(ids) = cx
@@ -199,9 +300,147 @@ fn visit_entity(cx: &mut EventContext, entity: Entity, event: &mut Event) {
     // Send event to the view attached to the entity
     if let Some(mut view) = cx.views.remove(&entity) {
         cx.current = entity;
-        view.event(cx, event);
+        let result = catch_panic(|| view.event(cx, event));
 
         cx.views.insert(entity, view);
+
+        // Hand the panic over to the closest error boundary, which rebuilds its contents.
+        if let Err(payload) = result {
+            let (boundary, message) = recover_from_panic(&cx.tree, &cx.views, entity, payload);
+            cx.emit_to(boundary, ErrorBoundaryEvent::Error(message));
+        }
+    }
+
+    // A view which scrolls its overflow handles the scroll events which it and its descendants haven't handled.
+    if !event.meta.consumed {
+        scroll_overflow(cx, entity, event);
+    }
+}
+
+// Scrolls a view with `overflow: scroll` or `overflow: auto` with scroll events, the mouse wheel and its scrollbars.
+fn scroll_overflow(cx: &mut EventContext, entity: Entity, event: &mut Event) {
+    let (scroll_x, scroll_y) = cx.style.scrolls(entity);
+    if !scroll_x && !scroll_y {
+        return;
+    }
+
+    cx.current = entity;
+    let range = scroll_range(cx.style, cx.cache, cx.tree, entity);
+    let mut state = cx.style.scroll_states.get(entity).copied().unwrap_or_default();
+    state.offset = scroll_offset(cx.style, cx.cache, cx.tree, entity);
+    let previous = state;
+
+    // The cursor in the coordinates of the view, in which its scrollbars are positioned.
+    let cursor = cx.cache.transform.get(entity).and_then(Matrix::invert).map(|inverse| {
+        let point = inverse.map_point((cx.mouse.cursor_x, cx.mouse.cursor_y));
+        (point.x, point.y)
+    });
+    let along = |orientation: Orientation, (x, y): (f32, f32)| match orientation {
+        Orientation::Horizontal => x,
+        Orientation::Vertical => y,
+    };
+
+    // Like for a scroll view, progress is the fraction of the distance the view can be scrolled.
+    event.map(|scroll_event, meta| {
+        match *scroll_event {
+            ScrollEvent::SetX(x) => state.offset.0 = x * range.0,
+            ScrollEvent::SetY(y) => state.offset.1 = y * range.1,
+            ScrollEvent::ScrollX(x) => state.offset.0 += x * range.0,
+            ScrollEvent::ScrollY(y) => state.offset.1 += y * range.1,
+            ScrollEvent::ChildGeo(..) => return,
+        }
+
+        meta.consume();
+    });
+
+    event.map(|window_event, meta| match window_event {
+        WindowEvent::MouseScroll(x, y) => {
+            let horizontal_primary = range.0 > 0.0 && range.1 == 0.0;
+            let (x, y) = scroll_axes((-*x, -*y), cx.modifiers.shift(), horizontal_primary);
+            let settings = cx.interaction_settings();
+            let (x, y) = scroll_distance(
+                (x, y),
+                cx.mouse.scroll_unit,
+                settings.scroll_speed,
+                settings.scroll_line_height,
+            );
+
+            let scale_factor = cx.scale_factor();
+            state.offset.0 += x * scale_factor;
+            state.offset.1 += y * scale_factor;
+
+            // The wheel is passed on to the ancestors of a view which can't be scrolled along its axis.
+            if (x != 0.0 && range.0 > 0.0) || (y != 0.0 && range.1 > 0.0) {
+                meta.consume();
+            }
+        }
+
+        WindowEvent::MouseDown(MouseButton::Left) => {
+            let Some(cursor) = cursor else {
+                return;
+            };
+            let Some(scrollbar) = scrollbar_at(cx.style, cx.cache, cx.tree, entity, cursor) else {
+                return;
+            };
+
+            // Pressing the track outside of the thumb moves the center of the thumb to the cursor before dragging it.
+            let orientation = scrollbar.orientation;
+            let position = along(orientation, cursor);
+            let offset = match orientation {
+                Orientation::Horizontal => &mut state.offset.0,
+                Orientation::Vertical => &mut state.offset.1,
+            };
+            let (start, end) = match orientation {
+                Orientation::Horizontal => (scrollbar.thumb.left(), scrollbar.thumb.right()),
+                Orientation::Vertical => (scrollbar.thumb.top(), scrollbar.thumb.bottom()),
+            };
+            if position < start || position >= end {
+                let range = along(orientation, range);
+                *offset = (*offset
+                    + (position - (start + end) / 2.0) * scrollbar.scroll_per_pixel(range))
+                .clamp(0.0, range);
+            }
+
+            state.drag = Some((orientation, position, *offset));
+            cx.capture();
+            meta.consume();
+        }
+
+        WindowEvent::MouseMove(..) => {
+            let (Some((orientation, start, start_offset)), Some(cursor)) = (state.drag, cursor)
+            else {
+                return;
+            };
+
+            let scrollbar = scrollbars(cx.style, cx.cache, cx.tree, entity)
+                .into_iter()
+                .find(|scrollbar| scrollbar.orientation == orientation);
+            if let Some(scrollbar) = scrollbar {
+                let range = along(orientation, range);
+                let offset = start_offset
+                    + (along(orientation, cursor) - start) * scrollbar.scroll_per_pixel(range);
+                match orientation {
+                    Orientation::Horizontal => state.offset.0 = offset,
+                    Orientation::Vertical => state.offset.1 = offset,
+                }
+            }
+
+            meta.consume();
+        }
+
+        WindowEvent::MouseUp(MouseButton::Left) if state.drag.is_some() => {
+            state.drag = None;
+            cx.release();
+            meta.consume();
+        }
+
+        _ => {}
+    });
+
+    state.offset = (state.offset.0.clamp(0.0, range.0), state.offset.1.clamp(0.0, range.1));
+    if state != previous {
+        cx.style.scroll_states.insert(entity, state);
+        cx.needs_redraw();
     }
 }
 
@@ -259,6 +498,11 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
                             cx.needs_restyle(cx.triggered);
                         }
                     }
+
+                    if !disabled {
+                        let cursor = (cx.mouse.cursor_x, cx.mouse.cursor_y);
+                        press_ripple(cx, cx.triggered, Some(cursor));
+                    }
                     let focusable = cx
                         .style
                         .abilities
@@ -301,7 +545,7 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
             let new_click_time = Instant::now();
             let click_duration = new_click_time - cx.click_time;
             let new_click_pos = (cx.mouse.cursor_x, cx.mouse.cursor_y);
-            if click_duration <= DOUBLE_CLICK_INTERVAL
+            if click_duration <= cx.interaction_settings.double_click_interval
                 && new_click_pos == cx.click_pos
                 && *button == cx.click_button
             {
@@ -363,11 +607,19 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
                 }
 
                 cx.needs_restyle(cx.triggered);
+                release_ripple(cx, cx.triggered);
 
                 cx.triggered = Entity::null();
             }
 
             mutate_direct_or_up(meta, cx.captured, cx.hovered, true);
+
+            // A touch has no position once it is released, so it stops hovering views.
+            if cx.mouse.pointer_type == PointerType::Touch
+                && cx.mouse.left.state == MouseButtonState::Released
+            {
+                clear_hover(cx);
+            }
         }
         WindowEvent::MouseScroll(_, _) => {
             meta.target = cx.hovered;
@@ -509,6 +761,15 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
                 // }
             }
 
+            #[cfg(debug_assertions)]
+            if *code == Code::KeyU
+                && cx.modifiers == Modifiers::CTRL | Modifiers::SHIFT | Modifiers::ALT
+            {
+                let enabled = !cx.update_overlay.is_enabled();
+                cx.update_overlay.set_enabled(enabled);
+                debug!("Update overlay {}", if enabled { "shown" } else { "hidden" });
+            }
+
             if *code == Code::F5 {
                 EventContext::new(cx).reload_styles().unwrap();
             }
@@ -550,6 +811,7 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
                             pseudo_classes.set(PseudoClassFlags::ACTIVE, false);
                             cx.needs_restyle(cx.triggered);
                         }
+                        release_ripple(cx, cx.triggered);
                         cx.triggered = Entity::null();
                     }
                 } else {
@@ -584,6 +846,7 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
                             pseudo_classes.set(PseudoClassFlags::ACTIVE, false);
                             cx.needs_restyle(cx.triggered);
                         }
+                        release_ripple(cx, cx.triggered);
                         cx.triggered = Entity::null();
                     }
                 }
@@ -594,6 +857,13 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
                 if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(cx.triggered) {
                     pseudo_classes.set(PseudoClassFlags::ACTIVE, true);
                 }
+                // A held key repeats its key down events, which only start one ripple.
+                let held = ripple_target(cx, cx.triggered)
+                    .and_then(|target| cx.style.ripples.get(target))
+                    .is_some_and(|ripples| ripples.iter().any(|ripple| !ripple.is_released()));
+                if !held {
+                    press_ripple(cx, cx.triggered, None);
+                }
                 cx.with_current(cx.focused, |cx| cx.emit(WindowEvent::PressDown { mouse: false }));
             }
         }
@@ -609,6 +879,7 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
                     pseudo_classes.set(PseudoClassFlags::ACTIVE, false);
                 }
                 cx.needs_restyle(cx.triggered);
+                release_ripple(cx, cx.triggered);
                 cx.triggered = Entity::null();
             }
         }
@@ -635,6 +906,11 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
                 );
             }
         }
+        WindowEvent::CloseRequested => {
+            if let Some(window_state) = cx.windows.get_mut(&meta.origin) {
+                window_state.close_requested = true;
+            }
+        }
         WindowEvent::MouseEnter => {
             if let Some(pseudo_class) = cx.style.pseudo_classes.get_mut(meta.origin) {
                 pseudo_class.set(PseudoClassFlags::OVER, true);
@@ -655,11 +931,47 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
 
             cx.hovered = Entity::null();
         }
+        WindowEvent::ScrollUnitChanged(scroll_unit) => {
+            cx.mouse.scroll_unit = *scroll_unit;
+        }
+        WindowEvent::PointerTypeChanged(pointer_type) => {
+            cx.mouse.pointer_type = *pointer_type;
+
+            // Don't leave a view hovered by the mouse while using a touch screen.
+            if *pointer_type == PointerType::Touch {
+                clear_hover(cx);
+            }
+        }
 
         _ => {}
     }
 }
 
+// Removes the hover state from the hovered view and its ancestors, sending the events for the cursor leaving them.
+fn clear_hover(cx: &mut Context) {
+    if cx.hovered == Entity::null() {
+        return;
+    }
+
+    let parent_iter = LayoutParentIterator::new(&cx.tree, cx.hovered);
+    for ancestor in parent_iter {
+        if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(ancestor) {
+            pseudo_classes.set(PseudoClassFlags::HOVER, false);
+            // The windows keep their over state so that hovering resumes when switching back to a mouse.
+            if !cx.windows.contains_key(&ancestor) {
+                pseudo_classes.set(PseudoClassFlags::OVER, false);
+            }
+
+            cx.style.needs_restyle(ancestor);
+        }
+    }
+
+    cx.event_queue.push_back(Event::new(WindowEvent::MouseLeave).direct(cx.hovered));
+    cx.event_queue.push_back(Event::new(WindowEvent::MouseOut).target(cx.hovered));
+
+    cx.hovered = Entity::null();
+}
+
 fn mutate_direct_or_up(meta: &mut EventMeta, direct: Entity, up: Entity, root: bool) {
     if direct != Entity::null() {
         meta.target = direct;
@@ -683,3 +995,395 @@ fn emit_direct_or_up<M: Any + Send>(
     mutate_direct_or_up(&mut event.meta, direct, up, root);
     cx.emit_custom(event);
 }
+
+// Returns the view which draws the ripples of presses on an entity, which is the entity or its nearest ancestor with a
+// ripple press effect.
+fn ripple_target(cx: &Context, entity: Entity) -> Option<Entity> {
+    entity.parent_iter(&cx.tree).find(|entity| {
+        cx.style.press_effect.get(*entity).copied().unwrap_or_default() == PressEffect::Ripple
+    })
+}
+
+// Starts a ripple from where an entity was pressed with the cursor, in window coordinates, or from the center of the
+// view which draws it for a key press.
+fn press_ripple(cx: &mut Context, entity: Entity, cursor: Option<(f32, f32)>) {
+    let Some(target) = ripple_target(cx, entity) else {
+        return;
+    };
+
+    // The ripple is drawn with the transform of the view, so the cursor is mapped into its untransformed bounds.
+    let center = cursor
+        .and_then(|cursor| {
+            let inverse = cx.cache.transform.get(target)?.invert()?;
+            let point = inverse.map_point(cursor);
+            Some((point.x, point.y))
+        })
+        .unwrap_or_else(|| cx.cache.get_bounds(target).center());
+
+    let expand = !cx.interaction_settings.reduced_motion;
+    cx.style.ripples.press(target, center, Instant::now(), expand);
+    cx.needs_redraw(target);
+}
+
+// Starts fading out the ripples of a press on an entity which has been released.
+fn release_ripple(cx: &mut Context, entity: Entity) {
+    if let Some(target) = ripple_target(cx, entity) {
+        cx.style.ripples.release(target, Instant::now());
+        cx.needs_redraw(target);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Document {
+        unsaved: bool,
+        closed: bool,
+    }
+
+    impl Model for Document {
+        fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+            event.map(|window_event, _| match window_event {
+                WindowEvent::CloseRequested if self.unsaved => cx.emit(WindowEvent::CancelClose),
+                WindowEvent::WindowClose => self.closed = true,
+                _ => {}
+            });
+        }
+    }
+
+    fn is_closed(cx: &Context) -> bool {
+        cx.data::<Document>().map(|document| document.closed).unwrap_or_default()
+    }
+
+    #[test]
+    fn close_request_can_be_cancelled() {
+        let mut cx = Context::default();
+        cx.windows.insert(Entity::root(), WindowState::default());
+        Document { unsaved: true, closed: false }.build(&mut cx);
+
+        let mut event_manager = EventManager::new();
+        EventContext::new(&mut cx).request_quit();
+        event_manager.flush_events(&mut cx, |_| {});
+        assert!(!is_closed(&cx));
+        assert!(!cx.windows[&Entity::root()].close_requested);
+
+        cx.replace_model(Document { unsaved: false, closed: false });
+        EventContext::new(&mut cx).request_close();
+        event_manager.flush_events(&mut cx, |_| {});
+        assert!(is_closed(&cx));
+    }
+
+    // Cancels the close requests of a window from outside of it.
+    struct KeepOpen(Entity);
+
+    impl Model for KeepOpen {
+        fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+            event.map(|window_event, meta| {
+                if let WindowEvent::CloseRequested = window_event {
+                    if meta.origin == self.0 {
+                        cx.emit(WindowEvent::CancelClose);
+                    }
+                }
+            });
+        }
+    }
+
+    #[test]
+    fn cancelling_a_close_only_keeps_the_requesting_window_open() {
+        let mut cx = Context::default();
+        cx.windows.insert(Entity::root(), WindowState::default());
+        let windows: Vec<_> = (0..2)
+            .map(|_| {
+                let window = Element::new(&mut cx).entity();
+                cx.tree.set_window(window, true);
+                cx.windows.insert(window, WindowState::default());
+                cx.with_current(window, |cx| Document { unsaved: false, closed: false }.build(cx));
+                window
+            })
+            .collect();
+        KeepOpen(windows[1]).build(&mut cx);
+
+        // The close is cancelled by a model of the main window, but only for the window whose request it handled.
+        let mut event_manager = EventManager::new();
+        EventContext::new(&mut cx).request_quit();
+        event_manager.flush_events(&mut cx, |_| {});
+        let closed = |cx: &mut Context, window| cx.with_current(window, |cx| is_closed(cx));
+        assert!(closed(&mut cx, windows[0]));
+        assert!(!closed(&mut cx, windows[1]));
+        assert!(!cx.windows[&windows[1]].close_requested);
+
+        // Outside of a close request, the request of the window containing the target is cancelled.
+        for window in &windows {
+            let document = Document { unsaved: false, closed: false };
+            cx.with_current(*window, |cx| cx.replace_model(document));
+            cx.windows.get_mut(window).unwrap().close_requested = true;
+        }
+        let content = cx.with_current(windows[1], |cx| Element::new(cx).entity());
+        cx.emit_custom(Event::new(WindowEvent::CancelClose).target(content).origin(content));
+        event_manager.flush_events(&mut cx, |_| {});
+        assert!(closed(&mut cx, windows[0]));
+        assert!(!closed(&mut cx, windows[1]));
+    }
+
+    fn emit_window_event(cx: &mut Context, event_manager: &mut EventManager, event: WindowEvent) {
+        cx.emit_custom(
+            Event::new(event)
+                .target(Entity::root())
+                .origin(Entity::root())
+                .propagate(Propagation::Up),
+        );
+        event_manager.flush_events(cx, |_| {});
+    }
+
+    fn is_hovered(cx: &Context, entity: Entity) -> bool {
+        cx.style
+            .pseudo_classes
+            .get(entity)
+            .is_some_and(|flags| flags.contains(PseudoClassFlags::HOVER))
+    }
+
+    fn build_window(cx: &mut Context) {
+        cx.windows.insert(Entity::root(), WindowState::default());
+        cx.style.dpi_factor = 1.0;
+        cx.style.width.insert(Entity::root(), Units::Pixels(800.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(600.0));
+        cx.style.pseudo_classes.insert(Entity::root(), PseudoClassFlags::OVER);
+    }
+
+    fn build_button(cx: &mut Context) -> Entity {
+        build_window(cx);
+        let button = Element::new(cx).size(Pixels(100.0)).entity();
+        crate::systems::layout_system(cx);
+        button
+    }
+
+    enum ClickEvent {
+        Clicked(usize),
+    }
+
+    #[derive(Default)]
+    struct Clicks(Vec<usize>);
+
+    impl Model for Clicks {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|ClickEvent::Clicked(index), _| self.0.push(*index));
+        }
+    }
+
+    #[test]
+    fn non_interactive_overlay_passes_clicks_to_views_below() {
+        let mut cx = Context::default();
+        build_window(&mut cx);
+        Clicks::default().build(&mut cx);
+
+        let buttons: Vec<_> = (0..2)
+            .map(|index| {
+                Button::new(&mut cx, Element::new)
+                    .size(Pixels(100.0))
+                    .on_press(move |cx| cx.emit(ClickEvent::Clicked(index)))
+                    .entity()
+            })
+            .collect();
+
+        // A full-window overlay in a higher z-index, whose only interactive content is a badge in its corner.
+        let mut badge = Entity::null();
+        Element::new(&mut cx)
+            .position_type(PositionType::Absolute)
+            .size(Stretch(1.0))
+            .z_index(1)
+            .pointer_events(PointerEvents::None);
+        VStack::new(&mut cx, |cx| {
+            badge = Element::new(cx)
+                .position_type(PositionType::Absolute)
+                .left(Pixels(700.0))
+                .top(Pixels(0.0))
+                .size(Pixels(50.0))
+                .pointer_events(PointerEvents::Auto)
+                .entity();
+        })
+        .position_type(PositionType::Absolute)
+        .size(Stretch(1.0))
+        .z_index(2)
+        .pointer_events(PointerEvents::None);
+        crate::systems::style_system(&mut cx);
+        crate::systems::layout_system(&mut cx);
+        crate::systems::transform_system(&mut cx);
+
+        let mut event_manager = EventManager::new();
+        for (index, button) in buttons.iter().enumerate() {
+            let y = 50.0 + 100.0 * index as f32;
+            emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseMove(50.0, y));
+            assert_eq!(cx.hovered, *button);
+            assert!(is_hovered(&cx, *button));
+
+            emit_window_event(
+                &mut cx,
+                &mut event_manager,
+                WindowEvent::MouseDown(MouseButton::Left),
+            );
+            emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseUp(MouseButton::Left));
+        }
+        assert_eq!(cx.data::<Clicks>().unwrap().0, vec![0, 1]);
+
+        // Content of an overlay which opts back into pointer events is still hovered over the views below it.
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseMove(725.0, 25.0));
+        assert_eq!(cx.hovered, badge);
+
+        // Where the overlays have no interactive content the window is hovered.
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseMove(400.0, 400.0));
+        assert_eq!(cx.hovered, Entity::root());
+    }
+
+    #[test]
+    fn mouse_hover_persists_after_release() {
+        let mut cx = Context::default();
+        let button = build_button(&mut cx);
+
+        let mut event_manager = EventManager::new();
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseMove(50.0, 50.0));
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseDown(MouseButton::Left));
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseUp(MouseButton::Left));
+
+        assert_eq!(cx.primary_pointer_type(), PointerType::Mouse);
+        assert_eq!(cx.hovered, button);
+        assert!(is_hovered(&cx, button));
+    }
+
+    #[test]
+    fn touch_hovers_only_while_pressed() {
+        let mut cx = Context::default();
+        let button = build_button(&mut cx);
+
+        let mut event_manager = EventManager::new();
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseMove(50.0, 50.0));
+        assert!(is_hovered(&cx, button));
+
+        // Switching to touch removes the hover left by the mouse.
+        let touch = WindowEvent::PointerTypeChanged(PointerType::Touch);
+        emit_window_event(&mut cx, &mut event_manager, touch);
+        assert_eq!(cx.primary_pointer_type(), PointerType::Touch);
+        assert!(!is_hovered(&cx, button));
+
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseMove(50.0, 50.0));
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseDown(MouseButton::Left));
+        let active = cx.style.pseudo_classes.get(button).copied().unwrap_or_default();
+        assert!(active.contains(PseudoClassFlags::ACTIVE | PseudoClassFlags::HOVER));
+
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseUp(MouseButton::Left));
+        let released = cx.style.pseudo_classes.get(button).copied().unwrap_or_default();
+        assert!(!released.intersects(
+            PseudoClassFlags::ACTIVE | PseudoClassFlags::HOVER | PseudoClassFlags::OVER
+        ));
+        assert_eq!(cx.hovered, Entity::null());
+
+        // The mouse hovers views again once it is used.
+        let mouse = WindowEvent::PointerTypeChanged(PointerType::Mouse);
+        emit_window_event(&mut cx, &mut event_manager, mouse);
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseMove(60.0, 60.0));
+        assert!(is_hovered(&cx, button));
+    }
+
+    #[test]
+    fn presses_start_overlapping_ripples_from_the_cursor() {
+        let mut cx = Context::default();
+        let button = build_button(&mut cx);
+        cx.style.press_effect.insert(button, PressEffect::Ripple);
+        crate::systems::transform_system(&mut cx);
+
+        let mut event_manager = EventManager::new();
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseMove(30.0, 40.0));
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseDown(MouseButton::Left));
+        let ripples = cx.style.ripples.get(button).unwrap();
+        assert_eq!(ripples.len(), 1);
+        assert_eq!(ripples[0].center, (30.0, 40.0));
+        assert!(!ripples[0].is_released());
+
+        // A second press before the first ripple has faded out overlaps it.
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseUp(MouseButton::Left));
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseMove(60.0, 70.0));
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseDown(MouseButton::Left));
+        let ripples = cx.style.ripples.get(button).unwrap();
+        assert_eq!(ripples.len(), 2);
+        assert!(ripples[0].is_released());
+        assert_eq!(ripples[1].center, (60.0, 70.0));
+
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseUp(MouseButton::Left));
+        assert!(cx.style.ripples.get(button).unwrap().iter().all(|ripple| ripple.is_released()));
+    }
+
+    #[test]
+    fn key_presses_ripple_from_the_center() {
+        let mut cx = Context::default();
+        let button = build_button(&mut cx);
+        cx.style.press_effect.insert(button, PressEffect::Ripple);
+        cx.interaction_settings.reduced_motion = true;
+        cx.focused = button;
+        crate::systems::transform_system(&mut cx);
+
+        // Holding the key down only starts one ripple.
+        let mut event_manager = EventManager::new();
+        let enter = WindowEvent::KeyDown(Code::Enter, None);
+        emit_window_event(&mut cx, &mut event_manager, enter.clone());
+        emit_window_event(&mut cx, &mut event_manager, enter);
+        let ripples = cx.style.ripples.get(button).unwrap();
+        assert_eq!(ripples.len(), 1);
+        assert_eq!(ripples[0].center, (50.0, 50.0));
+
+        // With reduced motion the ripple fills the view rather than expanding.
+        assert_eq!(ripples[0].frame(Instant::now(), Duration::from_secs(3600)).0, 1.0);
+
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::KeyUp(Code::Enter, None));
+        assert!(cx.style.ripples.get(button).unwrap()[0].is_released());
+    }
+
+    #[test]
+    fn overflow_scrolls_with_the_wheel_scroll_events_and_scrollbars() {
+        let mut cx = Context::default();
+        build_window(&mut cx);
+        let mut rows = Vec::new();
+        let list = VStack::new(&mut cx, |cx| {
+            for _ in 0..10 {
+                rows.push(Element::new(cx).height(Pixels(50.0)).entity());
+            }
+        })
+        .size(Pixels(200.0))
+        .overflow(Overflow::Scroll)
+        .entity();
+        crate::systems::layout_system(&mut cx);
+        crate::systems::transform_system(&mut cx);
+        let offset = |cx: &Context| scroll_offset(&cx.style, &cx.cache, &cx.tree, list);
+
+        let mut event_manager = EventManager::new();
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseMove(50.0, 25.0));
+        assert_eq!(cx.hovered, rows[0]);
+
+        // The wheel scrolls the rows, which are hit where they're drawn once they're scrolled.
+        let settings = &cx.interaction_settings;
+        let tick = settings.scroll_speed.tick_distance(settings.scroll_line_height);
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseScroll(0.0, -1.0));
+        assert_eq!(offset(&cx), (0.0, tick));
+        crate::systems::transform_system(&mut cx);
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseMove(50.0, 25.0));
+        assert_eq!(cx.hovered, rows[((25.0 + tick) / 50.0) as usize]);
+
+        cx.emit_to(list, ScrollEvent::SetY(1.0));
+        event_manager.flush_events(&mut cx, |_| {});
+        assert_eq!(offset(&cx), (0.0, 300.0));
+
+        // The thumb of the vertical scrollbar is at the bottom of its track, and dragging it up by half of the space
+        // above it scrolls back by half of the range.
+        let thumb = scrollbars(&cx.style, &cx.cache, &cx.tree, list)[1].thumb;
+        let (x, y) = thumb.center();
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseMove(x, y));
+        assert_eq!(cx.hovered, list);
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseDown(MouseButton::Left));
+        assert_eq!(cx.captured, list);
+        let up = (thumb.top() - 2.0) / 2.0;
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseMove(x, y - up));
+        assert!((offset(&cx).1 - 150.0).abs() < 0.01);
+
+        emit_window_event(&mut cx, &mut event_manager, WindowEvent::MouseUp(MouseButton::Left));
+        assert_eq!(cx.captured, Entity::null());
+    }
+}
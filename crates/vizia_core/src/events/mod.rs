@@ -98,4 +98,11 @@ mod timer;
 pub(crate) use timer::TimerState;
 pub use timer::{Timer, TimerAction};
 
+mod rate_limit;
+pub use rate_limit::{debounce, throttle};
+
+mod subscription;
+pub use subscription::Subscription;
+pub(crate) use subscription::{Publication, Subscriptions};
+
 pub use crate::window::WindowEvent;
@@ -0,0 +1,269 @@
+use std::sync::{Arc, Mutex};
+
+use web_time::Duration;
+
+use crate::context::EventContext;
+use crate::events::{Timer, TimerAction};
+
+// The state shared between a rate limited callback and the timer which calls it.
+struct RateLimit<T> {
+    timer: Option<Timer>,
+    // The latest value which the callback hasn't been called with yet.
+    pending: Option<T>,
+}
+
+impl<T> RateLimit<T> {
+    fn new() -> Arc<Mutex<Self>> {
+        Arc::new(Mutex::new(RateLimit { timer: None, pending: None }))
+    }
+}
+
+/// Wraps a callback so that it is only called once the wrapper hasn't been called for the given quiet period,
+/// with the latest value it was called with.
+///
+/// The wrapper can be passed to any callback modifier which provides a value, such as the `on_edit` callback of a
+/// [`Textbox`](crate::views::Textbox), and runs on a timer which is cancelled if the view which called it is removed.
+///
+/// # Example
+/// Searches once the user has stopped typing for 300 milliseconds, rather than on every key press:
+/// ```
+/// # use vizia_core::prelude::*;
+/// # #[derive(Lens)]
+/// # struct AppData {
+/// #     query: String,
+/// # }
+/// # impl Model for AppData {}
+/// # enum AppEvent {
+/// #     Search(String),
+/// # }
+/// # let cx = &mut Context::default();
+/// # AppData { query: String::new() }.build(cx);
+/// Textbox::new(cx, AppData::query).on_edit(debounce(Duration::from_millis(300), |cx, text| {
+///     cx.emit(AppEvent::Search(text));
+/// }));
+/// ```
+pub fn debounce<T, F>(
+    quiet_period: Duration,
+    callback: F,
+) -> impl Fn(&mut EventContext, T) + Send + Sync
+where
+    T: Send + 'static,
+    F: Fn(&mut EventContext, T) + Send + Sync + 'static,
+{
+    let state = RateLimit::new();
+    let callback = Arc::new(callback);
+
+    move |cx, value| {
+        let callback = callback.clone();
+        let timer =
+            get_or_add_timer(cx, &state, quiet_period, move |state, cx, action| match action {
+                TimerAction::Start => {}
+
+                TimerAction::Tick(_) => {
+                    let (timer, pending) = {
+                        let mut state = state.lock().unwrap();
+                        (state.timer, state.pending.take())
+                    };
+
+                    if let Some(timer) = timer {
+                        cx.stop_timer(timer);
+                    }
+
+                    if let Some(value) = pending {
+                        (callback)(cx, value);
+                    }
+                }
+
+                TimerAction::Stop => {
+                    state.lock().unwrap().pending = None;
+                }
+            });
+
+        // Restarting the timer delays the call until the quiet period has passed again.
+        cx.stop_timer(timer);
+        state.lock().unwrap().pending = Some(value);
+        cx.start_timer(timer);
+    }
+}
+
+/// Wraps a callback so that it is called at most once per interval.
+///
+/// The first call of the wrapper calls the callback immediately. Further calls within the interval are held back, and
+/// at the end of the interval the callback is called with the latest value the wrapper was called with.
+///
+/// Like [`debounce`], the wrapper can be passed to any callback modifier which provides a value, and runs on a timer
+/// which is cancelled if the view which called it is removed.
+///
+/// # Example
+/// Updates a preview at most every 50 milliseconds while a slider is dragged:
+/// ```
+/// # use vizia_core::prelude::*;
+/// # #[derive(Lens)]
+/// # struct AppData {
+/// #     value: f32,
+/// # }
+/// # impl Model for AppData {}
+/// # enum AppEvent {
+/// #     UpdatePreview(f32),
+/// # }
+/// # let cx = &mut Context::default();
+/// # AppData { value: 0.0 }.build(cx);
+/// Slider::new(cx, AppData::value).on_changing(throttle(Duration::from_millis(50), |cx, value| {
+///     cx.emit(AppEvent::UpdatePreview(value));
+/// }));
+/// ```
+pub fn throttle<T, F>(
+    interval: Duration,
+    callback: F,
+) -> impl Fn(&mut EventContext, T) + Send + Sync
+where
+    T: Send + 'static,
+    F: Fn(&mut EventContext, T) + Send + Sync + 'static,
+{
+    let state = RateLimit::new();
+    let callback = Arc::new(callback);
+
+    move |cx, value| {
+        let timer = {
+            let callback = callback.clone();
+            get_or_add_timer(cx, &state, interval, move |state, cx, action| match action {
+                TimerAction::Start => {}
+
+                // Values held back during the interval are passed on at its end, which starts a new interval.
+                TimerAction::Tick(_) => {
+                    let (timer, pending) = {
+                        let mut state = state.lock().unwrap();
+                        (state.timer, state.pending.take())
+                    };
+
+                    if let Some(value) = pending {
+                        (callback)(cx, value);
+                    } else if let Some(timer) = timer {
+                        cx.stop_timer(timer);
+                    }
+                }
+
+                TimerAction::Stop => {
+                    state.lock().unwrap().pending = None;
+                }
+            })
+        };
+
+        if cx.timer_is_running(timer) {
+            state.lock().unwrap().pending = Some(value);
+        } else {
+            cx.start_timer(timer);
+            (callback)(cx, value);
+        }
+    }
+}
+
+// Returns the timer of a rate limited callback, adding it the first time the callback is called.
+fn get_or_add_timer<T: 'static>(
+    cx: &mut EventContext,
+    state: &Arc<Mutex<RateLimit<T>>>,
+    interval: Duration,
+    on_timer: impl Fn(&Mutex<RateLimit<T>>, &mut EventContext, TimerAction) + 'static,
+) -> Timer {
+    if let Some(timer) = state.lock().unwrap().timer {
+        return timer;
+    }
+
+    let timer_state = state.clone();
+    let timer =
+        cx.add_timer(interval, None, move |cx, action| (on_timer)(&timer_state, cx, action));
+    state.lock().unwrap().timer = Some(timer);
+    timer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // Records the values a rate limited callback was called with.
+    fn recorder() -> (Arc<Mutex<Vec<u32>>>, impl Fn(&mut EventContext, u32) + Send + Sync) {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let recorded = calls.clone();
+        (calls, move |_: &mut EventContext, value| recorded.lock().unwrap().push(value))
+    }
+
+    fn call(cx: &mut Context, entity: Entity, f: &impl Fn(&mut EventContext, u32), value: u32) {
+        f(&mut EventContext::new_with_current(cx, entity), value);
+    }
+
+    // Ticks each running timer once, as if its interval had passed.
+    fn advance(cx: &mut Context) {
+        let now = Instant::now();
+        let mut running_timers = std::mem::take(&mut cx.running_timers).into_vec();
+        for timer_state in running_timers.iter_mut() {
+            timer_state.time = timer_state.time.min(now);
+        }
+        cx.running_timers = running_timers.into();
+        cx.tick_timers();
+    }
+
+    #[test]
+    fn debounce_calls_once_with_the_latest_value() {
+        let mut cx = Context::default();
+        let entity = Element::new(&mut cx).entity();
+        let (calls, callback) = recorder();
+        let debounced = debounce(Duration::from_millis(300), callback);
+
+        for value in 1..=3 {
+            call(&mut cx, entity, &debounced, value);
+            advance(&mut cx);
+        }
+        assert!(calls.lock().unwrap().is_empty());
+
+        advance(&mut cx);
+        assert_eq!(*calls.lock().unwrap(), vec![3]);
+
+        advance(&mut cx);
+        advance(&mut cx);
+        assert_eq!(*calls.lock().unwrap(), vec![3]);
+    }
+
+    #[test]
+    fn throttle_calls_on_the_leading_and_trailing_edges() {
+        let mut cx = Context::default();
+        let entity = Element::new(&mut cx).entity();
+        let (calls, callback) = recorder();
+        let throttled = throttle(Duration::from_millis(50), callback);
+
+        for value in 1..=3 {
+            call(&mut cx, entity, &throttled, value);
+        }
+        assert_eq!(*calls.lock().unwrap(), vec![1]);
+
+        // The first tick starts the timer and the second ends the interval.
+        advance(&mut cx);
+        advance(&mut cx);
+        assert_eq!(*calls.lock().unwrap(), vec![1, 3]);
+
+        // An interval without calls stops the timer, so the next call is passed on immediately.
+        advance(&mut cx);
+        call(&mut cx, entity, &throttled, 4);
+        assert_eq!(*calls.lock().unwrap(), vec![1, 3, 4]);
+    }
+
+    #[test]
+    fn pending_calls_are_cancelled_when_the_view_is_removed() {
+        let mut cx = Context::default();
+        let entity = Element::new(&mut cx).entity();
+        let count = Arc::new(AtomicUsize::new(0));
+        let counter = count.clone();
+        let debounced = debounce(Duration::from_millis(300), move |_, _: u32| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        call(&mut cx, entity, &debounced, 1);
+        advance(&mut cx);
+        cx.remove(entity);
+        advance(&mut cx);
+        advance(&mut cx);
+
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+    }
+}
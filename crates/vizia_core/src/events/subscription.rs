@@ -0,0 +1,295 @@
+use std::any::{Any, TypeId};
+use std::rc::Rc;
+
+use hashbrown::HashMap;
+
+use crate::prelude::*;
+
+/// A handle to a subscription added with [`subscribe`](Context::subscribe), which can be used to unsubscribe.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct Subscription(pub usize);
+
+type SubscriptionHandler = Rc<dyn Fn(&mut EventContext, &dyn Any)>;
+
+struct SubscriptionState {
+    id: Subscription,
+    entity: Entity,
+    scope: Option<Entity>,
+    handler: SubscriptionHandler,
+}
+
+/// The subscriptions of an application, grouped by topic in the order they were added.
+#[derive(Default)]
+pub(crate) struct Subscriptions {
+    next_id: usize,
+    topics: HashMap<TypeId, Vec<SubscriptionState>>,
+}
+
+impl Subscriptions {
+    pub(crate) fn add<T: 'static>(
+        &mut self,
+        entity: Entity,
+        scope: Option<Entity>,
+        handler: impl 'static + Fn(&mut EventContext, &T),
+    ) -> Subscription {
+        let id = Subscription(self.next_id);
+        self.next_id += 1;
+
+        self.topics.entry(TypeId::of::<T>()).or_default().push(SubscriptionState {
+            id,
+            entity,
+            scope,
+            handler: Rc::new(move |cx, topic| {
+                if let Some(topic) = topic.downcast_ref::<T>() {
+                    (handler)(cx, topic);
+                }
+            }),
+        });
+
+        id
+    }
+
+    pub(crate) fn remove(&mut self, subscription: Subscription) {
+        for subscriptions in self.topics.values_mut() {
+            subscriptions.retain(|state| state.id != subscription);
+        }
+    }
+
+    /// Removes the subscriptions of an entity which has been removed.
+    pub(crate) fn remove_entity(&mut self, entity: Entity) {
+        for subscriptions in self.topics.values_mut() {
+            subscriptions.retain(|state| state.entity != entity);
+        }
+    }
+
+    pub(crate) fn contains_entity(&self, entity: Entity) -> bool {
+        self.topics.values().flatten().any(|state| state.entity == entity)
+    }
+
+    fn contains(&self, subscription: Subscription) -> bool {
+        self.topics.values().flatten().any(|state| state.id == subscription)
+    }
+}
+
+/// The message of an event which publishes a topic to its subscribers.
+pub(crate) struct Publication {
+    topic: TypeId,
+    payload: Box<dyn Any + Send>,
+}
+
+impl Publication {
+    pub(crate) fn new<T: Any + Send>(topic: T) -> Self {
+        Self { topic: TypeId::of::<T>(), payload: Box::new(topic) }
+    }
+
+    /// Calls the handlers of the subscribers to the topic, in the order they subscribed.
+    ///
+    /// The subscribers are collected before any handler is called, so a subscription added by a handler only
+    /// receives later publications, while a subscription removed by a handler, or whose entity was removed, is
+    /// skipped.
+    pub(crate) fn deliver(self, cx: &mut Context, origin: Entity) {
+        let Some(subscriptions) = cx.subscriptions.topics.get(&self.topic) else {
+            return;
+        };
+
+        let subscribers = subscriptions
+            .iter()
+            .filter(|state| {
+                state.scope.map_or(true, |scope| origin.is_descendant_of(&cx.tree, scope))
+            })
+            .map(|state| (state.id, state.entity, state.handler.clone()))
+            .collect::<Vec<_>>();
+
+        for (id, entity, handler) in subscribers {
+            if !cx.subscriptions.contains(id) || !cx.entity_manager.is_alive(entity) {
+                continue;
+            }
+
+            cx.with_current(entity, |cx| {
+                (handler)(&mut EventContext::new(cx), self.payload.as_ref())
+            });
+        }
+    }
+}
+
+impl Context {
+    /// Subscribes an entity to a topic, calling the handler with the topic whenever it's published with
+    /// [`publish`](Context::publish) anywhere in the application.
+    ///
+    /// Topics are delivered during event processing, in the order the subscriptions were added, with the
+    /// subscribing entity as the current entity, so events emitted by the handler propagate up from the subscriber.
+    /// The subscription is removed when the entity is removed.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// # enum TableEvent { Highlight(usize) }
+    /// struct SelectionChanged(usize);
+    ///
+    /// let table = Element::new(cx).entity();
+    /// cx.subscribe::<SelectionChanged>(table, |cx, selection| {
+    ///     cx.emit(TableEvent::Highlight(selection.0));
+    /// });
+    ///
+    /// cx.publish(SelectionChanged(3));
+    /// ```
+    pub fn subscribe<T: 'static>(
+        &mut self,
+        entity: Entity,
+        handler: impl 'static + Fn(&mut EventContext, &T),
+    ) -> Subscription {
+        self.subscriptions.add(entity, None, handler)
+    }
+
+    /// Subscribes an entity to a topic like [`subscribe`](Context::subscribe), but only receives the topic when it's
+    /// published by the `scope` entity or one of its descendants.
+    pub fn subscribe_scoped<T: 'static>(
+        &mut self,
+        entity: Entity,
+        scope: Entity,
+        handler: impl 'static + Fn(&mut EventContext, &T),
+    ) -> Subscription {
+        self.subscriptions.add(entity, Some(scope), handler)
+    }
+
+    /// Removes a subscription, so its handler is no longer called, including for topics which have already been
+    /// published.
+    pub fn unsubscribe(&mut self, subscription: Subscription) {
+        self.subscriptions.remove(subscription);
+    }
+
+    /// Publishes a topic from the current entity to its subscribers.
+    ///
+    /// The topic is delivered by reference to each subscriber when the event queue is next processed.
+    pub fn publish<T: Any + Send>(&mut self, topic: T) {
+        self.emit_custom(
+            Event::new(Publication::new(topic))
+                .target(self.current)
+                .origin(self.current)
+                .propagate(Propagation::Direct),
+        );
+    }
+}
+
+impl EventContext<'_> {
+    /// Subscribes an entity to a topic. See [`Context::subscribe`].
+    pub fn subscribe<T: 'static>(
+        &mut self,
+        entity: Entity,
+        handler: impl 'static + Fn(&mut EventContext, &T),
+    ) -> Subscription {
+        self.subscriptions.add(entity, None, handler)
+    }
+
+    /// Subscribes an entity to a topic published within a subtree. See [`Context::subscribe_scoped`].
+    pub fn subscribe_scoped<T: 'static>(
+        &mut self,
+        entity: Entity,
+        scope: Entity,
+        handler: impl 'static + Fn(&mut EventContext, &T),
+    ) -> Subscription {
+        self.subscriptions.add(entity, Some(scope), handler)
+    }
+
+    /// Removes a subscription. See [`Context::unsubscribe`].
+    pub fn unsubscribe(&mut self, subscription: Subscription) {
+        self.subscriptions.remove(subscription);
+    }
+
+    /// Publishes a topic from the current entity to its subscribers. See [`Context::publish`].
+    pub fn publish<T: Any + Send>(&mut self, topic: T) {
+        self.emit_custom(
+            Event::new(Publication::new(topic))
+                .target(self.current)
+                .origin(self.current)
+                .propagate(Propagation::Direct),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    use crate::events::EventManager;
+    use crate::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Selection(usize);
+
+    type Log = Rc<RefCell<Vec<(&'static str, usize)>>>;
+
+    fn subscribe(cx: &mut Context, entity: Entity, name: &'static str, log: &Log) -> Subscription {
+        let log = log.clone();
+        cx.subscribe::<Selection>(entity, move |_, selection| {
+            log.borrow_mut().push((name, selection.0))
+        })
+    }
+
+    #[test]
+    fn subscribers_receive_topics_in_order() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+        let log = Log::default();
+
+        let first = Element::new(&mut cx).entity();
+        let second = Element::new(&mut cx).entity();
+        let third = Element::new(&mut cx).entity();
+        let publisher = Element::new(&mut cx).entity();
+
+        // Removing a subscription while a topic is delivered skips it.
+        let second_subscription = Rc::new(Cell::new(None));
+        subscribe(&mut cx, first, "first", &log);
+        let unsubscribe = second_subscription.clone();
+        cx.subscribe::<Selection>(first, move |cx, _| {
+            if let Some(subscription) = unsubscribe.take() {
+                cx.unsubscribe(subscription);
+            }
+        });
+        second_subscription.set(Some(subscribe(&mut cx, second, "second", &log)));
+        subscribe(&mut cx, third, "third", &log);
+
+        cx.with_current(publisher, |cx| cx.publish(Selection(1)));
+        event_manager.flush_events(&mut cx, |_| {});
+        assert_eq!(*log.borrow(), [("first", 1), ("third", 1)]);
+
+        // Subscriptions are removed with their entity, including for topics already published.
+        log.borrow_mut().clear();
+        cx.with_current(publisher, |cx| cx.publish(Selection(2)));
+        cx.remove(third);
+        assert!(!cx.subscriptions.contains_entity(third));
+        event_manager.flush_events(&mut cx, |_| {});
+        assert_eq!(*log.borrow(), [("first", 2)]);
+    }
+
+    #[test]
+    fn topics_published_by_handlers_are_delivered_afterwards() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+        let log = Log::default();
+
+        let panel = VStack::new(&mut cx, |_| {}).entity();
+        let inside = cx.with_current(panel, |cx| Element::new(cx).entity());
+        let outside = Element::new(&mut cx).entity();
+
+        // The first subscriber republishes the topic, which is delivered to every subscriber once the current
+        // publication has been delivered.
+        cx.subscribe::<Selection>(outside, |cx, selection| {
+            if selection.0 < 2 {
+                cx.publish(Selection(selection.0 + 1));
+            }
+        });
+        subscribe(&mut cx, outside, "outside", &log);
+
+        let scoped = log.clone();
+        cx.subscribe_scoped::<Selection>(inside, panel, move |_, selection| {
+            scoped.borrow_mut().push(("scoped", selection.0))
+        });
+
+        cx.with_current(inside, |cx| cx.publish(Selection(0)));
+        event_manager.flush_events(&mut cx, |_| {});
+        assert_eq!(*log.borrow(), [("outside", 0), ("scoped", 0), ("outside", 1), ("outside", 2)]);
+    }
+}
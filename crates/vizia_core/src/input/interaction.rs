@@ -0,0 +1,58 @@
+use crate::prelude::Data;
+use web_time::Duration;
+
+/// Timings and distances used to interpret user input, such as the maximum interval between the clicks of a
+/// double click.
+///
+/// Backends populate the settings from the platform where possible, falling back to the [`Default`] values. An
+/// application can override them with [`Context::interaction_settings_mut`](crate::context::Context::interaction_settings_mut).
+/// Built-in views read the settings when handling input, so any changes apply to all views.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InteractionSettings {
+    /// The maximum time between two clicks for them to count as a double click. Defaults to 500ms.
+    pub double_click_interval: Duration,
+    /// The time the text caret is shown for, and then hidden for, while it blinks. Defaults to 530ms.
+    pub caret_blink_interval: Duration,
+    /// The distance in logical pixels the cursor must move while pressed before a drag starts. Defaults to 4px.
+    pub drag_threshold: f32,
+    /// The distance in logical pixels scrolled by one line of a mouse wheel. Defaults to 20px.
+    pub scroll_line_height: f32,
+    /// The distance scrolled by one tick of a mouse wheel, which scroll views can override. Defaults to one line.
+    pub scroll_speed: ScrollSpeed,
+    /// Whether to reduce motion, such as the focus ring scaling in around a newly focused view. Defaults to false.
+    pub reduced_motion: bool,
+}
+
+impl Default for InteractionSettings {
+    fn default() -> Self {
+        Self {
+            double_click_interval: Duration::from_millis(500),
+            caret_blink_interval: Duration::from_millis(530),
+            drag_threshold: 4.0,
+            scroll_line_height: 20.0,
+            scroll_speed: ScrollSpeed::Lines(1.0),
+            reduced_motion: false,
+        }
+    }
+}
+
+/// The distance scrolled by one tick of a mouse wheel.
+///
+/// The speed doesn't apply to precise devices such as trackpads, which scroll by the distance they report.
+#[derive(Debug, Clone, Copy, PartialEq, Data)]
+pub enum ScrollSpeed {
+    /// A number of lines, each the scroll line height of the [`InteractionSettings`].
+    Lines(f32),
+    /// A distance in logical pixels.
+    Pixels(f32),
+}
+
+impl ScrollSpeed {
+    /// Returns the distance in logical pixels scrolled by one tick, given the height of a line.
+    pub fn tick_distance(self, line_height: f32) -> f32 {
+        match self {
+            ScrollSpeed::Lines(lines) => lines * line_height,
+            ScrollSpeed::Pixels(pixels) => pixels,
+        }
+    }
+}
@@ -6,4 +6,9 @@ pub use keymap::*;
 mod entry;
 pub use entry::*;
 
-pub use vizia_input::{Code, Key, Modifiers, MouseButton, MouseButtonData, MouseState};
+mod interaction;
+pub use interaction::*;
+
+pub use vizia_input::{
+    Code, Key, Modifiers, MouseButton, MouseButtonData, MouseState, PointerType, ScrollUnit,
+};
@@ -0,0 +1,92 @@
+use crate::prelude::*;
+use crate::text::{TextContext, TextLayout};
+use morphorm::Node;
+
+/// Returns the distance in physical pixels from the top of a view with the given bounds to its first baseline.
+///
+/// The baseline is given by the `baseline-offset` property when it is set, and otherwise by the first line of the
+/// text of the view. Views without text use their bottom edge as their baseline.
+pub(crate) fn baseline(
+    style: &Style,
+    text_context: &TextContext,
+    entity: Entity,
+    bounds: BoundingBox,
+) -> f32 {
+    let height = bounds.h;
+    if let Some(offset) = baseline_offset(style, entity, height) {
+        return offset;
+    }
+
+    let Some(text) = text_context.text_layout(entity) else {
+        return height;
+    };
+
+    // The text is positioned within the view in the same way as when it is drawn.
+    let (_, inset_top, _, inset_bottom) = content_insets(style, entity, bounds);
+    inset_top
+        + text_top(style, entity, text, height, inset_top, inset_bottom)
+        + text.alphabetic_baseline()
+}
+
+/// Returns the space in physical pixels between the edges of a view with the given bounds and its content, such as
+/// its text, which is taken by its padding and border, in the order left, top, right, bottom.
+///
+/// The lengths are resolved in the same way as during layout, with percentages relative to the size of the view
+/// along the same axis and stretch units taking no space.
+pub(crate) fn content_insets(
+    style: &Style,
+    entity: Entity,
+    bounds: BoundingBox,
+) -> (f32, f32, f32, f32) {
+    let inset = |padding: Option<Units>, border: Option<Units>, size: f32| {
+        padding.map_or(0.0, |padding| padding.to_px(size, 0.0))
+            + border.map_or(0.0, |border| border.to_px(size, 0.0))
+    };
+
+    (
+        inset(entity.padding_left(style), entity.border_left(style), bounds.w),
+        inset(entity.padding_top(style), entity.border_top(style), bounds.h),
+        inset(entity.padding_right(style), entity.border_right(style), bounds.w),
+        inset(entity.padding_bottom(style), entity.border_bottom(style), bounds.h),
+    )
+}
+
+// Returns the distance in physical pixels from the top of a view to the baseline given by its `baseline-offset`.
+fn baseline_offset(style: &Style, entity: Entity, height: f32) -> Option<f32> {
+    style.baseline_offset.get(entity).map(|baseline_offset| match *baseline_offset {
+        Units::Pixels(val) => val * style.scale_factor(),
+        Units::Percentage(val) => height * val / 100.0,
+        _ => height,
+    })
+}
+
+/// Returns the distance in physical pixels from the top of the content of a view, inside of its top padding, to the
+/// top of its text.
+///
+/// The text is placed by the `vertical-align` property when it is set, and otherwise by the vertical part of the
+/// alignment of the view.
+pub(crate) fn text_top(
+    style: &Style,
+    entity: Entity,
+    text: &dyn TextLayout,
+    height: f32,
+    padding_top: f32,
+    padding_bottom: f32,
+) -> f32 {
+    let factor = match style.vertical_align.get(entity) {
+        Some(VerticalAlign::Top) => 0.0,
+        Some(VerticalAlign::Center) => 0.5,
+        Some(VerticalAlign::Bottom) => 1.0,
+        Some(VerticalAlign::Baseline) => {
+            return baseline_offset(style, entity, height)
+                .map_or(0.0, |offset| offset - padding_top - text.alphabetic_baseline());
+        }
+        None => match style.alignment.get(entity).copied().unwrap_or_default() {
+            Alignment::TopLeft | Alignment::TopCenter | Alignment::TopRight => 0.0,
+            Alignment::Left | Alignment::Center | Alignment::Right => 0.5,
+            Alignment::BottomLeft | Alignment::BottomCenter | Alignment::BottomRight => 1.0,
+        },
+    };
+
+    factor * (height - padding_top - padding_bottom - text.height())
+}
@@ -27,6 +27,7 @@
 //! - `Auto` - The spacing is determined by the corresponding `child_space` of the parent. So `left` would be determined by the parent `padding_left` etc.
 //!
 //! # Child Space
+pub(crate) mod baseline;
 pub(crate) mod cache;
 pub(crate) mod node;
 
@@ -4,13 +4,39 @@ use vizia_storage::MorphormChildIter;
 
 use crate::prelude::*;
 use crate::resource::{ImageOrSvg, ResourceManager};
-use crate::text::TextContext;
+use crate::style::LayoutAxis;
+use crate::text::{MeasureKey, TextContext, TextMeasurement};
 
 pub struct SubLayout<'a> {
     pub text_context: &'a mut TextContext,
     pub resource_manager: &'a ResourceManager,
 }
 
+// Returns the axis of a view whose size is derived from the size of the other axis with the aspect ratio of the view,
+// along with the ratio. The derived axis is the one with an auto size, or the one with a stretch size if the other has
+// a fixed size, and the ratio is ignored if both sizes are fixed or both stretch.
+fn aspect_ratio_axis(entity: Entity, store: &Style) -> Option<(LayoutAxis, f32)> {
+    let ratio = store.aspect_ratio.get(entity).and_then(AspectRatio::ratio)?;
+
+    let width = store
+        .layout_units(entity, &store.width, &store.layout_calc.width, LayoutAxis::Horizontal, false)
+        .unwrap_or(Stretch(1.0));
+    let height = store
+        .layout_units(entity, &store.height, &store.layout_calc.height, LayoutAxis::Vertical, false)
+        .unwrap_or(Stretch(1.0));
+
+    let is_fixed = |units: Units| !matches!(units, Auto | Stretch(_));
+    let axis = match (width, height) {
+        (_, Auto) => LayoutAxis::Vertical,
+        (Auto, _) => LayoutAxis::Horizontal,
+        (Stretch(_), height) if is_fixed(height) => LayoutAxis::Horizontal,
+        (width, Stretch(_)) if is_fixed(width) => LayoutAxis::Vertical,
+        _ => return None,
+    };
+
+    Some((axis, ratio))
+}
+
 impl Node for Entity {
     type Store = Style;
     type Tree = Tree<Entity>;
@@ -39,52 +65,72 @@ impl Node for Entity {
     }
 
     fn left(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.left.get(*self).cloned().map(|l| match l {
-            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
-            t => t,
-        })
+        store.layout_units(
+            *self,
+            &store.left,
+            &store.layout_calc.left,
+            LayoutAxis::Horizontal,
+            true,
+        )
     }
 
     fn right(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.right.get(*self).cloned().map(|r| match r {
-            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
-            t => t,
-        })
+        store.layout_units(
+            *self,
+            &store.right,
+            &store.layout_calc.right,
+            LayoutAxis::Horizontal,
+            true,
+        )
     }
 
     fn top(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.top.get(*self).cloned().map(|t| match t {
-            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
-            t => t,
-        })
+        store.layout_units(*self, &store.top, &store.layout_calc.top, LayoutAxis::Vertical, true)
     }
 
     fn bottom(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.bottom.get(*self).cloned().map(|b| match b {
-            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
-            t => t,
-        })
+        store.layout_units(
+            *self,
+            &store.bottom,
+            &store.layout_calc.bottom,
+            LayoutAxis::Vertical,
+            true,
+        )
     }
 
     fn width(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.width.get(*self).cloned().map(|w| match w {
-            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
-            t => t,
-        })
+        // The derived axis is sized by `content_size`, which is only used for auto sizes.
+        if aspect_ratio_axis(*self, store).is_some_and(|(axis, _)| axis == LayoutAxis::Horizontal) {
+            return Some(Auto);
+        }
+
+        store.layout_units(
+            *self,
+            &store.width,
+            &store.layout_calc.width,
+            LayoutAxis::Horizontal,
+            false,
+        )
     }
 
     fn min_width(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.min_width.get(*self).cloned().map(|w| match w {
-            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
-            t => t,
-        })
+        store.layout_units(
+            *self,
+            &store.min_width,
+            &store.layout_calc.min_width,
+            LayoutAxis::Horizontal,
+            false,
+        )
     }
 
     fn max_width(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.max_width.get(*self).cloned().map(|w| match w {
-            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
-            t => t,
-        })
+        store.layout_units(
+            *self,
+            &store.max_width,
+            &store.layout_calc.max_width,
+            LayoutAxis::Horizontal,
+            false,
+        )
     }
 
     fn content_size(
@@ -94,213 +140,104 @@ impl Node for Entity {
         width: Option<f32>,
         height: Option<f32>,
     ) -> Option<(f32, f32)> {
-        if let Some(paragraph) = sublayout.text_context.text_paragraphs.get_mut(*self) {
-            // // If the width is known use that, else use 0 for wrapping text or 999999 for non-wrapping text.
-            // let max_width = if let Some(width) = width {
-            //     let padding_left =
-            //         store.padding_left.get(*self).cloned().unwrap_or_default().to_px(width, 0.0)
-            //             * store.scale_factor();
-            //     let padding_right =
-            //         store.padding_right.get(*self).cloned().unwrap_or_default().to_px(width, 0.0)
-            //             * store.scale_factor();
-            //     let border_width = store
-            //         .border_width
-            //         .get(*self)
-            //         .cloned()
-            //         .unwrap_or_default()
-            //         .to_pixels(0.0, store.scale_factor());
-
-            //     width.ceil() - padding_left - padding_right - border_width - border_width
-            // } else {
-            //     f32::MAX
-            // };
-
-            paragraph.layout(f32::MAX);
-
-            let padding_left = store.padding_left.get(*self).copied().unwrap_or_default();
-            let padding_right = store.padding_right.get(*self).copied().unwrap_or_default();
-            let padding_top = store.padding_top.get(*self).copied().unwrap_or_default();
-            let padding_bottom = store.padding_bottom.get(*self).copied().unwrap_or_default();
-
-            let mut child_space_x = 0.0;
-            let mut child_space_y = 0.0;
-
-            let mut p_left = 0.0;
-            let mut p_top = 0.0;
-
-            // shrink the bounding box based on pixel values
-            if let Pixels(val) = padding_left {
-                let val = val * store.scale_factor();
-                child_space_x += val;
-                p_left += val;
+        let Some((axis, ratio)) = aspect_ratio_axis(*self, store) else {
+            return measure_content(*self, store, sublayout, width, height);
+        };
+
+        // The derived size is clamped by the size constraints of the view during layout.
+        match (axis, width, height) {
+            (LayoutAxis::Vertical, Some(width), None) => Some((width, width / ratio)),
+            (LayoutAxis::Horizontal, None, Some(height)) => Some((height * ratio, height)),
+            (_, Some(width), Some(height)) => Some((width, height)),
+            // When both sizes are auto the height is derived from the width of the content.
+            _ => {
+                let (width, _) = measure_content(*self, store, sublayout, None, None)?;
+                Some((width, width / ratio))
             }
-            if let Pixels(val) = padding_right {
-                let val = val * store.scale_factor();
-                child_space_x += val;
-            }
-            if let Pixels(val) = padding_top {
-                let val = val * store.scale_factor();
-                child_space_y += val;
-                p_top += val;
-            }
-            if let Pixels(val) = padding_bottom {
-                let val = val * store.scale_factor();
-                child_space_y += val;
-            }
-
-            let border_width = store
-                .border_width
-                .get(*self)
-                .cloned()
-                .unwrap_or_default()
-                .to_pixels(0.0, store.scale_factor());
-
-            child_space_x += 2.0 * border_width;
-            child_space_y += 2.0 * border_width;
-
-            p_left += border_width;
-            p_top += border_width;
-
-            let text_width = match (
-                store.text_wrap.get(*self).copied().unwrap_or(true),
-                store.text_overflow.get(*self).copied(),
-            ) {
-                (true, _) => {
-                    if let Some(width) = width {
-                        width - child_space_x
-                    } else {
-                        paragraph.min_intrinsic_width().ceil()
-                    }
-                }
-                (false, Some(TextOverflow::Ellipsis)) => {
-                    if let Some(width) = width {
-                        width - child_space_x
-                    } else {
-                        paragraph.max_intrinsic_width().ceil()
-                    }
-                }
-                _ => {
-                    if let Some(width) = width {
-                        (width - child_space_x).max(paragraph.min_intrinsic_width().ceil())
-                    } else {
-                        paragraph.max_intrinsic_width().ceil()
-                    }
-                }
-            };
-
-            paragraph.layout(text_width);
-
-            let text_height = if let Some(height) = height { height } else { paragraph.height() };
-
-            let width =
-                if let Some(width) = width { width } else { text_width.round() + child_space_x };
+        }
+    }
 
-            let height = if let Some(height) = height {
-                height
-            } else {
-                text_height.round() + child_space_y
-            };
+    fn height(&self, store: &Self::Store) -> Option<morphorm::Units> {
+        if aspect_ratio_axis(*self, store).is_some_and(|(axis, _)| axis == LayoutAxis::Vertical) {
+            return Some(Auto);
+        }
 
-            // Cache the text_width/ text_height in the text context so we can use it to compute transforms later
-            sublayout.text_context.set_text_bounds(
-                *self,
-                BoundingBox { x: p_left, y: p_top, w: text_width, h: text_height },
-            );
-
-            Some((width, height))
-        } else if let Some(images) = store.background_image.get(*self) {
-            let mut max_width = 0.0f32;
-            let mut max_height = 0.0f32;
-            for image in images.iter() {
-                match image {
-                    ImageOrGradient::Image(image_name) => {
-                        if let Some(image_id) = sublayout.resource_manager.image_ids.get(image_name)
-                        {
-                            match sublayout
-                                .resource_manager
-                                .images
-                                .get(image_id)
-                                .map(|stored_img| &stored_img.image)
-                            {
-                                Some(ImageOrSvg::Image(image)) => {
-                                    max_width =
-                                        max_width.max(image.width() as f32 * store.scale_factor());
-                                    max_height = max_height
-                                        .max(image.height() as f32 * store.scale_factor());
-                                }
-
-                                Some(ImageOrSvg::Svg(svg)) => {
-                                    max_width = max_width.max(
-                                        svg.inner().fContainerSize.fWidth * store.scale_factor(),
-                                    );
-                                    max_height = max_height.max(
-                                        svg.inner().fContainerSize.fWidth * store.scale_factor(),
-                                    );
-                                }
-
-                                _ => {}
-                            }
-                        }
-                    }
-                    _ => {}
-                }
+        let height = store.layout_units(
+            *self,
+            &store.height,
+            &store.layout_calc.height,
+            LayoutAxis::Vertical,
+            false,
+        );
+
+        // A row whose children are moved down to align their baselines grows to fit them.
+        if height == Some(Auto) {
+            if let Some(height) = store.layout_calc.baseline_heights.get(*self) {
+                return Some(Pixels(*height));
             }
-
-            let width = if let Some(width) = width { width } else { max_width };
-            let height = if let Some(height) = height { height } else { max_height };
-            Some((width, height))
-        } else {
-            None
         }
-    }
 
-    fn height(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.height.get(*self).cloned().map(|h| match h {
-            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
-            t => t,
-        })
+        height
     }
 
     fn min_height(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.min_height.get(*self).cloned().map(|h| match h {
-            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
-            t => t,
-        })
+        store.layout_units(
+            *self,
+            &store.min_height,
+            &store.layout_calc.min_height,
+            LayoutAxis::Vertical,
+            false,
+        )
     }
 
     fn max_height(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.max_height.get(*self).cloned().map(|h| match h {
-            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
-            t => t,
-        })
+        store.layout_units(
+            *self,
+            &store.max_height,
+            &store.layout_calc.max_height,
+            LayoutAxis::Vertical,
+            false,
+        )
     }
 
     fn padding_left(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.padding_left.get(*self).cloned().map(|l| match l {
-            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
-            t => t,
-        })
+        store.layout_units(
+            *self,
+            &store.padding_left,
+            &store.layout_calc.padding_left,
+            LayoutAxis::Horizontal,
+            false,
+        )
     }
 
     fn padding_right(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.padding_right.get(*self).cloned().map(|r| match r {
-            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
-            t => t,
-        })
+        store.layout_units(
+            *self,
+            &store.padding_right,
+            &store.layout_calc.padding_right,
+            LayoutAxis::Horizontal,
+            false,
+        )
     }
 
     fn padding_top(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.padding_top.get(*self).cloned().map(|t| match t {
-            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
-            t => t,
-        })
+        store.layout_units(
+            *self,
+            &store.padding_top,
+            &store.layout_calc.padding_top,
+            LayoutAxis::Vertical,
+            false,
+        )
     }
 
     fn padding_bottom(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.padding_bottom.get(*self).cloned().map(|b| match b {
-            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
-            t => t,
-        })
+        store.layout_units(
+            *self,
+            &store.padding_bottom,
+            &store.layout_calc.padding_bottom,
+            LayoutAxis::Vertical,
+            false,
+        )
     }
 
     fn vertical_gap(&self, store: &Self::Store) -> Option<morphorm::Units> {
@@ -318,38 +255,50 @@ impl Node for Entity {
     }
 
     fn border_left(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.border_width.get(*self).map(|border_width| match border_width {
-            LengthOrPercentage::Length(val) => {
-                Units::Pixels(store.logical_to_physical(val.to_px().unwrap_or_default()))
-            }
+        store.border_left_width.get(*self).map(|border_width| match border_width {
+            LengthOrPercentage::Length(val) => Units::Pixels(store.logical_to_physical(
+                val.resolve(&store.length_context(*self, LayoutAxis::Horizontal)),
+            )),
             LengthOrPercentage::Percentage(val) => Units::Percentage(*val),
+            LengthOrPercentage::Calc(length) => Units::Pixels(store.logical_to_physical(
+                length.resolve(&store.length_context(*self, LayoutAxis::Horizontal)),
+            )),
         })
     }
 
     fn border_right(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.border_width.get(*self).map(|border_width| match border_width {
-            LengthOrPercentage::Length(val) => {
-                Units::Pixels(store.logical_to_physical(val.to_px().unwrap_or_default()))
-            }
+        store.border_right_width.get(*self).map(|border_width| match border_width {
+            LengthOrPercentage::Length(val) => Units::Pixels(store.logical_to_physical(
+                val.resolve(&store.length_context(*self, LayoutAxis::Horizontal)),
+            )),
             LengthOrPercentage::Percentage(val) => Units::Percentage(*val),
+            LengthOrPercentage::Calc(length) => Units::Pixels(store.logical_to_physical(
+                length.resolve(&store.length_context(*self, LayoutAxis::Horizontal)),
+            )),
         })
     }
 
     fn border_top(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.border_width.get(*self).map(|border_width| match border_width {
-            LengthOrPercentage::Length(val) => {
-                Units::Pixels(store.logical_to_physical(val.to_px().unwrap_or_default()))
-            }
+        store.border_top_width.get(*self).map(|border_width| match border_width {
+            LengthOrPercentage::Length(val) => Units::Pixels(store.logical_to_physical(
+                val.resolve(&store.length_context(*self, LayoutAxis::Vertical)),
+            )),
             LengthOrPercentage::Percentage(val) => Units::Percentage(*val),
+            LengthOrPercentage::Calc(length) => Units::Pixels(store.logical_to_physical(
+                length.resolve(&store.length_context(*self, LayoutAxis::Vertical)),
+            )),
         })
     }
 
     fn border_bottom(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.border_width.get(*self).map(|border_width| match border_width {
-            LengthOrPercentage::Length(val) => {
-                Units::Pixels(store.logical_to_physical(val.to_px().unwrap_or_default()))
-            }
+        store.border_bottom_width.get(*self).map(|border_width| match border_width {
+            LengthOrPercentage::Length(val) => Units::Pixels(store.logical_to_physical(
+                val.resolve(&store.length_context(*self, LayoutAxis::Vertical)),
+            )),
             LengthOrPercentage::Percentage(val) => Units::Percentage(*val),
+            LengthOrPercentage::Calc(length) => Units::Pixels(store.logical_to_physical(
+                length.resolve(&store.length_context(*self, LayoutAxis::Vertical)),
+            )),
         })
     }
 
@@ -365,6 +314,30 @@ impl Node for Entity {
         store.horizontal_scroll.get(*self).copied()
     }
 
+    fn grid_columns(&self, store: &Self::Store) -> Option<Vec<Units>> {
+        store.grid_tracks(store.grid_columns.get(*self))
+    }
+
+    fn grid_rows(&self, store: &Self::Store) -> Option<Vec<Units>> {
+        store.grid_tracks(store.grid_rows.get(*self))
+    }
+
+    fn column_start(&self, store: &Self::Store) -> Option<usize> {
+        store.grid_cells.get(*self).map(|cell| cell.column)
+    }
+
+    fn row_start(&self, store: &Self::Store) -> Option<usize> {
+        store.grid_cells.get(*self).map(|cell| cell.row)
+    }
+
+    fn column_span(&self, store: &Self::Store) -> Option<usize> {
+        store.grid_cells.get(*self).map(|cell| cell.column_span)
+    }
+
+    fn row_span(&self, store: &Self::Store) -> Option<usize> {
+        store.grid_cells.get(*self).map(|cell| cell.row_span)
+    }
+
     fn min_vertical_gap(&self, store: &Self::Store) -> Option<Units> {
         store.min_vertical_gap.get(*self).cloned().map(|h| match h {
             Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
@@ -393,3 +366,160 @@ impl Node for Entity {
         })
     }
 }
+
+// Returns the size of the text or background images of a view, with the given width and height if they're known.
+fn measure_content(
+    entity: Entity,
+    store: &Style,
+    sublayout: &mut SubLayout<'_>,
+    width: Option<f32>,
+    height: Option<f32>,
+) -> Option<(f32, f32)> {
+    if sublayout.text_context.text_layout(entity).is_some() {
+        // The padding and border are in physical pixels.
+        let padding_left = entity.padding_left(store).unwrap_or_default();
+        let padding_right = entity.padding_right(store).unwrap_or_default();
+        let padding_top = entity.padding_top(store).unwrap_or_default();
+        let padding_bottom = entity.padding_bottom(store).unwrap_or_default();
+
+        let mut child_space_x = 0.0;
+        let mut child_space_y = 0.0;
+
+        let mut p_left = 0.0;
+        let mut p_top = 0.0;
+
+        // shrink the bounding box based on pixel values
+        if let Pixels(val) = padding_left {
+            child_space_x += val;
+            p_left += val;
+        }
+        if let Pixels(val) = padding_right {
+            child_space_x += val;
+        }
+        if let Pixels(val) = padding_top {
+            child_space_y += val;
+            p_top += val;
+        }
+        if let Pixels(val) = padding_bottom {
+            child_space_y += val;
+        }
+
+        let border_width = |border: Option<morphorm::Units>| match border {
+            Some(Pixels(val)) => val,
+            _ => 0.0,
+        };
+        let border_left = border_width(entity.border_left(store));
+        let border_top = border_width(entity.border_top(store));
+
+        child_space_x += border_left + border_width(entity.border_right(store));
+        child_space_y += border_top + border_width(entity.border_bottom(store));
+
+        p_left += border_left;
+        p_top += border_top;
+
+        let white_space = store.white_space.get(entity).copied().unwrap_or_default();
+        let text_overflow = store.text_overflow.get(entity).copied();
+
+        // Layout can ask for the size of the same text several times with the same constraints, such as when
+        // stretching, so the measured size is cached until the paragraph is rebuilt.
+        let key = MeasureKey {
+            width: width.map(f32::round),
+            height: height.map(f32::round),
+            child_space: (child_space_x, child_space_y),
+            white_space,
+            text_overflow,
+        };
+
+        let measurement = sublayout.text_context.measure(entity, key, |paragraph| {
+            paragraph.layout(f32::MAX);
+
+            let text_width = match (white_space.wraps(), text_overflow) {
+                (true, _) => {
+                    if let Some(width) = width {
+                        width - child_space_x
+                    } else {
+                        paragraph.min_intrinsic_width().ceil()
+                    }
+                }
+                (false, Some(TextOverflow::Ellipsis)) => {
+                    if let Some(width) = width {
+                        width - child_space_x
+                    } else {
+                        paragraph.max_intrinsic_width().ceil()
+                    }
+                }
+                // Text which doesn't wrap is as wide as its longest line, so a narrower view clips it.
+                _ => {
+                    if let Some(width) = width {
+                        (width - child_space_x).max(paragraph.max_intrinsic_width().ceil())
+                    } else {
+                        paragraph.max_intrinsic_width().ceil()
+                    }
+                }
+            };
+
+            paragraph.layout(text_width);
+
+            let text_height = if let Some(height) = height { height } else { paragraph.height() };
+
+            TextMeasurement { width: text_width, height: text_height }
+        })?;
+
+        let text_width = measurement.width;
+        let text_height = measurement.height;
+
+        let width =
+            if let Some(width) = width { width } else { text_width.round() + child_space_x };
+
+        let height =
+            if let Some(height) = height { height } else { text_height.round() + child_space_y };
+
+        // Cache the text_width/ text_height in the text context so we can use it to compute transforms later
+        sublayout.text_context.set_text_bounds(
+            entity,
+            BoundingBox { x: p_left, y: p_top, w: text_width, h: text_height },
+        );
+
+        Some((width, height))
+    } else if let Some(images) = store.background_image.get(entity) {
+        let mut max_width = 0.0f32;
+        let mut max_height = 0.0f32;
+        for image in images.iter() {
+            match image {
+                ImageOrGradient::Image(image_name) => {
+                    if let Some(image_id) = sublayout.resource_manager.image_ids.get(image_name) {
+                        match sublayout
+                            .resource_manager
+                            .images
+                            .get(image_id)
+                            .map(|stored_img| &stored_img.image)
+                        {
+                            Some(ImageOrSvg::Image(image)) => {
+                                max_width =
+                                    max_width.max(image.width() as f32 * store.scale_factor());
+                                max_height =
+                                    max_height.max(image.height() as f32 * store.scale_factor());
+                            }
+
+                            Some(ImageOrSvg::Svg(svg)) => {
+                                max_width = max_width
+                                    .max(svg.inner().fContainerSize.fWidth * store.scale_factor());
+                                max_height = max_height
+                                    .max(svg.inner().fContainerSize.fWidth * store.scale_factor());
+                            }
+
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let width = if let Some(width) = width { width } else { max_width };
+        let height = if let Some(height) = height { height } else { max_height };
+        Some((width, height))
+    } else {
+        None
+    }
+}
@@ -13,6 +13,8 @@ pub mod binding;
 #[doc(hidden)]
 pub(crate) mod cache;
 pub mod context;
+#[cfg(feature = "description")]
+pub mod description;
 #[doc(hidden)]
 pub(crate) mod entity;
 pub mod environment;
@@ -54,31 +56,36 @@ pub mod backend {
 #[doc(hidden)]
 pub mod prelude {
     pub use super::binding::{
-        Binding, Data, Index, Lens, LensExt, LensValue, Map, MapRef, Res, ResGet, StaticLens, Then,
-        UnwrapLens, Wrapper,
+        Binding, Data, Index, Lens, LensExt, LensMut, LensValue, Map, MapRef, Res, ResGet,
+        StaticLens, Then, TwoWay, UnwrapLens, Wrapper,
     };
 
     pub use super::impl_res_simple;
 
     pub use crate::model::Model;
 
-    pub use super::animation::{Animation, AnimationBuilder, KeyframeBuilder};
+    pub use super::animation::{Animation, AnimationBuilder, AnimationEvent, KeyframeBuilder};
     pub use super::context::{
         AccessContext, AccessNode, Context, ContextProxy, DataContext, DrawContext, EmitContext,
         EventContext, ProxyEmitError, WindowState,
     };
+    #[cfg(feature = "description")]
+    pub use super::description::{DescriptionError, ViewDescription};
     pub use super::entity::Entity;
     pub use super::environment::{AppTheme, Environment, EnvironmentEvent, ThemeMode};
-    pub use super::events::{Event, Propagation, Timer, TimerAction};
+    pub use super::events::{
+        debounce, throttle, Event, Propagation, Subscription, Timer, TimerAction,
+    };
     pub use super::include_style;
-    pub use super::input::{Keymap, KeymapEntry, KeymapEvent};
+    pub use super::input::{InteractionSettings, Keymap, KeymapEntry, KeymapEvent, ScrollSpeed};
     pub use super::layout::{BoundingBox, GeoChanged};
-    pub use super::localization::{Localized, ToStringLocalized};
+    pub use super::localization::{LocaleFonts, Localized, ToStringLocalized};
     pub use super::modifiers::{
-        AbilityModifiers, AccessibilityModifiers, ActionModifiers, LayoutModifiers,
-        LinearGradientBuilder, ShadowBuilder, StyleModifiers, TextModifiers,
+        AbilityModifiers, AccessibilityModifiers, ActionModifiers, AnchorModifiers, AnchorPoint,
+        AnchorRemoval, AnchorSpec, AnchorTarget, LayoutModifiers, LinearGradientBuilder,
+        MovableModifiers, ShadowBuilder, StyleModifiers, TextModifiers,
     };
-    pub use super::resource::{ImageId, ImageRetentionPolicy};
+    pub use super::resource::{ImageId, ImageRetentionPolicy, StyleSheetHandle};
     pub use super::util::{IntoCssStr, CSS};
     pub use super::view::{Handle, View};
     pub use super::views::*;
@@ -87,7 +94,9 @@ pub mod prelude {
     pub use skia_safe::Canvas;
     pub use vizia_derive::{Data, Lens};
     pub use vizia_id::GenerationalId;
-    pub use vizia_input::{Code, Key, KeyChord, Modifiers, MouseButton, MouseButtonState};
+    pub use vizia_input::{
+        Code, Key, KeyChord, Modifiers, MouseButton, MouseButtonState, PointerType, ScrollUnit,
+    };
     pub use vizia_storage::{Tree, TreeExt};
     pub use vizia_window::{WindowButtons, WindowPosition, WindowSize};
 
@@ -0,0 +1,184 @@
+use unic_langid::LanguageIdentifier;
+
+use crate::style::FamilyOwned;
+
+/// Preferred font families for languages and scripts.
+///
+/// When the locale of the [`Environment`](crate::environment::Environment) changes, the families of the locale are
+/// used as the font family of the root view, replacing the font family of the built-in theme. Font families set by
+/// the application, either in a stylesheet or with a modifier, are not affected.
+///
+/// The families of every language are also used as fallbacks for text which can't be shown with its own font
+/// family, with the families of the current locale first, so that text in another language is shown with the same
+/// fonts throughout an application.
+///
+/// Languages are given as a language subtag, such as `ja`, or a language and script, such as `zh-Hant`. The
+/// built-in families are fonts which are installed with common operating systems, and can be replaced with
+/// [`Context::set_locale_fonts`](crate::context::Context::set_locale_fonts).
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocaleFonts {
+    fonts: Vec<(String, Vec<String>)>,
+}
+
+impl Default for LocaleFonts {
+    fn default() -> Self {
+        let mut fonts = LocaleFonts::new();
+        fonts.set("ja", &["Hiragino Sans", "Yu Gothic UI", "Meiryo", "Noto Sans CJK JP"]);
+        fonts.set("ko", &["Apple SD Gothic Neo", "Malgun Gothic", "Noto Sans CJK KR"]);
+        fonts.set("zh-Hans", &["PingFang SC", "Microsoft YaHei", "Noto Sans CJK SC"]);
+        fonts.set("zh-Hant", &["PingFang TC", "Microsoft JhengHei", "Noto Sans CJK TC"]);
+        fonts.set("ar", &["Geeza Pro", "Segoe UI", "Noto Sans Arabic"]);
+        fonts.set("he", &["Arial Hebrew", "Segoe UI", "Noto Sans Hebrew"]);
+        fonts.set("th", &["Thonburi", "Leelawadee UI", "Noto Sans Thai"]);
+        fonts.set("hi", &["Kohinoor Devanagari", "Nirmala UI", "Noto Sans Devanagari"]);
+        fonts
+    }
+}
+
+impl LocaleFonts {
+    /// Creates an empty set of locale fonts.
+    pub fn new() -> Self {
+        Self { fonts: Vec::new() }
+    }
+
+    /// Sets the preferred font families for a language, replacing any existing families for the language.
+    pub fn set(&mut self, language: &str, families: &[&str]) {
+        let families = families.iter().map(|family| family.to_string()).collect();
+        match self.fonts.iter_mut().find(|(key, _)| key.eq_ignore_ascii_case(language)) {
+            Some((_, existing)) => *existing = families,
+            None => self.fonts.push((language.to_owned(), families)),
+        }
+    }
+
+    /// Removes the preferred font families for a language.
+    pub fn remove(&mut self, language: &str) {
+        self.fonts.retain(|(key, _)| !key.eq_ignore_ascii_case(language));
+    }
+
+    /// Returns the preferred font families for a locale, looking up the language and script of the locale before
+    /// the language alone.
+    ///
+    /// A Chinese locale without a script uses traditional characters in Taiwan, Hong Kong and Macau, and simplified
+    /// characters elsewhere.
+    pub fn families(&self, locale: &LanguageIdentifier) -> Option<&[String]> {
+        let language = locale.language.as_str();
+        let script =
+            locale.script.as_ref().map(|script| script.as_str().to_owned()).or_else(|| {
+                (language == "zh").then(|| {
+                    match locale.region.as_ref().map(|region| region.as_str()) {
+                        Some("TW" | "HK" | "MO") => "Hant",
+                        _ => "Hans",
+                    }
+                    .to_owned()
+                })
+            });
+
+        let get = |key: &str| {
+            self.fonts
+                .iter()
+                .find(|(language, _)| language.eq_ignore_ascii_case(key))
+                .map(|(_, families)| families.as_slice())
+        };
+
+        script.and_then(|script| get(&format!("{}-{}", language, script))).or_else(|| get(language))
+    }
+
+    // Returns a stylesheet which sets the font family of the root view to the families of a locale, or an empty
+    // stylesheet if the locale has no families.
+    pub(crate) fn root_style(&self, locale: &LanguageIdentifier) -> String {
+        match self.families(locale) {
+            Some(families) if !families.is_empty() => {
+                let families = families
+                    .iter()
+                    .map(|family| format!("\"{}\"", family.replace('"', "\\\"")))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!(":root {{ font-family: {}, sans-serif; }}\n", families)
+            }
+            _ => String::new(),
+        }
+    }
+
+    // Returns the families of every language, with the families of a locale first, without duplicates.
+    pub(crate) fn fallback_families(&self, locale: &LanguageIdentifier) -> Vec<FamilyOwned> {
+        let mut fallbacks = Vec::new();
+        let families = self
+            .families(locale)
+            .into_iter()
+            .chain(self.fonts.iter().map(|(_, families)| families.as_slice()));
+        for family in families.flatten() {
+            let family = FamilyOwned::Named(family.clone());
+            if !fallbacks.contains(&family) {
+                fallbacks.push(family);
+            }
+        }
+
+        fallbacks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn locale(locale: &str) -> LanguageIdentifier {
+        locale.parse().unwrap()
+    }
+
+    #[test]
+    fn families_match_language_and_script() {
+        let fonts = LocaleFonts::default();
+
+        assert_eq!(fonts.families(&locale("ja-JP")).unwrap()[0], "Hiragino Sans");
+        assert_eq!(fonts.families(&locale("zh-TW")).unwrap()[0], "PingFang TC");
+        assert_eq!(fonts.families(&locale("zh-CN")).unwrap()[0], "PingFang SC");
+        assert_eq!(fonts.families(&locale("zh-Hant-CN")).unwrap()[0], "PingFang TC");
+        assert_eq!(fonts.families(&locale("en-US")), None);
+    }
+
+    #[test]
+    fn families_can_be_overridden() {
+        let mut fonts = LocaleFonts::default();
+        fonts.set("ja", &["Noto Sans JP"]);
+        fonts.set("en", &["Inter"]);
+
+        assert_eq!(fonts.families(&locale("ja")), Some(&["Noto Sans JP".to_string()][..]));
+        assert_eq!(
+            fonts.root_style(&locale("en-GB")),
+            ":root { font-family: \"Inter\", sans-serif; }\n"
+        );
+
+        fonts.remove("en");
+        assert_eq!(fonts.root_style(&locale("en-GB")), "");
+    }
+
+    #[test]
+    fn locale_changes_set_the_root_fonts() {
+        let mut cx = Context::default();
+        cx.set_language(locale("ja-JP"));
+
+        assert!(cx.resource_manager.locale_theme.contains("\"Hiragino Sans\""));
+        assert_eq!(cx.style.fallback_fonts[0], FamilyOwned::Named("Hiragino Sans".to_owned()));
+
+        cx.set_locale_fonts("ja", &[]);
+        assert_eq!(cx.resource_manager.locale_theme, "");
+    }
+
+    #[test]
+    fn fallbacks_start_with_the_locale() {
+        let mut fonts = LocaleFonts::new();
+        fonts.set("ja", &["Japanese", "Shared"]);
+        fonts.set("ko", &["Korean", "Shared"]);
+
+        let named = |family: &str| FamilyOwned::Named(family.to_owned());
+        assert_eq!(
+            fonts.fallback_families(&locale("ko")),
+            vec![named("Korean"), named("Shared"), named("Japanese")]
+        );
+        assert_eq!(
+            fonts.fallback_families(&locale("en")),
+            vec![named("Japanese"), named("Shared"), named("Korean")]
+        );
+    }
+}
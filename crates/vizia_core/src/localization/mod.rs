@@ -75,6 +75,9 @@ use fluent_bundle::FluentValue;
 use hashbrown::HashMap;
 use std::rc::Rc;
 
+mod fonts;
+pub use fonts::LocaleFonts;
+
 pub(crate) trait FluentStore {
     fn get_val(&self, cx: &LocalizationContext) -> FluentValue<'static>;
     fn make_clone(&self) -> Box<dyn FluentStore>;
@@ -348,3 +351,27 @@ impl ToStringLocalized for Localized {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+
+    #[test]
+    fn localized_name_updates_with_locale() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+
+        cx.add_translation("en-US".parse().unwrap(), "greeting = Hello");
+        cx.add_translation("fr".parse().unwrap(), "greeting = Bonjour");
+        cx.emit(EnvironmentEvent::SetLocale("en-US".parse().unwrap()));
+        event_manager.flush_events(&mut cx, |_| {});
+
+        let entity = Element::new(&mut cx).name(Localized::new("greeting")).entity();
+        assert_eq!(cx.style.name.get(entity), Some(&String::from("Hello")));
+
+        cx.emit(EnvironmentEvent::SetLocale("fr".parse().unwrap()));
+        event_manager.flush_events(&mut cx, |_| {});
+        assert_eq!(cx.style.name.get(entity), Some(&String::from("Bonjour")));
+    }
+}
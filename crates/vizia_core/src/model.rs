@@ -60,18 +60,17 @@ pub trait Model: 'static + Sized {
     /// }
     /// ```
     fn build(self, cx: &mut Context) {
-        let current = if cx.tree.is_ignored(cx.current) {
-            cx.tree.get_layout_parent(cx.current).unwrap()
-        } else {
-            cx.current
-        };
+        let current = cx.model_entity();
+
+        let model_data_store = cx.data.entry(current).or_default();
+        model_data_store.models.insert(TypeId::of::<Self>(), Box::new(self));
 
-        if let Some(model_data_store) = cx.data.get_mut(&current) {
-            model_data_store.models.insert(TypeId::of::<Self>(), Box::new(self));
+        // Models built within the content of a binding are stored on the binding's parent, but belong to the
+        // binding so that they are removed along with the content.
+        if current != cx.current {
+            model_data_store.owners.insert(TypeId::of::<Self>(), cx.current);
         } else {
-            let mut models: HashMap<TypeId, Box<dyn ModelData>> = HashMap::new();
-            models.insert(TypeId::of::<Self>(), Box::new(self));
-            cx.data.insert(current, ModelDataStore { models, stores: HashMap::default() });
+            model_data_store.owners.remove(&TypeId::of::<Self>());
         }
     }
 
@@ -122,6 +121,8 @@ pub(crate) trait ModelData: Any {
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {}
 
     fn as_any_ref(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
     #[cfg(debug_assertions)]
     fn name(&self) -> Option<&'static str>;
 }
@@ -130,6 +131,10 @@ impl dyn ModelData {
     pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
         self.as_any_ref().downcast_ref()
     }
+
+    pub fn downcast_mut<T: Any>(&mut self) -> Option<&mut T> {
+        self.as_any_mut().downcast_mut()
+    }
 }
 
 impl<T: Model> ModelData for T {
@@ -141,6 +146,14 @@ impl<T: Model> ModelData for T {
         self
     }
 
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
     #[cfg(debug_assertions)]
     fn name(&self) -> Option<&'static str> {
         <T as Model>::name(self)
@@ -151,6 +164,8 @@ impl<T: Model> ModelData for T {
 pub(crate) struct ModelDataStore {
     pub models: HashMap<TypeId, Box<dyn ModelData>>,
     pub stores: HashMap<StoreId, Box<dyn Store>>,
+    /// The bindings which built each model, for models built within the content of a binding.
+    pub owners: HashMap<TypeId, Entity>,
 }
 
 impl Model for () {}
@@ -169,3 +184,129 @@ impl<'a> ModelOrView<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::binding_system;
+
+    #[derive(Lens)]
+    struct Document {
+        title: String,
+        show_details: bool,
+    }
+
+    impl Model for Document {}
+
+    struct Details;
+
+    impl Model for Details {}
+
+    fn document(title: &str, show_details: bool) -> Document {
+        Document { title: title.to_string(), show_details }
+    }
+
+    fn text_in_subtree(cx: &Context, root: Entity) -> Vec<String> {
+        vizia_storage::TreeIterator::subtree(&cx.tree, root)
+            .filter_map(|entity| cx.style.text.get(entity).cloned())
+            .collect()
+    }
+
+    #[test]
+    fn replace_model_rebuilds_bound_views() {
+        let mut cx = Context::default();
+
+        document("First", false).build(&mut cx);
+        let stack = VStack::new(&mut cx, |cx| {
+            Binding::new(cx, Document::title, |cx, title| {
+                Label::new(cx, title.get(cx));
+            });
+        })
+        .entity();
+
+        let previous = cx.replace_model(document("Second", false));
+        binding_system(&mut cx);
+
+        assert_eq!(previous.map(|document| document.title), Some(String::from("First")));
+        assert_eq!(text_in_subtree(&cx, stack), vec![String::from("Second")]);
+    }
+
+    #[test]
+    fn removed_model_rebinds_to_ancestor_model() {
+        let mut cx = Context::default();
+
+        document("Outer", false).build(&mut cx);
+        let stack = VStack::new(&mut cx, |cx| {
+            document("Inner", false).build(cx);
+            Binding::new(cx, Document::title, |cx, title| {
+                Label::new(cx, title.get(cx));
+            });
+        })
+        .entity();
+
+        assert_eq!(text_in_subtree(&cx, stack), vec![String::from("Inner")]);
+
+        let removed = cx.with_current(stack, |cx| cx.remove_model::<Document>());
+        binding_system(&mut cx);
+
+        assert_eq!(removed.map(|document| document.title), Some(String::from("Inner")));
+        assert_eq!(text_in_subtree(&cx, stack), vec![String::from("Outer")]);
+    }
+
+    #[test]
+    fn removed_model_without_ancestor_clears_bound_views() {
+        let mut cx = Context::default();
+
+        let stack = VStack::new(&mut cx, |cx| {
+            document("Inner", false).build(cx);
+            Binding::new(cx, Document::title, |cx, title| {
+                Label::new(cx, title.get(cx));
+            });
+        })
+        .entity();
+
+        cx.with_current(stack, |cx| cx.remove_model::<Document>());
+        binding_system(&mut cx);
+
+        assert!(text_in_subtree(&cx, stack).is_empty());
+    }
+
+    #[test]
+    fn models_built_in_binding_are_removed_with_content() {
+        let mut cx = Context::default();
+
+        document("Document", true).build(&mut cx);
+        Binding::new(&mut cx, Document::show_details, |cx, show_details| {
+            if show_details.get(cx) {
+                Details.build(cx);
+            }
+        });
+
+        assert!(cx.data::<Details>().is_some());
+
+        cx.replace_model(document("Document", false));
+        binding_system(&mut cx);
+
+        assert!(cx.data::<Details>().is_none());
+    }
+
+    #[test]
+    fn models_are_removed_with_owning_entity() {
+        let mut cx = Context::default();
+
+        document("Document", true).build(&mut cx);
+        let stack = VStack::new(&mut cx, |cx| {
+            Binding::new(cx, Document::show_details, |cx, _| {
+                Details.build(cx);
+            });
+        })
+        .entity();
+
+        assert!(cx.data.get(&stack).is_some_and(|store| !store.models.is_empty()));
+
+        let binding = cx.tree.get_first_child(stack).unwrap();
+        cx.remove(binding);
+
+        assert!(cx.data.get(&stack).is_some_and(|store| store.models.is_empty()));
+    }
+}
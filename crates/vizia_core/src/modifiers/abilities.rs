@@ -1,5 +1,7 @@
 use super::internal;
+use super::set_or_rebind;
 use crate::prelude::*;
+use crate::views::RovingFocusInternalEvent;
 
 /// Modifiers for changing the abilities of a view.
 pub trait AbilityModifiers: internal::Modifiable {
@@ -19,14 +21,12 @@ pub trait AbilityModifiers: internal::Modifiable {
     fn hoverable<U: Into<bool>>(mut self, state: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.entity();
-        self.context().with_current(current, move |cx| {
-            state.set_or_bind(cx, entity, move |cx, v| {
-                let val = v.get(cx).into();
-                if let Some(abilities) = cx.style.abilities.get_mut(entity) {
-                    abilities.set(Abilities::HOVERABLE, val);
-                    cx.needs_restyle(entity);
-                }
-            });
+        set_or_rebind(self.context(), current, entity, "hoverable", state, move |cx, v| {
+            let val = v.get(cx).into();
+            if let Some(abilities) = cx.style.abilities.get_mut(entity) {
+                abilities.set(Abilities::HOVERABLE, val);
+                cx.needs_restyle(entity);
+            }
         });
 
         self
@@ -45,20 +45,18 @@ pub trait AbilityModifiers: internal::Modifiable {
     fn focusable<U: Into<bool>>(mut self, state: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, move |cx| {
-            state.set_or_bind(cx, entity, move |cx, v| {
-                let state = v.get(cx).into();
-                if let Some(abilities) = cx.style.abilities.get_mut(entity) {
-                    abilities.set(Abilities::FOCUSABLE, state);
-
-                    // If an element is not focusable then it can't be keyboard navigable.
-                    if !state {
-                        abilities.set(Abilities::NAVIGABLE, false);
-                    }
-
-                    cx.needs_restyle(entity);
+        set_or_rebind(self.context(), current, entity, "focusable", state, move |cx, v| {
+            let state = v.get(cx).into();
+            if let Some(abilities) = cx.style.abilities.get_mut(entity) {
+                abilities.set(Abilities::FOCUSABLE, state);
+
+                // If an element is not focusable then it can't be keyboard navigable.
+                if !state {
+                    abilities.set(Abilities::NAVIGABLE, false);
                 }
-            });
+
+                cx.needs_restyle(entity);
+            }
         });
 
         self
@@ -77,15 +75,13 @@ pub trait AbilityModifiers: internal::Modifiable {
     fn checkable<U: Into<bool>>(mut self, state: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, move |cx| {
-            state.set_or_bind(cx, entity, move |cx, v| {
-                let state = v.get(cx).into();
-                if let Some(abilities) = cx.style.abilities.get_mut(cx.current) {
-                    abilities.set(Abilities::CHECKABLE, state);
+        set_or_rebind(self.context(), current, entity, "checkable", state, move |cx, v| {
+            let state = v.get(cx).into();
+            if let Some(abilities) = cx.style.abilities.get_mut(cx.current) {
+                abilities.set(Abilities::CHECKABLE, state);
 
-                    cx.needs_restyle(entity);
-                }
-            });
+                cx.needs_restyle(entity);
+            }
         });
 
         self
@@ -105,18 +101,70 @@ pub trait AbilityModifiers: internal::Modifiable {
     fn navigable<U: Into<bool>>(mut self, state: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, move |cx| {
-            state.set_or_bind(cx, entity, move |cx, v| {
-                let val = v.get(cx).into();
-                if let Some(abilities) = cx.style.abilities.get_mut(entity) {
-                    abilities.set(Abilities::NAVIGABLE, val);
-                    cx.needs_restyle(entity);
-                }
-            });
+        set_or_rebind(self.context(), current, entity, "navigable", state, move |cx, v| {
+            let val = v.get(cx).into();
+            if let Some(abilities) = cx.style.abilities.get_mut(entity) {
+                abilities.set(Abilities::NAVIGABLE, val);
+                cx.needs_restyle(entity);
+            }
+        });
+
+        self
+    }
+
+    /// Sets the text used to find the view by typing its first letters, such as an option of a [`Dropdown`].
+    ///
+    /// By default the text of the first view with text within the view is used, which allows views without text,
+    /// or views containing more than one label, to be found by typing.
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// HStack::new(cx, |cx| {
+    ///     Element::new(cx).class("swatch");
+    ///     Label::new(cx, "Apple");
+    ///     Label::new(cx, "Fruit");
+    /// })
+    /// .nav_text("Apple");
+    /// ```
+    fn nav_text<U: ToStringLocalized>(mut self, text: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        set_or_rebind(self.context(), current, entity, "nav_text", text, move |cx, text| {
+            cx.style.nav_text.insert(entity, text.get(cx).to_string_local(cx));
         });
 
         self
     }
+
+    /// Makes the view an item of the [`RovingFocusGroup`] which contains it, so that it can be focused with the
+    /// arrow keys, and can only be navigated to with `tab` while it's the active item of the group.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// RovingFocusGroup::new(cx, |cx| {
+    ///     Button::new(cx, |cx| Label::new(cx, "Bold")).roving_focus_item();
+    ///     Button::new(cx, |cx| Label::new(cx, "Italic")).roving_focus_item();
+    /// });
+    /// ```
+    fn roving_focus_item(mut self) -> Self {
+        let entity = self.entity();
+        let cx = self.context();
+        if let Some(abilities) = cx.style.abilities.get_mut(entity) {
+            abilities.set(Abilities::FOCUSABLE, true);
+        }
+
+        cx.emit_custom(
+            Event::new(RovingFocusInternalEvent::Register)
+                .target(entity)
+                .origin(entity)
+                .propagate(Propagation::Up),
+        );
+
+        self
+    }
 }
 
 impl<V> AbilityModifiers for Handle<'_, V> {}
@@ -1,15 +1,17 @@
 use super::internal;
+use super::set_or_rebind;
 use crate::prelude::*;
 
 /// Modifiers for changing the accessibility properties of a view.
 pub trait AccessibilityModifiers: internal::Modifiable {
     /// Sets the accessibility role of the view.
-    fn role(mut self, role: Role) -> Self {
-        let id = self.entity();
-
-        self.context().style.role.insert(id, role);
-
-        self.context().style.needs_access_update(id);
+    fn role<U: Into<Role>>(mut self, role: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        set_or_rebind(self.context(), current, entity, "role", role, move |cx, role| {
+            cx.style.role.insert(entity, role.get(cx).into());
+            cx.style.needs_access_update(entity);
+        });
 
         self
     }
@@ -18,32 +20,34 @@ pub trait AccessibilityModifiers: internal::Modifiable {
     fn name<U: ToStringLocalized>(mut self, name: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, move |cx| {
-            name.set_or_bind(cx, entity, move |cx, name| {
-                cx.style.name.insert(entity, name.get(cx).to_string_local(cx));
-                cx.style.needs_access_update(entity);
-            });
+        set_or_rebind(self.context(), current, entity, "name", name, move |cx, name| {
+            cx.style.name.insert(entity, name.get(cx).to_string_local(cx));
+            cx.style.needs_access_update(entity);
         });
 
         self
     }
 
     /// Sets the accessibility default action for the view.
-    fn default_action_verb(mut self, action_verb: DefaultActionVerb) -> Self {
-        let id = self.entity();
-
-        self.context().style.default_action_verb.insert(id, action_verb);
-        self.context().style.needs_access_update(id);
+    fn default_action_verb<U: Into<DefaultActionVerb>>(mut self, action_verb: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        set_or_rebind(self.context(), current, entity, "default_action_verb", action_verb, move |cx, action_verb| {
+            cx.style.default_action_verb.insert(entity, action_verb.get(cx).into());
+            cx.style.needs_access_update(entity);
+        });
 
         self
     }
 
     /// Sets whether the view should act as an accessibility live region.
-    fn live(mut self, live: Live) -> Self {
-        let id = self.entity();
-
-        self.context().style.live.insert(id, live);
-        self.context().style.needs_access_update(id);
+    fn live<U: Into<Live>>(mut self, live: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        set_or_rebind(self.context(), current, entity, "live", live, move |cx, live| {
+            cx.style.live.insert(entity, live.get(cx).into());
+            cx.style.needs_access_update(entity);
+        });
 
         self
     }
@@ -52,11 +56,24 @@ pub trait AccessibilityModifiers: internal::Modifiable {
     fn hidden<U: Into<bool>>(mut self, hidden: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            hidden.set_or_bind(cx, entity, |cx, hidden| {
-                cx.style.hidden.insert(cx.current, hidden.get(cx).into());
-                cx.style.needs_access_update(cx.current);
-            });
+        set_or_rebind(self.context(), current, entity, "hidden", hidden, |cx, hidden| {
+            cx.style.hidden.insert(cx.current, hidden.get(cx).into());
+            cx.style.needs_access_update(cx.current);
+        });
+
+        self
+    }
+
+    /// Sets whether the view is expanded, such as a dropdown with an open popup.
+    ///
+    /// A view with an expanded state offers the expand and collapse actions to assistive technologies, which it
+    /// receives as a [`WindowEvent::ActionRequest`].
+    fn expanded<U: Into<bool>>(mut self, expanded: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        set_or_rebind(self.context(), current, entity, "expanded", expanded, |cx, expanded| {
+            cx.style.expanded.insert(cx.current, expanded.get(cx).into());
+            cx.style.needs_access_update(cx.current);
         });
 
         self
@@ -66,13 +83,11 @@ pub trait AccessibilityModifiers: internal::Modifiable {
     fn numeric_value<U: Into<f64>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, |cx, val| {
-                let v = val.get(cx).into();
+        set_or_rebind(self.context(), current, entity, "numeric_value", value, |cx, val| {
+            let v = val.get(cx).into();
 
-                cx.style.numeric_value.insert(cx.current, v);
-                cx.style.needs_access_update(cx.current);
-            });
+            cx.style.numeric_value.insert(cx.current, v);
+            cx.style.needs_access_update(cx.current);
         });
 
         self
@@ -82,11 +97,9 @@ pub trait AccessibilityModifiers: internal::Modifiable {
     fn text_value<U: ToStringLocalized>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, |cx, val| {
-                cx.style.text_value.insert(cx.current, val.get(cx).to_string_local(cx));
-                cx.style.needs_access_update(cx.current);
-            });
+        set_or_rebind(self.context(), current, entity, "text_value", value, |cx, val| {
+            cx.style.text_value.insert(cx.current, val.get(cx).to_string_local(cx));
+            cx.style.needs_access_update(cx.current);
         });
 
         self
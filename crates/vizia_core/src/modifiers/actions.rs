@@ -73,6 +73,8 @@ pub(crate) struct ActionsModel {
     pub(crate) on_geo_changed: Option<Box<dyn Fn(&mut EventContext, GeoChanged) + Send + Sync>>,
     pub(crate) on_drag_start: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
     pub(crate) on_drop: Option<Box<dyn Fn(&mut EventContext, DropData) + Send + Sync>>,
+    // Whether `on_drag_start` has been called since the left mouse button was pressed.
+    drag_started: bool,
 }
 
 impl ActionsModel {
@@ -93,6 +95,20 @@ impl ActionsModel {
             on_geo_changed: None,
             on_drag_start: None,
             on_drop: None,
+            drag_started: false,
+        }
+    }
+
+    fn start_drag(&mut self, cx: &mut EventContext) {
+        if !self.drag_started
+            && cx.mouse.left.state == MouseButtonState::Pressed
+            && cx.mouse.left.pressed == cx.current()
+            && cx.is_draggable()
+        {
+            if let Some(action) = &self.on_drag_start {
+                self.drag_started = true;
+                (action)(cx);
+            }
         }
     }
 }
@@ -161,6 +177,14 @@ impl Model for ActionsModel {
             ActionsEvent::OnDrop(on_drop) => {
                 self.on_drop = Some(on_drop);
             }
+
+            ActionsEvent::Press => {
+                if !cx.is_disabled() {
+                    if let Some(action) = &self.on_press {
+                        (action)(cx);
+                    }
+                }
+            }
         });
 
         event.map(|window_event, meta| match window_event {
@@ -225,14 +249,7 @@ impl Model for ActionsModel {
                     (action)(cx);
                 }
 
-                if cx.mouse.left.state == MouseButtonState::Pressed
-                    && cx.mouse.left.pressed == cx.current()
-                    && cx.is_draggable()
-                {
-                    if let Some(action) = &self.on_drag_start {
-                        (action)(cx);
-                    }
-                }
+                self.start_drag(cx);
                 // }
             }
 
@@ -240,6 +257,14 @@ impl Model for ActionsModel {
                 if let Some(action) = &self.on_mouse_move {
                     (action)(cx, *x, *y);
                 }
+
+                // A drag also starts when the cursor moves far enough within the view.
+                let (down_x, down_y) = cx.mouse.left.pos_down;
+                let threshold = cx.interaction_settings().drag_threshold * cx.scale_factor();
+                if (x - down_x).hypot(y - down_y) >= threshold {
+                    self.start_drag(cx);
+                }
+
                 if cx.mouse.left.state == MouseButtonState::Released {
                     if let Some(drop_data) = cx.drop_data.take() {
                         if let Some(action) = &self.on_drop {
@@ -250,6 +275,10 @@ impl Model for ActionsModel {
             }
 
             WindowEvent::MouseDown(mouse_button) => {
+                if *mouse_button == MouseButton::Left {
+                    self.drag_started = false;
+                }
+
                 if let Some(action) = &self.on_mouse_down {
                     (action)(cx, *mouse_button);
                 }
@@ -307,6 +336,9 @@ pub(crate) enum ActionsEvent {
     OnGeoChanged(Box<dyn Fn(&mut EventContext, GeoChanged) + Send + Sync>),
     OnDragStart(Box<dyn Fn(&mut EventContext) + Send + Sync>),
     OnDrop(Box<dyn Fn(&mut EventContext, DropData) + Send + Sync>),
+    // Performs the press action of the view on behalf of another view, such as a dropdown choosing its highlighted
+    // option with the keyboard.
+    Press,
 }
 
 /// Modifiers which add an action callback to a view.
@@ -0,0 +1,195 @@
+use log::warn;
+
+use super::internal;
+use crate::prelude::*;
+
+/// A point on the bounds of a view, used to anchor one view to another.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AnchorPoint {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    #[default]
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl AnchorPoint {
+    /// Returns the position of the point within the given bounds.
+    pub fn position(self, bounds: BoundingBox) -> (f32, f32) {
+        let x = match self {
+            AnchorPoint::TopLeft | AnchorPoint::Left | AnchorPoint::BottomLeft => 0.0,
+            AnchorPoint::Top | AnchorPoint::Center | AnchorPoint::Bottom => 0.5,
+            AnchorPoint::TopRight | AnchorPoint::Right | AnchorPoint::BottomRight => 1.0,
+        };
+
+        let y = match self {
+            AnchorPoint::TopLeft | AnchorPoint::Top | AnchorPoint::TopRight => 0.0,
+            AnchorPoint::Left | AnchorPoint::Center | AnchorPoint::Right => 0.5,
+            AnchorPoint::BottomLeft | AnchorPoint::Bottom | AnchorPoint::BottomRight => 1.0,
+        };
+
+        (bounds.x + bounds.w * x, bounds.y + bounds.h * y)
+    }
+}
+
+/// Determines what happens to an anchored view when the view it is anchored to is removed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AnchorRemoval {
+    /// The anchored view is hidden. If the anchor targets an id, the view is shown again when a view with the id is
+    /// built.
+    #[default]
+    Hide,
+    /// The anchored view is released from the anchor and stays at its last position.
+    Release,
+}
+
+/// Describes how a view is positioned relative to the view it is anchored to.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct AnchorSpec {
+    /// The point on the target view which the anchored view is pinned to.
+    pub their: AnchorPoint,
+    /// The point on the anchored view which is placed at the point on the target view.
+    pub mine: AnchorPoint,
+    /// The offset of the anchored view from the point on the target view, in logical pixels.
+    pub offset: (f32, f32),
+    /// Whether the anchored view is sized to match the width and height of the target view.
+    pub match_size: bool,
+    /// What happens to the anchored view when the target view is removed.
+    pub on_target_removed: AnchorRemoval,
+}
+
+/// The view which a view is anchored to, given by either its entity or its id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnchorTarget {
+    Entity(Entity),
+    Id(String),
+}
+
+impl AnchorTarget {
+    /// Returns the entity of the target, if it exists.
+    pub(crate) fn resolve(&self, cx: &Context) -> Option<Entity> {
+        match self {
+            AnchorTarget::Entity(entity) => {
+                Some(*entity).filter(|e| cx.entity_manager.is_alive(*e))
+            }
+            AnchorTarget::Id(id) => cx.resolve_entity_identifier(id),
+        }
+    }
+}
+
+impl From<Entity> for AnchorTarget {
+    fn from(entity: Entity) -> Self {
+        AnchorTarget::Entity(entity)
+    }
+}
+
+impl From<&str> for AnchorTarget {
+    fn from(id: &str) -> Self {
+        AnchorTarget::Id(id.to_owned())
+    }
+}
+
+impl From<String> for AnchorTarget {
+    fn from(id: String) -> Self {
+        AnchorTarget::Id(id)
+    }
+}
+
+pub(crate) struct Anchor {
+    pub target: AnchorTarget,
+    pub spec: AnchorSpec,
+    // Whether the anchored view must be positioned even if neither it nor its target has moved.
+    pub dirty: bool,
+    // Whether the anchored view was hidden because its target was removed.
+    pub hidden: bool,
+}
+
+/// Returns true if anchoring the entity to the target would make the position of the target depend on the position
+/// of the entity.
+pub(crate) fn anchor_creates_cycle(cx: &Context, entity: Entity, target: &AnchorTarget) -> bool {
+    let mut next = target.resolve(cx);
+    // Each anchor is followed at most once, so a cycle which doesn't include the entity still ends the loop.
+    let mut remaining = cx.anchors.len() + 1;
+
+    while let Some(target) = next {
+        // The position of a view also determines the position of its descendants.
+        if target == entity || target.is_descendant_of(&cx.tree, entity) {
+            return true;
+        }
+
+        if remaining == 0 {
+            return false;
+        }
+
+        remaining -= 1;
+        next = cx.anchors.get(&target).and_then(|anchor| anchor.target.resolve(cx));
+    }
+
+    false
+}
+
+/// Modifiers for anchoring a view to another view elsewhere in the tree.
+pub trait AnchorModifiers: internal::Modifiable {
+    /// Anchors the view to the target view, given by its entity or its id, so that the view follows the target.
+    ///
+    /// The anchored view is given an absolute position type and doesn't take part in the layout of its siblings.
+    /// Whenever the bounds of the target change, the anchored view is moved after layout so that its `mine` point
+    /// lies on the `their` point of the target, plus the offset. Anchoring a view to itself, to one of its
+    /// descendants, or to a view which is anchored to it is rejected with a warning.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Button::new(cx, |cx| Label::new(cx, "Inbox")).id("inbox");
+    ///
+    /// Label::new(cx, "3").class("badge").anchor_to(
+    ///     "inbox",
+    ///     AnchorSpec { their: AnchorPoint::TopRight, mine: AnchorPoint::Center, ..Default::default() },
+    /// );
+    /// ```
+    fn anchor_to(mut self, target: impl Into<AnchorTarget>, spec: AnchorSpec) -> Self {
+        let entity = self.entity();
+        let target = target.into();
+        let cx = self.context();
+
+        if anchor_creates_cycle(cx, entity, &target) {
+            warn!(
+                "Anchoring {} to {:?} would create a cycle, so the anchor is ignored",
+                entity, target
+            );
+            return self;
+        }
+
+        // The anchored view is positioned from the top-left corner of its parent.
+        cx.style.position_type.insert(entity, PositionType::Absolute);
+        cx.style.left.insert(entity, Units::Pixels(0.0));
+        cx.style.top.insert(entity, Units::Pixels(0.0));
+        cx.style.needs_relayout_entity(entity);
+
+        cx.anchors.insert(entity, Anchor { target, spec, dirty: true, hidden: false });
+
+        self
+    }
+
+    /// Releases the view from its anchor, leaving it at its current position. A view which was hidden because its
+    /// target was removed is shown again.
+    fn release_anchor(mut self) -> Self {
+        let entity = self.entity();
+        let cx = self.context();
+        if cx.anchors.remove(&entity).is_some_and(|anchor| anchor.hidden) {
+            cx.style.display.remove(entity);
+            cx.needs_restyle(entity);
+            cx.style.needs_relayout_entity(entity);
+        }
+
+        self
+    }
+}
+
+impl<V: View> AnchorModifiers for Handle<'_, V> {}
@@ -1,4 +1,5 @@
 use super::internal;
+use super::set_or_rebind;
 use crate::prelude::*;
 
 /// Modifiers for changing the layout properties of a view.
@@ -56,6 +57,60 @@ pub trait LayoutModifiers: internal::Modifiable {
         SystemFlags::RELAYOUT
     );
 
+    modifier!(
+        /// Sets the sizes of the columns of the view when its layout type is `LayoutType::Grid`.
+        ///
+        /// A `Stretch` column shares the free space of the grid, like a column of `1fr` in CSS, and so does an `Auto`
+        /// column, as columns aren't sized to their content.
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// Element::new(cx).layout_type(LayoutType::Grid).grid_columns(vec![Pixels(100.0), Stretch(1.0)]);
+        /// ```
+        grid_columns,
+        Vec<Units>,
+        SystemFlags::RELAYOUT
+    );
+
+    modifier!(
+        /// Sets the sizes of the rows of the view when its layout type is `LayoutType::Grid`.
+        grid_rows,
+        Vec<Units>,
+        SystemFlags::RELAYOUT
+    );
+
+    modifier!(
+        /// Sets the index of the first column of its grid which the view occupies, starting from zero.
+        ///
+        /// A view without a column and a row is placed in the next free cell of the grid.
+        column_start,
+        usize,
+        SystemFlags::RELAYOUT
+    );
+
+    modifier!(
+        /// Sets the number of columns of its grid which the view occupies.
+        column_span,
+        usize,
+        SystemFlags::RELAYOUT
+    );
+
+    modifier!(
+        /// Sets the index of the first row of its grid which the view occupies, starting from zero.
+        row_start,
+        usize,
+        SystemFlags::RELAYOUT
+    );
+
+    modifier!(
+        /// Sets the number of rows of its grid which the view occupies.
+        row_span,
+        usize,
+        SystemFlags::RELAYOUT
+    );
+
     modifier!(
         /// Sets the space on the left side of the view.
         ///
@@ -144,16 +199,14 @@ pub trait LayoutModifiers: internal::Modifiable {
     fn space<U: Into<Units>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, |cx, v| {
-                let value = v.get(cx).into();
-                cx.style.left.insert(cx.current, value);
-                cx.style.right.insert(cx.current, value);
-                cx.style.top.insert(cx.current, value);
-                cx.style.bottom.insert(cx.current, value);
-
-                cx.style.needs_relayout();
-            });
+        set_or_rebind(self.context(), current, entity, "space", value, |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.left.insert(cx.current, value);
+            cx.style.right.insert(cx.current, value);
+            cx.style.top.insert(cx.current, value);
+            cx.style.bottom.insert(cx.current, value);
+
+            cx.style.needs_relayout_entity(cx.current);
         });
 
         self
@@ -177,19 +230,36 @@ pub trait LayoutModifiers: internal::Modifiable {
     fn size<U: Into<Units>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, move |cx, v| {
-                let value = v.get(cx).into();
-                cx.style.width.insert(cx.current, value);
-                cx.style.height.insert(cx.current, value);
-
-                cx.style.needs_relayout();
-            });
+        set_or_rebind(self.context(), current, entity, "size", value, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.width.insert(cx.current, value);
+            cx.style.height.insert(cx.current, value);
+
+            cx.style.needs_relayout_entity(cx.current);
         });
 
         self
     }
 
+    modifier!(
+        /// Sets the preferred ratio of the width to the height of the view.
+        ///
+        /// The size of the axis which is auto, or which stretches while the other axis has a fixed size, is derived
+        /// from the size of the other axis, and is still clamped by the size constraints of the view. The ratio is
+        /// ignored if both axes have a fixed size, and only applies to views without children which are positioned
+        /// by them.
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// Element::new(cx).width(Stretch(1.0)).height(Auto).aspect_ratio((16.0, 9.0));
+        /// ```
+        aspect_ratio,
+        AspectRatio,
+        SystemFlags::RELAYOUT
+    );
+
     modifier!(
         /// Sets the space between the left side of the view and the left side of its children.
         ///
@@ -228,22 +298,53 @@ pub trait LayoutModifiers: internal::Modifiable {
 
     modifier!(alignment, Alignment, SystemFlags::RELAYOUT);
 
+    modifier!(
+        /// Sets whether the view is aligned by its baseline within a row.
+        ///
+        /// The views in a row which are aligned by their baseline are moved down so that their baselines coincide,
+        /// for example to align a value with its unit in a smaller font. The baseline of a view is the baseline of
+        /// the first line of its text, or its bottom edge if it has no text, unless it has a
+        /// [`baseline_offset`](LayoutModifiers::baseline_offset).
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// HStack::new(cx, |cx| {
+        ///     Label::new(cx, "-12.5").font_size(24.0).align_baseline(true);
+        ///     Label::new(cx, "dB").font_size(12.0).align_baseline(true);
+        /// });
+        /// ```
+        align_baseline,
+        bool,
+        SystemFlags::RELAYOUT
+    );
+
+    modifier!(
+        /// Sets the distance from the top of the view to its baseline, overriding the baseline of its text.
+        ///
+        /// - `Units::Pixels(...)` - The baseline is a fixed number of points below the top of the view.
+        /// - `Units::Percentage(...)` - The baseline is a proportion of the view height below the top of the view.
+        /// - `Units::Stretch(...)` and `Units::Auto` - The baseline is the bottom edge of the view.
+        baseline_offset,
+        Units,
+        SystemFlags::RELAYOUT
+    );
+
     /// Sets the space between the vew and its children.
     ///
     /// The child_space works by overriding the `Auto` space properties of its children.
     fn padding<U: Into<Units>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, move |cx, v| {
-                let value = v.get(cx).into();
-                cx.style.padding_left.insert(cx.current, value);
-                cx.style.padding_right.insert(cx.current, value);
-                cx.style.padding_top.insert(cx.current, value);
-                cx.style.padding_bottom.insert(cx.current, value);
-
-                cx.style.needs_relayout();
-            });
+        set_or_rebind(self.context(), current, entity, "padding", value, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.padding_left.insert(cx.current, value);
+            cx.style.padding_right.insert(cx.current, value);
+            cx.style.padding_top.insert(cx.current, value);
+            cx.style.padding_bottom.insert(cx.current, value);
+
+            cx.style.needs_relayout_entity(cx.current);
         });
 
         self
@@ -267,14 +368,36 @@ pub trait LayoutModifiers: internal::Modifiable {
     fn gap<U: Into<Units>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, move |cx, v| {
-                let value = v.get(cx).into();
-                cx.style.horizontal_gap.insert(cx.current, value);
-                cx.style.vertical_gap.insert(cx.current, value);
-
-                cx.style.needs_relayout();
-            });
+        set_or_rebind(self.context(), current, entity, "gap", value, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.horizontal_gap.insert(cx.current, value);
+            cx.style.vertical_gap.insert(cx.current, value);
+
+            cx.style.needs_relayout_entity(cx.current);
+        });
+
+        self
+    }
+
+    /// Sets the space between the rows of the views children, which is the same as the vertical gap.
+    fn row_gap<U: Into<Units>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        set_or_rebind(self.context(), current, entity, "row_gap", value, move |cx, v| {
+            cx.style.vertical_gap.insert(cx.current, v.get(cx).into());
+            cx.style.needs_relayout_entity(cx.current);
+        });
+
+        self
+    }
+
+    /// Sets the space between the columns of the views children, which is the same as the horizontal gap.
+    fn col_gap<U: Into<Units>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        set_or_rebind(self.context(), current, entity, "col_gap", value, move |cx, v| {
+            cx.style.horizontal_gap.insert(cx.current, v.get(cx).into());
+            cx.style.needs_relayout_entity(cx.current);
         });
 
         self
@@ -302,14 +425,12 @@ pub trait LayoutModifiers: internal::Modifiable {
     fn min_size<U: Into<Units>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, move |cx, v| {
-                let value = v.get(cx).into();
-                cx.style.min_width.insert(cx.current, value);
-                cx.style.min_height.insert(cx.current, value);
-
-                cx.needs_relayout();
-            });
+        set_or_rebind(self.context(), current, entity, "min_size", value, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.min_width.insert(cx.current, value);
+            cx.style.min_height.insert(cx.current, value);
+
+            cx.style.needs_relayout_entity(cx.current);
         });
 
         self
@@ -333,14 +454,12 @@ pub trait LayoutModifiers: internal::Modifiable {
     fn max_size<U: Into<Units>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(entity, |cx| {
-            value.set_or_bind(cx, current, move |cx, v| {
-                let value = v.get(cx).into();
-                cx.style.max_width.insert(cx.current, value);
-                cx.style.max_height.insert(cx.current, value);
-
-                cx.needs_relayout();
-            });
+        set_or_rebind(self.context(), current, entity, "max_size", value, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.max_width.insert(cx.current, value);
+            cx.style.max_height.insert(cx.current, value);
+
+            cx.style.needs_relayout_entity(cx.current);
         });
 
         self
@@ -364,14 +483,12 @@ pub trait LayoutModifiers: internal::Modifiable {
     fn min_gap<U: Into<Units>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, move |cx, v| {
-                let value = v.get(cx).into();
-                cx.style.min_horizontal_gap.insert(cx.current, value);
-                cx.style.min_vertical_gap.insert(cx.current, value);
-
-                cx.needs_relayout();
-            });
+        set_or_rebind(self.context(), current, entity, "min_gap", value, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.min_horizontal_gap.insert(cx.current, value);
+            cx.style.min_vertical_gap.insert(cx.current, value);
+
+            cx.style.needs_relayout_entity(cx.current);
         });
 
         self
@@ -395,14 +512,12 @@ pub trait LayoutModifiers: internal::Modifiable {
     fn max_gap<U: Into<Units>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, move |cx, v| {
-                let value = v.get(cx).into();
-                cx.style.max_horizontal_gap.insert(cx.current, value);
-                cx.style.max_vertical_gap.insert(cx.current, value);
-
-                cx.needs_relayout();
-            });
+        set_or_rebind(self.context(), current, entity, "max_gap", value, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.max_horizontal_gap.insert(cx.current, value);
+            cx.style.max_vertical_gap.insert(cx.current, value);
+
+            cx.style.needs_relayout_entity(cx.current);
         });
 
         self
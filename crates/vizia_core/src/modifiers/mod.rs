@@ -36,7 +36,16 @@
 //! # }).run();
 //! ```
 
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use crate::prelude::*;
+
 // Macro used within modifier traits to set style properties.
+//
+// The flags determine which systems must run again when the property changes. Inline properties do not take part
+// in selector matching, so only properties which can change the matched rules, such as `disabled`, should use
+// `SystemFlags::RESTYLE`. Other properties use `RELAYOUT`, `REFLOW`, `REPAINT`, or `REDRAW` depending on whether
+// they affect layout, text layout, text painting, or only drawing.
 macro_rules! modifier {
     (
         $(#[$meta:meta])*
@@ -47,10 +56,9 @@ macro_rules! modifier {
         fn $name<U: Into<$t>>(mut self, value: impl Res<U>) -> Self {
             let entity = self.entity();
             let current = self.current();
-            value.set_or_bind(self.context(), current, move |cx, v| {
+            set_or_rebind(self.context(), current, entity, stringify!($name), value, move |cx, v| {
                 cx.style.$name.insert(entity, v.get(cx).into());
 
-                cx.style.system_flags |= $flags;
                 cx.set_system_flags(entity, $flags);
             });
 
@@ -59,6 +67,43 @@ macro_rules! modifier {
     };
 }
 
+// Sets a modifier of a view to a value, or binds it to a lens, with `Res::set_or_bind`.
+//
+// A binding created by a previous application of the same modifier to the view is removed first, so that applying a
+// modifier again replaces its value rather than adding another observer which keeps setting the old one. Modifiers
+// are told apart by the key, which is the name of the modifier, along with its arguments for modifiers such as
+// `toggle_class` which can be applied several times with different arguments.
+fn set_or_rebind<T, R, F>(
+    cx: &mut Context,
+    current: Entity,
+    entity: Entity,
+    key: impl Hash,
+    value: R,
+    closure: F,
+) where
+    R: Res<T>,
+    F: 'static + Clone + Fn(&mut Context, R),
+{
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    let key = hasher.finish();
+
+    let previous = cx.modifier_bindings.get_mut(&entity).and_then(|bindings| bindings.remove(&key));
+    if let Some(binding) = previous.filter(|binding| cx.entity_manager.is_alive(*binding)) {
+        cx.remove(binding);
+    }
+
+    let last_child = cx.tree.get_last_child(current).copied();
+    cx.with_current(current, |cx| value.set_or_bind(cx, entity, closure));
+
+    // A lens is bound with a binding view, which is added as the last child of the current view.
+    if let Some(binding) = cx.tree.get_last_child(current).copied() {
+        if Some(binding) != last_child && cx.bindings.contains_key(&binding) {
+            cx.modifier_bindings.entry(entity).or_default().insert(key, binding);
+        }
+    }
+}
+
 // Inside private module to hide implementation details.
 mod internal {
     use crate::prelude::{Context, Entity, Handle};
@@ -102,3 +147,162 @@ pub use text::*;
 
 mod abilities;
 pub use abilities::*;
+
+mod movable;
+pub use movable::*;
+
+mod anchor;
+pub use anchor::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binding::Store;
+    use crate::systems::binding_system;
+
+    #[derive(Lens)]
+    struct AppData {
+        on: bool,
+    }
+
+    impl Model for AppData {}
+
+    fn observers(cx: &Context) -> usize {
+        cx.data
+            .values()
+            .flat_map(|model_data_store| model_data_store.stores.values())
+            .map(|store| store.num_observers())
+            .sum()
+    }
+
+    fn shadow() -> Shadow {
+        Shadow::from("0px 2px 4px black")
+    }
+
+    fn set_on(cx: &mut Context, on: bool) {
+        cx.replace_model(AppData { on });
+        binding_system(cx);
+    }
+
+    #[test]
+    fn flipping_a_lens_updates_each_kind_of_property() {
+        let mut cx = Context::default();
+
+        AppData { on: false }.build(&mut cx);
+        let entity = Element::new(&mut cx)
+            .background_color(AppData::on.map(|on| if *on { Color::red() } else { Color::blue() }))
+            .width(AppData::on.map(|on| if *on { Pixels(100.0) } else { Pixels(50.0) }))
+            .font_size(AppData::on.map(|on| if *on { 20.0 } else { 10.0 }))
+            .role(AppData::on.map(|on| if *on { Role::Button } else { Role::Label }))
+            .toggle_class("on", AppData::on)
+            .box_shadow(AppData::on.map(|on| if *on { vec![shadow(), shadow()] } else { vec![] }))
+            .entity();
+
+        set_on(&mut cx, true);
+
+        assert_eq!(cx.style.background_color.get(entity), Some(&Color::red()));
+        assert_eq!(cx.style.width.get(entity), Some(&Pixels(100.0)));
+        assert_eq!(cx.style.font_size.get(entity), Some(&FontSize(20.0)));
+        assert_eq!(cx.style.role.get(entity), Some(&Role::Button));
+        assert!(cx.style.classes.get(entity).is_some_and(|classes| classes.contains("on")));
+        assert_eq!(cx.style.shadow.get(entity), Some(&vec![shadow(), shadow()]));
+
+        set_on(&mut cx, false);
+
+        assert_eq!(cx.style.background_color.get(entity), Some(&Color::blue()));
+        assert_eq!(cx.style.width.get(entity), Some(&Pixels(50.0)));
+        assert_eq!(cx.style.font_size.get(entity), Some(&FontSize(10.0)));
+        assert_eq!(cx.style.role.get(entity), Some(&Role::Label));
+        assert!(cx.style.classes.get(entity).is_some_and(|classes| !classes.contains("on")));
+        assert_eq!(cx.style.shadow.get(entity), Some(&vec![]));
+    }
+
+    #[test]
+    fn transitions_animate_changes_made_by_bindings() {
+        let mut cx = Context::default();
+
+        AppData { on: false }.build(&mut cx);
+        let entity = Element::new(&mut cx)
+            .opacity(AppData::on.map(|on| if *on { 1.0 } else { 0.0 }))
+            .width(AppData::on.map(|on| if *on { Pixels(100.0) } else { Pixels(50.0) }))
+            .transition("opacity", Duration::from_secs(1), EasingFunction::Linear)
+            .transition("width", Duration::from_secs(1), EasingFunction::Linear)
+            .entity();
+
+        let opacity = |cx: &Context| cx.style.opacity.get(entity).map(|opacity| opacity.0);
+        assert_eq!(opacity(&cx), Some(0.0));
+
+        set_on(&mut cx, true);
+        assert_eq!(opacity(&cx), Some(0.0));
+        cx.style.opacity.tick(Instant::now() + Duration::from_millis(500), ColorSpace::default());
+        assert!(opacity(&cx).is_some_and(|opacity| (opacity - 0.5).abs() < 0.05));
+        assert!(cx.style.width.get_active_animation(entity).is_some());
+
+        // Removing the transition by giving it no duration makes the property change at once.
+        cx.style.opacity.tick(Instant::now() + Duration::from_secs(2), ColorSpace::default());
+        cx.style.opacity.remove_innactive_animations();
+        let transition = Transition::new("opacity".to_string(), Duration::ZERO, None, None);
+        cx.style.set_transition(entity, &transition);
+        set_on(&mut cx, false);
+        assert_eq!(opacity(&cx), Some(0.0));
+    }
+
+    #[test]
+    fn observers_do_not_accumulate_across_updates() {
+        let mut cx = Context::default();
+
+        AppData { on: false }.build(&mut cx);
+        let mut handle = Element::new(&mut cx);
+        for _ in 0..10 {
+            handle = handle
+                .background_color(
+                    AppData::on.map(|on| if *on { Color::red() } else { Color::blue() }),
+                )
+                .toggle_class("on", AppData::on);
+        }
+        let entity = handle.entity();
+
+        let initial = observers(&cx);
+        assert_eq!(initial, 2);
+
+        for i in 0..50 {
+            set_on(&mut cx, i % 2 == 0);
+            assert_eq!(observers(&cx), initial);
+        }
+
+        assert_eq!(cx.style.background_color.get(entity), Some(&Color::blue()));
+    }
+
+    #[test]
+    fn reapplying_a_modifier_with_a_different_lens_replaces_its_binding() {
+        let mut cx = Context::default();
+
+        AppData { on: false }.build(&mut cx);
+        let entity = Element::new(&mut cx)
+            .width(AppData::on.map(|on| if *on { Pixels(100.0) } else { Pixels(50.0) }))
+            .width(AppData::on.map(|on| if *on { Pixels(200.0) } else { Pixels(20.0) }))
+            .entity();
+        assert_eq!(observers(&cx), 1);
+        assert_eq!(cx.style.width.get(entity), Some(&Pixels(20.0)));
+
+        set_on(&mut cx, true);
+        assert_eq!(cx.style.width.get(entity), Some(&Pixels(200.0)));
+    }
+
+    #[test]
+    fn reapplying_a_modifier_with_a_value_removes_its_binding() {
+        let mut cx = Context::default();
+
+        AppData { on: false }.build(&mut cx);
+        let entity = Element::new(&mut cx)
+            .width(AppData::on.map(|on| if *on { Pixels(100.0) } else { Pixels(50.0) }))
+            .toggle_class("on", AppData::on)
+            .width(Pixels(10.0))
+            .entity();
+        assert_eq!(observers(&cx), 1);
+
+        set_on(&mut cx, true);
+        assert_eq!(cx.style.width.get(entity), Some(&Pixels(10.0)));
+        assert!(cx.style.classes.get(entity).is_some_and(|classes| classes.contains("on")));
+    }
+}
@@ -0,0 +1,507 @@
+use std::any::TypeId;
+
+use super::internal;
+use crate::prelude::*;
+
+pub(crate) struct MovableModel {
+    horizontal: bool,
+    vertical: bool,
+    snap: Option<Units>,
+    bounds: Option<BoundingBox>,
+    on_move: Option<Box<dyn Fn(&mut EventContext, (f32, f32)) + Send + Sync>>,
+    // The cursor position and the position of the view when the drag started.
+    drag_start: Option<((f32, f32), (f32, f32))>,
+}
+
+pub(crate) enum MovableEvent {
+    SetAxes(bool, bool),
+    SetSnap(Units),
+    SetBounds(BoundingBox),
+    OnMove(Box<dyn Fn(&mut EventContext, (f32, f32)) + Send + Sync>),
+}
+
+impl MovableModel {
+    fn parent(cx: &EventContext) -> Entity {
+        cx.tree.get_layout_parent(cx.current()).unwrap_or(Entity::root())
+    }
+
+    // Returns the cursor position in logical pixels within the parent, undoing any transforms of the parent and its ancestors.
+    fn cursor(cx: &EventContext) -> (f32, f32) {
+        let parent = Self::parent(cx);
+        let parent_bounds = cx.cache.get_bounds(parent);
+        let (cursor_x, cursor_y) = (cx.mouse().cursor_x, cx.mouse().cursor_y);
+        let (x, y) = cx
+            .cache
+            .transform
+            .get(parent)
+            .and_then(|transform| transform.invert())
+            .map(|inverse| {
+                let point = inverse.map_point((cursor_x, cursor_y));
+                (point.x, point.y)
+            })
+            .unwrap_or((cursor_x, cursor_y));
+
+        let scale_factor = cx.scale_factor();
+        ((x - parent_bounds.x) / scale_factor, (y - parent_bounds.y) / scale_factor)
+    }
+
+    // Returns the position of the view in logical pixels within the parent.
+    fn offset(cx: &EventContext) -> (f32, f32) {
+        let bounds = cx.bounds();
+        let parent_bounds = cx.cache.get_bounds(Self::parent(cx));
+        let scale_factor = cx.scale_factor();
+        ((bounds.x - parent_bounds.x) / scale_factor, (bounds.y - parent_bounds.y) / scale_factor)
+    }
+
+    // Returns the current `left` and `top` of the view, falling back to its position within the parent.
+    fn position(cx: &EventContext) -> (f32, f32) {
+        let (x, y) = Self::offset(cx);
+        let left = match cx.style.left.get(cx.current()) {
+            Some(Units::Pixels(left)) => *left,
+            _ => x,
+        };
+        let top = match cx.style.top.get(cx.current()) {
+            Some(Units::Pixels(top)) => *top,
+            _ => y,
+        };
+
+        (left, top)
+    }
+
+    fn move_to(&self, cx: &mut EventContext, (mut left, mut top): (f32, f32)) {
+        let current = Self::position(cx);
+        if !self.horizontal {
+            left = current.0;
+        }
+
+        if !self.vertical {
+            top = current.1;
+        }
+
+        let scale_factor = cx.scale_factor();
+        let parent_bounds = cx.cache.get_bounds(Self::parent(cx));
+        let (parent_width, parent_height) =
+            (parent_bounds.w / scale_factor, parent_bounds.h / scale_factor);
+
+        if let Some(snap) = self.snap {
+            let step_x = snap.to_px(parent_width, 0.0);
+            let step_y = snap.to_px(parent_height, 0.0);
+            if step_x > 0.0 {
+                left = (left / step_x).round() * step_x;
+            }
+
+            if step_y > 0.0 {
+                top = (top / step_y).round() * step_y;
+            }
+        }
+
+        // The `left` and `top` properties may not be measured from the top-left corner of the parent,
+        // for example because of padding, so the bounds are offset by the difference.
+        let offset = Self::offset(cx);
+        let (origin_x, origin_y) = (offset.0 - current.0, offset.1 - current.1);
+        let bounds = cx.bounds();
+        let (width, height) = (bounds.w / scale_factor, bounds.h / scale_factor);
+        let area = self.bounds.unwrap_or(BoundingBox {
+            x: 0.0,
+            y: 0.0,
+            w: parent_width,
+            h: parent_height,
+        });
+
+        left = left.min(area.right() - width - origin_x).max(area.x - origin_x);
+        top = top.min(area.bottom() - height - origin_y).max(area.y - origin_y);
+
+        if (left, top) != current {
+            cx.set_left(Units::Pixels(left));
+            cx.set_top(Units::Pixels(top));
+
+            if let Some(callback) = &self.on_move {
+                (callback)(cx, (left, top));
+            }
+        }
+    }
+}
+
+impl Model for MovableModel {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.take(|movable_event, _| match movable_event {
+            MovableEvent::SetAxes(horizontal, vertical) => {
+                self.horizontal = horizontal;
+                self.vertical = vertical;
+            }
+
+            MovableEvent::SetSnap(snap) => self.snap = Some(snap),
+
+            MovableEvent::SetBounds(bounds) => self.bounds = Some(bounds),
+
+            MovableEvent::OnMove(on_move) => self.on_move = Some(on_move),
+        });
+
+        if !self.horizontal && !self.vertical {
+            return;
+        }
+
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                // Don't take over the drags of descendants which have captured the mouse, such as sliders.
+                if cx.is_disabled() || *cx.captured != Entity::null() {
+                    return;
+                }
+
+                if meta.target == cx.current() {
+                    cx.focus();
+                }
+
+                self.drag_start = Some((Self::cursor(cx), Self::position(cx)));
+                cx.capture();
+                cx.set_dragging(true);
+            }
+
+            WindowEvent::MouseMove(_, _) => {
+                if let Some((start_cursor, start_position)) = self.drag_start {
+                    let cursor = Self::cursor(cx);
+                    self.move_to(
+                        cx,
+                        (
+                            start_position.0 + cursor.0 - start_cursor.0,
+                            start_position.1 + cursor.1 - start_cursor.1,
+                        ),
+                    );
+                }
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                if self.drag_start.take().is_some() {
+                    cx.release();
+                    cx.set_dragging(false);
+                    meta.consume();
+                }
+            }
+
+            WindowEvent::KeyDown(code, _) if cx.is_focused() && self.drag_start.is_none() => {
+                // When snapping, the view is moved by the snap step instead, so that it doesn't snap back.
+                let step = match self.snap {
+                    Some(Units::Pixels(step)) => step,
+                    _ => 1.0,
+                };
+                let step = if cx.modifiers.shift() { step * 10.0 } else { step };
+
+                let (dx, dy) = match code {
+                    Code::ArrowLeft => (-step, 0.0),
+                    Code::ArrowRight => (step, 0.0),
+                    Code::ArrowUp => (0.0, -step),
+                    Code::ArrowDown => (0.0, step),
+                    _ => return,
+                };
+
+                let (left, top) = Self::position(cx);
+                self.move_to(cx, (left + dx, top + dy));
+                meta.consume();
+            }
+
+            _ => {}
+        });
+    }
+}
+
+// If the entity doesn't have a `MovableModel` then add one to the entity.
+fn build_movable_model(cx: &mut Context, entity: Entity) {
+    if cx
+        .data
+        .get(&entity)
+        .and_then(|model_data_store| model_data_store.models.get(&TypeId::of::<MovableModel>()))
+        .is_none()
+    {
+        cx.with_current(entity, |cx| {
+            MovableModel {
+                horizontal: false,
+                vertical: false,
+                snap: None,
+                bounds: None,
+                on_move: None,
+                drag_start: None,
+            }
+            .build(cx);
+        });
+    }
+}
+
+fn emit_movable_event(cx: &mut Context, entity: Entity, event: MovableEvent) {
+    build_movable_model(cx, entity);
+    cx.emit_custom(Event::new(event).target(entity).origin(entity));
+}
+
+/// Modifiers which let the user move a view by dragging it.
+pub trait MovableModifiers: internal::Modifiable {
+    /// Makes the view movable within the bounds of its parent, by dragging it with the left mouse button or by
+    /// pressing the arrow keys while it is focused. Each key press moves the view by 1 pixel, or by 10 pixels while
+    /// holding shift.
+    ///
+    /// The view is moved by setting its `left` and `top` properties, so it is best used with an absolute position
+    /// type. While the view is being dragged it can be selected with the `:dragging` CSS pseudo-class.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx)
+    ///     .size(Pixels(100.0))
+    ///     .position_type(PositionType::Absolute)
+    ///     .movable()
+    ///     .snap(Pixels(8.0))
+    ///     .on_move(|_, (left, top)| println!("Moved to {} {}", left, top));
+    /// ```
+    fn movable(mut self) -> Self {
+        let entity = self.entity();
+        if let Some(abilities) = self.context().style.abilities.get_mut(entity) {
+            abilities.set(Abilities::FOCUSABLE, true);
+        }
+
+        emit_movable_event(self.context(), entity, MovableEvent::SetAxes(true, true));
+
+        self
+    }
+
+    /// Makes the view movable as with [`movable`](Self::movable), but only horizontally.
+    fn movable_x(mut self) -> Self {
+        let entity = self.entity();
+        self = self.movable();
+        emit_movable_event(self.context(), entity, MovableEvent::SetAxes(true, false));
+
+        self
+    }
+
+    /// Makes the view movable as with [`movable`](Self::movable), but only vertically.
+    fn movable_y(mut self) -> Self {
+        let entity = self.entity();
+        self = self.movable();
+        emit_movable_event(self.context(), entity, MovableEvent::SetAxes(false, true));
+
+        self
+    }
+
+    /// Snaps the position of a movable view to a grid with the given spacing.
+    ///
+    /// While snapping, the arrow keys move the view by the spacing instead of by 1 pixel.
+    fn snap<U: Into<Units>>(mut self, spacing: U) -> Self {
+        let entity = self.entity();
+        emit_movable_event(self.context(), entity, MovableEvent::SetSnap(spacing.into()));
+
+        self
+    }
+
+    /// Limits the position of a movable view to the given area, in logical pixels relative to its parent,
+    /// instead of to the bounds of its parent.
+    fn movable_bounds(mut self, bounds: BoundingBox) -> Self {
+        let entity = self.entity();
+        emit_movable_event(self.context(), entity, MovableEvent::SetBounds(bounds));
+
+        self
+    }
+
+    /// Adds a callback which is called with the new `left` and `top` of a movable view, in logical pixels,
+    /// whenever the view is moved.
+    fn on_move<F>(mut self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, (f32, f32)) + Send + Sync,
+    {
+        let entity = self.entity();
+        emit_movable_event(self.context(), entity, MovableEvent::OnMove(Box::new(callback)));
+
+        self
+    }
+}
+
+impl<V: View> MovableModifiers for Handle<'_, V> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+    use crate::systems::{layout_system, transform_system};
+
+    fn build_movable(
+        cx: &mut Context,
+        modifiers: impl FnOnce(Handle<Element>) -> Handle<Element>,
+    ) -> Entity {
+        cx.windows.insert(Entity::root(), WindowState::default());
+        cx.style.dpi_factor = 1.0;
+        cx.style.width.insert(Entity::root(), Units::Pixels(800.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(600.0));
+        cx.style.pseudo_classes.insert(Entity::root(), PseudoClassFlags::OVER);
+
+        let handle = Element::new(cx)
+            .size(Pixels(100.0))
+            .position_type(PositionType::Absolute)
+            .left(Pixels(0.0))
+            .top(Pixels(0.0));
+        let entity = modifiers(handle.movable()).entity();
+        EventManager::new().flush_events(cx, |_| {});
+        layout_system(cx);
+        transform_system(cx);
+
+        entity
+    }
+
+    fn emit(cx: &mut Context, event_manager: &mut EventManager, event: WindowEvent) {
+        cx.emit_custom(
+            Event::new(event)
+                .target(Entity::root())
+                .origin(Entity::root())
+                .propagate(Propagation::Up),
+        );
+        event_manager.flush_events(cx, |_| {});
+        layout_system(cx);
+        transform_system(cx);
+    }
+
+    fn drag(cx: &mut Context, from: (f32, f32), to: (f32, f32)) {
+        let mut event_manager = EventManager::new();
+        emit(cx, &mut event_manager, WindowEvent::MouseMove(from.0, from.1));
+        emit(cx, &mut event_manager, WindowEvent::MouseDown(MouseButton::Left));
+        emit(cx, &mut event_manager, WindowEvent::MouseMove(to.0, to.1));
+        emit(cx, &mut event_manager, WindowEvent::MouseUp(MouseButton::Left));
+    }
+
+    fn position(cx: &Context, entity: Entity) -> (f32, f32) {
+        match (cx.style.left.get(entity), cx.style.top.get(entity)) {
+            (Some(Units::Pixels(left)), Some(Units::Pixels(top))) => (*left, *top),
+            _ => panic!("The view has no position in pixels"),
+        }
+    }
+
+    fn is_dragging(cx: &Context, entity: Entity) -> bool {
+        cx.style
+            .pseudo_classes
+            .get(entity)
+            .is_some_and(|flags| flags.contains(PseudoClassFlags::DRAGGING))
+    }
+
+    #[test]
+    fn dragging_moves_the_view_with_the_cursor() {
+        let mut cx = Context::default();
+        let movable = build_movable(&mut cx, |handle| handle);
+
+        let mut event_manager = EventManager::new();
+        emit(&mut cx, &mut event_manager, WindowEvent::MouseMove(50.0, 50.0));
+        emit(&mut cx, &mut event_manager, WindowEvent::MouseDown(MouseButton::Left));
+        assert!(is_dragging(&cx, movable));
+        assert_eq!(cx.focused, movable);
+
+        emit(&mut cx, &mut event_manager, WindowEvent::MouseMove(80.0, 70.0));
+        assert_eq!(position(&cx, movable), (30.0, 20.0));
+        emit(&mut cx, &mut event_manager, WindowEvent::MouseMove(130.0, 90.0));
+        assert_eq!(position(&cx, movable), (80.0, 40.0));
+
+        emit(&mut cx, &mut event_manager, WindowEvent::MouseUp(MouseButton::Left));
+        assert!(!is_dragging(&cx, movable));
+        assert_eq!(cx.captured, Entity::null());
+
+        // Moving the cursor after the release leaves the view where it was dropped.
+        emit(&mut cx, &mut event_manager, WindowEvent::MouseMove(300.0, 300.0));
+        assert_eq!(position(&cx, movable), (80.0, 40.0));
+    }
+
+    #[test]
+    fn dragging_is_limited_to_the_movable_axis() {
+        let mut cx = Context::default();
+        let movable = build_movable(&mut cx, |handle| handle.movable_x());
+
+        drag(&mut cx, (50.0, 50.0), (90.0, 120.0));
+        assert_eq!(position(&cx, movable), (40.0, 0.0));
+    }
+
+    #[test]
+    fn dragging_snaps_to_the_grid() {
+        let mut cx = Context::default();
+        let movable = build_movable(&mut cx, |handle| handle.snap(Pixels(8.0)));
+
+        drag(&mut cx, (50.0, 50.0), (63.0, 55.0));
+        assert_eq!(position(&cx, movable), (16.0, 8.0));
+
+        drag(&mut cx, (60.0, 60.0), (62.0, 63.0));
+        assert_eq!(position(&cx, movable), (16.0, 8.0));
+    }
+
+    #[test]
+    fn dragging_is_clamped_to_the_parent() {
+        let mut cx = Context::default();
+        let movable = build_movable(&mut cx, |handle| handle);
+
+        drag(&mut cx, (50.0, 50.0), (1000.0, -100.0));
+        assert_eq!(position(&cx, movable), (700.0, 0.0));
+
+        drag(&mut cx, (750.0, 50.0), (-200.0, 1000.0));
+        assert_eq!(position(&cx, movable), (0.0, 500.0));
+    }
+
+    #[test]
+    fn dragging_is_clamped_to_the_movable_bounds() {
+        let mut cx = Context::default();
+        let bounds = BoundingBox { x: 10.0, y: 10.0, w: 200.0, h: 200.0 };
+        let movable = build_movable(&mut cx, |handle| handle.movable_bounds(bounds));
+
+        drag(&mut cx, (50.0, 50.0), (1000.0, 1000.0));
+        assert_eq!(position(&cx, movable), (110.0, 110.0));
+
+        drag(&mut cx, (150.0, 150.0), (0.0, 0.0));
+        assert_eq!(position(&cx, movable), (10.0, 10.0));
+    }
+
+    #[test]
+    fn arrow_keys_nudge_the_focused_view() {
+        let mut cx = Context::default();
+        let moves = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let on_move = moves.clone();
+        let movable = build_movable(&mut cx, |handle| {
+            handle.on_move(move |_, position| on_move.lock().unwrap().push(position))
+        });
+
+        // The view only moves while it is focused.
+        let mut event_manager = EventManager::new();
+        emit(&mut cx, &mut event_manager, WindowEvent::KeyDown(Code::ArrowRight, None));
+        assert_eq!(position(&cx, movable), (0.0, 0.0));
+
+        cx.focused = movable;
+        emit(&mut cx, &mut event_manager, WindowEvent::KeyDown(Code::ArrowRight, None));
+        emit(&mut cx, &mut event_manager, WindowEvent::KeyDown(Code::ArrowDown, None));
+        assert_eq!(position(&cx, movable), (1.0, 1.0));
+
+        cx.modifiers = Modifiers::SHIFT;
+        emit(&mut cx, &mut event_manager, WindowEvent::KeyDown(Code::ArrowRight, None));
+        emit(&mut cx, &mut event_manager, WindowEvent::KeyDown(Code::ArrowDown, None));
+        assert_eq!(position(&cx, movable), (11.0, 11.0));
+
+        // The view can't be nudged out of its parent.
+        emit(&mut cx, &mut event_manager, WindowEvent::KeyDown(Code::ArrowLeft, None));
+        emit(&mut cx, &mut event_manager, WindowEvent::KeyDown(Code::ArrowUp, None));
+        emit(&mut cx, &mut event_manager, WindowEvent::KeyDown(Code::ArrowUp, None));
+        assert_eq!(position(&cx, movable), (1.0, 0.0));
+
+        assert_eq!(
+            *moves.lock().unwrap(),
+            vec![
+                (1.0, 0.0),
+                (1.0, 1.0),
+                (11.0, 1.0),
+                (11.0, 11.0),
+                (1.0, 11.0),
+                (1.0, 1.0),
+                (1.0, 0.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn arrow_keys_move_by_the_snap_step() {
+        let mut cx = Context::default();
+        let movable = build_movable(&mut cx, |handle| handle.snap(Pixels(8.0)));
+        cx.focused = movable;
+
+        let mut event_manager = EventManager::new();
+        emit(&mut cx, &mut event_manager, WindowEvent::KeyDown(Code::ArrowRight, None));
+        emit(&mut cx, &mut event_manager, WindowEvent::KeyDown(Code::ArrowDown, None));
+        emit(&mut cx, &mut event_manager, WindowEvent::KeyDown(Code::ArrowDown, None));
+        assert_eq!(position(&cx, movable), (8.0, 16.0));
+    }
+}
@@ -1,6 +1,8 @@
+use hashbrown::{HashMap, HashSet};
 use vizia_style::{ColorStop, CornerRadius, Rect};
 
 use super::internal;
+use super::set_or_rebind;
 use crate::prelude::*;
 
 /// Modifiers for changing the style properties of a view.
@@ -55,6 +57,10 @@ pub trait StyleModifiers: internal::Modifiable {
         let entity = self.entity();
         if let Some(class_list) = self.context().style.classes.get_mut(entity) {
             class_list.insert(name.to_string());
+        } else {
+            let mut class_list = HashSet::new();
+            class_list.insert(name.to_string());
+            self.context().style.classes.insert(entity, class_list);
         }
 
         self.context().needs_restyle(entity);
@@ -67,8 +73,13 @@ pub trait StyleModifiers: internal::Modifiable {
         let name = name.to_owned();
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            applied.set_or_bind(cx, entity, move |cx, applied| {
+        set_or_rebind(
+            self.context(),
+            current,
+            entity,
+            ("toggle_class", name.clone()),
+            applied,
+            move |cx, applied| {
                 let applied = applied.get(cx);
                 if let Some(class_list) = cx.style.classes.get_mut(entity) {
                     if applied {
@@ -76,11 +87,59 @@ pub trait StyleModifiers: internal::Modifiable {
                     } else {
                         class_list.remove(&name);
                     }
+                } else if applied {
+                    let mut class_list = HashSet::new();
+                    class_list.insert(name.clone());
+                    cx.style.classes.insert(entity, class_list);
                 }
 
                 cx.needs_restyle(entity);
-            });
-        });
+            },
+        );
+
+        self
+    }
+
+    /// Sets a custom property on the view, which can be referenced with `var()` in the styles of the view and its
+    /// descendants. A custom property set on a view overrides any declared for it in a stylesheet.
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// VStack::new(cx, |cx| {
+    ///     Element::new(cx).class("swatch");
+    /// })
+    /// .custom_property("--accent", Color::red());
+    /// ```
+    /// css
+    /// ```css
+    /// .swatch {
+    ///     background-color: var(--accent, gray);
+    /// }
+    ///```
+    fn custom_property<U: ToString>(mut self, name: &str, value: impl Res<U>) -> Self {
+        let name = name.to_owned();
+        let entity = self.entity();
+        let current = self.current();
+        set_or_rebind(
+            self.context(),
+            current,
+            entity,
+            ("custom_property", name.clone()),
+            value,
+            move |cx, value| {
+                let value = value.get(cx).to_string();
+                if let Some(custom_properties) = cx.style.custom_properties.get_mut(entity) {
+                    custom_properties.insert(name.clone(), value);
+                } else {
+                    let mut custom_properties = HashMap::new();
+                    custom_properties.insert(name.clone(), value);
+                    cx.style.custom_properties.insert(entity, custom_properties);
+                }
+
+                cx.needs_restyle(entity);
+            },
+        );
 
         self
     }
@@ -97,14 +156,13 @@ pub trait StyleModifiers: internal::Modifiable {
             abilities.set(Abilities::CHECKABLE, true);
         }
 
-        self.context().with_current(current, move |cx| {
-            state.set_or_bind(cx, entity, move |cx, val| {
-                let val = val.get(cx).into();
-                if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(entity) {
-                    pseudo_classes.set(PseudoClassFlags::CHECKED, val);
-                }
-                cx.needs_restyle(entity);
-            });
+        set_or_rebind(self.context(), current, entity, "checked", state, move |cx, val| {
+            let val = val.get(cx).into();
+            if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(entity) {
+                pseudo_classes.set(PseudoClassFlags::CHECKED, val);
+            }
+            cx.needs_restyle(entity);
+            cx.style.needs_access_update(entity);
         });
 
         self
@@ -118,17 +176,15 @@ pub trait StyleModifiers: internal::Modifiable {
         let entity = self.entity();
         let current = self.current();
 
-        self.context().with_current(current, |cx| {
-            state.set_or_bind(cx, entity, |cx, val| {
-                let val = val.get(cx).into();
+        set_or_rebind(self.context(), current, entity, "focused", state, |cx, val| {
+            let val = val.get(cx).into();
 
-                if val {
-                    cx.focus();
-                    // cx.focus_with_visibility(true);
-                }
+            if val {
+                cx.focus();
+                // cx.focus_with_visibility(true);
+            }
 
-                cx.needs_restyle(cx.current);
-            });
+            cx.needs_restyle(cx.current);
         });
 
         self
@@ -142,8 +198,13 @@ pub trait StyleModifiers: internal::Modifiable {
         let entity = self.entity();
         let current = self.current();
 
-        self.context().with_current(current, move |cx| {
-            focus.set_or_bind(cx, entity, move |cx, f| {
+        set_or_rebind(
+            self.context(),
+            current,
+            entity,
+            "focused_with_visibility",
+            focus,
+            move |cx, f| {
                 visibility.set_or_bind(cx, entity, move |cx, v| {
                     let focus = f.get(cx).into();
                     let visibility = v.get(cx).into();
@@ -153,8 +214,8 @@ pub trait StyleModifiers: internal::Modifiable {
                         cx.needs_restyle(cx.current);
                     }
                 });
-            });
-        });
+            },
+        );
 
         self
     }
@@ -162,15 +223,13 @@ pub trait StyleModifiers: internal::Modifiable {
     fn read_only<U: Into<bool>>(mut self, state: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            state.set_or_bind(cx, entity, move |cx, val| {
-                let val = val.get(cx).into();
-                if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(cx.current) {
-                    pseudo_classes.set(PseudoClassFlags::READ_ONLY, val);
-                }
+        set_or_rebind(self.context(), current, entity, "read_only", state, move |cx, val| {
+            let val = val.get(cx).into();
+            if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(cx.current) {
+                pseudo_classes.set(PseudoClassFlags::READ_ONLY, val);
+            }
 
-                cx.needs_restyle(cx.current);
-            });
+            cx.needs_restyle(cx.current);
         });
 
         self
@@ -179,20 +238,71 @@ pub trait StyleModifiers: internal::Modifiable {
     fn read_write<U: Into<bool>>(mut self, state: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            state.set_or_bind(cx, entity, move |cx, val| {
-                let val = val.get(cx).into();
-                if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(cx.current) {
-                    pseudo_classes.set(PseudoClassFlags::READ_WRITE, val);
-                }
+        set_or_rebind(self.context(), current, entity, "read_write", state, move |cx, val| {
+            let val = val.get(cx).into();
+            if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(cx.current) {
+                pseudo_classes.set(PseudoClassFlags::READ_WRITE, val);
+            }
 
-                cx.needs_restyle(cx.current);
-            });
+            cx.needs_restyle(cx.current);
         });
 
         self
     }
 
+    /// Sets style properties of the view which apply while it's hovered, as for a `:hover` rule in a stylesheet.
+    ///
+    /// See [`StateStyleBuilder`] for how the properties take precedence over those set in other ways.
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx)
+    ///     .on_hover_style(|s| s.background_color(Color::red()).cursor(CursorIcon::Hand));
+    /// ```
+    fn on_hover_style(
+        mut self,
+        style: impl FnOnce(StateStyleBuilder) -> StateStyleBuilder,
+    ) -> Self {
+        let entity = self.entity();
+        add_state_style(self.context(), entity, PseudoClassFlags::HOVER, style);
+
+        self
+    }
+
+    /// Sets style properties of the view which apply while it's active, as for an `:active` rule in a stylesheet.
+    fn on_active_style(
+        mut self,
+        style: impl FnOnce(StateStyleBuilder) -> StateStyleBuilder,
+    ) -> Self {
+        let entity = self.entity();
+        add_state_style(self.context(), entity, PseudoClassFlags::ACTIVE, style);
+
+        self
+    }
+
+    /// Sets style properties of the view which apply while it's focused, as for a `:focus` rule in a stylesheet.
+    fn on_focus_style(
+        mut self,
+        style: impl FnOnce(StateStyleBuilder) -> StateStyleBuilder,
+    ) -> Self {
+        let entity = self.entity();
+        add_state_style(self.context(), entity, PseudoClassFlags::FOCUS, style);
+
+        self
+    }
+
+    /// Sets style properties of the view which apply while it's checked, as for a `:checked` rule in a stylesheet.
+    fn on_checked_style(
+        mut self,
+        style: impl FnOnce(StateStyleBuilder) -> StateStyleBuilder,
+    ) -> Self {
+        let entity = self.entity();
+        add_state_style(self.context(), entity, PseudoClassFlags::CHECKED, style);
+
+        self
+    }
+
     modifier!(
         /// Sets the view to be disabled.
         ///
@@ -229,18 +339,49 @@ pub trait StyleModifiers: internal::Modifiable {
         SystemFlags::REDRAW
     );
 
+    modifier!(
+        /// Sets how the view is blended with what's drawn beneath it.
+        ///
+        /// The view and its children are drawn into a layer which is blended with what's beneath it at the opacity
+        /// of the view. If the view has a backdrop filter, it's blended with the filtered backdrop.
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// Element::new(cx).background_color(Color::red()).mix_blend_mode(BlendMode::Multiply);
+        /// ```
+        mix_blend_mode,
+        BlendMode,
+        SystemFlags::REDRAW
+    );
+
     /// Sets the z-index of the view.
     ///
     /// Views with a higher z-index will be rendered on top of those with a lower z-order.
     /// Views with the same z-index are rendered in tree order.
     fn z_index<U: Into<i32>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
-        // value.set_or_bind(self.context(), entity, |cx, v| {
-        let cx = self.context();
-        let value = value.get(cx).into();
-        cx.style.z_index.insert(entity, value);
-        cx.needs_redraw(entity);
-        // });
+        let current = self.current();
+        set_or_rebind(self.context(), current, entity, "z_index", value, move |cx, v| {
+            cx.style.z_index.insert(entity, v.get(cx).into());
+            cx.needs_redraw(entity);
+        });
+
+        self
+    }
+
+    /// Sets the [`Layer`] the view and its descendants are drawn and hit-tested in.
+    ///
+    /// Views in a higher layer are always rendered on top of those in lower layers, whatever their z-index, and are
+    /// drawn outside of the clip of their ancestors. A view without a layer is in the layer of its parent.
+    fn layer<U: Into<Layer>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        set_or_rebind(self.context(), current, entity, "layer", value, move |cx, v| {
+            cx.style.layer.insert(entity, v.get(cx).into());
+            cx.needs_redraw(entity);
+        });
 
         self
     }
@@ -249,29 +390,29 @@ pub trait StyleModifiers: internal::Modifiable {
     fn clip_path<U: Into<ClipPath>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, move |cx, v| {
-                let value = v.get(cx).into();
-                cx.style.clip_path.insert(cx.current, value);
+        set_or_rebind(self.context(), current, entity, "clip_path", value, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.clip_path.insert(cx.current, value);
 
-                cx.needs_redraw(entity);
-            });
+            cx.needs_redraw(entity);
         });
 
         self
     }
 
+    /// Sets how the content which overflows the view is drawn along both axes.
+    ///
+    /// With `Overflow::Scroll` or `Overflow::Auto` the content is clipped and can be scrolled with the mouse wheel, the
+    /// scrollbars drawn over it, or by sending the view a [`ScrollEvent`].
     fn overflow<U: Into<Overflow>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, move |cx, v| {
-                let value = v.get(cx).into();
-                cx.style.overflowx.insert(cx.current, value);
-                cx.style.overflowy.insert(cx.current, value);
+        set_or_rebind(self.context(), current, entity, "overflow", value, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.overflowx.insert(cx.current, value);
+            cx.style.overflowy.insert(cx.current, value);
 
-                cx.needs_redraw(entity);
-            });
+            cx.needs_redraw(entity);
         });
 
         self
@@ -295,77 +436,126 @@ pub trait StyleModifiers: internal::Modifiable {
         SystemFlags::REDRAW
     );
 
-    /// Sets the backdrop filter for the view.
-    fn backdrop_filter<U: Into<Filter>>(mut self, value: impl Res<U>) -> Self {
+    /// Sets the filters applied to the view and its children, such as blurring them or converting them to grayscale.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx).filter(vec![Filter::Grayscale(1.0), Filter::Blur(Length::px(2.0))]);
+    /// ```
+    fn filter<U: Into<Vec<Filter>>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, move |cx, v| {
-                let value = v.get(cx).into();
-                cx.style.backdrop_filter.insert(cx.current, value);
+        set_or_rebind(self.context(), current, entity, "filter", value, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.filter.insert(cx.current, value);
 
-                cx.needs_redraw(entity);
-            });
+            cx.needs_redraw(entity);
         });
 
         self
     }
 
+    /// Sets the filters applied to what's drawn behind the view.
+    fn backdrop_filter<U: Into<Vec<Filter>>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        set_or_rebind(self.context(), current, entity, "backdrop_filter", value, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.backdrop_filter.insert(cx.current, value);
+
+            cx.needs_redraw(entity);
+        });
+
+        self
+    }
+
+    modifier!(
+        /// Sets the elevation of the view, which is shown by shadows from a built-in table of levels from 0 to 5.
+        ///
+        /// Shadows set with the `shadow` modifier or property take precedence over the shadows of the elevation.
+        elevation,
+        Elevation,
+        SystemFlags::REDRAW
+    );
+
     /// Add a shadow to the view.
     fn shadow<U: Into<Shadow>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, move |cx, v| {
-                let value = v.get(cx).into();
-                if let Some(shadows) = cx.style.shadow.get_inline_mut(cx.current) {
-                    shadows.push(value);
-                } else {
-                    cx.style.shadow.insert(cx.current, vec![value]);
-                }
-
-                cx.needs_redraw(entity);
-            });
+        set_or_rebind(self.context(), current, entity, "shadow", value, move |cx, v| {
+            let value = v.get(cx).into();
+            if let Some(shadows) = cx.style.shadow.get_inline_mut(cx.current) {
+                shadows.push(value);
+            } else {
+                cx.style.shadow.insert(cx.current, vec![value]);
+            }
+
+            cx.needs_redraw(entity);
         });
 
         self
     }
 
-    fn shadows<U: Into<Vec<Shadow>>>(mut self, value: impl Res<U>) -> Self {
+    /// Sets the shadows of the view, replacing any it already has. The shadows follow the corner radii of the view
+    /// and, like the `shadow` property, are drawn outside of the view unless they're inset.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # #[derive(Lens)]
+    /// # struct AppData {
+    /// #     elevated: bool,
+    /// # }
+    /// # impl Model for AppData {}
+    /// # let cx = &mut Context::default();
+    /// # AppData { elevated: false }.build(cx);
+    /// Element::new(cx).corner_radius(Pixels(8.0)).box_shadow(AppData::elevated.map(|elevated| {
+    ///     if *elevated {
+    ///         vec![Shadow::from("0px 4px 12px #00000040"), Shadow::from("0px 1px 3px #00000030")]
+    ///     } else {
+    ///         vec![Shadow::from("0px 1px 2px #00000030")]
+    ///     }
+    /// }));
+    /// ```
+    fn box_shadow<U: Into<Vec<Shadow>>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, move |cx, v| {
-                let value = v.get(cx).into();
+        set_or_rebind(self.context(), current, entity, "box_shadow", value, move |cx, v| {
+            let value = v.get(cx).into();
 
-                cx.style.shadow.insert(cx.current, value);
+            cx.style.shadow.insert(cx.current, value);
 
-                cx.needs_redraw(entity);
-            });
+            cx.needs_redraw(entity);
         });
 
         self
     }
 
+    /// Sets the shadows of the view, which is the same as [`box_shadow`](Self::box_shadow).
+    fn shadows<U: Into<Vec<Shadow>>>(self, value: impl Res<U>) -> Self {
+        self.box_shadow(value)
+    }
+
+    /// Adds a background gradient to the view, below the background images and gradients added before it.
     fn background_gradient<U: Into<Gradient>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, move |cx, v| {
+        let layer = background_layer_count(self.context(), entity);
+        set_or_rebind(
+            self.context(),
+            current,
+            entity,
+            ("background_gradient", layer),
+            value,
+            move |cx, v| {
                 let value = v.get(cx).into();
-                if let Some(background_images) =
-                    cx.style.background_image.get_inline_mut(cx.current)
-                {
-                    background_images.push(ImageOrGradient::Gradient(value));
-                } else {
-                    cx.style
-                        .background_image
-                        .insert(cx.current, vec![ImageOrGradient::Gradient(value)]);
-                }
+                set_background_layer(cx, layer, ImageOrGradient::Gradient(value));
 
                 cx.needs_redraw(entity);
-            });
-        });
+            },
+        );
 
         self
     }
@@ -378,49 +568,232 @@ pub trait StyleModifiers: internal::Modifiable {
         SystemFlags::REDRAW
     );
 
+    /// Adds a background image or gradient to the view, below the background images and gradients added before it.
+    ///
+    /// Each image is drawn with the background position, size and repeat at the same index, or the first of them if
+    /// there is only one.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx)
+    ///     .background_image("url(overlay.png)")
+    ///     .background_image("linear-gradient(white, gray)")
+    ///     .background_repeat(BackgroundRepeatKeyword::NoRepeat);
+    /// ```
     fn background_image<'i, U: Into<BackgroundImage<'i>>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, move |cx, val| {
-                let image = val.get(cx).into();
-                let image = match image {
-                    BackgroundImage::Gradient(gradient) => {
-                        Some(ImageOrGradient::Gradient(*gradient))
-                    }
-                    BackgroundImage::Url(url) => Some(ImageOrGradient::Image(url.url.to_string())),
-                    _ => None,
-                };
-
-                if let Some(image) = image {
-                    cx.style.background_image.insert(cx.current, vec![image]);
+        let layer = background_layer_count(self.context(), entity);
+        set_or_rebind(
+            self.context(),
+            current,
+            entity,
+            ("background_image", layer),
+            value,
+            move |cx, val| {
+                if let Some(image) = ImageOrGradient::from_background_image(val.get(cx).into()) {
+                    set_background_layer(cx, layer, image);
                 }
 
                 cx.needs_redraw(entity);
-            });
+            },
+        );
+
+        self
+    }
+
+    /// Sets the position of the background images of the view.
+    fn background_position<U: Into<Position>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        set_or_rebind(
+            self.context(),
+            current,
+            entity,
+            "background_position",
+            value,
+            move |cx, v| {
+                let value = v.get(cx).into();
+                let x = value.x.to_length_or_percentage();
+                let y = value.y.to_length_or_percentage();
+                cx.style.background_position.insert(cx.current, vec![Translate { x, y }]);
+                cx.needs_redraw(entity);
+            },
+        );
+
+        self
+    }
+
+    /// Sets the size of the background images of the view.
+    fn background_size<U: Into<BackgroundSize>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        set_or_rebind(self.context(), current, entity, "background_size", value, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.background_size.insert(cx.current, vec![value]);
+            cx.needs_redraw(entity);
+        });
+
+        self
+    }
+
+    /// Sets whether the background images of the view are repeated to fill the background.
+    fn background_repeat<U: Into<BackgroundRepeat>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        set_or_rebind(self.context(), current, entity, "background_repeat", value, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.background_repeat.insert(cx.current, vec![value]);
+            cx.needs_redraw(entity);
         });
 
         self
     }
 
     // Border Properties
+    /// Sets the width of all four borders of the view.
+    fn border_width<U: Into<LengthOrPercentage>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        set_or_rebind(self.context(), current, entity, "border_width", value, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.border_top_width.insert(cx.current, value.clone());
+            cx.style.border_right_width.insert(cx.current, value.clone());
+            cx.style.border_bottom_width.insert(cx.current, value.clone());
+            cx.style.border_left_width.insert(cx.current, value);
+
+            cx.set_system_flags(entity, SystemFlags::RELAYOUT | SystemFlags::REDRAW);
+        });
+
+        self
+    }
+
     modifier!(
-        /// Sets the border width of the view.
-        border_width,
+        /// Sets the width of the top border of the view.
+        border_top_width,
         LengthOrPercentage,
         SystemFlags::RELAYOUT | SystemFlags::REDRAW
     );
 
     modifier!(
-        /// Sets the border color of the view.
-        border_color,
-        Color,
+        /// Sets the width of the right border of the view.
+        border_right_width,
+        LengthOrPercentage,
+        SystemFlags::RELAYOUT | SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the width of the bottom border of the view.
+        border_bottom_width,
+        LengthOrPercentage,
+        SystemFlags::RELAYOUT | SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the width of the left border of the view.
+        border_left_width,
+        LengthOrPercentage,
+        SystemFlags::RELAYOUT | SystemFlags::REDRAW
+    );
+
+    /// Sets the color of all four borders of the view, which can be a solid color or a gradient.
+    fn border_color<U: Into<ColorOrGradient>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        set_or_rebind(self.context(), current, entity, "border_color", value, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.border_top_color.insert(cx.current, value.clone());
+            cx.style.border_right_color.insert(cx.current, value.clone());
+            cx.style.border_bottom_color.insert(cx.current, value.clone());
+            cx.style.border_left_color.insert(cx.current, value);
+
+            cx.set_system_flags(entity, SystemFlags::REDRAW);
+        });
+
+        self
+    }
+
+    modifier!(
+        /// Sets the color of the top border of the view.
+        border_top_color,
+        ColorOrGradient,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the color of the right border of the view.
+        border_right_color,
+        ColorOrGradient,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the color of the bottom border of the view.
+        border_bottom_color,
+        ColorOrGradient,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the color of the left border of the view.
+        border_left_color,
+        ColorOrGradient,
+        SystemFlags::REDRAW
+    );
+
+    /// Sets the style of all four borders of the view.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx)
+    ///     .border_width(Pixels(2.0))
+    ///     .border_color(Color::blue())
+    ///     .border_style(BorderStyleKeyword::Dashed);
+    /// ```
+    fn border_style<U: Into<BorderStyleKeyword>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        set_or_rebind(self.context(), current, entity, "border_style", value, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.border_top_style.insert(cx.current, value);
+            cx.style.border_right_style.insert(cx.current, value);
+            cx.style.border_bottom_style.insert(cx.current, value);
+            cx.style.border_left_style.insert(cx.current, value);
+
+            cx.set_system_flags(entity, SystemFlags::REDRAW);
+        });
+
+        self
+    }
+
+    modifier!(
+        /// Sets the style of the top border of the view.
+        border_top_style,
+        BorderStyleKeyword,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the style of the right border of the view.
+        border_right_style,
+        BorderStyleKeyword,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the style of the bottom border of the view.
+        border_bottom_style,
+        BorderStyleKeyword,
         SystemFlags::REDRAW
     );
 
     modifier!(
-        /// Sets the border color of the view.
-        border_style,
+        /// Sets the style of the left border of the view.
+        border_left_style,
         BorderStyleKeyword,
         SystemFlags::REDRAW
     );
@@ -428,48 +801,60 @@ pub trait StyleModifiers: internal::Modifiable {
     modifier!(
         /// Sets the corner radius for the top-left corner of the view.
         corner_top_left_radius,
-        LengthOrPercentage,
+        CornerRadiusValue,
         SystemFlags::REDRAW
     );
 
     modifier!(
         /// Sets the corner radius for the top-right corner of the view.
         corner_top_right_radius,
-        LengthOrPercentage,
+        CornerRadiusValue,
         SystemFlags::REDRAW
     );
 
     modifier!(
         /// Sets the corner radius for the bottom-left corner of the view.
         corner_bottom_left_radius,
-        LengthOrPercentage,
+        CornerRadiusValue,
         SystemFlags::REDRAW
     );
 
     modifier!(
         /// Sets the corner radius for the bottom-right corner of the view.
         corner_bottom_right_radius,
-        LengthOrPercentage,
+        CornerRadiusValue,
         SystemFlags::REDRAW
     );
 
     /// Sets the corner radius for all four corners of the view.
+    ///
+    /// Percentage radii are relative to the shorter side of the view, so `"50%"` rounds a wide view into a pill and a
+    /// square view into a circle. Different horizontal and vertical radii, such as `"50% / 25%"`, are relative to the
+    /// width and the height of the view. Radii of adjacent corners which add up to more than the side between them are
+    /// scaled down to fit.
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Button::new(cx, |cx| Label::new(cx, "Pill")).corner_radius("50%");
+    ///
+    /// // Only round the top corners.
+    /// Element::new(cx).corner_radius((Pixels(4.0), Pixels(4.0), Pixels(0.0), Pixels(0.0)));
+    /// ```
     fn corner_radius<U: std::fmt::Debug + Into<CornerRadius>>(
         mut self,
         value: impl Res<U>,
     ) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, move |cx, v| {
-                let value = v.get(cx).into();
-                cx.style.corner_top_left_radius.insert(cx.current, value.top_left);
-                cx.style.corner_top_right_radius.insert(cx.current, value.top_right);
-                cx.style.corner_bottom_left_radius.insert(cx.current, value.bottom_left);
-                cx.style.corner_bottom_right_radius.insert(cx.current, value.bottom_right);
-
-                cx.needs_redraw(entity);
-            });
+        set_or_rebind(self.context(), current, entity, "corner_radius", value, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.corner_top_left_radius.insert(cx.current, value.top_left);
+            cx.style.corner_top_right_radius.insert(cx.current, value.top_right);
+            cx.style.corner_bottom_left_radius.insert(cx.current, value.bottom_left);
+            cx.style.corner_bottom_right_radius.insert(cx.current, value.bottom_right);
+
+            cx.needs_redraw(entity);
         });
 
         self
@@ -510,16 +895,14 @@ pub trait StyleModifiers: internal::Modifiable {
     ) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, move |cx, v| {
-                let value = v.get(cx).into();
-                cx.style.corner_top_left_shape.insert(cx.current, value.0);
-                cx.style.corner_top_right_shape.insert(cx.current, value.1);
-                cx.style.corner_bottom_right_shape.insert(cx.current, value.2);
-                cx.style.corner_bottom_left_shape.insert(cx.current, value.3);
-
-                cx.needs_redraw(entity);
-            });
+        set_or_rebind(self.context(), current, entity, "corner_shape", value, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.corner_top_left_shape.insert(cx.current, value.0);
+            cx.style.corner_top_right_shape.insert(cx.current, value.1);
+            cx.style.corner_bottom_right_shape.insert(cx.current, value.2);
+            cx.style.corner_bottom_left_shape.insert(cx.current, value.3);
+
+            cx.needs_redraw(entity);
         });
 
         self
@@ -560,16 +943,14 @@ pub trait StyleModifiers: internal::Modifiable {
     ) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, move |cx, v| {
-                let value = v.get(cx).into();
-                cx.style.corner_top_left_smoothing.insert(cx.current, value.0);
-                cx.style.corner_top_right_smoothing.insert(cx.current, value.1);
-                cx.style.corner_bottom_left_smoothing.insert(cx.current, value.2);
-                cx.style.corner_bottom_right_smoothing.insert(cx.current, value.3);
-
-                cx.needs_redraw(entity);
-            });
+        set_or_rebind(self.context(), current, entity, "corner_smoothing", value, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.corner_top_left_smoothing.insert(cx.current, value.0);
+            cx.style.corner_top_right_smoothing.insert(cx.current, value.1);
+            cx.style.corner_bottom_left_smoothing.insert(cx.current, value.2);
+            cx.style.corner_bottom_right_smoothing.insert(cx.current, value.3);
+
+            cx.needs_redraw(entity);
         });
 
         self
@@ -584,9 +965,9 @@ pub trait StyleModifiers: internal::Modifiable {
     );
 
     modifier!(
-        /// Sets the outline color of the view.
+        /// Sets the outline color of the view, which can be a solid color or a gradient.
         outline_color,
-        Color,
+        ColorOrGradient,
         SystemFlags::REDRAW
     );
 
@@ -597,6 +978,81 @@ pub trait StyleModifiers: internal::Modifiable {
         SystemFlags::REDRAW
     );
 
+    modifier!(
+        /// Sets the outline style of the view, which is solid by default.
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// Element::new(cx)
+        ///     .outline_width(Pixels(2.0))
+        ///     .outline_color(Color::blue())
+        ///     .outline_style(BorderStyleKeyword::Dashed);
+        /// ```
+        outline_style,
+        BorderStyleKeyword,
+        SystemFlags::REDRAW
+    );
+
+    // Focus Ring Properties
+    modifier!(
+        /// Sets whether the framework draws a focus ring around the view while it has visible keyboard focus.
+        /// Views which draw their own focus indication can disable the ring. Defaults to true.
+        focus_ring,
+        bool,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the color of the focus ring of the view and its descendants.
+        focus_ring_color,
+        Color,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the width of the focus ring of the view and its descendants.
+        focus_ring_width,
+        LengthOrPercentage,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the distance between the focus ring and the bounds of the view and its descendants.
+        focus_ring_offset,
+        LengthOrPercentage,
+        SystemFlags::REDRAW
+    );
+
+    /// Sets the feedback drawn over the view while it's pressed, such as a ripple from where it was pressed. A
+    /// boolean enables or disables the ripple.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Button::new(cx, |cx| Label::new(cx, "Press Me")).press_effect(true);
+    /// ```
+    fn press_effect<U: Into<PressEffect>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        set_or_rebind(self.context(), current, entity, "press_effect", value, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.press_effect.insert(cx.current, value);
+        });
+
+        self
+    }
+
+    modifier!(
+        /// Sets the color of the ripples drawn over the view when it's pressed, whose alpha sets their opacity.
+        /// Defaults to the font color of the view at 20% opacity.
+        ripple_color,
+        Color,
+        SystemFlags::REDRAW
+    );
+
     // Cursor Icon
     modifier!(
         /// Sets the mouse cursor used when the view is hovered.
@@ -609,26 +1065,48 @@ pub trait StyleModifiers: internal::Modifiable {
     fn pointer_events<U: Into<PointerEvents>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, move |cx, v| {
-                let value = v.get(cx).into();
-                cx.style.pointer_events.insert(cx.current, value);
-            });
+        set_or_rebind(self.context(), current, entity, "pointer_events", value, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.pointer_events.insert(cx.current, value);
         });
 
         self
     }
 
+    /// Sets a transition for a property of the view, given by its CSS name, which animates the changes made to the
+    /// property by modifiers and bindings. Each property of the view can have its own transition, and a transition
+    /// with a zero duration removes the transition of the property.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx)
+    ///     .background_color(Color::red())
+    ///     .transition("background-color", Duration::from_millis(150), EasingFunction::EaseOut)
+    ///     .transition("opacity", Duration::from_millis(300), EasingFunction::Linear);
+    /// ```
+    fn transition(
+        mut self,
+        property: impl Into<String>,
+        duration: Duration,
+        easing: EasingFunction,
+    ) -> Self {
+        let entity = self.entity();
+        let transition = Transition::new(property.into(), duration, None, Some(easing));
+        self.context().style.set_transition(entity, &transition);
+
+        self
+    }
+
     /// Sets the transform of the view with a list of transform functions.
     fn transform<U: Into<Vec<Transform>>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, move |cx, v| {
-                let value = v.get(cx).into();
-                cx.style.transform.insert(cx.current, value);
-                cx.needs_redraw(entity);
-            });
+        set_or_rebind(self.context(), current, entity, "transform", value, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.transform.insert(cx.current, value);
+            cx.needs_redraw(entity);
         });
 
         self
@@ -638,14 +1116,12 @@ pub trait StyleModifiers: internal::Modifiable {
     fn transform_origin<U: Into<Position>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, move |cx, v| {
-                let value = v.get(cx).into();
-                let x = value.x.to_length_or_percentage();
-                let y = value.y.to_length_or_percentage();
-                cx.style.transform_origin.insert(cx.current, Translate { x, y });
-                cx.needs_redraw(entity);
-            });
+        set_or_rebind(self.context(), current, entity, "transform_origin", value, move |cx, v| {
+            let value = v.get(cx).into();
+            let x = value.x.to_length_or_percentage();
+            let y = value.y.to_length_or_percentage();
+            cx.style.transform_origin.insert(cx.current, Translate { x, y });
+            cx.needs_redraw(entity);
         });
 
         self
@@ -684,6 +1160,34 @@ pub trait StyleModifiers: internal::Modifiable {
 
 impl<V: View> StyleModifiers for Handle<'_, V> {}
 
+fn add_state_style(
+    cx: &mut Context,
+    entity: Entity,
+    state: PseudoClassFlags,
+    style: impl FnOnce(StateStyleBuilder) -> StateStyleBuilder,
+) {
+    cx.style.add_state_style(entity, state, style(StateStyleBuilder::new()));
+    cx.needs_restyle(entity);
+}
+
+// Returns the number of background images and gradients set on a view with modifiers.
+fn background_layer_count(cx: &mut Context, entity: Entity) -> usize {
+    cx.style.background_image.get_inline_mut(entity).map_or(0, |images| images.len())
+}
+
+// Sets a background image or gradient of the current view, adding it below the others if the view has fewer layers.
+fn set_background_layer(cx: &mut Context, layer: usize, image: ImageOrGradient) {
+    if let Some(images) = cx.style.background_image.get_inline_mut(cx.current) {
+        if let Some(current_image) = images.get_mut(layer) {
+            *current_image = image;
+        } else {
+            images.push(image);
+        }
+    } else {
+        cx.style.background_image.insert(cx.current, vec![image]);
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct LinearGradientBuilder {
     direction: LineDirection,
@@ -784,3 +1288,9 @@ impl From<ShadowBuilder> for Shadow {
         value.build()
     }
 }
+
+impl From<ShadowBuilder> for Vec<Shadow> {
+    fn from(value: ShadowBuilder) -> Self {
+        vec![value.build()]
+    }
+}
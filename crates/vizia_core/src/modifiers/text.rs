@@ -1,4 +1,5 @@
 use super::internal;
+use super::set_or_rebind;
 use crate::prelude::*;
 
 /// Modifiers for changing the text properties of a view.
@@ -7,17 +8,15 @@ pub trait TextModifiers: internal::Modifiable {
     fn text<T: ToStringLocalized>(mut self, value: impl Res<T>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, |cx| {
-            value.set_or_bind(cx, entity, move |cx, val| {
-                let cx: &mut EventContext<'_> = &mut EventContext::new_with_current(cx, entity);
-                let text_data = val.get(cx).to_string_local(cx);
-                // cx.text_context.set_text(entity, &text_data);
-                cx.style.text.insert(entity, text_data);
-
-                cx.style.needs_text_update(entity);
-                cx.needs_relayout();
-                cx.needs_redraw();
-            });
+        set_or_rebind(self.context(), current, entity, "text", value, move |cx, val| {
+            let cx: &mut EventContext<'_> = &mut EventContext::new_with_current(cx, entity);
+            let text_data = val.get(cx).to_string_local(cx);
+            // cx.text_context.set_text(entity, &text_data);
+            cx.style.text.insert(entity, text_data);
+
+            cx.style.needs_text_update(entity);
+            cx.needs_relayout();
+            cx.needs_redraw();
         });
 
         self
@@ -54,22 +53,29 @@ pub trait TextModifiers: internal::Modifiable {
     );
 
     modifier!(
-        /// Sets the font variation settings that should be used by the view.
+        /// Sets the values of the axes of the variable font used by the view, such as its weight with
+        /// `FontVariation::new(b"wght", 650.0)`. Axes which aren't set keep the default value of the font.
         font_variation_settings,
         Vec<FontVariation>,
         SystemFlags::REFLOW
     );
 
-    /// Sets the text color of the view.
-    fn color<U: Clone + Into<Color>>(mut self, value: impl Res<U>) -> Self {
+    modifier!(
+        /// Sets the OpenType features of the font used by the view, such as tabular figures with `"tnum"`, so that
+        /// digits don't shift as a number changes, or `"liga" off` to turn off ligatures.
+        font_feature_settings,
+        FontFeatureSettings,
+        SystemFlags::REFLOW
+    );
+
+    /// Sets the text color of the view, which can be a solid color or a gradient.
+    fn color<U: Clone + Into<ColorOrGradient>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, move |cx| {
-            value.set_or_bind(cx, entity, move |cx, v| {
-                cx.style.font_color.insert(entity, v.get(cx).into());
-                cx.style.needs_text_update(entity);
-                cx.needs_redraw(entity);
-            });
+        set_or_rebind(self.context(), current, entity, "color", value, move |cx, v| {
+            cx.style.font_color.insert(entity, v.get(cx).into());
+            cx.style.needs_text_repaint(entity);
+            cx.needs_redraw(entity);
         });
         self
     }
@@ -78,11 +84,9 @@ pub trait TextModifiers: internal::Modifiable {
     fn font_size<U: Into<FontSize>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
-        self.context().with_current(current, move |cx| {
-            value.set_or_bind(cx, entity, move |cx, v| {
-                cx.style.font_size.insert(cx.current, v.get(cx).into());
-                cx.style.needs_text_update(entity);
-            });
+        set_or_rebind(self.context(), current, entity, "font_size", value, move |cx, v| {
+            cx.style.font_size.insert(cx.current, v.get(cx).into());
+            cx.style.needs_text_update(entity);
         });
         self
     }
@@ -102,12 +106,31 @@ pub trait TextModifiers: internal::Modifiable {
     );
 
     modifier!(
-        /// Sets whether the text of the view should be allowed to wrap.
-        text_wrap,
-        bool,
+        /// Sets whether line breaks in the text of the view are kept and whether its lines wrap.
+        ///
+        /// With `WhiteSpace::NoWrap` and an ellipsis text overflow, the text is truncated on a single line.
+        white_space,
+        WhiteSpace,
         SystemFlags::REFLOW
     );
 
+    /// Sets whether the text of the view should be allowed to wrap.
+    ///
+    /// This is the same as setting the white space to `WhiteSpace::PreWrap` if true, or `WhiteSpace::Pre` if false,
+    /// so line breaks in the text are kept either way.
+    fn text_wrap<U: Into<bool>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        set_or_rebind(self.context(), current, entity, "text_wrap", value, move |cx, v| {
+            let white_space = if v.get(cx).into() { WhiteSpace::PreWrap } else { WhiteSpace::Pre };
+            cx.style.white_space.insert(entity, white_space);
+
+            cx.set_system_flags(entity, SystemFlags::REFLOW);
+        });
+
+        self
+    }
+
     modifier!(
         /// Sets the horizontal alignment of text within the view.
         text_align,
@@ -115,6 +138,13 @@ pub trait TextModifiers: internal::Modifiable {
         SystemFlags::REFLOW
     );
 
+    modifier!(
+        /// Sets the vertical alignment of text within the view, which otherwise follows the alignment of the view.
+        vertical_align,
+        VerticalAlign,
+        SystemFlags::RELAYOUT | SystemFlags::REDRAW
+    );
+
     modifier!(
         /// Sets the text overflow.
         text_overflow,
@@ -130,12 +160,64 @@ pub trait TextModifiers: internal::Modifiable {
     );
 
     modifier!(
-        /// Sets the max number of .
+        /// Sets the height of each line of text, as a multiple of the font size, a percentage of the font size, or a
+        /// fixed length. A line height smaller than the font size causes the lines to overlap.
+        line_height,
+        LineHeight,
+        SystemFlags::REFLOW
+    );
+
+    modifier!(
+        /// Sets the case the text is displayed in, such as uppercase. The text itself, including the text of a
+        /// textbox, is unchanged.
+        text_transform,
+        TextTransform,
+        SystemFlags::REFLOW
+    );
+
+    modifier!(
+        /// Sets whether text which is too small to read, such as in a zoomed out view, is painted as bars along its
+        /// lines rather than with its glyphs. The text is laid out the same either way.
+        text_rendering,
+        TextRendering,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the lines drawn on the text, such as an underline.
         text_decoration_line,
         TextDecorationLine,
-        SystemFlags::REFLOW
+        SystemFlags::REPAINT
     );
 
+    /// Sets the lines drawn on the text, such as an underline or a strikethrough, along with their style, color,
+    /// and thickness.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Label::new(cx, "Completed task").text_decoration(TextDecoration {
+    ///     line: TextDecorationLine::Strikethrough,
+    ///     color: Color::gray(),
+    ///     ..Default::default()
+    /// });
+    /// ```
+    fn text_decoration<U: Into<TextDecoration>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        set_or_rebind(self.context(), current, entity, "text_decoration", value, move |cx, v| {
+            let decoration = v.get(cx).into();
+            cx.style.text_decoration_line.insert(entity, decoration.line);
+            cx.style.text_decoration_style.insert(entity, decoration.style);
+            cx.style.text_decoration_color.insert(entity, decoration.color);
+            cx.style.text_decoration_thickness.insert(entity, decoration.thickness);
+            cx.style.needs_text_repaint(entity);
+            cx.needs_redraw(entity);
+        });
+        self
+    }
+
     modifier!(
         /// Sets the width of the text stroke.
         /// This sets Skia's [`skia_safe::textlayout::TextStyle`]'s foreground [`skia_safe::Paint`] to
@@ -144,7 +226,7 @@ pub trait TextModifiers: internal::Modifiable {
         /// See also [`Self::text_stroke_style`].
         text_stroke_width,
         Length,
-        SystemFlags::REFLOW
+        SystemFlags::REPAINT
     );
 
     modifier!(
@@ -162,6 +244,20 @@ pub trait TextModifiers: internal::Modifiable {
         /// on achieving this.
         text_stroke_style,
         TextStrokeStyle,
+        SystemFlags::REPAINT
+    );
+
+    modifier!(
+        /// Sets the additional spacing between the letters of the text, which may be negative to tighten dense text.
+        letter_spacing,
+        LetterSpacing,
+        SystemFlags::REFLOW
+    );
+
+    modifier!(
+        /// Sets the additional spacing between the words of the text.
+        word_spacing,
+        WordSpacing,
         SystemFlags::REFLOW
     );
 }
@@ -26,6 +26,10 @@ pub(crate) struct StoredImage {
     pub observers: HashSet<Entity>,
 }
 
+/// A handle to a stylesheet added to the application, which can be used to remove or replace it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StyleSheetHandle(usize);
+
 #[derive(Copy, Clone, PartialEq)]
 pub enum ImageRetentionPolicy {
     Forever,
@@ -37,7 +41,10 @@ pub enum ImageRetentionPolicy {
 #[derive(Default)]
 pub struct ResourceManager {
     pub themes: Vec<String>, // Themes are the string content stylesheets
-    pub styles: Vec<Box<dyn IntoCssStr>>,
+    // Sets the font family of the root view for the current locale, and is parsed after the built-in themes.
+    pub(crate) locale_theme: String,
+    pub styles: Vec<(StyleSheetHandle, Box<dyn IntoCssStr>)>,
+    next_stylesheet: usize,
 
     pub(crate) image_id_manager: IdManager<ImageId>,
     pub(crate) images: HashMap<ImageId, StoredImage>,
@@ -108,11 +115,13 @@ impl ResourceManager {
 
         ResourceManager {
             themes: Vec::new(),
+            locale_theme: String::new(),
 
             image_id_manager,
             images,
             image_ids: HashMap::new(),
             styles: Vec::new(),
+            next_stylesheet: 0,
 
             translations: HashMap::from([(
                 LanguageIdentifier::default(),
@@ -124,6 +133,14 @@ impl ResourceManager {
         }
     }
 
+    /// Adds a stylesheet after those already added, returning the handle to it.
+    pub(crate) fn add_stylesheet(&mut self, style: Box<dyn IntoCssStr>) -> StyleSheetHandle {
+        let handle = StyleSheetHandle(self.next_stylesheet);
+        self.next_stylesheet += 1;
+        self.styles.push((handle, style));
+        handle
+    }
+
     pub fn renegotiate_language(&mut self) {
         let available = self
             .translations
@@ -1,4 +1,4 @@
-use crate::animation::{AnimationState, Interpolator};
+use crate::animation::{AnimationPlayback, AnimationState, Interpolator, PlaybackControl};
 use crate::prelude::*;
 use vizia_storage::{SparseSet, SparseSetGeneric, SparseSetIndex};
 
@@ -59,6 +59,11 @@ impl DataIndex {
     pub fn null() -> Self {
         Self(u32::MAX >> 1)
     }
+
+    /// Create a data index which refers to no data and isn't inherited, for a property set to its initial value.
+    pub fn initial() -> Self {
+        Self(INDEX_MASK)
+    }
 }
 
 impl std::fmt::Debug for DataIndex {
@@ -124,6 +129,15 @@ impl SparseSetIndex for SharedIndex {
     }
 }
 
+/// What a transition is declared for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum TransitionTarget {
+    /// The transition plays when an entity is linked to the rule.
+    Rule(Rule),
+    /// The transition plays when the inline data of the entity changes.
+    Entity(Entity),
+}
+
 /// Animatable set is used for storing inline and shared data for entities as well as definitions for
 /// animations, which can be played for entities, and transitions, which play when an entity matches a new shared style
 /// rule which defines a trnasition.
@@ -140,21 +154,60 @@ pub(crate) struct AnimatableSet<T: Interpolator> {
     animations: SparseSet<AnimationState<T>>,
     /// Animations which are currently playing
     active_animations: Vec<AnimationState<T>>,
+    /// Transitions which play when the inline data of an entity changes
+    inline_transitions: SparseSet<Animation>,
 }
 
 impl<T> AnimatableSet<T>
 where
     T: 'static + Default + Clone + Interpolator + PartialEq + std::fmt::Debug,
 {
-    /// Insert an inline value for an entity.
+    /// Insert an inline value for an entity, playing any transition of the entity from its current value.
     pub fn insert(&mut self, entity: Entity, value: T) {
+        if let Some(animation) = self.inline_transitions.get(entity).copied() {
+            self.play_inline_transition(entity, animation, &value);
+        }
+
         self.inline_data.insert(entity, value);
     }
 
+    // Plays the transition of an entity from its current value, which may be partway through the transition, to the
+    // new value of its inline data.
+    fn play_inline_transition(&mut self, entity: Entity, animation: Animation, value: &T) {
+        let Some(current) = self.get(entity).cloned() else {
+            return;
+        };
+
+        if current == *value {
+            return;
+        }
+
+        // As with the transitions of rules, an entity playing another animation isn't interrupted.
+        if self.get_active_animation(entity).is_some_and(|state| state.id != animation) {
+            return;
+        }
+
+        self.stop_animation(entity, animation);
+
+        let Some(transition_state) = self.animations.get_mut(animation) else {
+            return;
+        };
+
+        transition_state.keyframes.first_mut().unwrap().value = current;
+        transition_state.keyframes.last_mut().unwrap().value = value.clone();
+
+        let playback = AnimationPlayback::new(transition_state.duration, transition_state.delay);
+        self.play_animation(entity, animation, Instant::now(), &playback);
+    }
+
     /// Remove an entity and any inline data.
     pub fn remove(&mut self, entity: Entity) -> Option<T> {
         let entity_index = entity.index();
 
+        if let Some(animation) = self.inline_transitions.remove(entity) {
+            self.animations.remove(animation);
+        }
+
         if entity_index < self.inline_data.sparse.len() {
             let active_anim_index = self.inline_data.sparse[entity_index].anim_index as usize;
 
@@ -193,8 +246,9 @@ where
 
                 let entity_sparse_index = self.inline_data.sparse[entity_index];
 
-                if self.inline_data.sparse[entity_index].data_index.index()
-                    != parent_sparse_index.data_index.index()
+                if entity_sparse_index.data_index != DataIndex::initial()
+                    && entity_sparse_index.data_index.index()
+                        != parent_sparse_index.data_index.index()
                 {
                     if entity_sparse_index.data_index.index() < self.inline_data.dense.len() {
                         if entity_sparse_index.data_index.is_inherited()
@@ -241,7 +295,8 @@ where
                 let entity_sparse_index = self.inline_data.sparse[entity_index];
 
                 if !entity_sparse_index.data_index.is_inline()
-                    && self.inline_data.sparse[entity_index].data_index.index()
+                    && entity_sparse_index.data_index != DataIndex::initial()
+                    && entity_sparse_index.data_index.index()
                         != parent_sparse_index.data_index.index()
                 {
                     if entity_sparse_index.data_index.index() < self.shared_data.dense.len() {
@@ -295,15 +350,37 @@ where
     //     self.shared_data.remove(rule)
     // }
 
-    /// Inserts a transition for a given rule
+    /// Inserts a transition for a given rule or entity
     ///
     /// Transitions are animations which are defined for a particular rule. When an entity is linked to
     /// a rule any transition associated with that rule will play for that entity.
     ///
-    pub(crate) fn insert_transition(&mut self, rule: Rule, animation: Animation) {
-        // Check if the rule exists
-        if self.shared_data.contains(rule) && self.animations.contains(animation) {
-            self.shared_data.sparse[rule.index()].animation = animation;
+    /// A transition for an entity plays whenever the inline data of the entity changes instead, and one without a
+    /// duration removes the transition of the entity.
+    pub(crate) fn insert_transition(&mut self, target: TransitionTarget, animation: Animation) {
+        match target {
+            TransitionTarget::Rule(rule) => {
+                // Check if the rule exists
+                if self.shared_data.contains(rule) && self.animations.contains(animation) {
+                    self.shared_data.sparse[rule.index()].animation = animation;
+                }
+            }
+
+            TransitionTarget::Entity(entity) => {
+                if let Some(previous) = self.inline_transitions.remove(entity) {
+                    self.animations.remove(previous);
+                }
+
+                match self.animations.get(animation) {
+                    Some(transition_state) if transition_state.duration.is_zero() => {
+                        self.animations.remove(animation);
+                    }
+
+                    Some(_) => self.inline_transitions.insert(entity, animation),
+
+                    None => {}
+                }
+            }
         }
     }
 
@@ -312,8 +389,7 @@ where
         entity: Entity,
         animation: Animation,
         start_time: Instant,
-        duration: Duration,
-        delay: Duration,
+        playback: &AnimationPlayback,
     ) {
         let entity_index = entity.index();
 
@@ -327,98 +403,90 @@ where
         }
 
         if entity_index < self.inline_data.sparse.len() {
+            // The animation replaces the one the entity is playing, whether it's the same animation played again or
+            // a transition of the same property, which finishes once no other entity is playing it.
             let active_anim_index = self.inline_data.sparse[entity_index].anim_index as usize;
             if active_anim_index < self.active_animations.len() {
                 let anim_state = &mut self.active_animations[active_anim_index];
-                if anim_state.id == animation {
-                    anim_state.active = true;
-                    anim_state.t = 0.0;
-                    anim_state.start_time = start_time;
-                    anim_state.output = Some(
-                        self.animations
-                            .get(animation)
-                            .cloned()
-                            .unwrap()
-                            .keyframes
-                            .first()
-                            .unwrap()
-                            .value
-                            .clone(),
-                    );
-                } else {
-                    anim_state.output = Some(
-                        self.animations
-                            .get(animation)
-                            .cloned()
-                            .unwrap()
-                            .keyframes
-                            .first()
-                            .unwrap()
-                            .value
-                            .clone(),
-                    );
-                    anim_state.entities.remove(&entity);
+                anim_state.entities.remove(&entity);
+                if anim_state.entities.is_empty() {
+                    anim_state.t = 1.0;
+                    anim_state.persistent = false;
                 }
+
+                self.inline_data.sparse[entity_index].anim_index = u32::MAX;
+                self.remove_innactive_animations();
             }
 
             // Safe to unwrap because already checked that the animation exists
             let mut anim_state = self.animations.get(animation).cloned().unwrap();
-            anim_state.duration = duration;
-            anim_state.delay = delay;
-            anim_state.dt = delay.as_secs_f32() / duration.as_secs_f32();
-            anim_state.output = Some(
-                self.animations
-                    .get(animation)
-                    .cloned()
-                    .unwrap()
-                    .keyframes
-                    .first()
-                    .unwrap()
-                    .value
-                    .clone(),
-            );
+            anim_state.duration = playback.duration;
+            anim_state.delay = playback.delay;
+            anim_state.dt = playback.delay.as_secs_f32() / playback.duration.as_secs_f32();
+            anim_state.iterations = playback.iterations;
+            anim_state.direction = playback.direction;
+            anim_state.persistent |= playback.fill_mode.fills_forwards();
+            anim_state.fill_backwards = playback.fill_mode.fills_backwards();
+            if let Some(timing_function) = playback.timing_function {
+                for keyframe in anim_state.keyframes.iter_mut() {
+                    keyframe.timing_function = timing_function;
+                }
+            }
+            if playback.spring.is_some() {
+                anim_state.spring = playback.spring;
+            }
+
+            // During its delay, an animation shows the keyframe it starts from only if it fills backwards.
+            anim_state.output = if anim_state.fill_backwards || playback.delay.is_zero() {
+                let first = if anim_state.is_reversed(0.0) {
+                    anim_state.keyframes.last()
+                } else {
+                    anim_state.keyframes.first()
+                };
+                first.map(|keyframe| keyframe.value.clone())
+            } else {
+                None
+            };
             anim_state.play(entity);
             self.inline_data.sparse[entity_index].anim_index = self.active_animations.len() as u32;
             self.active_animations.push(anim_state);
         }
     }
 
-    pub fn tick(&mut self, time: Instant) -> Vec<Entity> {
+    /// Stops an animation playing for an entity, which then shows its inline or shared data again.
+    pub(crate) fn stop_animation(&mut self, entity: Entity, animation: Animation) {
+        let entity_index = entity.index();
+        if entity_index >= self.inline_data.sparse.len() {
+            return;
+        }
+
+        let anim_index = self.inline_data.sparse[entity_index].anim_index as usize;
+        if anim_index < self.active_animations.len()
+            && self.active_animations[anim_index].id == animation
+        {
+            let anim_state = &mut self.active_animations[anim_index];
+            anim_state.entities.remove(&entity);
+            if anim_state.entities.is_empty() {
+                anim_state.t = 1.0;
+                anim_state.persistent = false;
+            }
+
+            self.inline_data.sparse[entity_index].anim_index = u32::MAX;
+            self.remove_innactive_animations();
+        }
+    }
+
+    pub fn tick(&mut self, time: Instant, color_space: ColorSpace) -> Vec<Entity> {
         self.remove_innactive_animations();
 
         if self.has_animations() {
             for state in self.active_animations.iter_mut() {
-                // If the animation is already finished then skip
-                if state.t == 1.0 {
+                // If the animation is already finished or is paused then skip
+                if state.t == 1.0 || state.paused_at.is_some() {
                     continue;
                 }
 
-                if state.keyframes.len() == 1 {
-                    state.output = Some(state.keyframes[0].value.clone());
-                    continue;
-                }
-
-                let elapsed_time = time.duration_since(state.start_time);
-                let mut normalised_time =
-                    (elapsed_time.as_secs_f32() / state.duration.as_secs_f32()) - state.dt;
-
-                normalised_time = normalised_time.clamp(0.0, 1.0);
-
-                let mut i = 0;
-                while i < state.keyframes.len() - 1 && state.keyframes[i + 1].time < normalised_time
-                {
-                    i += 1;
-                }
-                let start = &state.keyframes[i];
-                let end = &state.keyframes[i + 1];
-
-                let normalised_elapsed_time =
-                    (normalised_time - start.time) / (end.time - start.time);
-
-                state.t = normalised_time;
-
-                let timing_t = start.timing_function.value(normalised_elapsed_time);
-                state.output = Some(T::interpolate(&start.value, &end.value, timing_t));
+                state.update(time, color_space);
             }
 
             self.active_animations
@@ -471,7 +539,7 @@ where
 
     pub fn has_animations(&self) -> bool {
         for state in self.active_animations.iter() {
-            if state.t < 1.0 {
+            if state.t < 1.0 && state.paused_at.is_none() {
                 return true;
             }
         }
@@ -485,6 +553,7 @@ where
             let anim_index = self.inline_data.sparse[entity_index].anim_index as usize;
             if anim_index < self.active_animations.len()
                 && self.active_animations[anim_index].id == animation
+                && self.active_animations[anim_index].t < 1.0
             {
                 return true;
             }
@@ -493,18 +562,48 @@ where
         false
     }
 
-    // Returns a reference to any inline data on the entity if it exists.
-    // pub fn get_inline(&self, entity: Entity) -> Option<&T> {
-    //     let entity_index = entity.index();
-    //     if entity_index < self.inline_data.sparse.len() {
-    //         let data_index = self.inline_data.sparse[entity_index].data_index;
-    //         if data_index.is_inline() {
-    //             return self.inline_data.get(entity);
-    //         }
-    //     }
+    /// Pauses, resumes, or moves an animation playing for an entity.
+    pub(crate) fn control_animation(
+        &mut self,
+        entity: Entity,
+        animation: Animation,
+        control: PlaybackControl,
+        time: Instant,
+        color_space: ColorSpace,
+    ) {
+        let entity_index = entity.index();
+        if entity_index >= self.inline_data.sparse.len() {
+            return;
+        }
 
-    //     None
-    // }
+        let anim_index = self.inline_data.sparse[entity_index].anim_index as usize;
+        let Some(anim_state) = self.active_animations.get_mut(anim_index) else {
+            return;
+        };
+
+        if anim_state.id != animation {
+            return;
+        }
+
+        match control {
+            PlaybackControl::Pause => anim_state.pause(time),
+            PlaybackControl::Resume => anim_state.resume(time),
+            PlaybackControl::Seek(position) => anim_state.seek(position, time, color_space),
+        }
+    }
+
+    /// Returns a reference to any inline data on the entity if it exists.
+    pub fn get_inline(&self, entity: Entity) -> Option<&T> {
+        let entity_index = entity.index();
+        if entity_index < self.inline_data.sparse.len() {
+            let data_index = self.inline_data.sparse[entity_index].data_index;
+            if data_index.is_inline() {
+                return self.inline_data.get(entity);
+            }
+        }
+
+        None
+    }
 
     /// Returns a mutable reference to any inline data on the entity if it exists.
     pub fn get_inline_mut(&mut self, entity: Entity) -> Option<&mut T> {
@@ -558,7 +657,9 @@ where
             let animation_index = self.inline_data.sparse[entity_index].anim_index as usize;
 
             if animation_index < self.active_animations.len() {
-                return self.active_animations[animation_index].get_output();
+                if let Some(output) = self.active_animations[animation_index].get_output() {
+                    return Some(output);
+                }
             }
 
             let data_index = self.inline_data.sparse[entity_index].data_index;
@@ -623,8 +724,14 @@ where
                                         .value
                                         .clone();
 
-                                current_anim_state.dt = current_anim_state.t - 1.0;
-                                current_anim_state.start_time = Instant::now();
+                                // A spring carries on with its motion rather than starting over.
+                                if let Some(spring) = &mut current_anim_state.spring {
+                                    spring.reverse();
+                                    current_anim_state.t = 0.0;
+                                } else {
+                                    current_anim_state.dt = current_anim_state.t - 1.0;
+                                    current_anim_state.start_time = Instant::now();
+                                }
                             } else {
                                 // Transitioning to new rule
                                 current_anim_state.to_rule = rule_data_index;
@@ -636,6 +743,9 @@ where
                                         .clone();
                                 current_anim_state.t = 0.0;
                                 current_anim_state.start_time = Instant::now();
+                                if let Some(spring) = &mut current_anim_state.spring {
+                                    spring.retarget();
+                                }
                             }
                         }
                     }
@@ -670,8 +780,7 @@ where
                             entity,
                             rule_animation,
                             Instant::now(),
-                            duration,
-                            delay,
+                            &AnimationPlayback::new(duration, delay),
                         );
                     }
                     //}
@@ -707,6 +816,63 @@ where
         false
     }
 
+    /// Returns true if the rule sets a value.
+    pub(crate) fn has_rule(&self, rule: Rule) -> bool {
+        self.shared_data.contains(rule)
+    }
+
+    /// Links an entity to the value of its parent, as if it were inherited, or to no value if the parent has none.
+    pub(crate) fn link_parent(&mut self, entity: Entity, parent: Option<Entity>) -> bool {
+        let data_index = parent
+            .and_then(|parent| self.inline_data.sparse.get(parent.index()))
+            .map(|index| index.data_index)
+            .filter(|data_index| {
+                if data_index.is_inline() {
+                    data_index.index() < self.inline_data.dense.len()
+                } else {
+                    data_index.index() < self.shared_data.dense.len()
+                }
+            })
+            .map_or(DataIndex::null(), DataIndex::inherited);
+
+        self.link_data_index(entity, data_index)
+    }
+
+    /// Unlinks an entity from any shared data, so it has no value, which isn't replaced by the value of its parent
+    /// when inherited properties are linked.
+    pub(crate) fn link_initial(&mut self, entity: Entity) -> bool {
+        self.link_data_index(entity, DataIndex::initial())
+    }
+
+    /// Unlinks an entity from the value of its parent, if it's linked to it.
+    pub(crate) fn unlink_inherited(&mut self, entity: Entity) -> bool {
+        match self.inline_data.sparse.get(entity.index()) {
+            Some(index)
+                if index.data_index.is_inherited() && index.data_index != DataIndex::null() =>
+            {
+                self.inline_data.sparse[entity.index()].data_index = DataIndex::null();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // Links an entity to the given data, unless it has inline data of its own.
+    fn link_data_index(&mut self, entity: Entity, data_index: DataIndex) -> bool {
+        let entity_index = entity.index();
+        if entity_index >= self.inline_data.sparse.len() {
+            self.inline_data.sparse.resize(entity_index + 1, InlineIndex::null());
+        }
+
+        let current = self.inline_data.sparse[entity_index].data_index;
+        if (current.is_inline() && !current.is_inherited()) || current == data_index {
+            return false;
+        }
+
+        self.inline_data.sparse[entity_index].data_index = data_index;
+        true
+    }
+
     pub fn clear_rules(&mut self) {
         // Remove transitions
         for index in self.shared_data.sparse.iter() {
@@ -59,6 +59,11 @@ impl DataIndex {
     pub fn null() -> Self {
         Self(u32::MAX >> 1)
     }
+
+    /// Create a data index which refers to no data and isn't inherited, for a property set to its initial value.
+    pub fn initial() -> Self {
+        Self(INDEX_MASK)
+    }
 }
 
 /// An Index is used by the AnimatableStorage and contains a data index and an animation index.
@@ -154,8 +159,9 @@ where
 
                 let entity_sparse_index = self.inline_data.sparse[entity_index];
 
-                if self.inline_data.sparse[entity_index].data_index.index()
-                    != parent_sparse_index.data_index.index()
+                if entity_sparse_index.data_index != DataIndex::initial()
+                    && entity_sparse_index.data_index.index()
+                        != parent_sparse_index.data_index.index()
                 {
                     if entity_sparse_index.data_index.index() < self.inline_data.dense.len() {
                         if entity_sparse_index.data_index.is_inherited()
@@ -202,7 +208,8 @@ where
                 let entity_sparse_index = self.inline_data.sparse[entity_index];
 
                 if !entity_sparse_index.data_index.is_inline()
-                    && self.inline_data.sparse[entity_index].data_index.index()
+                    && entity_sparse_index.data_index != DataIndex::initial()
+                    && entity_sparse_index.data_index.index()
                         != parent_sparse_index.data_index.index()
                 {
                     if entity_sparse_index.data_index.index() < self.shared_data.dense.len() {
@@ -368,6 +375,63 @@ where
 
         false
     }
+
+    /// Returns true if the rule sets a value.
+    pub(crate) fn has_rule(&self, rule: Rule) -> bool {
+        self.shared_data.contains(rule)
+    }
+
+    /// Links an entity to the value of its parent, as if it were inherited, or to no value if the parent has none.
+    pub(crate) fn link_parent(&mut self, entity: Entity, parent: Option<Entity>) -> bool {
+        let data_index = parent
+            .and_then(|parent| self.inline_data.sparse.get(parent.index()))
+            .map(|index| index.data_index)
+            .filter(|data_index| {
+                if data_index.is_inline() {
+                    data_index.index() < self.inline_data.dense.len()
+                } else {
+                    data_index.index() < self.shared_data.dense.len()
+                }
+            })
+            .map_or(DataIndex::null(), DataIndex::inherited);
+
+        self.link_data_index(entity, data_index)
+    }
+
+    /// Unlinks an entity from any shared data, so it has no value, which isn't replaced by the value of its parent
+    /// when inherited properties are linked.
+    pub(crate) fn link_initial(&mut self, entity: Entity) -> bool {
+        self.link_data_index(entity, DataIndex::initial())
+    }
+
+    /// Unlinks an entity from the value of its parent, if it's linked to it.
+    pub(crate) fn unlink_inherited(&mut self, entity: Entity) -> bool {
+        match self.inline_data.sparse.get(entity.index()) {
+            Some(index)
+                if index.data_index.is_inherited() && index.data_index != DataIndex::null() =>
+            {
+                self.inline_data.sparse[entity.index()].data_index = DataIndex::null();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // Links an entity to the given data, unless it has inline data of its own.
+    fn link_data_index(&mut self, entity: Entity, data_index: DataIndex) -> bool {
+        let entity_index = entity.index();
+        if entity_index >= self.inline_data.sparse.len() {
+            self.inline_data.sparse.resize(entity_index + 1, Index::null());
+        }
+
+        let current = self.inline_data.sparse[entity_index].data_index;
+        if (current.is_inline() && !current.is_inherited()) || current == data_index {
+            return false;
+        }
+
+        self.inline_data.sparse[entity_index].data_index = data_index;
+        true
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,144 @@
+use super::Style;
+use crate::prelude::*;
+use crate::storage::animatable_set::AnimatableSet;
+use crate::storage::style_set::StyleSet;
+
+/// The radii and shapes of the corners of a view, resolved to physical pixels, in the order top-left, top-right,
+/// bottom-right, bottom-left.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct CornerRadii {
+    /// The horizontal and vertical radii of each corner.
+    pub radii: [(f32, f32); 4],
+    pub shapes: [CornerShape; 4],
+}
+
+/// Resolves the horizontal and vertical radii of a corner of a view with the given bounds to physical pixels.
+///
+/// A single radius, which is the same for both axes, is relative to the shorter side of the view when it's a
+/// percentage, so that `50%` rounds the ends of the view into semicircles. A pair of different radii is resolved per
+/// axis, with the horizontal radius relative to the width and the vertical radius relative to the height. Percentages
+/// aren't rounded so that they produce exact curves, and radii which overlap are scaled down when the path is built.
+pub(crate) fn resolve_corner_radius(
+    radius: &CornerRadiusValue,
+    bounds: BoundingBox,
+    scale_factor: f32,
+) -> (f32, f32) {
+    let resolve = |radius: &LengthOrPercentage, size: f32| match radius {
+        LengthOrPercentage::Percentage(_) => radius.to_pixels(size, scale_factor),
+        _ => radius.to_pixels(size, scale_factor).round(),
+    };
+
+    if radius.x == radius.y {
+        let radius = resolve(&radius.x, bounds.w.min(bounds.h));
+        return (radius, radius);
+    }
+
+    (resolve(&radius.x, bounds.w), resolve(&radius.y, bounds.h))
+}
+
+impl Style {
+    /// Returns the resolved corners of a view with the given bounds, or `None` if none of its corners are rounded.
+    pub(crate) fn corner_radii(&self, entity: Entity, bounds: BoundingBox) -> Option<CornerRadii> {
+        let scale_factor = self.scale_factor();
+        let radius = |radius: &AnimatableSet<CornerRadiusValue>| {
+            radius
+                .get(entity)
+                .map_or((0.0, 0.0), |radius| resolve_corner_radius(radius, bounds, scale_factor))
+        };
+        let radii = [
+            radius(&self.corner_top_left_radius),
+            radius(&self.corner_top_right_radius),
+            radius(&self.corner_bottom_right_radius),
+            radius(&self.corner_bottom_left_radius),
+        ];
+
+        if radii.iter().all(|(x, y)| *x <= 0.0 || *y <= 0.0) {
+            return None;
+        }
+
+        let shape = |shape: &StyleSet<CornerShape>| shape.get(entity).copied().unwrap_or_default();
+        Some(CornerRadii {
+            radii,
+            shapes: [
+                shape(&self.corner_top_left_shape),
+                shape(&self.corner_top_right_shape),
+                shape(&self.corner_bottom_right_shape),
+                shape(&self.corner_bottom_left_shape),
+            ],
+        })
+    }
+}
+
+impl CornerRadii {
+    /// Returns true if a point within a rectangle is also within the rectangle with these corners.
+    ///
+    /// Like when the rectangle is drawn, radii of adjacent corners which add up to more than the length of a side are
+    /// all scaled down by the same factor so that they fit.
+    pub(crate) fn contains(&self, rect: BoundingBox, (x, y): (f32, f32)) -> bool {
+        let [top_left, top_right, bottom_right, bottom_left] = self.radii;
+        let fit = |length: f32, a: f32, b: f32| if a + b > length { length / (a + b) } else { 1.0 };
+        let scale = fit(rect.w, top_left.0, top_right.0)
+            .min(fit(rect.w, bottom_left.0, bottom_right.0))
+            .min(fit(rect.h, top_left.1, bottom_left.1))
+            .min(fit(rect.h, top_right.1, bottom_right.1));
+
+        let corners = [
+            (rect.left(), rect.top()),
+            (rect.right(), rect.top()),
+            (rect.right(), rect.bottom()),
+            (rect.left(), rect.bottom()),
+        ];
+
+        corners.into_iter().zip(self.radii).zip(self.shapes).all(|(((cx, cy), (rx, ry)), shape)| {
+            let (rx, ry) = (rx * scale, ry * scale);
+            if rx <= 0.0 || ry <= 0.0 {
+                return true;
+            }
+
+            // The distances of the point from the edges meeting at the corner, as fractions of the radii, which are
+            // both less than one where the point is in the box the corner is cut from.
+            let u = (x - cx).abs() / rx;
+            let v = (y - cy).abs() / ry;
+            if u >= 1.0 || v >= 1.0 {
+                return true;
+            }
+
+            match shape {
+                CornerShape::Round => (1.0 - u).powi(2) + (1.0 - v).powi(2) <= 1.0,
+                CornerShape::Bevel => u + v >= 1.0,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_in_cut_corners_are_outside() {
+        let rect = BoundingBox { x: 0.0, y: 0.0, w: 200.0, h: 40.0 };
+        let pill = CornerRadii { radii: [(20.0, 20.0); 4], ..Default::default() };
+
+        assert!(pill.contains(rect, (100.0, 1.0)));
+        assert!(pill.contains(rect, (20.0, 20.0)));
+        assert!(!pill.contains(rect, (2.0, 2.0)));
+        assert!(!pill.contains(rect, (198.0, 38.0)));
+        // Just inside the arc at 45 degrees.
+        assert!(pill.contains(rect, (6.5, 6.5)));
+        assert!(!pill.contains(rect, (5.0, 5.0)));
+
+        // Radii larger than the rectangle are scaled down to fit, so they still meet the middle of each side.
+        let oversized = CornerRadii { radii: [(100.0, 100.0); 4], ..Default::default() };
+        assert!(oversized.contains(rect, (100.0, 1.0)));
+        assert!(!oversized.contains(rect, (2.0, 2.0)));
+
+        let bevel = CornerRadii {
+            radii: [(20.0, 20.0), (0.0, 0.0), (0.0, 0.0), (0.0, 0.0)],
+            shapes: [CornerShape::Bevel; 4],
+        };
+        assert!(!bevel.contains(rect, (9.0, 9.0)));
+        assert!(bevel.contains(rect, (11.0, 11.0)));
+        assert!(bevel.contains(rect, (199.0, 1.0)));
+    }
+}
@@ -0,0 +1,449 @@
+use std::fmt::Debug;
+
+use hashbrown::HashSet;
+use vizia_style::{CssWideKeyword, Property};
+
+use super::{Rule, Style};
+use crate::animation::Interpolator;
+use crate::prelude::*;
+use crate::storage::animatable_set::AnimatableSet;
+use crate::storage::style_set::StyleSet;
+
+/// The storage of a longhand property, which can be linked to the value of a CSS-wide keyword.
+trait KeywordStore {
+    fn has_rule(&self, rule: Rule) -> bool;
+    fn link_parent(&mut self, entity: Entity, parent: Option<Entity>) -> bool;
+    fn link_initial(&mut self, entity: Entity) -> bool;
+    fn unlink_inherited(&mut self, entity: Entity) -> bool;
+}
+
+impl<T: 'static + Debug> KeywordStore for StyleSet<T> {
+    fn has_rule(&self, rule: Rule) -> bool {
+        self.has_rule(rule)
+    }
+
+    fn link_parent(&mut self, entity: Entity, parent: Option<Entity>) -> bool {
+        self.link_parent(entity, parent)
+    }
+
+    fn link_initial(&mut self, entity: Entity) -> bool {
+        self.link_initial(entity)
+    }
+
+    fn unlink_inherited(&mut self, entity: Entity) -> bool {
+        self.unlink_inherited(entity)
+    }
+}
+
+impl<T: 'static + Default + Clone + Interpolator + PartialEq + Debug> KeywordStore
+    for AnimatableSet<T>
+{
+    fn has_rule(&self, rule: Rule) -> bool {
+        self.has_rule(rule)
+    }
+
+    fn link_parent(&mut self, entity: Entity, parent: Option<Entity>) -> bool {
+        self.link_parent(entity, parent)
+    }
+
+    fn link_initial(&mut self, entity: Entity) -> bool {
+        self.link_initial(entity)
+    }
+
+    fn unlink_inherited(&mut self, entity: Entity) -> bool {
+        self.unlink_inherited(entity)
+    }
+}
+
+macro_rules! longhands {
+    ($($name:literal => [$($($store:ident).+),+],)*) => {
+        /// The names of the longhand properties which can be set to a CSS-wide keyword.
+        const LONGHANDS: &[&str] = &[$($name),*];
+
+        /// Returns the stores of a longhand property, which are more than one for a layout property which stores its
+        /// lengths separately.
+        fn stores<'a>(style: &'a mut Style, name: &str) -> Vec<&'a mut dyn KeywordStore> {
+            match name {
+                $($name => vec![$(&mut style.$($store).+ as &mut dyn KeywordStore),+],)*
+                _ => Vec::new(),
+            }
+        }
+    };
+}
+
+longhands! {
+    "display" => [display],
+    "visibility" => [visibility],
+    "opacity" => [opacity],
+    "z-index" => [z_index],
+    "clip-path" => [clip_path],
+    "overflow-x" => [overflowx],
+    "overflow-y" => [overflowy],
+    "filter" => [filter],
+    "backdrop-filter" => [backdrop_filter],
+    "mix-blend-mode" => [mix_blend_mode],
+    "transform" => [transform],
+    "transform-origin" => [transform_origin],
+    "translate" => [translate],
+    "rotate" => [rotate],
+    "scale" => [scale],
+    "border-top-width" => [border_top_width],
+    "border-right-width" => [border_right_width],
+    "border-bottom-width" => [border_bottom_width],
+    "border-left-width" => [border_left_width],
+    "border-top-color" => [border_top_color],
+    "border-right-color" => [border_right_color],
+    "border-bottom-color" => [border_bottom_color],
+    "border-left-color" => [border_left_color],
+    "border-top-style" => [border_top_style],
+    "border-right-style" => [border_right_style],
+    "border-bottom-style" => [border_bottom_style],
+    "border-left-style" => [border_left_style],
+    "corner-top-left-shape" => [corner_top_left_shape],
+    "corner-top-right-shape" => [corner_top_right_shape],
+    "corner-bottom-left-shape" => [corner_bottom_left_shape],
+    "corner-bottom-right-shape" => [corner_bottom_right_shape],
+    "corner-top-left-radius" => [corner_top_left_radius],
+    "corner-top-right-radius" => [corner_top_right_radius],
+    "corner-bottom-left-radius" => [corner_bottom_left_radius],
+    "corner-bottom-right-radius" => [corner_bottom_right_radius],
+    "outline-width" => [outline_width],
+    "outline-color" => [outline_color],
+    "outline-offset" => [outline_offset],
+    "outline-style" => [outline_style],
+    "focus-ring" => [focus_ring],
+    "focus-ring-color" => [focus_ring_color],
+    "focus-ring-width" => [focus_ring_width],
+    "focus-ring-offset" => [focus_ring_offset],
+    "press-effect" => [press_effect],
+    "ripple-color" => [ripple_color],
+    "ripple-duration" => [ripple_duration],
+    "background-color" => [background_color],
+    "background-image" => [background_image],
+    "background-position" => [background_position],
+    "background-size" => [background_size],
+    "background-repeat" => [background_repeat],
+    "shadow" => [shadow],
+    "elevation" => [elevation],
+    "white-space" => [white_space],
+    "text-overflow" => [text_overflow],
+    "line-clamp" => [line_clamp],
+    "line-height" => [line_height],
+    "text-transform" => [text_transform],
+    "text-rendering" => [text_rendering],
+    "text-align" => [text_align],
+    "vertical-align" => [vertical_align],
+    "text-decoration-line" => [text_decoration_line],
+    "text-decoration-style" => [text_decoration_style],
+    "text-decoration-color" => [text_decoration_color],
+    "text-decoration-thickness" => [text_decoration_thickness],
+    "text-stroke-width" => [text_stroke_width],
+    "text-stroke-style" => [text_stroke_style],
+    "letter-spacing" => [letter_spacing],
+    "word-spacing" => [word_spacing],
+    "underline-style" => [underline_style],
+    "overline-style" => [overline_style],
+    "strikethrough-style" => [strikethrough_style],
+    "underline-color" => [underline_color],
+    "overline-color" => [overline_color],
+    "strikethrough-color" => [strikethrough_color],
+    "font-family" => [font_family],
+    "color" => [font_color],
+    "font-size" => [font_size],
+    "font-weight" => [font_weight],
+    "font-slant" => [font_slant],
+    "font-width" => [font_width],
+    "font-variation-settings" => [font_variation_settings],
+    "font-feature-settings" => [font_feature_settings],
+    "caret-color" => [caret_color],
+    "selection-color" => [selection_color],
+    "fill" => [fill],
+    "stroke" => [stroke],
+    "stroke-width" => [stroke_width],
+    "stroke-linecap" => [stroke_linecap],
+    "stroke-linejoin" => [stroke_linejoin],
+    "cursor" => [cursor],
+    "animation" => [keyframe_animations],
+    "pointer-events" => [pointer_events],
+    "layout-type" => [layout_type],
+    "grid-template-columns" => [grid_columns],
+    "grid-template-rows" => [grid_rows],
+    "grid-column" => [column_start, column_span],
+    "grid-row" => [row_start, row_span],
+    "position-type" => [position_type],
+    "alignment" => [alignment],
+    "align-baseline" => [align_baseline],
+    "baseline-offset" => [baseline_offset],
+    "left" => [left, layout_calc.left],
+    "right" => [right, layout_calc.right],
+    "top" => [top, layout_calc.top],
+    "bottom" => [bottom, layout_calc.bottom],
+    "padding-left" => [padding_left, layout_calc.padding_left],
+    "padding-right" => [padding_right, layout_calc.padding_right],
+    "padding-top" => [padding_top, layout_calc.padding_top],
+    "padding-bottom" => [padding_bottom, layout_calc.padding_bottom],
+    "vertical-gap" => [vertical_gap],
+    "horizontal-gap" => [horizontal_gap],
+    "width" => [width, layout_calc.width],
+    "height" => [height, layout_calc.height],
+    "aspect-ratio" => [aspect_ratio],
+    "min-width" => [min_width, layout_calc.min_width],
+    "max-width" => [max_width, layout_calc.max_width],
+    "min-height" => [min_height, layout_calc.min_height],
+    "max-height" => [max_height, layout_calc.max_height],
+    "min-horizontal-gap" => [min_horizontal_gap],
+    "max-horizontal-gap" => [max_horizontal_gap],
+    "min-vertical-gap" => [min_vertical_gap],
+    "max-vertical-gap" => [max_vertical_gap],
+}
+
+/// Returns the names of the longhand properties set by a property, which is the property itself if it isn't a
+/// shorthand.
+pub(crate) fn longhand_names(name: &str) -> Vec<&'static str> {
+    let longhands: &[&str] = match name {
+        "overflow" => &["overflow-x", "overflow-y"],
+        "blend-mode" => &["mix-blend-mode"],
+        "space" => &["left", "right", "top", "bottom"],
+        "size" => &["width", "height"],
+        "min-size" => &["min-width", "min-height"],
+        "max-size" => &["max-width", "max-height"],
+        "padding" => &["padding-left", "padding-right", "padding-top", "padding-bottom"],
+        "gap" => &["horizontal-gap", "vertical-gap"],
+        "row-gap" => &["vertical-gap"],
+        "column-gap" => &["horizontal-gap"],
+        "text-wrap" => &["white-space"],
+        "min-gap" => &["min-horizontal-gap", "min-vertical-gap"],
+        "max-gap" => &["max-horizontal-gap", "max-vertical-gap"],
+        "border" => &[
+            "border-top-width",
+            "border-right-width",
+            "border-bottom-width",
+            "border-left-width",
+            "border-top-style",
+            "border-right-style",
+            "border-bottom-style",
+            "border-left-style",
+            "border-top-color",
+            "border-right-color",
+            "border-bottom-color",
+            "border-left-color",
+        ],
+        "border-width" => {
+            &["border-top-width", "border-right-width", "border-bottom-width", "border-left-width"]
+        }
+        "border-style" => {
+            &["border-top-style", "border-right-style", "border-bottom-style", "border-left-style"]
+        }
+        "border-color" => {
+            &["border-top-color", "border-right-color", "border-bottom-color", "border-left-color"]
+        }
+        "corner-shape" => &[
+            "corner-top-left-shape",
+            "corner-top-right-shape",
+            "corner-bottom-left-shape",
+            "corner-bottom-right-shape",
+        ],
+        "corner-radius" => &[
+            "corner-top-left-radius",
+            "corner-top-right-radius",
+            "corner-bottom-left-radius",
+            "corner-bottom-right-radius",
+        ],
+        "outline" => &["outline-width", "outline-style", "outline-color"],
+        "background" => &[
+            "background-color",
+            "background-image",
+            "background-position",
+            "background-size",
+            "background-repeat",
+        ],
+        "text-decoration" => &[
+            "text-decoration-line",
+            "text-decoration-style",
+            "text-decoration-color",
+            "text-decoration-thickness",
+        ],
+        "text-stroke" => &["text-stroke-width", "text-stroke-style"],
+        name => return LONGHANDS.iter().copied().filter(|longhand| *longhand == name).collect(),
+    };
+
+    longhands.to_vec()
+}
+
+impl Style {
+    /// Links the properties of an entity which are set to a CSS-wide keyword by the rules it matches, which are in
+    /// order of decreasing specificity, after the entity has been linked to the values of the rules. Returns true if
+    /// the value of any property has changed.
+    ///
+    /// A keyword applies to a property if no rule which takes precedence over the rule setting it to the keyword sets
+    /// a value for the property. `inherit` links the entity to the value of its parent when it's styled, while
+    /// `initial` leaves the property without a value, which isn't inherited from the parent either.
+    pub(crate) fn link_css_wide_keywords(
+        &mut self,
+        entity: Entity,
+        parent: Option<Entity>,
+        matched_rules: &[Rule],
+    ) -> bool {
+        let mut changed = false;
+
+        // A property which isn't inherited keeps the value of the parent until the entity is linked to it again.
+        if self.keyword_inherited.remove(&entity) {
+            for name in LONGHANDS.iter().filter(|name| !Property::is_inherited(name)) {
+                for store in stores(self, name) {
+                    changed |= store.unlink_inherited(entity);
+                }
+            }
+        }
+
+        if self.rule_keywords.is_empty() {
+            return changed;
+        }
+
+        let mut linked = HashSet::new();
+        for (position, rule) in matched_rules.iter().enumerate() {
+            let Some(declarations) = self.rule_keywords.get(rule).cloned() else {
+                continue;
+            };
+
+            for (name, keyword) in declarations {
+                // The keyword of a more specific rule takes precedence.
+                if !linked.insert(name) {
+                    continue;
+                }
+
+                let inherit = match keyword {
+                    CssWideKeyword::Inherit => true,
+                    CssWideKeyword::Initial => false,
+                    CssWideKeyword::Unset => Property::is_inherited(name),
+                };
+
+                for store in stores(self, name) {
+                    if matched_rules[..position].iter().any(|rule| store.has_rule(*rule)) {
+                        continue;
+                    }
+
+                    changed |= if inherit {
+                        store.link_parent(entity, parent)
+                    } else {
+                        store.link_initial(entity)
+                    };
+                }
+
+                if inherit && !Property::is_inherited(name) {
+                    self.keyword_inherited.insert(entity);
+                }
+            }
+        }
+
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::systems::style_system;
+
+    const STYLE: &str = r#"
+        .panel {
+            color: red;
+            font-size: 20px;
+            background-color: blue;
+        }
+
+        .swatch {
+            color: green;
+            font-size: 12px;
+            background-color: green;
+        }
+
+        .swatch.inherit {
+            color: inherit;
+            font-size: inherit;
+            background-color: inherit;
+        }
+
+        .swatch.initial {
+            color: initial;
+            font-size: initial;
+            background-color: initial;
+        }
+
+        .swatch.unset {
+            color: unset;
+            font-size: unset;
+            background-color: unset;
+        }
+
+        .backed {
+            background-color: inherit;
+        }
+    "#;
+
+    fn swatch(cx: &mut Context, keyword: &str) -> Entity {
+        let mut swatch = Entity::root();
+        VStack::new(cx, |cx| {
+            swatch = Element::new(cx).class("swatch").class(keyword).entity();
+        })
+        .class("panel");
+        style_system(cx);
+        swatch
+    }
+
+    #[test]
+    fn inherit_takes_the_value_of_the_parent() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(STYLE).unwrap();
+        let swatch = swatch(&mut cx, "inherit");
+
+        assert_eq!(cx.style.font_color.get(swatch), Some(&Color::red().into()));
+        assert_eq!(cx.style.font_size.get(swatch), Some(&FontSize(20.0)));
+        assert_eq!(cx.style.background_color.get(swatch), Some(&Color::blue()));
+    }
+
+    #[test]
+    fn initial_removes_the_value() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(STYLE).unwrap();
+        let swatch = swatch(&mut cx, "initial");
+
+        // Inherited properties aren't inherited from the parent either.
+        assert_eq!(cx.style.font_color.get(swatch), None);
+        assert_eq!(cx.style.font_size.get(swatch), None);
+        assert_eq!(cx.style.background_color.get(swatch), None);
+    }
+
+    #[test]
+    fn unset_inherits_only_inherited_properties() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(STYLE).unwrap();
+        let swatch = swatch(&mut cx, "unset");
+
+        assert_eq!(cx.style.font_color.get(swatch), Some(&Color::red().into()));
+        assert_eq!(cx.style.font_size.get(swatch), Some(&FontSize(20.0)));
+        assert_eq!(cx.style.background_color.get(swatch), None);
+    }
+
+    #[test]
+    fn keyword_stops_applying_when_its_rule_no_longer_matches() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(STYLE).unwrap();
+        let mut element = Entity::root();
+        VStack::new(&mut cx, |cx| {
+            element = Element::new(cx).class("backed").entity();
+        })
+        .class("panel");
+        style_system(&mut cx);
+        assert_eq!(cx.style.background_color.get(element), Some(&Color::blue()));
+
+        cx.with_current(element, |cx| cx.toggle_class("backed", false));
+        style_system(&mut cx);
+        assert_eq!(cx.style.background_color.get(element), None);
+
+        // An inline value takes precedence over the keyword.
+        cx.with_current(element, |cx| cx.toggle_class("backed", true));
+        cx.style.background_color.insert(element, Color::green());
+        style_system(&mut cx);
+        assert_eq!(cx.style.background_color.get(element), Some(&Color::green()));
+    }
+}
@@ -0,0 +1,198 @@
+use hashbrown::HashMap;
+use vizia_style::{Property, TokenList};
+
+use super::{Rule, Style};
+use crate::prelude::*;
+
+// Substitutes the `var()` references in a value, returning `None` if the value is invalid.
+fn substitute<'a>(value: &str, lookup: &impl Fn(&str) -> Option<&'a str>) -> Option<String> {
+    TokenList::parse_string(value).ok()?.substitute_variables(lookup)
+}
+
+impl Style {
+    /// Computes the custom properties of an entity from those inherited from its parent, those declared by the rules
+    /// it matches, and those set on it with a modifier. Returns true if the custom properties have changed.
+    ///
+    /// The matched rules are in order of decreasing specificity.
+    pub(crate) fn compute_custom_properties(
+        &mut self,
+        entity: Entity,
+        parent: Option<Entity>,
+        matched_rules: &[Rule],
+    ) -> bool {
+        let computed = {
+            let mut declared = HashMap::new();
+            for rule in matched_rules.iter().rev() {
+                if let Some(properties) = self.rule_custom_properties.get(rule) {
+                    for (name, value) in properties.iter() {
+                        declared.insert(name.as_str(), value.as_str());
+                    }
+                }
+            }
+
+            if let Some(properties) = self.custom_properties.get(entity) {
+                for (name, value) in properties.iter() {
+                    declared.insert(name.as_str(), value.as_str());
+                }
+            }
+
+            let inherited = parent.and_then(|parent| self.computed_custom_properties.get(parent));
+            let lookup = |name: &str| {
+                declared.get(name).copied().or_else(|| {
+                    inherited.and_then(|inherited| inherited.get(name)).map(String::as_str)
+                })
+            };
+
+            let mut computed = inherited.cloned().unwrap_or_default();
+            for (name, value) in declared.iter() {
+                match substitute(value, &lookup) {
+                    Some(value) => computed.insert(name.to_string(), value),
+                    None => computed.remove(*name),
+                };
+            }
+
+            computed
+        };
+
+        let changed = match self.computed_custom_properties.get(entity) {
+            Some(previous) => *previous != computed,
+            None => !computed.is_empty(),
+        };
+
+        if computed.is_empty() {
+            self.computed_custom_properties.remove(entity);
+        } else {
+            self.computed_custom_properties.insert(entity, computed);
+        }
+
+        changed
+    }
+
+    /// Returns the matched rules of an entity with a rule holding the values of the declarations which reference
+    /// custom properties placed before each rule the declarations are from.
+    ///
+    /// A declaration whose value is invalid once the custom properties are substituted is ignored.
+    pub(crate) fn resolve_variables(
+        &mut self,
+        entity: Entity,
+        matched_rules: &[Rule],
+    ) -> Vec<Rule> {
+        let mut rules = Vec::with_capacity(matched_rules.len());
+
+        for rule in matched_rules.iter() {
+            if let Some(declarations) = self.rule_variables.get(rule) {
+                let custom_properties = self.computed_custom_properties.get(entity);
+                let lookup = |name: &str| {
+                    custom_properties
+                        .and_then(|custom_properties| custom_properties.get(name))
+                        .map(String::as_str)
+                };
+
+                let resolved = declarations
+                    .iter()
+                    .filter_map(|(name, value)| Some((name.clone(), substitute(value, &lookup)?)))
+                    .collect::<Vec<_>>();
+
+                // Entities which substitute the same values share a rule.
+                let key = (*rule, resolved);
+                let resolved_rule = match self.resolved_rules.get(&key) {
+                    Some(resolved_rule) => *resolved_rule,
+                    None => {
+                        let resolved_rule = self.rule_manager.create();
+                        let mut transitions = Vec::new();
+                        for (name, value) in key.1.iter() {
+                            match Property::parse_declaration(name, value) {
+                                Some(Property::Transition(resolved)) => {
+                                    transitions.extend(resolved)
+                                }
+
+                                Some(property) => self.insert_property(resolved_rule, property),
+
+                                None => {}
+                            }
+                        }
+
+                        for transition in transitions.iter() {
+                            self.insert_transition(resolved_rule, transition);
+                        }
+
+                        self.resolved_rules.insert(key, resolved_rule);
+                        resolved_rule
+                    }
+                };
+
+                rules.push(resolved_rule);
+            }
+
+            rules.push(*rule);
+        }
+
+        rules
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::systems::style_system;
+
+    const STYLE: &str = r#"
+        :root {
+            --accent: #ff0000;
+        }
+
+        .panel {
+            --accent: #0000ff;
+        }
+
+        .swatch {
+            background-color: var(--accent);
+            border-color: var(--border, var(--accent));
+            width: var(--size, 20px);
+            opacity: var(--missing);
+        }
+    "#;
+
+    fn build(cx: &mut Context) -> (Entity, Entity, Entity) {
+        cx.add_stylesheet(STYLE).unwrap();
+        let mut swatches = (Entity::root(), Entity::root());
+        let panel = VStack::new(cx, |cx| {
+            swatches.0 = Element::new(cx).class("swatch").entity();
+            swatches.1 =
+                Element::new(cx).class("swatch").custom_property("--size", "30px").entity();
+        })
+        .class("panel")
+        .entity();
+        style_system(cx);
+        (panel, swatches.0, swatches.1)
+    }
+
+    #[test]
+    fn variables_are_substituted() {
+        let mut cx = Context::default();
+        let (_, swatch, sized) = build(&mut cx);
+
+        assert_eq!(cx.style.background_color.get(swatch), Some(&Color::rgb(0, 0, 255)));
+        assert_eq!(
+            cx.style.border_bottom_color.get(swatch),
+            Some(&ColorOrGradient::Color(Color::rgb(0, 0, 255)))
+        );
+        assert_eq!(cx.style.width.get(swatch), Some(&Units::Pixels(20.0)));
+        assert_eq!(cx.style.width.get(sized), Some(&Units::Pixels(30.0)));
+        assert_eq!(cx.style.opacity.get(swatch), None);
+    }
+
+    #[test]
+    fn changing_a_variable_restyles_descendants() {
+        let mut cx = Context::default();
+        let (panel, swatch, _) = build(&mut cx);
+
+        cx.with_current(panel, |cx| {
+            Handle::<VStack> { current: panel, entity: panel, p: Default::default(), cx }
+                .custom_property("--accent", Color::rgb(0, 255, 0));
+        });
+        style_system(&mut cx);
+
+        assert_eq!(cx.style.background_color.get(swatch), Some(&Color::rgb(0, 255, 0)));
+    }
+}
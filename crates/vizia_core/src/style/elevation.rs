@@ -0,0 +1,127 @@
+use vizia_style::{Color, Elevation, Length, Shadow};
+
+// The shadows shown by a level of elevation: a sharp key shadow directly below the view and a softer ambient shadow
+// around it. Lengths are in logical pixels.
+#[derive(Clone, Copy)]
+struct ShadowRecipe {
+    key_y: f32,
+    key_blur: f32,
+    key_alpha: f32,
+    ambient_y: f32,
+    ambient_blur: f32,
+    ambient_spread: f32,
+    ambient_alpha: f32,
+}
+
+impl ShadowRecipe {
+    const fn new(
+        key_y: f32,
+        key_blur: f32,
+        ambient_y: f32,
+        ambient_blur: f32,
+        ambient_spread: f32,
+    ) -> Self {
+        Self {
+            key_y,
+            key_blur,
+            key_alpha: 0.3,
+            ambient_y,
+            ambient_blur,
+            ambient_spread,
+            ambient_alpha: 0.15,
+        }
+    }
+
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        let lerp = |start: f32, end: f32| start + (end - start) * t;
+        Self {
+            key_y: lerp(self.key_y, other.key_y),
+            key_blur: lerp(self.key_blur, other.key_blur),
+            key_alpha: lerp(self.key_alpha, other.key_alpha),
+            ambient_y: lerp(self.ambient_y, other.ambient_y),
+            ambient_blur: lerp(self.ambient_blur, other.ambient_blur),
+            ambient_spread: lerp(self.ambient_spread, other.ambient_spread),
+            ambient_alpha: lerp(self.ambient_alpha, other.ambient_alpha),
+        }
+    }
+}
+
+// Level 0 has transparent shadows with no offset or blur, so that the shadows grow and fade in as a view is raised
+// from it.
+const LEVELS: [ShadowRecipe; 6] = [
+    ShadowRecipe {
+        key_alpha: 0.0,
+        ambient_alpha: 0.0,
+        ..ShadowRecipe::new(0.0, 0.0, 0.0, 0.0, 0.0)
+    },
+    ShadowRecipe::new(1.0, 2.0, 1.0, 3.0, 1.0),
+    ShadowRecipe::new(1.0, 2.0, 2.0, 6.0, 2.0),
+    ShadowRecipe::new(1.0, 3.0, 4.0, 8.0, 3.0),
+    ShadowRecipe::new(2.0, 3.0, 6.0, 10.0, 4.0),
+    ShadowRecipe::new(4.0, 4.0, 8.0, 12.0, 6.0),
+];
+
+/// Returns the shadows shown by an elevation, interpolating the shadows of the levels either side of a fractional
+/// elevation. Elevations above the highest level show the shadows of the highest level.
+pub(crate) fn elevation_shadows(elevation: Elevation) -> Vec<Shadow> {
+    let level = elevation.0.clamp(0.0, (LEVELS.len() - 1) as f32);
+    let lower = level.floor() as usize;
+    let upper = level.ceil() as usize;
+    let recipe = LEVELS[lower].lerp(&LEVELS[upper], level - lower as f32);
+
+    vec![
+        shadow(recipe.key_y, recipe.key_blur, 0.0, recipe.key_alpha),
+        shadow(recipe.ambient_y, recipe.ambient_blur, recipe.ambient_spread, recipe.ambient_alpha),
+    ]
+}
+
+fn shadow(y_offset: f32, blur_radius: f32, spread_radius: f32, alpha: f32) -> Shadow {
+    Shadow::new(
+        Length::px(0.0),
+        Length::px(y_offset),
+        Some(Length::px(blur_radius)),
+        Some(Length::px(spread_radius)),
+        Some(Color::rgba(0, 0, 0, (alpha * 255.0).round() as u8)),
+        false,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn levels_map_to_the_built_in_shadows() {
+        assert_eq!(
+            elevation_shadows(Elevation(2.0)),
+            vec![shadow(1.0, 2.0, 0.0, 0.3), shadow(2.0, 6.0, 2.0, 0.15)]
+        );
+        assert_eq!(elevation_shadows(Elevation(8.0)), elevation_shadows(Elevation(5.0)));
+    }
+
+    #[test]
+    fn fractional_elevations_interpolate_the_shadows() {
+        assert_eq!(
+            elevation_shadows(Elevation(3.5)),
+            vec![shadow(1.5, 3.0, 0.0, 0.3), shadow(5.0, 9.0, 3.5, 0.15)]
+        );
+        assert_eq!(
+            elevation_shadows(Elevation(0.5)),
+            vec![shadow(0.5, 1.0, 0.0, 0.15), shadow(0.5, 1.5, 0.5, 0.075)]
+        );
+    }
+
+    #[test]
+    fn explicit_shadows_override_elevation() {
+        let mut cx = Context::default();
+        let raised = Element::new(&mut cx).elevation(2).entity();
+        let shadowed = Element::new(&mut cx).elevation(2).shadow("0px 4px 8px black").entity();
+
+        assert_eq!(cx.style.shadows(raised).unwrap().as_ref(), elevation_shadows(Elevation(2.0)));
+        assert_eq!(
+            cx.style.shadows(shadowed).unwrap().as_ref(),
+            [Shadow::from("0px 4px 8px black")]
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use morphorm::Node;
+
+use super::Style;
+use crate::prelude::*;
+
+/// The cells of a grid which a child of the grid occupies.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct GridCell {
+    pub column: usize,
+    pub column_span: usize,
+    pub row: usize,
+    pub row_span: usize,
+}
+
+// The cells of a grid which are occupied by the children placed so far.
+struct Occupied {
+    columns: usize,
+    rows: usize,
+    cells: Vec<bool>,
+}
+
+impl Occupied {
+    fn new(columns: usize, rows: usize) -> Self {
+        Self { columns, rows, cells: vec![false; columns * rows] }
+    }
+
+    fn is_free(&self, column: usize, row: usize, column_span: usize, row_span: usize) -> bool {
+        column + column_span <= self.columns
+            && row + row_span <= self.rows
+            && (row..row + row_span).all(|row| {
+                (column..column + column_span)
+                    .all(|column| !self.cells[row * self.columns + column])
+            })
+    }
+
+    fn occupy(&mut self, cell: GridCell) {
+        for row in cell.row..cell.row + cell.row_span {
+            for column in cell.column..cell.column + cell.column_span {
+                self.cells[row * self.columns + column] = true;
+            }
+        }
+    }
+}
+
+impl Style {
+    /// Returns the sizes of the columns or rows of a grid, with the lengths in physical pixels.
+    ///
+    /// Tracks aren't sized to their content, so an `auto` track shares the free space of the grid like a `1fr` track.
+    pub(crate) fn grid_tracks(&self, tracks: Option<&Vec<Units>>) -> Option<Vec<Units>> {
+        tracks.map(|tracks| {
+            tracks
+                .iter()
+                .map(|track| match *track {
+                    Units::Pixels(val) => Units::Pixels(self.logical_to_physical(val)),
+                    Units::Auto => Units::Stretch(1.0),
+                    track => track,
+                })
+                .collect()
+        })
+    }
+
+    /// Places the children of a grid in its cells.
+    ///
+    /// The children with both a row and a column are placed first, clamped to the tracks of the grid, and the rest
+    /// then fill the first free cells in which their span fits, from left to right and then from top to bottom. A
+    /// child which doesn't fit in the remaining cells overlaps the last row.
+    pub(crate) fn place_grid_children(&mut self, tree: &Tree<Entity>, grid: Entity) {
+        let columns = self.grid_columns.get(grid).map_or(0, Vec::len).max(1);
+        let rows = self.grid_rows.get(grid).map_or(0, Vec::len).max(1);
+        let mut occupied = Occupied::new(columns, rows);
+
+        // The same children which morphorm lays out in the grid.
+        let style = &*self;
+        let children = grid
+            .children(tree)
+            .filter(|child| {
+                child.visible(style)
+                    && child.position_type(style).unwrap_or_default() == PositionType::Relative
+            })
+            .copied()
+            .collect::<Vec<_>>();
+
+        let span =
+            |span: Option<&usize>, tracks: usize| span.copied().unwrap_or(1).clamp(1, tracks);
+
+        let mut auto_placed = Vec::new();
+        for child in children {
+            let column_span = span(self.column_span.get(child), columns);
+            let row_span = span(self.row_span.get(child), rows);
+            match (self.column_start.get(child), self.row_start.get(child)) {
+                (Some(column), Some(row)) => {
+                    let cell = GridCell {
+                        column: (*column).min(columns - column_span),
+                        column_span,
+                        row: (*row).min(rows - row_span),
+                        row_span,
+                    };
+                    occupied.occupy(cell);
+                    self.grid_cells.insert(child, cell);
+                }
+
+                (column, row) => {
+                    auto_placed.push((child, column.copied(), row.copied(), column_span, row_span))
+                }
+            }
+        }
+
+        let (mut cursor_column, mut cursor_row) = (0, 0);
+        for (child, column, row, column_span, row_span) in auto_placed {
+            let column = column.map(|column| column.min(columns - column_span));
+            let row = row.map(|row| row.min(rows - row_span));
+
+            // A child with a row or a column is placed in the first free cell of it, without moving the cursor.
+            let free = (0..rows)
+                .flat_map(|row| (0..columns).map(move |column| (column, row)))
+                .skip(if column.is_none() && row.is_none() {
+                    cursor_row * columns + cursor_column
+                } else {
+                    0
+                })
+                .filter(|&(free_column, free_row)| {
+                    column.map_or(true, |column| column == free_column)
+                        && row.map_or(true, |row| row == free_row)
+                })
+                .find(|&(column, row)| occupied.is_free(column, row, column_span, row_span));
+
+            let (free_column, free_row) =
+                free.unwrap_or((column.unwrap_or(0), row.unwrap_or(rows - row_span)));
+            let cell = GridCell { column: free_column, column_span, row: free_row, row_span };
+            occupied.occupy(cell);
+            self.grid_cells.insert(child, cell);
+
+            if column.is_none() && row.is_none() {
+                (cursor_column, cursor_row) = (free_column + column_span, free_row);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::systems::{layout_system, style_system};
+
+    #[test]
+    fn children_fill_the_free_cells_in_order() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(
+            r#"
+            .grid {
+                layout-type: grid;
+                grid-template-columns: 100px 1fr;
+                grid-template-rows: 20px 20px 20px;
+            }
+            .header { grid-column: 1 / span 2; }
+            .wide { grid-column: span 5; grid-row: 9; }
+            "#,
+        )
+        .unwrap();
+
+        let grid = Element::new(&mut cx).class("grid").entity();
+        let children = cx.with_current(grid, |cx| {
+            vec![
+                Element::new(cx).entity(),
+                Element::new(cx).class("header").entity(),
+                Element::new(cx).entity(),
+                Element::new(cx).class("wide").entity(),
+                Element::new(cx).row_start(0).column_start(1).entity(),
+            ]
+        });
+        style_system(&mut cx);
+        layout_system(&mut cx);
+
+        let cell = |child: Entity| {
+            let cell = cx.style.grid_cells.get(child).unwrap();
+            (cell.column, cell.column_span, cell.row, cell.row_span)
+        };
+
+        // The child placed in the first row takes the cell after the first, and the header spans the second row.
+        assert_eq!(cell(children[4]), (1, 1, 0, 1));
+        assert_eq!(cell(children[0]), (0, 1, 0, 1));
+        assert_eq!(cell(children[1]), (0, 2, 1, 1));
+        assert_eq!(cell(children[2]), (0, 1, 2, 1));
+        // A placement outside of the grid is clamped to its last row and its span to the number of columns.
+        assert_eq!(cell(children[3]), (0, 2, 2, 1));
+    }
+}
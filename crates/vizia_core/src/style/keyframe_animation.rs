@@ -0,0 +1,237 @@
+use super::{Rule, Style};
+use crate::animation::{AnimationPlayback, Spring, TimingFunction};
+use crate::prelude::*;
+
+impl Style {
+    /// Links an entity to the animations set by the `animation` property of the rules it matches, starting those it's
+    /// newly linked to and stopping those it's no longer linked to.
+    ///
+    /// An animation which has finished isn't played again until the entity stops matching the rules which set it, so
+    /// restyling the entity for another reason, such as when the stylesheets are reloaded, doesn't restart it.
+    ///
+    /// Returns true if an animation was stopped, so the entity shows its values without the animation again.
+    pub(crate) fn link_keyframe_animations(
+        &mut self,
+        entity: Entity,
+        matched_rules: &[Rule],
+    ) -> bool {
+        if !self.keyframe_animations.link(entity, matched_rules) {
+            return false;
+        }
+
+        let animations = self.keyframe_animations.get(entity).cloned().unwrap_or_default();
+        let running = self.running_animations.remove(entity).unwrap_or_default();
+
+        let mut stopped = false;
+        for (animation, id) in running.iter() {
+            if !animations.contains(animation) {
+                self.stop_animation(entity, *id);
+                stopped = true;
+            }
+        }
+
+        // Animations later in the list are played last, so take precedence for the properties they share.
+        let mut started = Vec::new();
+        for animation in animations {
+            if let Some(running) = running.iter().find(|(running, _)| *running == animation) {
+                started.push(running.clone());
+                continue;
+            }
+
+            let Some(id) =
+                animation.name.as_ref().and_then(|name| self.animations.get(name)).copied()
+            else {
+                continue;
+            };
+
+            let playback = AnimationPlayback {
+                duration: animation.duration,
+                delay: animation.delay,
+                iterations: animation.iteration_count.as_f32(),
+                direction: animation.direction,
+                fill_mode: animation.fill_mode,
+                timing_function: Some(TimingFunction::from(animation.timing_function)),
+                spring: Spring::from_easing(animation.timing_function),
+            };
+            self.play_animation(entity, id, Instant::now(), &playback);
+            started.push((animation, id));
+        }
+
+        if !started.is_empty() {
+            self.running_animations.insert(entity, started);
+        }
+
+        stopped
+    }
+
+    /// Stops the animations of an entity whose keyframes are no longer declared by any stylesheet, such as once the
+    /// stylesheet declaring them has been removed, so the entity shows its values without them.
+    pub(crate) fn stop_undeclared_animations(&mut self, entity: Entity) {
+        let Some(running) = self.running_animations.get_mut(entity) else {
+            return;
+        };
+
+        let mut undeclared = Vec::new();
+        running.retain(|(animation, id)| {
+            let declared =
+                animation.name.as_ref().is_some_and(|name| self.animations.contains_key(name));
+            if !declared {
+                undeclared.push(*id);
+            }
+            declared
+        });
+
+        if running.is_empty() {
+            self.running_animations.remove(entity);
+        }
+
+        for id in undeclared {
+            self.stop_animation(entity, id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::systems::style_system;
+
+    fn opacity(cx: &Context, entity: Entity) -> f32 {
+        cx.style.opacity.get(entity).map(|opacity| opacity.0).unwrap_or(1.0)
+    }
+
+    fn toggle_class(cx: &mut Context, entity: Entity, class: &str, applied: bool) {
+        cx.with_current(entity, |cx| cx.toggle_class(class, applied));
+        style_system(cx);
+    }
+
+    fn tick(cx: &mut Context, elapsed: Duration) {
+        cx.style.opacity.tick(Instant::now() + elapsed, ColorSpace::default());
+    }
+
+    #[test]
+    fn class_starts_and_stops_animation() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(
+            r#"
+            @keyframes fade {
+                to { opacity: 1; }
+                from { opacity: 0; }
+            }
+
+            element { opacity: 0.5; transition: opacity 1s; }
+            element.fading { opacity: 0.8; transition: opacity 1s; animation: fade 1s linear 2 alternate; }
+            "#,
+        )
+        .unwrap();
+
+        let element = Element::new(&mut cx).entity();
+        style_system(&mut cx);
+        assert_eq!(opacity(&cx, element), 0.5);
+
+        // The animation takes precedence over the transition to the value of the rule.
+        toggle_class(&mut cx, element, "fading", true);
+        assert_eq!(opacity(&cx, element), 0.0);
+
+        tick(&mut cx, Duration::from_millis(250));
+        assert!((opacity(&cx, element) - 0.25).abs() < 0.05);
+
+        // The second iteration plays backwards.
+        tick(&mut cx, Duration::from_millis(1250));
+        assert!((opacity(&cx, element) - 0.75).abs() < 0.05);
+
+        // The value of the rule applies once the animation finishes.
+        tick(&mut cx, Duration::from_secs(3));
+        cx.style.opacity.remove_innactive_animations();
+        assert_eq!(opacity(&cx, element), 0.8);
+
+        // Removing the class and adding it again plays the animation again, and removing it stops the animation.
+        toggle_class(&mut cx, element, "fading", false);
+        toggle_class(&mut cx, element, "fading", true);
+        assert_eq!(opacity(&cx, element), 0.0);
+
+        toggle_class(&mut cx, element, "fading", false);
+        assert_eq!(opacity(&cx, element), 0.5);
+        assert!(cx.style.opacity.get_active_animation(element).is_none());
+        assert!(!cx.style.running_animations.contains(element));
+    }
+
+    #[test]
+    fn fill_modes_and_reloads() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(
+            r#"
+            @keyframes fade {
+                0% { opacity: 0; }
+                100% { opacity: 1; }
+            }
+
+            element { opacity: 0.5; }
+            .late { animation: fade 1s 500ms forwards; }
+            .early { animation: fade 1s 500ms reverse backwards; }
+            "#,
+        )
+        .unwrap();
+
+        let late = Element::new(&mut cx).class("late").entity();
+        let early = Element::new(&mut cx).class("early").entity();
+        style_system(&mut cx);
+
+        // During the delay, only an animation which fills backwards applies, from the keyframe it starts at.
+        tick(&mut cx, Duration::from_millis(100));
+        assert_eq!(opacity(&cx, late), 0.5);
+        assert!((opacity(&cx, early) - 1.0).abs() < 0.01);
+
+        // After finishing, only an animation which fills forwards still applies.
+        tick(&mut cx, Duration::from_secs(2));
+        cx.style.opacity.remove_innactive_animations();
+        assert!((opacity(&cx, late) - 1.0).abs() < 0.01);
+        assert_eq!(opacity(&cx, early), 0.5);
+
+        // Reloading the stylesheets doesn't play the animation again.
+        cx.add_stylesheet("element { width: 10px; }").unwrap();
+        style_system(&mut cx);
+        assert!((opacity(&cx, late) - 1.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn removing_and_replacing_stylesheets() {
+        let mut cx = Context::default();
+        cx.add_stylesheet("element { opacity: 0.5; }").unwrap();
+        let theme = cx
+            .add_stylesheet(
+                r#"
+                @keyframes fade {
+                    from { opacity: 0; }
+                    to { opacity: 1; }
+                }
+
+                element { animation: fade 10s; }
+                "#,
+            )
+            .unwrap();
+
+        let element = Element::new(&mut cx).entity();
+        style_system(&mut cx);
+        assert_eq!(opacity(&cx, element), 0.0);
+
+        // Removing the stylesheet which declares the keyframes stops the animation.
+        assert!(cx.remove_stylesheet(theme));
+        style_system(&mut cx);
+        assert!(!cx.style.running_animations.contains(element));
+        assert!(cx.style.opacity.get_active_animation(element).is_none());
+        assert_eq!(opacity(&cx, element), 0.5);
+        assert!(!cx.remove_stylesheet(theme));
+
+        // A replaced stylesheet keeps its precedence over the stylesheets added before it.
+        let dark = cx.add_stylesheet("element { opacity: 0.2; }").unwrap();
+        style_system(&mut cx);
+        assert_eq!(opacity(&cx, element), 0.2);
+
+        cx.replace_stylesheet(dark, "element { opacity: 0.8; }").unwrap();
+        style_system(&mut cx);
+        assert_eq!(opacity(&cx, element), 0.8);
+
+        assert!(cx.replace_stylesheet(theme, "element { opacity: 0; }").is_err());
+    }
+}
@@ -0,0 +1,124 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// A named layer which a view and its descendants are drawn and hit-tested in.
+///
+/// Views in a higher layer are always drawn above, and receive the mouse before, views in lower layers, regardless of
+/// their position in the tree or their z-index. Within a layer, views are ordered by their z-index and then by their
+/// position in the tree.
+///
+/// A view without a layer is in the layer of its parent.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// // Drawn above the rest of the content, but below popups and tooltips.
+/// VStack::new(cx, |cx| {
+///     Label::new(cx, "Floating toolbar");
+/// })
+/// .layer(Layer::Floating);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Layer {
+    /// The layer of the content of a window.
+    #[default]
+    Base,
+    /// Content which floats above the content of a window, such as a floating toolbar.
+    Floating,
+    /// Popups, such as the content of dropdowns and menus.
+    Popup,
+    /// Tooltips.
+    Tooltip,
+    /// Toast notifications.
+    Toast,
+    /// Previews of dragged content.
+    DragOverlay,
+    /// Debugging overlays.
+    Debug,
+}
+
+/// The position of a view in the order that views are drawn and hit-tested, compared by layer and then by z-index.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct StackOrder {
+    pub layer: Layer,
+    pub z_index: i32,
+}
+
+/// A queue of the views which are drawn or hit-tested after their ancestors because of their layer or z-index.
+///
+/// Views are popped from the lowest stack order to the highest, and views with the same stack order are popped in the
+/// order they were pushed, which is the order they are found while walking the tree.
+pub(crate) struct StackQueue<T> {
+    heap: BinaryHeap<Stacked<T>>,
+    pushed: usize,
+}
+
+impl<T> StackQueue<T> {
+    pub fn new() -> Self {
+        Self { heap: BinaryHeap::new(), pushed: 0 }
+    }
+
+    pub fn push(&mut self, order: StackOrder, item: T) {
+        self.heap.push(Stacked { order, sequence: self.pushed, item });
+        self.pushed += 1;
+    }
+
+    pub fn pop(&mut self) -> Option<(StackOrder, T)> {
+        self.heap.pop().map(|stacked| (stacked.order, stacked.item))
+    }
+}
+
+struct Stacked<T> {
+    order: StackOrder,
+    sequence: usize,
+    item: T,
+}
+
+impl<T> Ord for Stacked<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed, as the binary heap pops the greatest item first.
+        other.order.cmp(&self.order).then(other.sequence.cmp(&self.sequence))
+    }
+}
+
+impl<T> PartialOrd for Stacked<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> PartialEq for Stacked<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.order == other.order && self.sequence == other.sequence
+    }
+}
+
+impl<T> Eq for Stacked<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn higher_layers_are_popped_last_regardless_of_z_index() {
+        let mut queue = StackQueue::new();
+        queue.push(StackOrder { layer: Layer::Tooltip, z_index: 0 }, "tooltip");
+        queue.push(StackOrder { layer: Layer::Base, z_index: 1000 }, "content");
+        queue.push(StackOrder { layer: Layer::Popup, z_index: 0 }, "popup");
+
+        let popped = std::iter::from_fn(|| queue.pop().map(|(_, item)| item)).collect::<Vec<_>>();
+        assert_eq!(popped, vec!["content", "popup", "tooltip"]);
+    }
+
+    #[test]
+    fn views_in_the_same_layer_are_ordered_by_z_index_then_tree_order() {
+        let mut queue = StackQueue::new();
+        queue.push(StackOrder { layer: Layer::Popup, z_index: 1 }, "raised");
+        queue.push(StackOrder { layer: Layer::Popup, z_index: 0 }, "first");
+        queue.push(StackOrder { layer: Layer::Popup, z_index: 0 }, "second");
+
+        let popped = std::iter::from_fn(|| queue.pop().map(|(_, item)| item)).collect::<Vec<_>>();
+        assert_eq!(popped, vec!["first", "second", "raised"]);
+    }
+}
@@ -0,0 +1,252 @@
+use vizia_style::{LayoutUnits, LengthContext, LengthPercentage};
+
+use super::{Rule, Style};
+use crate::animation::Interpolator;
+use crate::prelude::*;
+use crate::storage::animatable_set::AnimatableSet;
+use vizia_storage::SparseSet;
+
+/// The axis of a layout property, which determines whether its percentages are relative to the width or the height
+/// of the parent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LayoutAxis {
+    Horizontal,
+    Vertical,
+}
+
+/// The sizes which the lengths of the layout properties of a view are resolved against, in logical pixels.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct CalcBasis {
+    /// The size of the content of the parent, within its padding and border.
+    pub parent_width: f32,
+    pub parent_height: f32,
+    pub viewport_width: f32,
+    pub viewport_height: f32,
+}
+
+/// The length of a layout property set by a rule, or `None` if the rule sets the property to units.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct LayoutLength(pub Option<LengthPercentage>);
+
+// A transition between two lengths interpolates the pixels they resolve to, while a transition between a length and
+// units jumps to the end, as the units are interpolated separately.
+impl Interpolator for LayoutLength {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        match (&start.0, &end.0) {
+            (Some(start), Some(end)) => {
+                LayoutLength(Some(LengthPercentage::interpolate(start, end, t)))
+            }
+            _ => end.clone(),
+        }
+    }
+}
+
+/// Lengths of the layout properties which can't be represented by layout units, such as `calc(100% - 20px)`, and are
+/// resolved when a view is laid out.
+///
+/// A rule which sets a property to units stores `None`, so that linking finds the rule which determines the value of
+/// the property, with the units of a rule which stores a length set to a placeholder.
+#[derive(Default)]
+pub(crate) struct LayoutCalc {
+    pub left: AnimatableSet<LayoutLength>,
+    pub right: AnimatableSet<LayoutLength>,
+    pub top: AnimatableSet<LayoutLength>,
+    pub bottom: AnimatableSet<LayoutLength>,
+
+    pub width: AnimatableSet<LayoutLength>,
+    pub height: AnimatableSet<LayoutLength>,
+    pub min_width: AnimatableSet<LayoutLength>,
+    pub max_width: AnimatableSet<LayoutLength>,
+    pub min_height: AnimatableSet<LayoutLength>,
+    pub max_height: AnimatableSet<LayoutLength>,
+
+    pub padding_left: AnimatableSet<LayoutLength>,
+    pub padding_right: AnimatableSet<LayoutLength>,
+    pub padding_top: AnimatableSet<LayoutLength>,
+    pub padding_bottom: AnimatableSet<LayoutLength>,
+
+    /// The sizes each view with a length is resolved against, which are updated by the layout system.
+    pub bases: SparseSet<CalcBasis>,
+
+    /// The heights in physical pixels of the rows with an auto height whose children, once aligned by their
+    /// baselines, don't fit within the height of their tallest child, which are updated by the layout system.
+    pub baseline_heights: SparseSet<f32>,
+}
+
+impl LayoutCalc {
+    fn sets(&self) -> [&AnimatableSet<LayoutLength>; 14] {
+        [
+            &self.left,
+            &self.right,
+            &self.top,
+            &self.bottom,
+            &self.width,
+            &self.height,
+            &self.min_width,
+            &self.max_width,
+            &self.min_height,
+            &self.max_height,
+            &self.padding_left,
+            &self.padding_right,
+            &self.padding_top,
+            &self.padding_bottom,
+        ]
+    }
+
+    fn sets_mut(&mut self) -> [&mut AnimatableSet<LayoutLength>; 14] {
+        [
+            &mut self.left,
+            &mut self.right,
+            &mut self.top,
+            &mut self.bottom,
+            &mut self.width,
+            &mut self.height,
+            &mut self.min_width,
+            &mut self.max_width,
+            &mut self.min_height,
+            &mut self.max_height,
+            &mut self.padding_left,
+            &mut self.padding_right,
+            &mut self.padding_top,
+            &mut self.padding_bottom,
+        ]
+    }
+
+    /// Returns true if any layout property of the entity is a length which is resolved during layout.
+    pub fn has_lengths(&self, entity: Entity) -> bool {
+        self.sets().iter().any(|set| matches!(set.get(entity), Some(LayoutLength(Some(_)))))
+    }
+
+    pub fn link(&mut self, entity: Entity, matched_rules: &[Rule]) -> bool {
+        let mut changed = false;
+        for set in self.sets_mut() {
+            changed |= set.link(entity, matched_rules);
+        }
+
+        changed
+    }
+
+    pub fn remove(&mut self, entity: Entity) {
+        for set in self.sets_mut() {
+            set.remove(entity);
+        }
+
+        self.bases.remove(entity);
+        self.baseline_heights.remove(entity);
+    }
+
+    pub fn clear_rules(&mut self) {
+        for set in self.sets_mut() {
+            set.clear_rules();
+        }
+    }
+
+    /// Advances the transitions between lengths, returning the entities which need to be laid out again.
+    pub fn tick(&mut self, time: Instant, color_space: ColorSpace) -> Vec<Entity> {
+        let mut entities = Vec::new();
+        for set in self.sets_mut() {
+            entities.extend(set.tick(time, color_space));
+        }
+
+        entities
+    }
+}
+
+/// Inserts the value of a layout property for a rule.
+pub(crate) fn insert_layout_rule(
+    units: &mut AnimatableSet<Units>,
+    lengths: &mut AnimatableSet<LayoutLength>,
+    rule: Rule,
+    value: LayoutUnits,
+) {
+    match value {
+        LayoutUnits::Units(value) => {
+            units.insert_rule(rule, value);
+            lengths.insert_rule(rule, LayoutLength(None));
+        }
+
+        LayoutUnits::Calc(length) => {
+            units.insert_rule(rule, Units::Auto);
+            lengths.insert_rule(rule, LayoutLength(Some(length)));
+        }
+    }
+}
+
+impl Style {
+    /// Returns the context which the lengths of the layout properties of an entity along an axis are resolved
+    /// against.
+    pub(crate) fn length_context(&self, entity: Entity, axis: LayoutAxis) -> LengthContext {
+        let basis = self.layout_calc.bases.get(entity).copied().unwrap_or_default();
+        LengthContext {
+            percentage_basis: match axis {
+                LayoutAxis::Horizontal => basis.parent_width,
+                LayoutAxis::Vertical => basis.parent_height,
+            },
+            font_size: self.font_size.get(entity).map_or(16.0, |font_size| font_size.0),
+            root_font_size: self
+                .font_size
+                .get(Entity::root())
+                .map_or(16.0, |font_size| font_size.0),
+            viewport_width: basis.viewport_width,
+            viewport_height: basis.viewport_height,
+        }
+    }
+
+    /// Returns the value of a layout property of an entity in physical pixels, with a length resolved against the
+    /// size of the parent of the entity.
+    ///
+    /// Lengths which resolve to a negative size are clamped to zero unless `allow_negative` is true.
+    pub(crate) fn layout_units(
+        &self,
+        entity: Entity,
+        units: &AnimatableSet<Units>,
+        lengths: &AnimatableSet<LayoutLength>,
+        axis: LayoutAxis,
+        allow_negative: bool,
+    ) -> Option<Units> {
+        // Units set with a modifier override the lengths of rules.
+        if units.get_inline(entity).is_none() {
+            if let Some(LayoutLength(Some(length))) = lengths.get(entity) {
+                let mut pixels = length.resolve(&self.length_context(entity, axis));
+                if !allow_negative {
+                    pixels = pixels.max(0.0);
+                }
+
+                return Some(Units::Pixels(self.logical_to_physical(pixels)));
+            }
+        }
+
+        units.get(entity).copied().map(|units| match units {
+            Units::Pixels(val) => Units::Pixels(self.logical_to_physical(val)),
+            units => units,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LayoutLength;
+    use crate::prelude::*;
+    use crate::systems::style_system;
+
+    #[test]
+    fn inline_units_override_lengths() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(".calc { width: calc(100% - 20px); height: 2em; }").unwrap();
+        let element = Element::new(&mut cx).class("calc").height(Pixels(30.0)).entity();
+        style_system(&mut cx);
+
+        assert!(cx.style.layout_calc.has_lengths(element));
+        assert!(matches!(cx.style.layout_calc.width.get(element), Some(LayoutLength(Some(_)))));
+        assert_eq!(cx.style.width.get(element), Some(&Units::Auto));
+
+        let height = cx.style.layout_units(
+            element,
+            &cx.style.height,
+            &cx.style.layout_calc.height,
+            super::LayoutAxis::Vertical,
+            false,
+        );
+        assert_eq!(height, Some(Units::Pixels(cx.style.logical_to_physical(30.0))));
+    }
+}
@@ -0,0 +1,33 @@
+use vizia_style::MediaState;
+
+use super::{Rule, Style};
+use crate::prelude::*;
+
+impl Style {
+    /// Evaluates the media queries of the stylesheets for a window with the given logical size. Returns true if
+    /// whether any of the media queries match the window has changed.
+    pub(crate) fn update_media(&mut self, window: Entity, width: f32, height: f32) -> bool {
+        let state = MediaState { width, height, color_scheme: self.color_scheme };
+        let matches =
+            self.media_queries.iter().map(|query| query.matches(&state)).collect::<Vec<_>>();
+
+        if self.media_matches.get(&window) == Some(&matches) {
+            return false;
+        }
+
+        self.media_matches.insert(window, matches);
+        true
+    }
+
+    /// Returns true if the media queries of all of the `@media` rules a rule is inside match the given window.
+    pub(crate) fn matches_media(&self, window: Entity, rule: Rule) -> bool {
+        let Some(media) = self.rule_media.get(&rule) else {
+            return true;
+        };
+
+        let matches = self.media_matches.get(&window);
+        media.iter().all(|index| {
+            matches.and_then(|matches| matches.get(*index)).copied().unwrap_or_default()
+        })
+    }
+}
@@ -63,25 +63,33 @@
 use hashbrown::{HashMap, HashSet};
 use indexmap::IndexMap;
 use log::warn;
+use std::borrow::Cow;
 use std::fmt::Debug;
 use std::ops::{Deref, DerefMut, Range};
+use std::sync::{Arc, RwLock};
 
 use crate::prelude::*;
 
 pub use vizia_style::{
-    Alignment, Angle, BackgroundImage, BackgroundSize, BorderStyleKeyword, ClipPath, Color,
-    CornerShape, CssRule, CursorIcon, Display, Filter, FontFamily, FontSize, FontSlant,
-    FontVariation, FontWeight, FontWeightKeyword, FontWidth, GenericFontFamily, Gradient,
-    HorizontalPosition, HorizontalPositionKeyword, Length, LengthOrPercentage, LengthValue,
-    LineClamp, LineDirection, LinearGradient, Matrix, Opacity, Overflow, PointerEvents, Position,
-    PositionType, Scale, Shadow, TextAlign, TextDecorationLine, TextDecorationStyle, TextOverflow,
-    TextStroke, TextStrokeStyle, Transform, Transition, Translate, VerticalPosition,
-    VerticalPositionKeyword, Visibility, RGBA,
+    Alignment, Angle, AngleOrPercentage, AnimationDirection, AnimationFillMode,
+    AnimationIterationCount, AspectRatio, BackgroundImage, BackgroundRepeat,
+    BackgroundRepeatKeyword, BackgroundSize, BlendMode, BorderStyleKeyword, ClipPath, Color,
+    ColorOrGradient, ColorSpace, ConicGradient, CornerRadiusValue, CornerShape, CssRule,
+    CursorIcon, Display, EasingFunction, Elevation, Filter, FontFamily, FontFeature,
+    FontFeatureSettings, FontSize, FontSlant, FontVariation, FontWeight, FontWeightKeyword,
+    FontWidth, GenericFontFamily, Gradient, HorizontalPosition, HorizontalPositionKeyword, Length,
+    LengthOrPercentage, LengthValue, LetterSpacing, LineClamp, LineDirection, LineHeight,
+    LinearGradient, Matrix, Opacity, Overflow, PointerEvents, Position, PositionType, PressEffect,
+    Scale, Shadow, StrokeLinecap, StrokeLinejoin, TextAlign, TextDecoration, TextDecorationLine,
+    TextDecorationStyle, TextOverflow, TextRendering, TextStroke, TextStrokeStyle, TextTransform,
+    Transform, Transition, Translate, VerticalAlign, VerticalPosition, VerticalPositionKeyword,
+    Visibility, WhiteSpace, WordSpacing, RGBA,
 };
 
 use vizia_style::{
-    BlendMode, EasingFunction, KeyframeSelector, ParserOptions, Property, SelectorList, Selectors,
-    StyleSheet,
+    selectors::parser::{Combinator, Component},
+    ColorScheme, CssWideKeyword, KeyframeAnimation, KeyframeSelector, LayoutUnits, MediaQueryList,
+    ParserOptions, Property, SelectorList, Selectors, StyleSheet, ToCss,
 };
 
 mod rule;
@@ -93,8 +101,49 @@ pub(crate) use pseudoclass::*;
 mod transform;
 pub(crate) use transform::*;
 
-use crate::animation::{AnimationState, Interpolator, Keyframe, TimingFunction};
-use crate::storage::animatable_set::AnimatableSet;
+mod ripple;
+pub(crate) use ripple::Ripples;
+
+mod grid;
+pub(crate) use grid::GridCell;
+
+mod scroll;
+pub(crate) use scroll::*;
+
+mod corners;
+pub(crate) use corners::{resolve_corner_radius, CornerRadii};
+
+mod layer;
+pub use layer::Layer;
+pub(crate) use layer::{StackOrder, StackQueue};
+
+mod elevation;
+pub(crate) use elevation::elevation_shadows;
+
+mod custom_property;
+
+mod css_wide_keyword;
+use css_wide_keyword::longhand_names;
+
+mod state_style;
+pub use state_style::StateStyleBuilder;
+
+mod keyframe_animation;
+
+mod rule_index;
+use rule_index::RuleIndex;
+
+mod media;
+
+mod layout_calc;
+use layout_calc::{insert_layout_rule, LayoutCalc};
+pub(crate) use layout_calc::{CalcBasis, LayoutAxis};
+
+use crate::animation::{
+    AnimationPlayback, AnimationState, Interpolator, Keyframe, PlaybackControl, Spring,
+    TimingFunction,
+};
+use crate::storage::animatable_set::{AnimatableSet, TransitionTarget};
 use crate::storage::style_set::StyleSet;
 use bitflags::bitflags;
 use vizia_id::IdManager;
@@ -127,9 +176,14 @@ bitflags! {
     pub struct SystemFlags: u8 {
         /// Layout system flag.
         const RELAYOUT = 1;
+        /// Rematches style rules. Only needed when a change can affect which selectors match,
+        /// such as a change to the classes, id, pseudo-classes, or position in the tree of a view.
         const RESTYLE = 1 << 1;
+        /// Rebuilds and lays out the text of a view.
         const REFLOW = 1 << 2;
         const REDRAW = 1 << 3;
+        /// Rebuilds the text of a view for a property which only affects how the text is painted.
+        const REPAINT = 1 << 4;
     }
 }
 
@@ -145,6 +199,23 @@ pub enum ImageOrGradient {
     Gradient(Gradient),
 }
 
+impl ImageOrGradient {
+    /// Returns the image or gradient of a background image, or `None` if there isn't one.
+    pub(crate) fn from_background_image(image: BackgroundImage) -> Option<Self> {
+        match image {
+            BackgroundImage::None => None,
+            BackgroundImage::Gradient(gradient) => Some(ImageOrGradient::Gradient(*gradient)),
+            BackgroundImage::Url(url) => Some(ImageOrGradient::Image(url.url.to_string())),
+        }
+    }
+}
+
+// Returns the offset of a background image within the background, which is stored in the same way as a transform
+// origin.
+fn background_position(position: &Position) -> Translate {
+    Translate { x: position.x.to_length_or_percentage(), y: position.y.to_length_or_percentage() }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FamilyOwned {
     Generic(GenericFontFamily),
@@ -188,6 +259,119 @@ impl DerefMut for Bloom {
     }
 }
 
+// Returns true if a selector list depends on the position of a view among its siblings, such as `:nth-child(odd)`,
+// `:last-of-type` or `label + label`.
+fn is_structural(selectors: &SelectorList<Selectors>) -> bool {
+    selectors.slice().iter().any(|selector| {
+        selector.iter_raw_match_order().any(|component| {
+            matches!(
+                component,
+                Component::Nth(_)
+                    | Component::NthOf(_)
+                    | Component::Combinator(Combinator::NextSibling | Combinator::LaterSibling)
+            )
+        })
+    })
+}
+
+// Returns true if a selector list depends on the position of an ancestor of a view among its siblings, such as
+// `.row:nth-child(odd) label`.
+fn has_structural_ancestor(selectors: &SelectorList<Selectors>) -> bool {
+    selectors.slice().iter().any(|selector| {
+        let mut ancestor = false;
+        selector.iter_raw_match_order().any(|component| match component {
+            Component::Combinator(Combinator::Child | Combinator::Descendant) => {
+                ancestor = true;
+                false
+            }
+            Component::Nth(_)
+            | Component::NthOf(_)
+            | Component::Combinator(Combinator::NextSibling | Combinator::LaterSibling) => ancestor,
+            _ => false,
+        })
+    })
+}
+
+// Returns true if a selector list matches a view depending on the state of the siblings before it, such as
+// `checkbox:checked + label` or `:nth-child(odd of .selected)`.
+fn has_sibling_dependency(selectors: &SelectorList<Selectors>) -> bool {
+    selectors.slice().iter().any(|selector| {
+        selector.iter_raw_match_order().any(|component| {
+            matches!(
+                component,
+                Component::NthOf(_)
+                    | Component::Combinator(Combinator::NextSibling | Combinator::LaterSibling)
+            )
+        })
+    })
+}
+
+/// The longhand properties which `transition: all` transitions, which are those that can be transitioned except for
+/// `display`, as it changes discretely.
+const TRANSITION_PROPERTIES: &[&str] = &[
+    "opacity",
+    "clip-path",
+    "filter",
+    "backdrop-filter",
+    "transform",
+    "transform-origin",
+    "translate",
+    "rotate",
+    "scale",
+    "border-top-width",
+    "border-right-width",
+    "border-bottom-width",
+    "border-left-width",
+    "border-top-color",
+    "border-right-color",
+    "border-bottom-color",
+    "border-left-color",
+    "corner-top-left-radius",
+    "corner-top-right-radius",
+    "corner-bottom-left-radius",
+    "corner-bottom-right-radius",
+    "outline-width",
+    "outline-color",
+    "outline-offset",
+    "background-color",
+    "background-image",
+    "background-position",
+    "background-size",
+    "shadow",
+    "elevation",
+    "color",
+    "text-decoration-color",
+    "font-size",
+    "font-variation-settings",
+    "letter-spacing",
+    "word-spacing",
+    "caret-color",
+    "selection-color",
+    "left",
+    "right",
+    "top",
+    "bottom",
+    "padding-left",
+    "padding-right",
+    "padding-top",
+    "padding-bottom",
+    "horizontal-gap",
+    "vertical-gap",
+    "width",
+    "height",
+    "min-width",
+    "max-width",
+    "min-height",
+    "max-height",
+    "min-horizontal-gap",
+    "max-horizontal-gap",
+    "min-vertical-gap",
+    "max-vertical-gap",
+    "underline-color",
+    "fill",
+    "stroke",
+];
+
 /// Stores the style properties of all entities in the application.
 #[derive(Default)]
 pub struct Style {
@@ -196,17 +380,68 @@ pub struct Style {
     // Creates and destroys animation ids
     pub(crate) animation_manager: IdManager<Animation>,
     pub(crate) animations: HashMap<String, Animation>,
+    // The easing functions of animations built with a timing function, which apply whenever they're played.
+    pub(crate) animation_easings: HashMap<Animation, EasingFunction>,
     // List of animations to be started on the next frame
     pub(crate) pending_animations: Vec<(Entity, Animation, Duration, Duration)>,
+    // The animations which have been started for entities by events, which are notified once they finish.
+    pub(crate) played_animations: Vec<(Entity, Animation)>,
+    // The animations set by the `animation` property, and those which have been started for each entity with the
+    // ids of their keyframes.
+    pub(crate) keyframe_animations: StyleSet<Vec<KeyframeAnimation>>,
+    pub(crate) running_animations: SparseSet<Vec<(KeyframeAnimation, Animation)>>,
 
     // List of rules
     pub(crate) rules: IndexMap<Rule, SelectorList<Selectors>>,
+    // The rules indexed by the names their selectors require, which is built when the rules are next matched.
+    pub(crate) rule_index: RuleIndex,
+
+    // Custom properties declared by each rule, and the declarations of each rule which reference custom properties.
+    pub(crate) rule_custom_properties: HashMap<Rule, Vec<(String, String)>>,
+    pub(crate) rule_variables: HashMap<Rule, Vec<(String, String)>>,
+    // The longhand properties each rule sets to a CSS-wide keyword, and the entities with a property which isn't
+    // inherited linked to the value of their parent by `inherit`.
+    pub(crate) rule_keywords: HashMap<Rule, Vec<(&'static str, CssWideKeyword)>>,
+    pub(crate) keyword_inherited: HashSet<Entity>,
+    // Rules holding the values of declarations which reference custom properties, keyed by the rule the declarations
+    // are from and their values once the custom properties are substituted.
+    pub(crate) resolved_rules: HashMap<(Rule, Vec<(String, String)>), Rule>,
+
+    // The media queries of the `@media` rules of the stylesheets, the media queries of the `@media` rules each rule is
+    // inside, and whether each media query matches each window.
+    pub(crate) media_queries: Vec<MediaQueryList>,
+    pub(crate) rule_media: HashMap<Rule, Vec<usize>>,
+    pub(crate) media_matches: HashMap<Entity, Vec<bool>>,
+    // The color scheme of the theme, which `prefers-color-scheme` media queries are compared with.
+    pub(crate) color_scheme: ColorScheme,
+    // The accent color of the operating system, which the `accent-color` keyword resolves to once it's known.
+    pub(crate) accent_color: Option<RGBA>,
+
+    // Rules holding the values of the state styles set with modifiers, shared by entities with equal state styles.
+    pub(crate) state_rules: Vec<(StateStyleBuilder, Rule)>,
+    // The state styles of each entity, in the order they were set, with the pseudo-classes each applies for.
+    pub(crate) state_styles: SparseSet<Vec<(PseudoClassFlags, Rule)>>,
+
+    // Custom properties set on views with the `custom_property` modifier.
+    pub(crate) custom_properties: SparseSet<HashMap<String, String>>,
+    // Custom properties of each view, including those inherited from its ancestors, with references substituted.
+    pub(crate) computed_custom_properties: SparseSet<HashMap<String, String>>,
 
     pub(crate) default_font: Vec<FamilyOwned>,
 
+    // Preferred font families for each language, and the families used as fallbacks for all text, in order of
+    // preference for the current locale.
+    pub(crate) locale_fonts: LocaleFonts,
+    pub(crate) fallback_fonts: Vec<FamilyOwned>,
+
+    // Color space used to interpolate animated colors when not specified by a transition.
+    pub(crate) color_space: ColorSpace,
+
     // CSS Selector Properties
     pub(crate) ids: SparseSet<String>,
     pub(crate) classes: SparseSet<HashSet<String>>,
+    // The names of the views which are parts of their parent, matched by pseudo-element selectors like `::highlight`.
+    pub(crate) parts: SparseSet<String>,
     pub(crate) pseudo_classes: SparseSet<PseudoClassFlags>,
     pub(crate) disabled: StyleSet<bool>,
     pub(crate) abilities: SparseSet<Abilities>,
@@ -218,6 +453,7 @@ pub struct Style {
     pub live: SparseSet<Live>,
     pub labelled_by: SparseSet<Entity>,
     pub hidden: SparseSet<bool>,
+    pub expanded: SparseSet<bool>,
     pub text_value: SparseSet<String>,
     pub numeric_value: SparseSet<f64>,
 
@@ -229,6 +465,7 @@ pub struct Style {
 
     // Z Order
     pub(crate) z_index: StyleSet<i32>,
+    pub(crate) layer: SparseSet<Layer>,
 
     // Clipping
     pub(crate) clip_path: AnimatableSet<ClipPath>,
@@ -238,9 +475,10 @@ pub struct Style {
     pub(crate) overflowy: StyleSet<Overflow>,
 
     // Filters
-    pub(crate) backdrop_filter: AnimatableSet<Filter>,
+    pub(crate) filter: AnimatableSet<Vec<Filter>>,
+    pub(crate) backdrop_filter: AnimatableSet<Vec<Filter>>,
 
-    pub(crate) blend_mode: StyleSet<BlendMode>,
+    pub(crate) mix_blend_mode: StyleSet<BlendMode>,
 
     // Transform
     pub(crate) transform: AnimatableSet<Vec<Transform>>,
@@ -250,9 +488,18 @@ pub struct Style {
     pub(crate) scale: AnimatableSet<Scale>,
 
     // Border
-    pub(crate) border_width: AnimatableSet<LengthOrPercentage>,
-    pub(crate) border_color: AnimatableSet<Color>,
-    pub(crate) border_style: StyleSet<BorderStyleKeyword>,
+    pub(crate) border_top_width: AnimatableSet<LengthOrPercentage>,
+    pub(crate) border_right_width: AnimatableSet<LengthOrPercentage>,
+    pub(crate) border_bottom_width: AnimatableSet<LengthOrPercentage>,
+    pub(crate) border_left_width: AnimatableSet<LengthOrPercentage>,
+    pub(crate) border_top_color: AnimatableSet<ColorOrGradient>,
+    pub(crate) border_right_color: AnimatableSet<ColorOrGradient>,
+    pub(crate) border_bottom_color: AnimatableSet<ColorOrGradient>,
+    pub(crate) border_left_color: AnimatableSet<ColorOrGradient>,
+    pub(crate) border_top_style: StyleSet<BorderStyleKeyword>,
+    pub(crate) border_right_style: StyleSet<BorderStyleKeyword>,
+    pub(crate) border_bottom_style: StyleSet<BorderStyleKeyword>,
+    pub(crate) border_left_style: StyleSet<BorderStyleKeyword>,
 
     // Corner Shape
     pub(crate) corner_top_left_shape: StyleSet<CornerShape>,
@@ -261,10 +508,10 @@ pub struct Style {
     pub(crate) corner_bottom_right_shape: StyleSet<CornerShape>,
 
     // Corner Radius
-    pub(crate) corner_top_left_radius: AnimatableSet<LengthOrPercentage>,
-    pub(crate) corner_top_right_radius: AnimatableSet<LengthOrPercentage>,
-    pub(crate) corner_bottom_left_radius: AnimatableSet<LengthOrPercentage>,
-    pub(crate) corner_bottom_right_radius: AnimatableSet<LengthOrPercentage>,
+    pub(crate) corner_top_left_radius: AnimatableSet<CornerRadiusValue>,
+    pub(crate) corner_top_right_radius: AnimatableSet<CornerRadiusValue>,
+    pub(crate) corner_bottom_left_radius: AnimatableSet<CornerRadiusValue>,
+    pub(crate) corner_bottom_right_radius: AnimatableSet<CornerRadiusValue>,
 
     // Corner Smoothing
     pub(crate) corner_top_left_smoothing: AnimatableSet<f32>,
@@ -274,24 +521,49 @@ pub struct Style {
 
     // Outline
     pub(crate) outline_width: AnimatableSet<LengthOrPercentage>,
-    pub(crate) outline_color: AnimatableSet<Color>,
+    pub(crate) outline_color: AnimatableSet<ColorOrGradient>,
     pub(crate) outline_offset: AnimatableSet<LengthOrPercentage>,
+    pub(crate) outline_style: StyleSet<BorderStyleKeyword>,
+
+    // Focus Ring
+    pub(crate) focus_ring: StyleSet<bool>,
+    pub(crate) focus_ring_color: StyleSet<Color>,
+    pub(crate) focus_ring_width: StyleSet<LengthOrPercentage>,
+    pub(crate) focus_ring_offset: StyleSet<LengthOrPercentage>,
+
+    // Press Effect
+    pub(crate) press_effect: StyleSet<PressEffect>,
+    pub(crate) ripple_color: StyleSet<Color>,
+    pub(crate) ripple_duration: StyleSet<Duration>,
+    // The ripples of views with a ripple press effect which are pressed or fading out.
+    pub(crate) ripples: Ripples,
 
     // Background
     pub(crate) background_color: AnimatableSet<Color>,
     pub(crate) background_image: AnimatableSet<Vec<ImageOrGradient>>,
+    pub(crate) background_position: AnimatableSet<Vec<Translate>>,
     pub(crate) background_size: AnimatableSet<Vec<BackgroundSize>>,
+    pub(crate) background_repeat: StyleSet<Vec<BackgroundRepeat>>,
 
     // Shadow
     pub(crate) shadow: AnimatableSet<Vec<Shadow>>,
+    pub(crate) elevation: AnimatableSet<Elevation>,
 
     // Text
     pub text: SparseSet<String>,
-    pub(crate) text_wrap: StyleSet<bool>,
+    pub(crate) nav_text: SparseSet<String>,
+    pub(crate) white_space: StyleSet<WhiteSpace>,
     pub(crate) text_overflow: StyleSet<TextOverflow>,
     pub(crate) line_clamp: StyleSet<LineClamp>,
+    pub(crate) line_height: StyleSet<LineHeight>,
+    pub(crate) text_transform: StyleSet<TextTransform>,
+    pub(crate) text_rendering: StyleSet<TextRendering>,
     pub(crate) text_align: StyleSet<TextAlign>,
+    pub(crate) vertical_align: StyleSet<VerticalAlign>,
     pub(crate) text_decoration_line: StyleSet<TextDecorationLine>,
+    pub(crate) text_decoration_style: StyleSet<TextDecorationStyle>,
+    pub(crate) text_decoration_color: AnimatableSet<Color>,
+    pub(crate) text_decoration_thickness: StyleSet<LengthOrPercentage>,
     pub(crate) text_stroke_width: StyleSet<Length>,
     pub(crate) text_stroke_style: StyleSet<TextStrokeStyle>,
     pub(crate) underline_style: StyleSet<TextDecorationLine>,
@@ -301,16 +573,23 @@ pub struct Style {
     pub(crate) overline_color: AnimatableSet<Color>,
     pub(crate) strikethrough_color: AnimatableSet<Color>,
     pub(crate) font_family: StyleSet<Vec<FamilyOwned>>,
-    pub(crate) font_color: AnimatableSet<Color>,
+    pub(crate) font_color: AnimatableSet<ColorOrGradient>,
     pub(crate) font_size: AnimatableSet<FontSize>,
+    pub(crate) letter_spacing: AnimatableSet<LetterSpacing>,
+    pub(crate) word_spacing: AnimatableSet<WordSpacing>,
     pub(crate) font_weight: StyleSet<FontWeight>,
     pub(crate) font_slant: StyleSet<FontSlant>,
     pub(crate) font_width: StyleSet<FontWidth>,
-    pub(crate) font_variation_settings: StyleSet<Vec<FontVariation>>,
+    pub(crate) font_variation_settings: AnimatableSet<Vec<FontVariation>>,
+    pub(crate) font_feature_settings: StyleSet<FontFeatureSettings>,
     pub(crate) caret_color: AnimatableSet<Color>,
     pub(crate) selection_color: AnimatableSet<Color>,
 
     pub(crate) fill: AnimatableSet<Color>,
+    pub(crate) stroke: AnimatableSet<Color>,
+    pub(crate) stroke_width: StyleSet<Length>,
+    pub(crate) stroke_linecap: StyleSet<StrokeLinecap>,
+    pub(crate) stroke_linejoin: StyleSet<StrokeLinejoin>,
 
     // cursor Icon
     pub(crate) cursor: StyleSet<CursorIcon>,
@@ -325,10 +604,25 @@ pub struct Style {
     // Layout Type
     pub(crate) layout_type: StyleSet<LayoutType>,
 
+    // Grid
+    pub(crate) grid_columns: StyleSet<Vec<Units>>,
+    pub(crate) grid_rows: StyleSet<Vec<Units>>,
+    pub(crate) column_start: StyleSet<usize>,
+    pub(crate) column_span: StyleSet<usize>,
+    pub(crate) row_start: StyleSet<usize>,
+    pub(crate) row_span: StyleSet<usize>,
+    // The cells the children of grids are placed in during layout.
+    pub(crate) grid_cells: SparseSet<GridCell>,
+
+    // The scroll positions of the views which scroll their overflow.
+    pub(crate) scroll_states: SparseSet<ScrollState>,
+
     // Position
     pub(crate) position_type: StyleSet<PositionType>,
 
     pub(crate) alignment: StyleSet<Alignment>,
+    pub(crate) align_baseline: StyleSet<bool>,
+    pub(crate) baseline_offset: StyleSet<Units>,
 
     // Spacing
     pub(crate) left: AnimatableSet<Units>,
@@ -351,6 +645,7 @@ pub struct Style {
     // Size
     pub(crate) width: AnimatableSet<Units>,
     pub(crate) height: AnimatableSet<Units>,
+    pub(crate) aspect_ratio: StyleSet<AspectRatio>,
 
     // Size Constraints
     pub(crate) min_width: AnimatableSet<Units>,
@@ -358,6 +653,9 @@ pub struct Style {
     pub(crate) min_height: AnimatableSet<Units>,
     pub(crate) max_height: AnimatableSet<Units>,
 
+    // Lengths of the space, size, size constraint and padding properties which are resolved during layout.
+    pub(crate) layout_calc: LayoutCalc,
+
     // Gap Constraints
     pub(crate) min_horizontal_gap: AnimatableSet<Units>,
     pub(crate) max_horizontal_gap: AnimatableSet<Units>,
@@ -366,11 +664,28 @@ pub struct Style {
 
     pub(crate) system_flags: SystemFlags,
 
+    // Entities whose size or position may have changed since the last layout.
+    pub(crate) relayout: HashSet<Entity>,
+
     pub(crate) restyle: Bloom,
+    // Views whose children have been added, removed or reordered, and whose children are restyled as a result.
+    pub(crate) restyle_children: HashSet<Entity>,
+    // The rules with a selector which depends on the position of a view among its siblings.
+    pub(crate) structural_rules: HashSet<Rule>,
+    // Whether any of those selectors depends on the position of an ancestor of a view, so changing the children of a
+    // view also restyles their descendants.
+    pub(crate) has_structural_ancestors: bool,
+    // Whether any rule has a selector which depends on the state of the siblings before a view, so restyling a view
+    // also restyles the siblings after it.
+    pub(crate) has_sibling_selectors: bool,
     pub(crate) text_construction: Bloom,
+    pub(crate) text_repaint: Bloom,
     pub(crate) text_layout: Bloom,
     pub(crate) reaccess: Bloom,
 
+    // The number of entities whose style rules were matched during the last style pass.
+    pub(crate) entities_matched: usize,
+
     pub(crate) text_range: SparseSet<Range<usize>>,
     pub(crate) text_span: SparseSet<bool>,
 
@@ -383,6 +698,14 @@ impl Style {
         self.dpi_factor as f32
     }
 
+    /// Returns the color with the `accent-color` keyword resolved to the accent color of the operating system.
+    pub(crate) fn resolve_accent_color(&self, color: Color) -> Color {
+        match (color, self.accent_color) {
+            (Color::AccentColor, Some(accent_color)) => Color::RGBA(accent_color),
+            (color, _) => color,
+        }
+    }
+
     /// Function to convert logical points to physical pixels.
     pub fn logical_to_physical(&self, logical: f32) -> f32 {
         (logical * self.dpi_factor as f32).round()
@@ -393,9 +716,45 @@ impl Style {
         physical / self.dpi_factor as f32
     }
 
+    /// Returns the shadows of the view, which are derived from its elevation unless its shadows are set explicitly.
+    pub(crate) fn shadows(&self, entity: Entity) -> Option<Cow<'_, [Shadow]>> {
+        if let Some(shadows) = self.shadow.get(entity) {
+            return Some(Cow::Borrowed(shadows));
+        }
+
+        self.elevation.get(entity).map(|elevation| Cow::Owned(elevation_shadows(*elevation)))
+    }
+
+    /// Returns the order the view is drawn and hit-tested in, which is in the layer of its parent unless it has a
+    /// layer of its own.
+    pub(crate) fn stack_order(&self, entity: Entity, parent_layer: Layer) -> StackOrder {
+        StackOrder {
+            layer: self.layer.get(entity).copied().unwrap_or(parent_layer),
+            z_index: self.z_index.get(entity).copied().unwrap_or_default(),
+        }
+    }
+
+    /// Returns true if the view is drawn outside of the clip of its ancestors because of its layer or z-index.
+    pub(crate) fn is_layered(&self, entity: Entity) -> bool {
+        self.layer.get(entity).is_some_and(|layer| *layer != Layer::Base)
+            || self.z_index.get(entity).copied().unwrap_or_default() != 0
+    }
+
     pub(crate) fn remove_rules(&mut self) {
         self.rule_manager.reset();
         self.rules.clear();
+        self.rule_index.invalidate();
+        self.rule_custom_properties.clear();
+        self.rule_variables.clear();
+        self.rule_keywords.clear();
+        self.resolved_rules.clear();
+        self.media_queries.clear();
+        self.rule_media.clear();
+        self.media_matches.clear();
+        self.structural_rules.clear();
+        self.has_structural_ancestors = false;
+        self.has_sibling_selectors = false;
+        self.animations.clear();
     }
 
     pub(crate) fn get_animation(&self, name: &str) -> Option<&Animation> {
@@ -416,8 +775,10 @@ impl Style {
         ) {
             let keyframe = Keyframe { time, value, timing_function: TimingFunction::linear() };
 
+            // Keyframes are kept in order of time, whatever order the keyframe selectors are in.
             if let Some(anim_state) = storage.get_animation_mut(animation_id) {
-                anim_state.keyframes.push(keyframe)
+                let index = anim_state.keyframes.partition_point(|keyframe| keyframe.time <= time);
+                anim_state.keyframes.insert(index, keyframe)
             } else {
                 let anim_state = AnimationState::new(animation_id).with_keyframe(keyframe);
                 storage.insert_animation(animation_id, anim_state);
@@ -439,6 +800,15 @@ impl Style {
                     insert_keyframe(&mut self.clip_path, animation_id, time, value.clone());
                 }
 
+                // FILTER
+                Property::Filter(value) => {
+                    insert_keyframe(&mut self.filter, animation_id, time, value.clone());
+                }
+
+                Property::BackdropFilter(value) => {
+                    insert_keyframe(&mut self.backdrop_filter, animation_id, time, value.clone());
+                }
+
                 // TRANSFORM
                 Property::Transform(value) => {
                     insert_keyframe(&mut self.transform, animation_id, time, value.clone());
@@ -466,15 +836,118 @@ impl Style {
                 // BORDER
                 Property::BorderWidth(value) => {
                     insert_keyframe(
-                        &mut self.border_width,
+                        &mut self.border_top_width,
+                        animation_id,
+                        time,
+                        value.top.0.clone(),
+                    );
+                    insert_keyframe(
+                        &mut self.border_right_width,
+                        animation_id,
+                        time,
+                        value.right.0.clone(),
+                    );
+                    insert_keyframe(
+                        &mut self.border_bottom_width,
+                        animation_id,
+                        time,
+                        value.bottom.0.clone(),
+                    );
+                    insert_keyframe(
+                        &mut self.border_left_width,
                         animation_id,
                         time,
                         value.left.0.clone(),
                     );
                 }
 
+                Property::BorderTopWidth(value) => {
+                    insert_keyframe(
+                        &mut self.border_top_width,
+                        animation_id,
+                        time,
+                        value.0.clone(),
+                    );
+                }
+
+                Property::BorderRightWidth(value) => {
+                    insert_keyframe(
+                        &mut self.border_right_width,
+                        animation_id,
+                        time,
+                        value.0.clone(),
+                    );
+                }
+
+                Property::BorderBottomWidth(value) => {
+                    insert_keyframe(
+                        &mut self.border_bottom_width,
+                        animation_id,
+                        time,
+                        value.0.clone(),
+                    );
+                }
+
+                Property::BorderLeftWidth(value) => {
+                    insert_keyframe(
+                        &mut self.border_left_width,
+                        animation_id,
+                        time,
+                        value.0.clone(),
+                    );
+                }
+
                 Property::BorderColor(value) => {
-                    insert_keyframe(&mut self.border_color, animation_id, time, *value);
+                    insert_keyframe(&mut self.border_top_color, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.border_right_color,
+                        animation_id,
+                        time,
+                        value.clone(),
+                    );
+                    insert_keyframe(
+                        &mut self.border_bottom_color,
+                        animation_id,
+                        time,
+                        value.clone(),
+                    );
+                    insert_keyframe(&mut self.border_left_color, animation_id, time, value.clone());
+                }
+
+                Property::BorderTopColor(value) => {
+                    insert_keyframe(
+                        &mut self.border_top_color,
+                        animation_id,
+                        time,
+                        (*value).into(),
+                    );
+                }
+
+                Property::BorderRightColor(value) => {
+                    insert_keyframe(
+                        &mut self.border_right_color,
+                        animation_id,
+                        time,
+                        (*value).into(),
+                    );
+                }
+
+                Property::BorderBottomColor(value) => {
+                    insert_keyframe(
+                        &mut self.border_bottom_color,
+                        animation_id,
+                        time,
+                        (*value).into(),
+                    );
+                }
+
+                Property::BorderLeftColor(value) => {
+                    insert_keyframe(
+                        &mut self.border_left_color,
+                        animation_id,
+                        time,
+                        (*value).into(),
+                    );
                 }
 
                 Property::CornerTopLeftRadius(value) => {
@@ -524,7 +997,7 @@ impl Style {
                 }
 
                 Property::OutlineColor(value) => {
-                    insert_keyframe(&mut self.outline_color, animation_id, time, *value);
+                    insert_keyframe(&mut self.outline_color, animation_id, time, value.clone());
                 }
 
                 Property::OutlineOffset(value) => {
@@ -539,19 +1012,17 @@ impl Style {
                 Property::BackgroundImage(images) => {
                     let images = images
                         .iter()
-                        .filter_map(|img| match img {
-                            BackgroundImage::None => None,
-                            BackgroundImage::Gradient(gradient) => {
-                                Some(ImageOrGradient::Gradient(*gradient.clone()))
-                            }
-                            BackgroundImage::Url(url) => {
-                                Some(ImageOrGradient::Image(url.url.to_string()))
-                            }
-                        })
+                        .cloned()
+                        .filter_map(ImageOrGradient::from_background_image)
                         .collect::<Vec<_>>();
                     insert_keyframe(&mut self.background_image, animation_id, time, images);
                 }
 
+                Property::BackgroundPosition(positions) => {
+                    let positions = positions.iter().map(background_position).collect::<Vec<_>>();
+                    insert_keyframe(&mut self.background_position, animation_id, time, positions);
+                }
+
                 Property::BackgroundSize(value) => {
                     insert_keyframe(&mut self.background_size, animation_id, time, value.clone());
                 }
@@ -561,15 +1032,40 @@ impl Style {
                     insert_keyframe(&mut self.shadow, animation_id, time, value.clone());
                 }
 
+                Property::Elevation(value) => {
+                    insert_keyframe(&mut self.elevation, animation_id, time, *value);
+                }
+
                 // TEXT
                 Property::FontColor(value) => {
-                    insert_keyframe(&mut self.font_color, animation_id, time, *value);
+                    insert_keyframe(&mut self.font_color, animation_id, time, value.clone());
+                }
+
+                Property::TextDecorationColor(value) => {
+                    insert_keyframe(&mut self.text_decoration_color, animation_id, time, *value);
                 }
 
                 Property::FontSize(value) => {
                     insert_keyframe(&mut self.font_size, animation_id, time, *value);
                 }
 
+                Property::FontVariationSettings(value) => {
+                    insert_keyframe(
+                        &mut self.font_variation_settings,
+                        animation_id,
+                        time,
+                        value.clone(),
+                    );
+                }
+
+                Property::LetterSpacing(value) => {
+                    insert_keyframe(&mut self.letter_spacing, animation_id, time, value.clone());
+                }
+
+                Property::WordSpacing(value) => {
+                    insert_keyframe(&mut self.word_spacing, animation_id, time, value.clone());
+                }
+
                 Property::CaretColor(value) => {
                     insert_keyframe(&mut self.caret_color, animation_id, time, *value);
                 }
@@ -579,36 +1075,36 @@ impl Style {
                 }
 
                 // SPACE
-                Property::Left(value) => {
+                Property::Left(LayoutUnits::Units(value)) => {
                     insert_keyframe(&mut self.left, animation_id, time, *value);
                 }
 
-                Property::Right(value) => {
+                Property::Right(LayoutUnits::Units(value)) => {
                     insert_keyframe(&mut self.right, animation_id, time, *value);
                 }
 
-                Property::Top(value) => {
+                Property::Top(LayoutUnits::Units(value)) => {
                     insert_keyframe(&mut self.top, animation_id, time, *value);
                 }
 
-                Property::Bottom(value) => {
+                Property::Bottom(LayoutUnits::Units(value)) => {
                     insert_keyframe(&mut self.bottom, animation_id, time, *value);
                 }
 
                 // Padding
-                Property::PaddingLeft(value) => {
+                Property::PaddingLeft(LayoutUnits::Units(value)) => {
                     insert_keyframe(&mut self.padding_left, animation_id, time, *value);
                 }
 
-                Property::PaddingRight(value) => {
+                Property::PaddingRight(LayoutUnits::Units(value)) => {
                     insert_keyframe(&mut self.padding_right, animation_id, time, *value);
                 }
 
-                Property::PaddingTop(value) => {
+                Property::PaddingTop(LayoutUnits::Units(value)) => {
                     insert_keyframe(&mut self.padding_top, animation_id, time, *value);
                 }
 
-                Property::PaddingBottom(value) => {
+                Property::PaddingBottom(LayoutUnits::Units(value)) => {
                     insert_keyframe(&mut self.padding_bottom, animation_id, time, *value);
                 }
 
@@ -620,11 +1116,19 @@ impl Style {
                     insert_keyframe(&mut self.vertical_gap, animation_id, time, *value);
                 }
 
-                Property::Gap(value) => {
-                    insert_keyframe(&mut self.horizontal_gap, animation_id, time, *value);
+                Property::RowGap(value) => {
                     insert_keyframe(&mut self.vertical_gap, animation_id, time, *value);
                 }
 
+                Property::ColumnGap(value) => {
+                    insert_keyframe(&mut self.horizontal_gap, animation_id, time, *value);
+                }
+
+                Property::Gap(value) => {
+                    insert_keyframe(&mut self.horizontal_gap, animation_id, time, value.column);
+                    insert_keyframe(&mut self.vertical_gap, animation_id, time, value.row);
+                }
+
                 // GAP CONSSTRAINTS
                 Property::MinGap(value) => {
                     insert_keyframe(&mut self.min_horizontal_gap, animation_id, time, *value);
@@ -653,28 +1157,28 @@ impl Style {
                 }
 
                 // SIZE
-                Property::Width(value) => {
+                Property::Width(LayoutUnits::Units(value)) => {
                     insert_keyframe(&mut self.width, animation_id, time, *value);
                 }
 
-                Property::Height(value) => {
+                Property::Height(LayoutUnits::Units(value)) => {
                     insert_keyframe(&mut self.height, animation_id, time, *value);
                 }
 
                 // SIZE CONSTRAINTS
-                Property::MinWidth(value) => {
+                Property::MinWidth(LayoutUnits::Units(value)) => {
                     insert_keyframe(&mut self.min_width, animation_id, time, *value);
                 }
 
-                Property::MaxWidth(value) => {
+                Property::MaxWidth(LayoutUnits::Units(value)) => {
                     insert_keyframe(&mut self.max_width, animation_id, time, *value);
                 }
 
-                Property::MinHeight(value) => {
+                Property::MinHeight(LayoutUnits::Units(value)) => {
                     insert_keyframe(&mut self.min_height, animation_id, time, *value);
                 }
 
-                Property::MaxHeight(value) => {
+                Property::MaxHeight(LayoutUnits::Units(value)) => {
                     insert_keyframe(&mut self.max_height, animation_id, time, *value);
                 }
 
@@ -686,6 +1190,10 @@ impl Style {
                     insert_keyframe(&mut self.fill, animation_id, time, *value);
                 }
 
+                Property::Stroke(value) => {
+                    insert_keyframe(&mut self.stroke, animation_id, time, *value);
+                }
+
                 _ => {}
             }
         }
@@ -697,6 +1205,10 @@ impl Style {
             self.add_keyframe(animation_id, keyframe.time, &keyframe.properties);
         }
 
+        if let Some(easing) = animation.timing_function {
+            self.animation_easings.insert(animation_id, easing);
+        }
+
         animation_id
     }
 
@@ -716,94 +1228,329 @@ impl Style {
         let pending_animations = self.pending_animations.drain(..).collect::<Vec<_>>();
 
         for (entity, animation, duration, delay) in pending_animations {
-            self.play_animation(entity, animation, start_time + delay, duration, delay)
+            let mut playback = AnimationPlayback::new(duration, delay);
+            if let Some(easing) = self.animation_easings.get(&animation) {
+                playback.timing_function = Some(TimingFunction::from(*easing));
+                playback.spring = Spring::from_easing(*easing);
+            }
+
+            self.play_animation(entity, animation, start_time + delay, &playback);
+            if !self.played_animations.contains(&(entity, animation)) {
+                self.played_animations.push((entity, animation));
+            }
         }
     }
 
+    /// Returns the animations started by events which have finished playing since the last time this was called.
+    pub(crate) fn take_finished_animations(&mut self) -> Vec<(Entity, Animation)> {
+        let (finished, playing) = std::mem::take(&mut self.played_animations)
+            .into_iter()
+            .partition(|(entity, animation)| !self.is_animating(*entity, *animation));
+        self.played_animations = playing;
+
+        finished
+    }
+
     pub(crate) fn play_animation(
         &mut self,
         entity: Entity,
         animation: Animation,
         start_time: Instant,
-        duration: Duration,
-        delay: Duration,
+        playback: &AnimationPlayback,
     ) {
-        self.display.play_animation(entity, animation, start_time, duration, delay);
-        self.opacity.play_animation(entity, animation, start_time, duration, delay);
-        self.clip_path.play_animation(entity, animation, start_time, duration, delay);
-
-        self.transform.play_animation(entity, animation, start_time, duration, delay);
-        self.transform_origin.play_animation(entity, animation, start_time, duration, delay);
-        self.translate.play_animation(entity, animation, start_time, duration, delay);
-        self.rotate.play_animation(entity, animation, start_time, duration, delay);
-        self.scale.play_animation(entity, animation, start_time, duration, delay);
-
-        self.border_width.play_animation(entity, animation, start_time, duration, delay);
-        self.border_color.play_animation(entity, animation, start_time, duration, delay);
-
-        self.corner_top_left_radius.play_animation(entity, animation, start_time, duration, delay);
-        self.corner_top_right_radius.play_animation(entity, animation, start_time, duration, delay);
-        self.corner_bottom_left_radius
-            .play_animation(entity, animation, start_time, duration, delay);
-        self.corner_bottom_right_radius
-            .play_animation(entity, animation, start_time, duration, delay);
-
-        self.outline_width.play_animation(entity, animation, start_time, duration, delay);
-        self.outline_color.play_animation(entity, animation, start_time, duration, delay);
-        self.outline_offset.play_animation(entity, animation, start_time, duration, delay);
-
-        self.background_color.play_animation(entity, animation, start_time, duration, delay);
-        self.background_image.play_animation(entity, animation, start_time, duration, delay);
-        self.background_size.play_animation(entity, animation, start_time, duration, delay);
-
-        self.shadow.play_animation(entity, animation, start_time, duration, delay);
-
-        self.font_color.play_animation(entity, animation, start_time, duration, delay);
-        self.font_size.play_animation(entity, animation, start_time, duration, delay);
-        self.caret_color.play_animation(entity, animation, start_time, duration, delay);
-        self.selection_color.play_animation(entity, animation, start_time, duration, delay);
-
-        self.left.play_animation(entity, animation, start_time, duration, delay);
-        self.right.play_animation(entity, animation, start_time, duration, delay);
-        self.top.play_animation(entity, animation, start_time, duration, delay);
-        self.bottom.play_animation(entity, animation, start_time, duration, delay);
-
-        self.padding_left.play_animation(entity, animation, start_time, duration, delay);
-        self.padding_right.play_animation(entity, animation, start_time, duration, delay);
-        self.padding_top.play_animation(entity, animation, start_time, duration, delay);
-        self.padding_bottom.play_animation(entity, animation, start_time, duration, delay);
-        self.horizontal_gap.play_animation(entity, animation, start_time, duration, delay);
-        self.vertical_gap.play_animation(entity, animation, start_time, duration, delay);
-
-        self.width.play_animation(entity, animation, start_time, duration, delay);
-        self.height.play_animation(entity, animation, start_time, duration, delay);
-
-        self.min_width.play_animation(entity, animation, start_time, duration, delay);
-        self.max_width.play_animation(entity, animation, start_time, duration, delay);
-        self.min_height.play_animation(entity, animation, start_time, duration, delay);
-        self.max_height.play_animation(entity, animation, start_time, duration, delay);
-
-        self.min_horizontal_gap.play_animation(entity, animation, start_time, duration, delay);
-        self.max_horizontal_gap.play_animation(entity, animation, start_time, duration, delay);
-        self.min_vertical_gap.play_animation(entity, animation, start_time, duration, delay);
-        self.max_vertical_gap.play_animation(entity, animation, start_time, duration, delay);
-
-        self.underline_color.play_animation(entity, animation, start_time, duration, delay);
-
-        self.fill.play_animation(entity, animation, start_time, duration, delay);
+        self.display.play_animation(entity, animation, start_time, playback);
+        self.opacity.play_animation(entity, animation, start_time, playback);
+        self.clip_path.play_animation(entity, animation, start_time, playback);
+        self.filter.play_animation(entity, animation, start_time, playback);
+        self.backdrop_filter.play_animation(entity, animation, start_time, playback);
+
+        self.transform.play_animation(entity, animation, start_time, playback);
+        self.transform_origin.play_animation(entity, animation, start_time, playback);
+        self.translate.play_animation(entity, animation, start_time, playback);
+        self.rotate.play_animation(entity, animation, start_time, playback);
+        self.scale.play_animation(entity, animation, start_time, playback);
+
+        self.border_top_width.play_animation(entity, animation, start_time, playback);
+        self.border_right_width.play_animation(entity, animation, start_time, playback);
+        self.border_bottom_width.play_animation(entity, animation, start_time, playback);
+        self.border_left_width.play_animation(entity, animation, start_time, playback);
+        self.border_top_color.play_animation(entity, animation, start_time, playback);
+        self.border_right_color.play_animation(entity, animation, start_time, playback);
+        self.border_bottom_color.play_animation(entity, animation, start_time, playback);
+        self.border_left_color.play_animation(entity, animation, start_time, playback);
+
+        self.corner_top_left_radius.play_animation(entity, animation, start_time, playback);
+        self.corner_top_right_radius.play_animation(entity, animation, start_time, playback);
+        self.corner_bottom_left_radius.play_animation(entity, animation, start_time, playback);
+        self.corner_bottom_right_radius.play_animation(entity, animation, start_time, playback);
+
+        self.outline_width.play_animation(entity, animation, start_time, playback);
+        self.outline_color.play_animation(entity, animation, start_time, playback);
+        self.outline_offset.play_animation(entity, animation, start_time, playback);
+
+        self.background_color.play_animation(entity, animation, start_time, playback);
+        self.background_image.play_animation(entity, animation, start_time, playback);
+        self.background_position.play_animation(entity, animation, start_time, playback);
+        self.background_size.play_animation(entity, animation, start_time, playback);
+
+        self.shadow.play_animation(entity, animation, start_time, playback);
+        self.elevation.play_animation(entity, animation, start_time, playback);
+
+        self.font_color.play_animation(entity, animation, start_time, playback);
+        self.text_decoration_color.play_animation(entity, animation, start_time, playback);
+        self.font_size.play_animation(entity, animation, start_time, playback);
+        self.font_variation_settings.play_animation(entity, animation, start_time, playback);
+        self.letter_spacing.play_animation(entity, animation, start_time, playback);
+        self.word_spacing.play_animation(entity, animation, start_time, playback);
+        self.caret_color.play_animation(entity, animation, start_time, playback);
+        self.selection_color.play_animation(entity, animation, start_time, playback);
+
+        self.left.play_animation(entity, animation, start_time, playback);
+        self.right.play_animation(entity, animation, start_time, playback);
+        self.top.play_animation(entity, animation, start_time, playback);
+        self.bottom.play_animation(entity, animation, start_time, playback);
+
+        self.padding_left.play_animation(entity, animation, start_time, playback);
+        self.padding_right.play_animation(entity, animation, start_time, playback);
+        self.padding_top.play_animation(entity, animation, start_time, playback);
+        self.padding_bottom.play_animation(entity, animation, start_time, playback);
+        self.horizontal_gap.play_animation(entity, animation, start_time, playback);
+        self.vertical_gap.play_animation(entity, animation, start_time, playback);
+
+        self.width.play_animation(entity, animation, start_time, playback);
+        self.height.play_animation(entity, animation, start_time, playback);
+
+        self.min_width.play_animation(entity, animation, start_time, playback);
+        self.max_width.play_animation(entity, animation, start_time, playback);
+        self.min_height.play_animation(entity, animation, start_time, playback);
+        self.max_height.play_animation(entity, animation, start_time, playback);
+
+        self.min_horizontal_gap.play_animation(entity, animation, start_time, playback);
+        self.max_horizontal_gap.play_animation(entity, animation, start_time, playback);
+        self.min_vertical_gap.play_animation(entity, animation, start_time, playback);
+        self.max_vertical_gap.play_animation(entity, animation, start_time, playback);
+
+        self.underline_color.play_animation(entity, animation, start_time, playback);
+
+        self.fill.play_animation(entity, animation, start_time, playback);
+        self.stroke.play_animation(entity, animation, start_time, playback);
+    }
+
+    pub(crate) fn stop_animation(&mut self, entity: Entity, animation: Animation) {
+        self.display.stop_animation(entity, animation);
+        self.opacity.stop_animation(entity, animation);
+        self.clip_path.stop_animation(entity, animation);
+        self.filter.stop_animation(entity, animation);
+        self.backdrop_filter.stop_animation(entity, animation);
+
+        self.transform.stop_animation(entity, animation);
+        self.transform_origin.stop_animation(entity, animation);
+        self.translate.stop_animation(entity, animation);
+        self.rotate.stop_animation(entity, animation);
+        self.scale.stop_animation(entity, animation);
+
+        self.border_top_width.stop_animation(entity, animation);
+        self.border_right_width.stop_animation(entity, animation);
+        self.border_bottom_width.stop_animation(entity, animation);
+        self.border_left_width.stop_animation(entity, animation);
+        self.border_top_color.stop_animation(entity, animation);
+        self.border_right_color.stop_animation(entity, animation);
+        self.border_bottom_color.stop_animation(entity, animation);
+        self.border_left_color.stop_animation(entity, animation);
+
+        self.corner_top_left_radius.stop_animation(entity, animation);
+        self.corner_top_right_radius.stop_animation(entity, animation);
+        self.corner_bottom_left_radius.stop_animation(entity, animation);
+        self.corner_bottom_right_radius.stop_animation(entity, animation);
+
+        self.outline_width.stop_animation(entity, animation);
+        self.outline_color.stop_animation(entity, animation);
+        self.outline_offset.stop_animation(entity, animation);
+
+        self.background_color.stop_animation(entity, animation);
+        self.background_image.stop_animation(entity, animation);
+        self.background_position.stop_animation(entity, animation);
+        self.background_size.stop_animation(entity, animation);
+
+        self.shadow.stop_animation(entity, animation);
+        self.elevation.stop_animation(entity, animation);
+
+        self.font_color.stop_animation(entity, animation);
+        self.text_decoration_color.stop_animation(entity, animation);
+        self.font_size.stop_animation(entity, animation);
+        self.font_variation_settings.stop_animation(entity, animation);
+        self.letter_spacing.stop_animation(entity, animation);
+        self.word_spacing.stop_animation(entity, animation);
+        self.caret_color.stop_animation(entity, animation);
+        self.selection_color.stop_animation(entity, animation);
+
+        self.left.stop_animation(entity, animation);
+        self.right.stop_animation(entity, animation);
+        self.top.stop_animation(entity, animation);
+        self.bottom.stop_animation(entity, animation);
+
+        self.padding_left.stop_animation(entity, animation);
+        self.padding_right.stop_animation(entity, animation);
+        self.padding_top.stop_animation(entity, animation);
+        self.padding_bottom.stop_animation(entity, animation);
+        self.horizontal_gap.stop_animation(entity, animation);
+        self.vertical_gap.stop_animation(entity, animation);
+
+        self.width.stop_animation(entity, animation);
+        self.height.stop_animation(entity, animation);
+
+        self.min_width.stop_animation(entity, animation);
+        self.max_width.stop_animation(entity, animation);
+        self.min_height.stop_animation(entity, animation);
+        self.max_height.stop_animation(entity, animation);
+
+        self.min_horizontal_gap.stop_animation(entity, animation);
+        self.max_horizontal_gap.stop_animation(entity, animation);
+        self.min_vertical_gap.stop_animation(entity, animation);
+        self.max_vertical_gap.stop_animation(entity, animation);
+
+        self.underline_color.stop_animation(entity, animation);
+
+        self.fill.stop_animation(entity, animation);
+        self.stroke.stop_animation(entity, animation);
+    }
+
+    /// Pauses, resumes, or moves an animation playing for an entity.
+    pub(crate) fn control_animation(
+        &mut self,
+        entity: Entity,
+        animation: Animation,
+        control: PlaybackControl,
+    ) {
+        let time = Instant::now();
+        let color_space = self.color_space;
+        self.display.control_animation(entity, animation, control, time, color_space);
+        self.opacity.control_animation(entity, animation, control, time, color_space);
+        self.clip_path.control_animation(entity, animation, control, time, color_space);
+        self.filter.control_animation(entity, animation, control, time, color_space);
+        self.backdrop_filter.control_animation(entity, animation, control, time, color_space);
+
+        self.transform.control_animation(entity, animation, control, time, color_space);
+        self.transform_origin.control_animation(entity, animation, control, time, color_space);
+        self.translate.control_animation(entity, animation, control, time, color_space);
+        self.rotate.control_animation(entity, animation, control, time, color_space);
+        self.scale.control_animation(entity, animation, control, time, color_space);
+
+        self.border_top_width.control_animation(entity, animation, control, time, color_space);
+        self.border_right_width.control_animation(entity, animation, control, time, color_space);
+        self.border_bottom_width.control_animation(entity, animation, control, time, color_space);
+        self.border_left_width.control_animation(entity, animation, control, time, color_space);
+        self.border_top_color.control_animation(entity, animation, control, time, color_space);
+        self.border_right_color.control_animation(entity, animation, control, time, color_space);
+        self.border_bottom_color.control_animation(entity, animation, control, time, color_space);
+        self.border_left_color.control_animation(entity, animation, control, time, color_space);
+
+        self.corner_top_left_radius.control_animation(
+            entity,
+            animation,
+            control,
+            time,
+            color_space,
+        );
+        self.corner_top_right_radius.control_animation(
+            entity,
+            animation,
+            control,
+            time,
+            color_space,
+        );
+        self.corner_bottom_left_radius.control_animation(
+            entity,
+            animation,
+            control,
+            time,
+            color_space,
+        );
+        self.corner_bottom_right_radius.control_animation(
+            entity,
+            animation,
+            control,
+            time,
+            color_space,
+        );
+
+        self.outline_width.control_animation(entity, animation, control, time, color_space);
+        self.outline_color.control_animation(entity, animation, control, time, color_space);
+        self.outline_offset.control_animation(entity, animation, control, time, color_space);
+
+        self.background_color.control_animation(entity, animation, control, time, color_space);
+        self.background_image.control_animation(entity, animation, control, time, color_space);
+        self.background_position.control_animation(entity, animation, control, time, color_space);
+        self.background_size.control_animation(entity, animation, control, time, color_space);
+
+        self.shadow.control_animation(entity, animation, control, time, color_space);
+        self.elevation.control_animation(entity, animation, control, time, color_space);
+
+        self.font_color.control_animation(entity, animation, control, time, color_space);
+        self.text_decoration_color.control_animation(entity, animation, control, time, color_space);
+        self.font_size.control_animation(entity, animation, control, time, color_space);
+        self.font_variation_settings.control_animation(
+            entity,
+            animation,
+            control,
+            time,
+            color_space,
+        );
+        self.letter_spacing.control_animation(entity, animation, control, time, color_space);
+        self.word_spacing.control_animation(entity, animation, control, time, color_space);
+        self.caret_color.control_animation(entity, animation, control, time, color_space);
+        self.selection_color.control_animation(entity, animation, control, time, color_space);
+
+        self.left.control_animation(entity, animation, control, time, color_space);
+        self.right.control_animation(entity, animation, control, time, color_space);
+        self.top.control_animation(entity, animation, control, time, color_space);
+        self.bottom.control_animation(entity, animation, control, time, color_space);
+
+        self.padding_left.control_animation(entity, animation, control, time, color_space);
+        self.padding_right.control_animation(entity, animation, control, time, color_space);
+        self.padding_top.control_animation(entity, animation, control, time, color_space);
+        self.padding_bottom.control_animation(entity, animation, control, time, color_space);
+        self.horizontal_gap.control_animation(entity, animation, control, time, color_space);
+        self.vertical_gap.control_animation(entity, animation, control, time, color_space);
+
+        self.width.control_animation(entity, animation, control, time, color_space);
+        self.height.control_animation(entity, animation, control, time, color_space);
+
+        self.min_width.control_animation(entity, animation, control, time, color_space);
+        self.max_width.control_animation(entity, animation, control, time, color_space);
+        self.min_height.control_animation(entity, animation, control, time, color_space);
+        self.max_height.control_animation(entity, animation, control, time, color_space);
+
+        self.min_horizontal_gap.control_animation(entity, animation, control, time, color_space);
+        self.max_horizontal_gap.control_animation(entity, animation, control, time, color_space);
+        self.min_vertical_gap.control_animation(entity, animation, control, time, color_space);
+        self.max_vertical_gap.control_animation(entity, animation, control, time, color_space);
+
+        self.underline_color.control_animation(entity, animation, control, time, color_space);
+
+        self.fill.control_animation(entity, animation, control, time, color_space);
+        self.stroke.control_animation(entity, animation, control, time, color_space);
     }
 
     pub(crate) fn is_animating(&self, entity: Entity, animation: Animation) -> bool {
         self.display.has_active_animation(entity, animation)
             | self.opacity.has_active_animation(entity, animation)
             | self.clip_path.has_active_animation(entity, animation)
+            | self.filter.has_active_animation(entity, animation)
+            | self.backdrop_filter.has_active_animation(entity, animation)
             | self.transform.has_active_animation(entity, animation)
             | self.transform_origin.has_active_animation(entity, animation)
             | self.translate.has_active_animation(entity, animation)
             | self.rotate.has_active_animation(entity, animation)
             | self.scale.has_active_animation(entity, animation)
-            | self.border_width.has_active_animation(entity, animation)
-            | self.border_color.has_active_animation(entity, animation)
+            | self.border_top_width.has_active_animation(entity, animation)
+            | self.border_right_width.has_active_animation(entity, animation)
+            | self.border_bottom_width.has_active_animation(entity, animation)
+            | self.border_left_width.has_active_animation(entity, animation)
+            | self.border_top_color.has_active_animation(entity, animation)
+            | self.border_right_color.has_active_animation(entity, animation)
+            | self.border_bottom_color.has_active_animation(entity, animation)
+            | self.border_left_color.has_active_animation(entity, animation)
             | self.corner_top_left_radius.has_active_animation(entity, animation)
             | self.corner_top_right_radius.has_active_animation(entity, animation)
             | self.corner_bottom_left_radius.has_active_animation(entity, animation)
@@ -813,10 +1560,16 @@ impl Style {
             | self.outline_offset.has_active_animation(entity, animation)
             | self.background_color.has_active_animation(entity, animation)
             | self.background_image.has_active_animation(entity, animation)
+            | self.background_position.has_active_animation(entity, animation)
             | self.background_size.has_active_animation(entity, animation)
             | self.shadow.has_active_animation(entity, animation)
+            | self.elevation.has_active_animation(entity, animation)
             | self.font_color.has_active_animation(entity, animation)
+            | self.text_decoration_color.has_active_animation(entity, animation)
             | self.font_size.has_active_animation(entity, animation)
+            | self.font_variation_settings.has_active_animation(entity, animation)
+            | self.letter_spacing.has_active_animation(entity, animation)
+            | self.word_spacing.has_active_animation(entity, animation)
             | self.caret_color.has_active_animation(entity, animation)
             | self.selection_color.has_active_animation(entity, animation)
             | self.left.has_active_animation(entity, animation)
@@ -841,348 +1594,571 @@ impl Style {
             | self.max_vertical_gap.has_active_animation(entity, animation)
             | self.underline_color.has_active_animation(entity, animation)
             | self.fill.has_active_animation(entity, animation)
+            | self.stroke.has_active_animation(entity, animation)
     }
 
     pub(crate) fn parse_theme(&mut self, stylesheet: &str) {
-        if let Ok(stylesheet) = StyleSheet::parse(stylesheet, ParserOptions::new()) {
-            let rules = stylesheet.rules.0;
+        let warnings = Arc::new(RwLock::new(Vec::new()));
+        let mut options = ParserOptions::new();
+        options.warnings = Some(warnings.clone());
 
-            for rule in rules {
-                match rule {
-                    CssRule::Style(style_rule) => {
-                        let rule_id = self.rule_manager.create();
+        if let Ok(stylesheet) = StyleSheet::parse(stylesheet, options) {
+            self.insert_css_rules(stylesheet.rules.0, &[]);
+        } else {
+            warn!("Failed to parse stylesheet");
+        }
 
-                        let selectors = style_rule.selectors;
+        // Invalid rules and declarations are skipped, so are logged rather than ignored silently.
+        if let Ok(warnings) = warnings.read() {
+            for warning in warnings.iter() {
+                warn!("Ignored invalid style: {:?}", warning);
+            }
+        }
+    }
 
-                        self.rules.insert(rule_id, selectors);
+    // Inserts the rules of a stylesheet, which are inside the `@media` rules with the given media queries.
+    fn insert_css_rules(&mut self, rules: Vec<CssRule>, media: &[usize]) {
+        for rule in rules {
+            match rule {
+                CssRule::Style(style_rule) => {
+                    let rule_id = self.rule_manager.create();
+                    if !media.is_empty() {
+                        self.rule_media.insert(rule_id, media.to_vec());
+                    }
 
-                        for property in style_rule.declarations.declarations {
-                            match property {
-                                Property::Transition(transitions) => {
-                                    for transition in transitions.iter() {
-                                        self.insert_transition(rule_id, transition);
-                                    }
-                                }
+                    let selectors = style_rule.selectors;
+                    if is_structural(&selectors) {
+                        self.structural_rules.insert(rule_id);
+                        self.has_structural_ancestors |= has_structural_ancestor(&selectors);
+                    }
+                    self.has_sibling_selectors |= has_sibling_dependency(&selectors);
+
+                    self.rules.insert(rule_id, selectors);
+                    self.rule_index.invalidate();
+
+                    // Transitions are inserted once the values of the rule are, as a transition only applies to a
+                    // property the rule sets, wherever it's declared.
+                    let mut transitions = Vec::new();
+                    for property in style_rule.declarations.declarations {
+                        match property {
+                            Property::Transition(rule_transitions) => {
+                                transitions.extend(rule_transitions);
+                            }
 
-                                _ => {
-                                    self.insert_property(rule_id, property);
-                                }
+                            _ => {
+                                self.insert_property(rule_id, property);
                             }
                         }
                     }
 
-                    CssRule::Keyframes(keyframes_rule) => {
-                        let name = keyframes_rule.name.as_string();
-
-                        let animation_id = self.animation_manager.create();
-
-                        for keyframes in keyframes_rule.keyframes {
-                            for selector in keyframes.selectors.iter() {
-                                let time = match selector {
-                                    KeyframeSelector::From => 0.0,
-                                    KeyframeSelector::To => 1.0,
-                                    KeyframeSelector::Percentage(percentage) => {
-                                        percentage.0 / 100.0
-                                    }
-                                };
-
-                                self.add_keyframe(
-                                    animation_id,
-                                    time,
-                                    &keyframes.declarations.declarations,
-                                );
-                            }
-                        }
+                    for transition in transitions.iter() {
+                        self.insert_transition(TransitionTarget::Rule(rule_id), transition);
+                    }
+                }
+
+                CssRule::Keyframes(keyframes_rule) => {
+                    let name = keyframes_rule.name.as_string();
 
-                        self.animations.insert(name, animation_id);
+                    let animation_id = self.animation_manager.create();
+
+                    for keyframes in keyframes_rule.keyframes {
+                        for selector in keyframes.selectors.iter() {
+                            let time = match selector {
+                                KeyframeSelector::From => 0.0,
+                                KeyframeSelector::To => 1.0,
+                                KeyframeSelector::Percentage(percentage) => percentage.0 / 100.0,
+                            };
+
+                            self.add_keyframe(
+                                animation_id,
+                                time,
+                                &keyframes.declarations.declarations,
+                            );
+                        }
                     }
 
-                    _ => {}
+                    self.animations.insert(name, animation_id);
                 }
+
+                CssRule::Media(media_rule) => {
+                    let mut media = media.to_vec();
+                    media.push(self.media_queries.len());
+                    self.media_queries.push(media_rule.query);
+                    self.insert_css_rules(media_rule.rules.0, &media);
+                }
+
+                _ => {}
             }
-        } else {
-            println!("Failed to parse stylesheet");
         }
     }
 
-    fn insert_transition(&mut self, rule_id: Rule, transition: &Transition) {
+    /// Sets the transition of a property of an entity, which plays when the inline value of the property changes, or
+    /// removes it if the transition has no duration.
+    pub(crate) fn set_transition(&mut self, entity: Entity, transition: &Transition) {
+        self.insert_transition(TransitionTarget::Entity(entity), transition);
+    }
+
+    fn insert_transition(&mut self, target: TransitionTarget, transition: &Transition) {
+        // Only the properties which a rule sets a value for are transitioned.
+        if transition.property == "all" {
+            for property in TRANSITION_PROPERTIES {
+                let transition =
+                    Transition { property: property.to_string(), ..transition.clone() };
+                self.insert_transition(target, &transition);
+            }
+
+            return;
+        }
+
         let animation = self.animation_manager.create();
         match transition.property.as_ref() {
             "display" => {
                 self.display.insert_animation(animation, self.add_transition(transition));
-                self.display.insert_transition(rule_id, animation);
+                self.display.insert_transition(target, animation);
             }
 
             "opacity" => {
                 self.opacity.insert_animation(animation, self.add_transition(transition));
-                self.opacity.insert_transition(rule_id, animation);
+                self.opacity.insert_transition(target, animation);
             }
 
             "clip-path" => {
                 self.clip_path.insert_animation(animation, self.add_transition(transition));
-                self.clip_path.insert_transition(rule_id, animation);
+                self.clip_path.insert_transition(target, animation);
+            }
+
+            "filter" => {
+                self.filter.insert_animation(animation, self.add_transition(transition));
+                self.filter.insert_transition(target, animation);
+            }
+
+            "backdrop-filter" => {
+                self.backdrop_filter.insert_animation(animation, self.add_transition(transition));
+                self.backdrop_filter.insert_transition(target, animation);
             }
 
             "transform" => {
                 self.transform.insert_animation(animation, self.add_transition(transition));
-                self.transform.insert_transition(rule_id, animation);
+                self.transform.insert_transition(target, animation);
             }
 
             "transform-origin" => {
                 self.transform_origin.insert_animation(animation, self.add_transition(transition));
-                self.transform_origin.insert_transition(rule_id, animation);
+                self.transform_origin.insert_transition(target, animation);
             }
 
             "translate" => {
                 self.translate.insert_animation(animation, self.add_transition(transition));
-                self.translate.insert_transition(rule_id, animation);
+                self.translate.insert_transition(target, animation);
             }
 
             "rotate" => {
                 self.rotate.insert_animation(animation, self.add_transition(transition));
-                self.rotate.insert_transition(rule_id, animation);
+                self.rotate.insert_transition(target, animation);
             }
 
             "scale" => {
                 self.scale.insert_animation(animation, self.add_transition(transition));
-                self.scale.insert_transition(rule_id, animation);
+                self.scale.insert_transition(target, animation);
             }
 
             "border" => {
-                self.border_width.insert_animation(animation, self.add_transition(transition));
-                self.border_width.insert_transition(rule_id, animation);
-                self.border_color.insert_animation(animation, self.add_transition(transition));
-                self.border_color.insert_transition(rule_id, animation);
+                self.border_top_width.insert_animation(animation, self.add_transition(transition));
+                self.border_top_width.insert_transition(target, animation);
+                self.border_right_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_width.insert_transition(target, animation);
+                self.border_bottom_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_width.insert_transition(target, animation);
+                self.border_left_width.insert_animation(animation, self.add_transition(transition));
+                self.border_left_width.insert_transition(target, animation);
+                self.border_top_color.insert_animation(animation, self.add_transition(transition));
+                self.border_top_color.insert_transition(target, animation);
+                self.border_right_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_color.insert_transition(target, animation);
+                self.border_bottom_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_color.insert_transition(target, animation);
+                self.border_left_color.insert_animation(animation, self.add_transition(transition));
+                self.border_left_color.insert_transition(target, animation);
             }
 
             "border-width" => {
-                self.border_width.insert_animation(animation, self.add_transition(transition));
-                self.border_width.insert_transition(rule_id, animation);
+                self.border_top_width.insert_animation(animation, self.add_transition(transition));
+                self.border_top_width.insert_transition(target, animation);
+                self.border_right_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_width.insert_transition(target, animation);
+                self.border_bottom_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_width.insert_transition(target, animation);
+                self.border_left_width.insert_animation(animation, self.add_transition(transition));
+                self.border_left_width.insert_transition(target, animation);
+            }
+
+            "border-top-width" => {
+                self.border_top_width.insert_animation(animation, self.add_transition(transition));
+                self.border_top_width.insert_transition(target, animation);
+            }
+
+            "border-right-width" => {
+                self.border_right_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_width.insert_transition(target, animation);
+            }
+
+            "border-bottom-width" => {
+                self.border_bottom_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_width.insert_transition(target, animation);
+            }
+
+            "border-left-width" => {
+                self.border_left_width.insert_animation(animation, self.add_transition(transition));
+                self.border_left_width.insert_transition(target, animation);
             }
 
             "border-color" => {
-                self.border_color.insert_animation(animation, self.add_transition(transition));
-                self.border_color.insert_transition(rule_id, animation);
+                self.border_top_color.insert_animation(animation, self.add_transition(transition));
+                self.border_top_color.insert_transition(target, animation);
+                self.border_right_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_color.insert_transition(target, animation);
+                self.border_bottom_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_color.insert_transition(target, animation);
+                self.border_left_color.insert_animation(animation, self.add_transition(transition));
+                self.border_left_color.insert_transition(target, animation);
+            }
+
+            "border-top-color" => {
+                self.border_top_color.insert_animation(animation, self.add_transition(transition));
+                self.border_top_color.insert_transition(target, animation);
+            }
+
+            "border-right-color" => {
+                self.border_right_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_color.insert_transition(target, animation);
+            }
+
+            "border-bottom-color" => {
+                self.border_bottom_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_color.insert_transition(target, animation);
+            }
+
+            "border-left-color" => {
+                self.border_left_color.insert_animation(animation, self.add_transition(transition));
+                self.border_left_color.insert_transition(target, animation);
             }
 
             "corner-radius" => {
                 self.corner_bottom_left_radius
                     .insert_animation(animation, self.add_transition(transition));
-                self.corner_bottom_left_radius.insert_transition(rule_id, animation);
+                self.corner_bottom_left_radius.insert_transition(target, animation);
                 self.corner_bottom_right_radius
                     .insert_animation(animation, self.add_transition(transition));
-                self.corner_bottom_right_radius.insert_transition(rule_id, animation);
+                self.corner_bottom_right_radius.insert_transition(target, animation);
                 self.corner_top_left_radius
                     .insert_animation(animation, self.add_transition(transition));
-                self.corner_top_left_radius.insert_transition(rule_id, animation);
+                self.corner_top_left_radius.insert_transition(target, animation);
                 self.corner_top_right_radius
                     .insert_animation(animation, self.add_transition(transition));
-                self.corner_top_right_radius.insert_transition(rule_id, animation);
+                self.corner_top_right_radius.insert_transition(target, animation);
             }
 
             "corner-top-left-radius" => {
                 self.corner_top_left_radius
                     .insert_animation(animation, self.add_transition(transition));
-                self.corner_top_left_radius.insert_transition(rule_id, animation);
+                self.corner_top_left_radius.insert_transition(target, animation);
             }
 
             "corner-top-right-radius" => {
                 self.corner_top_right_radius
                     .insert_animation(animation, self.add_transition(transition));
-                self.corner_top_right_radius.insert_transition(rule_id, animation);
+                self.corner_top_right_radius.insert_transition(target, animation);
             }
 
             "corner-bottom-left-radius" => {
                 self.corner_bottom_left_radius
                     .insert_animation(animation, self.add_transition(transition));
-                self.corner_bottom_left_radius.insert_transition(rule_id, animation);
+                self.corner_bottom_left_radius.insert_transition(target, animation);
             }
 
             "corner-bottom-right-radius" => {
                 self.corner_bottom_right_radius
                     .insert_animation(animation, self.add_transition(transition));
-                self.corner_bottom_right_radius.insert_transition(rule_id, animation);
+                self.corner_bottom_right_radius.insert_transition(target, animation);
             }
 
             "outline" => {
                 self.outline_width.insert_animation(animation, self.add_transition(transition));
-                self.outline_width.insert_transition(rule_id, animation);
+                self.outline_width.insert_transition(target, animation);
                 self.outline_color.insert_animation(animation, self.add_transition(transition));
-                self.outline_color.insert_transition(rule_id, animation);
+                self.outline_color.insert_transition(target, animation);
             }
 
             "outline-width" => {
                 self.outline_width.insert_animation(animation, self.add_transition(transition));
-                self.outline_width.insert_transition(rule_id, animation);
+                self.outline_width.insert_transition(target, animation);
             }
 
             "outline-color" => {
                 self.outline_color.insert_animation(animation, self.add_transition(transition));
-                self.outline_color.insert_transition(rule_id, animation);
+                self.outline_color.insert_transition(target, animation);
             }
 
             "outline-offset" => {
                 self.outline_offset.insert_animation(animation, self.add_transition(transition));
-                self.outline_offset.insert_transition(rule_id, animation);
+                self.outline_offset.insert_transition(target, animation);
             }
 
             "background-color" => {
                 self.background_color.insert_animation(animation, self.add_transition(transition));
-                self.background_color.insert_transition(rule_id, animation);
+                self.background_color.insert_transition(target, animation);
             }
 
             "background-image" => {
                 self.background_image.insert_animation(animation, self.add_transition(transition));
-                self.background_image.insert_transition(rule_id, animation);
+                self.background_image.insert_transition(target, animation);
+            }
+
+            "background-position" => {
+                self.background_position
+                    .insert_animation(animation, self.add_transition(transition));
+                self.background_position.insert_transition(target, animation);
             }
 
             "background-size" => {
                 self.background_size.insert_animation(animation, self.add_transition(transition));
-                self.background_size.insert_transition(rule_id, animation);
+                self.background_size.insert_transition(target, animation);
             }
 
             "shadow" => {
                 self.shadow.insert_animation(animation, self.add_transition(transition));
-                self.shadow.insert_transition(rule_id, animation);
+                self.shadow.insert_transition(target, animation);
+            }
+
+            "elevation" => {
+                self.elevation.insert_animation(animation, self.add_transition(transition));
+                self.elevation.insert_transition(target, animation);
             }
 
             "color" => {
                 self.font_color.insert_animation(animation, self.add_transition(transition));
-                self.font_color.insert_transition(rule_id, animation);
+                self.font_color.insert_transition(target, animation);
+            }
+
+            "text-decoration-color" => {
+                self.text_decoration_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.text_decoration_color.insert_transition(target, animation);
             }
 
             "font-size" => {
                 self.font_size.insert_animation(animation, self.add_transition(transition));
-                self.font_size.insert_transition(rule_id, animation);
+                self.font_size.insert_transition(target, animation);
+            }
+
+            "font-variation-settings" => {
+                self.font_variation_settings
+                    .insert_animation(animation, self.add_transition(transition));
+                self.font_variation_settings.insert_transition(target, animation);
+            }
+
+            "letter-spacing" => {
+                self.letter_spacing.insert_animation(animation, self.add_transition(transition));
+                self.letter_spacing.insert_transition(target, animation);
+            }
+
+            "word-spacing" => {
+                self.word_spacing.insert_animation(animation, self.add_transition(transition));
+                self.word_spacing.insert_transition(target, animation);
             }
 
             "caret-color" => {
                 self.caret_color.insert_animation(animation, self.add_transition(transition));
-                self.caret_color.insert_transition(rule_id, animation);
+                self.caret_color.insert_transition(target, animation);
             }
 
             "selection-color" => {
                 self.selection_color.insert_animation(animation, self.add_transition(transition));
-                self.selection_color.insert_transition(rule_id, animation);
+                self.selection_color.insert_transition(target, animation);
             }
 
             "left" => {
                 self.left.insert_animation(animation, self.add_transition(transition));
-                self.left.insert_transition(rule_id, animation);
+                self.left.insert_transition(target, animation);
+                self.layout_calc.left.insert_animation(animation, self.add_transition(transition));
+                self.layout_calc.left.insert_transition(target, animation);
             }
 
             "right" => {
                 self.right.insert_animation(animation, self.add_transition(transition));
-                self.right.insert_transition(rule_id, animation);
+                self.right.insert_transition(target, animation);
+                self.layout_calc.right.insert_animation(animation, self.add_transition(transition));
+                self.layout_calc.right.insert_transition(target, animation);
             }
 
             "top" => {
                 self.top.insert_animation(animation, self.add_transition(transition));
-                self.top.insert_transition(rule_id, animation);
+                self.top.insert_transition(target, animation);
+                self.layout_calc.top.insert_animation(animation, self.add_transition(transition));
+                self.layout_calc.top.insert_transition(target, animation);
             }
 
             "bottom" => {
                 self.bottom.insert_animation(animation, self.add_transition(transition));
-                self.bottom.insert_transition(rule_id, animation);
+                self.bottom.insert_transition(target, animation);
+                self.layout_calc
+                    .bottom
+                    .insert_animation(animation, self.add_transition(transition));
+                self.layout_calc.bottom.insert_transition(target, animation);
             }
 
             "padding-left" => {
                 self.padding_left.insert_animation(animation, self.add_transition(transition));
-                self.padding_left.insert_transition(rule_id, animation);
+                self.padding_left.insert_transition(target, animation);
+                self.layout_calc
+                    .padding_left
+                    .insert_animation(animation, self.add_transition(transition));
+                self.layout_calc.padding_left.insert_transition(target, animation);
             }
 
             "padding-right" => {
                 self.padding_right.insert_animation(animation, self.add_transition(transition));
-                self.padding_right.insert_transition(rule_id, animation);
+                self.padding_right.insert_transition(target, animation);
+                self.layout_calc
+                    .padding_right
+                    .insert_animation(animation, self.add_transition(transition));
+                self.layout_calc.padding_right.insert_transition(target, animation);
             }
 
             "padding-top" => {
                 self.padding_top.insert_animation(animation, self.add_transition(transition));
-                self.padding_top.insert_transition(rule_id, animation);
+                self.padding_top.insert_transition(target, animation);
+                self.layout_calc
+                    .padding_top
+                    .insert_animation(animation, self.add_transition(transition));
+                self.layout_calc.padding_top.insert_transition(target, animation);
             }
 
             "padding-bottom" => {
                 self.padding_bottom.insert_animation(animation, self.add_transition(transition));
-                self.padding_bottom.insert_transition(rule_id, animation);
+                self.padding_bottom.insert_transition(target, animation);
+                self.layout_calc
+                    .padding_bottom
+                    .insert_animation(animation, self.add_transition(transition));
+                self.layout_calc.padding_bottom.insert_transition(target, animation);
             }
 
             "horizontal-gap" => {
                 self.horizontal_gap.insert_animation(animation, self.add_transition(transition));
-                self.horizontal_gap.insert_transition(rule_id, animation);
+                self.horizontal_gap.insert_transition(target, animation);
             }
 
             "vertical-gap" => {
                 self.vertical_gap.insert_animation(animation, self.add_transition(transition));
-                self.vertical_gap.insert_transition(rule_id, animation);
+                self.vertical_gap.insert_transition(target, animation);
             }
 
             "gap" => {
                 self.horizontal_gap.insert_animation(animation, self.add_transition(transition));
-                self.horizontal_gap.insert_transition(rule_id, animation);
+                self.horizontal_gap.insert_transition(target, animation);
                 self.vertical_gap.insert_animation(animation, self.add_transition(transition));
-                self.vertical_gap.insert_transition(rule_id, animation);
+                self.vertical_gap.insert_transition(target, animation);
             }
 
             "width" => {
                 self.width.insert_animation(animation, self.add_transition(transition));
-                self.width.insert_transition(rule_id, animation);
+                self.width.insert_transition(target, animation);
+                self.layout_calc.width.insert_animation(animation, self.add_transition(transition));
+                self.layout_calc.width.insert_transition(target, animation);
             }
 
             "height" => {
                 self.height.insert_animation(animation, self.add_transition(transition));
-                self.height.insert_transition(rule_id, animation);
+                self.height.insert_transition(target, animation);
+                self.layout_calc
+                    .height
+                    .insert_animation(animation, self.add_transition(transition));
+                self.layout_calc.height.insert_transition(target, animation);
             }
 
             "min-width" => {
                 self.min_width.insert_animation(animation, self.add_transition(transition));
-                self.min_width.insert_transition(rule_id, animation);
+                self.min_width.insert_transition(target, animation);
+                self.layout_calc
+                    .min_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.layout_calc.min_width.insert_transition(target, animation);
             }
 
             "max-width" => {
                 self.max_width.insert_animation(animation, self.add_transition(transition));
-                self.max_width.insert_transition(rule_id, animation);
+                self.max_width.insert_transition(target, animation);
+                self.layout_calc
+                    .max_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.layout_calc.max_width.insert_transition(target, animation);
             }
 
             "min-height" => {
                 self.min_height.insert_animation(animation, self.add_transition(transition));
-                self.min_height.insert_transition(rule_id, animation);
+                self.min_height.insert_transition(target, animation);
+                self.layout_calc
+                    .min_height
+                    .insert_animation(animation, self.add_transition(transition));
+                self.layout_calc.min_height.insert_transition(target, animation);
             }
 
             "max-height" => {
                 self.max_height.insert_animation(animation, self.add_transition(transition));
-                self.max_height.insert_transition(rule_id, animation);
+                self.max_height.insert_transition(target, animation);
+                self.layout_calc
+                    .max_height
+                    .insert_animation(animation, self.add_transition(transition));
+                self.layout_calc.max_height.insert_transition(target, animation);
             }
 
             "min-horizontal-gap" => {
                 self.min_horizontal_gap
                     .insert_animation(animation, self.add_transition(transition));
-                self.min_horizontal_gap.insert_transition(rule_id, animation);
+                self.min_horizontal_gap.insert_transition(target, animation);
             }
 
             "max-horizontal-gap" => {
                 self.max_horizontal_gap
                     .insert_animation(animation, self.add_transition(transition));
-                self.max_horizontal_gap.insert_transition(rule_id, animation);
+                self.max_horizontal_gap.insert_transition(target, animation);
             }
 
             "min-vertical-gap" => {
                 self.min_vertical_gap.insert_animation(animation, self.add_transition(transition));
-                self.min_vertical_gap.insert_transition(rule_id, animation);
+                self.min_vertical_gap.insert_transition(target, animation);
             }
 
             "max-vertical-gap" => {
                 self.max_vertical_gap.insert_animation(animation, self.add_transition(transition));
-                self.max_vertical_gap.insert_transition(rule_id, animation);
+                self.max_vertical_gap.insert_transition(target, animation);
             }
 
             "underline-color" => {
                 self.underline_color.insert_animation(animation, self.add_transition(transition));
-                self.underline_color.insert_transition(rule_id, animation);
+                self.underline_color.insert_transition(target, animation);
             }
 
             "fill" => {
                 self.fill.insert_animation(animation, self.add_transition(transition));
-                self.fill.insert_transition(rule_id, animation);
+                self.fill.insert_transition(target, animation);
+            }
+
+            "stroke" => {
+                self.stroke.insert_animation(animation, self.add_transition(transition));
+                self.stroke.insert_transition(target, animation);
             }
 
             _ => {}
@@ -1212,13 +2188,17 @@ impl Style {
             }
 
             // Filters
+            Property::Filter(filter) => {
+                self.filter.insert_rule(rule_id, filter);
+            }
+
             Property::BackdropFilter(filter) => {
                 self.backdrop_filter.insert_rule(rule_id, filter);
             }
 
             // Blend Mode
-            Property::BlendMode(blend_mode) => {
-                self.blend_mode.insert_rule(rule_id, blend_mode);
+            Property::MixBlendMode(blend_mode) | Property::BlendMode(blend_mode) => {
+                self.mix_blend_mode.insert_rule(rule_id, blend_mode);
             }
 
             // Layout Type
@@ -1226,6 +2206,29 @@ impl Style {
                 self.layout_type.insert_rule(rule_id, layout_type);
             }
 
+            // Grid
+            Property::GridTemplateColumns(grid_columns) => {
+                self.grid_columns.insert_rule(rule_id, grid_columns);
+            }
+
+            Property::GridTemplateRows(grid_rows) => {
+                self.grid_rows.insert_rule(rule_id, grid_rows);
+            }
+
+            Property::GridColumn(placement) => {
+                if let Some(start) = placement.start {
+                    self.column_start.insert_rule(rule_id, start);
+                }
+                self.column_span.insert_rule(rule_id, placement.span);
+            }
+
+            Property::GridRow(placement) => {
+                if let Some(start) = placement.start {
+                    self.row_start.insert_rule(rule_id, start);
+                }
+                self.row_span.insert_rule(rule_id, placement.span);
+            }
+
             // Position Type
             Property::PositionType(position) => {
                 self.position_type.insert_rule(rule_id, position);
@@ -1235,66 +2238,138 @@ impl Style {
                 self.alignment.insert_rule(rule_id, alignment);
             }
 
+            Property::AlignBaseline(align_baseline) => {
+                self.align_baseline.insert_rule(rule_id, align_baseline);
+            }
+
+            Property::BaselineOffset(baseline_offset) => {
+                self.baseline_offset.insert_rule(rule_id, baseline_offset);
+            }
+
             // Space
             Property::Space(space) => {
-                self.left.insert_rule(rule_id, space);
-                self.right.insert_rule(rule_id, space);
-                self.top.insert_rule(rule_id, space);
-                self.bottom.insert_rule(rule_id, space);
+                insert_layout_rule(
+                    &mut self.left,
+                    &mut self.layout_calc.left,
+                    rule_id,
+                    space.clone(),
+                );
+                insert_layout_rule(
+                    &mut self.right,
+                    &mut self.layout_calc.right,
+                    rule_id,
+                    space.clone(),
+                );
+                insert_layout_rule(
+                    &mut self.top,
+                    &mut self.layout_calc.top,
+                    rule_id,
+                    space.clone(),
+                );
+                insert_layout_rule(&mut self.bottom, &mut self.layout_calc.bottom, rule_id, space);
             }
 
             Property::Left(left) => {
-                self.left.insert_rule(rule_id, left);
+                insert_layout_rule(&mut self.left, &mut self.layout_calc.left, rule_id, left);
             }
 
             Property::Right(right) => {
-                self.right.insert_rule(rule_id, right);
+                insert_layout_rule(&mut self.right, &mut self.layout_calc.right, rule_id, right);
             }
 
             Property::Top(top) => {
-                self.top.insert_rule(rule_id, top);
+                insert_layout_rule(&mut self.top, &mut self.layout_calc.top, rule_id, top);
             }
 
             Property::Bottom(bottom) => {
-                self.bottom.insert_rule(rule_id, bottom);
+                insert_layout_rule(&mut self.bottom, &mut self.layout_calc.bottom, rule_id, bottom);
             }
 
             // Size
             Property::Size(size) => {
-                self.width.insert_rule(rule_id, size);
-                self.height.insert_rule(rule_id, size);
+                insert_layout_rule(
+                    &mut self.width,
+                    &mut self.layout_calc.width,
+                    rule_id,
+                    size.clone(),
+                );
+                insert_layout_rule(&mut self.height, &mut self.layout_calc.height, rule_id, size);
             }
 
             Property::Width(width) => {
-                self.width.insert_rule(rule_id, width);
+                insert_layout_rule(&mut self.width, &mut self.layout_calc.width, rule_id, width);
             }
 
             Property::Height(height) => {
-                self.height.insert_rule(rule_id, height);
+                insert_layout_rule(&mut self.height, &mut self.layout_calc.height, rule_id, height);
+            }
+
+            Property::AspectRatio(aspect_ratio) => {
+                self.aspect_ratio.insert_rule(rule_id, aspect_ratio);
             }
 
             // Padding
             Property::Padding(padding) => {
-                self.padding_left.insert_rule(rule_id, padding);
-                self.padding_right.insert_rule(rule_id, padding);
-                self.padding_top.insert_rule(rule_id, padding);
-                self.padding_bottom.insert_rule(rule_id, padding);
+                insert_layout_rule(
+                    &mut self.padding_left,
+                    &mut self.layout_calc.padding_left,
+                    rule_id,
+                    padding.clone(),
+                );
+                insert_layout_rule(
+                    &mut self.padding_right,
+                    &mut self.layout_calc.padding_right,
+                    rule_id,
+                    padding.clone(),
+                );
+                insert_layout_rule(
+                    &mut self.padding_top,
+                    &mut self.layout_calc.padding_top,
+                    rule_id,
+                    padding.clone(),
+                );
+                insert_layout_rule(
+                    &mut self.padding_bottom,
+                    &mut self.layout_calc.padding_bottom,
+                    rule_id,
+                    padding,
+                );
             }
 
             Property::PaddingLeft(padding_left) => {
-                self.padding_left.insert_rule(rule_id, padding_left);
+                insert_layout_rule(
+                    &mut self.padding_left,
+                    &mut self.layout_calc.padding_left,
+                    rule_id,
+                    padding_left,
+                );
             }
 
             Property::PaddingRight(padding_right) => {
-                self.padding_right.insert_rule(rule_id, padding_right);
+                insert_layout_rule(
+                    &mut self.padding_right,
+                    &mut self.layout_calc.padding_right,
+                    rule_id,
+                    padding_right,
+                );
             }
 
             Property::PaddingTop(padding_top) => {
-                self.padding_top.insert_rule(rule_id, padding_top);
+                insert_layout_rule(
+                    &mut self.padding_top,
+                    &mut self.layout_calc.padding_top,
+                    rule_id,
+                    padding_top,
+                );
             }
 
             Property::PaddingBottom(padding_bottom) => {
-                self.padding_bottom.insert_rule(rule_id, padding_bottom);
+                insert_layout_rule(
+                    &mut self.padding_bottom,
+                    &mut self.layout_calc.padding_bottom,
+                    rule_id,
+                    padding_bottom,
+                );
             }
 
             Property::VerticalGap(vertical_gap) => {
@@ -1305,36 +2380,84 @@ impl Style {
                 self.horizontal_gap.insert_rule(rule_id, horizontal_gap);
             }
 
+            Property::RowGap(row_gap) => {
+                self.vertical_gap.insert_rule(rule_id, row_gap);
+            }
+
+            Property::ColumnGap(column_gap) => {
+                self.horizontal_gap.insert_rule(rule_id, column_gap);
+            }
+
             Property::Gap(gap) => {
-                self.horizontal_gap.insert_rule(rule_id, gap);
-                self.vertical_gap.insert_rule(rule_id, gap);
+                self.horizontal_gap.insert_rule(rule_id, gap.column);
+                self.vertical_gap.insert_rule(rule_id, gap.row);
             }
 
             // Size Constraints
             Property::MinSize(min_size) => {
-                self.min_width.insert_rule(rule_id, min_size);
-                self.min_height.insert_rule(rule_id, min_size);
+                insert_layout_rule(
+                    &mut self.min_width,
+                    &mut self.layout_calc.min_width,
+                    rule_id,
+                    min_size.clone(),
+                );
+                insert_layout_rule(
+                    &mut self.min_height,
+                    &mut self.layout_calc.min_height,
+                    rule_id,
+                    min_size,
+                );
             }
 
             Property::MinWidth(min_width) => {
-                self.min_width.insert_rule(rule_id, min_width);
+                insert_layout_rule(
+                    &mut self.min_width,
+                    &mut self.layout_calc.min_width,
+                    rule_id,
+                    min_width,
+                );
             }
 
             Property::MinHeight(min_height) => {
-                self.min_height.insert_rule(rule_id, min_height);
+                insert_layout_rule(
+                    &mut self.min_height,
+                    &mut self.layout_calc.min_height,
+                    rule_id,
+                    min_height,
+                );
             }
 
             Property::MaxSize(max_size) => {
-                self.max_width.insert_rule(rule_id, max_size);
-                self.max_height.insert_rule(rule_id, max_size);
+                insert_layout_rule(
+                    &mut self.max_width,
+                    &mut self.layout_calc.max_width,
+                    rule_id,
+                    max_size.clone(),
+                );
+                insert_layout_rule(
+                    &mut self.max_height,
+                    &mut self.layout_calc.max_height,
+                    rule_id,
+                    max_size,
+                );
             }
 
             Property::MaxWidth(max_width) => {
-                self.max_width.insert_rule(rule_id, max_width);
+                insert_layout_rule(
+                    &mut self.max_width,
+                    &mut self.layout_calc.max_width,
+                    rule_id,
+                    max_width,
+                );
             }
 
             Property::MaxHeight(max_height) => {
-                self.max_height.insert_rule(rule_id, max_height);
+                insert_layout_rule(
+                    &mut self.max_height,
+                    &mut self.layout_calc.max_height,
+                    rule_id,
+                    max_height,
+                );
             }
 
             // Gap Constraints
@@ -1372,29 +2495,97 @@ impl Style {
             // Border
             Property::Border(border) => {
                 if let Some(border_color) = border.color {
-                    self.border_color.insert_rule(rule_id, border_color);
+                    self.border_top_color.insert_rule(rule_id, border_color.into());
+                    self.border_right_color.insert_rule(rule_id, border_color.into());
+                    self.border_bottom_color.insert_rule(rule_id, border_color.into());
+                    self.border_left_color.insert_rule(rule_id, border_color.into());
                 }
 
                 if let Some(border_width) = border.width {
-                    self.border_width.insert_rule(rule_id, border_width.into());
+                    self.border_top_width.insert_rule(rule_id, border_width.0.clone());
+                    self.border_right_width.insert_rule(rule_id, border_width.0.clone());
+                    self.border_bottom_width.insert_rule(rule_id, border_width.0.clone());
+                    self.border_left_width.insert_rule(rule_id, border_width.0.clone());
                 }
 
                 if let Some(border_style) = border.style {
-                    self.border_style.insert_rule(rule_id, border_style.top);
+                    self.border_top_style.insert_rule(rule_id, border_style.top);
+                    self.border_right_style.insert_rule(rule_id, border_style.right);
+                    self.border_bottom_style.insert_rule(rule_id, border_style.bottom);
+                    self.border_left_style.insert_rule(rule_id, border_style.left);
                 }
             }
 
-            // Border
+            // Border Width
             Property::BorderWidth(border_width) => {
-                self.border_width.insert_rule(rule_id, border_width.top.0);
+                self.border_top_width.insert_rule(rule_id, border_width.top.0);
+                self.border_right_width.insert_rule(rule_id, border_width.right.0);
+                self.border_bottom_width.insert_rule(rule_id, border_width.bottom.0);
+                self.border_left_width.insert_rule(rule_id, border_width.left.0);
+            }
+
+            Property::BorderTopWidth(border_width) => {
+                self.border_top_width.insert_rule(rule_id, border_width.0);
+            }
+
+            Property::BorderRightWidth(border_width) => {
+                self.border_right_width.insert_rule(rule_id, border_width.0);
             }
 
+            Property::BorderBottomWidth(border_width) => {
+                self.border_bottom_width.insert_rule(rule_id, border_width.0);
+            }
+
+            Property::BorderLeftWidth(border_width) => {
+                self.border_left_width.insert_rule(rule_id, border_width.0);
+            }
+
+            // Border Color
             Property::BorderColor(color) => {
-                self.border_color.insert_rule(rule_id, color);
+                self.border_top_color.insert_rule(rule_id, color.clone());
+                self.border_right_color.insert_rule(rule_id, color.clone());
+                self.border_bottom_color.insert_rule(rule_id, color.clone());
+                self.border_left_color.insert_rule(rule_id, color.clone());
+            }
+
+            Property::BorderTopColor(color) => {
+                self.border_top_color.insert_rule(rule_id, color.into());
+            }
+
+            Property::BorderRightColor(color) => {
+                self.border_right_color.insert_rule(rule_id, color.into());
+            }
+
+            Property::BorderBottomColor(color) => {
+                self.border_bottom_color.insert_rule(rule_id, color.into());
             }
 
+            Property::BorderLeftColor(color) => {
+                self.border_left_color.insert_rule(rule_id, color.into());
+            }
+
+            // Border Style
             Property::BorderStyle(style) => {
-                self.border_style.insert_rule(rule_id, style.top);
+                self.border_top_style.insert_rule(rule_id, style.top);
+                self.border_right_style.insert_rule(rule_id, style.right);
+                self.border_bottom_style.insert_rule(rule_id, style.bottom);
+                self.border_left_style.insert_rule(rule_id, style.left);
+            }
+
+            Property::BorderTopStyle(style) => {
+                self.border_top_style.insert_rule(rule_id, style);
+            }
+
+            Property::BorderRightStyle(style) => {
+                self.border_right_style.insert_rule(rule_id, style);
+            }
+
+            Property::BorderBottomStyle(style) => {
+                self.border_bottom_style.insert_rule(rule_id, style);
+            }
+
+            Property::BorderLeftStyle(style) => {
+                self.border_left_style.insert_rule(rule_id, style);
             }
 
             // Border Radius
@@ -1489,6 +2680,10 @@ impl Style {
                 self.font_variation_settings.insert_rule(rule_id, font_variation_settings);
             }
 
+            Property::FontFeatureSettings(font_feature_settings) => {
+                self.font_feature_settings.insert_rule(rule_id, font_feature_settings);
+            }
+
             // Caret Color
             Property::CaretColor(caret_color) => {
                 self.caret_color.insert_rule(rule_id, caret_color);
@@ -1542,12 +2737,16 @@ impl Style {
             // Outline
             Property::Outline(outline) => {
                 if let Some(outline_color) = outline.color {
-                    self.outline_color.insert_rule(rule_id, outline_color);
+                    self.outline_color.insert_rule(rule_id, outline_color.into());
                 }
 
                 if let Some(outline_width) = outline.width {
                     self.outline_width.insert_rule(rule_id, outline_width.into());
                 }
+
+                if let Some(outline_style) = outline.style {
+                    self.outline_style.insert_rule(rule_id, outline_style.top);
+                }
             }
 
             Property::OutlineColor(outline_color) => {
@@ -1562,32 +2761,100 @@ impl Style {
                 self.outline_offset.insert_rule(rule_id, outline_offset);
             }
 
+            Property::OutlineStyle(outline_style) => {
+                self.outline_style.insert_rule(rule_id, outline_style);
+            }
+
+            // Focus Ring
+            Property::FocusRing(focus_ring) => {
+                self.focus_ring.insert_rule(rule_id, focus_ring);
+            }
+
+            Property::FocusRingColor(focus_ring_color) => {
+                self.focus_ring_color.insert_rule(rule_id, focus_ring_color);
+            }
+
+            Property::FocusRingWidth(focus_ring_width) => {
+                self.focus_ring_width.insert_rule(rule_id, focus_ring_width);
+            }
+
+            Property::FocusRingOffset(focus_ring_offset) => {
+                self.focus_ring_offset.insert_rule(rule_id, focus_ring_offset);
+            }
+
+            // Press Effect
+            Property::PressEffect(press_effect) => {
+                self.press_effect.insert_rule(rule_id, press_effect);
+            }
+
+            Property::RippleColor(ripple_color) => {
+                self.ripple_color.insert_rule(rule_id, ripple_color);
+            }
+
+            Property::RippleDuration(ripple_duration) => {
+                self.ripple_duration.insert_rule(rule_id, ripple_duration);
+            }
+
             // Background Images & Gradients
             Property::BackgroundImage(images) => {
                 let images = images
                     .into_iter()
-                    .filter_map(|img| match img {
-                        BackgroundImage::None => None,
-                        BackgroundImage::Gradient(gradient) => {
-                            Some(ImageOrGradient::Gradient(*gradient))
-                        }
-                        BackgroundImage::Url(url) => {
-                            Some(ImageOrGradient::Image(url.url.to_string()))
-                        }
-                    })
+                    .filter_map(ImageOrGradient::from_background_image)
                     .collect::<Vec<_>>();
 
                 self.background_image.insert_rule(rule_id, images);
             }
 
+            // Background Position
+            Property::BackgroundPosition(positions) => {
+                let positions = positions.iter().map(background_position).collect::<Vec<_>>();
+                self.background_position.insert_rule(rule_id, positions);
+            }
+
             // Background Size
             Property::BackgroundSize(sizes) => {
                 self.background_size.insert_rule(rule_id, sizes);
             }
 
-            // Text Wrapping
+            // Background Repeat
+            Property::BackgroundRepeat(repeats) => {
+                self.background_repeat.insert_rule(rule_id, repeats);
+            }
+
+            // Background Shorthand
+            Property::Background(background) => {
+                // Layers without an image draw nothing, so they're left out along with their other properties.
+                let mut images = Vec::new();
+                let mut positions = Vec::new();
+                let mut sizes = Vec::new();
+                let mut repeats = Vec::new();
+                for layer in background.layers {
+                    if let Some(image) = ImageOrGradient::from_background_image(layer.image) {
+                        images.push(image);
+                        positions.push(background_position(&layer.position));
+                        sizes.push(layer.size);
+                        repeats.push(layer.repeat);
+                    }
+                }
+
+                self.background_color
+                    .insert_rule(rule_id, background.color.unwrap_or(Color::transparent()));
+                self.background_image.insert_rule(rule_id, images);
+                self.background_position.insert_rule(rule_id, positions);
+                self.background_size.insert_rule(rule_id, sizes);
+                self.background_repeat.insert_rule(rule_id, repeats);
+            }
+
+            // White Space
+            Property::WhiteSpace(white_space) => {
+                self.white_space.insert_rule(rule_id, white_space);
+            }
+
             Property::TextWrap(text_wrap) => {
-                self.text_wrap.insert_rule(rule_id, text_wrap);
+                self.white_space.insert_rule(
+                    rule_id,
+                    if text_wrap { WhiteSpace::PreWrap } else { WhiteSpace::Pre },
+                );
             }
 
             // Text Alignment
@@ -1595,28 +2862,68 @@ impl Style {
                 self.text_align.insert_rule(rule_id, text_align);
             }
 
+            Property::VerticalAlign(vertical_align) => {
+                self.vertical_align.insert_rule(rule_id, vertical_align);
+            }
+
             // Box Shadows
             Property::Shadow(shadows) => {
                 self.shadow.insert_rule(rule_id, shadows);
             }
 
+            // Elevation
+            Property::Elevation(elevation) => {
+                self.elevation.insert_rule(rule_id, elevation);
+            }
+
             // Cursor Icon
             Property::Cursor(cursor) => {
                 self.cursor.insert_rule(rule_id, cursor);
             }
 
+            Property::Animation(animations) => {
+                self.keyframe_animations.insert_rule(rule_id, animations);
+            }
+
             Property::PointerEvents(pointer_events) => {
                 self.pointer_events.insert_rule(rule_id, pointer_events);
             }
 
-            // Unparsed. TODO: Log the error.
+            // Keywords are linked after the values of the rules, as they depend on the rules which match a view.
+            Property::CssWide(declaration) => {
+                let longhands = longhand_names(&declaration.name);
+                if longhands.is_empty() {
+                    warn!("CSS-wide keyword for unsupported property: {}", declaration.name);
+                }
+
+                self.rule_keywords
+                    .entry(rule_id)
+                    .or_default()
+                    .extend(longhands.into_iter().map(|name| (name, declaration.keyword)));
+            }
+
+            // Declarations which reference custom properties are parsed once the references are substituted, when
+            // styles are matched.
             Property::Unparsed(unparsed) => {
-                warn!("Unparsed: {}", unparsed.name);
+                if unparsed.value.has_variables() {
+                    self.rule_variables
+                        .entry(rule_id)
+                        .or_default()
+                        .push((unparsed.name.to_string(), unparsed.value.to_css_string()));
+                } else {
+                    warn!("Unparsed: {}", unparsed.name);
+                }
             }
 
-            // TODO: Custom property support
             Property::Custom(custom) => {
-                warn!("Custom Property: {}", custom.name);
+                if custom.name.starts_with("--") {
+                    self.rule_custom_properties
+                        .entry(rule_id)
+                        .or_default()
+                        .push((custom.name.to_string(), custom.value.to_css_string()));
+                } else {
+                    warn!("Custom Property: {}", custom.name);
+                }
             }
             Property::TextOverflow(text_overflow) => {
                 self.text_overflow.insert_rule(rule_id, text_overflow);
@@ -1624,9 +2931,35 @@ impl Style {
             Property::LineClamp(line_clamp) => {
                 self.line_clamp.insert_rule(rule_id, line_clamp);
             }
+
+            // Line Height
+            Property::LineHeight(line_height) => {
+                self.line_height.insert_rule(rule_id, line_height);
+            }
+            Property::TextTransform(text_transform) => {
+                self.text_transform.insert_rule(rule_id, text_transform);
+            }
+            Property::TextRendering(text_rendering) => {
+                self.text_rendering.insert_rule(rule_id, text_rendering);
+            }
+            Property::TextDecoration(decoration) => {
+                self.text_decoration_line.insert_rule(rule_id, decoration.line);
+                self.text_decoration_style.insert_rule(rule_id, decoration.style);
+                self.text_decoration_color.insert_rule(rule_id, decoration.color);
+                self.text_decoration_thickness.insert_rule(rule_id, decoration.thickness);
+            }
             Property::TextDecorationLine(line) => {
                 self.text_decoration_line.insert_rule(rule_id, line);
             }
+            Property::TextDecorationStyle(decoration_style) => {
+                self.text_decoration_style.insert_rule(rule_id, decoration_style);
+            }
+            Property::TextDecorationColor(color) => {
+                self.text_decoration_color.insert_rule(rule_id, color);
+            }
+            Property::TextDecorationThickness(thickness) => {
+                self.text_decoration_thickness.insert_rule(rule_id, thickness);
+            }
             Property::TextStroke(stroke) => {
                 self.text_stroke_width.insert_rule(rule_id, stroke.width);
                 self.text_stroke_style.insert_rule(rule_id, stroke.style);
@@ -1637,9 +2970,27 @@ impl Style {
             Property::TextStrokeStyle(stroke_style) => {
                 self.text_stroke_style.insert_rule(rule_id, stroke_style);
             }
+            Property::LetterSpacing(letter_spacing) => {
+                self.letter_spacing.insert_rule(rule_id, letter_spacing);
+            }
+            Property::WordSpacing(word_spacing) => {
+                self.word_spacing.insert_rule(rule_id, word_spacing);
+            }
             Property::Fill(fill) => {
                 self.fill.insert_rule(rule_id, fill);
             }
+            Property::Stroke(stroke) => {
+                self.stroke.insert_rule(rule_id, stroke);
+            }
+            Property::StrokeWidth(stroke_width) => {
+                self.stroke_width.insert_rule(rule_id, stroke_width);
+            }
+            Property::StrokeLinecap(stroke_linecap) => {
+                self.stroke_linecap.insert_rule(rule_id, stroke_linecap);
+            }
+            Property::StrokeLinejoin(stroke_linejoin) => {
+                self.stroke_linejoin.insert_rule(rule_id, stroke_linejoin);
+            }
             _ => {}
         }
     }
@@ -1649,21 +3000,14 @@ impl Style {
         &self,
         transition: &Transition,
     ) -> AnimationState<T> {
-        let timing_function = transition
-            .timing_function
-            .map(|easing| match easing {
-                EasingFunction::Linear => TimingFunction::linear(),
-                EasingFunction::Ease => TimingFunction::ease(),
-                EasingFunction::EaseIn => TimingFunction::ease_in(),
-                EasingFunction::EaseOut => TimingFunction::ease_out(),
-                EasingFunction::EaseInOut => TimingFunction::ease_in_out(),
-                EasingFunction::CubicBezier(x1, y1, x2, y2) => TimingFunction::new(x1, y1, x2, y2),
-            })
-            .unwrap_or_default();
+        let timing_function =
+            transition.timing_function.map(TimingFunction::from).unwrap_or_default();
 
         AnimationState::new(Animation::null())
+            .with_color_space(transition.color_space)
             .with_duration(transition.duration)
             .with_delay(transition.delay.unwrap_or_default())
+            .with_spring(transition.timing_function.and_then(Spring::from_easing))
             .with_keyframe(Keyframe { time: 0.0, value: Default::default(), timing_function })
             .with_keyframe(Keyframe { time: 1.0, value: Default::default(), timing_function })
     }
@@ -1674,6 +3018,7 @@ impl Style {
         self.classes.insert(entity, HashSet::new());
         self.abilities.insert(entity, Abilities::default());
         self.system_flags = SystemFlags::RELAYOUT;
+        self.relayout.insert(entity);
         self.restyle.0.insert(entity).unwrap();
         self.reaccess.0.insert(entity).unwrap();
     }
@@ -1681,7 +3026,13 @@ impl Style {
     // Remove style data for the given entity.
     pub fn remove(&mut self, entity: Entity) {
         self.ids.remove(entity);
+        self.restyle_children.remove(&entity);
+        self.keyword_inherited.remove(&entity);
         self.classes.remove(entity);
+        self.parts.remove(entity);
+        self.custom_properties.remove(entity);
+        self.computed_custom_properties.remove(entity);
+        self.state_styles.remove(entity);
         self.pseudo_classes.remove(entity);
         self.disabled.remove(entity);
         self.abilities.remove(entity);
@@ -1692,6 +3043,7 @@ impl Style {
         self.live.remove(entity);
         self.labelled_by.remove(entity);
         self.hidden.remove(entity);
+        self.expanded.remove(entity);
         self.text_value.remove(entity);
         self.numeric_value.remove(entity);
 
@@ -1703,6 +3055,7 @@ impl Style {
         self.opacity.remove(entity);
         // Z Order
         self.z_index.remove(entity);
+        self.layer.remove(entity);
         // Clipping
         self.clip_path.remove(entity);
 
@@ -1710,10 +3063,11 @@ impl Style {
         self.overflowy.remove(entity);
 
         // Backdrop Filter
+        self.filter.remove(entity);
         self.backdrop_filter.remove(entity);
 
         // Blend Mode
-        self.blend_mode.remove(entity);
+        self.mix_blend_mode.remove(entity);
 
         // Transform
         self.transform.remove(entity);
@@ -1723,9 +3077,18 @@ impl Style {
         self.scale.remove(entity);
 
         // Border
-        self.border_width.remove(entity);
-        self.border_color.remove(entity);
-        self.border_style.remove(entity);
+        self.border_top_width.remove(entity);
+        self.border_right_width.remove(entity);
+        self.border_bottom_width.remove(entity);
+        self.border_left_width.remove(entity);
+        self.border_top_color.remove(entity);
+        self.border_right_color.remove(entity);
+        self.border_bottom_color.remove(entity);
+        self.border_left_color.remove(entity);
+        self.border_top_style.remove(entity);
+        self.border_right_style.remove(entity);
+        self.border_bottom_style.remove(entity);
+        self.border_left_style.remove(entity);
 
         // Corner Shape
         self.corner_bottom_left_shape.remove(entity);
@@ -1749,21 +3112,41 @@ impl Style {
         self.outline_width.remove(entity);
         self.outline_color.remove(entity);
         self.outline_offset.remove(entity);
+        self.outline_style.remove(entity);
+
+        // Focus Ring
+        self.focus_ring.remove(entity);
+        self.focus_ring_color.remove(entity);
+        self.focus_ring_width.remove(entity);
+        self.focus_ring_offset.remove(entity);
+
+        self.press_effect.remove(entity);
+        self.ripple_color.remove(entity);
+        self.ripple_duration.remove(entity);
+        self.ripples.remove(entity);
 
         // Background
         self.background_color.remove(entity);
         self.background_image.remove(entity);
+        self.background_position.remove(entity);
         self.background_size.remove(entity);
+        self.background_repeat.remove(entity);
 
         // Box Shadow
         self.shadow.remove(entity);
+        self.elevation.remove(entity);
 
         // Text and Font
         self.text.remove(entity);
-        self.text_wrap.remove(entity);
+        self.nav_text.remove(entity);
+        self.white_space.remove(entity);
         self.text_overflow.remove(entity);
         self.line_clamp.remove(entity);
+        self.line_height.remove(entity);
+        self.text_transform.remove(entity);
+        self.text_rendering.remove(entity);
         self.text_align.remove(entity);
+        self.vertical_align.remove(entity);
         self.font_family.remove(entity);
         self.font_color.remove(entity);
         self.font_size.remove(entity);
@@ -1771,24 +3154,45 @@ impl Style {
         self.font_slant.remove(entity);
         self.font_width.remove(entity);
         self.font_variation_settings.remove(entity);
+        self.font_feature_settings.remove(entity);
         self.caret_color.remove(entity);
         self.selection_color.remove(entity);
         self.text_decoration_line.remove(entity);
+        self.text_decoration_style.remove(entity);
+        self.text_decoration_color.remove(entity);
+        self.text_decoration_thickness.remove(entity);
         self.text_stroke_width.remove(entity);
         self.text_stroke_style.remove(entity);
+        self.letter_spacing.remove(entity);
+        self.word_spacing.remove(entity);
 
         // Cursor
         self.cursor.remove(entity);
+        self.keyframe_animations.remove(entity);
+        self.running_animations.remove(entity);
+        self.played_animations.retain(|(played, _)| *played != entity);
 
         self.pointer_events.remove(entity);
 
         // Layout Type
         self.layout_type.remove(entity);
 
+        // Grid
+        self.grid_columns.remove(entity);
+        self.grid_rows.remove(entity);
+        self.column_start.remove(entity);
+        self.column_span.remove(entity);
+        self.row_start.remove(entity);
+        self.row_span.remove(entity);
+        self.grid_cells.remove(entity);
+        self.scroll_states.remove(entity);
+
         // Position Type
         self.position_type.remove(entity);
 
         self.alignment.remove(entity);
+        self.align_baseline.remove(entity);
+        self.baseline_offset.remove(entity);
 
         // Space
         self.left.remove(entity);
@@ -1811,12 +3215,14 @@ impl Style {
         // Size
         self.width.remove(entity);
         self.height.remove(entity);
+        self.aspect_ratio.remove(entity);
 
         // Size Constraints
         self.min_width.remove(entity);
         self.max_width.remove(entity);
         self.min_height.remove(entity);
         self.max_height.remove(entity);
+        self.layout_calc.remove(entity);
 
         self.min_horizontal_gap.remove(entity);
         self.max_horizontal_gap.remove(entity);
@@ -1827,14 +3233,229 @@ impl Style {
         self.text_span.remove(entity);
 
         self.fill.remove(entity);
+        self.stroke.remove(entity);
+        self.stroke_width.remove(entity);
+        self.stroke_linecap.remove(entity);
+        self.stroke_linejoin.remove(entity);
+
+        self.relayout.remove(&entity);
+    }
+
+    /// Calls the given closure with the name and value of each style property which has been set on the entity,
+    /// either inline or from a matched style rule.
+    pub(crate) fn for_each_property(
+        &self,
+        entity: Entity,
+        mut f: impl FnMut(&'static str, &dyn std::fmt::Debug),
+    ) {
+        macro_rules! properties {
+            ($($name:literal => $store:ident,)*) => {
+                $(
+                    if let Some(value) = self.$store.get(entity) {
+                        f($name, value);
+                    }
+                )*
+            };
+        }
+
+        properties! {
+            "disabled" => disabled,
+            "display" => display,
+            "visibility" => visibility,
+            "opacity" => opacity,
+            "z-index" => z_index,
+            "clip-path" => clip_path,
+            "overflow-x" => overflowx,
+            "overflow-y" => overflowy,
+            "filter" => filter,
+            "backdrop-filter" => backdrop_filter,
+            "mix-blend-mode" => mix_blend_mode,
+            "transform" => transform,
+            "transform-origin" => transform_origin,
+            "translate" => translate,
+            "rotate" => rotate,
+            "scale" => scale,
+            "border-top-width" => border_top_width,
+            "border-right-width" => border_right_width,
+            "border-bottom-width" => border_bottom_width,
+            "border-left-width" => border_left_width,
+            "border-top-color" => border_top_color,
+            "border-right-color" => border_right_color,
+            "border-bottom-color" => border_bottom_color,
+            "border-left-color" => border_left_color,
+            "border-top-style" => border_top_style,
+            "border-right-style" => border_right_style,
+            "border-bottom-style" => border_bottom_style,
+            "border-left-style" => border_left_style,
+            "corner-top-left-shape" => corner_top_left_shape,
+            "corner-top-right-shape" => corner_top_right_shape,
+            "corner-bottom-left-shape" => corner_bottom_left_shape,
+            "corner-bottom-right-shape" => corner_bottom_right_shape,
+            "corner-top-left-radius" => corner_top_left_radius,
+            "corner-top-right-radius" => corner_top_right_radius,
+            "corner-bottom-left-radius" => corner_bottom_left_radius,
+            "corner-bottom-right-radius" => corner_bottom_right_radius,
+            "corner-top-left-smoothing" => corner_top_left_smoothing,
+            "corner-top-right-smoothing" => corner_top_right_smoothing,
+            "corner-bottom-left-smoothing" => corner_bottom_left_smoothing,
+            "corner-bottom-right-smoothing" => corner_bottom_right_smoothing,
+            "outline-width" => outline_width,
+            "outline-color" => outline_color,
+            "outline-offset" => outline_offset,
+            "outline-style" => outline_style,
+            "focus-ring" => focus_ring,
+            "focus-ring-color" => focus_ring_color,
+            "focus-ring-width" => focus_ring_width,
+            "focus-ring-offset" => focus_ring_offset,
+            "press-effect" => press_effect,
+            "ripple-color" => ripple_color,
+            "ripple-duration" => ripple_duration,
+            "background-color" => background_color,
+            "background-image" => background_image,
+            "background-position" => background_position,
+            "background-size" => background_size,
+            "background-repeat" => background_repeat,
+            "shadow" => shadow,
+            "elevation" => elevation,
+            "white-space" => white_space,
+            "text-overflow" => text_overflow,
+            "line-clamp" => line_clamp,
+            "line-height" => line_height,
+            "text-transform" => text_transform,
+            "text-rendering" => text_rendering,
+            "text-align" => text_align,
+            "vertical-align" => vertical_align,
+            "text-decoration-line" => text_decoration_line,
+            "text-decoration-style" => text_decoration_style,
+            "text-decoration-color" => text_decoration_color,
+            "text-decoration-thickness" => text_decoration_thickness,
+            "text-stroke-width" => text_stroke_width,
+            "text-stroke-style" => text_stroke_style,
+            "letter-spacing" => letter_spacing,
+            "word-spacing" => word_spacing,
+            "underline-style" => underline_style,
+            "overline-style" => overline_style,
+            "strikethrough-style" => strikethrough_style,
+            "underline-color" => underline_color,
+            "overline-color" => overline_color,
+            "strikethrough-color" => strikethrough_color,
+            "font-family" => font_family,
+            "color" => font_color,
+            "font-size" => font_size,
+            "font-weight" => font_weight,
+            "font-slant" => font_slant,
+            "font-width" => font_width,
+            "font-variation-settings" => font_variation_settings,
+            "font-feature-settings" => font_feature_settings,
+            "caret-color" => caret_color,
+            "selection-color" => selection_color,
+            "fill" => fill,
+            "stroke" => stroke,
+            "stroke-width" => stroke_width,
+            "stroke-linecap" => stroke_linecap,
+            "stroke-linejoin" => stroke_linejoin,
+            "cursor" => cursor,
+            "animation" => keyframe_animations,
+            "pointer-events" => pointer_events,
+            "layout-type" => layout_type,
+            "grid-template-columns" => grid_columns,
+            "grid-template-rows" => grid_rows,
+            "column-start" => column_start,
+            "column-span" => column_span,
+            "row-start" => row_start,
+            "row-span" => row_span,
+            "position-type" => position_type,
+            "alignment" => alignment,
+            "align-baseline" => align_baseline,
+            "baseline-offset" => baseline_offset,
+            "left" => left,
+            "right" => right,
+            "top" => top,
+            "bottom" => bottom,
+            "padding-left" => padding_left,
+            "padding-right" => padding_right,
+            "padding-top" => padding_top,
+            "padding-bottom" => padding_bottom,
+            "vertical-gap" => vertical_gap,
+            "horizontal-gap" => horizontal_gap,
+            "vertical-scroll" => vertical_scroll,
+            "horizontal-scroll" => horizontal_scroll,
+            "width" => width,
+            "height" => height,
+            "aspect-ratio" => aspect_ratio,
+            "min-width" => min_width,
+            "max-width" => max_width,
+            "min-height" => min_height,
+            "max-height" => max_height,
+            "min-horizontal-gap" => min_horizontal_gap,
+            "max-horizontal-gap" => max_horizontal_gap,
+            "min-vertical-gap" => min_vertical_gap,
+            "max-vertical-gap" => max_vertical_gap,
+        }
+    }
+
+    /// Returns the names of the stores which hold data for the entity, which should be none once it has been removed.
+    pub(crate) fn stores_with_data(&self, entity: Entity) -> Vec<&'static str> {
+        let mut stores = Vec::new();
+        self.for_each_property(entity, |name, _| stores.push(name));
+
+        let other_stores = [
+            ("id", self.ids.contains(entity)),
+            ("classes", self.classes.contains(entity)),
+            ("custom-properties", self.custom_properties.contains(entity)),
+            ("computed-custom-properties", self.computed_custom_properties.contains(entity)),
+            ("state-styles", self.state_styles.contains(entity)),
+            ("running-animations", self.running_animations.contains(entity)),
+            ("pseudo-classes", self.pseudo_classes.contains(entity)),
+            ("abilities", self.abilities.contains(entity)),
+            ("name", self.name.get(entity).is_some()),
+            ("role", self.role.contains(entity)),
+            ("default-action-verb", self.default_action_verb.contains(entity)),
+            ("live", self.live.contains(entity)),
+            ("labelled-by", self.labelled_by.contains(entity)),
+            ("hidden", self.hidden.contains(entity)),
+            ("expanded", self.expanded.contains(entity)),
+            ("text-value", self.text_value.contains(entity)),
+            ("numeric-value", self.numeric_value.contains(entity)),
+            ("layer", self.layer.contains(entity)),
+            ("text-range", self.text_range.contains(entity)),
+            ("text-span", self.text_span.contains(entity)),
+            (
+                "layout-calc",
+                self.layout_calc.has_lengths(entity) || self.layout_calc.bases.contains(entity),
+            ),
+            ("relayout", self.relayout.contains(&entity)),
+            ("keyword-inherited", self.keyword_inherited.contains(&entity)),
+        ];
+        stores.extend(other_stores.iter().filter(|(_, contains)| *contains).map(|(name, _)| *name));
+
+        stores
     }
 
     pub fn needs_restyle(&mut self, entity: Entity) {
         self.restyle.0.insert(entity).unwrap();
     }
 
+    /// Marks the children of the given entity as needing to be restyled after they have changed, if any selector
+    /// depends on the position of a view among its siblings.
+    pub(crate) fn needs_children_restyle(&mut self, entity: Entity) {
+        if !self.structural_rules.is_empty() {
+            self.restyle_children.insert(entity);
+        }
+    }
+
+    /// Marks the whole tree as needing a layout computation.
     pub fn needs_relayout(&mut self) {
         self.system_flags.set(SystemFlags::RELAYOUT, true);
+        self.relayout.insert(Entity::root());
+    }
+
+    /// Marks the given entity as needing a layout computation.
+    ///
+    /// Only the subtree of the closest ancestor which cannot change size as a result is laid out again.
+    pub fn needs_relayout_entity(&mut self, entity: Entity) {
+        self.system_flags.set(SystemFlags::RELAYOUT, true);
+        self.relayout.insert(entity);
     }
 
     pub fn needs_access_update(&mut self, entity: Entity) {
@@ -1846,6 +3467,11 @@ impl Style {
         self.text_layout.0.insert(entity).unwrap();
     }
 
+    /// Marks the text of the given entity as needing to be rebuilt without affecting its layout.
+    pub fn needs_text_repaint(&mut self, entity: Entity) {
+        self.text_repaint.0.insert(entity).unwrap();
+    }
+
     pub fn needs_text_layout(&mut self, entity: Entity) {
         self.text_layout.0.insert(entity).unwrap();
     }
@@ -1872,10 +3498,11 @@ impl Style {
         self.clip_path.clear_rules();
 
         // Backdrop Filer
+        self.filter.clear_rules();
         self.backdrop_filter.clear_rules();
 
         // Blend Mode
-        self.blend_mode.clear_rules();
+        self.mix_blend_mode.clear_rules();
 
         // Transform
         self.transform.clear_rules();
@@ -1888,9 +3515,18 @@ impl Style {
         self.overflowy.clear_rules();
 
         // Border
-        self.border_width.clear_rules();
-        self.border_color.clear_rules();
-        self.border_style.clear_rules();
+        self.border_top_width.clear_rules();
+        self.border_right_width.clear_rules();
+        self.border_bottom_width.clear_rules();
+        self.border_left_width.clear_rules();
+        self.border_top_color.clear_rules();
+        self.border_right_color.clear_rules();
+        self.border_bottom_color.clear_rules();
+        self.border_left_color.clear_rules();
+        self.border_top_style.clear_rules();
+        self.border_right_style.clear_rules();
+        self.border_bottom_style.clear_rules();
+        self.border_left_style.clear_rules();
 
         // Corner Shape
         self.corner_bottom_left_shape.clear_rules();
@@ -1914,17 +3550,39 @@ impl Style {
         self.outline_width.clear_rules();
         self.outline_color.clear_rules();
         self.outline_offset.clear_rules();
+        self.outline_style.clear_rules();
+
+        // Focus Ring
+        self.focus_ring.clear_rules();
+        self.focus_ring_color.clear_rules();
+        self.focus_ring_width.clear_rules();
+        self.focus_ring_offset.clear_rules();
+
+        self.press_effect.clear_rules();
+        self.ripple_color.clear_rules();
+        self.ripple_duration.clear_rules();
 
         // Background
         self.background_color.clear_rules();
         self.background_image.clear_rules();
+        self.background_position.clear_rules();
         self.background_size.clear_rules();
+        self.background_repeat.clear_rules();
 
         self.shadow.clear_rules();
+        self.elevation.clear_rules();
 
         self.layout_type.clear_rules();
+        self.grid_columns.clear_rules();
+        self.grid_rows.clear_rules();
+        self.column_start.clear_rules();
+        self.column_span.clear_rules();
+        self.row_start.clear_rules();
+        self.row_span.clear_rules();
         self.position_type.clear_rules();
         self.alignment.clear_rules();
+        self.align_baseline.clear_rules();
+        self.baseline_offset.clear_rules();
 
         // Space
         self.left.clear_rules();
@@ -1935,6 +3593,7 @@ impl Style {
         // Size
         self.width.clear_rules();
         self.height.clear_rules();
+        self.aspect_ratio.clear_rules();
 
         // Size Constraints
         self.min_width.clear_rules();
@@ -1942,6 +3601,8 @@ impl Style {
         self.min_height.clear_rules();
         self.max_height.clear_rules();
 
+        self.layout_calc.clear_rules();
+
         self.min_horizontal_gap.clear_rules();
         self.max_horizontal_gap.clear_rules();
         self.min_vertical_gap.clear_rules();
@@ -1960,28 +3621,43 @@ impl Style {
         self.vertical_scroll.clear_rules();
 
         // Text and Font
-        self.text_wrap.clear_rules();
+        self.white_space.clear_rules();
         self.text_overflow.clear_rules();
         self.line_clamp.clear_rules();
+        self.line_height.clear_rules();
+        self.text_transform.clear_rules();
+        self.text_rendering.clear_rules();
         self.text_align.clear_rules();
+        self.vertical_align.clear_rules();
         self.font_family.clear_rules();
         self.font_weight.clear_rules();
         self.font_slant.clear_rules();
         self.font_color.clear_rules();
         self.font_size.clear_rules();
         self.font_variation_settings.clear_rules();
+        self.font_feature_settings.clear_rules();
         self.selection_color.clear_rules();
         self.caret_color.clear_rules();
         self.text_decoration_line.clear_rules();
+        self.text_decoration_style.clear_rules();
+        self.text_decoration_color.clear_rules();
+        self.text_decoration_thickness.clear_rules();
         self.text_stroke_width.clear_rules();
         self.text_stroke_style.clear_rules();
+        self.letter_spacing.clear_rules();
+        self.word_spacing.clear_rules();
 
         self.cursor.clear_rules();
+        self.keyframe_animations.clear_rules();
 
         self.pointer_events.clear_rules();
 
         self.name.clear_rules();
 
         self.fill.clear_rules();
+        self.stroke.clear_rules();
+        self.stroke_width.clear_rules();
+        self.stroke_linecap.clear_rules();
+        self.stroke_linejoin.clear_rules();
     }
 }
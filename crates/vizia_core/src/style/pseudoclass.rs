@@ -29,6 +29,8 @@ bitflags! {
         const OPTIONAL = 1 << 18;
         const USER_VALID = 1 << 19;
         const USER_INVALID = 1 << 20;
+        const DRAGGING = 1 << 21;
+        const ACTIVE_DESCENDANT = 1 << 22;
     }
 }
 
@@ -0,0 +1,179 @@
+use hashbrown::HashMap;
+use web_time::{Duration, Instant};
+
+use super::Style;
+use crate::entity::Entity;
+
+/// How long a ripple takes to expand, and to fade out once it's released, if the view doesn't set a duration.
+pub(crate) const DEFAULT_RIPPLE_DURATION: Duration = Duration::from_millis(400);
+
+/// A ripple drawn over a view from where it was pressed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Ripple {
+    /// The center of the ripple within the bounds of the view, before the view is transformed.
+    pub center: (f32, f32),
+    start: Instant,
+    release: Option<Instant>,
+    // Whether the ripple expands from its center, rather than filling the view and fading in, which it does when
+    // motion is reduced.
+    expand: bool,
+}
+
+impl Ripple {
+    /// Returns the radius of the ripple, as a fraction of the distance to the farthest corner of the view, and its
+    /// opacity at the given time.
+    pub(crate) fn frame(&self, time: Instant, duration: Duration) -> (f32, f32) {
+        let progress = |from: Instant| {
+            if duration.is_zero() {
+                return 1.0;
+            }
+            (time.saturating_duration_since(from).as_secs_f32() / duration.as_secs_f32()).min(1.0)
+        };
+
+        let (radius, opacity) = if self.expand {
+            (1.0 - (1.0 - progress(self.start)).powi(3), 1.0)
+        } else {
+            (1.0, progress(self.start))
+        };
+
+        let fade = self.release.map_or(1.0, |release| 1.0 - progress(release));
+
+        (radius, opacity * fade)
+    }
+
+    pub(crate) fn is_released(&self) -> bool {
+        self.release.is_some()
+    }
+
+    fn is_finished(&self, time: Instant, duration: Duration) -> bool {
+        self.release.is_some_and(|release| time.saturating_duration_since(release) >= duration)
+    }
+}
+
+/// The ripples of the views which are pressed, or are fading out after they've been released.
+#[derive(Default)]
+pub(crate) struct Ripples {
+    ripples: HashMap<Entity, Vec<Ripple>>,
+}
+
+impl Ripples {
+    /// Starts a ripple over a view, which overlaps any ripples already over it.
+    pub(crate) fn press(
+        &mut self,
+        entity: Entity,
+        center: (f32, f32),
+        time: Instant,
+        expand: bool,
+    ) {
+        self.ripples.entry(entity).or_default().push(Ripple {
+            center,
+            start: time,
+            release: None,
+            expand,
+        });
+    }
+
+    /// Starts fading out the ripples of a view which haven't been released.
+    pub(crate) fn release(&mut self, entity: Entity, time: Instant) {
+        for ripple in self.ripples.get_mut(&entity).into_iter().flatten() {
+            ripple.release.get_or_insert(time);
+        }
+    }
+
+    pub(crate) fn get(&self, entity: Entity) -> Option<&[Ripple]> {
+        self.ripples.get(&entity).map(Vec::as_slice)
+    }
+
+    pub(crate) fn remove(&mut self, entity: Entity) {
+        self.ripples.remove(&entity);
+    }
+}
+
+impl Style {
+    /// Returns how long the ripples of a view take to expand and to fade out.
+    pub(crate) fn ripple_duration(&self, entity: Entity) -> Duration {
+        self.ripple_duration.get(entity).copied().unwrap_or(DEFAULT_RIPPLE_DURATION)
+    }
+
+    /// Removes the ripples which have faded out, returning the views which have ripples to redraw, including those
+    /// whose last ripple has just been removed.
+    pub(crate) fn tick_ripples(&mut self, time: Instant) -> Vec<Entity> {
+        let entities = self.ripples.ripples.keys().copied().collect::<Vec<_>>();
+        for entity in entities.iter() {
+            let duration = self.ripple_duration(*entity);
+            let ripples = self.ripples.ripples.get_mut(entity).unwrap();
+            ripples.retain(|ripple| !ripple.is_finished(time, duration));
+            if ripples.is_empty() {
+                self.ripples.remove(*entity);
+            }
+        }
+
+        entities
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DURATION: Duration = Duration::from_millis(400);
+
+    fn at(start: Instant, millis: u64) -> Instant {
+        start + Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn ripple_expands_and_fades_out_after_release() {
+        let start = Instant::now();
+        let mut ripples = Ripples::default();
+        let entity = Entity::root();
+        ripples.press(entity, (10.0, 20.0), start, true);
+        let ripple = |ripples: &Ripples| ripples.get(entity).unwrap()[0];
+
+        assert_eq!(ripple(&ripples).frame(start, DURATION), (0.0, 1.0));
+        let (radius, opacity) = ripple(&ripples).frame(at(start, 200), DURATION);
+        assert!(radius > 0.5 && radius < 1.0);
+        assert_eq!(opacity, 1.0);
+
+        // A held ripple stays at its full size.
+        assert_eq!(ripple(&ripples).frame(at(start, 1000), DURATION), (1.0, 1.0));
+
+        ripples.release(entity, at(start, 1000));
+        assert_eq!(ripple(&ripples).frame(at(start, 1200), DURATION), (1.0, 0.5));
+        assert_eq!(ripple(&ripples).frame(at(start, 1400), DURATION), (1.0, 0.0));
+    }
+
+    #[test]
+    fn reduced_motion_ripple_fades_in() {
+        let start = Instant::now();
+        let mut ripples = Ripples::default();
+        ripples.press(Entity::root(), (10.0, 20.0), start, false);
+
+        let ripple = ripples.get(Entity::root()).unwrap()[0];
+        assert_eq!(ripple.frame(start, DURATION), (1.0, 0.0));
+        assert_eq!(ripple.frame(at(start, 200), DURATION), (1.0, 0.5));
+    }
+
+    #[test]
+    fn finished_ripples_are_removed() {
+        let start = Instant::now();
+        let mut style = Style::default();
+        let entity = Entity::root();
+
+        // Rapid presses overlap, and a release only fades out the ripples which are still held.
+        style.ripples.press(entity, (0.0, 0.0), start, true);
+        style.ripples.release(entity, at(start, 50));
+        style.ripples.press(entity, (5.0, 5.0), at(start, 100), true);
+        assert_eq!(style.tick_ripples(at(start, 100)), [entity]);
+        assert_eq!(style.ripples.get(entity).unwrap().len(), 2);
+
+        style.ripples.release(entity, at(start, 300));
+        assert_eq!(style.tick_ripples(at(start, 450)), [entity]);
+        assert_eq!(style.ripples.get(entity).unwrap().len(), 1);
+
+        // The view is redrawn once more after its last ripple is removed.
+        assert_eq!(style.tick_ripples(at(start, 700)), [entity]);
+        assert!(style.ripples.get(entity).is_none());
+        assert!(style.tick_ripples(at(start, 800)).is_empty());
+    }
+}
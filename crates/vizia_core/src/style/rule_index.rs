@@ -0,0 +1,219 @@
+use std::cell::OnceCell;
+
+use hashbrown::{HashMap, HashSet};
+use indexmap::IndexMap;
+use vizia_style::{
+    selectors::parser::{Component, Selector},
+    SelectorList, Selectors,
+};
+
+use super::Rule;
+
+/// An index of the style rules by the names their selectors require of the view they apply to, so a view is only
+/// matched against the rules which could apply to it.
+///
+/// Each selector is keyed by the id, a class or the element name required by its rightmost compound selector, in
+/// that order of preference, or is a candidate for every view if it requires none of them. The index is built the
+/// first time rules are matched after they change, rather than as each stylesheet is parsed, so the themes loaded
+/// at startup are only indexed once, when the first frame is styled.
+#[derive(Default)]
+pub(crate) struct RuleIndex {
+    buckets: OnceCell<Buckets>,
+}
+
+#[derive(Default)]
+struct Buckets {
+    ids: HashMap<String, Vec<usize>>,
+    classes: HashMap<String, Vec<usize>>,
+    elements: HashMap<String, Vec<usize>>,
+    // The rules with a selector which requires no name, which are candidates for every view.
+    universal: Vec<usize>,
+}
+
+impl Buckets {
+    fn new(rules: &IndexMap<Rule, SelectorList<Selectors>>) -> Self {
+        let mut buckets = Buckets::default();
+        for (position, selectors) in rules.values().enumerate() {
+            for selector in selectors.slice() {
+                let bucket = match selector_key(selector) {
+                    Some(Component::ID(id)) => buckets.ids.entry(id.0.clone()).or_default(),
+                    Some(Component::Class(class)) => {
+                        buckets.classes.entry(class.0.clone()).or_default()
+                    }
+                    Some(Component::LocalName(local_name)) => {
+                        buckets.elements.entry(local_name.name.0.clone()).or_default()
+                    }
+                    _ => &mut buckets.universal,
+                };
+
+                // A rule with several selectors with the same key is only listed once.
+                if bucket.last() != Some(&position) {
+                    bucket.push(position);
+                }
+            }
+        }
+
+        buckets
+    }
+}
+
+// Returns the most selective component of the rightmost compound selector which a view must have a name for.
+fn selector_key(selector: &Selector<Selectors>) -> Option<&Component<Selectors>> {
+    let compound = selector
+        .iter_raw_match_order()
+        .take_while(|component| !matches!(component, Component::Combinator(_)));
+
+    let mut key = None;
+    for component in compound {
+        match component {
+            Component::ID(_) => return Some(component),
+            Component::Class(_) => key = Some(component),
+            Component::LocalName(_) if key.is_none() => key = Some(component),
+            _ => {}
+        }
+    }
+
+    key
+}
+
+impl RuleIndex {
+    /// Discards the index, which is built again from the rules when they're next matched.
+    pub(crate) fn invalidate(&mut self) {
+        self.buckets.take();
+    }
+
+    /// Returns the positions of the rules which could match a view with the given id, classes and element name, in
+    /// the order of the rules.
+    pub(crate) fn candidates(
+        &self,
+        rules: &IndexMap<Rule, SelectorList<Selectors>>,
+        id: Option<&String>,
+        classes: Option<&HashSet<String>>,
+        element: Option<&str>,
+    ) -> Vec<usize> {
+        let buckets = self.buckets.get_or_init(|| Buckets::new(rules));
+
+        let mut candidates = buckets.universal.clone();
+        if let Some(rules) = id.and_then(|id| buckets.ids.get(id)) {
+            candidates.extend_from_slice(rules);
+        }
+        for class in classes.into_iter().flatten() {
+            if let Some(rules) = buckets.classes.get(class) {
+                candidates.extend_from_slice(rules);
+            }
+        }
+        if let Some(rules) = element.and_then(|element| buckets.elements.get(element)) {
+            candidates.extend_from_slice(rules);
+        }
+
+        candidates.sort_unstable();
+        candidates.dedup();
+        candidates
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::style::Rule;
+    use crate::systems::{compute_matched_rules, match_rules, style_system};
+
+    // Matches an entity against every rule, which the index must agree with.
+    fn match_all_rules(cx: &Context, entity: Entity) -> Vec<(Rule, u32)> {
+        let mut matched_rules = Vec::new();
+        match_rules(cx, entity, 0..cx.style.rules.len(), &mut matched_rules);
+        matched_rules
+    }
+
+    fn match_indexed_rules(cx: &Context, entity: Entity) -> Vec<(Rule, u32)> {
+        let mut matched_rules = Vec::new();
+        compute_matched_rules(cx, entity, &mut matched_rules);
+        matched_rules
+    }
+
+    #[test]
+    fn indexed_matching_is_identical_to_matching_all_rules() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(
+            r#"
+            * { opacity: 0.9; }
+            element { opacity: 0.8; }
+            .a { opacity: 0.7; }
+            .a.b, #first { opacity: 0.6; }
+            vstack > .b { opacity: 0.5; }
+            element:hover, label { opacity: 0.4; }
+            :is(.c, .d) { opacity: 0.3; }
+            :not(.a) { opacity: 0.2; }
+            #first.a element, .b { opacity: 0.1; }
+            @media (min-width: 0px) { .a { opacity: 0.05; } }
+            "#,
+        )
+        .unwrap();
+
+        let mut entities = Vec::new();
+        VStack::new(&mut cx, |cx| {
+            entities.push(Element::new(cx).class("a").id("first").entity());
+            entities.push(Element::new(cx).class("a").class("b").entity());
+            entities.push(Label::new(cx, "label").class("d").entity());
+            entities.push(Element::new(cx).entity());
+        });
+        style_system(&mut cx);
+
+        for entity in entities.iter() {
+            assert_eq!(match_indexed_rules(&cx, *entity), match_all_rules(&cx, *entity));
+        }
+
+        // The index is rebuilt when rules are added.
+        let matched = match_indexed_rules(&cx, entities[1]).len();
+        cx.add_stylesheet(".b { opacity: 0; }").unwrap();
+        style_system(&mut cx);
+        assert_eq!(match_indexed_rules(&cx, entities[1]), match_all_rules(&cx, entities[1]));
+        assert_eq!(match_indexed_rules(&cx, entities[1]).len(), matched + 1);
+    }
+
+    // Run with `cargo test --release -p vizia_core -- --ignored --nocapture large_stylesheet`.
+    #[test]
+    #[ignore]
+    fn large_stylesheet_startup_benchmark() {
+        let mut cx = Context::default();
+        cx.remove_user_themes();
+
+        let mut stylesheet = String::new();
+        for i in 0..2000 {
+            match i % 4 {
+                0 => stylesheet += &format!(".generated-{i} {{ background-color: red; }}\n"),
+                1 => stylesheet += &format!(".panel .generated-{i}:hover {{ opacity: 0.5; }}\n"),
+                2 => stylesheet += &format!("#generated-{i} {{ width: {i}px; }}\n"),
+                _ => stylesheet += &format!("element.generated-{i} > label {{ color: blue; }}\n"),
+            }
+        }
+        cx.add_stylesheet(CSS::String(stylesheet)).unwrap();
+        println!("{} rules", cx.style.rules.len());
+
+        let mut entities = Vec::new();
+        VStack::new(&mut cx, |cx| {
+            for i in 0..500 {
+                entities.push(
+                    Element::new(cx).class("panel").class(&format!("generated-{i}")).entity(),
+                );
+                entities.push(Button::new(cx, |cx| Label::new(cx, "Button")).entity());
+            }
+        });
+
+        let start = Instant::now();
+        for entity in entities.iter() {
+            match_all_rules(&cx, *entity);
+        }
+        println!("Matching every rule: {:?}", start.elapsed());
+
+        let start = Instant::now();
+        style_system(&mut cx);
+        println!("First style pass, including building the index: {:?}", start.elapsed());
+
+        let start = Instant::now();
+        for entity in entities.iter() {
+            match_indexed_rules(&cx, *entity);
+        }
+        println!("Matching the indexed rules: {:?}", start.elapsed());
+    }
+}
@@ -0,0 +1,233 @@
+use skia_safe::Matrix;
+use vizia_storage::LayoutChildIterator;
+
+use super::Style;
+use crate::cache::CachedData;
+use crate::prelude::*;
+
+// The thickness of the scrollbars of a view which scrolls its overflow, and the space between them and its edges, in
+// logical pixels.
+const SCROLLBAR_THICKNESS: f32 = 6.0;
+const SCROLLBAR_MARGIN: f32 = 2.0;
+// The shortest length of the thumb of a scrollbar, so that it can still be grabbed when the content is very long.
+const MIN_THUMB_LENGTH: f32 = 16.0;
+
+/// The scroll position of a view with `overflow: scroll` or `overflow: auto`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct ScrollState {
+    /// The distances the content is scrolled horizontally and vertically, in physical pixels.
+    pub offset: (f32, f32),
+    /// The scrollbar being dragged, with the position of the cursor along it and the scroll offset along it when the
+    /// drag started.
+    pub drag: Option<(Orientation, f32, f32)>,
+}
+
+/// The track and thumb of a scrollbar, in the coordinates of its view before the view is transformed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ScrollbarGeometry {
+    pub orientation: Orientation,
+    pub track: BoundingBox,
+    pub thumb: BoundingBox,
+}
+
+impl ScrollbarGeometry {
+    /// Returns the distance the content is scrolled by moving the thumb by one pixel.
+    pub(crate) fn scroll_per_pixel(&self, range: f32) -> f32 {
+        let free = match self.orientation {
+            Orientation::Horizontal => self.track.w - self.thumb.w,
+            Orientation::Vertical => self.track.h - self.thumb.h,
+        };
+
+        if free > 0.0 {
+            range / free
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Style {
+    /// Returns whether a view scrolls its overflow horizontally and vertically.
+    pub(crate) fn scrolls(&self, entity: Entity) -> (bool, bool) {
+        (
+            self.overflowx.get(entity).is_some_and(Overflow::is_scrollable),
+            self.overflowy.get(entity).is_some_and(Overflow::is_scrollable),
+        )
+    }
+}
+
+/// Returns how far the content of a view can be scrolled horizontally and vertically, in physical pixels, which is
+/// how far its children overflow its right and bottom edges along each axis it scrolls.
+pub(crate) fn scroll_range(
+    style: &Style,
+    cache: &CachedData,
+    tree: &Tree<Entity>,
+    entity: Entity,
+) -> (f32, f32) {
+    let (scroll_x, scroll_y) = style.scrolls(entity);
+    if !scroll_x && !scroll_y {
+        return (0.0, 0.0);
+    }
+
+    // The bounds of the children aren't scrolled, as scrolling only translates them when they're drawn.
+    let bounds = cache.get_bounds(entity);
+    let content = LayoutChildIterator::new(tree, entity)
+        .filter(|child| style.display.get(*child).copied().unwrap_or_default() != Display::None)
+        .filter_map(|child| cache.bounds.get(child))
+        .fold(bounds, |content, child_bounds| content.union(child_bounds));
+
+    (
+        if scroll_x { (content.right() - bounds.right()).max(0.0) } else { 0.0 },
+        if scroll_y { (content.bottom() - bounds.bottom()).max(0.0) } else { 0.0 },
+    )
+}
+
+/// Returns the distances the content of a view is scrolled, clamped to how far it can currently be scrolled.
+pub(crate) fn scroll_offset(
+    style: &Style,
+    cache: &CachedData,
+    tree: &Tree<Entity>,
+    entity: Entity,
+) -> (f32, f32) {
+    let Some(state) = style.scroll_states.get(entity) else {
+        return (0.0, 0.0);
+    };
+
+    let (range_x, range_y) = scroll_range(style, cache, tree, entity);
+    (state.offset.0.clamp(0.0, range_x), state.offset.1.clamp(0.0, range_y))
+}
+
+/// Returns the translation of the children of a view by the distances its content is scrolled.
+pub(crate) fn scroll_transform(
+    style: &Style,
+    cache: &CachedData,
+    tree: &Tree<Entity>,
+    entity: Entity,
+) -> Matrix {
+    let (x, y) = scroll_offset(style, cache, tree, entity);
+    Matrix::translate((-x.round(), -y.round()))
+}
+
+/// Returns the scrollbars of a view which scrolls its overflow. A scrollbar is always shown along an axis with
+/// `overflow: scroll`, and only while the content overflows along an axis with `overflow: auto`.
+pub(crate) fn scrollbars(
+    style: &Style,
+    cache: &CachedData,
+    tree: &Tree<Entity>,
+    entity: Entity,
+) -> Vec<ScrollbarGeometry> {
+    let (range_x, range_y) = scroll_range(style, cache, tree, entity);
+    let (offset_x, offset_y) = scroll_offset(style, cache, tree, entity);
+    let shown = |overflow: Option<&Overflow>, range: f32| match overflow {
+        Some(Overflow::Scroll) => true,
+        Some(Overflow::Auto) => range > 0.0,
+        _ => false,
+    };
+    let horizontal = shown(style.overflowx.get(entity), range_x);
+    let vertical = shown(style.overflowy.get(entity), range_y);
+
+    let bounds = cache.get_bounds(entity);
+    let thickness = style.logical_to_physical(SCROLLBAR_THICKNESS);
+    let margin = style.logical_to_physical(SCROLLBAR_MARGIN);
+    let min_thumb = style.logical_to_physical(MIN_THUMB_LENGTH);
+
+    // The length of the thumb is the fraction of the content which is visible, and its position is the fraction of
+    // the range which is scrolled.
+    let thumb = |track: f32, visible: f32, range: f32, offset: f32| {
+        let length = (track * visible / (visible + range)).max(min_thumb).min(track);
+        let position = if range > 0.0 { (track - length) * offset / range } else { 0.0 };
+        (position, length)
+    };
+
+    // Where both scrollbars are shown they stop short of the corner between them.
+    let corner = thickness + margin;
+    let mut scrollbars = Vec::new();
+    if horizontal {
+        let track = BoundingBox {
+            x: bounds.left() + margin,
+            y: bounds.bottom() - margin - thickness,
+            w: (bounds.w - 2.0 * margin - if vertical { corner } else { 0.0 }).max(0.0),
+            h: thickness,
+        };
+        let (position, length) = thumb(track.w, bounds.w, range_x, offset_x);
+        scrollbars.push(ScrollbarGeometry {
+            orientation: Orientation::Horizontal,
+            track,
+            thumb: BoundingBox { x: track.x + position, w: length, ..track },
+        });
+    }
+
+    if vertical {
+        let track = BoundingBox {
+            x: bounds.right() - margin - thickness,
+            y: bounds.top() + margin,
+            w: thickness,
+            h: (bounds.h - 2.0 * margin - if horizontal { corner } else { 0.0 }).max(0.0),
+        };
+        let (position, length) = thumb(track.h, bounds.h, range_y, offset_y);
+        scrollbars.push(ScrollbarGeometry {
+            orientation: Orientation::Vertical,
+            track,
+            thumb: BoundingBox { y: track.y + position, h: length, ..track },
+        });
+    }
+
+    scrollbars
+}
+
+/// Returns the scrollbar of a view at a point in the coordinates of the view before it's transformed.
+pub(crate) fn scrollbar_at(
+    style: &Style,
+    cache: &CachedData,
+    tree: &Tree<Entity>,
+    entity: Entity,
+    (x, y): (f32, f32),
+) -> Option<ScrollbarGeometry> {
+    scrollbars(style, cache, tree, entity).into_iter().find(|scrollbar| {
+        let track = scrollbar.track;
+        x >= track.left() && x < track.right() && y >= track.top() && y < track.bottom()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::{layout_system, style_system};
+
+    #[test]
+    fn overflowing_content_sets_the_scroll_range_and_scrollbars() {
+        let mut cx = Context::default();
+        cx.style.dpi_factor = 1.0;
+
+        let list = VStack::new(&mut cx, |cx| {
+            for _ in 0..10 {
+                Element::new(cx).width(Pixels(100.0)).height(Pixels(50.0));
+            }
+        })
+        .size(Pixels(200.0))
+        .overflow(Overflow::Auto)
+        .entity();
+        style_system(&mut cx);
+        layout_system(&mut cx);
+
+        // The rows overflow the bottom by 300px, so only a vertical scrollbar is shown.
+        assert_eq!(scroll_range(&cx.style, &cx.cache, &cx.tree, list), (0.0, 300.0));
+        let bars = scrollbars(&cx.style, &cx.cache, &cx.tree, list);
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].orientation, Orientation::Vertical);
+        assert_eq!(bars[0].track, BoundingBox { x: 192.0, y: 2.0, w: 6.0, h: 196.0 });
+        assert_eq!(bars[0].thumb.h, 196.0 * 200.0 / 500.0);
+
+        // Offsets beyond the range are clamped, which moves the thumb to the end of the track.
+        cx.style.scroll_states.insert(list, ScrollState { offset: (0.0, 1000.0), drag: None });
+        assert_eq!(scroll_offset(&cx.style, &cx.cache, &cx.tree, list), (0.0, 300.0));
+        let thumb = scrollbars(&cx.style, &cx.cache, &cx.tree, list)[0].thumb;
+        assert!((thumb.bottom() - 198.0).abs() < 0.01);
+
+        // A scrollbar is always shown along an axis with `overflow: scroll`.
+        cx.style.overflowx.insert(list, Overflow::Scroll);
+        let bars = scrollbars(&cx.style, &cx.cache, &cx.tree, list);
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].thumb, bars[0].track);
+    }
+}
@@ -0,0 +1,236 @@
+use hashbrown::HashMap;
+use vizia_style::Property;
+
+use super::{PseudoClassFlags, Rule, Style};
+use crate::prelude::*;
+
+/// A builder for the style properties of a view which apply while it is in a state, such as when it's hovered,
+/// created with modifiers like [`on_hover_style`](crate::modifiers::StyleModifiers::on_hover_style).
+///
+/// The properties take part in the cascade like those of a stylesheet rule with the matching pseudo-class. They
+/// override the properties of any stylesheet rule the view matches, but are overridden by properties set on the
+/// view with a modifier. If the view is in several states, the properties of the style set last take precedence.
+///
+/// A transition animates a property when the view enters the state. When it leaves the state, the transitions of
+/// the rules the view then matches apply, as for any other change of rule.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StateStyleBuilder {
+    pub(crate) properties: Vec<Property<'static>>,
+    pub(crate) transitions: Vec<Transition>,
+}
+
+impl StateStyleBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn background_color(mut self, color: impl Into<Color>) -> Self {
+        self.properties.push(Property::BackgroundColor(color.into()));
+
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<ColorOrGradient>) -> Self {
+        self.properties.push(Property::FontColor(color.into()));
+
+        self
+    }
+
+    pub fn border_color(mut self, color: impl Into<ColorOrGradient>) -> Self {
+        self.properties.push(Property::BorderColor(color.into()));
+
+        self
+    }
+
+    pub fn outline_color(mut self, color: impl Into<ColorOrGradient>) -> Self {
+        self.properties.push(Property::OutlineColor(color.into()));
+
+        self
+    }
+
+    pub fn opacity(mut self, opacity: impl Into<Opacity>) -> Self {
+        self.properties.push(Property::Opacity(opacity.into()));
+
+        self
+    }
+
+    pub fn cursor(mut self, cursor: CursorIcon) -> Self {
+        self.properties.push(Property::Cursor(cursor));
+
+        self
+    }
+
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transitions.push(transition);
+
+        self
+    }
+}
+
+impl Style {
+    /// Adds a style which applies to an entity while it has all of the given pseudo-classes.
+    ///
+    /// The values of the style are held by a rule which isn't matched by selectors, so is shared by all of the
+    /// entities with an equal style.
+    pub(crate) fn add_state_style(
+        &mut self,
+        entity: Entity,
+        state: PseudoClassFlags,
+        builder: StateStyleBuilder,
+    ) {
+        let rule = match self.state_rules.iter().find(|(existing, _)| *existing == builder) {
+            Some((_, rule)) => *rule,
+            None => {
+                let rule = self.insert_state_rule(&builder);
+                self.state_rules.push((builder, rule));
+                rule
+            }
+        };
+
+        if let Some(state_styles) = self.state_styles.get_mut(entity) {
+            state_styles.push((state, rule));
+        } else {
+            self.state_styles.insert(entity, vec![(state, rule)]);
+        }
+    }
+
+    fn insert_state_rule(&mut self, builder: &StateStyleBuilder) -> Rule {
+        let rule = self.rule_manager.create();
+
+        for property in builder.properties.iter() {
+            self.insert_property(rule, property.clone());
+        }
+
+        for transition in builder.transitions.iter() {
+            self.insert_transition(rule, transition);
+        }
+
+        rule
+    }
+
+    /// Inserts the rules of the state styles again after the rules of the stylesheets have been removed, which also
+    /// removes the rules of the state styles.
+    pub(crate) fn reinsert_state_rules(&mut self) {
+        let mut state_rules = std::mem::take(&mut self.state_rules);
+        let mut replaced = HashMap::new();
+        for (builder, rule) in state_rules.iter_mut() {
+            let new_rule = self.insert_state_rule(builder);
+            replaced.insert(*rule, new_rule);
+            *rule = new_rule;
+        }
+        self.state_rules = state_rules;
+
+        for entry in self.state_styles.dense.iter_mut() {
+            for (_, rule) in entry.value.iter_mut() {
+                *rule = replaced[rule];
+            }
+        }
+    }
+
+    /// Returns the matched rules of an entity with the rules of the state styles it's in placed first, with the
+    /// style added last first.
+    ///
+    /// The matched rules are in order of decreasing specificity.
+    pub(crate) fn add_state_rules(&self, entity: Entity, matched_rules: Vec<Rule>) -> Vec<Rule> {
+        let Some(state_styles) = self.state_styles.get(entity) else {
+            return matched_rules;
+        };
+
+        let pseudo_classes = self.pseudo_classes.get(entity).copied().unwrap_or_default();
+        state_styles
+            .iter()
+            .rev()
+            .filter(|(state, _)| pseudo_classes.contains(*state))
+            .map(|(_, rule)| *rule)
+            .chain(matched_rules)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::style::PseudoClassFlags;
+    use crate::systems::style_system;
+
+    fn set_state(cx: &mut Context, entity: Entity, state: PseudoClassFlags, value: bool) {
+        cx.style.pseudo_classes.get_mut(entity).unwrap().set(state, value);
+        cx.needs_restyle(entity);
+        style_system(cx);
+    }
+
+    #[test]
+    fn hover_style_follows_the_hover_state() {
+        let mut cx = Context::default();
+        let element = Element::new(&mut cx)
+            .on_hover_style(|s| {
+                s.background_color(Color::red()).cursor(CursorIcon::Hand).transition(
+                    Transition::new(
+                        "background-color".to_owned(),
+                        Duration::from_millis(100),
+                        None,
+                        None,
+                    ),
+                )
+            })
+            .entity();
+
+        // The state rules are inserted again when the stylesheets are reloaded.
+        cx.add_stylesheet("element { background-color: blue; }").unwrap();
+        style_system(&mut cx);
+        assert_eq!(cx.style.background_color.get(element), Some(&Color::blue()));
+        assert_eq!(cx.style.cursor.get(element), None);
+
+        set_state(&mut cx, element, PseudoClassFlags::HOVER, true);
+        assert_eq!(cx.style.cursor.get(element), Some(&CursorIcon::Hand));
+        assert!(cx.style.background_color.get_active_animation(element).is_some());
+
+        cx.style
+            .background_color
+            .tick(Instant::now() + Duration::from_secs(1), ColorSpace::default());
+        cx.style.background_color.remove_innactive_animations();
+        assert_eq!(cx.style.background_color.get(element), Some(&Color::red()));
+
+        set_state(&mut cx, element, PseudoClassFlags::HOVER, false);
+        assert_eq!(cx.style.background_color.get(element), Some(&Color::blue()));
+        assert_eq!(cx.style.cursor.get(element), None);
+    }
+
+    #[test]
+    fn state_styles_and_stylesheet_precedence() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(
+            r#"
+            element { background-color: blue; }
+            element:hover { background-color: green; cursor: text; }
+            "#,
+        )
+        .unwrap();
+
+        let element = Element::new(&mut cx)
+            .on_hover_style(|s| s.background_color(Color::red()))
+            .on_active_style(|s| s.background_color(Color::white()))
+            .entity();
+        let inline = Element::new(&mut cx)
+            .background_color(Color::black())
+            .on_hover_style(|s| s.background_color(Color::red()))
+            .entity();
+        style_system(&mut cx);
+
+        // A state style overrides the stylesheet rules, including those for the same state.
+        set_state(&mut cx, element, PseudoClassFlags::HOVER, true);
+        assert_eq!(cx.style.background_color.get(element), Some(&Color::red()));
+        assert_eq!(cx.style.cursor.get(element), Some(&CursorIcon::Text));
+
+        // The state style set last takes precedence.
+        set_state(&mut cx, element, PseudoClassFlags::ACTIVE, true);
+        assert_eq!(cx.style.background_color.get(element), Some(&Color::white()));
+
+        // Values set with modifiers override state styles.
+        set_state(&mut cx, inline, PseudoClassFlags::HOVER, true);
+        assert_eq!(cx.style.background_color.get(inline), Some(&Color::black()));
+
+        // Equal state styles share a rule.
+        assert_eq!(cx.style.state_rules.len(), 2);
+    }
+}
@@ -1,8 +1,67 @@
 use skia_safe::Matrix;
-use vizia_style::{Angle, Scale, Transform, Translate};
+use vizia_style::{Angle, LengthContext, Scale, Transform, Translate};
 
+use super::Style;
+use crate::animation::Interpolator;
+use crate::entity::Entity;
 use crate::layout::BoundingBox;
 
+impl Style {
+    /// Returns the transform of an entity within the coordinates of its layout parent, combining its translate,
+    /// rotate, scale and transform properties about its transform origin.
+    ///
+    /// The transform of an entity in window coordinates is the transform of its parent multiplied by this transform,
+    /// which is used both to draw the entity and to hit-test the cursor against it.
+    pub(crate) fn local_transform(&self, entity: Entity, bounds: BoundingBox) -> Matrix {
+        let scale_factor = self.scale_factor();
+
+        // Apply transform origin.
+        let origin = self
+            .transform_origin
+            .get(entity)
+            .map(|transform_origin| {
+                let offset = transform_origin.as_transform(bounds, scale_factor);
+                offset * Matrix::translate(bounds.top_left())
+            })
+            .unwrap_or(Matrix::translate(bounds.center()));
+
+        let mut transform = origin;
+
+        // Apply translation.
+        if let Some(translate) = self.translate.get(entity) {
+            transform = transform * translate.as_transform(bounds, scale_factor);
+        }
+
+        // Apply rotation.
+        if let Some(rotate) = self.rotate.get(entity) {
+            transform = transform * rotate.as_transform(bounds, scale_factor);
+        }
+
+        // Apply scaling.
+        if let Some(scale) = self.scale.get(entity) {
+            transform = transform * scale.as_transform(bounds, scale_factor);
+        }
+
+        // Apply transform functions, interpolating between the keyframes of an active animation.
+        if let Some(transforms) = self.transform.get(entity) {
+            if let Some(animation_state) = self.transform.get_active_animation(entity) {
+                if let (Some(start), Some(end)) =
+                    (animation_state.keyframes.first(), animation_state.keyframes.last())
+                {
+                    let start_transform = start.value.as_transform(bounds, scale_factor);
+                    let end_transform = end.value.as_transform(bounds, scale_factor);
+                    transform = transform
+                        * Matrix::interpolate(&start_transform, &end_transform, animation_state.t);
+                }
+            } else {
+                transform = transform * transforms.as_transform(bounds, scale_factor);
+            }
+        }
+
+        transform * origin.invert().unwrap_or_default()
+    }
+}
+
 /// Trait for converting a transform definition into a `Matrix`.
 pub(crate) trait IntoTransform {
     fn as_transform(&self, bounds: BoundingBox, scale_factor: f32) -> Matrix;
@@ -36,76 +95,215 @@ impl IntoTransform for Angle {
 
 impl IntoTransform for Vec<Transform> {
     fn as_transform(&self, bounds: BoundingBox, scale_factor: f32) -> Matrix {
-        let mut result = Matrix::new_identity();
+        // The functions are composed in 3D so that a perspective applies to the rotations and translations which
+        // follow it, and the result is then flattened onto the plane of the view.
+        let mut result = IDENTITY44;
         for transform in self.iter() {
             let t = match transform {
                 Transform::Translate(translate) => {
                     let tx = translate.0.to_pixels(bounds.w, scale_factor);
                     let ty = translate.1.to_pixels(bounds.h, scale_factor);
 
-                    Matrix::translate((tx, ty))
+                    matrix44(Matrix::translate((tx, ty)))
                 }
 
                 Transform::TranslateX(x) => {
                     let tx = x.to_pixels(bounds.w, scale_factor);
 
-                    Matrix::translate((tx, 0.0))
+                    matrix44(Matrix::translate((tx, 0.0)))
                 }
 
                 Transform::TranslateY(y) => {
                     let ty = y.to_pixels(bounds.h, scale_factor);
 
-                    Matrix::translate((0.0, ty))
+                    matrix44(Matrix::translate((0.0, ty)))
+                }
+
+                Transform::Translate3d((x, y, z)) => {
+                    let mut m = IDENTITY44;
+                    m[0][3] = x.to_pixels(bounds.w, scale_factor);
+                    m[1][3] = y.to_pixels(bounds.h, scale_factor);
+                    m[2][3] = z.resolve(&LengthContext::default()) * scale_factor;
+                    m
                 }
 
                 Transform::Scale(scale) => {
                     let sx = scale.0.to_factor();
                     let sy = scale.1.to_factor();
 
-                    Matrix::scale((sx, sy))
+                    matrix44(Matrix::scale((sx, sy)))
                 }
 
                 Transform::ScaleX(x) => {
                     let sx = x.to_factor();
 
-                    Matrix::scale((sx, 1.0))
+                    matrix44(Matrix::scale((sx, 1.0)))
                 }
 
                 Transform::ScaleY(y) => {
                     let sy = y.to_factor();
 
-                    Matrix::scale((1.0, sy))
+                    matrix44(Matrix::scale((1.0, sy)))
                 }
 
-                Transform::Rotate(angle) => Matrix::rotate_rad(angle.to_radians()),
+                Transform::Rotate(angle) => matrix44(Matrix::rotate_rad(angle.to_radians())),
+
+                Transform::RotateX(angle) => {
+                    let (sin, cos) = angle.to_radians().sin_cos();
+
+                    let mut m = IDENTITY44;
+                    m[1][1] = cos;
+                    m[1][2] = -sin;
+                    m[2][1] = sin;
+                    m[2][2] = cos;
+                    m
+                }
+
+                Transform::RotateY(angle) => {
+                    let (sin, cos) = angle.to_radians().sin_cos();
+
+                    let mut m = IDENTITY44;
+                    m[0][0] = cos;
+                    m[0][2] = sin;
+                    m[2][0] = -sin;
+                    m[2][2] = cos;
+                    m
+                }
+
+                Transform::Perspective(distance) => {
+                    let distance = distance.resolve(&LengthContext::default()).max(1.0);
+
+                    let mut m = IDENTITY44;
+                    m[3][2] = -1.0 / (distance * scale_factor);
+                    m
+                }
 
                 Transform::Skew(x, y) => {
                     let cx = x.to_radians().tan();
                     let cy = y.to_radians().tan();
 
-                    Matrix::skew((cx, cy))
+                    matrix44(Matrix::skew((cx, cy)))
                 }
 
                 Transform::SkewX(angle) => {
                     let cx = angle.to_radians().tan();
 
-                    Matrix::skew((cx, 0.0))
+                    matrix44(Matrix::skew((cx, 0.0)))
                 }
 
                 Transform::SkewY(angle) => {
                     let cy = angle.to_radians().tan();
 
-                    Matrix::skew((0.0, cy))
+                    matrix44(Matrix::skew((0.0, cy)))
                 }
 
-                Transform::Matrix(matrix) => Matrix::new_all(
+                Transform::Matrix(matrix) => matrix44(Matrix::new_all(
                     matrix.a, matrix.c, matrix.e, matrix.b, matrix.d, matrix.f, 0.0, 0.0, 1.0,
-                ),
+                )),
             };
 
-            result = result * t;
+            result = multiply44(&result, &t);
         }
 
-        result
+        flatten44(&result)
+    }
+}
+
+// A 4x4 matrix, stored as rows, which transforms points in 3D.
+type Matrix44 = [[f32; 4]; 4];
+
+const IDENTITY44: Matrix44 =
+    [[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0], [0.0, 0.0, 0.0, 1.0]];
+
+// Returns the 3D matrix of a 2D matrix, which leaves the z coordinate unchanged.
+fn matrix44(m: Matrix) -> Matrix44 {
+    [
+        [m[0], m[1], 0.0, m[2]],
+        [m[3], m[4], 0.0, m[5]],
+        [0.0, 0.0, 1.0, 0.0],
+        [m[6], m[7], 0.0, m[8]],
+    ]
+}
+
+fn multiply44(a: &Matrix44, b: &Matrix44) -> Matrix44 {
+    let mut result = [[0.0; 4]; 4];
+    for (row, result_row) in result.iter_mut().enumerate() {
+        for (column, value) in result_row.iter_mut().enumerate() {
+            *value = (0..4).map(|i| a[row][i] * b[i][column]).sum();
+        }
+    }
+    result
+}
+
+// Returns the 2D matrix which maps points on the plane of the view as the 3D matrix does, before the z coordinate is
+// discarded, by dropping the z row and column.
+fn flatten44(m: &Matrix44) -> Matrix {
+    Matrix::new_all(m[0][0], m[0][1], m[0][3], m[1][0], m[1][1], m[1][3], m[3][0], m[3][1], m[3][3])
+}
+
+#[cfg(test)]
+mod tests {
+    use vizia_style::{Length, LengthOrPercentage};
+
+    use super::*;
+
+    const BOUNDS: BoundingBox = BoundingBox { x: 0.0, y: 0.0, w: 100.0, h: 100.0 };
+
+    fn map(transforms: Vec<Transform>, point: (f32, f32)) -> (f32, f32) {
+        let point = transforms.as_transform(BOUNDS, 1.0).map_point(point);
+        (point.x, point.y)
+    }
+
+    fn assert_close(actual: (f32, f32), expected: (f32, f32)) {
+        assert!(
+            (actual.0 - expected.0).abs() < 1e-3 && (actual.1 - expected.1).abs() < 1e-3,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn skews_are_applied_in_order() {
+        let transforms = vec![
+            Transform::TranslateX(LengthOrPercentage::px(10.0)),
+            Transform::SkewX(Angle::Deg(45.0)),
+            Transform::SkewY(Angle::Deg(45.0)),
+        ];
+        assert_close(map(transforms, (10.0, 20.0)), (50.0, 30.0));
+    }
+
+    #[test]
+    fn rotations_about_the_plane_are_foreshortened_by_perspective() {
+        // Without a perspective a rotation about the y axis narrows the view evenly.
+        let rotate = Transform::RotateY(Angle::Deg(60.0));
+        assert_close(map(vec![rotate.clone()], (50.0, 10.0)), (25.0, 10.0));
+        assert_close(map(vec![rotate.clone()], (-50.0, 10.0)), (-25.0, 10.0));
+
+        // With a perspective the side turned towards the viewer is larger.
+        let perspective = Transform::Perspective(Length::px(100.0));
+        let w = 1.0 + 0.5 * 3f32.sqrt() / 2.0;
+        assert_close(
+            map(vec![perspective.clone(), rotate.clone()], (50.0, 10.0)),
+            (25.0 / w, 10.0 / w),
+        );
+        let w = 1.0 - 0.5 * 3f32.sqrt() / 2.0;
+        assert_close(map(vec![perspective, rotate], (-50.0, 10.0)), (-25.0 / w, 10.0 / w));
+    }
+
+    #[test]
+    fn translation_towards_the_viewer_magnifies_with_perspective() {
+        let translate = Transform::Translate3d((
+            LengthOrPercentage::px(0.0),
+            LengthOrPercentage::px(0.0),
+            Length::px(50.0),
+        ));
+        assert_close(map(vec![translate.clone()], (10.0, 20.0)), (10.0, 20.0));
+        assert_close(
+            map(vec![Transform::Perspective(Length::px(100.0)), translate], (10.0, 20.0)),
+            (20.0, 40.0),
+        );
+
+        // A rotation about the x axis by a quarter turn leaves the view edge on.
+        let rotate = Transform::RotateX(Angle::Deg(90.0));
+        assert_close(map(vec![rotate], (10.0, 20.0)), (10.0, 0.0));
     }
 }
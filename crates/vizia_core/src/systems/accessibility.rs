@@ -3,66 +3,56 @@ use accesskit::{NodeBuilder, NodeId, Rect, Toggled, TreeUpdate};
 use hashbrown::HashMap;
 use vizia_storage::LayoutTreeIterator;
 
-/// Updates node properties from view properties
-/// Should be run after layout so that things like bounding box are correct.
-/// This system doesn't change the structure of the accessibility tree as this is done when views are built/removed.
+/// Sends the nodes of the entities whose accessibility properties, bounds or children have changed since the last
+/// update to the accessibility tree, in a single update.
+///
+/// Entities are marked with `needs_access_update`, and a view which is built or removed marks its layout parent, so
+/// the children of the parent are updated. Nodes which are no longer the child of any node are removed from the tree.
 pub(crate) fn accessibility_system(cx: &mut Context) {
-    if !cx.style.reaccess.is_empty() {
-        let iterator = LayoutTreeIterator::full(&cx.tree);
+    cx.access_nodes_updated = 0;
 
-        for entity in iterator {
-            if !cx.style.reaccess.contains(entity) {
-                continue;
-            }
+    if cx.style.reaccess.is_empty() {
+        return;
+    }
 
-            let mut access_context = AccessContext {
-                current: entity,
-                tree: &cx.tree,
-                cache: &cx.cache,
-                style: &cx.style,
-                text_context: &mut cx.text_context,
-            };
-
-            if let Some(node) = get_access_node(&mut access_context, &mut cx.views, entity) {
-                let navigable = cx
-                    .style
-                    .abilities
-                    .get(entity)
-                    .copied()
-                    .unwrap_or_default()
-                    .contains(Abilities::NAVIGABLE);
-
-                if node.node_builder.role() == Role::Unknown && !navigable {
-                    continue;
-                }
-
-                let mut nodes = vec![(node.node_id(), node.node_builder.build())];
-
-                // If child nodes were generated then append them to the nodes list
-                if !node.children.is_empty() {
-                    nodes.extend(
-                        node.children.into_iter().map(|child_node| {
-                            (child_node.node_id(), child_node.node_builder.build())
-                        }),
-                    );
-                }
-
-                cx.tree_updates.push(Some(TreeUpdate {
-                    nodes,
-                    tree: None,
-                    focus: if cx.window_has_focus {
-                        cx.focused.accesskit_id()
-                    } else {
-                        NodeId(0u64)
-                    },
-                }));
-            }
+    let mut nodes = Vec::new();
+    for entity in LayoutTreeIterator::full(&cx.tree) {
+        if !cx.style.reaccess.contains(entity) {
+            continue;
+        }
 
-            // }
+        let mut access_context = AccessContext {
+            current: entity,
+            tree: &cx.tree,
+            cache: &cx.cache,
+            style: &cx.style,
+            text_context: &mut cx.text_context,
+        };
+
+        if let Some(node) = get_access_node(&mut access_context, &mut cx.views, entity) {
+            nodes.push((node.node_id(), node.node_builder.build()));
+
+            // If child nodes were generated then append them to the nodes list
+            nodes.extend(
+                node.children
+                    .into_iter()
+                    .map(|child_node| (child_node.node_id(), child_node.node_builder.build())),
+            );
         }
+    }
 
-        cx.style.reaccess.clear();
+    cx.style.reaccess.clear();
+
+    if nodes.is_empty() {
+        return;
     }
+
+    cx.access_nodes_updated = nodes.len();
+    cx.tree_updates.push(Some(TreeUpdate {
+        nodes,
+        tree: None,
+        focus: if cx.window_has_focus { cx.focused.accesskit_id() } else { NodeId(0u64) },
+    }));
 }
 
 pub(crate) fn get_access_node(
@@ -126,6 +116,11 @@ pub(crate) fn get_access_node(
         }
     }
 
+    if let Some(expanded) = cx.style.expanded.get(entity).copied() {
+        node_builder.set_expanded(expanded);
+        node_builder.add_action(if expanded { Action::Collapse } else { Action::Expand });
+    }
+
     if let Some(live) = cx.style.live.get(entity) {
         node_builder.set_live(*live);
     }
@@ -185,3 +180,137 @@ pub(crate) fn get_access_node(
 
     Some(node)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+    use crate::systems::binding_system;
+    use accesskit::ActionRequest;
+
+    fn request_action(
+        cx: &mut Context,
+        event_manager: &mut EventManager,
+        entity: Entity,
+        action: Action,
+    ) {
+        let request = ActionRequest { action, target: entity.accesskit_id(), data: None };
+        cx.emit_custom(Event::new(WindowEvent::ActionRequest(request)).direct(entity));
+        event_manager.flush_events(cx, |_| {});
+        binding_system(cx);
+    }
+
+    #[test]
+    fn dropdown_expands_and_collapses_from_action_requests() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+
+        let dropdown = Dropdown::new(
+            &mut cx,
+            |cx| {
+                Label::new(cx, "Fruit");
+            },
+            |cx| {
+                Label::new(cx, "Apple");
+            },
+        )
+        .entity();
+        assert_eq!(cx.style.expanded.get(dropdown), Some(&false));
+
+        request_action(&mut cx, &mut event_manager, dropdown, Action::Expand);
+        assert_eq!(cx.style.expanded.get(dropdown), Some(&true));
+
+        request_action(&mut cx, &mut event_manager, dropdown, Action::Collapse);
+        assert_eq!(cx.style.expanded.get(dropdown), Some(&false));
+    }
+
+    #[derive(Lens)]
+    struct Greeting {
+        text: String,
+    }
+
+    impl Model for Greeting {}
+
+    fn updated_nodes(cx: &mut Context) -> Vec<(NodeId, accesskit::Node)> {
+        accessibility_system(cx);
+        let nodes =
+            cx.tree_updates.drain(..).flatten().flat_map(|update| update.nodes).collect::<Vec<_>>();
+        assert_eq!(nodes.len(), cx.access_nodes_updated());
+        nodes
+    }
+
+    #[test]
+    fn updates_only_contain_changed_nodes() {
+        let mut cx = Context::default();
+        Greeting { text: String::from("Hello") }.build(&mut cx);
+
+        let mut labels = Vec::new();
+        let list = VStack::new(&mut cx, |cx| {
+            for index in 0..100 {
+                labels.push(Label::new(cx, index.to_string()).entity());
+            }
+        })
+        .entity();
+        let greeting = Label::new(&mut cx, Greeting::text).entity();
+        binding_system(&mut cx);
+
+        // Every view is added in a single update.
+        assert_eq!(cx.tree_updates.len(), 0);
+        assert!(updated_nodes(&mut cx).len() > 100);
+        assert!(updated_nodes(&mut cx).is_empty());
+
+        // Changing the text of a label only updates its node.
+        cx.replace_model(Greeting { text: String::from("World") });
+        binding_system(&mut cx);
+        let nodes = updated_nodes(&mut cx);
+        assert_eq!(nodes.len(), 1);
+        assert_eq!(nodes[0].0, greeting.accesskit_id());
+        assert_eq!(nodes[0].1.name(), Some("World"));
+
+        // Removing a view updates the children of its parent.
+        cx.remove(labels[0]);
+        let nodes = updated_nodes(&mut cx);
+        assert_eq!(nodes.len(), 1);
+        assert_eq!(nodes[0].0, list.accesskit_id());
+        assert_eq!(nodes[0].1.children().len(), 99);
+
+        // Adding a view updates the new node and its parent.
+        cx.with_current(list, |cx| Label::new(cx, "New"));
+        let ids = updated_nodes(&mut cx).into_iter().map(|(id, _)| id).collect::<Vec<_>>();
+        assert_eq!(ids.len(), 2);
+        assert!(ids.contains(&list.accesskit_id()));
+    }
+
+    #[derive(Lens)]
+    struct Tabs {
+        tabs: Vec<&'static str>,
+    }
+
+    impl Model for Tabs {}
+
+    fn is_checked(cx: &Context, entity: Entity) -> bool {
+        cx.style
+            .pseudo_classes
+            .get(entity)
+            .is_some_and(|pseudo_classes| pseudo_classes.contains(PseudoClassFlags::CHECKED))
+    }
+
+    #[test]
+    fn tab_is_selected_from_default_action_request() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+
+        Tabs { tabs: vec!["One", "Two"] }.build(&mut cx);
+        TabView::new(&mut cx, Tabs::tabs, |_, _| TabPair::new(|_| {}, |_| {}));
+
+        let tabs = LayoutTreeIterator::full(&cx.tree)
+            .filter(|entity| cx.style.role.get(*entity) == Some(&Role::Tab))
+            .collect::<Vec<_>>();
+        assert_eq!(tabs.len(), 2);
+        assert!(is_checked(&cx, tabs[0]));
+
+        request_action(&mut cx, &mut event_manager, tabs[1], Action::Default);
+        assert!(!is_checked(&cx, tabs[0]));
+        assert!(is_checked(&cx, tabs[1]));
+    }
+}
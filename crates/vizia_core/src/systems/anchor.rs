@@ -0,0 +1,234 @@
+use log::warn;
+
+use crate::modifiers::{anchor_creates_cycle, AnchorRemoval};
+use crate::prelude::*;
+
+/// Positions anchored views relative to the views they are anchored to.
+///
+/// An anchored view is only positioned when it was just anchored, or when the geometry of it or its target changed
+/// during the last layout pass. Returns true if any anchored view was moved or resized and must be laid out again.
+pub(crate) fn anchor_system(cx: &mut Context) -> bool {
+    let changed = std::mem::take(&mut cx.cache.geometry_changed);
+    if cx.anchors.is_empty() {
+        return false;
+    }
+
+    let scale_factor = cx.scale_factor();
+    let mut needs_relayout = false;
+
+    let anchored = cx.anchors.keys().copied().collect::<Vec<_>>();
+    for entity in anchored {
+        let Some(anchor) = cx.anchors.get(&entity) else {
+            continue;
+        };
+
+        let Some(target) = anchor.target.resolve(cx) else {
+            continue;
+        };
+
+        if !anchor.dirty
+            && !anchor.hidden
+            && !changed.contains(&target)
+            && !changed.contains(&entity)
+        {
+            continue;
+        }
+
+        // A target given by id is only known once it has been built, so the anchor may only now close a cycle.
+        if (anchor.dirty || anchor.hidden) && anchor_creates_cycle(cx, entity, &anchor.target) {
+            warn!(
+                "Anchoring {} to {:?} would create a cycle, so the anchor is ignored",
+                entity, anchor.target
+            );
+            cx.anchors.remove(&entity);
+            continue;
+        }
+
+        let spec = anchor.spec;
+
+        // A view which was hidden is shown again and laid out before it is positioned.
+        if anchor.hidden {
+            cx.style.display.remove(entity);
+            cx.needs_restyle(entity);
+            cx.style.needs_relayout_entity(entity);
+            needs_relayout = true;
+            if let Some(anchor) = cx.anchors.get_mut(&entity) {
+                anchor.hidden = false;
+                anchor.dirty = true;
+            }
+
+            continue;
+        }
+
+        if let Some(anchor) = cx.anchors.get_mut(&entity) {
+            anchor.dirty = false;
+        }
+
+        let target_bounds = cx.cache.get_bounds(target);
+        let bounds = cx.cache.get_bounds(entity);
+        let mut moved = false;
+
+        if spec.match_size {
+            let width = Units::Pixels(target_bounds.w / scale_factor);
+            let height = Units::Pixels(target_bounds.h / scale_factor);
+            if cx.style.width.get(entity) != Some(&width)
+                || cx.style.height.get(entity) != Some(&height)
+            {
+                cx.style.width.insert(entity, width);
+                cx.style.height.insert(entity, height);
+                moved = true;
+            }
+        }
+
+        let (width, height) =
+            if spec.match_size { (target_bounds.w, target_bounds.h) } else { (bounds.w, bounds.h) };
+        let (target_x, target_y) = spec.their.position(target_bounds);
+        let (x, y) = spec.mine.position(BoundingBox { x: 0.0, y: 0.0, w: width, h: height });
+        let x = target_x - x + spec.offset.0 * scale_factor;
+        let y = target_y - y + spec.offset.1 * scale_factor;
+
+        // The view is positioned from the top-left corner of its parent, which is found from its current position.
+        // Positions are rounded by layout, so the view is moved by whole physical pixels to avoid oscillating.
+        let pixels = |units: Option<&Units>| match units {
+            Some(Units::Pixels(value)) => (*value * scale_factor).round(),
+            _ => 0.0,
+        };
+        let left = pixels(cx.style.left.get(entity));
+        let top = pixels(cx.style.top.get(entity));
+        let new_left = left + (x - bounds.x).round();
+        let new_top = top + (y - bounds.y).round();
+
+        if new_left != left || new_top != top {
+            cx.style.left.insert(entity, Units::Pixels(new_left / scale_factor));
+            cx.style.top.insert(entity, Units::Pixels(new_top / scale_factor));
+            moved = true;
+        }
+
+        if moved {
+            cx.style.needs_relayout_entity(entity);
+            needs_relayout = true;
+        }
+    }
+
+    needs_relayout
+}
+
+/// Hides or releases the views which are anchored to any of the removed entities.
+pub(crate) fn remove_anchors(cx: &mut Context, removed: &[Entity]) {
+    for entity in removed {
+        cx.anchors.remove(entity);
+    }
+
+    let orphaned = cx
+        .anchors
+        .iter()
+        .filter(|(_, anchor)| {
+            anchor.target.resolve(cx).is_some_and(|target| removed.contains(&target))
+        })
+        .map(|(entity, anchor)| (*entity, anchor.spec.on_target_removed))
+        .collect::<Vec<_>>();
+
+    for (entity, on_target_removed) in orphaned {
+        match on_target_removed {
+            AnchorRemoval::Hide => {
+                cx.style.display.insert(entity, Display::None);
+                cx.style.needs_relayout_entity(entity);
+                cx.needs_redraw(entity);
+                if let Some(anchor) = cx.anchors.get_mut(&entity) {
+                    anchor.hidden = true;
+                }
+            }
+
+            AnchorRemoval::Release => {
+                cx.anchors.remove(&entity);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::layout_system;
+
+    fn context() -> Context {
+        let mut cx = Context::default();
+        cx.style.dpi_factor = 1.0;
+        cx.style.width.insert(Entity::root(), Units::Pixels(800.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(600.0));
+        cx
+    }
+
+    #[test]
+    fn anchored_view_follows_target() {
+        let mut cx = context();
+
+        let target =
+            Element::new(&mut cx).size(Pixels(100.0)).left(Pixels(200.0)).top(Pixels(0.0)).entity();
+        let badge = VStack::new(&mut cx, |cx| {
+            Element::new(cx).size(Pixels(20.0)).anchor_to(
+                target,
+                AnchorSpec {
+                    their: AnchorPoint::TopRight,
+                    mine: AnchorPoint::Center,
+                    offset: (0.0, 5.0),
+                    ..Default::default()
+                },
+            );
+        })
+        .left(Pixels(50.0))
+        .top(Pixels(300.0))
+        .entity();
+        let badge = cx.tree.get_first_child(badge).copied().unwrap();
+
+        layout_system(&mut cx);
+        assert_eq!(cx.cache.get_posx(badge), 290.0);
+        assert_eq!(cx.cache.get_posy(badge), -5.0);
+
+        cx.style.left.insert(target, Units::Pixels(400.0));
+        cx.style.needs_relayout_entity(target);
+        layout_system(&mut cx);
+        assert_eq!(cx.cache.get_posx(badge), 490.0);
+    }
+
+    #[test]
+    fn anchor_cycles_are_rejected() {
+        let mut cx = context();
+
+        let first = Element::new(&mut cx).size(Pixels(10.0)).entity();
+        let second = Element::new(&mut cx)
+            .size(Pixels(10.0))
+            .anchor_to(first, AnchorSpec::default())
+            .entity();
+        Handle::<Element> { current: first, entity: first, p: Default::default(), cx: &mut cx }
+            .anchor_to(second, AnchorSpec::default());
+
+        assert!(cx.anchors.contains_key(&second));
+        assert!(!cx.anchors.contains_key(&first));
+    }
+
+    #[test]
+    fn removing_target_hides_or_releases_anchored_views() {
+        let mut cx = context();
+
+        let target = Element::new(&mut cx).size(Pixels(10.0)).id("target").entity();
+        let hidden = Element::new(&mut cx).anchor_to("target", AnchorSpec::default()).entity();
+        let released = Element::new(&mut cx)
+            .anchor_to(
+                target,
+                AnchorSpec { on_target_removed: AnchorRemoval::Release, ..Default::default() },
+            )
+            .entity();
+        layout_system(&mut cx);
+
+        cx.remove(target);
+        assert_eq!(cx.style.display.get(hidden), Some(&Display::None));
+        assert_eq!(cx.style.display.get(released), None);
+        assert!(!cx.anchors.contains_key(&released));
+
+        // A view anchored to an id is shown again once a view with the id is built.
+        Element::new(&mut cx).size(Pixels(10.0)).id("target");
+        layout_system(&mut cx);
+        assert_ne!(cx.style.display.get(hidden), Some(&Display::None));
+    }
+}
@@ -6,6 +6,7 @@ pub(crate) fn animation_system(cx: &mut Context) -> bool {
     // Tick all animations
 
     let time = Instant::now();
+    let color_space = cx.style.color_space;
 
     let mut redraw_entities = Vec::new();
     let mut reflow_entities = Vec::new();
@@ -13,73 +14,95 @@ pub(crate) fn animation_system(cx: &mut Context) -> bool {
 
     // Properties which affect rendering
     // Opacity
-    redraw_entities.extend(cx.style.opacity.tick(time));
-    // Corner Colour
-    redraw_entities.extend(cx.style.border_color.tick(time));
+    redraw_entities.extend(cx.style.opacity.tick(time, color_space));
+    // Border Colour
+    redraw_entities.extend(cx.style.border_top_color.tick(time, color_space));
+    redraw_entities.extend(cx.style.border_right_color.tick(time, color_space));
+    redraw_entities.extend(cx.style.border_bottom_color.tick(time, color_space));
+    redraw_entities.extend(cx.style.border_left_color.tick(time, color_space));
     // Corner Radius
-    redraw_entities.extend(cx.style.corner_top_left_radius.tick(time));
-    redraw_entities.extend(cx.style.corner_top_right_radius.tick(time));
-    redraw_entities.extend(cx.style.corner_bottom_left_radius.tick(time));
-    redraw_entities.extend(cx.style.corner_bottom_right_radius.tick(time));
+    redraw_entities.extend(cx.style.corner_top_left_radius.tick(time, color_space));
+    redraw_entities.extend(cx.style.corner_top_right_radius.tick(time, color_space));
+    redraw_entities.extend(cx.style.corner_bottom_left_radius.tick(time, color_space));
+    redraw_entities.extend(cx.style.corner_bottom_right_radius.tick(time, color_space));
     // Background
-    redraw_entities.extend(cx.style.background_color.tick(time));
-    redraw_entities.extend(cx.style.background_image.tick(time));
-    redraw_entities.extend(cx.style.background_size.tick(time));
+    redraw_entities.extend(cx.style.background_color.tick(time, color_space));
+    redraw_entities.extend(cx.style.background_image.tick(time, color_space));
+    redraw_entities.extend(cx.style.background_position.tick(time, color_space));
+    redraw_entities.extend(cx.style.background_size.tick(time, color_space));
     // Box Shadow
-    redraw_entities.extend(cx.style.shadow.tick(time));
+    redraw_entities.extend(cx.style.shadow.tick(time, color_space));
+    redraw_entities.extend(cx.style.elevation.tick(time, color_space));
     // Transform
-    redraw_entities.extend(cx.style.transform.tick(time));
-    redraw_entities.extend(cx.style.transform_origin.tick(time));
-    redraw_entities.extend(cx.style.translate.tick(time));
-    redraw_entities.extend(cx.style.rotate.tick(time));
-    redraw_entities.extend(cx.style.scale.tick(time));
+    redraw_entities.extend(cx.style.transform.tick(time, color_space));
+    redraw_entities.extend(cx.style.transform_origin.tick(time, color_space));
+    redraw_entities.extend(cx.style.translate.tick(time, color_space));
+    redraw_entities.extend(cx.style.rotate.tick(time, color_space));
+    redraw_entities.extend(cx.style.scale.tick(time, color_space));
     // Outline
-    redraw_entities.extend(cx.style.outline_color.tick(time));
-    redraw_entities.extend(cx.style.outline_offset.tick(time));
-    redraw_entities.extend(cx.style.outline_width.tick(time));
+    redraw_entities.extend(cx.style.outline_color.tick(time, color_space));
+    redraw_entities.extend(cx.style.outline_offset.tick(time, color_space));
+    redraw_entities.extend(cx.style.outline_width.tick(time, color_space));
     // Clip Path
-    redraw_entities.extend(cx.style.clip_path.tick(time));
+    redraw_entities.extend(cx.style.clip_path.tick(time, color_space));
+    // Filters
+    redraw_entities.extend(cx.style.filter.tick(time, color_space));
+    redraw_entities.extend(cx.style.backdrop_filter.tick(time, color_space));
 
-    redraw_entities.extend(cx.style.fill.tick(time));
+    redraw_entities.extend(cx.style.fill.tick(time, color_space));
+    redraw_entities.extend(cx.style.stroke.tick(time, color_space));
+    // Ripples
+    redraw_entities.extend(cx.style.tick_ripples(time));
 
     // Font Color
-    reflow_entities.extend(cx.style.font_color.tick(time));
+    reflow_entities.extend(cx.style.font_color.tick(time, color_space));
+    reflow_entities.extend(cx.style.text_decoration_color.tick(time, color_space));
     // Font Size
-    reflow_entities.extend(cx.style.font_size.tick(time));
+    reflow_entities.extend(cx.style.font_size.tick(time, color_space));
+    // Font Variations
+    reflow_entities.extend(cx.style.font_variation_settings.tick(time, color_space));
+    // Letter and Word Spacing
+    reflow_entities.extend(cx.style.letter_spacing.tick(time, color_space));
+    reflow_entities.extend(cx.style.word_spacing.tick(time, color_space));
 
     // Properties which affect layout
-    relayout_entities.extend(cx.style.display.tick(time));
+    relayout_entities.extend(cx.style.display.tick(time, color_space));
     // Border Width
-    relayout_entities.extend(cx.style.border_width.tick(time));
+    relayout_entities.extend(cx.style.border_top_width.tick(time, color_space));
+    relayout_entities.extend(cx.style.border_right_width.tick(time, color_space));
+    relayout_entities.extend(cx.style.border_bottom_width.tick(time, color_space));
+    relayout_entities.extend(cx.style.border_left_width.tick(time, color_space));
     // Space
-    relayout_entities.extend(cx.style.left.tick(time));
-    relayout_entities.extend(cx.style.right.tick(time));
-    relayout_entities.extend(cx.style.top.tick(time));
-    relayout_entities.extend(cx.style.bottom.tick(time));
+    relayout_entities.extend(cx.style.left.tick(time, color_space));
+    relayout_entities.extend(cx.style.right.tick(time, color_space));
+    relayout_entities.extend(cx.style.top.tick(time, color_space));
+    relayout_entities.extend(cx.style.bottom.tick(time, color_space));
     // Size
-    relayout_entities.extend(cx.style.width.tick(time));
-    relayout_entities.extend(cx.style.height.tick(time));
+    relayout_entities.extend(cx.style.width.tick(time, color_space));
+    relayout_entities.extend(cx.style.height.tick(time, color_space));
     // Min/Max Size
-    relayout_entities.extend(cx.style.max_width.tick(time));
-    relayout_entities.extend(cx.style.max_height.tick(time));
-    relayout_entities.extend(cx.style.min_width.tick(time));
-    relayout_entities.extend(cx.style.min_height.tick(time));
+    relayout_entities.extend(cx.style.max_width.tick(time, color_space));
+    relayout_entities.extend(cx.style.max_height.tick(time, color_space));
+    relayout_entities.extend(cx.style.min_width.tick(time, color_space));
+    relayout_entities.extend(cx.style.min_height.tick(time, color_space));
     // Min/Max Gap
-    relayout_entities.extend(cx.style.max_horizontal_gap.tick(time));
-    relayout_entities.extend(cx.style.max_vertical_gap.tick(time));
-    relayout_entities.extend(cx.style.min_horizontal_gap.tick(time));
-    relayout_entities.extend(cx.style.min_vertical_gap.tick(time));
+    relayout_entities.extend(cx.style.max_horizontal_gap.tick(time, color_space));
+    relayout_entities.extend(cx.style.max_vertical_gap.tick(time, color_space));
+    relayout_entities.extend(cx.style.min_horizontal_gap.tick(time, color_space));
+    relayout_entities.extend(cx.style.min_vertical_gap.tick(time, color_space));
     // Row/Col Between
-    relayout_entities.extend(cx.style.vertical_gap.tick(time));
-    relayout_entities.extend(cx.style.horizontal_gap.tick(time));
+    relayout_entities.extend(cx.style.vertical_gap.tick(time, color_space));
+    relayout_entities.extend(cx.style.horizontal_gap.tick(time, color_space));
     // Child Space
-    relayout_entities.extend(cx.style.padding_left.tick(time));
-    relayout_entities.extend(cx.style.padding_right.tick(time));
-    relayout_entities.extend(cx.style.padding_top.tick(time));
-    relayout_entities.extend(cx.style.padding_bottom.tick(time));
+    relayout_entities.extend(cx.style.padding_left.tick(time, color_space));
+    relayout_entities.extend(cx.style.padding_right.tick(time, color_space));
+    relayout_entities.extend(cx.style.padding_top.tick(time, color_space));
+    relayout_entities.extend(cx.style.padding_bottom.tick(time, color_space));
+    // Lengths resolved during layout, such as `clamp(200px, 50%, 600px)`
+    relayout_entities.extend(cx.style.layout_calc.tick(time, color_space));
 
-    if !relayout_entities.is_empty() {
-        cx.style.system_flags.set(SystemFlags::RELAYOUT, true);
+    for entity in relayout_entities.iter() {
+        cx.style.needs_relayout_entity(*entity);
     }
 
     for entity in redraw_entities.iter() {
@@ -90,5 +113,98 @@ pub(crate) fn animation_system(cx: &mut Context) -> bool {
         cx.style.text_construction.insert(*entity).unwrap();
     }
 
+    // Views are notified once the animations played on them finish.
+    for (entity, animation) in cx.style.take_finished_animations() {
+        cx.event_queue.push_back(
+            Event::new(AnimationEvent::Finished(animation))
+                .target(entity)
+                .origin(entity)
+                .propagate(Propagation::Up),
+        );
+    }
+
     !redraw_entities.is_empty() | !relayout_entities.is_empty() | !reflow_entities.is_empty()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn opacity(cx: &Context, entity: Entity) -> f32 {
+        cx.style.opacity.get(entity).map(|opacity| opacity.0).unwrap_or(1.0)
+    }
+
+    fn fade(cx: &mut Context) -> Animation {
+        cx.add_animation(
+            AnimationBuilder::new()
+                .keyframe(0.0, |key| key.opacity(0.0))
+                .keyframe(1.0, |key| key.opacity(1.0)),
+        )
+    }
+
+    #[test]
+    fn played_animation_can_be_paused_moved_and_finishes() {
+        let mut cx = Context::default();
+        let fade = fade(&mut cx);
+        let entity = Element::new(&mut cx).opacity(0.5).entity();
+
+        let mut ecx = EventContext::new_with_current(&mut cx, entity);
+        ecx.play_animation(fade, Duration::from_secs(1), Duration::ZERO);
+        ecx.pause_animation(fade);
+        ecx.seek_animation(fade, Duration::from_millis(250));
+        assert!((opacity(&cx, entity) - 0.25).abs() < 0.01);
+
+        // A paused animation holds its values.
+        cx.style.opacity.tick(Instant::now() + Duration::from_secs(5), ColorSpace::default());
+        assert!((opacity(&cx, entity) - 0.25).abs() < 0.01);
+        assert!(cx.style.is_animating(entity, fade));
+
+        EventContext::new_with_current(&mut cx, entity).resume_animation(fade);
+        cx.style.opacity.tick(Instant::now() + Duration::from_millis(500), ColorSpace::default());
+        assert!((opacity(&cx, entity) - 0.75).abs() < 0.05);
+
+        // Once the animation finishes, the view shows its own value again and is notified.
+        cx.style.opacity.tick(Instant::now() + Duration::from_secs(2), ColorSpace::default());
+        animation_system(&mut cx);
+        assert_eq!(opacity(&cx, entity), 0.5);
+
+        let mut finished = Vec::new();
+        for event in cx.event_queue.iter_mut() {
+            event.map(|event: &AnimationEvent, _| finished.push(*event));
+        }
+        assert_eq!(finished, [AnimationEvent::Finished(fade)]);
+        assert!(cx.style.played_animations.is_empty());
+    }
+
+    #[test]
+    fn animation_replaces_a_transition_of_the_same_property() {
+        let mut cx = Context::default();
+        let fade = fade(&mut cx);
+        let entity = Element::new(&mut cx)
+            .opacity(0.5)
+            .transition("opacity", Duration::from_secs(1), EasingFunction::Linear)
+            .entity();
+
+        cx.style.opacity.insert(entity, Opacity(1.0));
+        assert!(cx
+            .style
+            .opacity
+            .get_active_animation(entity)
+            .is_some_and(|state| state.id != fade));
+
+        let mut ecx = EventContext::new_with_current(&mut cx, entity);
+        ecx.play_animation(fade, Duration::from_secs(1), Duration::ZERO);
+        ecx.pause_animation(fade);
+        assert!(cx
+            .style
+            .opacity
+            .get_active_animation(entity)
+            .is_some_and(|state| state.id == fade));
+        assert_eq!(opacity(&cx, entity), 0.0);
+
+        // Stopping the animation shows the value the transition was heading to, without the transition.
+        EventContext::new_with_current(&mut cx, entity).stop_animation(fade);
+        assert!(cx.style.opacity.get_active_animation(entity).is_none());
+        assert_eq!(opacity(&cx, entity), 1.0);
+    }
+}
@@ -1,8 +1,15 @@
+#[cfg(debug_assertions)]
+use super::UpdateKind;
+use crate::views::{catch_panic, recover_from_panic};
 use crate::{binding::StoreId, model::ModelOrView, prelude::*};
 use hashbrown::{HashMap, HashSet};
 use std::any::TypeId;
 
 pub(crate) fn binding_system(cx: &mut Context) {
+    if !cx.rebind.is_empty() {
+        rebind_system(cx);
+    }
+
     let mut observers: HashMap<Entity, (Entity, Option<TypeId>, StoreId)> = HashMap::new();
 
     for (entity, model_data_store) in cx.data.iter_mut() {
@@ -75,11 +82,55 @@ pub(crate) fn binding_system(cx: &mut Context) {
     }
 }
 
+// Rebuilds bindings whose model was removed or replaced, against the closest source of their data.
+fn rebind_system(cx: &mut Context) {
+    let rebind = std::mem::take(&mut cx.rebind);
+
+    // Rebuild in tree order so that ancestors rebuild first, which skips any bindings they destroy.
+    let ordered_observers =
+        cx.tree.into_iter().filter(|entity| rebind.contains(entity)).collect::<Vec<_>>();
+
+    for observer in ordered_observers {
+        if !cx.entity_manager.is_alive(observer) {
+            continue;
+        }
+
+        let Some(binding) = cx.bindings.remove(&observer) else {
+            continue;
+        };
+
+        let attached = binding.attach(cx);
+        cx.bindings.insert(observer, binding);
+
+        if attached {
+            update_binding(cx, observer);
+        } else {
+            // There is no data left to build the content from.
+            for type_id in cx.release_models(observer) {
+                cx.take_model(observer, type_id);
+            }
+            cx.remove_children(observer);
+        }
+    }
+}
+
 fn update_binding(cx: &mut Context, observer: Entity) {
     if let Some(mut binding) = cx.bindings.remove(&observer) {
-        cx.with_current(observer, |cx| {
-            binding.update(cx);
-        });
+        #[cfg(debug_assertions)]
+        {
+            cx.update_overlay.flash(UpdateKind::Rebuild, observer);
+            cx.update_overlay.count(UpdateKind::Rebuild, 1);
+        }
+
+        let result = cx.with_current(observer, |cx| catch_panic(|| binding.update(cx)));
         cx.bindings.insert(observer, binding);
+
+        if let Err(payload) = result {
+            // The rebuild may have been interrupted part way, so drop whatever it managed to build.
+            cx.remove_children(observer);
+
+            let (boundary, message) = recover_from_panic(&cx.tree, &cx.views, observer, payload);
+            cx.emit_to(boundary, ErrorBoundaryEvent::Error(message));
+        }
     }
 }
@@ -1,82 +1,51 @@
-use crate::{animation::Interpolator, cache::CachedData, prelude::*};
+use super::draw_focus_ring;
+#[cfg(debug_assertions)]
+use super::draw_update_overlay;
+use crate::style::scroll_transform;
+use crate::views::{catch_panic, defer_error, emit_deferred_errors, recover_from_panic};
+use crate::{cache::CachedData, prelude::*};
+use hashbrown::HashMap;
 use morphorm::Node;
 use skia_safe::{
-    canvas::SaveLayerRec, ClipOp, ImageFilter, Matrix, Paint, Rect, SamplingOptions, Surface,
+    canvas::SaveLayerRec, color_filters, image_filters, ClipOp, ImageFilter, Matrix, Paint, Rect,
+    SamplingOptions, Surface,
 };
-use std::cmp::Ordering;
-use std::collections::BinaryHeap;
 use vizia_storage::{DrawChildIterator, LayoutTreeIterator};
-use vizia_style::BlendMode;
 
 pub(crate) fn transform_system(cx: &mut Context) {
     let iter = LayoutTreeIterator::full(&cx.tree);
 
-    for entity in iter {
-        let bounds = cx.cache.bounds.get(entity).copied().unwrap();
-        if let Some(parent) = cx.tree.get_layout_parent(entity) {
-            let parent_transform = cx.cache.transform.get(parent).copied().unwrap();
-            if let Some(tx) = cx.cache.transform.get_mut(entity) {
-                let scale_factor = cx.style.scale_factor();
-
-                // Apply transform origin.
-                let mut origin = cx
-                    .style
-                    .transform_origin
-                    .get(entity)
-                    .map(|transform_origin| {
-                        let mut origin = skia_safe::Matrix::translate(bounds.top_left());
-                        let offset = transform_origin.as_transform(bounds, scale_factor);
-                        origin = offset * origin;
-                        origin
-                    })
-                    .unwrap_or(skia_safe::Matrix::translate(bounds.center()));
-                // transform = origin * transform;
-                let mut transform = origin;
-                origin = origin.invert().unwrap();
-
-                // Apply translation.
-                if let Some(translate) = cx.style.translate.get(entity) {
-                    transform = transform * translate.as_transform(bounds, scale_factor);
-                }
-
-                // Apply rotation.
-                if let Some(rotate) = cx.style.rotate.get(entity) {
-                    transform = transform * rotate.as_transform(bounds, scale_factor);
-                }
+    // The children of a view which scrolls its overflow are translated by the distances it's scrolled, which are only
+    // computed once for all of its children.
+    let mut scroll_transforms = HashMap::new();
 
-                // Apply scaling.
-                if let Some(scale) = cx.style.scale.get(entity) {
-                    transform = transform * scale.as_transform(bounds, scale_factor);
-                }
+    // The hover bounds of each view are the bounds of the view and its descendants as they're drawn. They're
+    // accumulated from the leaves up while the tree is traversed, using the stack of the ancestors of the current view,
+    // whose descendants haven't all been visited yet. Descendants which overflow their ancestors or are layered above
+    // them are included, as they can still be hovered.
+    let mut ancestors: Vec<Entity> = Vec::new();
 
-                // Apply transform functions.
-                if let Some(transforms) = cx.style.transform.get(entity) {
-                    // Check if the transform is currently animating
-                    // Get the animation state
-                    // Manually interpolate the value to get the overall transform for the current frame
-                    if let Some(animation_state) = cx.style.transform.get_active_animation(entity) {
-                        if let Some(start) = animation_state.keyframes.first() {
-                            if let Some(end) = animation_state.keyframes.last() {
-                                let start_transform =
-                                    start.value.as_transform(bounds, scale_factor);
-                                let end_transform = end.value.as_transform(bounds, scale_factor);
-                                let t = animation_state.t;
-                                let animated_transform = skia_safe::Matrix::interpolate(
-                                    &start_transform,
-                                    &end_transform,
-                                    t,
-                                );
-                                transform = transform * animated_transform;
-                            }
-                        }
-                    } else {
-                        transform = transform * transforms.as_transform(bounds, scale_factor);
-                    }
-                }
+    for entity in iter {
+        let bounds = cx.cache.bounds.get(entity).copied().unwrap();
+        let parent = cx.tree.get_layout_parent(entity);
+        while ancestors.last().is_some_and(|ancestor| Some(*ancestor) != parent) {
+            close_hover_bounds(&mut cx.cache, &mut ancestors);
+        }
 
-                transform = transform * origin;
+        if let Some(parent) = parent {
+            let scroll = *scroll_transforms
+                .entry(parent)
+                .or_insert_with(|| scroll_transform(&cx.style, &cx.cache, &cx.tree, parent));
+            let parent_transform = cx.cache.transform.get(parent).copied().unwrap() * scroll;
+            let transform = parent_transform * cx.style.local_transform(entity, bounds);
+            if let Some(tx) = cx.cache.transform.get_mut(entity) {
+                *tx = transform;
+            }
 
-                *tx = parent_transform * transform;
+            if let Some(corner_radii) = cx.style.corner_radii(entity, bounds) {
+                cx.cache.corner_radii.insert(entity, corner_radii);
+            } else {
+                cx.cache.corner_radii.remove(entity);
             }
 
             let overflowx = cx.style.overflowx.get(entity).copied().unwrap_or_default();
@@ -103,21 +72,21 @@ pub(crate) fn transform_system(cx: &mut Context) {
 
             let clip_bounds = match (overflowx, overflowy) {
                 (Overflow::Visible, Overflow::Visible) => root_bounds,
-                (Overflow::Hidden, Overflow::Visible) => {
+                (_, Overflow::Visible) => {
                     let left = clip_bounds.left();
                     let right = clip_bounds.right();
                     let top = root_bounds.top();
                     let bottom = root_bounds.bottom();
                     BoundingBox::from_min_max(left, top, right, bottom)
                 }
-                (Overflow::Visible, Overflow::Hidden) => {
+                (Overflow::Visible, _) => {
                     let left = root_bounds.left();
                     let right = root_bounds.right();
                     let top = clip_bounds.top();
                     let bottom = clip_bounds.bottom();
                     BoundingBox::from_min_max(left, top, right, bottom)
                 }
-                (Overflow::Hidden, Overflow::Hidden) => clip_bounds,
+                _ => clip_bounds,
             };
 
             let transform =
@@ -126,7 +95,12 @@ pub(crate) fn transform_system(cx: &mut Context) {
             let rect: skia_safe::Rect = clip_bounds.into();
             let clip_bounds: BoundingBox = transform.map_rect(rect).0.into();
 
-            let parent_clip_bounds = cx.cache.clip_path.get(parent).copied().unwrap_or(root_bounds);
+            // A view with a layer or z-index is drawn outside of the clip of its ancestors.
+            let parent_clip_bounds = if cx.style.is_layered(entity) {
+                root_bounds
+            } else {
+                cx.cache.clip_path.get(parent).copied().unwrap_or(root_bounds)
+            };
 
             if let Some(clip_path) = cx.cache.clip_path.get_mut(entity) {
                 *clip_path = clip_bounds.intersection(&parent_clip_bounds);
@@ -134,6 +108,31 @@ pub(crate) fn transform_system(cx: &mut Context) {
                 cx.cache.clip_path.insert(entity, clip_bounds.intersection(&parent_clip_bounds));
             }
         }
+
+        let transform = cx.cache.transform.get(entity).copied().unwrap_or(Matrix::new_identity());
+        let drawn: BoundingBox = transform.map_rect(Rect::from(bounds)).0.into();
+        cx.cache.hover_bounds.insert(entity, drawn);
+        ancestors.push(entity);
+    }
+
+    // The views left at the end of the traversal have no more descendants to visit.
+    while !ancestors.is_empty() {
+        close_hover_bounds(&mut cx.cache, &mut ancestors);
+    }
+}
+
+// Adds the hover bounds of the last of the ancestors, whose descendants have all been visited, to those of its parent.
+fn close_hover_bounds(cache: &mut CachedData, ancestors: &mut Vec<Entity>) {
+    let Some(entity) = ancestors.pop() else {
+        return;
+    };
+
+    if let (Some(bounds), Some(&parent)) =
+        (cache.hover_bounds.get(entity).copied(), ancestors.last())
+    {
+        if let Some(parent_bounds) = cache.hover_bounds.get_mut(parent) {
+            *parent_bounds = parent_bounds.union(&bounds);
+        }
     }
 }
 
@@ -212,10 +211,10 @@ pub(crate) fn draw_system(
 
     cx.resource_manager.mark_images_unused();
 
-    let mut queue = BinaryHeap::new();
-    queue.push(ZEntity { index: 0, entity: window_entity, visible: true });
+    let mut queue = StackQueue::new();
+    queue.push(StackOrder::default(), ZEntity { entity: window_entity, visible: true });
 
-    while let Some(zentity) = queue.pop() {
+    while let Some((order, zentity)) = queue.pop() {
         canvas.save();
         draw_entity(
             &mut DrawContext {
@@ -233,7 +232,7 @@ pub(crate) fn draw_system(
             },
             &dirty_rect,
             canvas,
-            zentity.index,
+            order,
             &mut queue,
             zentity.visible,
         );
@@ -242,9 +241,19 @@ pub(crate) fn draw_system(
 
     canvas.restore();
 
+    emit_deferred_errors(cx);
+
     surface.canvas().clear(Color::transparent());
     dirty_surface.draw(surface.canvas(), (0, 0), SamplingOptions::default(), None);
 
+    // The focus ring is drawn over the copy of the dirty surface so that it isn't clipped by the ancestors of the
+    // focused view, and so that it can scale in without redrawing any views.
+    draw_focus_ring(cx, window_entity, surface.canvas());
+
+    // The overlay is drawn over the copy of the dirty surface so that it can fade out without redrawing any views.
+    #[cfg(debug_assertions)]
+    draw_update_overlay(cx, window_entity, surface.canvas());
+
     // Debug draw dirty rect
     // if let Some(rect) = dirty_rect.map(Rect::from) {
     //     let mut paint = Paint::default();
@@ -261,8 +270,8 @@ fn draw_entity(
     cx: &mut DrawContext,
     dirty_rect: &Option<BoundingBox>,
     canvas: &Canvas,
-    current_z: i32,
-    queue: &mut BinaryHeap<ZEntity>,
+    current_order: StackOrder,
+    queue: &mut StackQueue<ZEntity>,
     visible: bool,
 ) {
     let current = cx.current;
@@ -272,42 +281,38 @@ fn draw_entity(
         return;
     }
 
-    let z_index = cx.z_index();
-
-    if z_index > current_z {
-        queue.push(ZEntity { index: z_index, entity: current, visible });
+    // Views above the current layer or z-index are drawn later.
+    let order = cx.style.stack_order(current, current_order.layer);
+    if order > current_order {
+        queue.push(order, ZEntity { entity: current, visible });
         return;
     }
 
-    let backdrop_filter = cx.backdrop_filter();
-    let blend_mode = cx.style.blend_mode.get(current).copied().unwrap_or_default();
+    let is_visible = match (visible, cx.visibility()) {
+        (v, None) => v,
+        (_, Some(Visibility::Hidden)) => false,
+        (_, Some(Visibility::Visible)) => true,
+    };
 
-    canvas.save();
-    let layer_count =
-        if cx.opacity() != 1.0 || backdrop_filter.is_some() || blend_mode != BlendMode::Normal {
-            let mut paint = Paint::default();
-            paint.set_alpha_f(cx.opacity());
-            paint.set_blend_mode(blend_mode.into());
-
-            let rect: Rect = cx.bounds().into();
-            let mut filter = ImageFilter::crop(rect, None, None).unwrap();
-
-            let slr = if let Some(backdrop_filter) = backdrop_filter {
-                match backdrop_filter {
-                    Filter::Blur(radius) => {
-                        let sigma = radius.to_px().unwrap() * cx.scale_factor() / 2.0;
-                        filter = filter.blur(None, (sigma, sigma), None).unwrap();
-                        SaveLayerRec::default().paint(&paint).backdrop(&filter)
-                    }
-                }
-            } else {
-                SaveLayerRec::default().paint(&paint)
-            };
+    // The draw bounds of a view include its descendants unless it clips them, so a view whose draw bounds are outside
+    // of the dirty rect is skipped along with its descendants, apart from those drawn later because of their layer or
+    // z-index.
+    let bounds = draw_bounds(cx.style, cx.cache, cx.tree, current);
+    if dirty_rect.as_ref().is_some_and(|dirty_rect| is_culled(&bounds, dirty_rect)) {
+        queue_layered_descendants(cx, current_order, queue, is_visible);
+        return;
+    }
 
-            Some(canvas.save_layer(&slr))
-        } else {
-            None
-        };
+    canvas.save();
+    let layer_count = save_layers(
+        canvas,
+        cx.bounds(),
+        cx.opacity(),
+        cx.style.mix_blend_mode.get(current).copied().unwrap_or_default(),
+        cx.filter().map(Vec::as_slice),
+        cx.backdrop_filter().map(Vec::as_slice),
+        cx.scale_factor(),
+    );
 
     if let Some(transform) = cx.cache.transform.get(current) {
         canvas.set_matrix(&(transform.into()));
@@ -317,23 +322,23 @@ fn draw_entity(
         canvas.clip_path(&clip_path, ClipOp::Intersect, true);
     }
 
-    let is_visible = match (visible, cx.visibility()) {
-        (v, None) => v,
-        (_, Some(Visibility::Hidden)) => false,
-        (_, Some(Visibility::Visible)) => true,
-    };
-
     // Draw the view
-    if is_visible {
-        if let Some(dirty_rect) = dirty_rect {
-            let bounds = draw_bounds(cx.style, cx.cache, cx.tree, current);
-            if bounds.intersects(dirty_rect) {
-                if let Some(view) = cx.views.remove(&current) {
-                    view.draw(cx, canvas);
-                    cx.views.insert(current, view);
-                }
+    if is_visible && dirty_rect.is_some() {
+        if let Some(view) = cx.views.remove(&current) {
+            let save_count = canvas.save_count();
+            let result = catch_panic(|| view.draw(cx, canvas));
+            cx.views.insert(current, view);
+
+            // A panicking view may leave the canvas state unbalanced.
+            if let Err(payload) = result {
+                canvas.restore_to_count(save_count);
+                let (boundary, message) = recover_from_panic(cx.tree, cx.views, current, payload);
+                defer_error(boundary, message);
             }
         }
+
+        // Ripples are drawn over the view and beneath its children.
+        cx.draw_ripples(canvas);
     }
 
     let child_iter = DrawChildIterator::new(cx.tree, cx.current);
@@ -342,7 +347,13 @@ fn draw_entity(
     for child in child_iter {
         cx.current = child;
         // TODO: Skip views with zero-sized bounding boxes here? Or let user decide if they want to skip?
-        draw_entity(cx, dirty_rect, canvas, current_z, queue, is_visible);
+        draw_entity(cx, dirty_rect, canvas, current_order, queue, is_visible);
+    }
+
+    // Scrollbars are drawn over the children of a view which scrolls its overflow.
+    cx.current = current;
+    if is_visible && dirty_rect.is_some() {
+        cx.draw_scrollbars(canvas);
     }
 
     if let Some(count) = layer_count {
@@ -352,6 +363,88 @@ fn draw_entity(
     cx.current = current;
 }
 
+// Saves the layers which apply the opacity, blend mode and filters of a view, returning the save count to restore to
+// once the view and its children have been drawn, or `None` if no layer is needed.
+//
+// The backdrop is filtered first, within the bounds of the view, and is faded by the opacity of the view like the
+// rest of it. The view and its children are then drawn into a layer which is filtered, and then blended with the
+// filtered backdrop at the opacity of the view, so the filters only apply to the view and not to its backdrop.
+fn save_layers(
+    canvas: &Canvas,
+    bounds: BoundingBox,
+    opacity: f32,
+    blend_mode: BlendMode,
+    filter: Option<&[Filter]>,
+    backdrop_filter: Option<&[Filter]>,
+    scale_factor: f32,
+) -> Option<usize> {
+    let backdrop = backdrop_filter.filter(|filters| !filters.is_empty()).and_then(|filters| {
+        image_filter(filters, ImageFilter::crop(Rect::from(bounds), None, None), scale_factor)
+    });
+
+    if let Some(backdrop) = backdrop {
+        let mut paint = Paint::default();
+        paint.set_alpha_f(opacity);
+        canvas.save_layer(&SaveLayerRec::default().paint(&paint).backdrop(&backdrop));
+        canvas.restore();
+    }
+
+    let filter = filter.filter(|filters| !filters.is_empty());
+    if opacity == 1.0 && filter.is_none() && blend_mode == BlendMode::Normal {
+        return None;
+    }
+
+    let mut paint = Paint::default();
+    paint.set_alpha_f(opacity);
+    paint.set_blend_mode(blend_mode.into());
+    if let Some(filter) = filter {
+        paint.set_image_filter(image_filter(filter, None, scale_factor));
+    }
+
+    Some(canvas.save_layer(&SaveLayerRec::default().paint(&paint)))
+}
+
+// Returns true if nothing within the draw bounds of a view can be seen within the dirty rect. The draw bounds are
+// inverted when the view is entirely outside of the clip of its ancestors.
+fn is_culled(bounds: &BoundingBox, dirty_rect: &BoundingBox) -> bool {
+    bounds.w < 0.0 || bounds.h < 0.0 || !bounds.intersects(dirty_rect)
+}
+
+// Queues the descendants of a skipped view which have a higher layer or z-index, as they would have been queued if the
+// view was drawn.
+fn queue_layered_descendants(
+    cx: &mut DrawContext,
+    current_order: StackOrder,
+    queue: &mut StackQueue<ZEntity>,
+    visible: bool,
+) {
+    let current = cx.current;
+
+    for child in DrawChildIterator::new(cx.tree, current) {
+        cx.current = child;
+
+        if cx.display() == Display::None {
+            continue;
+        }
+
+        let order = cx.style.stack_order(child, current_order.layer);
+        if order > current_order {
+            queue.push(order, ZEntity { entity: child, visible });
+            continue;
+        }
+
+        let is_visible = match (visible, cx.visibility()) {
+            (v, None) => v,
+            (_, Some(Visibility::Hidden)) => false,
+            (_, Some(Visibility::Visible)) => true,
+        };
+
+        queue_layered_descendants(cx, current_order, queue, is_visible);
+    }
+
+    cx.current = current;
+}
+
 // Must be called after transform and clipping systems to be valid.
 pub(crate) fn draw_bounds(
     style: &Style,
@@ -361,28 +454,16 @@ pub(crate) fn draw_bounds(
 ) -> BoundingBox {
     let mut layout_bounds = cache.bounds.get(entity).copied().unwrap();
 
-    if let Some(shadows) = style.shadow.get(entity) {
-        for shadow in shadows.iter().filter(|shadow| !shadow.inset) {
-            let mut shadow_bounds = layout_bounds;
-
-            let x = shadow.x_offset.to_px().unwrap() * style.scale_factor();
-            let y = shadow.y_offset.to_px().unwrap() * style.scale_factor();
-
-            shadow_bounds = shadow_bounds.offset(x, y);
+    if let Some(shadows) = style.shadows(entity) {
+        let scale_factor = style.scale_factor();
+        let to_px =
+            |length: Option<&Length>| length.and_then(Length::to_px).unwrap_or(0.0) * scale_factor;
 
-            let scale_factor = style.scale_factor();
-
-            if let Some(blur_radius) =
-                shadow.blur_radius.as_ref().map(|br| br.clone().to_px().unwrap() * scale_factor)
-            {
-                shadow_bounds = shadow_bounds.expand(blur_radius);
-            }
-
-            if let Some(spread_radius) =
-                shadow.spread_radius.as_ref().map(|sr| sr.clone().to_px().unwrap() * scale_factor)
-            {
-                shadow_bounds = shadow_bounds.expand(spread_radius * style.scale_factor());
-            }
+        for shadow in shadows.iter().filter(|shadow| !shadow.inset) {
+            // The blur extends past the spread of the shadow by the blur radius.
+            let shadow_bounds = layout_bounds
+                .offset(to_px(Some(&shadow.x_offset)), to_px(Some(&shadow.y_offset)))
+                .expand(to_px(shadow.spread_radius.as_ref()) + to_px(shadow.blur_radius.as_ref()));
 
             layout_bounds = layout_bounds.union(&shadow_bounds);
         }
@@ -419,13 +500,15 @@ pub(crate) fn draw_bounds(
         }
     }
 
-    let z_index = style.z_index.get(entity).copied().unwrap_or_default();
+    if let Some(filters) = style.filter.get(entity) {
+        dirty_bounds = filter_bounds(filters, dirty_bounds, style.scale_factor());
+    }
 
     let parent = tree
         .get_layout_parent(entity)
         .unwrap_or(tree.get_parent_window(entity).unwrap_or(Entity::root()));
     if let Some(clip_bounds) = cache.clip_path.get(parent) {
-        if z_index != 0 {
+        if style.is_layered(entity) {
             dirty_bounds
         } else {
             dirty_bounds.intersection(clip_bounds)
@@ -435,26 +518,460 @@ pub(crate) fn draw_bounds(
     }
 }
 
+// Returns the image filter which applies a list of filters in order to the output of the input filter, or to the
+// content of the layer it's drawn with if there's no input.
+fn image_filter(
+    filters: &[Filter],
+    input: Option<ImageFilter>,
+    scale_factor: f32,
+) -> Option<ImageFilter> {
+    filters.iter().fold(input, |input, filter| {
+        let filtered = match filter {
+            Filter::Blur(radius) => {
+                let sigma = radius.to_px().unwrap_or(0.0) * scale_factor / 2.0;
+                image_filters::blur((sigma, sigma), None, input.clone(), None)
+            }
+
+            Filter::DropShadow(shadow) => {
+                let x = shadow.x_offset.to_px().unwrap_or(0.0) * scale_factor;
+                let y = shadow.y_offset.to_px().unwrap_or(0.0) * scale_factor;
+                let sigma =
+                    shadow.blur_radius.as_ref().and_then(|blur| blur.to_px()).unwrap_or(0.0)
+                        * scale_factor
+                        / 2.0;
+                let color: skia_safe::Color = shadow.color.unwrap_or_default().into();
+                image_filters::drop_shadow((x, y), (sigma, sigma), color, None, input.clone(), None)
+            }
+
+            _ => {
+                let matrix = color_matrix(filter);
+                let color_filter = color_filters::matrix_row_major(&matrix, None);
+                image_filters::color_filter(color_filter, input.clone(), None)
+            }
+        };
+
+        filtered.or(input)
+    })
+}
+
+// Returns the row-major color matrix of a filter which only changes the colors of its input, using the matrices from
+// the filter effects specification.
+fn color_matrix(filter: &Filter) -> [f32; 20] {
+    let (rgb, offset) = match *filter {
+        Filter::Brightness(amount) => {
+            ([[amount, 0.0, 0.0], [0.0, amount, 0.0], [0.0, 0.0, amount]], 0.0)
+        }
+
+        Filter::Contrast(amount) => {
+            ([[amount, 0.0, 0.0], [0.0, amount, 0.0], [0.0, 0.0, amount]], 0.5 - 0.5 * amount)
+        }
+
+        Filter::Grayscale(amount) => {
+            let a = 1.0 - amount;
+            (
+                [
+                    [0.2126 + 0.7874 * a, 0.7152 - 0.7152 * a, 0.0722 - 0.0722 * a],
+                    [0.2126 - 0.2126 * a, 0.7152 + 0.2848 * a, 0.0722 - 0.0722 * a],
+                    [0.2126 - 0.2126 * a, 0.7152 - 0.7152 * a, 0.0722 + 0.9278 * a],
+                ],
+                0.0,
+            )
+        }
+
+        Filter::Saturate(s) => (
+            [
+                [0.213 + 0.787 * s, 0.715 - 0.715 * s, 0.072 - 0.072 * s],
+                [0.213 - 0.213 * s, 0.715 + 0.285 * s, 0.072 - 0.072 * s],
+                [0.213 - 0.213 * s, 0.715 - 0.715 * s, 0.072 + 0.928 * s],
+            ],
+            0.0,
+        ),
+
+        Filter::HueRotate(angle) => {
+            let (sin, cos) = angle.to_radians().sin_cos();
+            (
+                [
+                    [
+                        0.213 + cos * 0.787 - sin * 0.213,
+                        0.715 - cos * 0.715 - sin * 0.715,
+                        0.072 - cos * 0.072 + sin * 0.928,
+                    ],
+                    [
+                        0.213 - cos * 0.213 + sin * 0.143,
+                        0.715 + cos * 0.285 + sin * 0.140,
+                        0.072 - cos * 0.072 - sin * 0.283,
+                    ],
+                    [
+                        0.213 - cos * 0.213 - sin * 0.787,
+                        0.715 - cos * 0.715 + sin * 0.715,
+                        0.072 + cos * 0.928 + sin * 0.072,
+                    ],
+                ],
+                0.0,
+            )
+        }
+
+        Filter::Blur(_) | Filter::DropShadow(_) => {
+            ([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]], 0.0)
+        }
+    };
+
+    let mut matrix = [0.0; 20];
+    for (row, values) in rgb.iter().enumerate() {
+        matrix[row * 5..row * 5 + 3].copy_from_slice(values);
+        matrix[row * 5 + 4] = offset;
+    }
+    matrix[18] = 1.0;
+    matrix
+}
+
+// Returns the bounds of what's drawn by a view with filters, which blurs and drop shadows draw outside of.
+fn filter_bounds(filters: &[Filter], bounds: BoundingBox, scale_factor: f32) -> BoundingBox {
+    filters.iter().fold(bounds, |bounds, filter| match filter {
+        // A gaussian blur is visible up to three standard deviations away.
+        Filter::Blur(radius) => bounds.expand(radius.to_px().unwrap_or(0.0) * scale_factor * 1.5),
+
+        Filter::DropShadow(shadow) => {
+            let x = shadow.x_offset.to_px().unwrap_or(0.0) * scale_factor;
+            let y = shadow.y_offset.to_px().unwrap_or(0.0) * scale_factor;
+            let blur = shadow.blur_radius.as_ref().and_then(|blur| blur.to_px()).unwrap_or(0.0)
+                * scale_factor;
+            bounds.union(&bounds.offset(x, y).expand(blur * 1.5))
+        }
+
+        _ => bounds,
+    })
+}
+
 struct ZEntity {
-    pub index: i32,
     pub entity: Entity,
     pub visible: bool,
 }
 
-impl Ord for ZEntity {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.index.cmp(&self.index)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::{layout_system, style_system};
+
+    fn context() -> Context {
+        let mut cx = Context::default();
+        cx.style.dpi_factor = 1.0;
+        cx.style.width.insert(Entity::root(), Units::Pixels(800.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(600.0));
+        cx
     }
-}
-impl PartialOrd for ZEntity {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+
+    fn is_drawn(cx: &Context, entity: Entity) -> bool {
+        let window = cx.cache.get_bounds(Entity::root());
+        !is_culled(&draw_bounds(&cx.style, &cx.cache, &cx.tree, entity), &window)
     }
-}
-impl PartialEq for ZEntity {
-    fn eq(&self, other: &Self) -> bool {
-        self.index == other.index
+
+    #[test]
+    fn scrolled_out_rows_are_culled() {
+        let mut cx = context();
+
+        let mut rows = Vec::new();
+        VStack::new(&mut cx, |cx| {
+            VStack::new(cx, |cx| {
+                for _ in 0..1000 {
+                    rows.push(Element::new(cx).height(Pixels(20.0)).entity());
+                }
+            })
+            .height(Auto)
+            .top(Pixels(-2000.0));
+        })
+        .height(Pixels(100.0))
+        .overflow(Overflow::Hidden);
+
+        layout_system(&mut cx);
+        transform_system(&mut cx);
+
+        // Only the rows within the clipped view are drawn.
+        let drawn = rows.iter().filter(|row| is_drawn(&cx, **row)).collect::<Vec<_>>();
+        assert_eq!(drawn, rows[100..105].iter().collect::<Vec<_>>());
     }
-}
 
-impl Eq for ZEntity {}
+    #[test]
+    fn transformed_children_outside_visible_overflow_are_drawn() {
+        let mut cx = context();
+
+        let mut child = Entity::null();
+        let parent = VStack::new(&mut cx, |cx| {
+            child = Element::new(cx)
+                .size(Pixels(50.0))
+                .translate((Pixels(-900.0), Pixels(0.0)))
+                .entity();
+        })
+        .size(Pixels(100.0))
+        .left(Pixels(1000.0))
+        .entity();
+
+        layout_system(&mut cx);
+        transform_system(&mut cx);
+
+        // The parent is outside of the window but its translated child is within it.
+        assert!(!cx.cache.get_bounds(parent).intersects(&cx.cache.get_bounds(Entity::root())));
+        assert!(is_drawn(&cx, child));
+        assert!(is_drawn(&cx, parent));
+
+        // Clipping the parent hides the child.
+        cx.style.overflowx.insert(parent, Overflow::Hidden);
+        cx.style.overflowy.insert(parent, Overflow::Hidden);
+        transform_system(&mut cx);
+        assert!(!is_drawn(&cx, child));
+        assert!(!is_drawn(&cx, parent));
+    }
+
+    #[test]
+    fn shadows_are_drawn_outside_of_a_parent_with_visible_overflow() {
+        let mut cx = context();
+
+        let mut child = Entity::null();
+        let parent = VStack::new(&mut cx, |cx| {
+            child = Element::new(cx)
+                .size(Pixels(100.0))
+                .corner_radius(Pixels(8.0))
+                .box_shadow(Shadow::from("20px 0px 10px 5px black"))
+                .entity();
+        })
+        .size(Pixels(100.0))
+        .entity();
+
+        layout_system(&mut cx);
+        transform_system(&mut cx);
+
+        // The shadow is offset, then spread and blurred past the right of the parent.
+        let right =
+            |cx: &Context, entity| draw_bounds(&cx.style, &cx.cache, &cx.tree, entity).right();
+        assert_eq!(right(&cx, child), 135.0);
+        assert_eq!(right(&cx, parent), 135.0);
+
+        cx.style.overflowx.insert(parent, Overflow::Hidden);
+        transform_system(&mut cx);
+        assert_eq!(right(&cx, child), 100.0);
+    }
+
+    #[test]
+    fn layered_views_escape_the_clip_of_their_ancestors() {
+        let mut cx = context();
+
+        let mut popup = Entity::null();
+        VStack::new(&mut cx, |cx| {
+            popup = Element::new(cx).size(Pixels(50.0)).top(Pixels(200.0)).z_index(1).entity();
+        })
+        .height(Pixels(100.0))
+        .overflow(Overflow::Hidden);
+
+        layout_system(&mut cx);
+        transform_system(&mut cx);
+
+        assert!(is_drawn(&cx, popup));
+    }
+
+    #[test]
+    fn layered_views_are_painted_outside_of_the_clip_of_their_ancestors() {
+        let mut cx = context();
+        cx.windows.insert(Entity::root(), WindowState::default());
+
+        let mut popup = Entity::null();
+        let mut item = Entity::null();
+        VStack::new(&mut cx, |cx| {
+            popup = VStack::new(cx, |cx| {
+                item = Element::new(cx)
+                    .size(Pixels(50.0))
+                    .top(Pixels(100.0))
+                    .background_color(Color::blue())
+                    .entity();
+            })
+            .size(Pixels(100.0))
+            .top(Pixels(200.0))
+            .overflow(Overflow::Hidden)
+            .background_color(Color::red())
+            .z_index(1)
+            .entity();
+        })
+        .height(Pixels(100.0))
+        .overflow(Overflow::Hidden);
+
+        style_system(&mut cx);
+        layout_system(&mut cx);
+        cx.needs_redraw(Entity::root());
+        let mut surface = skia_safe::surfaces::raster_n32_premul((800, 600)).unwrap();
+        let mut dirty_surface = skia_safe::surfaces::raster_n32_premul((800, 600)).unwrap();
+        draw_system(&mut cx, Entity::root(), &mut surface, &mut dirty_surface);
+
+        // The popup is clipped to its own bounds rather than to those of its ancestor, and still clips its children.
+        assert_eq!(cx.cache.clip_path.get(popup), Some(&cx.cache.get_bounds(popup)));
+        assert_eq!(cx.cache.clip_path.get(item), Some(&cx.cache.get_bounds(popup)));
+
+        let pixels = surface.image_snapshot().peek_pixels().unwrap();
+        assert_eq!(pixels.get_color((50, 250)), skia_safe::Color::RED);
+        assert_eq!(pixels.get_color((25, 325)), skia_safe::Color::TRANSPARENT);
+    }
+
+    #[test]
+    fn views_in_a_higher_layer_escape_the_clip_of_their_ancestors() {
+        let mut cx = context();
+
+        let mut tooltip = Entity::null();
+        VStack::new(&mut cx, |cx| {
+            tooltip = Element::new(cx)
+                .size(Pixels(50.0))
+                .top(Pixels(200.0))
+                .layer(Layer::Tooltip)
+                .entity();
+        })
+        .height(Pixels(100.0))
+        .overflow(Overflow::Hidden);
+
+        layout_system(&mut cx);
+        transform_system(&mut cx);
+
+        assert!(is_drawn(&cx, tooltip));
+
+        // A view explicitly in the base layer stays clipped.
+        cx.style.layer.insert(tooltip, Layer::Base);
+        transform_system(&mut cx);
+        assert!(!is_drawn(&cx, tooltip));
+    }
+
+    #[test]
+    fn filters_are_applied_to_the_layer() {
+        let mut surface = skia_safe::surfaces::raster_n32_premul((10, 10)).unwrap();
+        let canvas = surface.canvas();
+
+        let mut paint = Paint::default();
+        paint.set_image_filter(image_filter(
+            &[Filter::Saturate(0.5), Filter::Grayscale(1.0)],
+            None,
+            1.0,
+        ));
+        canvas.save_layer(&SaveLayerRec::default().paint(&paint));
+        let mut red = Paint::default();
+        red.set_color(skia_safe::Color::RED);
+        canvas.draw_rect(Rect::from_wh(10.0, 10.0), &red);
+        canvas.restore();
+
+        let pixels = surface.image_snapshot().peek_pixels().unwrap();
+        let color = pixels.get_color((5, 5));
+        assert_eq!(color.r(), color.g());
+        assert_eq!(color.g(), color.b());
+        assert!(color.r() > 0 && color.r() < 255);
+    }
+
+    #[test]
+    fn opacity_blend_mode_and_filters_compose() {
+        let mut surface = skia_safe::surfaces::raster_n32_premul((10, 10)).unwrap();
+        let canvas = surface.canvas();
+        let bounds = BoundingBox::from_min_max(0.0, 0.0, 10.0, 10.0);
+        let fill = |canvas: &Canvas, color: skia_safe::Color, width: f32| {
+            let mut paint = Paint::default();
+            paint.set_color(color);
+            canvas.draw_rect(Rect::from_wh(width, 10.0), &paint);
+        };
+        let color = |surface: &mut Surface, x: i32| {
+            let pixels = surface.image_snapshot().peek_pixels().unwrap().get_color((x, 5));
+            [pixels.r(), pixels.g(), pixels.b()]
+        };
+        let assert_near = |actual: [u8; 3], expected: [u8; 3]| {
+            assert!(actual.iter().zip(expected).all(|(a, e)| a.abs_diff(e) <= 2), "{actual:?}");
+        };
+
+        // The backdrop is filtered and faded by the opacity, and the view is then multiplied with it, also at the
+        // opacity of the view.
+        fill(canvas, skia_safe::Color::RED, 10.0);
+        let count = save_layers(
+            canvas,
+            bounds,
+            0.5,
+            BlendMode::Multiply,
+            None,
+            Some(&[Filter::Grayscale(1.0)]),
+            1.0,
+        );
+        fill(canvas, skia_safe::Color::YELLOW, 10.0);
+        canvas.restore_to_count(count.unwrap());
+        assert_near(color(&mut surface, 5), [155, 27, 14]);
+
+        // Filters only apply to the view, not to what's beneath it.
+        let canvas = surface.canvas();
+        fill(canvas, skia_safe::Color::RED, 10.0);
+        let count = save_layers(
+            canvas,
+            bounds,
+            1.0,
+            BlendMode::Normal,
+            Some(&[Filter::Grayscale(1.0)]),
+            None,
+            1.0,
+        );
+        fill(canvas, skia_safe::Color::BLUE, 5.0);
+        canvas.restore_to_count(count.unwrap());
+        assert_near(color(&mut surface, 2), [18, 18, 18]);
+        assert_near(color(&mut surface, 7), [255, 0, 0]);
+
+        // A view without any of them isn't drawn into a layer.
+        assert_eq!(
+            save_layers(surface.canvas(), bounds, 1.0, BlendMode::Normal, Some(&[]), None, 1.0),
+            None
+        );
+    }
+
+    #[test]
+    fn blurred_views_are_drawn_outside_of_their_bounds() {
+        let mut cx = context();
+
+        let element = Element::new(&mut cx)
+            .size(Pixels(50.0))
+            .left(Pixels(-60.0))
+            .filter(Filter::Blur(Length::px(10.0)))
+            .entity();
+
+        layout_system(&mut cx);
+        transform_system(&mut cx);
+
+        assert!(is_drawn(&cx, element));
+
+        cx.style.filter.insert(element, vec![Filter::Grayscale(1.0)]);
+        assert!(!is_drawn(&cx, element));
+    }
+
+    // Run with `cargo test --release -p vizia_core -- --ignored --nocapture draw_scrolled_list`.
+    #[test]
+    #[ignore]
+    fn draw_scrolled_list() {
+        const FRAMES: u32 = 20;
+        for rows in [100, 1000, 10000] {
+            let mut cx = context();
+            cx.windows.insert(Entity::root(), WindowState::default());
+
+            // A viewport of the height of 30 rows, scrolled to the middle of the list.
+            VStack::new(&mut cx, |cx| {
+                VStack::new(cx, |cx| {
+                    for i in 0..rows {
+                        Element::new(cx).height(Pixels(20.0)).background_color(if i % 2 == 0 {
+                            Color::red()
+                        } else {
+                            Color::blue()
+                        });
+                    }
+                })
+                .height(Auto)
+                .top(Pixels(-(rows as f32) * 10.0));
+            })
+            .height(Pixels(600.0))
+            .overflow(Overflow::Hidden);
+            style_system(&mut cx);
+            layout_system(&mut cx);
+
+            let mut surface = skia_safe::surfaces::raster_n32_premul((800, 600)).unwrap();
+            let mut dirty_surface = skia_safe::surfaces::raster_n32_premul((800, 600)).unwrap();
+            let start = Instant::now();
+            for _ in 0..FRAMES {
+                cx.needs_redraw(Entity::root());
+                draw_system(&mut cx, Entity::root(), &mut surface, &mut dirty_surface);
+            }
+            println!("{rows} rows: {:?} per frame", start.elapsed() / FRAMES);
+        }
+    }
+}
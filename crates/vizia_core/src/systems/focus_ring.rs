@@ -0,0 +1,112 @@
+use skia_safe::Canvas;
+
+use crate::prelude::*;
+
+// How long the focus ring takes to scale in around a view which gains visible focus.
+const SCALE_IN_DURATION: Duration = Duration::from_millis(150);
+// How far outside its final position the focus ring starts when it scales in, in logical pixels.
+const SCALE_IN_DISTANCE: f32 = 4.0;
+
+/// The focus ring drawn by the framework around the view with visible keyboard focus.
+pub(crate) struct FocusRing {
+    entity: Entity,
+    // When the ring started scaling in around the entity, or `None` if it isn't animated.
+    start: Option<Instant>,
+}
+
+impl Default for FocusRing {
+    fn default() -> Self {
+        Self { entity: Entity::null(), start: None }
+    }
+}
+
+impl FocusRing {
+    /// Returns true while the focus ring is scaling in.
+    pub fn is_animating(&self) -> bool {
+        self.start.is_some_and(|start| start.elapsed() < SCALE_IN_DURATION)
+    }
+}
+
+// Returns true if the framework should draw a focus ring around the entity.
+fn has_focus_ring(cx: &Context, entity: Entity) -> bool {
+    cx.entity_manager.is_alive(entity)
+        && !cx.tree.is_window(entity)
+        && cx
+            .style
+            .pseudo_classes
+            .get(entity)
+            .is_some_and(|pseudo_classes| pseudo_classes.contains(PseudoClassFlags::FOCUS_VISIBLE))
+        && cx.style.focus_ring.get(entity).copied().unwrap_or(true)
+        && entity.visible(&cx.style)
+}
+
+/// Draws the focus ring around the focused view, if it has visible focus and is within the window.
+///
+/// The ring is drawn over the views of the window, so it isn't clipped by scroll views or other ancestors which
+/// clip their content.
+pub(crate) fn draw_focus_ring(cx: &mut Context, window_entity: Entity, canvas: &Canvas) {
+    let focused = cx.focused;
+    if !has_focus_ring(cx, focused) {
+        cx.focus_ring.entity = Entity::null();
+        cx.focus_ring.start = None;
+        return;
+    }
+
+    if cx.tree.get_parent_window(focused).unwrap_or(Entity::root()) != window_entity {
+        return;
+    }
+
+    if cx.focus_ring.entity != focused {
+        cx.focus_ring.entity = focused;
+        cx.focus_ring.start =
+            if cx.interaction_settings.reduced_motion { None } else { Some(Instant::now()) };
+    }
+
+    // The ring eases in from outside its final position.
+    let progress = cx.focus_ring.start.map_or(1.0, |start| {
+        (start.elapsed().as_secs_f32() / SCALE_IN_DURATION.as_secs_f32()).min(1.0)
+    });
+    let expand = (1.0 - progress).powi(3) * SCALE_IN_DISTANCE * cx.scale_factor();
+
+    let mut draw_cx = DrawContext {
+        current: focused,
+        style: &cx.style,
+        cache: &cx.cache,
+        tree: &cx.tree,
+        data: &cx.data,
+        views: &mut cx.views,
+        resource_manager: &cx.resource_manager,
+        text_context: &mut cx.text_context,
+        modifiers: &cx.modifiers,
+        mouse: &cx.mouse,
+        windows: &mut cx.windows,
+    };
+
+    canvas.save();
+    if let Some(transform) = cx.cache.transform.get(focused) {
+        canvas.set_matrix(&(transform.into()));
+    }
+    draw_cx.draw_focus_ring(canvas, expand);
+    canvas.restore();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn focus_ring_follows_visible_focus() {
+        let mut cx = Context::default();
+        let button = Element::new(&mut cx).focusable(true).entity();
+        let opted_out = Element::new(&mut cx).focusable(true).focus_ring(false).entity();
+
+        cx.with_current(button, |cx| cx.focus_with_visibility(true));
+        assert!(has_focus_ring(&cx, button));
+
+        cx.with_current(button, |cx| cx.focus_with_visibility(false));
+        assert!(!has_focus_ring(&cx, button));
+
+        cx.with_current(opted_out, |cx| cx.focus_with_visibility(true));
+        assert!(!has_focus_ring(&cx, opted_out));
+    }
+}
@@ -1,6 +1,5 @@
-use std::{cmp::Ordering, collections::BinaryHeap};
-
 use crate::prelude::*;
+use crate::style::{scroll_transform, scrollbar_at};
 use log::debug;
 use skia_safe::Matrix;
 use vizia_storage::{DrawChildIterator, LayoutParentIterator};
@@ -9,36 +8,60 @@ use vizia_storage::{DrawChildIterator, LayoutParentIterator};
 pub fn hover_system(cx: &mut Context, window_entity: Entity) {
     cx.current = window_entity;
 
-    if let Some(pseudo_classes) = cx.style.pseudo_classes.get(window_entity) {
-        if !pseudo_classes.contains(PseudoClassFlags::OVER) {
-            return;
+    // A touch hovers views while it is pressed, even though the backend may not report it entering the window.
+    if cx.mouse.pointer_type != PointerType::Touch {
+        if let Some(pseudo_classes) = cx.style.pseudo_classes.get(window_entity) {
+            if !pseudo_classes.contains(PseudoClassFlags::OVER) {
+                return;
+            }
         }
     }
 
-    let mut queue = BinaryHeap::new();
-    let pointer_events: bool =
-        cx.style.pointer_events.get(window_entity).copied().unwrap_or_default().into();
-    queue.push(ZEntity { index: 0, pointer_events, entity: window_entity });
+    let previously_over = std::mem::take(&mut cx.cache.over);
+
+    let mut queue = StackQueue::new();
+    queue.push(
+        StackOrder::default(),
+        ZEntity { entity: window_entity, transform: Matrix::new_identity() },
+    );
     let mut hovered = window_entity;
-    let transform = Matrix::new_identity();
-    // let clip_bounds = cx.cache.get_bounds(window_entity);
-    let clip_bounds: BoundingBox =
-        BoundingBox { x: -f32::MAX / 2.0, y: -f32::MAX / 2.0, w: f32::MAX, h: f32::MAX };
-    while !queue.is_empty() {
-        let zentity = queue.pop().unwrap();
+    // Views in higher layers are hit-tested last, so that they are hovered over the views below them. Like when they
+    // are drawn, they keep the transforms of their ancestors but not their clips. A view in a higher layer only
+    // replaces the hovered view if it receives pointer events itself, so views below a non-interactive overlay stay
+    // hovered.
+    while let Some((order, zentity)) = queue.pop() {
+        let parent_pointer_events = cx
+            .tree
+            .get_layout_parent(zentity.entity)
+            .is_none_or(|parent| receives_pointer_events(&cx.style, &cx.tree, parent));
         cx.with_current(zentity.entity, |cx| {
             hover_entity(
                 &mut EventContext::new(cx),
-                zentity.index,
-                zentity.pointer_events,
+                order,
+                parent_pointer_events,
                 &mut queue,
                 &mut hovered,
-                transform,
-                &clip_bounds,
+                zentity.transform,
+                true,
             );
         });
     }
 
+    // The views in subtrees which were skipped because they're away from the cursor aren't over it any more.
+    for entity in previously_over {
+        if entity != window_entity
+            && !cx.cache.over.contains(&entity)
+            && cx.entity_manager.is_alive(entity)
+        {
+            if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(entity) {
+                if pseudo_classes.intersects(PseudoClassFlags::OVER | PseudoClassFlags::HOVER) {
+                    pseudo_classes.set(PseudoClassFlags::OVER | PseudoClassFlags::HOVER, false);
+                    cx.style.needs_restyle(entity);
+                }
+            }
+        }
+    }
+
     // Set hover state for hovered view and ancestors
     let parent_iter = LayoutParentIterator::new(&cx.tree, hovered);
     for ancestor in parent_iter {
@@ -87,12 +110,12 @@ pub fn hover_system(cx: &mut Context, window_entity: Entity) {
 
 fn hover_entity(
     cx: &mut EventContext,
-    current_z: i32,
+    current_order: StackOrder,
     parent_pointer_events: bool,
-    queue: &mut BinaryHeap<ZEntity>,
+    queue: &mut StackQueue<ZEntity>,
     hovered: &mut Entity,
     parent_transform: Matrix,
-    clip_bounds: &BoundingBox,
+    within_parent_clip: bool,
 ) {
     // Skip if non-hoverable (will skip any descendants)
     let hoverable = cx
@@ -114,24 +137,29 @@ fn hover_entity(
         return;
     }
 
+    // Skip the subtree if the cursor is outside of the bounds of the view and all of its descendants as they're drawn,
+    // with a pixel to spare for rounding when they were transformed into window coordinates.
+    if let Some(hover_bounds) = cx.cache.hover_bounds.get(cx.current) {
+        if !hover_bounds.expand(1.0).contains_point(cx.mouse.cursor_x, cx.mouse.cursor_y) {
+            return;
+        }
+    }
+
+    // Push to queue if the layer or z-index is higher than the current one.
+    let order = cx.style.stack_order(cx.current, current_order.layer);
+    if order > current_order {
+        queue.push(order, ZEntity { entity: cx.current, transform: parent_transform });
+        return;
+    }
+
     let pointer_events = cx
         .style
         .pointer_events
         .get(cx.current)
         .copied()
-        .map(|pointer_events| match pointer_events {
-            PointerEvents::Auto => true,
-            PointerEvents::None => false,
-        })
+        .map(bool::from)
         .unwrap_or(parent_pointer_events);
 
-    // Push to queue if the z-index is higher than the current z-index.
-    let z_index = cx.style.z_index.get(cx.current).copied().unwrap_or_default();
-    if z_index > current_z {
-        queue.push(ZEntity { index: z_index, entity: cx.current, pointer_events });
-        return;
-    }
-
     let bounds = cx.bounds();
 
     let cursor_x = cx.mouse.cursor_x;
@@ -141,84 +169,391 @@ fn hover_entity(
         return;
     }
 
-    let mut transform = parent_transform;
-
-    transform = cx.transform() * transform;
-
-    let t = transform.invert().unwrap();
-    let t = t.map_point((cursor_x, cursor_y));
-    let tx = t.x;
-    let ty = t.y;
-    let clipping = clip_bounds.intersection(&cx.clip_region());
-
-    let b = bounds.intersection(&clipping);
-    // let b = bounds;
+    // The cursor is mapped into the coordinates of the view with the same transform that the view is drawn with, and
+    // tested against the clip of the view and those of its ancestors, each in their own coordinates.
+    let transform = parent_transform * cx.transform();
+    let Some(inverse) = transform.invert() else {
+        return;
+    };
+    let point = inverse.map_point((cursor_x, cursor_y));
+    let (tx, ty) = (point.x, point.y);
+    // Rounded corners are cut out of the bounds of a view, and out of its clip where it clips its overflow along both
+    // axes, in the same way as they're drawn, without building their paths.
+    let corners = cx.cache.corner_radii.get(cx.current).copied();
+    let clips = |overflow: Option<&Overflow>| overflow.is_some_and(|o| *o != Overflow::Visible);
+    let clips_corners =
+        clips(cx.style.overflowx.get(cx.current)) && clips(cx.style.overflowy.get(cx.current));
+    let clip = cx.clip_region();
+    let within_clip = within_parent_clip
+        && tx >= clip.left()
+        && tx < clip.right()
+        && ty >= clip.top()
+        && ty < clip.bottom()
+        && (!clips_corners || corners.is_none_or(|corners| corners.contains(clip, (tx, ty))));
 
     if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(cx.current) {
         pseudo_classes.set(PseudoClassFlags::HOVER, false);
     }
 
-    if pointer_events {
-        if tx >= b.left() && tx < b.right() && ty >= b.top() && ty < b.bottom() {
-            *hovered = cx.current;
-
-            if !cx
-                .style
-                .pseudo_classes
-                .get(cx.current)
-                .copied()
-                .unwrap_or_default()
-                .contains(PseudoClassFlags::OVER)
-            {
-                if let Some(pseudo_class) = cx.style.pseudo_classes.get_mut(cx.current) {
-                    pseudo_class.set(PseudoClassFlags::OVER, true);
+    let over = cx
+        .style
+        .pseudo_classes
+        .get(cx.current)
+        .copied()
+        .unwrap_or_default()
+        .contains(PseudoClassFlags::OVER);
+
+    // A view which doesn't receive pointer events is never over the cursor, so that it can't be hovered in place of
+    // the views below it, although its descendants can be if they receive pointer events.
+    if pointer_events
+        && within_clip
+        && tx >= bounds.left()
+        && tx < bounds.right()
+        && ty >= bounds.top()
+        && ty < bounds.bottom()
+        && corners.is_none_or(|corners| corners.contains(bounds, (tx, ty)))
+        && cx.views.get(&cx.current).is_none_or(|view| view.hit_test(bounds, tx, ty))
+    {
+        *hovered = cx.current;
+        cx.cache.over.push(cx.current);
 
-                    cx.needs_restyle();
-                }
-            }
-        } else if cx
-            .style
-            .pseudo_classes
-            .get(cx.current)
-            .copied()
-            .unwrap_or_default()
-            .contains(PseudoClassFlags::OVER)
-        {
+        if !over {
             if let Some(pseudo_class) = cx.style.pseudo_classes.get_mut(cx.current) {
-                pseudo_class.set(PseudoClassFlags::OVER, false);
+                pseudo_class.set(PseudoClassFlags::OVER, true);
 
                 cx.needs_restyle();
             }
         }
+    } else if over {
+        if let Some(pseudo_class) = cx.style.pseudo_classes.get_mut(cx.current) {
+            pseudo_class.set(PseudoClassFlags::OVER, false);
+
+            cx.needs_restyle();
+        }
+    }
+
+    // The scrollbars of a view which scrolls its overflow are drawn over its children, so they're hit instead of them,
+    // and the children are hit where they're drawn after being scrolled.
+    if *hovered == cx.current
+        && scrollbar_at(cx.style, cx.cache, cx.tree, cx.current, (tx, ty)).is_some()
+    {
+        return;
     }
+    let transform = transform * scroll_transform(cx.style, cx.cache, cx.tree, cx.current);
 
     let child_iter = DrawChildIterator::new(cx.tree, cx.current);
     for child in child_iter {
         cx.current = child;
-        hover_entity(cx, current_z, pointer_events, queue, hovered, transform, &clipping);
+        hover_entity(cx, current_order, pointer_events, queue, hovered, transform, within_clip);
     }
 }
 
+// Returns whether a view receives pointer events, which it inherits from the nearest of its ancestors which sets
+// `pointer-events` if it doesn't set it itself.
+fn receives_pointer_events(style: &Style, tree: &Tree<Entity>, entity: Entity) -> bool {
+    LayoutParentIterator::new(tree, entity)
+        .find_map(|entity| style.pointer_events.get(entity).copied())
+        .unwrap_or_default()
+        .into()
+}
+
 struct ZEntity {
-    pub index: i32,
-    pub pointer_events: bool,
     pub entity: Entity,
+    // The transform of the layout parent of the entity in window coordinates.
+    pub transform: Matrix,
 }
 
-impl Ord for ZEntity {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.index.cmp(&self.index)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::{layout_system, style_system, transform_system};
+
+    const STYLE: &str = r#"
+        .outer { translate: 20px 10px; scale: 1.5; }
+        .middle { rotate: 30deg; }
+        .inner { transform: translate(5px, 5px) scale(0.8); transform-origin: left top; }
+        .layered { z-index: 1; }
+        .tilted { transform: perspective(200px) rotateY(40deg) rotateX(20deg) skewX(10deg); }
+    "#;
+
+    // Builds views nested within translated, scaled and rotated ancestors, returning the innermost view.
+    fn build(cx: &mut Context, class: &str) -> Entity {
+        cx.style.dpi_factor = 1.0;
+        cx.style.width.insert(Entity::root(), Units::Pixels(800.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(600.0));
+        cx.add_stylesheet(STYLE).unwrap();
+
+        let mut child = Entity::null();
+        VStack::new(cx, |cx| {
+            VStack::new(cx, |cx| {
+                VStack::new(cx, |cx| {
+                    child = Element::new(cx)
+                        .class(class)
+                        .left(Pixels(20.0))
+                        .top(Pixels(15.0))
+                        .size(Pixels(40.0))
+                        .entity();
+                })
+                .class("inner")
+                .left(Pixels(10.0))
+                .top(Pixels(10.0))
+                .size(Pixels(100.0));
+            })
+            .class("middle")
+            .left(Pixels(30.0))
+            .top(Pixels(20.0))
+            .size(Pixels(150.0));
+        })
+        .class("outer")
+        .left(Pixels(50.0))
+        .top(Pixels(40.0))
+        .size(Pixels(300.0));
+
+        style_system(cx);
+        layout_system(cx);
+        transform_system(cx);
+
+        if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(Entity::root()) {
+            pseudo_classes.set(PseudoClassFlags::OVER, true);
+        }
+
+        child
     }
-}
-impl PartialOrd for ZEntity {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+
+    fn hovered_at(cx: &mut Context, (x, y): (f32, f32)) -> Entity {
+        cx.mouse.cursor_x = x;
+        cx.mouse.cursor_y = y;
+        hover_system(cx, Entity::root());
+        cx.hovered
     }
-}
-impl PartialEq for ZEntity {
-    fn eq(&self, other: &Self) -> bool {
-        self.index == other.index
+
+    // Returns the points just inside and just outside of each corner of the entity, where it is drawn in the window.
+    fn corners(cx: &Context, entity: Entity) -> Vec<((f32, f32), (f32, f32))> {
+        let bounds = cx.cache.get_bounds(entity);
+        let transform = cx.cache.transform.get(entity).copied().unwrap();
+        let drawn = |x: f32, y: f32| {
+            let point = transform.map_point((x, y));
+            (point.x, point.y)
+        };
+
+        let (center_x, center_y) = bounds.center();
+        [
+            (bounds.left(), bounds.top()),
+            (bounds.right(), bounds.top()),
+            (bounds.right(), bounds.bottom()),
+            (bounds.left(), bounds.bottom()),
+        ]
+        .into_iter()
+        .map(|(x, y)| {
+            let inward = ((center_x - x).signum(), (center_y - y).signum());
+            (drawn(x + inward.0, y + inward.1), drawn(x - inward.0, y - inward.1))
+        })
+        .collect()
     }
-}
 
-impl Eq for ZEntity {}
+    #[test]
+    fn hit_test_matches_drawn_corners() {
+        let mut cx = Context::default();
+        let child = build(&mut cx, "child");
+
+        for (inside, outside) in corners(&cx, child) {
+            assert_eq!(hovered_at(&mut cx, inside), child, "{:?} should hover the child", inside);
+            assert_ne!(
+                hovered_at(&mut cx, outside),
+                child,
+                "{:?} shouldn't hover the child",
+                outside
+            );
+        }
+    }
+
+    #[test]
+    fn layered_views_keep_the_transforms_of_their_ancestors() {
+        let mut cx = Context::default();
+        let child = build(&mut cx, "layered");
+
+        for (inside, outside) in corners(&cx, child) {
+            assert_eq!(hovered_at(&mut cx, inside), child, "{:?} should hover the child", inside);
+            assert_ne!(
+                hovered_at(&mut cx, outside),
+                child,
+                "{:?} shouldn't hover the child",
+                outside
+            );
+        }
+    }
+
+    #[test]
+    fn hit_test_matches_drawn_corners_of_3d_transforms() {
+        let mut cx = Context::default();
+        let child = build(&mut cx, "tilted");
+
+        for (inside, outside) in corners(&cx, child) {
+            assert_eq!(hovered_at(&mut cx, inside), child, "{:?} should hover the child", inside);
+            assert_ne!(
+                hovered_at(&mut cx, outside),
+                child,
+                "{:?} shouldn't hover the child",
+                outside
+            );
+        }
+    }
+
+    #[test]
+    fn rounded_corners_and_clip_paths_are_cut_out_of_hits() {
+        let mut cx = Context::default();
+        cx.style.dpi_factor = 1.0;
+        cx.style.width.insert(Entity::root(), Units::Pixels(800.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(600.0));
+        cx.add_stylesheet(".clipped { clip-path: inset(20px); }").unwrap();
+
+        let mut pill = Entity::null();
+        let behind = VStack::new(&mut cx, |cx| {
+            pill = Element::new(cx)
+                .left(Pixels(50.0))
+                .top(Pixels(50.0))
+                .width(Pixels(200.0))
+                .height(Pixels(40.0))
+                .corner_radius("50%")
+                .entity();
+        })
+        .size(Pixels(400.0))
+        .entity();
+
+        // A circle clipping its overflow, inset by its clip path, which its child fills.
+        let mut content = Entity::null();
+        VStack::new(&mut cx, |cx| {
+            content = Element::new(cx).size(Stretch(1.0)).entity();
+        })
+        .class("clipped")
+        .position_type(PositionType::Absolute)
+        .left(Pixels(500.0))
+        .top(Pixels(0.0))
+        .size(Pixels(200.0))
+        .corner_radius("50%")
+        .overflow(Overflow::Hidden);
+
+        style_system(&mut cx);
+        layout_system(&mut cx);
+        transform_system(&mut cx);
+        if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(Entity::root()) {
+            pseudo_classes.set(PseudoClassFlags::OVER, true);
+        }
+
+        // The transparent corners of the pill hover the view behind it.
+        assert_eq!(hovered_at(&mut cx, (150.0, 52.0)), pill);
+        assert_eq!(hovered_at(&mut cx, (60.0, 70.0)), pill);
+        assert_eq!(hovered_at(&mut cx, (52.0, 52.0)), behind);
+        assert_eq!(hovered_at(&mut cx, (248.0, 88.0)), behind);
+
+        // The clip is the inset rectangle with the rounded corners of the view scaled down to fit, which is a circle
+        // with a radius of 80px.
+        assert_eq!(hovered_at(&mut cx, (600.0, 100.0)), content);
+        assert_eq!(hovered_at(&mut cx, (525.0, 100.0)), content);
+        assert_eq!(hovered_at(&mut cx, (510.0, 100.0)), Entity::root());
+        assert_eq!(hovered_at(&mut cx, (530.0, 30.0)), Entity::root());
+    }
+
+    // Builds rows of three cells, like a long table, returning the cells.
+    fn build_table(cx: &mut Context, rows: usize) -> Vec<Entity> {
+        cx.style.dpi_factor = 1.0;
+        cx.style.width.insert(Entity::root(), Units::Pixels(800.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(600.0));
+
+        let mut cells = Vec::new();
+        VStack::new(cx, |cx| {
+            for _ in 0..rows {
+                HStack::new(cx, |cx| {
+                    for _ in 0..3 {
+                        cells.push(
+                            Element::new(cx).width(Pixels(100.0)).height(Pixels(20.0)).entity(),
+                        );
+                    }
+                })
+                .width(Pixels(300.0))
+                .height(Pixels(20.0));
+            }
+        });
+
+        style_system(cx);
+        layout_system(cx);
+        transform_system(cx);
+
+        if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(Entity::root()) {
+            pseudo_classes.set(PseudoClassFlags::OVER, true);
+        }
+
+        cells
+    }
+
+    #[test]
+    fn hover_bounds_skip_subtrees_away_from_the_cursor() {
+        let mut cx = Context::default();
+        let cells = build_table(&mut cx, 100);
+
+        // The hover bounds of a row contain its cells, including one which is translated outside of it.
+        let moved = cells[30];
+        cx.style.translate.insert(moved, Translate::new(Pixels(500.0), Pixels(0.0)));
+        transform_system(&mut cx);
+        let row = cx.tree.get_layout_parent(moved).unwrap();
+        assert_eq!(
+            cx.cache.hover_bounds.get(row),
+            Some(&BoundingBox { x: 0.0, y: 200.0, w: 600.0, h: 20.0 })
+        );
+        assert_eq!(hovered_at(&mut cx, (550.0, 210.0)), moved);
+        assert_eq!(hovered_at(&mut cx, (150.0, 210.0)), cells[31]);
+
+        // A view which was hovered stops being hovered when its row is skipped.
+        let flags =
+            |cx: &Context, entity| cx.style.pseudo_classes.get(entity).copied().unwrap_or_default();
+        assert!(flags(&cx, cells[31]).contains(PseudoClassFlags::OVER | PseudoClassFlags::HOVER));
+        assert_eq!(hovered_at(&mut cx, (150.0, 410.0)), cells[61]);
+        assert!(!flags(&cx, cells[31]).intersects(PseudoClassFlags::OVER | PseudoClassFlags::HOVER));
+
+        // Views waiting to be redrawn keep their hover bounds, which stay valid until the layout changes.
+        cx.windows.insert(Entity::root(), WindowState::default());
+        cx.needs_redraw(cells[61]);
+        assert_eq!(hovered_at(&mut cx, (150.0, 410.0)), cells[61]);
+        assert!(cx.cache.hover_bounds.get(row).is_some());
+
+        // Every view is hit-tested while the hover bounds are out of date.
+        cx.style.translate.remove(moved);
+        cx.style.needs_relayout();
+        layout_system(&mut cx);
+        assert!(cx.cache.hover_bounds.is_empty());
+        assert_ne!(hovered_at(&mut cx, (550.0, 210.0)), moved);
+        assert_eq!(hovered_at(&mut cx, (50.0, 210.0)), moved);
+    }
+
+    // Run with `cargo test --release -p vizia_core -- --ignored --nocapture large_tree`.
+    #[test]
+    #[ignore]
+    fn large_tree_hover_benchmark() {
+        let mut cx = Context::default();
+        let cells = build_table(&mut cx, 2500);
+        println!("{} views", cells.len() + cells.len() / 3 + 2);
+
+        const MOVES: u32 = 200;
+        let points: Vec<(f32, f32)> =
+            (0..MOVES).map(|i| ((i * 37 % 300) as f32, (i * 53 % 600) as f32)).collect();
+
+        let start = Instant::now();
+        let mut walked = Vec::new();
+        for &point in points.iter() {
+            cx.cache.hover_bounds.clear();
+            walked.push(hovered_at(&mut cx, point));
+        }
+        println!("Hit-testing every view: {:?} per move", start.elapsed() / MOVES);
+
+        transform_system(&mut cx);
+        let start = Instant::now();
+        let mut skipped = Vec::new();
+        for &point in points.iter() {
+            skipped.push(hovered_at(&mut cx, point));
+        }
+        println!("Skipping subtrees away from the cursor: {:?} per move", start.elapsed() / MOVES);
+
+        assert_eq!(walked, skipped);
+        assert!(skipped.iter().all(|hovered| cells.contains(hovered)));
+    }
+}
@@ -1,122 +1,415 @@
+use hashbrown::HashSet;
 use morphorm::Node;
-use vizia_storage::LayoutTreeIterator;
+use vizia_storage::{LayoutChildIterator, LayoutTreeIterator};
 
+use crate::cache::CachedData;
+use crate::layout::baseline::{baseline, content_insets};
 use crate::layout::node::SubLayout;
 use crate::prelude::*;
+use crate::style::{CalcBasis, LayoutAxis};
 
-use super::{text_layout_system, text_system};
+#[cfg(debug_assertions)]
+use super::UpdateKind;
+use super::{anchor_system, text_layout_system, text_system};
+
+// The maximum number of times layout is rerun in a frame to position views anchored to views which moved, or to
+// resize views with lengths relative to parents which were resized. Each pass positions one more link of a chain of
+// anchored views.
+const MAX_LAYOUT_PASSES: usize = 8;
 
 /// Determines the size and position of views.
-/// TODO: Currently relayout is done on an entire tree rather than incrementally.
-/// Incremental relayout can be done by keeping a list of nodes that need relayout,
-/// and when a node undergoes relayout remove the descendants that have been processed from the list,
-/// then continue relayout on the remaining nodes in the list.
+///
+/// Layout is only recomputed for the subtrees containing views which have been marked as needing relayout.
+/// For each marked view the layout is rerun from the closest ancestor whose own size cannot be affected,
+/// that is an ancestor with a fixed pixel width and height and no size constraints, or otherwise the root.
 pub(crate) fn layout_system(cx: &mut Context) {
     text_system(cx);
 
+    cx.cache.entities_laid_out = 0;
+
     if cx.style.system_flags.contains(SystemFlags::RELAYOUT) {
-        // Perform layout on the whole tree.
-        Entity::root().layout(
-            &mut cx.cache,
-            &cx.tree,
-            &cx.style,
-            &mut SubLayout {
-                text_context: &mut cx.text_context,
-                resource_manager: &cx.resource_manager,
-            },
-        );
-
-        let cx = &mut EventContext::new(cx);
-
-        let iter = LayoutTreeIterator::full(cx.tree);
-
-        for entity in iter {
-            cx.current = entity;
-            if cx.style.display.get(entity).copied().unwrap_or_default() == Display::None {
-                continue;
+        for _ in 0..MAX_LAYOUT_PASSES {
+            let resized = relayout(cx);
+
+            if !anchor_system(cx) && !resized {
+                break;
             }
-            // Morphorm produces relative positions so convert to absolute.
-            if let Some(parent) = cx.tree.get_layout_parent(entity) {
-                let parent_bounds = cx.cache.get_bounds(parent);
-                if let Some(bounds) = cx.cache.bounds.get_mut(entity) {
-                    if let Some(relative_bounds) = cx.cache.relative_bounds.get(entity) {
-                        let x = relative_bounds.x + parent_bounds.x;
-                        let y = relative_bounds.y + parent_bounds.y;
-                        let w = relative_bounds.w;
-                        let h = relative_bounds.h;
+        }
 
-                        let mut geo_changed = GeoChanged::empty();
+        #[cfg(debug_assertions)]
+        cx.update_overlay.count(UpdateKind::Relayout, cx.cache.entities_laid_out);
 
-                        if x != bounds.x {
-                            geo_changed.set(GeoChanged::POSX_CHANGED, true);
-                        }
+        // The hover bounds are recomputed with the transforms when the views are next drawn.
+        cx.cache.hover_bounds.clear();
 
-                        if y != bounds.y {
-                            geo_changed.set(GeoChanged::POSY_CHANGED, true);
-                        }
+        // A relayout, retransform, or reclip, can cause the element under the cursor to change. So we push a mouse move event here to force
+        // a new event cycle and the hover system to trigger.
+        if let Some(proxy) = &cx.event_proxy {
+            let event = Event::new(WindowEvent::MouseMove(cx.mouse.cursor_x, cx.mouse.cursor_y))
+                .target(Entity::root())
+                .origin(Entity::root())
+                .propagate(Propagation::Up);
 
-                        if w != bounds.w {
-                            geo_changed.set(GeoChanged::WIDTH_CHANGED, true);
-                        }
+            proxy.send(event).expect("Failed to send event");
+        }
 
-                        if h != bounds.h {
-                            geo_changed.set(GeoChanged::HEIGHT_CHANGED, true);
-                        }
+        // Anchored views which are still waiting to be positioned are laid out in the next frame.
+        let pending = !cx.style.relayout.is_empty();
+        cx.style.system_flags.set(SystemFlags::RELAYOUT, pending);
+    }
 
-                        if let Some(geo) = cx.cache.geo_changed.get_mut(entity) {
-                            *geo = geo_changed;
-                        }
+    text_layout_system(cx);
+}
 
-                        let new_bounds = BoundingBox { x, y, w, h };
+/// Lays out the subtrees containing the views which have been marked as needing relayout.
+///
+/// Returns true if the size which the lengths of a view are relative to changed during layout, in which case the
+/// view is marked as needing relayout.
+fn relayout(cx: &mut Context) -> bool {
+    let dirty = std::mem::take(&mut cx.style.relayout);
+    let mut resized = false;
 
-                        // if new_bounds != *bounds && *bounds != BoundingBox::default() {
-                        //     cx.needs_redraw();
-                        // }
+    for layout_root in layout_roots(&cx.tree, &cx.style, dirty) {
+        update_calc_bases(cx, layout_root);
+        place_grid_children(cx, layout_root);
 
-                        *bounds = new_bounds;
-                    }
-                }
+        layout_subtree(cx, layout_root);
+
+        // Rows which grow to fit their baseline aligned children are laid out again with their new height, which
+        // moves the views after them and resizes their auto sized ancestors.
+        if align_baselines(cx, layout_root) {
+            layout_subtree(cx, layout_root);
+            align_baselines(cx, layout_root);
+        }
+
+        update_bounds(&mut EventContext::new(cx), layout_root);
+
+        for entity in update_calc_bases(cx, layout_root) {
+            cx.style.needs_relayout_entity(entity);
+            resized = true;
+        }
+
+        #[cfg(debug_assertions)]
+        cx.update_overlay.flash(UpdateKind::Relayout, layout_root);
+    }
+
+    resized
+}
+
+/// Lays out the subtree of the given entity with morphorm.
+fn layout_subtree(cx: &mut Context, layout_root: Entity) {
+    // Morphorm positions the node it is called on using its cached absolute position,
+    // so the relative position of a subtree root is restored after layout.
+    let relative_bounds = cx.cache.relative_bounds.get(layout_root).copied();
+
+    layout_root.layout(
+        &mut cx.cache,
+        &cx.tree,
+        &cx.style,
+        &mut SubLayout {
+            text_context: &mut cx.text_context,
+            resource_manager: &cx.resource_manager,
+        },
+    );
+
+    if layout_root != Entity::root() {
+        if let (Some(bounds), Some(relative_bounds)) =
+            (cx.cache.relative_bounds.get_mut(layout_root), relative_bounds)
+        {
+            bounds.x = relative_bounds.x;
+            bounds.y = relative_bounds.y;
+        }
+    }
+}
+
+/// Updates the sizes which the lengths of the views within the subtree of the given entity are resolved against,
+/// such as the `100%` of `calc(100% - 20px)`, from the current bounds of their parents. Returns the views whose
+/// sizes changed.
+fn update_calc_bases(cx: &mut Context, root: Entity) -> Vec<Entity> {
+    let scale_factor = cx.style.scale_factor();
+    let viewport = cx.cache.get_bounds(Entity::root());
+
+    let mut changed = Vec::new();
+    for entity in LayoutTreeIterator::subtree(&cx.tree, root) {
+        if !cx.style.layout_calc.has_lengths(entity) {
+            continue;
+        }
+
+        let parent = cx.tree.get_layout_parent(entity).unwrap_or(Entity::root());
+        let (parent_width, parent_height) = content_size(&cx.style, &cx.cache, parent);
+        let basis = CalcBasis {
+            parent_width: parent_width / scale_factor,
+            parent_height: parent_height / scale_factor,
+            viewport_width: viewport.w / scale_factor,
+            viewport_height: viewport.h / scale_factor,
+        };
+
+        if cx.style.layout_calc.bases.get(entity) != Some(&basis) {
+            cx.style.layout_calc.bases.insert(entity, basis);
+            changed.push(entity);
+        }
+    }
+
+    changed
+}
+
+/// Returns the size in physical pixels of the content of an entity within its padding and border.
+fn content_size(style: &Style, cache: &CachedData, entity: Entity) -> (f32, f32) {
+    let bounds = cache.get_bounds(entity);
+    let pixels = |units: Option<Units>| match units {
+        Some(Units::Pixels(val)) => val,
+        _ => 0.0,
+    };
+
+    let width = bounds.w
+        - pixels(entity.padding_left(style))
+        - pixels(entity.padding_right(style))
+        - pixels(entity.border_left(style))
+        - pixels(entity.border_right(style));
+    let height = bounds.h
+        - pixels(entity.padding_top(style))
+        - pixels(entity.padding_bottom(style))
+        - pixels(entity.border_top(style))
+        - pixels(entity.border_bottom(style));
+
+    (width.max(0.0), height.max(0.0))
+}
+
+/// Returns the roots of the subtrees which must be laid out again given the entities marked as needing relayout.
+fn layout_roots(tree: &Tree<Entity>, style: &Style, dirty: HashSet<Entity>) -> Vec<Entity> {
+    // No marked entities means the relayout was requested for the whole tree.
+    if dirty.is_empty() || dirty.contains(&Entity::root()) {
+        return vec![Entity::root()];
+    }
+
+    let mut roots = HashSet::new();
+
+    'dirty: for entity in dirty {
+        // A change to an entity can affect the size of its siblings and its parent, so start from the parent.
+        let parent = tree.get_layout_parent(entity).unwrap_or(Entity::root());
+
+        let mut layout_root = None;
+        for ancestor in parent.parent_iter(tree) {
+            // Hidden subtrees are skipped by layout and are laid out again when they are shown.
+            if style.display.get(ancestor).copied().unwrap_or_default() == Display::None {
+                continue 'dirty;
+            }
+
+            if layout_root.is_none() && is_layout_boundary(style, ancestor) {
+                layout_root = Some(ancestor);
             }
+        }
 
-            if let Some(geo) = cx.cache.geo_changed.get(entity).copied() {
-                if !geo.is_empty()
-                // && cx.style.text.get(entity).is_some()
-                {
-                    cx.needs_redraw();
-                    cx.style.needs_text_layout(entity);
+        roots.insert(layout_root.unwrap_or(Entity::root()));
+    }
+
+    if roots.contains(&Entity::root()) {
+        return vec![Entity::root()];
+    }
+
+    // Subtrees which are contained within another subtree are laid out as part of that subtree.
+    roots
+        .iter()
+        .filter(|root| !root.parent_iter(tree).skip(1).any(|ancestor| roots.contains(&ancestor)))
+        .copied()
+        .collect()
+}
+
+/// Places the children of the grids within the subtree of the given entity in the cells of their grid.
+fn place_grid_children(cx: &mut Context, root: Entity) {
+    for entity in LayoutTreeIterator::subtree(&cx.tree, root) {
+        if cx.style.layout_type.get(entity) == Some(&LayoutType::Grid) {
+            cx.style.place_grid_children(&cx.tree, entity);
+        }
+    }
+}
+
+/// Returns true if the size of the entity cannot be affected by a change to its descendants or siblings.
+fn is_layout_boundary(style: &Style, entity: Entity) -> bool {
+    entity == Entity::root()
+        || (matches!(style.width.get(entity), Some(Units::Pixels(_)))
+            && matches!(style.height.get(entity), Some(Units::Pixels(_)))
+            && style.min_width.get(entity).is_none()
+            && style.max_width.get(entity).is_none()
+            && style.min_height.get(entity).is_none()
+            && style.max_height.get(entity).is_none())
+}
+
+/// Moves the children of the rows within the subtree of the given entity which are aligned by their baseline, so
+/// that their baselines coincide with the lowest of their baselines.
+///
+/// Rows with an auto height are given the height which fits their children once they are moved. Returns true if the
+/// height of any row changed, in which case the subtree must be laid out again.
+fn align_baselines(cx: &mut Context, root: Entity) -> bool {
+    let mut resized = false;
+    for parent in LayoutTreeIterator::subtree(&cx.tree, root) {
+        if cx.style.layout_type.get(parent).copied().unwrap_or_default() != LayoutType::Row {
+            continue;
+        }
+
+        let children = LayoutChildIterator::new(&cx.tree, parent)
+            .filter(|child| {
+                cx.style.position_type.get(*child).copied().unwrap_or_default()
+                    == PositionType::Relative
+                    && cx.style.display.get(*child).copied().unwrap_or_default() != Display::None
+            })
+            .collect::<Vec<_>>();
+
+        let mut baselines = Vec::with_capacity(children.len());
+        // The height of the tallest child which isn't stretched to the height of the row.
+        let mut tallest = 0.0f32;
+        for child in children {
+            let Some(bounds) = cx.cache.relative_bounds.get(child).copied() else {
+                continue;
+            };
+
+            if !matches!(child.height(&cx.style), Some(Units::Stretch(_)) | None) {
+                tallest = tallest.max(bounds.h);
+            }
+
+            if !cx.style.align_baseline.get(child).copied().unwrap_or_default() {
+                continue;
+            }
+
+            // Text with a fixed size isn't measured during layout, so it may not have been laid out yet.
+            let (inset_left, _, inset_right, _) = content_insets(&cx.style, child, bounds);
+            if let Some(text) = cx.text_context.text_layout_mut(child) {
+                if text.height() == 0.0 {
+                    text.layout((bounds.w - inset_left - inset_right).max(0.0));
                 }
+            }
+
+            let offset = baseline(&cx.style, &cx.text_context, child, bounds);
+            baselines.push((child, bounds.y + offset));
+        }
 
-                // TODO: Use geo changed to determine whether an entity needs to be redrawn.
+        let Some(lowest) = baselines.iter().map(|(_, baseline)| *baseline).reduce(f32::max) else {
+            resized |= cx.style.layout_calc.baseline_heights.remove(parent).is_some();
+            continue;
+        };
 
-                if !geo.is_empty() {
-                    let mut event = Event::new(WindowEvent::GeometryChanged(geo))
-                        .target(entity)
-                        .origin(entity)
-                        .propagate(Propagation::Direct);
-                    visit_entity(cx, entity, &mut event);
+        let (mut top, mut bottom) = (f32::MAX, f32::MIN);
+        for (child, baseline) in baselines {
+            if let Some(bounds) = cx.cache.relative_bounds.get_mut(child) {
+                bounds.y += (lowest - baseline).round();
+                top = top.min(bounds.y);
+                bottom = bottom.max(bounds.bottom());
+            }
+        }
+
+        // The aligned children are as tall as the distance from the top of the highest to the bottom of the lowest,
+        // which only needs a taller row if it's more than the height of its tallest child.
+        let is_auto = cx.style.layout_units(
+            parent,
+            &cx.style.height,
+            &cx.style.layout_calc.height,
+            LayoutAxis::Vertical,
+            false,
+        ) == Some(Units::Auto);
+        let span = bottom - top;
+        let height = (is_auto && span > tallest).then(|| {
+            let bounds = cx.cache.relative_bounds.get(parent).copied().unwrap_or_default();
+            let (_, inset_top, _, inset_bottom) = content_insets(&cx.style, parent, bounds);
+            span + inset_top + inset_bottom
+        });
+
+        let previous = cx.style.layout_calc.baseline_heights.get(parent).copied();
+        if height != previous {
+            match height {
+                Some(height) => cx.style.layout_calc.baseline_heights.insert(parent, height),
+                None => {
+                    cx.style.layout_calc.baseline_heights.remove(parent);
                 }
             }
+            resized = true;
+        }
+    }
 
-            if let Some(geo) = cx.cache.geo_changed.get_mut(entity) {
-                *geo = GeoChanged::empty();
+    resized
+}
+
+/// Converts the relative bounds produced by morphorm into absolute bounds for the subtree of the given entity,
+/// sending a geometry changed event to any view whose bounds have changed.
+fn update_bounds(cx: &mut EventContext, root: Entity) {
+    let iter = LayoutTreeIterator::subtree(cx.tree, root);
+
+    for entity in iter {
+        cx.current = entity;
+        if cx.style.display.get(entity).copied().unwrap_or_default() == Display::None {
+            continue;
+        }
+
+        cx.cache.entities_laid_out += 1;
+
+        // Morphorm produces relative positions so convert to absolute.
+        if let Some(parent) = cx.tree.get_layout_parent(entity) {
+            let parent_bounds = cx.cache.get_bounds(parent);
+            if let Some(bounds) = cx.cache.bounds.get_mut(entity) {
+                if let Some(relative_bounds) = cx.cache.relative_bounds.get(entity) {
+                    let x = relative_bounds.x + parent_bounds.x;
+                    let y = relative_bounds.y + parent_bounds.y;
+                    let w = relative_bounds.w;
+                    let h = relative_bounds.h;
+
+                    let mut geo_changed = GeoChanged::empty();
+
+                    if x != bounds.x {
+                        geo_changed.set(GeoChanged::POSX_CHANGED, true);
+                    }
+
+                    if y != bounds.y {
+                        geo_changed.set(GeoChanged::POSY_CHANGED, true);
+                    }
+
+                    if w != bounds.w {
+                        geo_changed.set(GeoChanged::WIDTH_CHANGED, true);
+                    }
+
+                    if h != bounds.h {
+                        geo_changed.set(GeoChanged::HEIGHT_CHANGED, true);
+                    }
+
+                    if let Some(geo) = cx.cache.geo_changed.get_mut(entity) {
+                        *geo = geo_changed;
+                    }
+
+                    let new_bounds = BoundingBox { x, y, w, h };
+
+                    // if new_bounds != *bounds && *bounds != BoundingBox::default() {
+                    //     cx.needs_redraw();
+                    // }
+
+                    *bounds = new_bounds;
+                }
             }
         }
 
-        // A relayout, retransform, or reclip, can cause the element under the cursor to change. So we push a mouse move event here to force
-        // a new event cycle and the hover system to trigger.
-        if let Some(proxy) = &cx.event_proxy {
-            let event = Event::new(WindowEvent::MouseMove(cx.mouse.cursor_x, cx.mouse.cursor_y))
-                .target(Entity::root())
-                .origin(Entity::root())
-                .propagate(Propagation::Up);
+        if let Some(geo) = cx.cache.geo_changed.get(entity).copied() {
+            if !geo.is_empty()
+            // && cx.style.text.get(entity).is_some()
+            {
+                cx.needs_redraw();
+                cx.style.needs_text_layout(entity);
+            }
 
-            proxy.send(event).expect("Failed to send event");
+            // TODO: Use geo changed to determine whether an entity needs to be redrawn.
+
+            if !geo.is_empty() {
+                cx.cache.geometry_changed.insert(entity);
+                cx.style.needs_access_update(entity);
+
+                let mut event = Event::new(WindowEvent::GeometryChanged(geo))
+                    .target(entity)
+                    .origin(entity)
+                    .propagate(Propagation::Direct);
+                visit_entity(cx, entity, &mut event);
+            }
         }
 
-        cx.style.system_flags.set(SystemFlags::RELAYOUT, false);
+        if let Some(geo) = cx.cache.geo_changed.get_mut(entity) {
+            *geo = GeoChanged::empty();
+        }
     }
-
-    text_layout_system(cx);
 }
 
 fn visit_entity(cx: &mut EventContext, entity: Entity, event: &mut Event) {
@@ -156,3 +449,581 @@ fn visit_entity(cx: &mut EventContext, entity: Entity, event: &mut Event) {
         cx.views.insert(entity, view);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::style_system;
+
+    // Linear congruential generator so that the randomized trees are reproducible.
+    struct Rng(u64);
+
+    impl Rng {
+        fn range(&mut self, n: usize) -> usize {
+            self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (self.0 >> 33) as usize % n
+        }
+    }
+
+    fn random_units(rng: &mut Rng) -> Units {
+        match rng.range(4) {
+            0 => Units::Pixels(10.0 + rng.range(100) as f32),
+            1 => Units::Stretch(1.0 + rng.range(3) as f32),
+            2 => Units::Percentage(10.0 + rng.range(90) as f32),
+            _ => Units::Auto,
+        }
+    }
+
+    fn build_random_tree(
+        cx: &mut Context,
+        rng: &mut Rng,
+        depth: usize,
+        entities: &mut Vec<Entity>,
+    ) {
+        for _ in 0..1 + rng.range(4) {
+            let entity = Element::new(cx).entity();
+            entities.push(entity);
+
+            let layout_type = if rng.range(2) == 0 { LayoutType::Row } else { LayoutType::Column };
+            cx.style.layout_type.insert(entity, layout_type);
+            cx.style.width.insert(entity, random_units(rng));
+            cx.style.height.insert(entity, random_units(rng));
+            cx.style.left.insert(entity, random_units(rng));
+            cx.style.top.insert(entity, random_units(rng));
+            cx.style.padding_left.insert(entity, random_units(rng));
+            cx.style.padding_top.insert(entity, random_units(rng));
+
+            if depth < 4 && rng.range(3) != 0 {
+                cx.with_current(entity, |cx| build_random_tree(cx, rng, depth + 1, entities));
+            }
+        }
+    }
+
+    fn bounds(cx: &Context, entities: &[Entity]) -> Vec<BoundingBox> {
+        entities.iter().map(|entity| cx.cache.get_bounds(*entity)).collect()
+    }
+
+    #[test]
+    fn incremental_layout_matches_full_layout() {
+        for seed in 0..20 {
+            let mut rng = Rng(seed);
+            let mut cx = Context::default();
+            cx.style.width.insert(Entity::root(), Units::Pixels(800.0));
+            cx.style.height.insert(Entity::root(), Units::Pixels(600.0));
+
+            let mut entities = Vec::new();
+            build_random_tree(&mut cx, &mut rng, 0, &mut entities);
+            layout_system(&mut cx);
+
+            for _ in 0..10 {
+                let entity = entities[rng.range(entities.len())];
+                if rng.range(2) == 0 {
+                    cx.style.width.insert(entity, random_units(&mut rng));
+                } else {
+                    cx.style.height.insert(entity, random_units(&mut rng));
+                }
+                cx.style.needs_relayout_entity(entity);
+                layout_system(&mut cx);
+                let incremental = bounds(&cx, &entities);
+
+                cx.style.needs_relayout();
+                layout_system(&mut cx);
+                let full = bounds(&cx, &entities);
+
+                assert_eq!(incremental, full, "seed {}", seed);
+            }
+        }
+    }
+
+    #[test]
+    fn relayout_stops_at_fixed_size_ancestor() {
+        let mut cx = Context::default();
+        cx.style.width.insert(Entity::root(), Units::Pixels(800.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(600.0));
+
+        let container = Element::new(&mut cx).width(Pixels(200.0)).height(Pixels(200.0)).entity();
+        let child = cx.with_current(container, |cx| Element::new(cx).size(Auto).entity());
+        for _ in 0..100 {
+            Element::new(&mut cx).size(Pixels(10.0));
+        }
+
+        layout_system(&mut cx);
+        assert!(cx.entities_laid_out() > 100);
+
+        cx.style.width.insert(child, Units::Pixels(50.0));
+        cx.style.needs_relayout_entity(child);
+        layout_system(&mut cx);
+        assert_eq!(cx.entities_laid_out(), 2);
+        assert_eq!(cx.cache.get_width(child), 50.0);
+
+        // A child of an auto sized ancestor must propagate past it.
+        cx.style.width.insert(container, Units::Auto);
+        cx.style.needs_relayout_entity(container);
+        layout_system(&mut cx);
+        cx.style.width.insert(child, Units::Pixels(80.0));
+        cx.style.needs_relayout_entity(child);
+        layout_system(&mut cx);
+        assert!(cx.entities_laid_out() > 100);
+        assert_eq!(cx.cache.get_width(container), 80.0);
+    }
+
+    #[test]
+    fn calc_lengths_are_relative_to_the_parent() {
+        let mut cx = Context::default();
+        cx.style.dpi_factor = 1.0;
+        cx.style.width.insert(Entity::root(), Units::Pixels(800.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(600.0));
+        cx.add_stylesheet(
+            ".calc { width: calc(100% - 20px); height: calc((100% - 20px) / 2 + 1em); }",
+        )
+        .unwrap();
+
+        let parent = Element::new(&mut cx).width(Pixels(200.0)).height(Pixels(100.0)).entity();
+        let child =
+            cx.with_current(parent, |cx| Element::new(cx).class("calc").font_size(10.0).entity());
+        style_system(&mut cx);
+        layout_system(&mut cx);
+
+        assert_eq!(cx.cache.get_width(child), cx.cache.get_width(parent) - 20.0);
+        assert_eq!(cx.cache.get_height(child), 50.0);
+
+        // The lengths follow the size of the parent when it is resized.
+        cx.style.width.insert(parent, Units::Pixels(300.0));
+        cx.style.needs_relayout_entity(parent);
+        layout_system(&mut cx);
+        assert_eq!(cx.cache.get_width(child), 280.0);
+    }
+
+    #[test]
+    fn math_functions_are_resolved_against_the_parent() {
+        let mut cx = Context::default();
+        cx.style.dpi_factor = 1.0;
+        cx.style.width.insert(Entity::root(), Units::Pixels(3000.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(600.0));
+        cx.add_stylesheet(
+            r#"
+            .clamp { width: clamp(200px, 50%, 600px); height: 20px; }
+            .nested { width: max(min(50%, 30em), 120px); height: 20px; }
+            "#,
+        )
+        .unwrap();
+
+        let parent = Element::new(&mut cx).width(Pixels(300.0)).height(Pixels(100.0)).entity();
+        let (clamp, nested) = cx.with_current(parent, |cx| {
+            let clamp = Element::new(cx).class("clamp").entity();
+            let nested = Element::new(cx).class("nested").font_size(10.0).entity();
+            (clamp, nested)
+        });
+        style_system(&mut cx);
+
+        let mut widths = Vec::new();
+        for parent_width in [300.0, 800.0, 2000.0] {
+            cx.style.width.insert(parent, Units::Pixels(parent_width));
+            cx.style.needs_relayout_entity(parent);
+            layout_system(&mut cx);
+            widths.push((cx.cache.get_width(clamp), cx.cache.get_width(nested)));
+        }
+
+        assert_eq!(widths, [(200.0, 150.0), (400.0, 300.0), (600.0, 300.0)]);
+    }
+
+    #[test]
+    fn transitions_between_math_functions_interpolate_the_resolved_pixels() {
+        let mut cx = Context::default();
+        cx.style.dpi_factor = 1.0;
+        cx.style.width.insert(Entity::root(), Units::Pixels(800.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(600.0));
+        cx.add_stylesheet(
+            r#"
+            .panel { width: clamp(100px, 50%, 300px); height: 20px; transition: width 1s linear; }
+            .panel.wide { width: clamp(100px, 90%, 500px); transition: width 1s linear; }
+            "#,
+        )
+        .unwrap();
+
+        let parent = Element::new(&mut cx).width(Pixels(400.0)).height(Pixels(100.0)).entity();
+        let panel = cx.with_current(parent, |cx| Element::new(cx).class("panel").entity());
+        style_system(&mut cx);
+        layout_system(&mut cx);
+        assert_eq!(cx.cache.get_width(panel), 200.0);
+
+        cx.with_current(panel, |cx| cx.toggle_class("wide", true));
+        style_system(&mut cx);
+        let start = cx.style.layout_calc.width.get_active_animation(panel).unwrap().start_time;
+
+        let width_at = |cx: &mut Context, millis: u64| {
+            let time = start + Duration::from_millis(millis);
+            for entity in cx.style.layout_calc.tick(time, ColorSpace::default()) {
+                cx.style.needs_relayout_entity(entity);
+            }
+            layout_system(cx);
+            cx.cache.get_width(panel)
+        };
+
+        // Halfway between 200px and 360px, rather than between the unresolved values.
+        assert_eq!(width_at(&mut cx, 500), 280.0);
+        assert_eq!(width_at(&mut cx, 1000), 360.0);
+    }
+
+    #[test]
+    fn gaps_are_only_inserted_between_children() {
+        let mut cx = Context::default();
+        cx.style.dpi_factor = 1.0;
+        cx.style.width.insert(Entity::root(), Units::Pixels(800.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(600.0));
+        cx.add_stylesheet(
+            r#"
+            .row { layout-type: row; width: 100px; height: 50px; column-gap: 10px; row-gap: 30px; }
+            .row > element { width: 1s; height: 10px; }
+            .column { layout-type: column; width: 50px; height: 100px; gap: 20px 5px; }
+            .column > element { width: 10px; height: 20px; }
+            "#,
+        )
+        .unwrap();
+
+        let row = Element::new(&mut cx).class("row").entity();
+        let row_children = cx
+            .with_current(row, |cx| (0..2).map(|_| Element::new(cx).entity()).collect::<Vec<_>>());
+        let column = Element::new(&mut cx).class("column").entity();
+        let column_children = cx.with_current(column, |cx| {
+            (0..3).map(|_| Element::new(cx).entity()).collect::<Vec<_>>()
+        });
+        style_system(&mut cx);
+        layout_system(&mut cx);
+
+        let offsets = |cx: &Context, parent: Entity, children: &[Entity]| {
+            let parent = cx.cache.get_bounds(parent);
+            children
+                .iter()
+                .map(|child| {
+                    let bounds = cx.cache.get_bounds(*child);
+                    (bounds.x - parent.x, bounds.y - parent.y)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // The stretch children share the width which is left after the gap between them.
+        assert_eq!(offsets(&cx, row, &row_children), [(0.0, 0.0), (55.0, 0.0)]);
+        assert_eq!(cx.cache.get_width(row_children[0]), 45.0);
+        assert_eq!(offsets(&cx, column, &column_children), [(0.0, 0.0), (0.0, 40.0), (0.0, 80.0)]);
+    }
+
+    #[test]
+    fn grid_tracks_are_sized_and_spanned() {
+        let mut cx = Context::default();
+        cx.style.dpi_factor = 1.0;
+        cx.style.width.insert(Entity::root(), Units::Pixels(800.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(600.0));
+        cx.add_stylesheet(
+            r#"
+            .grid {
+                layout-type: grid;
+                width: 300px;
+                height: 100px;
+                grid-template-columns: 100px 1fr;
+                grid-template-rows: 20px 30px;
+                gap: 10px;
+            }
+            .footer { grid-column: 1 / span 2; grid-row: 2; }
+            "#,
+        )
+        .unwrap();
+
+        let grid = Element::new(&mut cx).class("grid").entity();
+        let children = cx.with_current(grid, |cx| {
+            vec![
+                Element::new(cx).entity(),
+                Element::new(cx).class("footer").entity(),
+                Element::new(cx).entity(),
+            ]
+        });
+        style_system(&mut cx);
+        layout_system(&mut cx);
+
+        let rect = |child: Entity| {
+            let (grid, bounds) = (cx.cache.get_bounds(grid), cx.cache.get_bounds(child));
+            (bounds.x - grid.x, bounds.y - grid.y, bounds.w, bounds.h)
+        };
+
+        // The second column takes the width left after the first column and the gap.
+        assert_eq!(rect(children[0]), (0.0, 0.0, 100.0, 20.0));
+        assert_eq!(rect(children[2]), (110.0, 0.0, 190.0, 20.0));
+        // The spanning child covers both columns and the gap between them.
+        assert_eq!(rect(children[1]), (0.0, 30.0, 300.0, 30.0));
+    }
+
+    #[test]
+    fn aspect_ratio_derives_the_missing_size() {
+        let mut cx = Context::default();
+        cx.style.dpi_factor = 1.0;
+        cx.style.width.insert(Entity::root(), Units::Pixels(800.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(600.0));
+        cx.add_stylesheet(".thumbnail { aspect-ratio: 16 / 9; }").unwrap();
+
+        let mut views = Vec::new();
+        VStack::new(&mut cx, |cx| {
+            // The width stretches and the height follows.
+            views.push(Element::new(cx).class("thumbnail").height(Auto).entity());
+            // The stretching height follows the fixed width.
+            views.push(Element::new(cx).aspect_ratio(2.0).width(Pixels(100.0)).entity());
+            // The width follows the fixed height.
+            views.push(Element::new(cx).aspect_ratio(2.0).size(Pixels(50.0)).width(Auto).entity());
+            // The derived height is clamped.
+            views.push(
+                Element::new(cx).aspect_ratio(1.0).height(Auto).max_height(Pixels(100.0)).entity(),
+            );
+            // Both sizes are fixed so the ratio is ignored.
+            views.push(Element::new(cx).aspect_ratio(2.0).size(Pixels(30.0)).entity());
+        })
+        .width(Pixels(320.0))
+        .height(Auto);
+        style_system(&mut cx);
+        layout_system(&mut cx);
+
+        let size = |entity| (cx.cache.get_width(entity), cx.cache.get_height(entity));
+        assert_eq!(size(views[0]), (320.0, 180.0));
+        assert_eq!(size(views[1]), (100.0, 50.0));
+        assert_eq!(size(views[2]), (100.0, 50.0));
+        assert_eq!(size(views[3]), (320.0, 100.0));
+        assert_eq!(size(views[4]), (30.0, 30.0));
+    }
+
+    #[test]
+    fn no_layout_without_changes() {
+        let mut cx = Context::default();
+        Element::new(&mut cx);
+        layout_system(&mut cx);
+        layout_system(&mut cx);
+        assert_eq!(cx.entities_laid_out(), 0);
+    }
+
+    #[test]
+    fn baseline_aligned_children_share_a_baseline() {
+        let mut cx = Context::default();
+        cx.style.dpi_factor = 1.0;
+        cx.style.width.insert(Entity::root(), Units::Pixels(800.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(600.0));
+
+        let mut aligned = Vec::new();
+        let mut unaligned = Entity::null();
+        let row = HStack::new(&mut cx, |cx| {
+            aligned.push(Label::new(cx, "-12.5").font_size(24.0).align_baseline(true).entity());
+            aligned.push(Label::new(cx, "dB").font_size(12.0).align_baseline(true).entity());
+            aligned.push(Element::new(cx).size(Pixels(10.0)).align_baseline(true).entity());
+            aligned.push(
+                Element::new(cx)
+                    .size(Pixels(10.0))
+                    .baseline_offset(Pixels(2.0))
+                    .align_baseline(true)
+                    .entity(),
+            );
+            unaligned = Element::new(cx).size(Pixels(50.0)).entity();
+        })
+        .size(Auto)
+        .entity();
+
+        layout_system(&mut cx);
+
+        let baselines = |cx: &Context| {
+            aligned
+                .iter()
+                .map(|child| {
+                    let bounds = cx.cache.get_bounds(*child);
+                    bounds.y + baseline(&cx.style, &cx.text_context, *child, bounds)
+                })
+                .collect::<Vec<_>>()
+        };
+        let shared = baselines(&cx);
+        for baseline in &shared {
+            assert!((baseline - shared[0]).abs() <= 1.0, "{:?}", shared);
+        }
+
+        // The smaller text is moved down to the baseline of the larger text.
+        assert!(cx.cache.get_posy(aligned[1]) >= cx.cache.get_posy(aligned[0]));
+        // A view without text is aligned by its bottom edge, unless it has a baseline offset.
+        assert_eq!(cx.cache.get_posy(aligned[3]) - cx.cache.get_posy(aligned[2]), 8.0);
+        assert_eq!(cx.cache.get_posy(unaligned), cx.cache.get_posy(row));
+        // The unaligned view is the tallest child, so the aligned children fit within the row.
+        assert_eq!(cx.cache.get_height(row), 50.0);
+
+        // Without it, the row grows to fit the aligned children, which are taller than any one of them once moved.
+        cx.style.display.insert(unaligned, Display::None);
+        cx.style.needs_relayout();
+        layout_system(&mut cx);
+
+        let shared = baselines(&cx);
+        for baseline in &shared {
+            assert!((baseline - shared[0]).abs() <= 1.0, "{:?}", shared);
+        }
+        let top = aligned.iter().map(|child| cx.cache.get_posy(*child)).fold(f32::MAX, f32::min);
+        let bottom = aligned
+            .iter()
+            .map(|child| cx.cache.get_bounds(*child).bottom())
+            .fold(f32::MIN, f32::max);
+        let tallest = aligned.iter().map(|child| cx.cache.get_height(*child)).fold(0.0, f32::max);
+        let row_bounds = cx.cache.get_bounds(row);
+        assert!(bottom - top > tallest);
+        assert!(row_bounds.height() >= bottom - top);
+        assert!(row_bounds.top() <= top && bottom <= row_bounds.bottom(), "{:?}", row_bounds);
+    }
+
+    #[test]
+    fn baselines_include_relative_padding_and_border() {
+        let mut cx = Context::default();
+        cx.style.dpi_factor = 1.0;
+        cx.style.width.insert(Entity::root(), Units::Pixels(800.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(600.0));
+
+        let mut labels = Vec::new();
+        HStack::new(&mut cx, |cx| {
+            labels.push(
+                Label::new(cx, "Text")
+                    .height(Pixels(100.0))
+                    .width(Pixels(100.0))
+                    .alignment(Alignment::TopLeft)
+                    .padding_top(Pixels(10.0))
+                    .align_baseline(true)
+                    .entity(),
+            );
+            labels.push(
+                Label::new(cx, "Text")
+                    .height(Pixels(100.0))
+                    .width(Pixels(100.0))
+                    .alignment(Alignment::TopLeft)
+                    .padding_top(Units::Percentage(5.0))
+                    .border_width(Pixels(5.0))
+                    .align_baseline(true)
+                    .entity(),
+            );
+        })
+        .size(Auto);
+
+        layout_system(&mut cx);
+
+        // Percentages of the padding are relative to the height of the view, as they are in layout.
+        let baselines = labels
+            .iter()
+            .map(|label| baseline(&cx.style, &cx.text_context, *label, cx.cache.get_bounds(*label)))
+            .collect::<Vec<_>>();
+        assert!((baselines[0] - baselines[1]).abs() < 0.01, "{:?}", baselines);
+        assert_eq!(cx.cache.get_posy(labels[0]), cx.cache.get_posy(labels[1]));
+
+        // The text of a view with a fixed size is laid out within its padding and border.
+        let text = cx.text_context.text_layout(labels[1]).unwrap();
+        assert_eq!(text.max_width(), 90.0);
+    }
+
+    #[test]
+    fn vertical_align_overrides_the_alignment_of_text() {
+        use crate::layout::baseline::text_top;
+
+        let mut cx = Context::default();
+        cx.style.dpi_factor = 1.0;
+        cx.style.width.insert(Entity::root(), Units::Pixels(800.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(600.0));
+
+        let label = Label::new(&mut cx, "Text").height(Pixels(100.0)).entity();
+        layout_system(&mut cx);
+
+        let top = |cx: &mut Context, vertical_align: Option<VerticalAlign>| {
+            if let Some(vertical_align) = vertical_align {
+                cx.style.vertical_align.insert(label, vertical_align);
+            } else {
+                cx.style.vertical_align.remove(label);
+            }
+            let paragraph = cx.text_context.text_paragraphs.get(label).unwrap();
+            text_top(&cx.style, label, paragraph, 100.0, 0.0, 0.0)
+        };
+
+        let space = 100.0 - cx.text_context.text_paragraphs.get(label).unwrap().height();
+        assert_eq!(top(&mut cx, Some(VerticalAlign::Top)), 0.0);
+        assert_eq!(top(&mut cx, Some(VerticalAlign::Center)), space / 2.0);
+        assert_eq!(top(&mut cx, Some(VerticalAlign::Bottom)), space);
+
+        // Without a baseline offset, baseline aligned text is placed at the top, and otherwise its first baseline is.
+        assert_eq!(top(&mut cx, Some(VerticalAlign::Baseline)), 0.0);
+        cx.style.baseline_offset.insert(label, Pixels(50.0));
+        let baseline_top = top(&mut cx, Some(VerticalAlign::Baseline));
+        let paragraph = cx.text_context.text_paragraphs.get(label).unwrap();
+        assert!((baseline_top + paragraph.alphabetic_baseline() - 50.0).abs() < 0.01);
+
+        // Without a vertical alignment the text follows the alignment of the view.
+        cx.style.alignment.insert(label, Alignment::BottomLeft);
+        assert_eq!(top(&mut cx, None), space);
+    }
+
+    #[test]
+    fn letter_and_word_spacing_widen_the_measured_text() {
+        let mut cx = Context::default();
+        cx.style.dpi_factor = 1.0;
+        cx.style.width.insert(Entity::root(), Units::Pixels(800.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(600.0));
+        cx.add_stylesheet(
+            r#"
+            label { font-size: 20px; }
+            .letters-px { letter-spacing: 4px; }
+            .letters-em { letter-spacing: 0.2em; }
+            .words-px { word-spacing: 20px; }
+            .words-em { word-spacing: 1em; }
+            "#,
+        )
+        .unwrap();
+
+        let labels = ["plain", "letters-px", "letters-em", "words-px", "words-em"]
+            .map(|class| Label::new(&mut cx, "Spaced out text").class(class).entity());
+        style_system(&mut cx);
+        layout_system(&mut cx);
+        let [plain, letters_px, letters_em, words_px, words_em] =
+            labels.map(|label| cx.cache.get_width(label));
+
+        assert!(letters_px > plain);
+        assert!(words_px > plain);
+
+        // Relative lengths are resolved against the font size of the text.
+        assert_eq!(letters_em, letters_px);
+        assert_eq!(words_em, words_px);
+    }
+
+    #[test]
+    fn text_is_measured_once_per_constraint() {
+        let mut cx = Context::default();
+        cx.style.dpi_factor = 1.0;
+        cx.style.width.insert(Entity::root(), Units::Pixels(800.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(600.0));
+
+        let mut labels = Vec::new();
+        HStack::new(&mut cx, |cx| {
+            for _ in 0..10 {
+                labels.push(
+                    Label::new(cx, "Some text which wraps onto several lines")
+                        .width(Stretch(1.0))
+                        .entity(),
+                );
+            }
+        })
+        .width(Pixels(400.0))
+        .height(Auto);
+
+        layout_system(&mut cx);
+
+        // Each label is measured at most once for each distinct set of constraints.
+        let cached = labels
+            .iter()
+            .map(|label| cx.text_context.text_measurements.get(*label).map_or(0, Vec::len))
+            .sum::<usize>();
+        assert!(cached >= labels.len());
+        assert_eq!(cx.text_context.measure_count, cached);
+
+        // Laying the labels out again with the same constraints reuses the measured sizes.
+        let measured = cx.text_context.measure_count;
+        cx.style.needs_relayout();
+        layout_system(&mut cx);
+        assert_eq!(cx.text_context.measure_count, measured);
+
+        // Changing the text of a label measures it again.
+        cx.style.text.insert(labels[0], String::from("Other text"));
+        cx.style.needs_text_update(labels[0]);
+        layout_system(&mut cx);
+        assert!(cx.text_context.measure_count > measured);
+    }
+}
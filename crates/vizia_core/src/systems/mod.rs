@@ -1,18 +1,26 @@
 pub(crate) mod accessibility;
+pub(crate) mod anchor;
 pub(crate) mod animation;
 pub(crate) mod binding;
 pub(crate) mod draw;
+pub(crate) mod focus_ring;
 pub mod hover;
 pub(crate) mod image;
 pub(crate) mod layout;
 pub(crate) mod style;
 pub(crate) mod text;
+#[cfg(debug_assertions)]
+pub(crate) mod update_overlay;
 pub(crate) use self::image::*;
 pub(crate) use accessibility::*;
+pub(crate) use anchor::*;
 pub(crate) use animation::*;
 pub(crate) use binding::*;
 pub(crate) use draw::*;
+pub(crate) use focus_ring::*;
 pub use hover::*;
 pub(crate) use layout::*;
 pub(crate) use style::*;
 pub(crate) use text::*;
+#[cfg(debug_assertions)]
+pub(crate) use update_overlay::*;
@@ -1,16 +1,20 @@
+#[cfg(debug_assertions)]
+use super::UpdateKind;
 use crate::{events::ViewHandler, prelude::*};
 use hashbrown::HashMap;
-use vizia_storage::{LayoutParentIterator, TreeBreadthIterator};
+use vizia_storage::{
+    LayoutChildIterator, LayoutParentIterator, LayoutTreeIterator, TreeBreadthIterator,
+};
 use vizia_style::{
     matches_selector,
     selectors::{
         attr::{AttrSelectorOperation, CaseSensitivity, NamespaceConstraint},
         context::{MatchingForInvalidation, NeedsSelectorFlags, SelectorCaches},
         matching::ElementSelectorFlags,
-        parser::{Component, NthType},
         OpaqueElement, SelectorImpl,
     },
-    Element, MatchingContext, MatchingMode, PseudoClass, QuirksMode, SelectorIdent, Selectors,
+    Element, MatchingContext, MatchingMode, PseudoClass, PseudoElement, QuirksMode, SelectorIdent,
+    Selectors,
 };
 
 /// A node used for style matching.
@@ -110,20 +114,21 @@ impl Element for Node<'_, '_, '_> {
         None
     }
 
+    // Parts are matched as pseudo-elements of their parent.
     fn is_pseudo_element(&self) -> bool {
-        false
+        self.store.parts.get(self.entity).is_some()
     }
 
     fn is_same_type(&self, other: &Self) -> bool {
-        if let Some(element) = self.views.get(&self.entity).and_then(|view| view.element()) {
-            if let Some(other_element) =
-                self.views.get(&other.entity).and_then(|view| view.element())
-            {
-                return element == other_element;
-            }
+        // Views without an element name are of the same type if they are the same view.
+        match (self.views.get(&self.entity), self.views.get(&other.entity)) {
+            (Some(view), Some(other_view)) => match (view.element(), other_view.element()) {
+                (Some(element), Some(other_element)) => element == other_element,
+                (None, None) => view.as_any_ref().type_id() == other_view.as_any_ref().type_id(),
+                _ => false,
+            },
+            _ => false,
         }
-
-        false
     }
 
     fn is_link(&self) -> bool {
@@ -165,10 +170,13 @@ impl Element for Node<'_, '_, '_> {
 
     fn match_pseudo_element(
         &self,
-        _pe: &<Self::Impl as SelectorImpl>::PseudoElement,
+        pe: &<Self::Impl as SelectorImpl>::PseudoElement,
         _context: &mut MatchingContext<'_, Self::Impl>,
     ) -> bool {
-        false
+        match (pe, self.store.parts.get(self.entity)) {
+            (PseudoElement::Custom(name), Some(part)) => name == part,
+            _ => false,
+        }
     }
 
     fn match_non_ts_pseudo_class(
@@ -217,12 +225,14 @@ impl Element for Node<'_, '_, '_> {
                 PseudoClass::UserInvalid => {
                     psudeo_class_flag.contains(PseudoClassFlags::USER_INVALID)
                 }
+                PseudoClass::Dragging => psudeo_class_flag.contains(PseudoClassFlags::DRAGGING),
+                PseudoClass::ActiveDescendant => {
+                    psudeo_class_flag.contains(PseudoClassFlags::ACTIVE_DESCENDANT)
+                }
                 PseudoClass::Lang(_) => todo!(),
                 PseudoClass::Dir(_) => todo!(),
-                PseudoClass::Custom(name) => {
-                    println!("custom: {}", name);
-                    todo!()
-                }
+                // Unknown pseudo-classes never match.
+                PseudoClass::Custom(_) => false,
             }
         } else {
             false
@@ -254,22 +264,38 @@ pub(crate) fn inline_inheritance_system(cx: &mut Context, redraw_entities: &mut
             if cx.style.disabled.inherit_inline(entity, parent)
                 | cx.style.caret_color.inherit_inline(entity, parent)
                 | cx.style.selection_color.inherit_inline(entity, parent)
+                | cx.style.focus_ring_color.inherit_inline(entity, parent)
+                | cx.style.focus_ring_width.inherit_inline(entity, parent)
+                | cx.style.focus_ring_offset.inherit_inline(entity, parent)
+                | cx.style.text_rendering.inherit_inline(entity, parent)
             {
                 redraw_entities.push(entity);
             }
 
-            if cx.style.font_color.inherit_inline(entity, parent)
-                | cx.style.font_size.inherit_inline(entity, parent)
+            if cx.style.font_size.inherit_inline(entity, parent)
                 | cx.style.font_family.inherit_inline(entity, parent)
                 | cx.style.font_weight.inherit_inline(entity, parent)
                 | cx.style.font_slant.inherit_inline(entity, parent)
                 | cx.style.font_width.inherit_inline(entity, parent)
+                | cx.style.font_variation_settings.inherit_inline(entity, parent)
+                | cx.style.font_feature_settings.inherit_inline(entity, parent)
+                | cx.style.line_height.inherit_inline(entity, parent)
+                | cx.style.text_transform.inherit_inline(entity, parent)
+                | cx.style.letter_spacing.inherit_inline(entity, parent)
+                | cx.style.word_spacing.inherit_inline(entity, parent)
+            {
+                cx.style.needs_text_update(entity);
+            }
+
+            if cx.style.font_color.inherit_inline(entity, parent)
                 | cx.style.text_decoration_line.inherit_inline(entity, parent)
+                | cx.style.text_decoration_style.inherit_inline(entity, parent)
+                | cx.style.text_decoration_color.inherit_inline(entity, parent)
+                | cx.style.text_decoration_thickness.inherit_inline(entity, parent)
                 | cx.style.text_stroke_width.inherit_inline(entity, parent)
                 | cx.style.text_stroke_style.inherit_inline(entity, parent)
-                | cx.style.font_variation_settings.inherit_inline(entity, parent)
             {
-                cx.style.needs_text_update(entity);
+                cx.style.needs_text_repaint(entity);
             }
         }
     }
@@ -279,22 +305,38 @@ pub(crate) fn inline_inheritance_system(cx: &mut Context, redraw_entities: &mut
 pub(crate) fn shared_inheritance_system(cx: &mut Context, redraw_entities: &mut Vec<Entity>) {
     for entity in cx.tree.into_iter() {
         if let Some(parent) = cx.tree.get_layout_parent(entity) {
-            if cx.style.font_color.inherit_shared(entity, parent)
-                | cx.style.font_size.inherit_shared(entity, parent)
+            if cx.style.font_size.inherit_shared(entity, parent)
                 | cx.style.font_family.inherit_shared(entity, parent)
                 | cx.style.font_weight.inherit_shared(entity, parent)
                 | cx.style.font_slant.inherit_shared(entity, parent)
                 | cx.style.font_width.inherit_shared(entity, parent)
+                | cx.style.font_variation_settings.inherit_shared(entity, parent)
+                | cx.style.font_feature_settings.inherit_shared(entity, parent)
+                | cx.style.line_height.inherit_shared(entity, parent)
+                | cx.style.text_transform.inherit_shared(entity, parent)
+                | cx.style.letter_spacing.inherit_shared(entity, parent)
+                | cx.style.word_spacing.inherit_shared(entity, parent)
+            {
+                cx.style.needs_text_update(entity);
+            }
+
+            if cx.style.font_color.inherit_shared(entity, parent)
                 | cx.style.text_decoration_line.inherit_shared(entity, parent)
+                | cx.style.text_decoration_style.inherit_shared(entity, parent)
+                | cx.style.text_decoration_color.inherit_shared(entity, parent)
+                | cx.style.text_decoration_thickness.inherit_shared(entity, parent)
                 | cx.style.text_stroke_width.inherit_shared(entity, parent)
                 | cx.style.text_stroke_style.inherit_shared(entity, parent)
-                | cx.style.font_variation_settings.inherit_shared(entity, parent)
             {
-                cx.style.needs_text_update(entity);
+                cx.style.needs_text_repaint(entity);
             }
 
             if cx.style.caret_color.inherit_shared(entity, parent)
                 | cx.style.selection_color.inherit_shared(entity, parent)
+                | cx.style.focus_ring_color.inherit_shared(entity, parent)
+                | cx.style.focus_ring_width.inherit_shared(entity, parent)
+                | cx.style.focus_ring_offset.inherit_shared(entity, parent)
+                | cx.style.text_rendering.inherit_shared(entity, parent)
             {
                 redraw_entities.push(entity);
             }
@@ -312,6 +354,7 @@ fn link_style_data(
     let mut should_relayout = false;
     let mut should_redraw = false;
     let mut should_reflow = false;
+    let mut should_repaint = false;
 
     // Display
     if style.display.link(entity, matched_rules) {
@@ -340,11 +383,15 @@ fn link_style_data(
         should_redraw = true;
     }
 
+    if style.filter.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
     if style.backdrop_filter.link(entity, matched_rules) {
         should_redraw = true;
     }
 
-    if style.blend_mode.link(entity, matched_rules) {
+    if style.mix_blend_mode.link(entity, matched_rules) {
         should_redraw = true;
     }
 
@@ -384,6 +431,11 @@ fn link_style_data(
         should_redraw = true;
     }
 
+    if style.aspect_ratio.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
     // Size Constraints
     if style.max_width.link(entity, matched_rules) {
         should_relayout = true;
@@ -405,6 +457,12 @@ fn link_style_data(
         should_redraw = true;
     }
 
+    // Lengths resolved during layout
+    if style.layout_calc.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
     // Gap Constraints
     if style.max_horizontal_gap.link(entity, matched_rules) {
         should_relayout = true;
@@ -427,16 +485,55 @@ fn link_style_data(
     }
 
     // Border
-    if style.border_width.link(entity, matched_rules) {
+    if style.border_top_width.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.border_right_width.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.border_bottom_width.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.border_left_width.link(entity, matched_rules) {
         should_relayout = true;
         should_redraw = true;
     }
 
-    if style.border_color.link(entity, matched_rules) {
+    if style.border_top_color.link(entity, matched_rules) {
         should_redraw = true;
     }
 
-    if style.border_style.link(entity, matched_rules) {
+    if style.border_right_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_bottom_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_left_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_top_style.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_right_style.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_bottom_style.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_left_style.link(entity, matched_rules) {
         should_redraw = true;
     }
 
@@ -486,11 +583,73 @@ fn link_style_data(
         should_redraw = true;
     }
 
+    if style.outline_style.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.focus_ring.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.focus_ring_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.focus_ring_width.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.focus_ring_offset.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.press_effect.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.ripple_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.ripple_duration.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
     if style.layout_type.link(entity, matched_rules) {
         should_relayout = true;
         should_redraw = true;
     }
 
+    if style.grid_columns.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.grid_rows.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.column_start.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.column_span.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.row_start.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.row_span.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
     if style.position_type.link(entity, matched_rules) {
         should_relayout = true;
         should_redraw = true;
@@ -501,6 +660,16 @@ fn link_style_data(
         should_redraw = true;
     }
 
+    if style.align_baseline.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.baseline_offset.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
     // Background
     if style.background_color.link(entity, matched_rules) {
         should_redraw = true;
@@ -510,14 +679,22 @@ fn link_style_data(
         should_redraw = true;
     }
 
+    if style.background_position.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
     if style.background_size.link(entity, matched_rules) {
         should_redraw = true;
     }
 
+    if style.background_repeat.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
     // Font
     if style.font_color.link(entity, matched_rules) {
         should_redraw = true;
-        should_reflow = true;
+        should_repaint = true;
     }
 
     if style.font_size.link(entity, matched_rules) {
@@ -556,7 +733,13 @@ fn link_style_data(
         should_reflow = true;
     }
 
-    if style.text_wrap.link(entity, matched_rules) {
+    if style.font_feature_settings.link(entity, matched_rules) {
+        should_redraw = true;
+        should_relayout = true;
+        should_reflow = true;
+    }
+
+    if style.white_space.link(entity, matched_rules) {
         should_redraw = true;
         should_relayout = true;
         should_reflow = true;
@@ -567,6 +750,11 @@ fn link_style_data(
         should_reflow = true;
     }
 
+    if style.vertical_align.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
     if style.text_overflow.link(entity, matched_rules) {
         should_redraw = true;
         should_reflow = true;
@@ -577,6 +765,22 @@ fn link_style_data(
         should_reflow = true;
     }
 
+    if style.line_height.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+        should_reflow = true;
+    }
+
+    if style.text_transform.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+        should_reflow = true;
+    }
+
+    if style.text_rendering.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
     if style.selection_color.link(entity, matched_rules) {
         should_redraw = true;
     }
@@ -587,15 +791,42 @@ fn link_style_data(
 
     if style.text_decoration_line.link(entity, matched_rules) {
         should_redraw = true;
-        should_reflow = true;
+        should_repaint = true;
+    }
+
+    if style.text_decoration_style.link(entity, matched_rules) {
+        should_redraw = true;
+        should_repaint = true;
+    }
+
+    if style.text_decoration_color.link(entity, matched_rules) {
+        should_redraw = true;
+        should_repaint = true;
+    }
+
+    if style.text_decoration_thickness.link(entity, matched_rules) {
+        should_redraw = true;
+        should_repaint = true;
     }
 
     if style.text_stroke_width.link(entity, matched_rules) {
         should_redraw = true;
-        should_reflow = true;
+        should_repaint = true;
     }
 
     if style.text_stroke_style.link(entity, matched_rules) {
+        should_redraw = true;
+        should_repaint = true;
+    }
+
+    if style.letter_spacing.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+        should_reflow = true;
+    }
+
+    if style.word_spacing.link(entity, matched_rules) {
+        should_relayout = true;
         should_redraw = true;
         should_reflow = true;
     }
@@ -635,6 +866,10 @@ fn link_style_data(
         should_redraw = true;
     }
 
+    if style.elevation.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
     if style.padding_left.link(entity, matched_rules) {
         should_relayout = true;
         should_redraw = true;
@@ -698,9 +933,25 @@ fn link_style_data(
         should_redraw = true;
     }
 
+    if style.stroke.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.stroke_width.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.stroke_linecap.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.stroke_linejoin.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
     //
     if should_relayout {
-        style.system_flags.set(SystemFlags::RELAYOUT, true);
+        style.needs_relayout_entity(entity);
     }
 
     if should_redraw {
@@ -716,6 +967,16 @@ fn link_style_data(
             }
         }
     }
+
+    if should_repaint {
+        let iter = LayoutParentIterator::new(tree, entity);
+        for parent in iter {
+            if style.display.get(parent).copied().unwrap_or_default() != Display::None {
+                style.needs_text_repaint(parent);
+                break;
+            }
+        }
+    }
 }
 
 /// Compute a list of matching style rules for a given entity.
@@ -724,7 +985,53 @@ pub(crate) fn compute_matched_rules(
     entity: Entity,
     matched_rules: &mut Vec<(Rule, u32)>,
 ) {
-    for (rule, selector_list) in cx.style.rules.iter() {
+    match_rules(cx, entity, candidates(cx, entity), matched_rules);
+}
+
+// Returns the positions of the rules which could match an entity, which are those that require a name the entity has,
+// or no name at all.
+fn candidates(cx: &Context, entity: Entity) -> Vec<usize> {
+    cx.style.rule_index.candidates(
+        &cx.style.rules,
+        cx.style.ids.get(entity),
+        cx.style.classes.get(entity),
+        cx.views.get(&entity).and_then(|view| view.element()),
+    )
+}
+
+// Returns the positions of the rules which could match an entity and which depend on its position among its siblings.
+fn structural_candidates(cx: &Context, entity: Entity) -> Vec<usize> {
+    let mut candidates = candidates(cx, entity);
+    candidates.retain(|position| {
+        cx.style
+            .rules
+            .get_index(*position)
+            .is_some_and(|(rule, _)| cx.style.structural_rules.contains(rule))
+    });
+    candidates
+}
+
+/// Matches an entity against the rules at the given positions, which are in the order of the rules, and sorts the
+/// rules which match, along with any already in `matched_rules`, by decreasing specificity and then by decreasing
+/// position.
+pub(crate) fn match_rules(
+    cx: &Context,
+    entity: Entity,
+    positions: impl IntoIterator<Item = usize>,
+    matched_rules: &mut Vec<(Rule, u32)>,
+) {
+    let window = if cx.style.rule_media.is_empty() || cx.tree.is_window(entity) {
+        entity
+    } else {
+        cx.tree.get_parent_window(entity).unwrap_or(Entity::root())
+    };
+
+    for (rule, selector_list) in positions.into_iter().filter_map(|i| cx.style.rules.get_index(i)) {
+        // Rules inside `@media` rules which don't match the window of the entity take no part in the cascade.
+        if !cx.style.matches_media(window, *rule) {
+            continue;
+        }
+
         let mut cache = SelectorCaches::default();
         let mut context = MatchingContext::new(
             MatchingMode::Normal,
@@ -735,6 +1042,8 @@ pub(crate) fn compute_matched_rules(
             MatchingForInvalidation::No,
         );
 
+        // A rule with a list of selectors has the specificity of the most specific selector which matches.
+        let mut specificity = None;
         for selector in selector_list.slice() {
             let matches = matches_selector(
                 selector,
@@ -744,14 +1053,17 @@ pub(crate) fn compute_matched_rules(
                 &mut context,
             );
             if matches {
-                matched_rules.push((*rule, selector.specificity()));
-                break;
+                specificity = specificity.max(Some(selector.specificity()));
             }
         }
+
+        if let Some(specificity) = specificity {
+            matched_rules.push((*rule, specificity));
+        }
     }
 
-    matched_rules.sort_by_cached_key(|(_, s)| *s);
-    matched_rules.reverse();
+    matched_rules
+        .sort_by_cached_key(|(rule, s)| std::cmp::Reverse((*s, cx.style.rules.get_index_of(rule))));
 }
 
 fn has_same_selector(cx: &Context, entity1: Entity, entity2: Entity) -> bool {
@@ -798,8 +1110,43 @@ pub(crate) struct MatchedRulesCache {
 pub(crate) fn style_system(cx: &mut Context) {
     let mut redraw_entities = Vec::new();
 
+    cx.style.entities_matched = 0;
+
     inline_inheritance_system(cx, &mut redraw_entities);
 
+    // The children of views whose children have changed may now match different structural selectors. Only the
+    // children which a structural rule could match are restyled, unless a structural selector applies to an ancestor.
+    for parent in std::mem::take(&mut cx.style.restyle_children) {
+        if !cx.entity_manager.is_alive(parent) {
+            continue;
+        }
+
+        for child in LayoutChildIterator::new(&cx.tree, parent) {
+            if cx.style.has_structural_ancestors {
+                for descendant in LayoutTreeIterator::subtree(&cx.tree, child) {
+                    cx.style.restyle.insert(descendant).unwrap();
+                }
+            } else if !structural_candidates(cx, child).is_empty() {
+                cx.style.restyle.insert(child).unwrap();
+            }
+        }
+    }
+
+    // The views of a window are restyled when the size of the window or the theme changes which media queries match.
+    if !cx.style.media_queries.is_empty() {
+        let mut windows = vec![Entity::root()];
+        windows.extend(cx.windows.keys().filter(|window| **window != Entity::root()));
+        for window in windows {
+            let width = cx.style.physical_to_logical(cx.cache.get_width(window));
+            let height = cx.style.physical_to_logical(cx.cache.get_height(window));
+            if cx.style.update_media(window, width, height) {
+                for entity in LayoutTreeIterator::subtree(&cx.tree, window) {
+                    cx.style.restyle.insert(entity).unwrap();
+                }
+            }
+        }
+    }
+
     if !cx.style.restyle.is_empty() {
         let iterator = TreeBreadthIterator::full(&cx.tree);
 
@@ -812,46 +1159,67 @@ pub(crate) fn style_system(cx: &mut Context) {
                 continue;
             }
 
+            // The siblings after a restyled view, and their descendants, may match a sibling combinator differently.
+            // Siblings are marked up to the first one which is already marked, which marks those after it in turn.
+            if cx.style.has_sibling_selectors {
+                let mut sibling = cx.tree.get_next_layout_sibling(entity);
+                while let Some(next) = sibling {
+                    let marked = cx.style.restyle.contains(next);
+                    for descendant in LayoutTreeIterator::subtree(&cx.tree, next) {
+                        cx.style.restyle.insert(descendant).unwrap();
+                    }
+
+                    if marked {
+                        break;
+                    }
+
+                    sibling = cx.tree.get_next_layout_sibling(next);
+                }
+            }
+
+            // Restyled subtrees are shown by the overlay of their root.
+            #[cfg(debug_assertions)]
+            if cx.update_overlay.is_enabled() {
+                cx.update_overlay.count(UpdateKind::Restyle, 1);
+                if !cx
+                    .tree
+                    .get_layout_parent(entity)
+                    .is_some_and(|parent| cx.style.restyle.contains(parent))
+                {
+                    cx.update_overlay.flash(UpdateKind::Restyle, entity);
+                }
+            }
+
             let mut matched_rules = Vec::with_capacity(50);
 
             let current_parent = cx.tree.get_layout_parent(entity);
 
             let mut compute_match = true;
 
-            if current_parent == parent
-                && !cx.tree.is_first_child(entity)
-                && !cx.tree.is_last_child(entity)
-            {
-                // if has same selector look up rules
-                'cache: for entry in &cache {
-                    if has_same_selector(cx, entry.entity, entity) {
+            // Siblings with the same selector match the same rules, except for the rules which depend on the position
+            // of a view among its siblings, which are matched separately.
+            if current_parent == parent {
+                if let Some(entry) =
+                    cache.iter().find(|entry| has_same_selector(cx, entry.entity, entity))
+                {
+                    if cx.style.structural_rules.is_empty() {
                         matched_rules.clone_from(&entry.rules);
-                        compute_match = false;
-
-                        for rule in entry.rules.iter() {
-                            if let Some(selectors) = cx.style.rules.get(&rule.0) {
-                                for selector in selectors.slice() {
-                                    for component in selector.iter() {
-                                        match *component {
-                                            Component::Nth(n)
-                                                if n.ty == NthType::Child
-                                                    || n.ty == NthType::LastChild
-                                                    || n.ty == NthType::OnlyChild =>
-                                            {
-                                                matched_rules.clear();
-                                                compute_match = true;
-                                                continue 'cache;
-                                            }
-
-                                            _ => {}
-                                        }
-                                    }
-                                }
-                            }
-                        }
-
-                        break 'cache;
+                    } else {
+                        matched_rules.extend(
+                            entry
+                                .rules
+                                .iter()
+                                .filter(|(rule, _)| !cx.style.structural_rules.contains(rule)),
+                        );
+                        match_rules(
+                            cx,
+                            entity,
+                            structural_candidates(cx, entity),
+                            &mut matched_rules,
+                        );
                     }
+
+                    compute_match = false;
                 }
             } else {
                 parent = current_parent;
@@ -860,18 +1228,43 @@ pub(crate) fn style_system(cx: &mut Context) {
 
             if compute_match {
                 compute_matched_rules(cx, entity, &mut matched_rules);
+                cx.style.entities_matched += 1;
                 cache.push(MatchedRulesCache { entity, rules: matched_rules.clone() });
             }
 
-            if !matched_rules.is_empty() {
+            let matched_rules = matched_rules.iter().map(|(rule, _)| *rule).collect::<Vec<_>>();
+
+            // Descendants may reference the custom properties of the entity, so are restyled if they change.
+            if cx.style.compute_custom_properties(entity, current_parent, &matched_rules) {
+                for descendant in LayoutTreeIterator::subtree(&cx.tree, entity) {
+                    cx.style.restyle.insert(descendant).unwrap();
+                }
+            }
+
+            let matched_rules = cx.style.resolve_variables(entity, &matched_rules);
+            let matched_rules = cx.style.add_state_rules(entity, matched_rules);
+
+            // An entity with state styles is linked without rules to unlink a state it has left.
+            if !matched_rules.is_empty() || cx.style.state_styles.contains(entity) {
                 link_style_data(
                     &mut cx.style,
                     &cx.tree,
                     entity,
                     &mut redraw_entities,
-                    &matched_rules.iter().map(|(rule, _)| *rule).collect::<Vec<_>>(),
+                    &matched_rules,
                 );
             }
+
+            if cx.style.link_css_wide_keywords(entity, current_parent, &matched_rules) {
+                cx.style.needs_relayout_entity(entity);
+                cx.style.needs_text_update(entity);
+                redraw_entities.push(entity);
+            }
+
+            if cx.style.link_keyframe_animations(entity, &matched_rules) {
+                cx.style.needs_relayout_entity(entity);
+                redraw_entities.push(entity);
+            }
         }
         cx.style.restyle.clear();
 
@@ -882,3 +1275,516 @@ pub(crate) fn style_system(cx: &mut Context) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::binding_system;
+
+    #[derive(Lens)]
+    struct Palette {
+        accent: Color,
+    }
+
+    impl Model for Palette {}
+
+    #[test]
+    fn bound_color_change_skips_matching() {
+        let mut cx = Context::default();
+
+        Palette { accent: Color::red() }.build(&mut cx);
+        let element = Element::new(&mut cx).background_color(Palette::accent).entity();
+
+        style_system(&mut cx);
+        assert!(cx.entities_matched() > 0);
+
+        cx.replace_model(Palette { accent: Color::blue() });
+        binding_system(&mut cx);
+        style_system(&mut cx);
+        assert_eq!(cx.entities_matched(), 0);
+        assert_eq!(cx.style.background_color.get(element), Some(&Color::blue()));
+
+        // A class change can affect which rules match, so the element is matched again.
+        cx.with_current(element, |cx| cx.toggle_class("accent", true));
+        style_system(&mut cx);
+        assert!(cx.entities_matched() > 0);
+    }
+
+    #[test]
+    fn border_shorthands_and_longhands_cascade_per_side() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(
+            r#"
+            .divider { border-width: 0px; border-bottom-width: 2px; border-bottom-color: red; }
+            .divider { border-style: solid dashed; }
+            .divider.wide { border-width: 4px 1px; }
+            "#,
+        )
+        .unwrap();
+
+        let divider = Element::new(&mut cx).class("divider").entity();
+        style_system(&mut cx);
+
+        let px = |px: f32| LengthOrPercentage::Length(Length::px(px));
+        assert_eq!(cx.style.border_top_width.get(divider), Some(&px(0.0)));
+        assert_eq!(cx.style.border_bottom_width.get(divider), Some(&px(2.0)));
+        assert_eq!(cx.style.border_bottom_color.get(divider), Some(&Color::red().into()));
+        assert_eq!(cx.style.border_top_color.get(divider), None);
+        assert_eq!(cx.style.border_top_style.get(divider), Some(&BorderStyleKeyword::Solid));
+        assert_eq!(cx.style.border_left_style.get(divider), Some(&BorderStyleKeyword::Dashed));
+
+        // A more specific shorthand overrides the longhand.
+        cx.with_current(divider, |cx| cx.toggle_class("wide", true));
+        style_system(&mut cx);
+        assert_eq!(cx.style.border_bottom_width.get(divider), Some(&px(4.0)));
+        assert_eq!(cx.style.border_left_width.get(divider), Some(&px(1.0)));
+    }
+
+    #[test]
+    fn transitions_per_property_and_all() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(
+            r#"
+            element {
+                transition: all 100ms linear, opacity 300ms linear;
+                background-color: red;
+                opacity: 1;
+                width: 10px;
+            }
+            element.on {
+                transition: background-color 200ms linear, 400ms opacity linear;
+                background-color: blue;
+                opacity: 0;
+                width: 20px;
+            }
+            "#,
+        )
+        .unwrap();
+
+        let turned_on = Element::new(&mut cx).entity();
+        let turned_off = Element::new(&mut cx).class("on").entity();
+        style_system(&mut cx);
+
+        cx.with_current(turned_on, |cx| cx.toggle_class("on", true));
+        cx.with_current(turned_off, |cx| cx.toggle_class("on", false));
+        style_system(&mut cx);
+
+        let millis = Duration::from_millis;
+        let background = &cx.style.background_color;
+        let opacity = &cx.style.opacity;
+
+        // Each property which changes in the same restyle plays the transition of the rule it's now set by.
+        assert_eq!(background.get_active_animation(turned_on).unwrap().duration, millis(200));
+        assert_eq!(opacity.get_active_animation(turned_on).unwrap().duration, millis(400));
+        assert!(cx.style.width.get_active_animation(turned_on).is_none());
+        assert_eq!(cx.style.width.get(turned_on), Some(&Units::Pixels(20.0)));
+
+        // A property listed after `all` overrides its duration.
+        assert_eq!(background.get_active_animation(turned_off).unwrap().duration, millis(100));
+        assert_eq!(opacity.get_active_animation(turned_off).unwrap().duration, millis(300));
+        assert_eq!(cx.style.width.get_active_animation(turned_off).unwrap().duration, millis(100));
+    }
+
+    #[test]
+    fn sibling_combinators_follow_state_changes() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(
+            r#"
+            .toggle:checked + .label { background-color: red; }
+            .toggle:checked ~ .label { width: 20px; }
+            .toggle:hover ~ .group .item { opacity: 0.5; }
+            "#,
+        )
+        .unwrap();
+
+        let mut toggle = Entity::null();
+        let mut labels = Vec::new();
+        let mut item = Entity::null();
+        VStack::new(&mut cx, |cx| {
+            labels.push(Element::new(cx).class("label").entity());
+            toggle = Element::new(cx).class("toggle").entity();
+            labels.push(Element::new(cx).class("label").entity());
+            labels.push(Element::new(cx).class("label").entity());
+            VStack::new(cx, |cx| {
+                item = Element::new(cx).class("item").entity();
+            })
+            .class("group");
+        });
+        style_system(&mut cx);
+        assert_eq!(cx.style.width.get(labels[1]), None);
+
+        // Only the toggle is marked, as when the state of a view changes with an event.
+        let set_state = |cx: &mut Context, state: PseudoClassFlags, value: bool| {
+            cx.style.pseudo_classes.get_mut(toggle).unwrap().set(state, value);
+            cx.style.needs_restyle(toggle);
+            style_system(cx);
+        };
+
+        set_state(&mut cx, PseudoClassFlags::CHECKED, true);
+        let background = |cx: &Context, entity| cx.style.background_color.get(entity).copied();
+        assert_eq!(background(&cx, labels[0]), None);
+        assert_eq!(background(&cx, labels[1]), Some(Color::red()));
+        assert_eq!(background(&cx, labels[2]), None);
+        assert_eq!(cx.style.width.get(labels[0]), None);
+        assert_eq!(cx.style.width.get(labels[1]), Some(&Units::Pixels(20.0)));
+        assert_eq!(cx.style.width.get(labels[2]), Some(&Units::Pixels(20.0)));
+
+        set_state(&mut cx, PseudoClassFlags::HOVER, true);
+        assert_eq!(cx.style.opacity.get(item).copied(), Some(Opacity(0.5)));
+
+        set_state(&mut cx, PseudoClassFlags::CHECKED, false);
+        set_state(&mut cx, PseudoClassFlags::HOVER, false);
+        assert_eq!(background(&cx, labels[1]), None);
+        assert_eq!(cx.style.width.get(labels[2]), None);
+        assert_eq!(cx.style.opacity.get(item), None);
+    }
+
+    #[test]
+    fn media_rules_follow_window_size_and_theme() {
+        let mut cx = Context::default();
+        cx.style.dpi_factor = 1.0;
+        cx.add_stylesheet(
+            r#"
+            .panel { width: 300px; }
+            @media (max-width: 599px) {
+                .panel { width: 100px; }
+                .panel.wide { width: 200px; }
+            }
+            @media (prefers-color-scheme: dark) {
+                .panel { background-color: black; }
+            }
+            "#,
+        )
+        .unwrap();
+
+        let panel = Element::new(&mut cx).class("panel").entity();
+        let set_window_width = |cx: &mut Context, width: f32| {
+            cx.cache.set_bounds(Entity::root(), BoundingBox::from_min_max(0.0, 0.0, width, 400.0));
+            style_system(cx);
+        };
+
+        set_window_width(&mut cx, 800.0);
+        assert_eq!(cx.style.width.get(panel), Some(&Units::Pixels(300.0)));
+        assert_eq!(cx.style.background_color.get(panel), None);
+
+        set_window_width(&mut cx, 500.0);
+        assert_eq!(cx.style.width.get(panel), Some(&Units::Pixels(100.0)));
+
+        // A rule in a media block which doesn't match doesn't override a less specific rule.
+        set_window_width(&mut cx, 800.0);
+        assert_eq!(cx.style.width.get(panel), Some(&Units::Pixels(300.0)));
+        cx.with_current(panel, |cx| cx.toggle_class("wide", true));
+        style_system(&mut cx);
+        assert_eq!(cx.style.width.get(panel), Some(&Units::Pixels(300.0)));
+
+        // The built-in themes aren't loaded by the test context.
+        cx.ignore_default_theme = true;
+        let mut event_cx = EventContext::new(&mut cx);
+        event_cx.set_theme_mode(ThemeMode::DarkMode);
+        event_cx.reload_styles().unwrap();
+        style_system(&mut cx);
+        assert_eq!(cx.style.background_color.get(panel), Some(&Color::black()));
+    }
+
+    #[test]
+    fn structural_selectors_follow_tree_changes() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(
+            r#"
+            .row:nth-child(odd) { background-color: red; }
+            .row:nth-child(even) { background-color: blue; }
+            .row:last-child { opacity: 0.5; }
+            element:nth-of-type(2) { width: 30px; }
+            "#,
+        )
+        .unwrap();
+
+        let mut rows = Vec::new();
+        let list = VStack::new(&mut cx, |cx| {
+            for _ in 0..3 {
+                rows.push(Element::new(cx).class("row").entity());
+            }
+        })
+        .entity();
+        style_system(&mut cx);
+
+        let background = |cx: &Context, entity| cx.style.background_color.get(entity).copied();
+        assert_eq!(background(&cx, rows[0]), Some(Color::red()));
+        assert_eq!(background(&cx, rows[1]), Some(Color::blue()));
+        assert_eq!(background(&cx, rows[2]), Some(Color::red()));
+        assert_eq!(cx.style.opacity.get(rows[1]), None);
+        assert_eq!(cx.style.opacity.get(rows[2]).copied(), Some(Opacity(0.5)));
+
+        // Removing a row moves the rows after it.
+        cx.remove(rows[0]);
+        style_system(&mut cx);
+        assert_eq!(background(&cx, rows[1]), Some(Color::red()));
+        assert_eq!(background(&cx, rows[2]), Some(Color::blue()));
+        assert_eq!(cx.style.width.get(rows[2]), Some(&Units::Pixels(30.0)));
+
+        // Adding a row means the previous last row is no longer the last child.
+        let label = cx.with_current(list, |cx| Label::new(cx, "Label").class("row").entity());
+        style_system(&mut cx);
+        assert_eq!(cx.style.opacity.get(rows[2]), None);
+        assert_eq!(cx.style.opacity.get(label).copied(), Some(Opacity(0.5)));
+        assert_eq!(background(&cx, label), Some(Color::red()));
+        assert_eq!(cx.style.width.get(label), None);
+    }
+
+    #[test]
+    fn structural_selectors_only_restyle_the_children_they_can_match() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(
+            r#"
+            .row { height: 20px; }
+            .row:nth-child(odd) { background-color: red; }
+            "#,
+        )
+        .unwrap();
+
+        let mut rows = Vec::new();
+        let list = VStack::new(&mut cx, |cx| {
+            for _ in 0..4 {
+                let row = HStack::new(cx, |cx| {
+                    Element::new(cx);
+                    Element::new(cx);
+                });
+                rows.push(row.class("row").entity());
+            }
+        })
+        .entity();
+        style_system(&mut cx);
+
+        // Rows share the rules matched for the first row except for the structural rules.
+        let background = |cx: &Context, entity| cx.style.background_color.get(entity).copied();
+        assert_eq!(background(&cx, rows[2]), Some(Color::red()));
+        assert_eq!(background(&cx, rows[3]), None);
+        assert_eq!(cx.style.height.get(rows[3]), Some(&Units::Pixels(20.0)));
+
+        // Adding a row restyles the rows but not their contents. Of the restyled views, only the first row and the
+        // first view of the new row have their rules matched, which their siblings share.
+        let row = cx.with_current(list, |cx| {
+            HStack::new(cx, |cx| {
+                Element::new(cx);
+                Element::new(cx);
+            })
+            .class("row")
+            .entity()
+        });
+        style_system(&mut cx);
+        assert_eq!(cx.entities_matched(), 2);
+        assert_eq!(background(&cx, row), Some(Color::red()));
+        assert_eq!(cx.style.height.get(row), Some(&Units::Pixels(20.0)));
+    }
+
+    #[test]
+    fn negations_lists_and_combinators() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(
+            r#"
+            element:not(.primary) { background-color: red; }
+            .primary { background-color: blue; }
+
+            .item, #special { width: 10px; }
+            .item.wide { width: 20px; }
+
+            vstack .item { height: 15px; }
+            vstack > .item { height: 5px; }
+            "#,
+        )
+        .unwrap();
+
+        let mut elements = (Entity::root(), Entity::root(), Entity::root());
+        VStack::new(&mut cx, |cx| {
+            elements.0 = Element::new(cx).class("primary").entity();
+            elements.1 = Element::new(cx).class("item").class("wide").id("special").entity();
+            HStack::new(cx, |cx| {
+                elements.2 = Element::new(cx).class("item").class("wide").entity();
+            });
+        });
+        style_system(&mut cx);
+
+        let (primary, special, nested) = elements;
+        assert_eq!(cx.style.background_color.get(primary), Some(&Color::blue()));
+        assert_eq!(cx.style.background_color.get(special), Some(&Color::red()));
+
+        // The list matches the second element by its id, which is more specific than the classes of the other rule.
+        assert_eq!(cx.style.width.get(special), Some(&Units::Pixels(10.0)));
+        assert_eq!(cx.style.width.get(nested), Some(&Units::Pixels(20.0)));
+
+        // Only the descendant combinator matches the element within the inner stack.
+        assert_eq!(cx.style.height.get(special), Some(&Units::Pixels(5.0)));
+        assert_eq!(cx.style.height.get(nested), Some(&Units::Pixels(15.0)));
+
+        cx.with_current(special, |cx| cx.toggle_class("primary", true));
+        style_system(&mut cx);
+        assert_eq!(cx.style.background_color.get(special), Some(&Color::blue()));
+    }
+
+    #[test]
+    fn filters_transition_on_hover() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(
+            r#"
+            element { filter: grayscale(100%); transition: filter 1s linear; }
+            element:hover { filter: none; transition: filter 1s linear; }
+            "#,
+        )
+        .unwrap();
+
+        let element = Element::new(&mut cx).entity();
+        style_system(&mut cx);
+        assert_eq!(cx.style.filter.get(element), Some(&vec![Filter::Grayscale(1.0)]));
+
+        cx.style.pseudo_classes.get_mut(element).unwrap().set(PseudoClassFlags::HOVER, true);
+        cx.style.needs_restyle(element);
+        style_system(&mut cx);
+
+        // A filter missing from one end of the transition is interpolated from its neutral value.
+        let start = Instant::now();
+        cx.style.filter.tick(start + Duration::from_millis(500), ColorSpace::default());
+        let Some([Filter::Grayscale(amount)]) = cx.style.filter.get(element).map(Vec::as_slice)
+        else {
+            panic!("expected a grayscale filter");
+        };
+        assert!((amount - 0.5).abs() < 0.05);
+
+        // The view is left without a filter once the transition has finished.
+        cx.style.filter.tick(start + Duration::from_secs(2), ColorSpace::default());
+        cx.style.filter.tick(start + Duration::from_secs(2), ColorSpace::default());
+        assert_eq!(cx.style.filter.get(element), Some(&vec![]));
+    }
+
+    #[test]
+    fn font_variations_transition_per_axis() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(
+            r#"
+            label { font-variation-settings: "wght" 400; transition: font-variation-settings 1s linear; }
+            label:hover { font-variation-settings: "wght" 700; }
+            "#,
+        )
+        .unwrap();
+
+        let label = Label::new(&mut cx, "Bold on hover").entity();
+        style_system(&mut cx);
+        assert_eq!(
+            cx.style.font_variation_settings.get(label),
+            Some(&vec![FontVariation::new(b"wght", 400.0)])
+        );
+
+        cx.style.pseudo_classes.get_mut(label).unwrap().set(PseudoClassFlags::HOVER, true);
+        cx.style.needs_restyle(label);
+        style_system(&mut cx);
+
+        let start = Instant::now();
+        cx.style
+            .font_variation_settings
+            .tick(start + Duration::from_millis(500), ColorSpace::default());
+        let Some([weight]) = cx.style.font_variation_settings.get(label).map(Vec::as_slice) else {
+            panic!("expected a weight axis");
+        };
+        assert!((weight.0.value - 550.0).abs() < 20.0);
+
+        cx.style
+            .font_variation_settings
+            .tick(start + Duration::from_secs(2), ColorSpace::default());
+        cx.style
+            .font_variation_settings
+            .tick(start + Duration::from_secs(2), ColorSpace::default());
+        assert_eq!(
+            cx.style.font_variation_settings.get(label),
+            Some(&vec![FontVariation::new(b"wght", 700.0)])
+        );
+    }
+
+    #[test]
+    fn font_features_are_inherited() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(r#".dashboard { font-feature-settings: "tnum", "liga" off; }"#).unwrap();
+
+        let mut labels = Vec::new();
+        let dashboard = VStack::new(&mut cx, |cx| {
+            labels.push(Label::new(cx, "1024").entity());
+            labels.push(Label::new(cx, "Ligatures").font_feature_settings("normal").entity());
+        })
+        .class("dashboard")
+        .entity();
+        let counter = Label::new(&mut cx, "42").font_feature_settings("tnum").entity();
+        style_system(&mut cx);
+
+        let features = |cx: &Context, entity| {
+            cx.style.font_feature_settings.get(entity).map(|settings| settings.0.clone())
+        };
+        let dashboard_features = vec![FontFeature::new(b"tnum", 1), FontFeature::new(b"liga", 0)];
+        assert_eq!(features(&cx, dashboard), Some(dashboard_features.clone()));
+        assert_eq!(features(&cx, labels[0]), Some(dashboard_features));
+        assert_eq!(features(&cx, labels[1]), Some(vec![]));
+        assert_eq!(features(&cx, counter), Some(vec![FontFeature::new(b"tnum", 1)]));
+    }
+
+    #[test]
+    fn text_wrap_sets_white_space() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(
+            r#"
+            .code { text-wrap: false; }
+            .title { white-space: nowrap; text-overflow: ellipsis; }
+            .title.wrapped { text-wrap: true; }
+            "#,
+        )
+        .unwrap();
+
+        let code = Label::new(&mut cx, "fn main() {\n}").class("code").entity();
+        let title = Label::new(&mut cx, "A long title").class("title").entity();
+        let label = Label::new(&mut cx, "Text").text_wrap(false).entity();
+        style_system(&mut cx);
+
+        assert_eq!(cx.style.white_space.get(code), Some(&WhiteSpace::Pre));
+        assert_eq!(cx.style.white_space.get(title), Some(&WhiteSpace::NoWrap));
+        assert_eq!(cx.style.white_space.get(label), Some(&WhiteSpace::Pre));
+
+        // The compatibility property cascades with the property it sets.
+        cx.with_current(title, |cx| cx.toggle_class("wrapped", true));
+        style_system(&mut cx);
+        assert_eq!(cx.style.white_space.get(title), Some(&WhiteSpace::PreWrap));
+    }
+
+    #[derive(Lens)]
+    struct Search {
+        text: String,
+        query: String,
+    }
+
+    impl Model for Search {}
+
+    #[test]
+    fn part_selectors_match_the_highlighted_matches_of_a_label() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(
+            r#"
+            label::highlight { background-color: red; }
+            text-span { color: blue; }
+            "#,
+        )
+        .unwrap();
+
+        Search { text: String::from("Die Straße"), query: String::from("STRASSE") }.build(&mut cx);
+        let label = Label::highlighted(&mut cx, Search::text, Search::query).entity();
+        style_system(&mut cx);
+
+        let spans = LayoutTreeIterator::subtree(&cx.tree, label)
+            .filter(|entity| cx.style.text_span.get(*entity).is_some())
+            .map(|span| {
+                (cx.style.text.get(span).unwrap().as_str(), cx.style.background_color.get(span))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(spans, vec![("Die ", None), ("Straße", Some(&Color::red()))]);
+
+        // The part is also matched by selectors of the view it is.
+        let highlight = LayoutTreeIterator::subtree(&cx.tree, label)
+            .find(|entity| cx.style.parts.get(*entity).is_some())
+            .unwrap();
+        assert_eq!(cx.style.font_color.get(highlight), Some(&ColorOrGradient::from(Color::blue())));
+    }
+}
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use skia_safe::{
     font_arguments::VariationPosition,
     textlayout::{
@@ -8,82 +10,110 @@ use skia_safe::{
 };
 use vizia_storage::{LayoutChildIterator, LayoutTreeIterator};
 
-use crate::{cache::CachedData, prelude::*};
+use crate::{cache::CachedData, prelude::*, style::LayoutAxis, text::CHUNK_LINES};
 
 pub(crate) fn text_system(cx: &mut Context) {
     let iterator = LayoutTreeIterator::full(&cx.tree);
     for entity in iterator {
-        if !cx.style.text_construction.contains(entity) {
+        let reflow = cx.style.text_construction.contains(entity);
+        if !reflow && !cx.style.text_repaint.contains(entity) {
             continue;
         }
 
         if cx.style.text.contains(entity)
             && cx.style.display.get(entity).copied().unwrap_or_default() != Display::None
         {
-            if let Some(paragraph) =
-                build_paragraph(entity, &mut cx.style, &cx.tree, cx.text_context.font_collection())
-            {
-                cx.text_context.text_paragraphs.insert(entity, paragraph);
-                cx.style.needs_relayout();
-                cx.style.needs_text_layout(entity);
+            // A paint-only change cannot change the size of the text, so the new text is laid out with the width
+            // of the previous one instead of relaying out the view.
+            let previous_width = if reflow {
+                None
+            } else {
+                cx.text_context.text_layout(entity).map(|previous| previous.max_width())
+            };
+
+            // Long labels are laid out in chunks of lines, so that appending to their text only shapes the new lines.
+            let is_label = cx.views.get(&entity).and_then(|view| view.element()) == Some("label");
+            let chunked =
+                match is_label.then(|| chunked_text(&cx.style, &cx.tree, entity)).flatten() {
+                    Some(text) => {
+                        cx.text_context.set_chunks(
+                            entity,
+                            &text,
+                            paragraph_style(&cx.style, entity),
+                            text_style(&cx.style, entity),
+                        );
+                        true
+                    }
+                    None => false,
+                };
+
+            if !chunked {
+                let Some(paragraph) = build_paragraph(
+                    entity,
+                    &mut cx.style,
+                    &cx.tree,
+                    cx.text_context.font_collection(),
+                ) else {
+                    continue;
+                };
+                cx.text_context.set_paragraph(entity, paragraph);
+            }
+
+            match (previous_width, cx.text_context.text_layout_mut(entity)) {
+                (Some(width), Some(text)) => text.layout(width),
+                _ => cx.style.needs_relayout_entity(entity),
             }
+
+            cx.style.needs_text_layout(entity);
         }
     }
 
     cx.style.text_construction.clear();
+    cx.style.text_repaint.clear();
 }
 
 pub(crate) fn text_layout_system(cx: &mut Context) {
     let iterator = LayoutTreeIterator::full(&cx.tree);
     let mut redraw_entities = Vec::new();
     for entity in iterator {
-        if !cx.style.text_layout.contains(entity) {
+        if !cx.style.text_layout.contains(entity) || cx.text_context.text_layout(entity).is_none() {
             continue;
         }
 
-        if let Some(paragraph) = cx.text_context.text_paragraphs.get_mut(entity) {
-            let bounds = cx.cache.get_bounds(entity);
-            let padding_left = cx
-                .style
-                .padding_left
-                .get(entity)
-                .copied()
-                .unwrap_or_default()
-                .to_px(bounds.width(), 0.0)
-                * cx.style.scale_factor();
-            let padding_right = cx
-                .style
-                .padding_right
-                .get(entity)
-                .copied()
-                .unwrap_or_default()
-                .to_px(bounds.width(), 0.0)
-                * cx.style.scale_factor();
-            let text_bounds = cx
-                .text_context
-                .text_bounds
-                .get(entity)
-                .copied()
-                .unwrap_or(bounds.shrink_sides(padding_left, 0.0, padding_right, 0.0));
-
-            if !cx.style.width.get(entity).copied().unwrap_or_default().is_auto()
-                && !cx.style.height.get(entity).copied().unwrap_or_default().is_auto()
-            {
-                if cx.style.text_overflow.get(entity).copied().unwrap_or_default()
-                    == TextOverflow::Clip
-                {
-                    paragraph.layout(f32::MAX);
-                    paragraph
-                        .layout(text_bounds.width().max(paragraph.min_intrinsic_width() + 1.0));
+        let bounds = cx.cache.get_bounds(entity);
+        // Text which isn't measured during layout is laid out within the padding and border of the view.
+        let (inset_left, _, inset_right, _) = content_insets(&cx.style, entity, bounds);
+        let text_bounds = cx
+            .text_context
+            .text_bounds
+            .get(entity)
+            .copied()
+            .unwrap_or(bounds.shrink_sides(inset_left, 0.0, inset_right, 0.0));
+
+        if !cx.style.width.get(entity).copied().unwrap_or_default().is_auto()
+            && !cx.style.height.get(entity).copied().unwrap_or_default().is_auto()
+        {
+            let wraps = cx.style.white_space.get(entity).copied().unwrap_or_default().wraps();
+            let clip = cx.style.text_overflow.get(entity).copied().unwrap_or_default()
+                == TextOverflow::Clip;
+            if let Some(text) = cx.text_context.text_layout_mut(entity) {
+                if clip {
+                    text.layout(f32::MAX);
+                    let min_width =
+                        if wraps { text.min_intrinsic_width() } else { text.max_intrinsic_width() };
+                    text.layout(text_bounds.width().max(min_width + 1.0));
                 } else {
-                    paragraph.layout(text_bounds.width());
+                    text.layout(text_bounds.width());
                 }
             }
+        }
 
+        // Spans are only laid out within a single paragraph, as text with spans isn't split into chunks.
+        if let Some(paragraph) = cx.text_context.text_paragraphs.get(entity) {
             layout_span(&cx.style, &mut cx.cache, &cx.tree, entity, paragraph, bounds);
-
-            redraw_entities.push(entity);
         }
+
+        redraw_entities.push(entity);
     }
     for entity in redraw_entities {
         cx.needs_redraw(entity);
@@ -148,6 +178,39 @@ pub fn build_paragraph(
     tree: &Tree<Entity>,
     font_collection: &FontCollection,
 ) -> Option<Paragraph> {
+    let paragraph_style = paragraph_style(style, entity);
+    let white_space = style.white_space.get(entity).copied().unwrap_or_default();
+
+    let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
+
+    add_block(style, tree, entity, white_space, &mut paragraph_builder, &mut 0);
+
+    paragraph_builder.add_text("\u{200B}");
+    paragraph_builder.build().into()
+}
+
+// Returns the text of the entity if it is long enough to be laid out in chunks of lines, which is only the case for
+// text without spans which keeps its line breaks and isn't clamped or truncated with an ellipsis.
+fn chunked_text<'a>(style: &'a Style, tree: &Tree<Entity>, entity: Entity) -> Option<Cow<'a, str>> {
+    let white_space = style.white_space.get(entity).copied().unwrap_or_default();
+    if !white_space.preserves_line_breaks()
+        || style.line_clamp.get(entity).is_some()
+        || style.text_overflow.get(entity) == Some(&TextOverflow::Ellipsis)
+        || LayoutChildIterator::new(tree, entity)
+            .any(|child| style.text_span.get(child).copied().unwrap_or_default())
+    {
+        return None;
+    }
+
+    let text = style.text.get(entity)?;
+    if text.split('\n').nth(CHUNK_LINES).is_none() {
+        return None;
+    }
+
+    Some(laid_out_text(style, entity, text, white_space))
+}
+
+fn paragraph_style(style: &Style, entity: Entity) -> ParagraphStyle {
     let mut paragraph_style = ParagraphStyle::default();
     // paragraph_style.turn_hinting_off();
 
@@ -171,11 +234,15 @@ pub fn build_paragraph(
         paragraph_style.set_max_lines(line_clamp.0 as usize);
     }
 
-    // if let Some(text_wrap) = style.text_wrap.get(entity).copied() {
-    //     if !text_wrap {
-    //         paragraph_style.set_max_lines(1);
-    //     }
-    // }
+    // White Space
+    // Text on a single line which is too wide is only truncated with an ellipsis if it's the last line.
+    let white_space = style.white_space.get(entity).copied().unwrap_or_default();
+    if white_space == WhiteSpace::NoWrap
+        && style.text_overflow.get(entity) == Some(&TextOverflow::Ellipsis)
+    {
+        paragraph_style.set_max_lines(1);
+    }
+    paragraph_style.set_replace_tab_characters(true);
 
     // Text Align
     paragraph_style.set_text_align(
@@ -195,18 +262,14 @@ pub fn build_paragraph(
         .into(),
     );
 
-    let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
-
-    add_block(style, tree, entity, &mut paragraph_builder, &mut 0);
-
-    paragraph_builder.add_text("\u{200B}");
-    paragraph_builder.build().into()
+    paragraph_style
 }
 
 fn add_block(
     style: &mut Style,
     tree: &Tree<Entity>,
     entity: Entity,
+    white_space: WhiteSpace,
     paragraph_builder: &mut ParagraphBuilder,
     current: &mut usize,
 ) {
@@ -214,93 +277,171 @@ fn add_block(
 
     if let Some(text) = style.text.get(entity) {
         if !text.is_empty() {
-            // Text Style
+            let text_style = text_style(style, entity);
 
-            let mut text_style = TextStyle::new();
+            // Spans are laid out within the paragraph of the view, so its white space applies to all of them.
+            let text = laid_out_text(style, entity, text, white_space);
 
-            let font_color = style.font_color.get(entity).cloned().unwrap_or_default();
+            paragraph_builder.push_style(&text_style);
+            style.text_range.insert(entity, *current..*current + text.len());
+            paragraph_builder.add_text(text.as_ref());
+            *current += text.len();
+        }
+    }
 
-            if let Some(text_decoration_line) = style.text_decoration_line.get(entity).copied() {
-                text_style.set_decoration_type(text_decoration_line.into());
-                text_style.set_decoration_color(font_color);
-            }
+    let iter = LayoutChildIterator::new(tree, entity);
+    for child in iter {
+        if style.text_span.get(child).copied().unwrap_or_default() {
+            add_block(style, tree, child, white_space, paragraph_builder, current);
+        }
+    }
+}
 
-            // Font Families
-            text_style.set_font_families(
-                style
-                    .font_family
-                    .get(entity)
-                    .map(Vec::as_slice)
-                    .unwrap_or(&[FamilyOwned::Generic(GenericFontFamily::SansSerif)]),
-            );
+fn text_style(style: &Style, entity: Entity) -> TextStyle {
+    let mut text_style = TextStyle::new();
+
+    let font_color = style.resolve_accent_color(
+        style.font_color.get(entity).map(ColorOrGradient::color).unwrap_or_default(),
+    );
+
+    // Font Families, followed by the fallbacks for the locale so that characters missing from the fonts
+    // are shown with the same fonts throughout the application.
+    let font_families = style
+        .font_family
+        .get(entity)
+        .map(Vec::as_slice)
+        .unwrap_or(&[FamilyOwned::Generic(GenericFontFamily::SansSerif)]);
+    let fallbacks = style.fallback_fonts.iter().filter(|family| !font_families.contains(family));
+    text_style
+        .set_font_families(&font_families.iter().chain(fallbacks).cloned().collect::<Vec<_>>());
+
+    let mut paint = Paint::default();
+    // Font Color
+    // Gradient text is painted with the color of the first stop and the gradient is applied when drawn.
+    if let Some(font_color) = style.font_color.get(entity) {
+        paint.set_color(style.resolve_accent_color(font_color.color()));
+        paint.set_anti_alias(false);
+        paint.set_blend_mode(BlendMode::SrcOver);
+    }
 
+    if let Some(text_stroke) = style.text_stroke_width.get(entity) {
+        paint.set_stroke_width(text_stroke.to_px().unwrap_or(0.0));
+        paint.set_style(
+            (*style.text_stroke_style.get(entity).unwrap_or(&TextStrokeStyle::default())).into(),
+        );
+    }
+
+    text_style.set_foreground_paint(&paint);
+
+    if let Some(background_color) = style.background_color.get(entity) {
+        if style.text_span.get(entity).is_some() {
             let mut paint = Paint::default();
-            // Font Color
-            if let Some(font_color) = style.font_color.get(entity) {
-                paint.set_color(*font_color);
-                paint.set_anti_alias(false);
-                paint.set_blend_mode(BlendMode::SrcOver);
-            }
+            paint.set_color(style.resolve_accent_color(*background_color));
+            paint.set_anti_alias(false);
+            paint.set_blend_mode(BlendMode::SrcOver);
+            text_style.set_background_paint(&paint);
+        }
+    }
 
-            if let Some(text_stroke) = style.text_stroke_width.get(entity) {
-                paint.set_stroke_width(text_stroke.to_px().unwrap_or(0.0));
-                paint.set_style(
-                    (*style.text_stroke_style.get(entity).unwrap_or(&TextStrokeStyle::default()))
-                        .into(),
-                );
-            }
+    // Font Size
+    let font_size = style.font_size.get(entity).map_or(16.0, |f| f.0);
+    text_style.set_font_size(font_size * style.scale_factor());
 
-            text_style.set_foreground_paint(&paint);
+    // Text Decoration
+    // Skia draws the decoration lines along each line of the laid out text.
+    if let Some(text_decoration_line) = style.text_decoration_line.get(entity).copied() {
+        text_style.set_decoration_type(text_decoration_line.into());
+        text_style.set_decoration_style(
+            style.text_decoration_style.get(entity).copied().unwrap_or_default().into(),
+        );
 
-            if let Some(background_color) = style.background_color.get(entity) {
-                if style.text_span.get(entity).is_some() {
-                    let mut paint = Paint::default();
-                    paint.set_color(*background_color);
-                    paint.set_anti_alias(false);
-                    paint.set_blend_mode(BlendMode::SrcOver);
-                    text_style.set_background_paint(&paint);
-                }
-            }
+        let decoration_color = match style.text_decoration_color.get(entity) {
+            Some(Color::CurrentColor) | None => font_color,
+            Some(color) => style.resolve_accent_color(*color),
+        };
+        text_style.set_decoration_color(decoration_color);
+
+        // Skia scales the thickness given by the font, which is assumed to be a fourteenth of the font size.
+        let thickness = style
+            .text_decoration_thickness
+            .get(entity)
+            .map(|thickness| thickness.to_pixels(font_size, 1.0))
+            .unwrap_or_default();
+        if thickness > 0.0 {
+            text_style.set_decoration_thickness_multiplier(thickness * 14.0 / font_size);
+        }
+    }
 
-            // Font Size
-            let font_size = style.font_size.get(entity).map_or(16.0, |f| f.0);
-            text_style.set_font_size(font_size * style.scale_factor());
-
-            // Font Style
-            match (
-                style.font_weight.get(entity),
-                style.font_width.get(entity),
-                style.font_slant.get(entity),
-            ) {
-                (None, None, None) => {}
-                (weight, width, slant) => {
-                    text_style.set_font_style(FontStyle::new(
-                        weight.copied().unwrap_or_default().into(),
-                        width.copied().unwrap_or_default().into(),
-                        slant.copied().unwrap_or_default().into(),
-                    ));
-                }
-            }
+    // Line Height
+    // A line height smaller than the font size is applied as given, so that the lines overlap.
+    if let Some(height) =
+        style.line_height.get(entity).and_then(|line_height| line_height.to_factor(font_size))
+    {
+        text_style.set_height_override(true);
+        text_style.set_half_leading(true);
+        text_style.set_height(height);
+    }
 
-            // Font Variations
-            if let Some(coordinates) = style.font_variation_settings.get(entity) {
-                let coordinates = coordinates.iter().map(|c| c.0).collect::<Vec<_>>();
-                text_style.set_font_arguments(&FontArguments::new().set_variation_design_position(
-                    VariationPosition { coordinates: &coordinates },
-                ));
-            }
+    // Letter and Word Spacing
+    // Relative lengths such as `em` are resolved against the font size of the entity.
+    let length_context = style.length_context(entity, LayoutAxis::Horizontal);
+    if let Some(letter_spacing) = style.letter_spacing.get(entity) {
+        text_style
+            .set_letter_spacing(letter_spacing.0.resolve(&length_context) * style.scale_factor());
+    }
 
-            paragraph_builder.push_style(&text_style);
-            style.text_range.insert(entity, *current..*current + text.len());
-            paragraph_builder.add_text(text.as_str());
-            *current += text.len();
+    if let Some(word_spacing) = style.word_spacing.get(entity) {
+        text_style.set_word_spacing(word_spacing.0.resolve(&length_context) * style.scale_factor());
+    }
+
+    // Font Style
+    match (
+        style.font_weight.get(entity),
+        style.font_width.get(entity),
+        style.font_slant.get(entity),
+    ) {
+        (None, None, None) => {}
+        (weight, width, slant) => {
+            text_style.set_font_style(FontStyle::new(
+                weight.copied().unwrap_or_default().into(),
+                width.copied().unwrap_or_default().into(),
+                slant.copied().unwrap_or_default().into(),
+            ));
         }
     }
 
-    let iter = LayoutChildIterator::new(tree, entity);
-    for child in iter {
-        if style.text_span.get(child).copied().unwrap_or_default() {
-            add_block(style, tree, child, paragraph_builder, current);
+    // Font Variations
+    if let Some(coordinates) = style.font_variation_settings.get(entity) {
+        let coordinates = coordinates.iter().map(|c| c.0).collect::<Vec<_>>();
+        text_style.set_font_arguments(
+            &FontArguments::new()
+                .set_variation_design_position(VariationPosition { coordinates: &coordinates }),
+        );
+    }
+
+    // Font Features
+    if let Some(font_feature_settings) = style.font_feature_settings.get(entity) {
+        for feature in font_feature_settings.0.iter() {
+            text_style.add_font_feature(feature.name(), feature.value as i32);
         }
     }
+
+    text_style
+}
+
+// Returns the text as it is laid out for the entity, with its text transform and white space applied.
+fn laid_out_text<'a>(
+    style: &Style,
+    entity: Entity,
+    text: &'a str,
+    white_space: WhiteSpace,
+) -> Cow<'a, str> {
+    // Text Transform
+    // The transform is applied to the laid out text so that the stored text keeps the bound data.
+    let text = style.text_transform.get(entity).copied().unwrap_or_default().apply(text);
+    let collapsed = match white_space.apply(&text) {
+        Cow::Owned(collapsed) => Some(collapsed),
+        Cow::Borrowed(_) => None,
+    };
+    collapsed.map_or(text, Cow::Owned)
 }
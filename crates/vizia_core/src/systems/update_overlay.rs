@@ -0,0 +1,190 @@
+use log::debug;
+use skia_safe::{Canvas, Paint, Rect};
+use vizia_storage::ChildIterator;
+
+use crate::prelude::*;
+
+// How long it takes for the overlay of an update to fade out.
+const FADE_DURATION: Duration = Duration::from_millis(400);
+
+/// The kinds of update which are shown by the update overlay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UpdateKind {
+    /// The contents of a binding were rebuilt.
+    Rebuild,
+    /// The style of a view was recomputed.
+    Restyle,
+    /// The layout of a subtree was recomputed.
+    Relayout,
+}
+
+impl UpdateKind {
+    fn color(self) -> Color {
+        match self {
+            UpdateKind::Rebuild => Color::rgb(255, 0, 200),
+            UpdateKind::Restyle => Color::rgb(0, 150, 255),
+            UpdateKind::Relayout => Color::rgb(255, 170, 0),
+        }
+    }
+}
+
+struct Flash {
+    entity: Entity,
+    kind: UpdateKind,
+    start: Instant,
+}
+
+/// Flashes a colored overlay over the views which were rebuilt, restyled, or relaid out, in debug builds.
+///
+/// The overlay is toggled with `ctrl + shift + alt + U`, or with [`Context::show_update_overlay`].
+#[derive(Default)]
+pub(crate) struct UpdateOverlay {
+    enabled: bool,
+    flashes: Vec<Flash>,
+    // The number of rebuilt bindings, restyled views, and relaid out views since the last frame.
+    rebuilt: usize,
+    restyled: usize,
+    relaid_out: usize,
+}
+
+impl UpdateOverlay {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.flashes.clear();
+        }
+    }
+
+    /// Returns true if there are overlays which are still fading out.
+    pub fn is_fading(&self) -> bool {
+        !self.flashes.is_empty()
+    }
+
+    /// Flashes the overlay of the given kind over the entity and its descendants.
+    pub fn flash(&mut self, kind: UpdateKind, entity: Entity) {
+        if !self.enabled {
+            return;
+        }
+
+        let start = Instant::now();
+        if let Some(flash) =
+            self.flashes.iter_mut().find(|flash| flash.entity == entity && flash.kind == kind)
+        {
+            flash.start = start;
+        } else {
+            self.flashes.push(Flash { entity, kind, start });
+        }
+    }
+
+    /// Adds to the number of updates of the given kind in the current frame.
+    pub fn count(&mut self, kind: UpdateKind, count: usize) {
+        if !self.enabled {
+            return;
+        }
+
+        match kind {
+            UpdateKind::Rebuild => self.rebuilt += count,
+            UpdateKind::Restyle => self.restyled += count,
+            UpdateKind::Relayout => self.relaid_out += count,
+        }
+    }
+}
+
+// Returns the bounds covered by the entity, which for bindings and other views ignored by layout is the union of the
+// bounds of their children.
+fn covered_bounds(cx: &Context, entity: Entity) -> Option<BoundingBox> {
+    if !cx.tree.is_ignored(entity) {
+        return cx
+            .cache
+            .bounds
+            .get(entity)
+            .copied()
+            .filter(|bounds| bounds.w > 0.0 && bounds.h > 0.0);
+    }
+
+    ChildIterator::new(&cx.tree, entity)
+        .filter_map(|child| covered_bounds(cx, child))
+        .reduce(|bounds, child_bounds| bounds.union(&child_bounds))
+}
+
+/// Draws the overlay for the updates within the window, and logs the number of updates since the last frame.
+pub(crate) fn draw_update_overlay(cx: &mut Context, window_entity: Entity, canvas: &Canvas) {
+    if !cx.update_overlay.enabled {
+        return;
+    }
+
+    let overlay = &mut cx.update_overlay;
+    if overlay.rebuilt != 0 || overlay.restyled != 0 || overlay.relaid_out != 0 {
+        debug!(
+            "Rebuilt {} bindings, restyled {} views, relaid out {} views",
+            overlay.rebuilt, overlay.restyled, overlay.relaid_out
+        );
+        overlay.rebuilt = 0;
+        overlay.restyled = 0;
+        overlay.relaid_out = 0;
+    }
+
+    let now = Instant::now();
+    overlay.flashes.retain(|flash| now.duration_since(flash.start) < FADE_DURATION);
+
+    for flash in cx.update_overlay.flashes.iter() {
+        if !cx.entity_manager.is_alive(flash.entity)
+            || cx.tree.get_parent_window(flash.entity).unwrap_or(Entity::root()) != window_entity
+        {
+            continue;
+        }
+
+        let Some(bounds) = covered_bounds(cx, flash.entity) else {
+            continue;
+        };
+
+        let fade =
+            1.0 - now.duration_since(flash.start).as_secs_f32() / FADE_DURATION.as_secs_f32();
+
+        let mut paint = Paint::default();
+        paint.set_color(flash.kind.color());
+        paint.set_alpha_f(0.35 * fade);
+        canvas.draw_rect(Rect::from(bounds), &paint);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::{binding_system, layout_system};
+
+    #[derive(Lens)]
+    struct Counter {
+        count: i32,
+    }
+
+    impl Model for Counter {}
+
+    #[test]
+    fn rebuilt_binding_is_flashed() {
+        let mut cx = Context::default();
+        cx.show_update_overlay(true);
+
+        Counter { count: 0 }.build(&mut cx);
+        Binding::new(&mut cx, Counter::count, |cx, _| {
+            Element::new(cx);
+        });
+        let binding = *cx.tree.get_last_child(Entity::root()).unwrap();
+
+        cx.replace_model(Counter { count: 1 });
+        binding_system(&mut cx);
+        layout_system(&mut cx);
+
+        let overlay = &cx.update_overlay;
+        assert_eq!(overlay.rebuilt, 1);
+        assert!(overlay.relaid_out > 0);
+        assert!(overlay
+            .flashes
+            .iter()
+            .any(|flash| flash.entity == binding && flash.kind == UpdateKind::Rebuild));
+    }
+}
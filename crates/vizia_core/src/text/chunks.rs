@@ -0,0 +1,327 @@
+use skia_safe::textlayout::{
+    FontCollection, Paragraph, ParagraphBuilder, ParagraphStyle, TextStyle,
+};
+use skia_safe::Canvas;
+
+/// The number of lines of text shaped together in each chunk of a [`TextChunks`].
+pub(crate) const CHUNK_LINES: usize = 64;
+
+/// The laid out text of a view, which is either a single paragraph or a paragraph for each chunk of lines.
+pub(crate) trait TextLayout {
+    /// Breaks the text into lines no wider than the given width.
+    fn layout(&mut self, width: f32);
+
+    /// Returns the width which the text was last laid out with.
+    fn max_width(&self) -> f32;
+
+    /// Returns the height of the laid out text.
+    fn height(&self) -> f32;
+
+    /// Returns the width of the widest word of the text.
+    fn min_intrinsic_width(&self) -> f32;
+
+    /// Returns the width of the widest line of the text when it isn't wrapped.
+    fn max_intrinsic_width(&self) -> f32;
+
+    /// Returns the distance from the top of the text to the baseline of its first line.
+    fn alphabetic_baseline(&self) -> f32;
+
+    /// Returns the position of each line of the laid out text.
+    fn lines(&self) -> Vec<TextLine>;
+
+    /// Paints the text with its top left corner at the given point.
+    fn paint(&self, canvas: &Canvas, origin: (f32, f32));
+}
+
+/// The position of a line of laid out text, relative to the top left corner of the text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct TextLine {
+    pub left: f32,
+    pub width: f32,
+    pub baseline: f32,
+    pub ascent: f32,
+}
+
+impl TextLayout for Paragraph {
+    fn layout(&mut self, width: f32) {
+        Paragraph::layout(self, width);
+    }
+
+    fn max_width(&self) -> f32 {
+        Paragraph::max_width(self)
+    }
+
+    fn height(&self) -> f32 {
+        Paragraph::height(self)
+    }
+
+    fn min_intrinsic_width(&self) -> f32 {
+        Paragraph::min_intrinsic_width(self)
+    }
+
+    fn max_intrinsic_width(&self) -> f32 {
+        Paragraph::max_intrinsic_width(self)
+    }
+
+    fn alphabetic_baseline(&self) -> f32 {
+        Paragraph::alphabetic_baseline(self)
+    }
+
+    fn lines(&self) -> Vec<TextLine> {
+        self.get_line_metrics()
+            .iter()
+            .map(|line| TextLine {
+                left: line.left as f32,
+                width: line.width as f32,
+                baseline: line.baseline as f32,
+                ascent: line.ascent as f32,
+            })
+            .collect()
+    }
+
+    fn paint(&self, canvas: &Canvas, origin: (f32, f32)) {
+        Paragraph::paint(self, canvas, origin);
+    }
+}
+
+/// The text of a view split into chunks of lines, each shaped as a separate paragraph and stacked vertically.
+///
+/// Skia shapes the whole of a paragraph when it is first laid out, so appending a line to a long text would
+/// otherwise shape all of it again. When the text changes, the chunks at the start of the text which are unchanged
+/// are kept along with their shaping, and only the remaining chunks are built again.
+pub(crate) struct TextChunks {
+    paragraph_style: ParagraphStyle,
+    text_style: TextStyle,
+    chunks: Vec<TextChunk>,
+    width: f32,
+}
+
+struct TextChunk {
+    text: String,
+    paragraph: Paragraph,
+    // The distance from the top of the text to the top of the chunk.
+    top: f32,
+}
+
+impl TextChunks {
+    pub(crate) fn new(paragraph_style: ParagraphStyle, text_style: TextStyle) -> Self {
+        Self { paragraph_style, text_style, chunks: Vec::new(), width: 0.0 }
+    }
+
+    /// Replaces the text with the given text, returning the number of chunks which were built.
+    ///
+    /// Chunks are compared from the start of the text until the first one which has changed, so the comparison
+    /// stops at the edit and costs no more than the unchanged text before it. All of the chunks are built again if
+    /// the styles have changed.
+    pub(crate) fn update(
+        &mut self,
+        text: &str,
+        paragraph_style: ParagraphStyle,
+        text_style: TextStyle,
+        font_collection: &FontCollection,
+    ) -> usize {
+        if paragraph_style != self.paragraph_style || text_style != self.text_style {
+            self.paragraph_style = paragraph_style;
+            self.text_style = text_style;
+            self.chunks.clear();
+        }
+
+        let texts = chunk_text(text);
+
+        let unchanged = self
+            .chunks
+            .iter()
+            .zip(texts.iter())
+            .take_while(|(chunk, text)| chunk.text == **text)
+            .count();
+        self.chunks.truncate(unchanged);
+
+        for text in &texts[unchanged..] {
+            let mut builder = ParagraphBuilder::new(&self.paragraph_style, font_collection);
+            builder.push_style(&self.text_style);
+            builder.add_text(text);
+            builder.add_text("\u{200B}");
+            self.chunks.push(TextChunk {
+                text: text.to_string(),
+                paragraph: builder.build(),
+                top: 0.0,
+            });
+        }
+
+        texts.len() - unchanged
+    }
+
+    /// Builds all of the chunks again the next time the text is updated, such as when the fonts have changed.
+    pub(crate) fn invalidate(&mut self) {
+        for chunk in self.chunks.iter_mut() {
+            chunk.text.clear();
+        }
+    }
+}
+
+// Splits the text after every `CHUNK_LINES` lines, dropping the line break between chunks.
+fn chunk_text(text: &str) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    for (lines, (index, _)) in text.match_indices('\n').enumerate() {
+        if (lines + 1) % CHUNK_LINES == 0 {
+            chunks.push(&text[start..index]);
+            start = index + 1;
+        }
+    }
+    chunks.push(&text[start..]);
+    chunks
+}
+
+impl TextLayout for TextChunks {
+    fn layout(&mut self, width: f32) {
+        self.width = width;
+        let mut top = 0.0;
+        for chunk in self.chunks.iter_mut() {
+            // Skia only breaks the lines of a chunk again if the width has changed, and never shapes it again.
+            chunk.paragraph.layout(width);
+            chunk.top = top;
+            top += chunk.paragraph.height();
+        }
+    }
+
+    fn max_width(&self) -> f32 {
+        self.width
+    }
+
+    fn height(&self) -> f32 {
+        self.chunks.last().map_or(0.0, |chunk| chunk.top + chunk.paragraph.height())
+    }
+
+    fn min_intrinsic_width(&self) -> f32 {
+        self.chunks.iter().map(|chunk| chunk.paragraph.min_intrinsic_width()).fold(0.0, f32::max)
+    }
+
+    fn max_intrinsic_width(&self) -> f32 {
+        self.chunks.iter().map(|chunk| chunk.paragraph.max_intrinsic_width()).fold(0.0, f32::max)
+    }
+
+    fn alphabetic_baseline(&self) -> f32 {
+        self.chunks.first().map_or(0.0, |chunk| chunk.paragraph.alphabetic_baseline())
+    }
+
+    fn lines(&self) -> Vec<TextLine> {
+        self.chunks
+            .iter()
+            .flat_map(|chunk| {
+                chunk
+                    .paragraph
+                    .lines()
+                    .into_iter()
+                    .map(|line| TextLine { baseline: chunk.top + line.baseline, ..line })
+            })
+            .collect()
+    }
+
+    fn paint(&self, canvas: &Canvas, origin: (f32, f32)) {
+        // Only the chunks within the clip are painted, so scrolling through a long text paints a few chunks.
+        let Some(clip) = canvas.local_clip_bounds() else {
+            return;
+        };
+
+        for chunk in self.chunks.iter() {
+            let top = origin.1 + chunk.top;
+            if top > clip.bottom() || top + chunk.paragraph.height() < clip.top() {
+                continue;
+            }
+
+            chunk.paragraph.paint(canvas, (origin.0, top));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::systems::{build_paragraph, layout_system};
+
+    fn long_label(cx: &mut Context, lines: usize) -> Entity {
+        cx.style.dpi_factor = 1.0;
+        cx.style.width.insert(Entity::root(), Units::Pixels(800.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(600.0));
+
+        let text = (0..lines).map(|line| format!("Line {line}")).collect::<Vec<_>>().join("\n");
+        let label = Label::new(cx, &text).width(Pixels(400.0)).height(Auto).entity();
+        layout_system(cx);
+        label
+    }
+
+    fn append_line(cx: &mut Context, label: Entity, line: &str) {
+        if let Some(text) = cx.style.text.get_mut(label) {
+            text.push('\n');
+            text.push_str(line);
+        }
+        cx.style.needs_text_update(label);
+        layout_system(cx);
+    }
+
+    #[test]
+    fn appending_to_a_long_label_only_builds_the_last_chunk() {
+        let mut cx = Context::default();
+        let label = long_label(&mut cx, 1000);
+        assert!(cx.text_context.text_chunks.contains(label));
+        assert_eq!(cx.text_context.chunks_built, 1000usize.div_ceil(CHUNK_LINES));
+
+        let built = cx.text_context.chunks_built;
+        append_line(&mut cx, label, "Line 1000");
+        assert_eq!(cx.text_context.chunks_built, built + 1);
+
+        // The chunks are stacked to the same height as the text laid out as a single paragraph.
+        let mut paragraph =
+            build_paragraph(label, &mut cx.style, &cx.tree, &cx.text_context.font_collection)
+                .unwrap();
+        let text = cx.text_context.text_layout(label).unwrap();
+        paragraph.layout(text.max_width());
+        assert!((text.height() - paragraph.height()).abs() < 1.0);
+        assert_eq!(text.lines().len(), paragraph.get_line_metrics().len());
+
+        // A change to the style of the text builds all of the chunks again.
+        let built = cx.text_context.chunks_built;
+        cx.style.font_size.insert(label, FontSize(20.0));
+        cx.style.needs_text_update(label);
+        layout_system(&mut cx);
+        assert_eq!(cx.text_context.chunks_built, built + 1001usize.div_ceil(CHUNK_LINES));
+    }
+
+    #[test]
+    fn short_labels_are_a_single_paragraph() {
+        let mut cx = Context::default();
+        let label = long_label(&mut cx, CHUNK_LINES);
+        assert!(cx.text_context.text_paragraphs.contains(label));
+        assert_eq!(cx.text_context.chunks_built, 0);
+
+        append_line(&mut cx, label, "Another line");
+        assert!(cx.text_context.text_chunks.contains(label));
+        assert!(!cx.text_context.text_paragraphs.contains(label));
+    }
+
+    // Run with `cargo test --release -p vizia_core -- --ignored --nocapture append_to_long_label`.
+    #[test]
+    #[ignore]
+    fn append_to_long_label_benchmark() {
+        const APPENDS: u32 = 100;
+
+        for lines in [1_000, 10_000] {
+            let mut cx = Context::default();
+            let label = long_label(&mut cx, lines);
+
+            let built = cx.text_context.chunks_built;
+            let start = Instant::now();
+            for line in 0..APPENDS {
+                append_line(&mut cx, label, &format!("Appended line {line}"));
+            }
+            println!("{lines} lines: {:?} per append", start.elapsed() / APPENDS);
+
+            // Each append only builds the chunk which the appended line is added to.
+            assert_eq!(cx.text_context.chunks_built - built, APPENDS as usize);
+        }
+    }
+}
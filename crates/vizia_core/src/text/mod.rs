@@ -1,8 +1,11 @@
 mod movement;
 pub use movement::*;
 
-// pub(crate) mod scrolling;
-// pub(crate) use scrolling::*;
+pub(crate) mod scrolling;
+pub(crate) use scrolling::*;
+
+pub(crate) mod chunks;
+pub(crate) use chunks::*;
 
 pub(crate) mod text_context;
 pub(crate) use text_context::*;
@@ -15,3 +18,6 @@ pub use selection::*;
 
 pub mod backspace;
 pub use backspace::*;
+
+pub mod search;
+pub use search::*;
@@ -1,6 +1,8 @@
 use crate::layout::BoundingBox;
 
-pub(crate) fn _enforce_text_bounds(
+/// Returns the transform clamped so that the text doesn't scroll further than its edges, given the bounds of the
+/// text and the bounds it is visible within. Text which fits within the bounds isn't scrolled.
+pub(crate) fn enforce_text_bounds(
     text_bounds: &BoundingBox,
     bounds: &BoundingBox,
     transform: (f32, f32),
@@ -34,17 +36,19 @@ pub(crate) fn _enforce_text_bounds(
     (tx, ty)
 }
 
-pub(crate) fn _ensure_visible(
-    text_bounds: &BoundingBox,
+/// Returns the transform adjusted by the least amount so that the box, usually around the caret, lies within the
+/// visible bounds.
+pub(crate) fn ensure_visible(
+    caret_bounds: &BoundingBox,
     bounds: &BoundingBox,
     transform: (f32, f32),
 ) -> (f32, f32) {
     let (mut tx, mut ty) = transform;
     let caret_box = BoundingBox {
-        x: text_bounds.x + tx,
-        y: text_bounds.y + ty,
-        w: text_bounds.w,
-        h: text_bounds.h,
+        x: caret_bounds.x + tx,
+        y: caret_bounds.y + ty,
+        w: caret_bounds.w,
+        h: caret_bounds.h,
     };
     if caret_box.left() < bounds.left() {
         tx += bounds.left() - caret_box.left();
@@ -60,3 +64,27 @@ pub(crate) fn _ensure_visible(
     }
     (tx, ty)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn caret_scrolls_into_view_within_text() {
+        let bounds = BoundingBox { x: 0.0, y: 0.0, w: 100.0, h: 20.0 };
+        let text = BoundingBox { x: 0.0, y: 0.0, w: 300.0, h: 20.0 };
+
+        // A caret past the right edge scrolls the text left until the caret is visible.
+        let caret = BoundingBox { x: 150.0, y: 0.0, w: 1.0, h: 20.0 };
+        let transform = ensure_visible(&caret, &bounds, (0.0, 0.0));
+        assert_eq!(transform, (-51.0, 0.0));
+        assert_eq!(enforce_text_bounds(&text, &bounds, transform), (-51.0, 0.0));
+
+        // The text never scrolls further than its end.
+        assert_eq!(enforce_text_bounds(&text, &bounds, (-250.0, 0.0)), (-200.0, 0.0));
+
+        // Text which fits isn't scrolled.
+        let short = BoundingBox { x: 0.0, y: 0.0, w: 50.0, h: 20.0 };
+        assert_eq!(enforce_text_bounds(&short, &bounds, (-20.0, 0.0)), (0.0, 0.0));
+    }
+}
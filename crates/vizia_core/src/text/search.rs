@@ -0,0 +1,111 @@
+use std::ops::Range;
+
+use caseless::Caseless;
+
+/// Appends the full case folding of a character to the given string.
+///
+/// The folding is the default (non-Turkic) full case folding of the Unicode `CaseFolding.txt`, so for example `ß`
+/// and `ẞ` fold to `ss`, `İ` folds to `i` followed by a combining dot, and the dotless `ı` folds to itself.
+pub fn fold_char(c: char, folded: &mut String) {
+    folded.extend(std::iter::once(c).default_case_fold());
+}
+
+/// Returns the case folded form of a string, such that strings which differ only by case fold to the same string.
+pub fn fold_case(text: &str) -> String {
+    let mut folded = String::with_capacity(text.len());
+    for c in text.chars() {
+        fold_char(c, &mut folded);
+    }
+
+    folded
+}
+
+/// Returns the byte ranges of the non-overlapping occurrences of `query` within `text`.
+///
+/// When `case_sensitive` is false the strings are compared by their case folding, so a match may have a different
+/// length than the query, for example `strasse` matches `Straße`. Matches which would start or end within the
+/// folding of a single character are ignored.
+pub fn find_matches(text: &str, query: &str, case_sensitive: bool) -> Vec<Range<usize>> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    if case_sensitive {
+        return text.match_indices(query).map(|(start, m)| start..start + m.len()).collect();
+    }
+
+    // Maps the offset of the folding of each character to the offset of the character in the original text.
+    let mut folded = String::with_capacity(text.len());
+    let mut offsets = Vec::with_capacity(text.len() + 1);
+    for (index, c) in text.char_indices() {
+        offsets.push((folded.len(), index));
+        fold_char(c, &mut folded);
+    }
+    offsets.push((folded.len(), text.len()));
+
+    let original_offset = |folded_offset: usize| {
+        offsets
+            .binary_search_by_key(&folded_offset, |(folded, _)| *folded)
+            .ok()
+            .map(|index| offsets[index].1)
+    };
+
+    let query = fold_case(query);
+    let mut matches = Vec::new();
+    let mut search_start = 0;
+    while let Some(position) = folded[search_start..].find(query.as_str()) {
+        let start = search_start + position;
+        let end = start + query.len();
+        if let (Some(original_start), Some(original_end)) =
+            (original_offset(start), original_offset(end))
+        {
+            matches.push(original_start..original_end);
+            search_start = end;
+        } else {
+            // Skip past the first character of the rejected match so that overlapping matches are still found.
+            search_start = start + folded[start..].chars().next().map_or(1, char::len_utf8);
+        }
+    }
+
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn folds_special_cases() {
+        assert_eq!(fold_case("Straße"), "strasse");
+        assert_eq!(fold_case("STRAẞE"), "strasse");
+        assert_eq!(fold_case("İ"), "i\u{307}");
+        assert_eq!(fold_case("ı"), "ı");
+        assert_eq!(fold_case("I"), "i");
+        assert_eq!(fold_case("ΣΑΣ"), fold_case("σας"));
+        assert_eq!(fold_case("ﬀ"), "ff");
+        // Characters which fold to a different case than their lowercase.
+        assert_eq!(fold_case("\u{212A}"), "k");
+        assert_eq!(fold_case("\u{AB70}"), "\u{13A0}");
+        assert_eq!(fold_case("\u{13A0}"), "\u{13A0}");
+    }
+
+    #[test]
+    fn finds_case_insensitive_matches() {
+        assert_eq!(find_matches("Hello hello HELLO", "hello", false), vec![0..5, 6..11, 12..17]);
+        assert_eq!(find_matches("Hello hello HELLO", "hello", true), vec![6..11]);
+        assert_eq!(find_matches("Hello", "", false), vec![]);
+    }
+
+    #[test]
+    fn matches_map_to_original_ranges() {
+        // `ß` is two bytes which fold to `ss`.
+        assert_eq!(find_matches("Straße", "STRASSE", false), vec![0..7]);
+        assert_eq!(find_matches("Die Straße", "ss", false), vec![8..10]);
+        assert_eq!(find_matches("MASSE", "maße", false), vec![0..5]);
+        // A match may not end within the folding of `ß`.
+        assert_eq!(find_matches("Straße", "stras", false), vec![]);
+        // The dotless i only matches itself, while the dotted capital I matches `i̇`.
+        assert_eq!(find_matches("ılık", "i", false), vec![]);
+        assert_eq!(find_matches("İstanbul", "i\u{307}stanbul", false), vec![0..9]);
+    }
+}
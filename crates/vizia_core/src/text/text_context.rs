@@ -1,15 +1,49 @@
-use skia_safe::textlayout::{Paragraph, TypefaceFontProvider};
+use skia_safe::textlayout::{Paragraph, ParagraphStyle, TextStyle, TypefaceFontProvider};
 use skia_safe::{textlayout::FontCollection, FontMgr};
 use vizia_storage::SparseSet;
+use vizia_style::{TextOverflow, WhiteSpace};
 
 use crate::{entity::Entity, layout::BoundingBox};
 
+use super::{TextChunks, TextLayout};
+
+// The number of measurements kept for each paragraph, which covers the passes of a typical layout.
+const MAX_MEASUREMENTS: usize = 4;
+
 pub struct TextContext {
     pub font_collection: FontCollection,
     pub default_font_manager: FontMgr,
     pub asset_provider: TypefaceFontProvider,
     pub text_bounds: SparseSet<BoundingBox>,
     pub text_paragraphs: SparseSet<Paragraph>,
+    // The text of long labels, split into chunks of lines so that appending to the text only shapes the new lines.
+    pub(crate) text_chunks: SparseSet<TextChunks>,
+    // The number of chunks of text which have been built.
+    pub(crate) chunks_built: usize,
+    // The sizes of each paragraph measured during layout, which are reused until the paragraph is rebuilt.
+    pub(crate) text_measurements: SparseSet<Vec<(MeasureKey, TextMeasurement)>>,
+    // The number of times a paragraph has been measured rather than read from the cache.
+    pub(crate) measure_count: usize,
+}
+
+/// The constraints and style which the size of a paragraph is measured with during layout.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct MeasureKey {
+    /// The available width, rounded to whole physical pixels.
+    pub width: Option<f32>,
+    /// The available height, rounded to whole physical pixels.
+    pub height: Option<f32>,
+    /// The horizontal and vertical space taken by the padding and border of the view.
+    pub child_space: (f32, f32),
+    pub white_space: WhiteSpace,
+    pub text_overflow: Option<TextOverflow>,
+}
+
+/// The size of a paragraph measured during layout.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct TextMeasurement {
+    pub width: f32,
+    pub height: f32,
 }
 
 impl TextContext {
@@ -21,4 +55,98 @@ impl TextContext {
     pub(crate) fn set_text_bounds(&mut self, entity: Entity, bounds: BoundingBox) {
         self.text_bounds.insert(entity, bounds);
     }
+
+    /// Sets the paragraph of the entity, discarding the sizes measured for its previous paragraph.
+    pub(crate) fn set_paragraph(&mut self, entity: Entity, paragraph: Paragraph) {
+        self.text_paragraphs.insert(entity, paragraph);
+        self.text_chunks.remove(entity);
+        self.text_measurements.remove(entity);
+    }
+
+    /// Sets the text of the entity to be laid out in chunks of lines, building only the chunks which have changed
+    /// since the text was last set, and discarding the sizes measured for the previous text.
+    pub(crate) fn set_chunks(
+        &mut self,
+        entity: Entity,
+        text: &str,
+        paragraph_style: ParagraphStyle,
+        text_style: TextStyle,
+    ) {
+        self.text_paragraphs.remove(entity);
+        if !self.text_chunks.contains(entity) {
+            self.text_chunks
+                .insert(entity, TextChunks::new(paragraph_style.clone(), text_style.clone()));
+        }
+
+        if let Some(chunks) = self.text_chunks.get_mut(entity) {
+            self.chunks_built +=
+                chunks.update(text, paragraph_style, text_style, &self.font_collection);
+        }
+        self.text_measurements.remove(entity);
+    }
+
+    /// Returns the laid out text of the entity, if it has any.
+    pub(crate) fn text_layout(&self, entity: Entity) -> Option<&dyn TextLayout> {
+        match self.text_paragraphs.get(entity) {
+            Some(paragraph) => Some(paragraph),
+            None => self.text_chunks.get(entity).map(|chunks| chunks as &dyn TextLayout),
+        }
+    }
+
+    /// Returns the laid out text of the entity mutably, if it has any.
+    pub(crate) fn text_layout_mut(&mut self, entity: Entity) -> Option<&mut dyn TextLayout> {
+        match self.text_paragraphs.get_mut(entity) {
+            Some(paragraph) => Some(paragraph),
+            None => self.text_chunks.get_mut(entity).map(|chunks| chunks as &mut dyn TextLayout),
+        }
+    }
+
+    /// Discards the sizes measured for all paragraphs, such as when the fonts or the scale factor change.
+    ///
+    /// The chunks of long texts are also built again the next time their text is set, as they may have been
+    /// shaped with other fonts.
+    pub(crate) fn clear_measurements(&mut self) {
+        self.text_measurements.clear();
+        for entry in self.text_chunks.dense.iter_mut() {
+            entry.value.invalidate();
+        }
+    }
+
+    /// Measures the paragraph of the entity with the given closure, or returns the size measured with the same key
+    /// since the paragraph was built. Returns `None` if the entity has no paragraph.
+    pub(crate) fn measure(
+        &mut self,
+        entity: Entity,
+        key: MeasureKey,
+        measure: impl FnOnce(&mut dyn TextLayout) -> TextMeasurement,
+    ) -> Option<TextMeasurement> {
+        let paragraph: &mut dyn TextLayout = match self.text_paragraphs.get_mut(entity) {
+            Some(paragraph) => paragraph,
+            None => self.text_chunks.get_mut(entity)?,
+        };
+
+        if !self.text_measurements.contains(entity) {
+            self.text_measurements.insert(entity, Vec::new());
+        }
+        let measurements = self.text_measurements.get_mut(entity)?;
+
+        if let Some((_, measurement)) = measurements.iter().find(|(k, _)| *k == key) {
+            // The paragraph is left laid out by the last measurement, which may have had other constraints.
+            if paragraph.max_width() != measurement.width {
+                paragraph.layout(measurement.width);
+            }
+
+            return Some(*measurement);
+        }
+
+        self.measure_count += 1;
+        let measurement = (measure)(paragraph);
+
+        if measurements.len() == MAX_MEASUREMENTS {
+            measurements.remove(0);
+        }
+        measurements.push((key, measurement));
+
+        Some(measurement)
+    }
 }
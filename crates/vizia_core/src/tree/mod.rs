@@ -1,5 +1,7 @@
 mod focus_iter;
 pub(crate) use focus_iter::*;
+mod query;
+pub(crate) use query::*;
 
 // Re-export tree
 pub use vizia_storage::{ChildIterator, ParentIterator, Tree, TreeExt};
@@ -0,0 +1,86 @@
+use crate::entity::Entity;
+use crate::events::ViewHandler;
+use crate::prelude::{Style, View};
+use hashbrown::HashMap;
+use vizia_storage::{Tree, TreeIterator};
+
+/// Returns the first descendant of the entity with the given id.
+pub(crate) fn descendant_with_id(
+    tree: &Tree<Entity>,
+    style: &Style,
+    entity: Entity,
+    id: &str,
+) -> Option<Entity> {
+    TreeIterator::subtree(tree, entity)
+        .skip(1)
+        .find(|descendant| style.ids.get(*descendant).is_some_and(|i| i == id))
+}
+
+/// Returns the first descendant of the entity which is of the view type `T`.
+pub(crate) fn first_descendant<T: View>(
+    tree: &Tree<Entity>,
+    views: &HashMap<Entity, Box<dyn ViewHandler>>,
+    entity: Entity,
+) -> Option<Entity> {
+    TreeIterator::subtree(tree, entity).skip(1).find(|descendant| {
+        views.get(descendant).is_some_and(|view_handler| view_handler.downcast_ref::<T>().is_some())
+    })
+}
+
+/// Returns the first descendant of the entity with the given element name.
+pub(crate) fn descendant_with_element(
+    tree: &Tree<Entity>,
+    views: &HashMap<Entity, Box<dyn ViewHandler>>,
+    entity: Entity,
+    element: &str,
+) -> Option<Entity> {
+    TreeIterator::subtree(tree, entity).skip(1).find(|descendant| {
+        views
+            .get(descendant)
+            .and_then(|view_handler| view_handler.element())
+            .is_some_and(|name| name == element)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn queries_find_first_matching_descendant() {
+        let mut cx = Context::default();
+
+        let mut label = Entity::null();
+        let mut button = Entity::null();
+        let outer = VStack::new(&mut cx, |cx| {
+            HStack::new(cx, |cx| {
+                label = Label::new(cx, "Name").id("name").entity();
+                button = Button::new(cx, |cx| Label::new(cx, "Clear")).id("clear").entity();
+            });
+            Button::new(cx, |cx| Label::new(cx, "Other"));
+        });
+
+        assert_eq!(outer.descendant_with_id("clear"), Some(button));
+        assert_eq!(outer.descendant_with_id("missing"), None);
+        assert_eq!(outer.first_descendant::<Button>(), Some(button));
+        assert_eq!(outer.first_descendant::<Label>(), Some(label));
+        assert_eq!(outer.descendant_with_element("button"), Some(button));
+        // The view itself is not one of its descendants.
+        assert_eq!(outer.first_descendant::<VStack>(), None);
+        let outer = outer.entity();
+
+        cx.with_current(outer, |cx| {
+            assert_eq!(cx.descendant_with_id("name"), Some(label));
+            assert_eq!(cx.first_descendant::<Button>(), Some(button));
+            assert_eq!(cx.descendant_with_element("label"), Some(label));
+        });
+
+        let mut cx = EventContext::new_with_current(&mut cx, outer);
+        assert_eq!(cx.descendant_with_id("name"), Some(label));
+        assert_eq!(cx.first_descendant::<Button>(), Some(button));
+        assert_eq!(cx.descendant_with_element("button"), Some(button));
+
+        cx.focus_descendant("clear");
+        assert_eq!(cx.focused(), button);
+    }
+}
@@ -12,16 +12,13 @@
 //! .run();
 //! ```
 
-use crate::accessibility::IntoNode;
 use crate::model::ModelDataStore;
 use crate::prelude::*;
-use crate::systems::get_access_node;
 use std::any::Any;
 mod handle;
 pub use handle::Handle;
 
 use crate::events::ViewHandler;
-use accesskit::{NodeBuilder, TreeUpdate};
 
 /// A view is any object which can be displayed on the screen.
 ///
@@ -122,27 +119,9 @@ pub trait View: 'static + Sized {
         cx.needs_redraw(id);
         cx.views.insert(id, Box::new(self));
         let parent_id = cx.tree.get_layout_parent(id).unwrap();
-        let parent_node_id = parent_id.accesskit_id();
-        let node_id = id.accesskit_id();
-
-        let mut access_context = AccessContext {
-            current: id,
-            tree: &cx.tree,
-            cache: &cx.cache,
-            style: &cx.style,
-            text_context: &mut cx.text_context,
-        };
-
-        if let Some(parent_node) = get_access_node(&mut access_context, &mut cx.views, parent_id) {
-            let parent_node = parent_node.node_builder.build();
-            let node = NodeBuilder::default().build();
-
-            cx.tree_updates.push(Some(TreeUpdate {
-                nodes: vec![(parent_node_id, parent_node), (node_id, node)],
-                tree: None,
-                focus: cx.focused.accesskit_id(),
-            }));
-        }
+        cx.style.needs_children_restyle(parent_id);
+        // The children of the accessibility node of the parent change, and the node of the view is added with them.
+        cx.style.needs_access_update(parent_id);
 
         cx.data.insert(id, ModelDataStore::default());
 
@@ -270,6 +249,14 @@ pub trait View: 'static + Sized {
 
     #[allow(unused_variables)]
     fn accessibility(&self, cx: &mut AccessContext, node: &mut AccessNode) {}
+
+    /// Returns whether a point within the bounds of the view hits the view, allowing views with a non-rectangular
+    /// shape to only be hovered over that shape. The point and bounds are in physical pixels, before the transform of
+    /// the view is applied. By default the whole bounds of the view are hit.
+    #[allow(unused_variables)]
+    fn hit_test(&self, bounds: BoundingBox, x: f32, y: f32) -> bool {
+        true
+    }
 }
 
 impl<T: View> ViewHandler for T
@@ -292,6 +279,10 @@ where
         <T as View>::accessibility(self, cx, node);
     }
 
+    fn hit_test(&self, bounds: BoundingBox, x: f32, y: f32) -> bool {
+        <T as View>::hit_test(self, bounds, x, y)
+    }
+
     fn as_any_ref(&self) -> &dyn Any {
         self
     }
@@ -20,7 +20,7 @@ impl<V> DataContext for Handle<'_, V> {
             return Some(t);
         }
 
-        for entity in self.entity.parent_iter(&self.cx.tree) {
+        for entity in self.entity.data_parent_iter(&self.cx.tree) {
             // Return any model data.
             if let Some(model_data_store) = self.cx.data.get(&entity) {
                 if let Some(model) = model_data_store.models.get(&TypeId::of::<T>()) {
@@ -89,6 +89,48 @@ impl<V> Handle<'_, V> {
         self
     }
 
+    /// Returns the entity of the first descendant of the view with the given id.
+    ///
+    /// The returned entity is only valid at build time. Views built within a [`Binding`]
+    /// are rebuilt when the bound data changes, after which the entity may no longer exist.
+    pub fn descendant_with_id(&self, id: &str) -> Option<Entity> {
+        crate::tree::descendant_with_id(&self.cx.tree, &self.cx.style, self.entity, id)
+    }
+
+    /// Returns the entity of the first descendant of the view which is of the view type `T`.
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// let toolbar = HStack::new(cx, |cx| {
+    ///     Label::new(cx, "Name");
+    ///     Button::new(cx, |cx| Label::new(cx, "Clear"));
+    /// });
+    /// let button = toolbar.first_descendant::<Button>();
+    /// # assert!(button.is_some());
+    /// ```
+    ///
+    /// Like [`descendant_with_id`](Self::descendant_with_id), the returned entity is only valid at build time.
+    pub fn first_descendant<T: View>(&self) -> Option<Entity> {
+        crate::tree::first_descendant::<T>(&self.cx.tree, &self.cx.views, self.entity)
+    }
+
+    /// Returns the entity of the first descendant of the view with the given element name.
+    ///
+    /// Like [`descendant_with_id`](Self::descendant_with_id), the returned entity is only valid at build time.
+    pub fn descendant_with_element(&self, element: &str) -> Option<Entity> {
+        crate::tree::descendant_with_element(&self.cx.tree, &self.cx.views, self.entity, element)
+    }
+
+    /// Moves keyboard focus to the descendant of the view with the given id, if it exists.
+    pub fn focus_descendant(self, id: &str) -> Self {
+        if let Some(descendant) = self.descendant_with_id(id) {
+            self.cx.with_current(descendant, |cx| cx.focus());
+        }
+
+        self
+    }
+
     /// Mody the internal data of the view.
     pub fn modify<F>(mut self, f: F) -> Self
     where
@@ -0,0 +1,754 @@
+use crate::prelude::*;
+
+/// A rectangular range of cells, described by an anchor cell and a focus cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Data)]
+pub struct CellRange {
+    /// The cell where the selection started.
+    pub anchor: (usize, usize),
+    /// The cell where the selection currently ends, i.e. the focused cell.
+    pub focus: (usize, usize),
+}
+
+impl CellRange {
+    /// Creates a range containing a single cell.
+    pub fn single(row: usize, col: usize) -> Self {
+        Self { anchor: (row, col), focus: (row, col) }
+    }
+
+    /// Returns the inclusive range of rows covered by the selection.
+    pub fn rows(&self) -> std::ops::RangeInclusive<usize> {
+        self.anchor.0.min(self.focus.0)..=self.anchor.0.max(self.focus.0)
+    }
+
+    /// Returns the inclusive range of columns covered by the selection.
+    pub fn cols(&self) -> std::ops::RangeInclusive<usize> {
+        self.anchor.1.min(self.focus.1)..=self.anchor.1.max(self.focus.1)
+    }
+
+    /// Returns true if the cell at the given row and column is within the range.
+    pub fn contains(&self, row: usize, col: usize) -> bool {
+        self.rows().contains(&row) && self.cols().contains(&col)
+    }
+}
+
+/// A direction in which the focused cell of a [`CellGrid`] can be moved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellMove {
+    Up,
+    Down,
+    Left,
+    Right,
+    /// Move to the next cell in reading order, wrapping onto the next row.
+    Next,
+    /// Move to the previous cell in reading order, wrapping onto the previous row.
+    Prev,
+}
+
+/// Events used to control a [`CellGrid`].
+pub enum CellGridEvent {
+    /// Focus the cell at the given row and column, committing the edit of the cell being edited.
+    FocusCell(usize, usize),
+    /// Move the focused cell, collapsing the selection.
+    Move(CellMove),
+    /// Move the focused cell, extending the selection from the anchor cell.
+    Extend(CellMove),
+    /// Start editing the focused cell. If a string is provided it replaces the contents of the cell.
+    StartEdit(Option<String>),
+    /// Finish editing the focused cell. The flag indicates whether focus should advance to the next cell.
+    EndEdit(bool),
+    /// Cancel editing the focused cell, discarding any changes.
+    CancelEdit,
+    /// Copy the text of the selected cells to the clipboard.
+    Copy,
+}
+
+/// A controller providing spreadsheet-like keyboard navigation over a grid of [`Editable`] cells.
+///
+/// The grid tracks a focused cell, which can be moved with the arrow keys, tab, and enter. Typing while a cell is
+/// focused starts editing the cell in-place, replacing its contents, while F2 or a double-click starts editing
+/// without clearing. Holding shift while moving extends a rectangular selection, and the text of the selected cells
+/// can be copied to the clipboard as tab-separated values.
+///
+/// # Example
+/// ```ignore
+/// CellGrid::new(cx, 3, 2, |cx| {
+///     for row in 0..3 {
+///         HStack::new(cx, |cx| {
+///             for col in 0..2 {
+///                 Editable::new(cx, row, col, AppData::cells.idx(row).idx(col))
+///                     .on_commit(|cx, row, col, value| cx.emit(AppEvent::SetCell(row, col, value)));
+///             }
+///         });
+///     }
+/// });
+/// ```
+#[derive(Lens)]
+pub struct CellGrid {
+    rows: usize,
+    cols: usize,
+    focused: Option<(usize, usize)>,
+    focus_visible: bool,
+    selection: Option<CellRange>,
+    editing: Option<(usize, usize)>,
+    edit_seed: Option<String>,
+    #[lens(ignore)]
+    pending_move: Option<CellMove>,
+    #[lens(ignore)]
+    pending_focus: Option<(usize, usize)>,
+}
+
+impl CellGrid {
+    /// Creates a new [`CellGrid`] with the given number of rows and columns.
+    ///
+    /// The `content` closure is used to build the cells of the grid, which are typically [`Editable`] views.
+    pub fn new<F>(
+        cx: &mut Context,
+        rows: impl Res<usize>,
+        cols: impl Res<usize>,
+        content: F,
+    ) -> Handle<Self>
+    where
+        F: FnOnce(&mut Context),
+    {
+        Self {
+            rows: 0,
+            cols: 0,
+            focused: None,
+            focus_visible: false,
+            selection: None,
+            editing: None,
+            edit_seed: None,
+            pending_move: None,
+            pending_focus: None,
+        }
+        .build(cx, |cx| {
+            // Intercept tab before it reaches the default focus navigation so that it moves between cells.
+            cx.add_listener(|grid: &mut Self, cx, event| {
+                event.map(|window_event, meta| match window_event {
+                    WindowEvent::KeyDown(Code::Tab, _) => {
+                        if !cx.focused().is_descendant_of(cx.tree, cx.current()) {
+                            return;
+                        }
+
+                        let movement =
+                            if cx.modifiers.shift() { CellMove::Prev } else { CellMove::Next };
+
+                        if grid.editing.is_some() {
+                            grid.pending_move = Some(movement);
+                            cx.emit_to(cx.focused(), TextEvent::Submit(true));
+                        } else {
+                            cx.emit(CellGridEvent::Move(movement));
+                        }
+
+                        meta.consume();
+                    }
+
+                    _ => {}
+                });
+            });
+
+            (content)(cx);
+        })
+        .bind(rows, |handle, rows| {
+            let rows = rows.get(&handle);
+            handle.modify(|grid| grid.rows = rows);
+        })
+        .bind(cols, |handle, cols| {
+            let cols = cols.get(&handle);
+            handle.modify(|grid| grid.cols = cols);
+        })
+        .role(Role::Grid)
+    }
+
+    fn step(&self, (row, col): (usize, usize), movement: CellMove) -> (usize, usize) {
+        let last_row = self.rows.saturating_sub(1);
+        let last_col = self.cols.saturating_sub(1);
+
+        match movement {
+            CellMove::Up => (row.saturating_sub(1), col),
+            CellMove::Down => ((row + 1).min(last_row), col),
+            CellMove::Left => (row, col.saturating_sub(1)),
+            CellMove::Right => (row, (col + 1).min(last_col)),
+            CellMove::Next => {
+                if col < last_col {
+                    (row, col + 1)
+                } else if row < last_row {
+                    (row + 1, 0)
+                } else {
+                    (row, col)
+                }
+            }
+            CellMove::Prev => {
+                if col > 0 {
+                    (row, col - 1)
+                } else if row > 0 {
+                    (row - 1, last_col)
+                } else {
+                    (row, col)
+                }
+            }
+        }
+    }
+
+    fn focus_cell(&mut self, cell: (usize, usize), extend: bool) {
+        self.focused = Some(cell);
+        self.selection = match self.selection {
+            Some(selection) if extend => Some(CellRange { anchor: selection.anchor, focus: cell }),
+            _ => Some(CellRange::single(cell.0, cell.1)),
+        };
+    }
+
+    /// Collects the text of the selected cells as rows of tab-separated values.
+    #[cfg(feature = "clipboard")]
+    fn selected_text(&self, cx: &EventContext) -> Option<String> {
+        let selection = self.selection?;
+
+        let mut cells = std::collections::BTreeMap::new();
+        for entity in vizia_storage::TreeIterator::subtree(cx.tree, cx.current()) {
+            if let Some(cell) = cx.get_view_with::<Editable>(entity) {
+                if selection.contains(cell.row, cell.col) {
+                    cells.insert((cell.row, cell.col), cell.text.clone());
+                }
+            }
+        }
+
+        let text = selection
+            .rows()
+            .map(|row| {
+                selection
+                    .cols()
+                    .map(|col| cells.get(&(row, col)).map(String::as_str).unwrap_or_default())
+                    .collect::<Vec<_>>()
+                    .join("\t")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Some(text)
+    }
+}
+
+impl View for CellGrid {
+    fn element(&self) -> Option<&'static str> {
+        Some("cell-grid")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, _| match window_event {
+            // Key presses bubble up from the textbox of the cell being edited, which handles them itself.
+            WindowEvent::KeyDown(code, _) if self.editing.is_none() => {
+                let extend = cx.modifiers.shift();
+                let movement = match code {
+                    Code::ArrowUp => Some(CellMove::Up),
+                    Code::ArrowDown => Some(CellMove::Down),
+                    Code::ArrowLeft => Some(CellMove::Left),
+                    Code::ArrowRight => Some(CellMove::Right),
+                    Code::Enter | Code::NumpadEnter => {
+                        if extend {
+                            cx.emit(CellGridEvent::Move(CellMove::Up));
+                        } else {
+                            cx.emit(CellGridEvent::Move(CellMove::Down));
+                        }
+                        None
+                    }
+                    _ => None,
+                };
+
+                if let Some(movement) = movement {
+                    if extend {
+                        cx.emit(CellGridEvent::Extend(movement));
+                    } else {
+                        cx.emit(CellGridEvent::Move(movement));
+                    }
+                }
+
+                match code {
+                    Code::F2 => cx.emit(CellGridEvent::StartEdit(None)),
+
+                    Code::Backspace | Code::Delete => {
+                        cx.emit(CellGridEvent::StartEdit(Some(String::new())))
+                    }
+
+                    Code::Escape => {
+                        if let Some(focused) = self.focused {
+                            self.selection = Some(CellRange::single(focused.0, focused.1));
+                        }
+                    }
+
+                    Code::KeyC => {
+                        #[cfg(target_os = "macos")]
+                        let modifier = Modifiers::SUPER;
+                        #[cfg(not(target_os = "macos"))]
+                        let modifier = Modifiers::CTRL;
+
+                        if cx.modifiers == &modifier {
+                            cx.emit(CellGridEvent::Copy);
+                        }
+                    }
+
+                    _ => {}
+                }
+            }
+
+            WindowEvent::CharInput(c) => {
+                if self.editing.is_none()
+                    && self.focused.is_some()
+                    && !c.is_control()
+                    && !cx.modifiers.ctrl()
+                    && !cx.modifiers.logo()
+                {
+                    cx.emit(CellGridEvent::StartEdit(Some(String::from(*c))));
+                }
+            }
+
+            _ => {}
+        });
+
+        event.map(|grid_event, _| match grid_event {
+            CellGridEvent::FocusCell(row, col) => {
+                if self.editing.is_some() {
+                    // The edit is committed first, and the cell is focused once editing has ended.
+                    self.pending_focus = Some((*row, *col));
+                    if cx.focused().is_descendant_of(cx.tree, cx.current()) {
+                        cx.emit_to(cx.focused(), TextEvent::Submit(false));
+                    }
+                } else {
+                    self.focus_visible = false;
+                    self.focus_cell((*row, *col), cx.modifiers.shift());
+                }
+            }
+
+            CellGridEvent::Move(movement) | CellGridEvent::Extend(movement) => {
+                if self.rows == 0 || self.cols == 0 {
+                    return;
+                }
+
+                let extend = matches!(grid_event, CellGridEvent::Extend(_));
+                let next =
+                    self.focused.map(|focused| self.step(focused, *movement)).unwrap_or((0, 0));
+                self.focus_visible = true;
+                self.focus_cell(next, extend);
+            }
+
+            CellGridEvent::StartEdit(seed) => {
+                if self.editing.is_none() {
+                    if let Some(focused) = self.focused {
+                        self.edit_seed.clone_from(seed);
+                        self.editing = Some(focused);
+                        self.selection = Some(CellRange::single(focused.0, focused.1));
+                    }
+                }
+            }
+
+            CellGridEvent::EndEdit(advance) => {
+                self.editing = None;
+                self.edit_seed = None;
+                let movement = self.pending_move.take().unwrap_or(CellMove::Down);
+                if let Some(cell) = self.pending_focus.take() {
+                    self.focus_visible = false;
+                    self.focus_cell(cell, false);
+                } else if *advance {
+                    if let Some(focused) = self.focused {
+                        let next = self.step(focused, movement);
+                        self.focus_cell(next, false);
+                    }
+                }
+            }
+
+            CellGridEvent::CancelEdit => {
+                self.editing = None;
+                self.edit_seed = None;
+                self.pending_move = None;
+                self.pending_focus = None;
+            }
+
+            CellGridEvent::Copy =>
+            {
+                #[cfg(feature = "clipboard")]
+                if let Some(text) = self.selected_text(cx) {
+                    if let Err(err) = cx.set_clipboard(text) {
+                        log::warn!("Failed to copy the selected cells to the clipboard: {err}");
+                    }
+                }
+            }
+        });
+    }
+}
+
+impl Handle<'_, CellGrid> {
+    /// Sets the focused cell of the grid.
+    pub fn focused_cell(self, cell: impl Res<Option<(usize, usize)>>) -> Self {
+        self.bind(cell, |handle, cell| {
+            let cell = cell.get(&handle);
+            handle.modify(|grid| {
+                grid.focused = cell;
+                grid.selection = cell.map(|(row, col)| CellRange::single(row, col));
+            });
+        })
+    }
+}
+
+enum EditableEvent {
+    Submit(bool),
+    Cancel,
+}
+
+/// A cell of a [`CellGrid`] which displays a value and can be edited in-place with a [`Textbox`].
+///
+/// While the cell is not being edited the value of the lens is displayed with a [`Label`]. When editing starts the label
+/// is swapped for a textbox bound to the same lens. Submitting the textbox triggers the `on_commit` callback with the
+/// edited text, while cancelling the edit restores the previous value. The lens is never written to directly, so the
+/// `on_commit` callback should be used to update the model data.
+pub struct Editable {
+    row: usize,
+    col: usize,
+    text: String,
+    on_commit: Option<Box<dyn Fn(&mut EventContext, usize, usize, String)>>,
+}
+
+impl Editable {
+    /// Creates a new editable cell at the given row and column of the parent [`CellGrid`].
+    pub fn new<L>(cx: &mut Context, row: usize, col: usize, lens: L) -> Handle<Self>
+    where
+        L: Lens<Target: Data + Clone + ToStringLocalized + std::str::FromStr>,
+    {
+        Self { row, col, text: String::new(), on_commit: None }
+            .build(cx, move |cx| {
+                let is_editing = CellGrid::editing.map(move |editing| *editing == Some((row, col)));
+                Binding::new(cx, is_editing, move |cx, is_editing| {
+                    if is_editing.get(cx) {
+                        let seed = CellGrid::edit_seed.get(cx);
+                        let textbox = Textbox::new(cx, lens)
+                            .width(Stretch(1.0))
+                            .on_submit(|cx, _, reason| cx.emit(EditableEvent::Submit(reason)))
+                            .on_cancel(|cx| cx.emit(EditableEvent::Cancel))
+                            .entity();
+
+                        cx.emit_to(textbox, TextEvent::StartEdit);
+                        if let Some(seed) = seed {
+                            cx.emit_to(textbox, TextEvent::InsertText(seed));
+                        }
+                    } else {
+                        Label::new(cx, lens).width(Stretch(1.0)).hoverable(false);
+                    }
+                });
+            })
+            .bind(lens, |handle, lens| {
+                let text = lens.get(&handle).to_string_local(handle.cx);
+                handle.modify(|cell| cell.text = text);
+            })
+            .role(Role::Cell)
+            .toggle_class(
+                "editing",
+                CellGrid::editing.map(move |editing| *editing == Some((row, col))),
+            )
+            .checked(CellGrid::selection.map(move |selection| {
+                selection.map(|selection| selection.contains(row, col)).unwrap_or_default()
+            }))
+            .focused_with_visibility(
+                CellGrid::focused.map(move |focused| *focused == Some((row, col))),
+                CellGrid::focus_visible,
+            )
+            .on_press(move |cx| cx.emit(CellGridEvent::FocusCell(row, col)))
+            .on_double_click(|cx, _| cx.emit(CellGridEvent::StartEdit(None)))
+    }
+}
+
+impl Handle<'_, Editable> {
+    /// Sets the callback triggered when an edit of the cell is committed.
+    ///
+    /// Callback provides the row and column of the cell and the edited text.
+    pub fn on_commit<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, usize, usize, String),
+    {
+        self.modify(|cell| cell.on_commit = Some(Box::new(callback)))
+    }
+}
+
+impl View for Editable {
+    fn element(&self) -> Option<&'static str> {
+        Some("editable-cell")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.take(|editable_event, meta| match editable_event {
+            EditableEvent::Submit(advance) => {
+                let text = cx.style.text.get(meta.origin).cloned().unwrap_or_default();
+
+                // Move focus back to the cell before the textbox is removed, unless focus has already moved elsewhere.
+                if advance || cx.focused() == meta.origin {
+                    cx.focus();
+                }
+
+                if let Some(callback) = &self.on_commit {
+                    (callback)(cx, self.row, self.col, text);
+                }
+
+                cx.emit(CellGridEvent::EndEdit(advance));
+            }
+
+            EditableEvent::Cancel => {
+                cx.focus();
+                cx.emit(CellGridEvent::CancelEdit);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+    use crate::systems::binding_system;
+
+    #[derive(Lens)]
+    struct Sheet {
+        cells: Vec<Vec<String>>,
+        commits: Vec<(usize, usize, String)>,
+    }
+
+    enum SheetEvent {
+        SetCell(usize, usize, String),
+    }
+
+    impl Model for Sheet {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|sheet_event, _| match sheet_event {
+                SheetEvent::SetCell(row, col, value) => {
+                    self.cells[*row][*col].clone_from(value);
+                    self.commits.push((*row, *col, value.clone()));
+                }
+            });
+        }
+    }
+
+    // Builds a grid with two rows of three cells, containing the letters a to f in reading order.
+    fn build_grid(cx: &mut Context) -> Entity {
+        Sheet {
+            cells: vec![
+                vec!["a".into(), "b".into(), "c".into()],
+                vec!["d".into(), "e".into(), "f".into()],
+            ],
+            commits: Vec::new(),
+        }
+        .build(cx);
+
+        CellGrid::new(cx, 2, 3, |cx| {
+            for row in 0..2 {
+                HStack::new(cx, |cx| {
+                    for col in 0..3 {
+                        Editable::new(
+                            cx,
+                            row,
+                            col,
+                            Sheet::cells.map(move |cells| cells[row][col].clone()),
+                        )
+                        .on_commit(|cx, row, col, value| {
+                            cx.emit(SheetEvent::SetCell(row, col, value))
+                        });
+                    }
+                });
+            }
+        })
+        .entity()
+    }
+
+    // Sends an event to the target and swaps the label and textbox of a cell when editing starts or ends.
+    fn emit(cx: &mut Context, target: Entity, event: impl Send + 'static) {
+        let mut event_manager = EventManager::new();
+        cx.emit_custom(Event::new(event).target(target).origin(target));
+        event_manager.flush_events(cx, |_| {});
+        binding_system(cx);
+        event_manager.flush_events(cx, |_| {});
+    }
+
+    fn sheet(cx: &Context) -> &Sheet {
+        cx.data::<Sheet>().unwrap()
+    }
+
+    // Returns the text shown by the cell at the given row and column, i.e. the text of its label or textbox.
+    fn cell_text(cx: &Context, grid: Entity, row: usize, col: usize) -> String {
+        let cell = vizia_storage::TreeIterator::subtree(&cx.tree, grid)
+            .find(|entity| {
+                cx.views
+                    .get(entity)
+                    .and_then(|view| view.downcast_ref::<Editable>())
+                    .is_some_and(|cell| (cell.row, cell.col) == (row, col))
+            })
+            .unwrap();
+        // The label or textbox of the cell is built within a binding.
+        let binding = cx.tree.get_first_child(cell).unwrap();
+        let content = cx.tree.get_first_child(binding).unwrap();
+        cx.style.text.get(content).cloned().unwrap_or_default()
+    }
+
+    fn grid(cx: &Context, grid: Entity) -> &CellGrid {
+        cx.views.get(&grid).and_then(|view| view.downcast_ref::<CellGrid>()).unwrap()
+    }
+
+    #[test]
+    fn moving_collapses_and_extending_grows_the_selection() {
+        let mut cx = Context::default();
+        let entity = build_grid(&mut cx);
+
+        // The first move focuses the first cell.
+        emit(&mut cx, entity, CellGridEvent::Move(CellMove::Right));
+        assert_eq!(grid(&cx, entity).focused, Some((0, 0)));
+        emit(&mut cx, entity, CellGridEvent::Move(CellMove::Right));
+        assert_eq!(grid(&cx, entity).selection, Some(CellRange::single(0, 1)));
+
+        emit(&mut cx, entity, CellGridEvent::Extend(CellMove::Down));
+        emit(&mut cx, entity, CellGridEvent::Extend(CellMove::Right));
+        emit(&mut cx, entity, CellGridEvent::Extend(CellMove::Right));
+        let selection = grid(&cx, entity).selection.unwrap();
+        assert_eq!(selection, CellRange { anchor: (0, 1), focus: (1, 2) });
+        assert!(selection.contains(0, 2));
+        assert!(!selection.contains(1, 0));
+
+        emit(&mut cx, entity, CellGridEvent::Move(CellMove::Left));
+        assert_eq!(grid(&cx, entity).selection, Some(CellRange::single(1, 1)));
+    }
+
+    #[test]
+    fn next_and_prev_wrap_between_rows() {
+        let mut cx = Context::default();
+        let entity = build_grid(&mut cx);
+
+        emit(&mut cx, entity, CellGridEvent::FocusCell(0, 2));
+        emit(&mut cx, entity, CellGridEvent::Move(CellMove::Next));
+        assert_eq!(grid(&cx, entity).focused, Some((1, 0)));
+        emit(&mut cx, entity, CellGridEvent::Move(CellMove::Prev));
+        assert_eq!(grid(&cx, entity).focused, Some((0, 2)));
+
+        // The focused cell stays within the grid.
+        emit(&mut cx, entity, CellGridEvent::FocusCell(1, 2));
+        emit(&mut cx, entity, CellGridEvent::Move(CellMove::Next));
+        emit(&mut cx, entity, CellGridEvent::Move(CellMove::Down));
+        assert_eq!(grid(&cx, entity).focused, Some((1, 2)));
+    }
+
+    #[test]
+    fn shift_and_arrow_keys_extend_the_selection() {
+        let mut cx = Context::default();
+        let entity = build_grid(&mut cx);
+
+        emit(&mut cx, entity, CellGridEvent::FocusCell(0, 0));
+        cx.modifiers = Modifiers::SHIFT;
+        emit(&mut cx, entity, WindowEvent::KeyDown(Code::ArrowRight, None));
+        emit(&mut cx, entity, WindowEvent::KeyDown(Code::ArrowDown, None));
+        assert_eq!(grid(&cx, entity).selection, Some(CellRange { anchor: (0, 0), focus: (1, 1) }));
+
+        // Escape collapses the selection to the focused cell.
+        cx.modifiers = Modifiers::empty();
+        emit(&mut cx, entity, WindowEvent::KeyDown(Code::Escape, None));
+        assert_eq!(grid(&cx, entity).selection, Some(CellRange::single(1, 1)));
+    }
+
+    #[test]
+    fn typing_starts_editing_with_the_typed_character() {
+        let mut cx = Context::default();
+        let entity = build_grid(&mut cx);
+
+        emit(&mut cx, entity, CellGridEvent::FocusCell(0, 1));
+        emit(&mut cx, entity, WindowEvent::CharInput('x'));
+        assert_eq!(grid(&cx, entity).editing, Some((0, 1)));
+        assert_eq!(cell_text(&cx, entity, 0, 1), "x");
+        assert_eq!(sheet(&cx).cells[0][1], "b");
+    }
+
+    #[test]
+    fn f2_starts_editing_without_clearing_the_cell() {
+        let mut cx = Context::default();
+        let entity = build_grid(&mut cx);
+
+        emit(&mut cx, entity, CellGridEvent::FocusCell(1, 2));
+        emit(&mut cx, entity, WindowEvent::KeyDown(Code::F2, None));
+        assert_eq!(grid(&cx, entity).editing, Some((1, 2)));
+        assert_eq!(cell_text(&cx, entity, 1, 2), "f");
+    }
+
+    #[test]
+    fn enter_commits_the_edit_and_moves_down() {
+        let mut cx = Context::default();
+        let entity = build_grid(&mut cx);
+
+        emit(&mut cx, entity, CellGridEvent::FocusCell(0, 1));
+        emit(&mut cx, entity, WindowEvent::CharInput('x'));
+        let textbox = cx.focused;
+        emit(&mut cx, textbox, WindowEvent::CharInput('y'));
+        emit(&mut cx, textbox, WindowEvent::KeyDown(Code::Enter, None));
+
+        assert_eq!(grid(&cx, entity).editing, None);
+        assert_eq!(grid(&cx, entity).focused, Some((1, 1)));
+        assert_eq!(sheet(&cx).commits, vec![(0, 1, String::from("xy"))]);
+        assert_eq!(cell_text(&cx, entity, 0, 1), "xy");
+    }
+
+    #[test]
+    fn escape_restores_the_original_value() {
+        let mut cx = Context::default();
+        let entity = build_grid(&mut cx);
+
+        emit(&mut cx, entity, CellGridEvent::FocusCell(1, 0));
+        emit(&mut cx, entity, WindowEvent::CharInput('x'));
+        let textbox = cx.focused;
+        emit(&mut cx, textbox, WindowEvent::KeyDown(Code::Escape, None));
+
+        assert_eq!(grid(&cx, entity).editing, None);
+        assert_eq!(grid(&cx, entity).focused, Some((1, 0)));
+        assert!(sheet(&cx).commits.is_empty());
+        assert_eq!(cell_text(&cx, entity, 1, 0), "d");
+    }
+
+    #[test]
+    fn focusing_another_cell_commits_the_edit() {
+        let mut cx = Context::default();
+        let entity = build_grid(&mut cx);
+
+        emit(&mut cx, entity, CellGridEvent::FocusCell(0, 0));
+        emit(&mut cx, entity, WindowEvent::CharInput('x'));
+        emit(&mut cx, entity, CellGridEvent::FocusCell(1, 2));
+
+        assert_eq!(grid(&cx, entity).editing, None);
+        assert_eq!(grid(&cx, entity).focused, Some((1, 2)));
+        assert_eq!(grid(&cx, entity).selection, Some(CellRange::single(1, 2)));
+        assert_eq!(sheet(&cx).commits, vec![(0, 0, String::from("x"))]);
+        assert_eq!(cell_text(&cx, entity, 0, 0), "x");
+    }
+
+    #[cfg(feature = "clipboard")]
+    #[test]
+    fn copy_writes_selected_cells_as_tab_separated_values() {
+        use copypasta::ClipboardProvider;
+        use std::error::Error;
+        use std::sync::{Arc, Mutex};
+
+        struct RecordingClipboard(Arc<Mutex<String>>);
+
+        impl ClipboardProvider for RecordingClipboard {
+            fn get_contents(&mut self) -> Result<String, Box<dyn Error + Send + Sync + 'static>> {
+                Ok(self.0.lock().unwrap().clone())
+            }
+
+            fn set_contents(
+                &mut self,
+                contents: String,
+            ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+                *self.0.lock().unwrap() = contents;
+                Ok(())
+            }
+        }
+
+        let mut cx = Context::default();
+        let contents = Arc::new(Mutex::new(String::new()));
+        cx.clipboard = Box::new(RecordingClipboard(contents.clone()));
+        let entity = build_grid(&mut cx);
+
+        emit(&mut cx, entity, CellGridEvent::FocusCell(0, 1));
+        emit(&mut cx, entity, CellGridEvent::Extend(CellMove::Down));
+        emit(&mut cx, entity, CellGridEvent::Extend(CellMove::Right));
+        emit(&mut cx, entity, CellGridEvent::Copy);
+        assert_eq!(*contents.lock().unwrap(), "b\tc\ne\tf");
+    }
+}
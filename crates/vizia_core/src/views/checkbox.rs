@@ -55,6 +55,28 @@ use crate::prelude::*;
 ///     .on_toggle(|cx| cx.emit(AppEvent::ToggleValue));
 /// ```
 ///
+/// ## Two-way checkbox
+///
+/// A checkbox bound with a [`two_way()`](crate::binding::LensExt::two_way) lens toggles the data directly
+/// when pressed, without the need for an event.
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # #[derive(Lens)]
+/// # struct AppData {
+/// #     value: bool,
+/// # }
+/// #
+/// # impl Model for AppData {}
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// # AppData { value: false }.build(cx);
+/// #
+/// Checkbox::new(cx, AppData::value.two_way());
+/// ```
+///
 /// ## Checkbox with a label
 ///
 /// A checkbox is usually used with a label next to it describing what data the checkbox
@@ -114,6 +136,7 @@ use crate::prelude::*;
 /// ```
 pub struct Checkbox {
     on_toggle: Option<Box<dyn Fn(&mut EventContext)>>,
+    toggle_lens: Box<dyn Fn(&mut EventContext)>,
 }
 
 impl Checkbox {
@@ -138,7 +161,7 @@ impl Checkbox {
     /// Checkbox::new(cx, AppData::value);
     /// ```
     pub fn new(cx: &mut Context, checked: impl Lens<Target = bool>) -> Handle<Self> {
-        Self { on_toggle: None }
+        Self { on_toggle: None, toggle_lens: toggle_lens(checked) }
             .build(cx, |cx| {
                 Binding::new(cx, checked, |cx, checked| {
                     if checked.get(cx) {
@@ -187,7 +210,7 @@ impl Checkbox {
     where
         T: AsRef<[u8]> + 'static,
     {
-        Self { on_toggle: None }
+        Self { on_toggle: None, toggle_lens: toggle_lens(checked) }
             .build(cx, |cx| {
                 Binding::new(cx, checked, move |cx, checked| {
                     let icon_default = icon_default.clone();
@@ -212,7 +235,7 @@ impl Checkbox {
         checked: impl Lens<Target = bool>,
         intermediate: impl Lens<Target = bool>,
     ) -> Handle<Self> {
-        Self { on_toggle: None }
+        Self { on_toggle: None, toggle_lens: toggle_lens(checked) }
             .build(cx, |_| {})
             .bind(checked, move |handle, c| {
                 handle.bind(intermediate, move |handle, i| {
@@ -279,6 +302,7 @@ impl View for Checkbox {
 
             WindowEvent::Press { mouse: _ } => {
                 if meta.target == cx.current {
+                    (self.toggle_lens)(cx);
                     if let Some(callback) = &self.on_toggle {
                         (callback)(cx);
                     }
@@ -287,6 +311,7 @@ impl View for Checkbox {
 
             WindowEvent::ActionRequest(action) => match action.action {
                 Action::Default => {
+                    (self.toggle_lens)(cx);
                     if let Some(callback) = &self.on_toggle {
                         (callback)(cx);
                     }
@@ -299,3 +324,11 @@ impl View for Checkbox {
         });
     }
 }
+
+// Returns a callback which toggles the data targeted by the lens, if the lens is two-way.
+pub(crate) fn toggle_lens(checked: impl Lens<Target = bool>) -> Box<dyn Fn(&mut EventContext)> {
+    Box::new(move |cx| {
+        let value = checked.get(cx);
+        cx.write_lens(checked, !value);
+    })
+}
@@ -1,4 +1,15 @@
+use std::any::TypeId;
+use std::rc::Rc;
+
+use vizia_storage::{LayoutChildIterator, TreeIterator};
+
+use crate::context::InternalEvent;
+use crate::modifiers::{ActionsEvent, ActionsModel};
 use crate::prelude::*;
+use crate::style::Abilities;
+
+// How long after the last typed character the typeahead search starts over.
+const TYPEAHEAD_TIMEOUT: Duration = Duration::from_millis(500);
 
 /// A dropdown is used to display some state with the ability to open a popup with options to change that state.
 ///
@@ -46,7 +57,100 @@ use crate::prelude::*;
 /// ```
 ///
 /// The line marked "close the popup" is not required for anything other than closing the popup -
-/// if you leave it out, the popup will simply not close until the user clicks out of the dropdown.
+/// if you leave it out, the popup will simply not close until the user clicks out of the dropdown. The popup also
+/// closes when the keyboard focus moves out of the dropdown.
+///
+/// ## Keyboard Navigation
+///
+/// While the keyboard focus is within the dropdown, the arrow keys and the home and end keys highlight the options
+/// of the open popup, which are the views in the popup with an [`on_press`](crate::modifiers::ActionModifiers::on_press)
+/// action. The arrow down key opens a closed popup, and the highlight wraps around at the first and last options. The
+/// enter key performs the press action of the highlighted option, and the escape key closes the popup and returns the
+/// keyboard focus to the trigger. The highlighted option can be selected with the `:active-descendant` CSS
+/// pseudo-class selector.
+///
+/// Typing the first letters of an option highlights the first option whose text starts with them, and typing the
+/// same letter repeatedly cycles through the options starting with it. The typed letters are forgotten after half a
+/// second without typing. The text of an option is the text of the first view with text within it, such as a
+/// [`Label`], and can be set explicitly with the [`nav_text`](crate::modifiers::AbilityModifiers::nav_text) modifier.
+/// When the highlighted option is within a [`ScrollView`] it is scrolled into view.
+///
+/// ## Placement
+///
+/// The popup opens below the dropdown by default. The [`placement`](Handle::placement) modifier sets where it opens
+/// instead, and `Placement::AutoVertical` opens it above the dropdown when there isn't enough room below.
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// Dropdown::new(cx, |cx| Label::new(cx, "Options"), |cx| {
+///     Label::new(cx, "Option");
+/// })
+/// .placement(Placement::AutoVertical);
+/// ```
+///
+/// ## Long Dropdowns
+///
+/// The [`max_popup_height`](Handle::max_popup_height) modifier limits the height of the popup, and content which is
+/// taller than the limit is scrolled within the popup. The highlighted option is scrolled into view when it is
+/// changed with the keyboard.
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// Dropdown::new(cx, |cx| Label::new(cx, "Options"), |cx| {
+///     for i in 0..100 {
+///         Label::new(cx, i).on_press(|cx| cx.emit(PopupEvent::Close));
+///     }
+/// })
+/// .max_popup_height(Pixels(300.0));
+/// ```
+///
+/// ## Multiple Selection
+///
+/// The [`keep_open`](Handle::keep_open) modifier keeps the popup open while options are chosen, so that several
+/// options can be checked before the popup is dismissed, and the trigger can summarize the checked options.
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # #[derive(Lens)]
+/// # struct AppData {
+/// #     checked: Vec<bool>,
+/// # }
+/// #
+/// # impl Model for AppData {}
+/// #
+/// # enum AppEvent {
+/// #     Toggle(usize),
+/// # }
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// # AppData { checked: vec![false; 5] }.build(cx);
+/// #
+/// Dropdown::new(
+///     cx,
+///     |cx| {
+///         Label::new(
+///             cx,
+///             AppData::checked.map(|checked| {
+///                 format!("{} selected", checked.iter().filter(|checked| **checked).count())
+///             }),
+///         )
+///     },
+///     |cx| {
+///         for i in 0..5 {
+///             Checkbox::new(cx, AppData::checked.map(move |checked| checked[i]))
+///                 .on_toggle(move |cx| {
+///                     cx.emit(AppEvent::Toggle(i));
+///                     cx.emit(PopupEvent::Close); // ignored while the popup is kept open
+///                 });
+///         }
+///     },
+/// )
+/// .keep_open(true);
+/// ```
 ///
 /// ## Custom Dropdown
 ///
@@ -117,7 +221,21 @@ use crate::prelude::*;
 ///     });
 /// }).width(Pixels(100.0));
 /// ```
-pub struct Dropdown;
+#[derive(Lens)]
+pub struct Dropdown {
+    placement: Placement,
+    #[lens(ignore)]
+    keep_open: bool,
+    max_popup_height: Option<Units>,
+    // The option of the popup which is highlighted with the keyboard.
+    #[lens(ignore)]
+    highlighted: Option<Entity>,
+    // The characters typed to search the options, and when the last one was typed.
+    #[lens(ignore)]
+    typeahead: String,
+    #[lens(ignore)]
+    typed_at: Option<Instant>,
+}
 
 impl Dropdown {
     /// Creates a new dropdown.
@@ -136,41 +254,225 @@ impl Dropdown {
         L: 'static + Fn(&mut Context),
         F: 'static + Fn(&mut Context),
     {
-        Self {}.build(cx, move |cx| {
-            // cx.add_listener(move |_dropdown: &mut Self, cx, event| {
-            //     event.map(|window_event, meta| match window_event {
-            //         WindowEvent::PressDown { mouse: _ } => {
-            //             if meta.origin != cx.current() {
-            //                 // Check if the mouse was pressed outside of any descendants
-            //                 if !cx.hovered.is_descendant_of(cx.tree, cx.current) {
-            //                     cx.emit(PopupEvent::Close);
-            //                 }
-            //             }
-            //         }
-
-            //         WindowEvent::KeyDown(code, _) => {
-            //             if *code == Code::Escape {
-            //                 cx.emit(PopupEvent::Close);
-            //             }
-            //         }
-
-            //         _ => {}
-            //     });
-            // });
+        Self {
+            placement: Placement::Bottom,
+            keep_open: false,
+            max_popup_height: None,
+            highlighted: None,
+            typeahead: String::new(),
+            typed_at: None,
+        }
+        .build(cx, move |cx| {
+            cx.add_listener(move |dropdown: &mut Self, cx, event| {
+                // With `keep_open`, the popup isn't closed by its own content, unless the content is within a nested
+                // popup.
+                event.map(|popup_event, meta| {
+                    if matches!(popup_event, PopupEvent::Close) && dropdown.keep_open {
+                        let origin_popup = meta.origin.parent_iter(cx.tree).find(|entity| {
+                            cx.views
+                                .get(entity)
+                                .is_some_and(|view| view.downcast_ref::<Popup>().is_some())
+                        });
+                        if origin_popup.is_some() && origin_popup == Self::popup(cx) {
+                            meta.consume();
+                        }
+                    }
+                });
+
+                // Close the popup when the mouse is pressed, or the keyboard focus moves, outside of the dropdown. A
+                // press on the trigger is left to the trigger, so that pressing it again toggles the popup.
+                event.map(|window_event, _| {
+                    let target = match window_event {
+                        WindowEvent::PressDown { mouse: true } => cx.hovered(),
+                        WindowEvent::PressDown { mouse: false } | WindowEvent::FocusIn => {
+                            cx.focused()
+                        }
+                        _ => return,
+                    };
+
+                    if !target.is_descendant_of(cx.tree, cx.current) && Self::popup(cx).is_some() {
+                        cx.emit(PopupEvent::Close);
+                    }
+                });
+            });
 
             PopupData::default().build(cx);
 
             (trigger)(cx);
 
+            let dropdown = cx.current();
+            let content = Rc::new(content);
             Binding::new(cx, PopupData::is_open, move |cx, is_open| {
-                if is_open.get(cx) {
+                let is_open = is_open.get(cx);
+                cx.style.expanded.insert(dropdown, is_open);
+                cx.style.needs_access_update(dropdown);
+
+                if is_open {
+                    let max_popup_height = Dropdown::max_popup_height.get(cx);
                     Popup::new(cx, |cx| {
-                        (content)(cx);
+                        if let Some(max_popup_height) = max_popup_height {
+                            let content = content.clone();
+                            ScrollView::new(cx, move |cx| (content)(cx))
+                                .height(Auto)
+                                .max_height(max_popup_height);
+                        } else {
+                            (content)(cx);
+                        }
                     })
+                    .placement(Dropdown::placement)
                     .arrow_size(Pixels(4.0));
                 }
             })
         })
+        .role(Role::ComboBox)
+    }
+
+    // Returns the popup of the dropdown, if it is open.
+    fn popup(cx: &EventContext) -> Option<Entity> {
+        LayoutChildIterator::new(cx.tree, cx.current).find(|child| {
+            cx.views.get(child).is_some_and(|view| view.downcast_ref::<Popup>().is_some())
+        })
+    }
+
+    // Returns the options of the popup, which are the visible views with a press action, in tree order.
+    fn options(cx: &EventContext, popup: Entity) -> Vec<Entity> {
+        TreeIterator::subtree(cx.tree, popup)
+            .filter(|entity| {
+                cx.style.display.get(*entity).copied().unwrap_or_default() != Display::None
+                    && cx
+                        .data
+                        .get(entity)
+                        .and_then(|store| store.models.get(&TypeId::of::<ActionsModel>()))
+                        .and_then(|model| model.downcast_ref::<ActionsModel>())
+                        .is_some_and(|actions| actions.on_press.is_some())
+            })
+            .collect()
+    }
+
+    fn set_highlighted(&mut self, cx: &mut EventContext, highlighted: Option<Entity>) {
+        if self.highlighted == highlighted {
+            return;
+        }
+
+        for (entity, flag) in [(self.highlighted, false), (highlighted, true)] {
+            if let Some(entity) = entity {
+                cx.with_current(entity, |cx| {
+                    if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(entity) {
+                        pseudo_classes.set(PseudoClassFlags::ACTIVE_DESCENDANT, flag);
+                    }
+                    cx.needs_restyle();
+                });
+            }
+        }
+
+        self.highlighted = highlighted;
+
+        if let Some(highlighted) = highlighted {
+            Self::scroll_into_view(cx, highlighted);
+        }
+    }
+
+    // Scrolls the nearest scrollview containing the option so that the option is within its bounds.
+    fn scroll_into_view(cx: &mut EventContext, option: Entity) {
+        let Some((scrollview, view)) = option.parent_iter(cx.tree).skip(1).find_map(|ancestor| {
+            cx.views
+                .get(&ancestor)
+                .and_then(|view| view.downcast_ref::<ScrollView>())
+                .map(|view| (ancestor, view))
+        }) else {
+            return;
+        };
+
+        let negative_space = view.inner_height - view.container_height;
+        if negative_space <= 0.0 {
+            return;
+        }
+
+        let container = cx.cache.get_bounds(scrollview);
+        let bounds = cx.cache.get_bounds(option);
+        let delta = if bounds.top() < container.top() {
+            bounds.top() - container.top()
+        } else if bounds.bottom() > container.bottom() {
+            (bounds.bottom() - container.bottom()).min(bounds.top() - container.top())
+        } else {
+            return;
+        };
+
+        cx.emit_to(scrollview, ScrollEvent::ScrollY(delta / negative_space));
+    }
+
+    // Returns the text used to find the option by typing, which is its navigation text or else the text of the first
+    // view with text within it.
+    fn option_text(cx: &EventContext, option: Entity) -> Option<String> {
+        cx.style
+            .nav_text
+            .get(option)
+            .or_else(|| {
+                TreeIterator::subtree(cx.tree, option).find_map(|entity| cx.style.text.get(entity))
+            })
+            .map(|text| text.trim_start().to_lowercase())
+    }
+
+    // Adds a typed character to the typeahead search and highlights the option it finds.
+    fn typeahead(&mut self, cx: &mut EventContext, popup: Entity, c: char) {
+        let now = Instant::now();
+        if self.typed_at.is_some_and(|typed_at| now.duration_since(typed_at) > TYPEAHEAD_TIMEOUT) {
+            self.typeahead.clear();
+        }
+        self.typed_at = Some(now);
+        self.typeahead.extend(c.to_lowercase());
+
+        let options = Self::options(cx, popup);
+        let texts = options.iter().map(|option| Self::option_text(cx, *option)).collect::<Vec<_>>();
+        let current =
+            self.highlighted.and_then(|highlighted| options.iter().position(|e| *e == highlighted));
+
+        if let Some(index) = find_typeahead_match(&texts, current, &self.typeahead) {
+            self.set_highlighted(cx, Some(options[index]));
+        }
+    }
+
+    // Moves the keyboard focus from within the popup back to the trigger, the first navigable view of the dropdown.
+    fn focus_trigger(cx: &mut EventContext, popup: Entity) {
+        if !cx.focused().is_descendant_of(cx.tree, popup) {
+            return;
+        }
+
+        let trigger = TreeIterator::subtree(cx.tree, cx.current).find(|entity| {
+            !entity.is_descendant_of(cx.tree, popup)
+                && cx
+                    .style
+                    .abilities
+                    .get(*entity)
+                    .is_some_and(|abilities| abilities.contains(Abilities::NAVIGABLE))
+        });
+
+        if let Some(trigger) = trigger {
+            cx.with_current(trigger, |cx| cx.focus());
+        }
+    }
+}
+
+impl Handle<'_, Dropdown> {
+    /// Sets where the popup opens relative to the dropdown. Defaults to `Placement::Bottom`.
+    pub fn placement(self, placement: impl Res<Placement>) -> Self {
+        self.bind(placement, |handle, placement| {
+            let placement = placement.get(&handle);
+            handle.modify(|dropdown| dropdown.placement = placement);
+        })
+    }
+
+    /// Sets whether the popup stays open when its content emits `PopupEvent::Close`, such as when an option is
+    /// chosen, so that several options can be chosen at once. The popup still closes with the escape key and when the
+    /// mouse is pressed outside of the dropdown. Defaults to false.
+    pub fn keep_open(self, keep_open: bool) -> Self {
+        self.modify(|dropdown| dropdown.keep_open = keep_open)
+    }
+
+    /// Sets the maximum height of the popup. Content which is taller than the maximum height is scrolled within the
+    /// popup. Defaults to no maximum height.
+    pub fn max_popup_height(self, max_popup_height: impl Into<Units>) -> Self {
+        self.modify(|dropdown| dropdown.max_popup_height = Some(max_popup_height.into()))
     }
 }
 
@@ -178,4 +480,442 @@ impl View for Dropdown {
     fn element(&self) -> Option<&'static str> {
         Some("dropdown")
     }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| {
+            if let WindowEvent::ActionRequest(request) = window_event {
+                match request.action {
+                    Action::Expand => cx.emit(PopupEvent::Open),
+                    Action::Collapse => cx.emit(PopupEvent::Close),
+                    _ => return,
+                }
+                meta.consume();
+                return;
+            }
+
+            if let WindowEvent::CharInput(c) = window_event {
+                // Characters typed into a text input within the dropdown, such as a trigger used to filter the
+                // options, are not used to search the options.
+                let is_text_input = cx.style.role.get(cx.focused()) == Some(&Role::TextInput);
+                let is_search_char = !c.is_control() && (*c != ' ' || !self.typeahead.is_empty());
+                if is_search_char && !is_text_input && !cx.modifiers.ctrl() && !cx.modifiers.logo()
+                {
+                    if let Some(popup) = Self::popup(cx) {
+                        self.typeahead(cx, popup, *c);
+                        meta.consume();
+                    }
+                }
+                return;
+            }
+
+            let WindowEvent::KeyDown(code, _) = window_event else {
+                return;
+            };
+
+            let Some(popup) = Self::popup(cx) else {
+                self.highlighted = None;
+                self.typeahead.clear();
+                if *code == Code::ArrowDown {
+                    cx.emit(PopupEvent::Open);
+                    meta.consume();
+                }
+                return;
+            };
+
+            let options = Self::options(cx, popup);
+            // The highlighted option is forgotten once it is no longer in the popup, such as after the popup closed.
+            let current = self
+                .highlighted
+                .and_then(|highlighted| options.iter().position(|e| *e == highlighted));
+
+            match code {
+                Code::ArrowDown | Code::ArrowUp | Code::Home | Code::End => {
+                    if options.is_empty() {
+                        return;
+                    }
+
+                    let last = options.len() - 1;
+                    let index = match code {
+                        Code::ArrowDown => current.map_or(0, |index| (index + 1) % options.len()),
+                        Code::ArrowUp => {
+                            current.map_or(last, |index| (index + last) % options.len())
+                        }
+                        Code::Home => 0,
+                        _ => last,
+                    };
+
+                    self.set_highlighted(cx, Some(options[index]));
+                    meta.consume();
+                }
+
+                Code::Enter | Code::NumpadEnter => {
+                    if let Some(index) = current {
+                        cx.emit_to(options[index], ActionsEvent::Press);
+                        // The key is used to choose the option, so the focused trigger isn't pressed when it is released.
+                        cx.emit(InternalEvent::CancelPress);
+                        meta.consume();
+                    }
+                }
+
+                Code::Escape => {
+                    self.set_highlighted(cx, None);
+                    Self::focus_trigger(cx, popup);
+                    cx.emit(PopupEvent::Close);
+                    meta.consume();
+                }
+
+                _ => {}
+            }
+        });
+    }
+}
+
+// Returns the index of the option found by the typeahead search, starting from the highlighted option. Repeating a
+// single character cycles through the options starting with it.
+fn find_typeahead_match(
+    texts: &[Option<String>],
+    current: Option<usize>,
+    query: &str,
+) -> Option<usize> {
+    let first = query.chars().next()?;
+    let (prefix, start) = if query.chars().all(|c| c == first) {
+        (&query[..first.len_utf8()], current.map_or(0, |index| index + 1))
+    } else {
+        (query, current.unwrap_or(0))
+    };
+
+    (0..texts.len())
+        .map(|offset| (start + offset) % texts.len())
+        .find(|index| texts[*index].as_ref().is_some_and(|text| text.starts_with(prefix)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+    use crate::systems::binding_system;
+    use std::sync::{Arc, Mutex};
+
+    fn is_open(cx: &Context, dropdown: Entity) -> bool {
+        cx.style.expanded.get(dropdown).copied().unwrap_or_default()
+    }
+
+    fn send(
+        cx: &mut Context,
+        event_manager: &mut EventManager,
+        target: Entity,
+        event: WindowEvent,
+    ) {
+        cx.emit_custom(Event::new(event).target(target).origin(target).propagate(Propagation::Up));
+        event_manager.flush_events(cx, |_| {});
+        binding_system(cx);
+    }
+
+    #[test]
+    fn pressing_outside_closes_popup() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+
+        let dropdown = Dropdown::new(
+            &mut cx,
+            |cx| {
+                Label::new(cx, "Fruit");
+            },
+            |cx| {
+                Label::new(cx, "Apple");
+            },
+        )
+        .entity();
+        let trigger = cx.tree.get_layout_first_child(dropdown).unwrap();
+        let outside = Element::new(&mut cx).entity();
+
+        cx.emit_custom(Event::new(PopupEvent::Open).target(dropdown).origin(dropdown));
+        event_manager.flush_events(&mut cx, |_| {});
+        binding_system(&mut cx);
+        assert!(is_open(&cx, dropdown));
+
+        // A press on the trigger is left to the trigger.
+        cx.hovered = trigger;
+        send(&mut cx, &mut event_manager, trigger, WindowEvent::PressDown { mouse: true });
+        assert!(is_open(&cx, dropdown));
+
+        cx.hovered = outside;
+        send(&mut cx, &mut event_manager, outside, WindowEvent::PressDown { mouse: true });
+        assert!(!is_open(&cx, dropdown));
+    }
+
+    #[test]
+    fn kept_open_popup_ignores_close_from_content() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+
+        let dropdown = Dropdown::new(
+            &mut cx,
+            |cx| {
+                Label::new(cx, "Fruit");
+            },
+            |cx| {
+                Label::new(cx, "Apple");
+            },
+        )
+        .keep_open(true)
+        .entity();
+        let outside = Element::new(&mut cx).entity();
+
+        cx.emit_custom(Event::new(PopupEvent::Open).target(dropdown).origin(dropdown));
+        event_manager.flush_events(&mut cx, |_| {});
+        binding_system(&mut cx);
+        assert!(is_open(&cx, dropdown));
+
+        let popup = TreeIterator::subtree(&cx.tree, dropdown)
+            .find(|entity| {
+                cx.views.get(entity).is_some_and(|view| view.downcast_ref::<Popup>().is_some())
+            })
+            .unwrap();
+        let option = cx.tree.get_layout_first_child(popup).unwrap();
+
+        cx.emit_custom(Event::new(PopupEvent::Close).target(option).origin(option));
+        event_manager.flush_events(&mut cx, |_| {});
+        binding_system(&mut cx);
+        assert!(is_open(&cx, dropdown));
+
+        // A close from outside of the content still closes the popup.
+        cx.emit_custom(Event::new(PopupEvent::Close).target(dropdown).origin(dropdown));
+        event_manager.flush_events(&mut cx, |_| {});
+        binding_system(&mut cx);
+        assert!(!is_open(&cx, dropdown));
+
+        cx.emit_custom(Event::new(PopupEvent::Open).target(dropdown).origin(dropdown));
+        event_manager.flush_events(&mut cx, |_| {});
+        binding_system(&mut cx);
+        cx.hovered = outside;
+        send(&mut cx, &mut event_manager, outside, WindowEvent::PressDown { mouse: true });
+        assert!(!is_open(&cx, dropdown));
+    }
+
+    #[test]
+    fn max_popup_height_scrolls_content() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+
+        let dropdown = Dropdown::new(
+            &mut cx,
+            |cx| {
+                Label::new(cx, "Number");
+            },
+            |cx| {
+                for i in 0..100 {
+                    Label::new(cx, i);
+                }
+            },
+        )
+        .max_popup_height(Pixels(300.0))
+        .entity();
+
+        cx.emit_custom(Event::new(PopupEvent::Open).target(dropdown).origin(dropdown));
+        event_manager.flush_events(&mut cx, |_| {});
+        binding_system(&mut cx);
+
+        let scrollview = TreeIterator::subtree(&cx.tree, dropdown)
+            .find(|entity| {
+                cx.views.get(entity).is_some_and(|view| view.downcast_ref::<ScrollView>().is_some())
+            })
+            .unwrap();
+        assert_eq!(cx.style.max_height.get(scrollview), Some(&Pixels(300.0)));
+    }
+
+    #[test]
+    fn focus_leaving_closes_popup() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+
+        let dropdown = Dropdown::new(
+            &mut cx,
+            |cx| {
+                Label::new(cx, "Fruit");
+            },
+            |cx| {
+                Label::new(cx, "Apple");
+            },
+        )
+        .entity();
+        let outside = Element::new(&mut cx).entity();
+
+        cx.emit_custom(Event::new(PopupEvent::Open).target(dropdown).origin(dropdown));
+        event_manager.flush_events(&mut cx, |_| {});
+        binding_system(&mut cx);
+        assert!(is_open(&cx, dropdown));
+
+        cx.focused = outside;
+        send(&mut cx, &mut event_manager, outside, WindowEvent::FocusIn);
+        assert!(!is_open(&cx, dropdown));
+    }
+
+    // Builds a dropdown with a button which toggles the popup and three options which record their presses, and
+    // focuses the button.
+    fn build_keyboard_dropdown(cx: &mut Context, pressed: Arc<Mutex<Vec<&'static str>>>) -> Entity {
+        cx.windows.insert(Entity::root(), WindowState::default());
+
+        let dropdown = Dropdown::new(
+            cx,
+            {
+                let pressed = pressed.clone();
+                move |cx| {
+                    let pressed = pressed.clone();
+                    Button::new(cx, |cx| Label::new(cx, "Fruit")).on_press(move |cx| {
+                        pressed.lock().unwrap().push("trigger");
+                        cx.emit(PopupEvent::Switch);
+                    });
+                }
+            },
+            move |cx| {
+                for name in ["Apple", "Banana", "Cherry"] {
+                    let pressed = pressed.clone();
+                    Label::new(cx, name).on_press(move |cx| {
+                        pressed.lock().unwrap().push(name);
+                        cx.emit(PopupEvent::Close);
+                    });
+                }
+            },
+        )
+        .entity();
+
+        cx.focused = cx.tree.get_layout_first_child(dropdown).unwrap();
+        dropdown
+    }
+
+    fn press_key(cx: &mut Context, event_manager: &mut EventManager, code: Code) {
+        for event in [WindowEvent::KeyDown(code, None), WindowEvent::KeyUp(code, None)] {
+            cx.emit_custom(
+                Event::new(event)
+                    .target(Entity::root())
+                    .origin(Entity::root())
+                    .propagate(Propagation::Up),
+            );
+            event_manager.flush_events(cx, |_| {});
+            binding_system(cx);
+        }
+    }
+
+    fn highlighted(cx: &Context, dropdown: Entity) -> Option<String> {
+        TreeIterator::subtree(&cx.tree, dropdown)
+            .find(|entity| {
+                cx.style
+                    .pseudo_classes
+                    .get(*entity)
+                    .is_some_and(|classes| classes.contains(PseudoClassFlags::ACTIVE_DESCENDANT))
+            })
+            .and_then(|entity| cx.style.text.get(entity).cloned())
+    }
+
+    #[test]
+    fn arrow_keys_highlight_options_and_wrap() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+        let dropdown = build_keyboard_dropdown(&mut cx, Arc::default());
+
+        press_key(&mut cx, &mut event_manager, Code::ArrowDown);
+        assert!(is_open(&cx, dropdown));
+        assert_eq!(highlighted(&cx, dropdown), None);
+
+        press_key(&mut cx, &mut event_manager, Code::ArrowDown);
+        assert_eq!(highlighted(&cx, dropdown).as_deref(), Some("Apple"));
+        press_key(&mut cx, &mut event_manager, Code::ArrowDown);
+        assert_eq!(highlighted(&cx, dropdown).as_deref(), Some("Banana"));
+        press_key(&mut cx, &mut event_manager, Code::ArrowUp);
+        press_key(&mut cx, &mut event_manager, Code::ArrowUp);
+        assert_eq!(highlighted(&cx, dropdown).as_deref(), Some("Cherry"));
+        press_key(&mut cx, &mut event_manager, Code::ArrowDown);
+        assert_eq!(highlighted(&cx, dropdown).as_deref(), Some("Apple"));
+    }
+
+    #[test]
+    fn home_and_end_highlight_first_and_last_options() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+        let dropdown = build_keyboard_dropdown(&mut cx, Arc::default());
+
+        press_key(&mut cx, &mut event_manager, Code::ArrowDown);
+        press_key(&mut cx, &mut event_manager, Code::End);
+        assert_eq!(highlighted(&cx, dropdown).as_deref(), Some("Cherry"));
+        press_key(&mut cx, &mut event_manager, Code::Home);
+        assert_eq!(highlighted(&cx, dropdown).as_deref(), Some("Apple"));
+    }
+
+    #[test]
+    fn enter_presses_highlighted_option_instead_of_trigger() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+        let pressed = Arc::new(Mutex::new(Vec::new()));
+        let dropdown = build_keyboard_dropdown(&mut cx, pressed.clone());
+        let trigger = cx.focused;
+
+        press_key(&mut cx, &mut event_manager, Code::ArrowDown);
+        press_key(&mut cx, &mut event_manager, Code::ArrowDown);
+        press_key(&mut cx, &mut event_manager, Code::ArrowDown);
+        press_key(&mut cx, &mut event_manager, Code::Enter);
+
+        assert_eq!(*pressed.lock().unwrap(), ["Banana"]);
+        assert!(!is_open(&cx, dropdown));
+        assert_eq!(cx.triggered, Entity::null());
+        assert!(!cx.style.pseudo_classes.get(trigger).unwrap().contains(PseudoClassFlags::ACTIVE));
+
+        // Without a highlighted option the key presses the trigger as usual.
+        press_key(&mut cx, &mut event_manager, Code::Enter);
+        assert_eq!(*pressed.lock().unwrap(), ["Banana", "trigger"]);
+        assert!(is_open(&cx, dropdown));
+    }
+
+    #[test]
+    fn escape_closes_popup_and_focuses_trigger() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+        let dropdown = build_keyboard_dropdown(&mut cx, Arc::default());
+        let trigger = cx.focused;
+
+        press_key(&mut cx, &mut event_manager, Code::ArrowDown);
+        press_key(&mut cx, &mut event_manager, Code::ArrowDown);
+        let option = TreeIterator::subtree(&cx.tree, dropdown)
+            .find(|entity| cx.style.text.get(*entity).is_some_and(|text| text == "Apple"))
+            .unwrap();
+        cx.focused = option;
+
+        press_key(&mut cx, &mut event_manager, Code::Escape);
+        assert!(!is_open(&cx, dropdown));
+        assert_eq!(cx.focused, trigger);
+        assert_eq!(highlighted(&cx, dropdown), None);
+    }
+
+    fn texts(texts: &[&str]) -> Vec<Option<String>> {
+        texts.iter().map(|text| Some(text.to_string())).collect()
+    }
+
+    #[test]
+    fn typeahead_finds_first_option_with_prefix() {
+        let texts = texts(&["apple", "banana", "blueberry", "cherry"]);
+
+        assert_eq!(find_typeahead_match(&texts, None, "b"), Some(1));
+        assert_eq!(find_typeahead_match(&texts, Some(1), "bl"), Some(2));
+        assert_eq!(find_typeahead_match(&texts, Some(2), "ch"), Some(3));
+        assert_eq!(find_typeahead_match(&texts, Some(0), "d"), None);
+    }
+
+    #[test]
+    fn typeahead_cycles_through_options_with_repeated_character() {
+        let texts = texts(&["apple", "banana", "blueberry", "cherry"]);
+
+        assert_eq!(find_typeahead_match(&texts, Some(1), "b"), Some(2));
+        assert_eq!(find_typeahead_match(&texts, Some(2), "bb"), Some(1));
+        assert_eq!(find_typeahead_match(&texts, Some(1), "bbb"), Some(2));
+        // A longer query keeps the highlighted option while it still matches.
+        assert_eq!(find_typeahead_match(&texts, Some(2), "blu"), Some(2));
+    }
+
+    #[test]
+    fn typeahead_skips_options_without_text() {
+        let texts = vec![None, Some("apple".to_string()), None];
+
+        assert_eq!(find_typeahead_match(&texts, None, "a"), Some(1));
+        assert_eq!(find_typeahead_match(&texts, Some(1), "a"), Some(1));
+    }
 }
@@ -66,7 +66,7 @@ use crate::prelude::*;
 /// Element::new(cx)
 ///     .width(Pixels(100.0))
 ///     .height(Pixels(100.0))
-///     .border_radius(Percentage(50.0))
+///     .corner_radius(Percentage(50.0))
 ///     .background_color(Color::black());
 /// ```
 ///
@@ -0,0 +1,294 @@
+use std::any::Any;
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::cell::RefCell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Once;
+
+use hashbrown::HashMap;
+
+use crate::context::CURRENT;
+use crate::prelude::*;
+use crate::view::ViewHandler;
+
+thread_local! {
+    // Backtrace of the most recent panic on this thread, captured by the panic hook.
+    static LAST_BACKTRACE: RefCell<Option<Backtrace>> = const { RefCell::new(None) };
+    // Panics caught while drawing, which are forwarded to their error boundaries after the frame.
+    static DEFERRED_ERRORS: RefCell<Vec<(Entity, String)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Events used to control an [`ErrorBoundary`].
+pub enum ErrorBoundaryEvent {
+    /// Replaces the contents of the boundary with the error placeholder, showing the given message.
+    Error(String),
+    /// Drops the error placeholder and rebuilds the contents of the boundary.
+    Retry,
+}
+
+/// A view which contains panics raised by the views inside of it.
+///
+/// If the content of the boundary panics while being built, rebuilt by a [`Binding`], handling an event, or drawing,
+/// the whole subtree is dropped and replaced with a placeholder showing the panic message and a button to retry.
+/// The rest of the application keeps running. Because the panicking views may have been left in an inconsistent
+/// state, retrying always rebuilds the content from scratch rather than reusing the previous views.
+///
+/// The panic message is logged with the `log` crate, along with a backtrace when backtraces are enabled with the
+/// `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variables.
+///
+/// # Example
+/// ```ignore
+/// ErrorBoundary::new(cx, |cx| {
+///     PluginPanel::new(cx);
+/// });
+/// ```
+///
+/// The placeholder can be styled with the `error-boundary .placeholder` selector.
+#[derive(Lens)]
+pub struct ErrorBoundary {
+    error: Option<String>,
+}
+
+impl ErrorBoundary {
+    /// Creates a new error boundary around the given content.
+    pub fn new<F>(cx: &mut Context, content: F) -> Handle<Self>
+    where
+        F: 'static + Fn(&mut Context),
+    {
+        install_panic_hook();
+
+        Self { error: None }.build(cx, move |cx| {
+            let boundary = cx.current();
+            Binding::new(cx, ErrorBoundary::error, move |cx, error| {
+                if let Some(message) = error.get(cx) {
+                    placeholder(cx, message);
+                    return;
+                }
+
+                let binding = cx.current();
+                if let Err(payload) = catch_panic(|| (content)(cx)) {
+                    // Unwinding may have skipped restoring the current entity.
+                    cx.current = binding;
+                    CURRENT.with_borrow_mut(|f| *f = binding);
+
+                    let message = log_panic(boundary, payload.as_ref());
+                    cx.remove_children(binding);
+                    placeholder(cx, message.clone());
+                    cx.emit_to(boundary, ErrorBoundaryEvent::Error(message));
+                }
+            });
+        })
+    }
+}
+
+impl View for ErrorBoundary {
+    fn element(&self) -> Option<&'static str> {
+        Some("error-boundary")
+    }
+
+    fn event(&mut self, _cx: &mut EventContext, event: &mut Event) {
+        event.map(|error_boundary_event, meta| match error_boundary_event {
+            ErrorBoundaryEvent::Error(message) => {
+                self.error = Some(message.clone());
+                meta.consume();
+            }
+
+            ErrorBoundaryEvent::Retry => {
+                self.error = None;
+                meta.consume();
+            }
+        });
+    }
+}
+
+fn placeholder(cx: &mut Context, message: String) {
+    VStack::new(cx, |cx| {
+        Label::new(cx, message).class("message");
+        Button::new(cx, |cx| Label::new(cx, "Retry"))
+            .on_press(|cx| cx.emit(ErrorBoundaryEvent::Retry));
+    })
+    .class("placeholder");
+}
+
+/// Runs the given closure, catching any panic raised within it.
+///
+/// Callers must not reuse state the closure may have left partially modified, which is why the
+/// views affected by a panic are dropped by their [`ErrorBoundary`] instead.
+pub(crate) fn catch_panic<R>(f: impl FnOnce() -> R) -> Result<R, Box<dyn Any + Send>> {
+    catch_unwind(AssertUnwindSafe(f))
+}
+
+/// Finds the closest [`ErrorBoundary`] containing an entity whose code panicked, logging the panic.
+///
+/// Returns the boundary and the panic message, or resumes unwinding if the entity is not inside a boundary.
+pub(crate) fn recover_from_panic(
+    tree: &Tree<Entity>,
+    views: &HashMap<Entity, Box<dyn ViewHandler>>,
+    entity: Entity,
+    payload: Box<dyn Any + Send>,
+) -> (Entity, String) {
+    let boundary = entity.parent_iter(tree).skip(1).find(|ancestor| {
+        views.get(ancestor).is_some_and(|view| view.downcast_ref::<ErrorBoundary>().is_some())
+    });
+
+    match boundary {
+        Some(boundary) => (boundary, log_panic(entity, payload.as_ref())),
+        None => std::panic::resume_unwind(payload),
+    }
+}
+
+/// Queues an error for a boundary to be emitted once the context is available again.
+pub(crate) fn defer_error(boundary: Entity, message: String) {
+    DEFERRED_ERRORS.with_borrow_mut(|errors| errors.push((boundary, message)));
+}
+
+/// Emits any errors queued with [`defer_error`] to their boundaries.
+pub(crate) fn emit_deferred_errors(cx: &mut Context) {
+    for (boundary, message) in DEFERRED_ERRORS.take() {
+        cx.emit_to(boundary, ErrorBoundaryEvent::Error(message));
+    }
+}
+
+fn log_panic(entity: Entity, payload: &(dyn Any + Send)) -> String {
+    let message = if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("Unknown panic")
+    };
+
+    match LAST_BACKTRACE.take().filter(|backtrace| backtrace.status() == BacktraceStatus::Captured)
+    {
+        Some(backtrace) => {
+            log::error!("View {} panicked: {}\n{}", entity, message, backtrace)
+        }
+        None => log::error!("View {} panicked: {}", entity, message),
+    }
+
+    message
+}
+
+// Chains a hook onto the existing panic hook which records the backtrace of the panic,
+// as it is no longer available once the panic has been caught.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            LAST_BACKTRACE.set(Some(Backtrace::capture()));
+            previous_hook(info);
+        }));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+    use crate::systems::{binding_system, draw_system, layout_system, style_system};
+
+    struct Explode;
+
+    // A view which panics when it receives an `Explode` event.
+    struct PanicsOnEvent;
+
+    impl View for PanicsOnEvent {
+        fn event(&mut self, _cx: &mut EventContext, event: &mut Event) {
+            event.map(|_: &Explode, _| panic!("Event panic"));
+        }
+    }
+
+    struct PanicsOnDraw;
+
+    impl View for PanicsOnDraw {
+        fn draw(&self, _cx: &mut DrawContext, _canvas: &Canvas) {
+            panic!("Draw panic");
+        }
+    }
+
+    fn text_in_subtree(cx: &Context, root: Entity) -> Vec<String> {
+        vizia_storage::TreeIterator::subtree(&cx.tree, root)
+            .filter_map(|entity| cx.style.text.get(entity).cloned())
+            .collect()
+    }
+
+    #[test]
+    fn panicking_content_is_replaced_by_placeholder() {
+        let mut cx = Context::default();
+
+        let boundary = ErrorBoundary::new(&mut cx, |cx| {
+            Label::new(cx, "Before");
+            panic!("Intentional panic");
+        })
+        .entity();
+
+        // The rest of the application keeps building.
+        let sibling = Label::new(&mut cx, "Sibling").entity();
+
+        let text = text_in_subtree(&cx, boundary);
+        assert!(text.contains(&String::from("Intentional panic")));
+        assert!(!text.contains(&String::from("Before")));
+        assert_eq!(cx.style.text.get(sibling), Some(&String::from("Sibling")));
+    }
+
+    #[test]
+    fn content_without_panic_is_built() {
+        let mut cx = Context::default();
+
+        let boundary = ErrorBoundary::new(&mut cx, |cx| {
+            Label::new(cx, "Content");
+        })
+        .entity();
+
+        assert_eq!(text_in_subtree(&cx, boundary), vec![String::from("Content")]);
+    }
+
+    #[test]
+    fn panicking_event_handler_is_replaced_by_placeholder() {
+        let mut cx = Context::default();
+
+        let boundary = ErrorBoundary::new(&mut cx, |cx| {
+            PanicsOnEvent.build(cx, |_| {});
+        })
+        .entity();
+        let sibling = Label::new(&mut cx, "Sibling").entity();
+
+        let view = cx.with_current(boundary, |cx| cx.first_descendant::<PanicsOnEvent>()).unwrap();
+        cx.emit_custom(Event::new(Explode).target(view).origin(view));
+        EventManager::new().flush_events(&mut cx, |_| {});
+        binding_system(&mut cx);
+
+        assert!(text_in_subtree(&cx, boundary).contains(&String::from("Event panic")));
+        assert_eq!(cx.with_current(boundary, |cx| cx.first_descendant::<PanicsOnEvent>()), None);
+        assert_eq!(cx.style.text.get(sibling), Some(&String::from("Sibling")));
+    }
+
+    #[test]
+    fn panicking_draw_is_replaced_by_placeholder() {
+        let mut cx = Context::default();
+        cx.windows.insert(Entity::root(), WindowState::default());
+        cx.style.dpi_factor = 1.0;
+        cx.style.width.insert(Entity::root(), Units::Pixels(400.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(200.0));
+
+        let boundary = ErrorBoundary::new(&mut cx, |cx| {
+            PanicsOnDraw.build(cx, |_| {}).size(Pixels(50.0));
+        })
+        .entity();
+        style_system(&mut cx);
+        layout_system(&mut cx);
+
+        let view = cx.with_current(boundary, |cx| cx.first_descendant::<PanicsOnDraw>()).unwrap();
+        cx.needs_redraw(view);
+        let mut surface = skia_safe::surfaces::raster_n32_premul((400, 200)).unwrap();
+        let mut dirty_surface = skia_safe::surfaces::raster_n32_premul((400, 200)).unwrap();
+        draw_system(&mut cx, Entity::root(), &mut surface, &mut dirty_surface);
+
+        // The error is emitted to the boundary once the frame has been drawn.
+        EventManager::new().flush_events(&mut cx, |_| {});
+        binding_system(&mut cx);
+
+        assert!(text_in_subtree(&cx, boundary).contains(&String::from("Draw panic")));
+        assert_eq!(cx.with_current(boundary, |cx| cx.first_descendant::<PanicsOnDraw>()), None);
+    }
+}
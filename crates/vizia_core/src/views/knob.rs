@@ -15,7 +15,9 @@ use std::{default, f32::consts::PI};
 
 pub struct Knob<L> {
     lens: L,
-    default_normal: f32,
+    // Maps the normalized value of the knob to the value of its parameter, to snap it to the steps of the parameter
+    // and wrap it around for parameters such as angles.
+    param: NumericParam,
 
     is_dragging: bool,
     prev_drag_y: f32,
@@ -38,7 +40,7 @@ impl<L: Lens<Target = f32>> Knob<L> {
     ) -> Handle<Self> {
         Self {
             lens,
-            default_normal: normalized_default.get(cx),
+            param: NumericParam::new(0.0, 1.0).default_value(normalized_default.get(cx)),
 
             is_dragging: false,
             prev_drag_y: 0.0,
@@ -86,7 +88,7 @@ impl<L: Lens<Target = f32>> Knob<L> {
     {
         Self {
             lens,
-            default_normal,
+            param: NumericParam::new(0.0, 1.0).default_value(default_normal),
 
             is_dragging: false,
             prev_drag_y: 0.0,
@@ -120,6 +122,35 @@ impl<L: Lens<Target = f32>> Handle<'_, Knob<L>> {
 
         self
     }
+
+    /// Sets the parameter controlled by the knob, which the normalized value of the knob is snapped to and which
+    /// determines whether the knob wraps around. The value of the knob and the value passed to the `on_changing`
+    /// callback remain normalized, and the default value of the knob is replaced by the default of the parameter.
+    ///
+    /// # Example
+    /// A knob which selects an angle in steps of 15 degrees, and can be turned past a full turn:
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_derive::*;
+    /// # let mut cx = &mut Context::default();
+    /// # #[derive(Lens, Default)]
+    /// # pub struct AppData {
+    /// #     angle: f32,
+    /// # }
+    /// # impl Model for AppData {}
+    /// # AppData::default().build(cx);
+    /// Knob::new(cx, 0.0, AppData::angle, false)
+    ///     .param(NumericParam::new(0.0, 360.0).step(15.0).wrapping(true));
+    /// ```
+    pub fn param(self, param: NumericParam) -> Self {
+        if let Some(view) = self.cx.views.get_mut(&self.entity) {
+            if let Some(knob) = view.downcast_mut::<Knob<L>>() {
+                knob.param = param;
+            }
+        }
+
+        self
+    }
 }
 
 impl<L: Lens<Target = f32>> View for Knob<L> {
@@ -129,10 +160,10 @@ impl<L: Lens<Target = f32>> View for Knob<L> {
 
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
         let move_virtual_slider = |self_ref: &mut Self, cx: &mut EventContext, new_normal: f32| {
-            self_ref.continuous_normal = new_normal.clamp(0.0, 1.0);
+            self_ref.continuous_normal = self_ref.param.constrain_normalized(new_normal);
 
             if let Some(callback) = &self_ref.on_changing {
-                (callback)(cx, self_ref.continuous_normal);
+                (callback)(cx, self_ref.param.snap_normalized(self_ref.continuous_normal));
             }
         };
 
@@ -184,7 +215,7 @@ impl<L: Lens<Target = f32>> View for Knob<L> {
             WindowEvent::MouseDoubleClick(button) if *button == MouseButton::Left => {
                 self.is_dragging = false;
 
-                move_virtual_slider(self, cx, self.default_normal);
+                move_virtual_slider(self, cx, self.param.default_normalized());
             }
 
             WindowEvent::KeyDown(Code::ArrowUp | Code::ArrowRight, _) => {
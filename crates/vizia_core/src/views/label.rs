@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::text::find_matches;
 
 /// A label used to display text.
 ///
@@ -73,6 +74,34 @@ use crate::prelude::*;
 /// .text_wrap(false);
 /// ```
 ///
+/// ## Label with search highlighting
+///
+/// A label can highlight the occurrences of a search query within its text. The matches are styled with the
+/// `::highlight` part of the label, for example with a `label::highlight` CSS selector.
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// #[derive(Lens)]
+/// struct AppData {
+///     text: String,
+///     query: String,
+/// }
+///
+/// impl Model for AppData {}
+///
+/// AppData {
+///     text: String::from("Text with some text"),
+///     query: String::from("text"),
+/// }
+/// .build(cx);
+///
+/// Label::highlighted(cx, AppData::text, AppData::query)
+///     .on_match_count(|_, count| println!("{} matches", count));
+/// ```
+///
 /// ## Label for a button
 ///
 /// A label can also be used inside of a button to be able to add text to it.
@@ -85,6 +114,31 @@ use crate::prelude::*;
 /// ```
 pub struct Label {
     describing: Option<String>,
+    on_match_count: Option<Box<dyn Fn(&mut EventContext, usize)>>,
+}
+
+enum LabelEvent {
+    MatchCount(usize),
+}
+
+#[derive(Lens)]
+struct HighlightData {
+    case_sensitive: bool,
+}
+
+enum HighlightEvent {
+    SetCaseSensitive(bool),
+}
+
+impl Model for HighlightData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|highlight_event, meta| match highlight_event {
+            HighlightEvent::SetCaseSensitive(flag) => {
+                self.case_sensitive = *flag;
+                meta.consume();
+            }
+        });
+    }
 }
 
 impl Label {
@@ -103,7 +157,11 @@ impl Label {
     where
         T: ToStringLocalized,
     {
-        Self { describing: None }.build(cx, |_| {}).text(text.clone()).role(Role::Label).name(text)
+        Self { describing: None, on_match_count: None }
+            .build(cx, |_| {})
+            .text(text.clone())
+            .role(Role::Label)
+            .name(text)
     }
 
     pub fn rich<T>(
@@ -114,7 +172,7 @@ impl Label {
     where
         T: ToStringLocalized,
     {
-        Self { describing: None }
+        Self { describing: None, on_match_count: None }
             .build(cx, |cx| {
                 children(cx);
             })
@@ -122,6 +180,60 @@ impl Label {
             .role(Role::Label)
             .name(text)
     }
+
+    /// Creates a new label which highlights the occurrences of a search query within its text.
+    ///
+    /// Each match is displayed as a [`TextSpan`] which is the `::highlight` part of the label. Matches are
+    /// case-insensitive by default, comparing the Unicode case folding of the text and query, which can be changed
+    /// with [`case_sensitive`](Handle::case_sensitive). The spans are rebuilt whenever the text or the query changes.
+    pub fn highlighted(
+        cx: &mut Context,
+        text: impl Lens<Target = String>,
+        query: impl Lens<Target = String>,
+    ) -> Handle<Self> {
+        Self { describing: None, on_match_count: None }
+            .build(cx, |cx| {
+                HighlightData { case_sensitive: false }.build(cx);
+
+                let label = cx.current();
+                Binding::new(cx, text, move |cx, text| {
+                    Binding::new(cx, query, move |cx, query| {
+                        Binding::new(
+                            cx,
+                            HighlightData::case_sensitive,
+                            move |cx, case_sensitive| {
+                                let text = text.get(cx);
+                                let matches =
+                                    find_matches(&text, &query.get(cx), case_sensitive.get(cx));
+
+                                let mut start = 0;
+                                for range in matches.iter() {
+                                    if range.start > start {
+                                        TextSpan::new(cx, &text[start..range.start], |_| {});
+                                    }
+
+                                    let span =
+                                        TextSpan::new(cx, &text[range.clone()], |_| {}).entity();
+                                    cx.style.parts.insert(span, String::from("highlight"));
+                                    start = range.end;
+                                }
+
+                                if start < text.len() {
+                                    TextSpan::new(cx, &text[start..], |_| {});
+                                }
+
+                                // The paragraph of the label is built from its spans.
+                                cx.style.needs_text_update(label);
+                                cx.emit(LabelEvent::MatchCount(matches.len()));
+                            },
+                        );
+                    });
+                });
+            })
+            .text("")
+            .role(Role::Label)
+            .name(text)
+    }
 }
 
 impl Handle<'_, Label> {
@@ -157,6 +269,22 @@ impl Handle<'_, Label> {
         }
         self.modify(|label| label.describing = Some(identifier)).class("describing").hidden(true)
     }
+
+    /// Sets whether the matches of a [highlighted](Label::highlighted) label are case sensitive. Defaults to false.
+    pub fn case_sensitive(self, flag: bool) -> Self {
+        let entity = self.entity;
+        self.cx.emit_custom(
+            Event::new(HighlightEvent::SetCaseSensitive(flag)).target(entity).origin(entity),
+        );
+
+        self
+    }
+
+    /// Sets a callback which is called with the number of matches of a [highlighted](Label::highlighted) label
+    /// whenever its text or query changes.
+    pub fn on_match_count(self, callback: impl Fn(&mut EventContext, usize) + 'static) -> Self {
+        self.modify(|label| label.on_match_count = Some(Box::new(callback)))
+    }
 }
 
 impl View for Label {
@@ -188,5 +316,15 @@ impl View for Label {
             }
             _ => {}
         });
+
+        event.map(|label_event, meta| match label_event {
+            LabelEvent::MatchCount(count) => {
+                if let Some(callback) = &self.on_match_count {
+                    (callback)(cx, *count);
+                }
+
+                meta.consume();
+            }
+        });
     }
 }
@@ -23,6 +23,10 @@ pub enum ListEvent {
 }
 
 /// A view for creating a list of items from a binding to an iteratable list.
+///
+/// The list is borrowed, rather than cloned, when the items are built and when the list data changes, so neither the
+/// list nor its items are required to implement `Clone` or [`Data`]. An item only needs to implement `Clone` to be
+/// bound to through the item lens which is passed to the item content.
 #[derive(Lens)]
 pub struct List {
     list_len: usize,
@@ -42,7 +46,7 @@ impl List {
         item_content: impl 'static + Fn(&mut Context, usize, MapRef<L, T>),
     ) -> Handle<Self>
     where
-        L::Target: Deref<Target = [T]> + Data,
+        L::Target: Deref<Target = [T]>,
     {
         Self::new_generic(
             cx,
@@ -61,7 +65,7 @@ impl List {
         item_content: impl 'static + Fn(&mut Context, usize, MapRef<L, T>),
     ) -> Handle<Self>
     where
-        L::Target: Deref<Target = [T]> + Data,
+        L::Target: Deref<Target = [T]>,
     {
         let f = filter.clone();
         Self::new_generic(
@@ -84,7 +88,7 @@ impl List {
         item_content: impl 'static + Fn(&mut Context, usize, MapRef<L, T>),
     ) -> Handle<Self>
     where
-        L::Target: Deref<Target = [T]> + Data,
+        L::Target: Deref<Target = [T]>,
     {
         let content = Rc::new(item_content);
         let num_items = list.map(list_len);
@@ -149,14 +153,18 @@ impl List {
                     // If the number of list items is different to the number of children of the ListView
                     // then remove and rebuild all the children
 
+                    // The list is borrowed from its model to find the items, so that it isn't cloned.
                     let mut f = filter.clone();
                     let ll = list
-                        .get(cx)
-                        .iter()
-                        .enumerate()
-                        .filter(|(_, v)| f(v))
-                        .map(|(idx, _)| idx)
-                        .collect::<Vec<_>>();
+                        .view_in(cx)
+                        .map(|list| {
+                            list.iter()
+                                .enumerate()
+                                .filter(|(_, v)| f(v))
+                                .map(|(idx, _)| idx)
+                                .collect::<Vec<_>>()
+                        })
+                        .unwrap_or_default();
 
                     for index in ll.into_iter() {
                         let ll = list_index.clone();
@@ -359,3 +367,49 @@ impl View for ListItem {
         Some("list-item")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+    use std::cell::Cell;
+
+    thread_local! {
+        static CLONES: Cell<usize> = const { Cell::new(0) };
+    }
+
+    // An item which counts how many times it is cloned.
+    struct Item;
+
+    impl Clone for Item {
+        fn clone(&self) -> Self {
+            CLONES.with(|clones| clones.set(clones.get() + 1));
+            Item
+        }
+    }
+
+    #[derive(Lens)]
+    struct AppData {
+        items: Vec<Item>,
+    }
+
+    impl Model for AppData {}
+
+    #[test]
+    fn items_are_not_cloned() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+
+        AppData { items: (0..100).map(|_| Item).collect() }.build(&mut cx);
+        List::new(&mut cx, AppData::items, |cx, index, _| {
+            Label::new(cx, index);
+        });
+        assert_eq!(CLONES.with(Cell::get), 0);
+
+        for _ in 0..3 {
+            cx.emit_custom(Event::new(WindowEvent::Redraw).target(Entity::root()));
+            event_manager.flush_events(&mut cx, |_| {});
+        }
+        assert_eq!(CLONES.with(Cell::get), 0);
+    }
+}
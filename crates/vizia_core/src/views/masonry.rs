@@ -0,0 +1,434 @@
+use std::ops::{Deref, Range};
+
+use super::virtual_list::VirtualListData;
+use crate::prelude::*;
+
+/// How the number of columns of a [`Masonry`] is chosen.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MasonryColumns {
+    /// A fixed number of columns.
+    Count(usize),
+    /// As many columns as fit in the width of the masonry, each at least the given width in logical pixels.
+    MinWidth(f32),
+}
+
+impl Default for MasonryColumns {
+    fn default() -> Self {
+        MasonryColumns::Count(2)
+    }
+}
+
+impl MasonryColumns {
+    // Returns the number of columns which fit in the given width, which is always at least one.
+    fn count(&self, width: f32, gap: f32) -> usize {
+        match *self {
+            MasonryColumns::Count(count) => count.max(1),
+            MasonryColumns::MinWidth(min_width) => {
+                ((width + gap) / (min_width + gap).max(1.0)).floor().max(1.0) as usize
+            }
+        }
+    }
+}
+
+/// The positions of the items of a [`Masonry`], in logical pixels relative to the top left of its content.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MasonryLayout {
+    /// The number of columns.
+    pub columns: usize,
+    /// The width of each column, which is the width of each item.
+    pub column_width: f32,
+    /// The bounds of each item, in the order of the items.
+    pub items: Vec<BoundingBox>,
+    /// The height of the tallest column.
+    pub height: f32,
+}
+
+impl Data for MasonryLayout {
+    fn same(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl MasonryLayout {
+    /// Places items with the given aspect ratios, their widths divided by their heights, into the columns which fit
+    /// in the given width.
+    ///
+    /// Each item is placed in turn at the bottom of the shortest column, or the leftmost of the shortest columns, so
+    /// the items of each column are in the order of the items.
+    pub fn new(
+        aspect_ratios: &[f32],
+        width: f32,
+        columns: MasonryColumns,
+        horizontal_gap: f32,
+        vertical_gap: f32,
+    ) -> Self {
+        let count = columns.count(width, horizontal_gap);
+        let column_width = ((width - horizontal_gap * (count - 1) as f32) / count as f32).max(0.0);
+
+        let mut column_heights = vec![0.0f32; count];
+        let items = aspect_ratios
+            .iter()
+            .map(|aspect_ratio| {
+                let column = (0..count)
+                    .min_by(|a, b| column_heights[*a].total_cmp(&column_heights[*b]))
+                    .unwrap_or_default();
+                let height = if *aspect_ratio > 0.0 { column_width / aspect_ratio } else { 0.0 };
+                let item = BoundingBox {
+                    x: column as f32 * (column_width + horizontal_gap),
+                    y: column_heights[column],
+                    w: column_width,
+                    h: height,
+                };
+
+                column_heights[column] += height + vertical_gap;
+                item
+            })
+            .collect::<Vec<_>>();
+
+        // The gap below the last item of each column isn't part of the height.
+        let height = if items.is_empty() {
+            0.0
+        } else {
+            (column_heights.iter().copied().fold(0.0, f32::max) - vertical_gap).max(0.0)
+        };
+
+        Self { columns: count, column_width, items, height }
+    }
+
+    /// Returns the range of indices from the first to the last item which intersects the vertical span from `top` to
+    /// `bottom`.
+    ///
+    /// Items in the range which are in other columns may be outside of the span, but are close to it as the items
+    /// of each column are in order.
+    pub fn visible_range(&self, top: f32, bottom: f32) -> Range<usize> {
+        let mut visible = self
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item.y < bottom && item.y + item.h > top)
+            .map(|(index, _)| index);
+
+        match visible.next() {
+            Some(first) => first..visible.last().unwrap_or(first) + 1,
+            None => 0..0,
+        }
+    }
+}
+
+/// A scrollable container which places items of different heights into columns, like a wall of bricks.
+///
+/// The height of each item is derived from its aspect ratio and the width of the columns, so the layout is known
+/// before the items are built. Only the items which intersect the visible area of the masonry are realized, so it can
+/// show a large number of items. The gaps between the items are set with the `horizontal-gap` and `vertical-gap` of
+/// the masonry, in pixels.
+///
+/// ```no_run
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// #[derive(Lens)]
+/// struct Gallery {
+///     photos: Vec<(String, f32)>,
+/// }
+///
+/// # impl Model for Gallery {}
+/// # Gallery { photos: vec![] }.build(cx);
+/// Masonry::new(cx, Gallery::photos, |(_, aspect_ratio)| *aspect_ratio, |cx, _, photo| {
+///     Image::new(cx, photo.map(|(path, _)| path.clone()))
+/// })
+/// .min_column_width(200.0)
+/// .horizontal_gap(Pixels(8.0))
+/// .vertical_gap(Pixels(8.0));
+/// ```
+#[derive(Lens)]
+pub struct Masonry {
+    /// The range of indices of the items which are currently realized.
+    pub visible_range: Range<usize>,
+    /// The layout of the items, which is kept until the width of the masonry or the items change.
+    pub layout: MasonryLayout,
+
+    // The bounds the items are shown at, which move from those of the previous layout while the layout is animated.
+    positions: Vec<BoundingBox>,
+    scroll_y: f32,
+    #[lens(ignore)]
+    columns: MasonryColumns,
+    #[lens(ignore)]
+    aspect_ratios: Vec<f32>,
+    #[lens(ignore)]
+    animation: Option<(Duration, Timer)>,
+    #[lens(ignore)]
+    animation_start: (Vec<BoundingBox>, Instant),
+}
+
+pub enum MasonryEvent {
+    SetScrollY(f32),
+}
+
+enum MasonryInternalEvent {
+    SetItems(Vec<f32>),
+    AnimationTick,
+}
+
+impl Masonry {
+    /// Creates a new masonry of the items of a list, with the aspect ratio of each item, its width divided by its
+    /// height, given by `aspect_ratio`.
+    pub fn new<V: View, L: Lens, T: 'static>(
+        cx: &mut Context,
+        list: L,
+        aspect_ratio: impl 'static + Fn(&T) -> f32,
+        item_content: impl 'static + Copy + Fn(&mut Context, usize, MapRef<L, T>) -> Handle<V>,
+    ) -> Handle<Self>
+    where
+        L::Target: Deref<Target = [T]>,
+    {
+        let aspect_ratios =
+            list.map(move |list| list.iter().map(&aspect_ratio).collect::<Vec<_>>());
+
+        Self {
+            visible_range: 0..0,
+            layout: MasonryLayout::default(),
+            positions: Vec::new(),
+            scroll_y: 0.0,
+            columns: MasonryColumns::default(),
+            aspect_ratios: Vec::new(),
+            animation: None,
+            animation_start: (Vec::new(), Instant::now()),
+        }
+        .build(cx, |cx| {
+            Binding::new(cx, aspect_ratios, |cx, aspect_ratios| {
+                let aspect_ratios = aspect_ratios.get(cx);
+                cx.emit(MasonryInternalEvent::SetItems(aspect_ratios));
+            });
+
+            ScrollView::new(cx, move |cx| {
+                // The content is sized to the height of the layout so the scroll bar is correct.
+                VStack::new(cx, |cx| {
+                    // As in a virtual list, each realized view shows the item with an index which only changes when
+                    // the item scrolls out of view, so the views of the other items aren't rebuilt.
+                    let num_visible_items = Masonry::visible_range.map(Range::len);
+                    Binding::new(cx, num_visible_items, move |cx, lens| {
+                        for i in 0..lens.get(cx) {
+                            let item_index = Masonry::visible_range.map(move |range| {
+                                VirtualListData::evaluate_index(i, range.start, range.end)
+                            });
+                            Binding::new(cx, item_index, move |cx, lens| {
+                                let index = lens.get(cx);
+                                let bounds = Masonry::positions.map(move |positions| {
+                                    positions.get(index).copied().unwrap_or_default()
+                                });
+
+                                HStack::new(cx, move |cx| {
+                                    let item = list.map_ref(move |list| &list[index]);
+                                    item_content(cx, index, item)
+                                        .width(Percentage(100.0))
+                                        .height(Percentage(100.0));
+                                })
+                                .position_type(PositionType::Absolute)
+                                .left(bounds.map(|bounds| Pixels(bounds.x)))
+                                .top(bounds.map(|bounds| Pixels(bounds.y)))
+                                .width(bounds.map(|bounds| Pixels(bounds.w)))
+                                .height(bounds.map(|bounds| Pixels(bounds.h)));
+                            });
+                        }
+                    });
+                })
+                .height(Masonry::layout.map(|layout| Pixels(layout.height)));
+            })
+            .show_horizontal_scrollbar(false)
+            .on_scroll(|cx, _, y| {
+                if y.is_finite() {
+                    cx.emit(MasonryEvent::SetScrollY(y));
+                }
+            });
+        })
+    }
+
+    // Places the items into columns for the current width of the masonry, animating them to their new positions if
+    // the layout is animated and the items haven't changed.
+    fn relayout(&mut self, cx: &mut EventContext, animate: bool) {
+        let width = cx.cache.get_width(cx.current());
+        if width == f32::MAX {
+            return;
+        }
+
+        let gap = |units: Option<&Units>| match units {
+            Some(Units::Pixels(pixels)) => *pixels,
+            _ => 0.0,
+        };
+        let horizontal_gap = gap(cx.style.horizontal_gap.get(cx.current()));
+        let vertical_gap = gap(cx.style.vertical_gap.get(cx.current()));
+
+        self.layout = MasonryLayout::new(
+            &self.aspect_ratios,
+            width / cx.scale_factor(),
+            self.columns,
+            horizontal_gap,
+            vertical_gap,
+        );
+
+        let previous = std::mem::replace(&mut self.positions, self.layout.items.clone());
+        if let Some((_, timer)) = self.animation {
+            if animate && previous.len() == self.positions.len() && previous != self.positions {
+                self.positions.clone_from(&previous);
+                self.animation_start = (previous, Instant::now());
+                cx.start_timer(timer);
+            }
+        }
+
+        self.update_visible_range(cx);
+    }
+
+    fn update_visible_range(&mut self, cx: &mut EventContext) {
+        let height = cx.cache.get_height(cx.current());
+        if height == f32::MAX {
+            return;
+        }
+
+        let visible_height = height / cx.scale_factor();
+        let top = self.scroll_y * (self.layout.height - visible_height).max(0.0);
+        self.visible_range = self.layout.visible_range(top, top + visible_height);
+    }
+
+    // Moves the items from their previous positions towards those of the layout, easing out.
+    fn animate(&mut self) {
+        let Some((duration, _)) = self.animation else {
+            return;
+        };
+
+        let (from, start) = &self.animation_start;
+        let t = (start.elapsed().as_secs_f32() / duration.as_secs_f32()).min(1.0);
+        let t = 1.0 - (1.0 - t).powi(3);
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+
+        self.positions = from
+            .iter()
+            .zip(self.layout.items.iter())
+            .map(|(from, to)| BoundingBox {
+                x: lerp(from.x, to.x),
+                y: lerp(from.y, to.y),
+                w: lerp(from.w, to.w),
+                h: lerp(from.h, to.h),
+            })
+            .collect();
+    }
+}
+
+impl View for Masonry {
+    fn element(&self) -> Option<&'static str> {
+        Some("masonry")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|masonry_event, meta| match masonry_event {
+            MasonryInternalEvent::SetItems(aspect_ratios) => {
+                self.aspect_ratios.clone_from(aspect_ratios);
+                self.relayout(cx, false);
+                meta.consume();
+            }
+
+            MasonryInternalEvent::AnimationTick => {
+                self.animate();
+                meta.consume();
+            }
+        });
+
+        event.map(|masonry_event, meta| match masonry_event {
+            MasonryEvent::SetScrollY(scroll_y) => {
+                self.scroll_y = *scroll_y;
+                self.update_visible_range(cx);
+                meta.consume();
+            }
+        });
+
+        event.map(|window_event, _| match window_event {
+            WindowEvent::GeometryChanged(geo) => {
+                if geo.contains(GeoChanged::WIDTH_CHANGED) {
+                    self.relayout(cx, true);
+                } else if geo.contains(GeoChanged::HEIGHT_CHANGED) {
+                    self.update_visible_range(cx);
+                }
+            }
+
+            _ => {}
+        });
+    }
+}
+
+impl Handle<'_, Masonry> {
+    /// Sets a fixed number of columns.
+    pub fn columns(self, columns: usize) -> Self {
+        self.modify(|masonry: &mut Masonry| masonry.columns = MasonryColumns::Count(columns))
+    }
+
+    /// Sets the minimum width of the columns in logical pixels, with as many columns as fit in the masonry.
+    pub fn min_column_width(self, min_width: f32) -> Self {
+        self.modify(|masonry: &mut Masonry| masonry.columns = MasonryColumns::MinWidth(min_width))
+    }
+
+    /// Sets the duration over which the items move to their new positions when the masonry is resized.
+    ///
+    /// The items move to their new positions immediately by default.
+    pub fn animate_layout(self, duration: Duration) -> Self {
+        let timer = self.cx.add_timer(Duration::from_millis(16), Some(duration), |cx, action| {
+            if matches!(action, TimerAction::Tick(_) | TimerAction::Stop) {
+                cx.emit(MasonryInternalEvent::AnimationTick);
+            }
+        });
+
+        self.modify(|masonry: &mut Masonry| masonry.animation = Some((duration, timer)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds(x: f32, y: f32, w: f32, h: f32) -> BoundingBox {
+        BoundingBox { x, y, w, h }
+    }
+
+    #[test]
+    fn items_are_placed_in_the_shortest_column() {
+        let layout = MasonryLayout::new(
+            &[1.0, 2.0, 0.5, 1.0, 4.0],
+            210.0,
+            MasonryColumns::Count(2),
+            10.0,
+            5.0,
+        );
+
+        assert_eq!(layout.columns, 2);
+        assert_eq!(layout.column_width, 100.0);
+        assert_eq!(
+            layout.items,
+            [
+                bounds(0.0, 0.0, 100.0, 100.0),
+                bounds(110.0, 0.0, 100.0, 50.0),
+                bounds(110.0, 55.0, 100.0, 200.0),
+                bounds(0.0, 105.0, 100.0, 100.0),
+                bounds(0.0, 210.0, 100.0, 25.0),
+            ]
+        );
+        assert_eq!(layout.height, 255.0);
+    }
+
+    #[test]
+    fn column_count_follows_the_width() {
+        let columns = MasonryColumns::MinWidth(100.0);
+        assert_eq!(MasonryLayout::new(&[], 310.0, columns, 10.0, 0.0).columns, 2);
+        assert_eq!(MasonryLayout::new(&[], 330.0, columns, 10.0, 0.0).columns, 3);
+        assert_eq!(MasonryLayout::new(&[], 50.0, columns, 10.0, 0.0).columns, 1);
+        assert_eq!(MasonryLayout::new(&[], 0.0, MasonryColumns::Count(0), 0.0, 0.0).columns, 1);
+        assert_eq!(MasonryLayout::new(&[], 0.0, columns, 0.0, 0.0).height, 0.0);
+    }
+
+    #[test]
+    fn visible_range_covers_intersecting_items() {
+        let layout = MasonryLayout::new(&[1.0; 10], 100.0, MasonryColumns::Count(2), 0.0, 0.0);
+
+        // Each row holds two items of 50 pixels.
+        assert_eq!(layout.visible_range(0.0, 60.0), 0..4);
+        assert_eq!(layout.visible_range(100.0, 150.0), 4..6);
+        assert_eq!(layout.visible_range(240.0, 400.0), 8..10);
+        assert_eq!(layout.visible_range(300.0, 400.0), 0..0);
+    }
+}
@@ -107,8 +107,13 @@ impl Submenu {
                 (content)(cx).hoverable(false);
                 Svg::new(cx, ICON_CHEVRON_RIGHT).class("arrow").hoverable(false);
                 // });
+                let submenu = cx.current();
                 Binding::new(cx, Submenu::is_open, move |cx, is_open| {
-                    if is_open.get(cx) {
+                    let is_open = is_open.get(cx);
+                    cx.style.expanded.insert(submenu, is_open);
+                    cx.style.needs_access_update(submenu);
+
+                    if is_open {
                         Popup::new(cx, |cx| {
                             (menu)(cx);
                         })
@@ -133,6 +138,7 @@ impl Submenu {
                 // .on_press_down(|cx| cx.emit(MenuEvent::CloseAll));
                 // .on_blur(|cx| cx.emit(MenuEvent::CloseAll));
             })
+            .role(Role::MenuItem)
             .navigable(true)
             .checked(Submenu::is_open)
             .layout_type(LayoutType::Row)
@@ -195,6 +201,20 @@ impl View for Submenu {
                 _ => {}
             },
 
+            // Expanding or collapsing the submenu from assistive technologies toggles it like a press.
+            WindowEvent::ActionRequest(request) => {
+                let toggle = match request.action {
+                    Action::Expand => !self.is_open,
+                    Action::Collapse => self.is_open,
+                    _ => return,
+                };
+
+                if toggle {
+                    cx.emit(MenuEvent::ToggleOpen);
+                }
+                meta.consume();
+            }
+
             _ => {}
         });
 
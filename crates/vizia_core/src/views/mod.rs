@@ -3,6 +3,7 @@
 mod avatar;
 mod badge;
 mod button;
+mod cell_grid;
 mod checkbox;
 mod chip;
 mod combobox;
@@ -11,18 +12,24 @@ mod dialog;
 mod divider;
 mod dropdown;
 mod element;
+mod error_boundary;
 mod image;
 mod knob;
 mod label;
 mod list;
 mod markdown;
+mod masonry;
 mod menu;
 pub mod normalized_map;
+mod numeric_param;
+mod path;
 mod picklist;
 mod popup;
+mod portal;
 mod progressbar;
 mod radio;
 mod rating;
+mod roving_focus;
 mod scrollbar;
 mod scrollview;
 mod slider;
@@ -30,6 +37,7 @@ mod spinbox;
 mod stack;
 mod switch;
 mod tabview;
+mod text_span;
 mod textbox;
 mod toggle_button;
 mod tooltip;
@@ -40,6 +48,7 @@ pub use crate::binding::Binding;
 pub use avatar::*;
 pub use badge::*;
 pub use button::{Button, ButtonGroup, ButtonModifiers, ButtonVariant};
+pub use cell_grid::*;
 pub use checkbox::Checkbox;
 pub use chip::*;
 pub use combobox::*;
@@ -48,26 +57,39 @@ pub use dialog::*;
 pub use divider::*;
 pub use dropdown::Dropdown;
 pub use element::*;
+pub(crate) use error_boundary::{
+    catch_panic, defer_error, emit_deferred_errors, recover_from_panic,
+};
+pub use error_boundary::{ErrorBoundary, ErrorBoundaryEvent};
 pub use image::*;
 pub use knob::{ArcTrack, Knob, KnobMode, TickKnob, Ticks};
 pub use label::Label;
 pub use list::*;
 #[cfg(feature = "markdown")]
 pub use markdown::*;
+pub use masonry::*;
 pub use menu::*;
+pub use normalized_map::{DisplayDecimals, ValueScaling};
+pub use numeric_param::NumericParam;
+pub use path::{Path, PathBuilder};
 pub use picklist::*;
 pub use popup::*;
+pub use portal::{Portal, PortalHost};
 pub use progressbar::ProgressBar;
 pub use radio::RadioButton;
 pub use rating::Rating;
+pub(crate) use roving_focus::RovingFocusInternalEvent;
+pub use roving_focus::{RovingFocusEvent, RovingFocusGroup};
 pub use scrollbar::Scrollbar;
-pub use scrollview::{ScrollEvent, ScrollView};
+pub(crate) use scrollview::{scroll_axes, scroll_distance};
+pub use scrollview::{ScrollEdge, ScrollEvent, ScrollView};
 pub use slider::{NamedSlider, Slider};
 pub use spinbox::{Spinbox, SpinboxEvent, SpinboxIcons};
 pub use stack::{HStack, VStack, ZStack};
 pub use switch::Switch;
 pub use tabview::*;
-pub use textbox::{TextEvent, Textbox};
+pub use text_span::TextSpan;
+pub use textbox::{SelectOnFocus, TextEvent, Textbox};
 pub use toggle_button::ToggleButton;
 pub use tooltip::Tooltip;
 pub use virtual_list::*;
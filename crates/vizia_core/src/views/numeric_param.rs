@@ -0,0 +1,428 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use super::normalized_map::{DisplayDecimals, ValueScaling};
+use crate::prelude::*;
+
+/// A numeric parameter with a range, a default value and an optional step, which maps values to and from the
+/// normalized range `0.0..=1.0` used by controls such as sliders and knobs.
+///
+/// Values outside of the range are clamped to it, or wrapped around it for parameters such as angles. The mapping
+/// between values and normalized values is set by a [`ValueScaling`], which can skew the range so that more of a
+/// control is given to lower values, or map it logarithmically for audio frequencies.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// let cutoff = NumericParam::new(20.0, 20_000.0)
+///     .default_value(1_000.0)
+///     .scaling(ValueScaling::Frequency)
+///     .formatter(|hz| format!("{:.0} Hz", hz));
+///
+/// // Halfway along a control is the geometric mean of the range.
+/// assert_eq!(cutoff.format(cutoff.normalized_to_value(0.5)), "632 Hz");
+/// ```
+#[derive(Clone)]
+pub struct NumericParam {
+    min: f32,
+    max: f32,
+    default: f32,
+    step: Option<f32>,
+    wrap: bool,
+    scaling: ValueScaling,
+    display_decimals: DisplayDecimals,
+    formatter: Option<Arc<dyn Fn(f32) -> String + Send + Sync>>,
+    parser: Option<Arc<dyn Fn(&str) -> Option<f32> + Send + Sync>>,
+}
+
+impl NumericParam {
+    /// Creates a new parameter with the given range, which defaults to the minimum of the range.
+    ///
+    /// The parameter is continuous, clamps values to its range and is mapped linearly to normalized values.
+    pub fn new(min: f32, max: f32) -> Self {
+        assert!(min < max);
+
+        Self {
+            min,
+            max,
+            default: min,
+            step: None,
+            wrap: false,
+            scaling: ValueScaling::Linear,
+            display_decimals: DisplayDecimals::default(),
+            formatter: None,
+            parser: None,
+        }
+    }
+
+    /// Sets the range of the parameter, clamping the default value to the new range.
+    pub fn range(mut self, range: Range<f32>) -> Self {
+        assert!(range.start < range.end);
+
+        self.min = range.start;
+        self.max = range.end;
+        self.default = self.default.clamp(self.min, self.max);
+        self
+    }
+
+    /// Sets the default value of the parameter.
+    pub fn default_value(mut self, default: f32) -> Self {
+        self.default = self.constrain(default);
+        self
+    }
+
+    /// Sets the step between the values of the parameter, counted from the minimum of its range.
+    ///
+    /// The maximum of the range can always be reached, even if the range isn't a multiple of the step.
+    pub fn step(mut self, step: f32) -> Self {
+        assert!(step > 0.0);
+
+        self.step = Some(step);
+        self
+    }
+
+    /// Sets whether values outside of the range wrap around it rather than being clamped to it, such as for an angle.
+    ///
+    /// The minimum and maximum of the range of a wrapping parameter are the same value, and the minimum is used.
+    pub fn wrapping(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Sets how values are mapped to and from normalized values.
+    pub fn scaling(mut self, scaling: ValueScaling) -> Self {
+        self.scaling = scaling;
+        self
+    }
+
+    /// Sets the number of decimal places shown by [`format`](Self::format), if a formatter hasn't been set.
+    pub fn display_decimals(mut self, display_decimals: DisplayDecimals) -> Self {
+        self.display_decimals = display_decimals;
+        self
+    }
+
+    /// Sets the function used to display values of the parameter.
+    pub fn formatter<F>(mut self, formatter: F) -> Self
+    where
+        F: 'static + Fn(f32) -> String + Send + Sync,
+    {
+        self.formatter = Some(Arc::new(formatter));
+        self
+    }
+
+    /// Sets the function used to parse values of the parameter from text, such as text typed into a textbox.
+    pub fn parser<F>(mut self, parser: F) -> Self
+    where
+        F: 'static + Fn(&str) -> Option<f32> + Send + Sync,
+    {
+        self.parser = Some(Arc::new(parser));
+        self
+    }
+
+    /// Returns the minimum of the range of the parameter.
+    pub fn min(&self) -> f32 {
+        self.min
+    }
+
+    /// Returns the maximum of the range of the parameter.
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Returns the default value of the parameter.
+    pub fn get_default(&self) -> f32 {
+        self.default
+    }
+
+    /// Returns the step between the values of the parameter, or `None` if the parameter is continuous.
+    pub fn get_step(&self) -> Option<f32> {
+        self.step
+    }
+
+    /// Returns whether values outside of the range of the parameter wrap around it.
+    pub fn is_wrapping(&self) -> bool {
+        self.wrap
+    }
+
+    /// Returns how values of the parameter are mapped to and from normalized values.
+    pub fn get_scaling(&self) -> ValueScaling {
+        self.scaling
+    }
+
+    /// Clamps a value to the range of the parameter, or wraps it around the range if the parameter wraps.
+    pub fn constrain(&self, value: f32) -> f32 {
+        if self.wrap {
+            self.min + (value - self.min).rem_euclid(self.max - self.min)
+        } else {
+            value.clamp(self.min, self.max)
+        }
+    }
+
+    /// Clamps a normalized value to `0.0..=1.0`, or wraps it around if the parameter wraps.
+    pub fn constrain_normalized(&self, normalized: f32) -> f32 {
+        if self.wrap {
+            normalized.rem_euclid(1.0)
+        } else {
+            normalized.clamp(0.0, 1.0)
+        }
+    }
+
+    /// Snaps a value to the nearest step of the parameter, after constraining it to the range of the parameter.
+    ///
+    /// Values of a continuous parameter are only constrained.
+    pub fn snap(&self, value: f32) -> f32 {
+        let value = self.constrain(value);
+        let Some(step) = self.step else {
+            return value;
+        };
+
+        let snapped = self.min + ((value - self.min) / step).round() * step;
+
+        // The maximum isn't on the grid of steps when the range isn't a multiple of the step, but is still a value of
+        // the parameter.
+        if (self.max - value).abs() < (snapped - value).abs() {
+            self.constrain(self.max)
+        } else {
+            self.constrain(snapped.min(self.max))
+        }
+    }
+
+    /// Moves a value by a number of steps, or by hundredths of the normalized range if the parameter is continuous.
+    pub fn step_by(&self, value: f32, steps: i32) -> f32 {
+        let Some(step) = self.step else {
+            let normalized = self.value_to_normalized(value) + steps as f32 * 0.01;
+            return self.normalized_to_value(self.constrain_normalized(normalized));
+        };
+
+        // The number of steps from the minimum, which is only fractional for a maximum which isn't on the grid of
+        // steps. Stepping down from it moves to the last step below it.
+        let mut position = (self.snap(value) - self.min) / step;
+        if (position - position.round()).abs() < 1e-4 {
+            position = position.round();
+        }
+
+        let position = if steps < 0 { position.ceil() } else { position.floor() };
+        self.snap(self.min + (position + steps as f32) * step)
+    }
+
+    /// Maps a value of the parameter to a normalized value between `0.0` and `1.0`.
+    pub fn value_to_normalized(&self, value: f32) -> f32 {
+        self.scaling.value_to_normalized(self.constrain(value), self.min, self.max)
+    }
+
+    /// Maps a normalized value between `0.0` and `1.0` to a value of the parameter.
+    pub fn normalized_to_value(&self, normalized: f32) -> f32 {
+        self.scaling.normalized_to_value(normalized, self.min, self.max)
+    }
+
+    /// Snaps a normalized value to the normalized value of the nearest step of the parameter.
+    pub fn snap_normalized(&self, normalized: f32) -> f32 {
+        let normalized = self.constrain_normalized(normalized);
+        if self.step.is_none() {
+            return normalized;
+        }
+
+        self.value_to_normalized(self.snap(self.normalized_to_value(normalized)))
+    }
+
+    /// Returns the normalized default value of the parameter.
+    pub fn default_normalized(&self) -> f32 {
+        self.value_to_normalized(self.default)
+    }
+
+    /// Formats a value of the parameter for display.
+    pub fn format(&self, value: f32) -> String {
+        match &self.formatter {
+            Some(formatter) => (formatter)(value),
+            None => self.display_decimals.display_value(value),
+        }
+    }
+
+    /// Parses a value of the parameter from text, snapping it to the parameter. Returns `None` if the text isn't a
+    /// valid value.
+    pub fn parse(&self, text: &str) -> Option<f32> {
+        let value = match &self.parser {
+            Some(parser) => (parser)(text)?,
+            None => text.trim().parse().ok()?,
+        };
+
+        value.is_finite().then(|| self.snap(value))
+    }
+}
+
+impl Default for NumericParam {
+    fn default() -> Self {
+        NumericParam::new(0.0, 1.0)
+    }
+}
+
+impl std::fmt::Debug for NumericParam {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NumericParam")
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .field("default", &self.default)
+            .field("step", &self.step)
+            .field("wrap", &self.wrap)
+            .field("scaling", &self.scaling)
+            .field("display_decimals", &self.display_decimals)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Data for NumericParam {
+    fn same(&self, other: &Self) -> bool {
+        fn same_hook<T: ?Sized>(a: &Option<Arc<T>>, b: &Option<Arc<T>>) -> bool {
+            match (a, b) {
+                (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+        }
+
+        self.min == other.min
+            && self.max == other.max
+            && self.default == other.default
+            && self.step == other.step
+            && self.wrap == other.wrap
+            && self.scaling == other.scaling
+            && self.display_decimals == other.display_decimals
+            && same_hook(&self.formatter, &other.formatter)
+            && same_hook(&self.parser, &other.parser)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[track_caller]
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-4, "expected {expected}, got {actual}");
+    }
+
+    #[test]
+    fn linear_values_map_to_and_from_normalized_values() {
+        let param = NumericParam::new(-10.0, 30.0);
+
+        assert_close(param.value_to_normalized(-10.0), 0.0);
+        assert_close(param.value_to_normalized(0.0), 0.25);
+        assert_close(param.value_to_normalized(50.0), 1.0);
+        assert_close(param.normalized_to_value(0.75), 20.0);
+        assert_close(param.normalized_to_value(-0.5), -10.0);
+    }
+
+    #[test]
+    fn power_scaling_skews_the_range() {
+        let param = NumericParam::new(0.0, 100.0).scaling(ValueScaling::Power(2.0));
+
+        assert_close(param.normalized_to_value(0.5), 25.0);
+        assert_close(param.value_to_normalized(25.0), 0.5);
+        assert_close(param.value_to_normalized(param.normalized_to_value(0.3)), 0.3);
+    }
+
+    #[test]
+    fn frequency_scaling_is_logarithmic() {
+        let param = NumericParam::new(20.0, 20_000.0).scaling(ValueScaling::Frequency);
+
+        // Each third of the range covers a tenfold increase in frequency.
+        assert_close(param.normalized_to_value(0.0), 20.0);
+        assert!((param.normalized_to_value(1.0 / 3.0) - 200.0).abs() < 0.01);
+        assert!((param.normalized_to_value(2.0 / 3.0) - 2_000.0).abs() < 0.1);
+        assert!((param.normalized_to_value(0.5) - 632.456).abs() < 0.01);
+        assert_close(param.normalized_to_value(1.0), 20_000.0);
+
+        for normalized in [0.01, 0.25, 0.5, 0.75, 0.99] {
+            assert_close(
+                param.value_to_normalized(param.normalized_to_value(normalized)),
+                normalized,
+            );
+        }
+    }
+
+    #[test]
+    fn values_snap_to_the_nearest_step() {
+        let param = NumericParam::new(-1.0, 1.0).step(0.25);
+
+        assert_close(param.snap(0.1), 0.0);
+        assert_close(param.snap(0.13), 0.25);
+        assert_close(param.snap(-0.9), -1.0);
+        assert_close(param.snap(2.0), 1.0);
+        assert_close(param.snap(-2.0), -1.0);
+    }
+
+    #[test]
+    fn snapping_near_the_maximum_reaches_the_maximum() {
+        // The steps are 0.0, 0.3, 0.6 and 0.9, followed by the maximum.
+        let param = NumericParam::new(0.0, 1.0).step(0.3);
+
+        assert_close(param.snap(0.92), 0.9);
+        assert_close(param.snap(0.96), 1.0);
+        assert_close(param.snap(1.0), 1.0);
+        assert_close(param.step_by(0.9, 1), 1.0);
+        assert_close(param.step_by(1.0, 1), 1.0);
+        assert_close(param.step_by(1.0, -1), 0.9);
+    }
+
+    #[test]
+    fn snapping_follows_the_steps_of_scaled_parameters() {
+        let param = NumericParam::new(20.0, 20_000.0).scaling(ValueScaling::Frequency).step(10.0);
+
+        assert_close(param.snap(1_004.0), 1_000.0);
+        assert_close(param.snap(19_996.0), 20_000.0);
+        assert_close(param.snap(21.0), 20.0);
+        assert_close(param.snap_normalized(0.0), 0.0);
+        assert_close(param.snap_normalized(1.0), 1.0);
+
+        let snapped = param.normalized_to_value(param.snap_normalized(0.5));
+        assert_close(snapped, 630.0);
+    }
+
+    #[test]
+    fn wrapping_parameters_wrap_around_the_range() {
+        let param = NumericParam::new(0.0, 360.0).step(45.0).wrapping(true);
+
+        assert_close(param.constrain(370.0), 10.0);
+        assert_close(param.constrain(-90.0), 270.0);
+        assert_close(param.constrain(360.0), 0.0);
+        assert_close(param.snap(350.0), 0.0);
+        assert_close(param.step_by(315.0, 1), 0.0);
+        assert_close(param.step_by(0.0, -1), 315.0);
+        assert_close(param.constrain_normalized(1.25), 0.25);
+    }
+
+    #[test]
+    fn continuous_parameters_step_by_hundredths() {
+        let param = NumericParam::new(0.0, 200.0);
+
+        assert_close(param.step_by(100.0, 1), 102.0);
+        assert_close(param.step_by(100.0, -5), 90.0);
+        assert_close(param.step_by(199.0, 1), 200.0);
+    }
+
+    #[test]
+    fn default_values_are_constrained() {
+        let param = NumericParam::new(0.0, 10.0).default_value(15.0);
+        assert_close(param.get_default(), 10.0);
+        assert_close(param.default_normalized(), 1.0);
+
+        let param = param.range(0.0..5.0);
+        assert_close(param.get_default(), 5.0);
+    }
+
+    #[test]
+    fn values_are_formatted_and_parsed_with_hooks() {
+        let param = NumericParam::new(0.0, 1.0).step(0.01).display_decimals(DisplayDecimals::Two);
+        assert_eq!(param.format(0.5), "0.50");
+        assert_close(param.parse(" 0.333 ").unwrap(), 0.33);
+        assert_close(param.parse("4").unwrap(), 1.0);
+        assert_eq!(param.parse("half"), None);
+        assert_eq!(param.parse("NaN"), None);
+
+        let percent = param.formatter(|value| format!("{:.0}%", value * 100.0)).parser(|text| {
+            text.trim().trim_end_matches('%').parse::<f32>().ok().map(|v| v / 100.0)
+        });
+        assert_eq!(percent.format(0.25), "25%");
+        assert_close(percent.parse("75%").unwrap(), 0.75);
+    }
+}
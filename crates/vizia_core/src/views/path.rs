@@ -0,0 +1,298 @@
+use std::cell::RefCell;
+
+use log::warn;
+
+use crate::prelude::*;
+use crate::vg;
+
+/// A view which draws a vector path, such as a simple icon or indicator, scaled to fit its bounds.
+///
+/// The path is filled with the `fill` color and stroked with the `stroke` color, `stroke-width`, `stroke-linecap`
+/// and `stroke-linejoin` style properties. Nothing is drawn for a fill or stroke which isn't set.
+///
+/// # Examples
+///
+/// A path can be created from SVG path data:
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// Path::new(cx, "M 2 12 L 9 19 L 22 5")
+///     .view_box(BoundingBox::from_min_max(0.0, 0.0, 24.0, 24.0))
+///     .size(Pixels(24.0))
+///     .class("checkmark");
+/// ```
+///
+/// Or from a sequence of commands:
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// Path::with_commands(cx, |path| {
+///     path.move_to(0.0, 10.0).line_to(5.0, 0.0).line_to(10.0, 10.0).close();
+/// })
+/// .size(Pixels(16.0));
+/// ```
+///
+/// And styled with CSS:
+///
+/// ```css
+/// path.checkmark {
+///     stroke: #4caf50;
+///     stroke-width: 2px;
+///     stroke-linecap: round;
+///     stroke-linejoin: round;
+/// }
+/// ```
+pub struct Path {
+    path: vg::Path,
+    view_box: Option<BoundingBox>,
+    preserve_aspect_ratio: bool,
+    hit_test_path: bool,
+    // The path fitted to the bounds it was last drawn or hit-tested with, so that it is only transformed again when
+    // the bounds change.
+    fitted: RefCell<Option<(BoundingBox, vg::Path)>>,
+}
+
+impl Path {
+    /// Creates a new path view from SVG path data, such as `"M 0 0 L 10 10"`.
+    ///
+    /// Invalid path data is logged and draws nothing.
+    pub fn new<T>(cx: &mut Context, data: impl Res<T>) -> Handle<Self>
+    where
+        T: AsRef<str> + 'static,
+    {
+        Self::from_path(cx, vg::Path::new()).bind(data, |handle, data| {
+            let data = data.get(&handle);
+            let path = vg::Path::from_svg(data.as_ref()).unwrap_or_else(|| {
+                warn!("Invalid SVG path data '{}'", data.as_ref());
+                vg::Path::new()
+            });
+
+            handle.modify(|view| view.set_path(path));
+        })
+    }
+
+    /// Creates a new path view from the commands added to the given [`PathBuilder`].
+    pub fn with_commands(
+        cx: &mut Context,
+        commands: impl FnOnce(&mut PathBuilder),
+    ) -> Handle<Self> {
+        let mut builder = PathBuilder { path: vg::Path::new() };
+        (commands)(&mut builder);
+        Self::from_path(cx, builder.path)
+    }
+
+    fn from_path(cx: &mut Context, path: vg::Path) -> Handle<Self> {
+        Self {
+            path,
+            view_box: None,
+            preserve_aspect_ratio: true,
+            hit_test_path: false,
+            fitted: RefCell::new(None),
+        }
+        .build(cx, |_| {})
+    }
+
+    fn set_path(&mut self, path: vg::Path) {
+        self.path = path;
+        self.fitted.take();
+    }
+
+    // Returns the path transformed from its view box to the given bounds, transforming it again only if the bounds
+    // changed since it was last fitted.
+    fn fitted_path(&self, bounds: BoundingBox) -> vg::Path {
+        let mut fitted = self.fitted.borrow_mut();
+        if let Some((fitted_bounds, path)) = fitted.as_ref() {
+            if *fitted_bounds == bounds {
+                return path.clone();
+            }
+        }
+
+        let view_box = self.view_box.unwrap_or_else(|| {
+            let rect = self.path.bounds();
+            BoundingBox::from_min_max(rect.left, rect.top, rect.right, rect.bottom)
+        });
+
+        let path = fit_matrix(view_box, bounds, self.preserve_aspect_ratio)
+            .map(|matrix| self.path.with_transform(&matrix))
+            .unwrap_or_default();
+        *fitted = Some((bounds, path.clone()));
+        path
+    }
+}
+
+/// Builds the commands of a [`Path`] view.
+///
+/// Coordinates are in the coordinate space of the view box of the path.
+pub struct PathBuilder {
+    path: vg::Path,
+}
+
+impl PathBuilder {
+    /// Starts a new subpath at the given point.
+    pub fn move_to(&mut self, x: f32, y: f32) -> &mut Self {
+        self.path.move_to((x, y));
+        self
+    }
+
+    /// Adds a straight line to the given point.
+    pub fn line_to(&mut self, x: f32, y: f32) -> &mut Self {
+        self.path.line_to((x, y));
+        self
+    }
+
+    /// Adds a quadratic bezier curve to the given point with the given control point.
+    pub fn quad_to(&mut self, cx: f32, cy: f32, x: f32, y: f32) -> &mut Self {
+        self.path.quad_to((cx, cy), (x, y));
+        self
+    }
+
+    /// Adds a cubic bezier curve to the given point with the given control points.
+    pub fn cubic_to(
+        &mut self,
+        c1x: f32,
+        c1y: f32,
+        c2x: f32,
+        c2y: f32,
+        x: f32,
+        y: f32,
+    ) -> &mut Self {
+        self.path.cubic_to((c1x, c1y), (c2x, c2y), (x, y));
+        self
+    }
+
+    /// Closes the current subpath with a straight line to its start.
+    pub fn close(&mut self) -> &mut Self {
+        self.path.close();
+        self
+    }
+}
+
+impl Handle<'_, Path> {
+    /// Sets the region of the path coordinate space which is scaled to the bounds of the view. Defaults to the
+    /// bounds of the path.
+    pub fn view_box(self, view_box: BoundingBox) -> Self {
+        self.modify(|path| {
+            path.view_box = Some(view_box);
+            path.fitted.take();
+        })
+    }
+
+    /// Sets whether the path keeps its aspect ratio when scaled, centering it within the bounds of the view.
+    /// Defaults to true.
+    pub fn preserve_aspect_ratio(self, preserve_aspect_ratio: bool) -> Self {
+        self.modify(|path| {
+            path.preserve_aspect_ratio = preserve_aspect_ratio;
+            path.fitted.take();
+        })
+    }
+
+    /// Sets whether the view is only hovered when the mouse is within the filled area of the path, rather than
+    /// anywhere within the bounds of the view. Defaults to false.
+    pub fn hit_test_path(self, hit_test_path: bool) -> Self {
+        self.modify(|path| path.hit_test_path = hit_test_path)
+    }
+}
+
+impl View for Path {
+    fn element(&self) -> Option<&'static str> {
+        Some("path")
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
+        cx.draw_background(canvas);
+        cx.draw_shadows(canvas);
+        cx.draw_border(canvas);
+        cx.draw_outline(canvas);
+
+        let bounds = cx.bounds();
+        if bounds.w == 0.0 || bounds.h == 0.0 {
+            return;
+        }
+
+        let path = self.fitted_path(bounds);
+
+        let mut paint = vg::Paint::default();
+        paint.set_anti_alias(true);
+
+        let fill = cx.fill();
+        if fill.a() > 0 {
+            paint.set_color(fill);
+            canvas.draw_path(&path, &paint);
+        }
+
+        let stroke = cx.stroke();
+        let stroke_width = cx.stroke_width();
+        if stroke.a() > 0 && stroke_width > 0.0 {
+            paint.set_style(vg::PaintStyle::Stroke);
+            paint.set_color(stroke);
+            paint.set_stroke_width(stroke_width);
+            paint.set_stroke_cap(cx.stroke_linecap().into());
+            paint.set_stroke_join(cx.stroke_linejoin().into());
+            canvas.draw_path(&path, &paint);
+        }
+    }
+
+    fn hit_test(&self, bounds: BoundingBox, x: f32, y: f32) -> bool {
+        !self.hit_test_path || self.fitted_path(bounds).contains((x, y))
+    }
+}
+
+// Returns the matrix which maps the view box onto the bounds, or `None` if the view box has no area.
+fn fit_matrix(
+    view_box: BoundingBox,
+    bounds: BoundingBox,
+    preserve_aspect_ratio: bool,
+) -> Option<vg::Matrix> {
+    if view_box.w <= 0.0 || view_box.h <= 0.0 {
+        return None;
+    }
+
+    let (mut sx, mut sy) = (bounds.w / view_box.w, bounds.h / view_box.h);
+    if preserve_aspect_ratio {
+        sx = sx.min(sy);
+        sy = sx;
+    }
+
+    let tx = bounds.x + (bounds.w - view_box.w * sx) / 2.0 - view_box.x * sx;
+    let ty = bounds.y + (bounds.h - view_box.h * sy) / 2.0 - view_box.y * sy;
+
+    Some(vg::Matrix::new_all(sx, 0.0, tx, 0.0, sy, ty, 0.0, 0.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map(matrix: vg::Matrix, x: f32, y: f32) -> (f32, f32) {
+        let point = matrix.map_point((x, y));
+        (point.x, point.y)
+    }
+
+    #[test]
+    fn view_box_is_stretched_to_bounds() {
+        let view_box = BoundingBox::from_min_max(10.0, 10.0, 30.0, 20.0);
+        let bounds = BoundingBox { x: 100.0, y: 50.0, w: 40.0, h: 40.0 };
+        let matrix = fit_matrix(view_box, bounds, false).unwrap();
+
+        assert_eq!(map(matrix, 10.0, 10.0), (100.0, 50.0));
+        assert_eq!(map(matrix, 30.0, 20.0), (140.0, 90.0));
+    }
+
+    #[test]
+    fn preserved_aspect_ratio_centers_view_box() {
+        let view_box = BoundingBox::from_min_max(10.0, 10.0, 30.0, 20.0);
+        let bounds = BoundingBox { x: 100.0, y: 50.0, w: 40.0, h: 40.0 };
+        let matrix = fit_matrix(view_box, bounds, true).unwrap();
+
+        assert_eq!(map(matrix, 10.0, 10.0), (100.0, 60.0));
+        assert_eq!(map(matrix, 30.0, 20.0), (140.0, 80.0));
+    }
+
+    #[test]
+    fn empty_view_box_is_not_fitted() {
+        let bounds = BoundingBox { x: 0.0, y: 0.0, w: 40.0, h: 40.0 };
+        assert!(fit_matrix(BoundingBox::default(), bounds, true).is_none());
+    }
+}
@@ -40,6 +40,8 @@ pub enum PopupEvent {
 #[derive(Lens)]
 pub struct Popup {
     placement: Placement,
+    // The placement of the popup after it was repositioned to fit within the window.
+    shift: Placement,
     show_arrow: bool,
     arrow_size: Length,
     should_reposition: bool,
@@ -49,6 +51,7 @@ impl Popup {
     pub fn new(cx: &mut Context, content: impl FnOnce(&mut Context)) -> Handle<Self> {
         Self {
             placement: Placement::Bottom,
+            shift: Placement::Bottom,
             show_arrow: true,
             arrow_size: Length::Value(LengthValue::Px(0.0)),
             should_reposition: true,
@@ -61,6 +64,7 @@ impl Popup {
                 }
             });
         })
+        .layer(Layer::Popup)
         .position_type(PositionType::Absolute)
         .space(Pixels(0.0))
     }
@@ -83,7 +87,13 @@ impl View for Popup {
                 let scale = cx.scale_factor();
                 let arrow_size = self.arrow_size.to_px().unwrap() * cx.scale_factor();
 
-                let shift = if self.should_reposition {
+                let shift = if self.placement == Placement::AutoVertical {
+                    vertical_placement(
+                        parent_bounds.top() - window_bounds.top(),
+                        window_bounds.bottom() - parent_bounds.bottom(),
+                        bounds.height() + arrow_size,
+                    )
+                } else if self.should_reposition {
                     let mut available = AvailablePlacement::all();
 
                     let top_start_bounds = BoundingBox::from_min_max(
@@ -229,6 +239,7 @@ impl View for Popup {
                     }
                     self.placement
                 };
+                self.shift = shift;
 
                 let arrow_size = self.arrow_size.to_px().unwrap();
 
@@ -297,6 +308,8 @@ pub enum Placement {
     LeftEnd,
     Over,
     Cursor,
+    /// Below the parent element, or above it if there isn't enough room below.
+    AutoVertical,
 }
 
 impl_res_simple!(Placement);
@@ -355,7 +368,8 @@ impl Placement {
             10 => Placement::Left,
             11 => Placement::LeftEnd,
             12 => Placement::Over,
-            _ => Placement::Cursor,
+            13 => Placement::Cursor,
+            _ => Placement::AutoVertical,
         }
     }
 
@@ -364,6 +378,16 @@ impl Placement {
             return *self;
         }
 
+        if *self == Placement::AutoVertical {
+            return if !available.contains(AvailablePlacement::BOTTOM)
+                && available.contains(AvailablePlacement::TOP)
+            {
+                Placement::Top
+            } else {
+                Placement::Bottom
+            };
+        }
+
         if available.is_empty() {
             return Placement::Over;
         }
@@ -412,13 +436,17 @@ impl Placement {
 }
 
 impl Handle<'_, Popup> {
-    /// Sets the position where the tooltip should appear relative to its parent element.
+    /// Sets the position where the popup should appear relative to its parent element.
     /// Defaults to `Placement::Bottom`.
+    ///
+    /// With `Placement::AutoVertical` the popup is placed below its parent element, or above it when there isn't
+    /// enough room below, regardless of [`should_reposition`](Self::should_reposition).
     pub fn placement(self, placement: impl Res<Placement>) -> Self {
         self.bind(placement, |handle, placement| {
             let placement = placement.get(&handle);
             handle.modify(|popup| {
                 popup.placement = placement;
+                popup.shift = placement;
             });
         })
     }
@@ -473,13 +501,24 @@ impl Handle<'_, Popup> {
     }
 }
 
+// Returns whether a popup with the given height, including the arrow, should be placed below or above its parent
+// element. The popup is placed below unless it only fits above, or it fits on neither side and there is more room
+// above.
+fn vertical_placement(space_above: f32, space_below: f32, height: f32) -> Placement {
+    if height <= space_below || (height > space_above && space_below >= space_above) {
+        Placement::Bottom
+    } else {
+        Placement::Top
+    }
+}
+
 /// An arrow view used by the Popup view.
 pub(crate) struct Arrow {}
 
 impl Arrow {
     pub(crate) fn new(cx: &mut Context) -> Handle<Self> {
         Self {}.build(cx, |_| {}).position_type(PositionType::Absolute).bind(
-            Popup::placement,
+            Popup::shift,
             |mut handle, placement| {
                 let (t, b) = match placement.get(&handle) {
                     Placement::TopStart | Placement::Top | Placement::TopEnd => {
@@ -544,7 +583,7 @@ impl View for Arrow {
     fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
         let bounds = cx.bounds();
         let mut path = vg::Path::new();
-        match Popup::placement.get(cx) {
+        match Popup::shift.get(cx) {
             Placement::Bottom | Placement::BottomStart | Placement::BottomEnd => {
                 path.move_to(bounds.bottom_left());
                 path.line_to(bounds.center_top());
@@ -583,3 +622,29 @@ impl View for Arrow {
         canvas.draw_path(&path, &paint);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auto_vertical_flips_above_when_there_is_no_room_below() {
+        assert_eq!(vertical_placement(100.0, 300.0, 200.0), Placement::Bottom);
+        assert_eq!(vertical_placement(300.0, 100.0, 200.0), Placement::Top);
+        // When the popup fits on neither side it is placed on the side with more room.
+        assert_eq!(vertical_placement(150.0, 100.0, 200.0), Placement::Top);
+        assert_eq!(vertical_placement(100.0, 150.0, 200.0), Placement::Bottom);
+    }
+
+    #[test]
+    fn auto_vertical_places_with_available_placements() {
+        let available = AvailablePlacement::all();
+        assert_eq!(Placement::AutoVertical.place(available), Placement::Bottom);
+
+        let available = AvailablePlacement::all() - AvailablePlacement::BOTTOM;
+        assert_eq!(Placement::AutoVertical.place(available), Placement::Top);
+
+        let available = AvailablePlacement::empty();
+        assert_eq!(Placement::AutoVertical.place(available), Placement::Bottom);
+    }
+}
@@ -0,0 +1,227 @@
+use std::cmp::Reverse;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use log::warn;
+use vizia_storage::ChildIterator;
+
+use crate::prelude::*;
+
+// Orders the content of portals with the same priority by when the portals were built.
+static NEXT_PORTAL: AtomicUsize = AtomicUsize::new(0);
+
+/// A view which displays the content of the [`Portal`]s targeting its id.
+///
+/// The content of each portal is laid out and styled as children of the host. Content with a higher
+/// [`priority`](Handle::priority) is placed first, and otherwise content is placed in the order that the portals
+/// were built.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// HStack::new(cx, |cx| {
+///     PortalHost::new(cx).id("toolbar-slot");
+/// });
+///
+/// VStack::new(cx, |cx| {
+///     Portal::into(cx, "toolbar-slot", |cx| {
+///         Button::new(cx, |cx| Label::new(cx, "Plugin Action"));
+///     });
+/// });
+/// ```
+pub struct PortalHost {}
+
+impl PortalHost {
+    /// Creates a new portal host. The host must be given an id, with the `id()` modifier, for portals to target it.
+    pub fn new(cx: &mut Context) -> Handle<Self> {
+        Self {}.build(cx, |_| {})
+    }
+}
+
+impl View for PortalHost {
+    fn element(&self) -> Option<&'static str> {
+        Some("portal-host")
+    }
+}
+
+/// A view which builds its content within a [`PortalHost`] elsewhere in the tree.
+///
+/// Although the content is a child of the host for layout and styling, it resolves lenses and models through the
+/// portal, as if it had been built in place. Events emitted by the content propagate up from the host. Removing
+/// the portal removes its content from the host.
+pub struct Portal {
+    // The entity within the host which the content of the portal is built in.
+    content: Entity,
+}
+
+impl Portal {
+    /// Creates a new portal which builds its content within the host with the given id.
+    ///
+    /// The host must have been built before the portal. If there is no host with the given id then the content is
+    /// not built.
+    pub fn into<'a>(
+        cx: &'a mut Context,
+        host: &str,
+        content: impl FnOnce(&mut Context),
+    ) -> Handle<'a, Self> {
+        let portal = Self { content: Entity::null() }.build(cx, |_| {}).ignore().entity();
+
+        if let Some(host_entity) = cx.resolve_entity_identifier(host) {
+            let sequence = NEXT_PORTAL.fetch_add(1, Ordering::Relaxed);
+            let content_entity = cx.with_current(host_entity, |cx| {
+                PortalContent { priority: 0, sequence }.build(cx, |_| {}).ignore().entity()
+            });
+
+            // The data parent is set before building the content so that its lenses resolve through the portal.
+            cx.tree.set_data_parent(content_entity, Some(portal));
+            place_portal_content(cx, content_entity);
+            cx.with_current(content_entity, content);
+
+            if let Some(view) =
+                cx.views.get_mut(&portal).and_then(|view| view.downcast_mut::<Self>())
+            {
+                view.content = content_entity;
+            }
+        } else {
+            warn!("No portal host with the id '{}'", host);
+        }
+
+        Handle { current: portal, entity: portal, p: Default::default(), cx }
+    }
+
+    /// Returns the entity within the host which the content of the portal is built in.
+    pub(crate) fn content(&self) -> Entity {
+        self.content
+    }
+}
+
+impl View for Portal {}
+
+impl Handle<'_, Portal> {
+    /// Sets the priority of the content of the portal within its host. Content with a higher priority is placed
+    /// before content with a lower priority. Defaults to 0.
+    pub fn priority(self, priority: i32) -> Self {
+        let content = self
+            .cx
+            .views
+            .get(&self.entity)
+            .and_then(|view| view.downcast_ref::<Portal>())
+            .map(|portal| portal.content);
+
+        if let Some(content) = content {
+            if let Some(portal_content) = self
+                .cx
+                .views
+                .get_mut(&content)
+                .and_then(|view| view.downcast_mut::<PortalContent>())
+            {
+                portal_content.priority = priority;
+                place_portal_content(self.cx, content);
+            }
+        }
+
+        self
+    }
+}
+
+// The content of a portal within its host, which is ignored by layout so that the content is laid out by the host.
+struct PortalContent {
+    priority: i32,
+    sequence: usize,
+}
+
+impl View for PortalContent {}
+
+// Moves the content of a portal to its position among the children of its host.
+fn place_portal_content(cx: &mut Context, content: Entity) {
+    let order = |cx: &Context, entity: Entity| {
+        cx.views
+            .get(&entity)
+            .and_then(|view| view.downcast_ref::<PortalContent>())
+            .map(|content| (Reverse(content.priority), content.sequence))
+    };
+
+    let Some(host) = cx.tree.get_parent(content) else {
+        return;
+    };
+
+    let Some(content_order) = order(cx, content) else {
+        return;
+    };
+
+    let children =
+        ChildIterator::new(&cx.tree, host).filter(|child| *child != content).collect::<Vec<_>>();
+    let next =
+        children.iter().find(|child| order(cx, **child).is_some_and(|order| order > content_order));
+
+    let _ = match (next, children.last()) {
+        (Some(next), _) => cx.tree.set_prev_sibling(*next, content),
+        (None, Some(last)) => cx.tree.set_next_sibling(*last, content),
+        (None, None) => Ok(()),
+    };
+
+    cx.style.needs_relayout_entity(host);
+    cx.style.needs_restyle(host);
+    cx.style.needs_children_restyle(host);
+    cx.needs_redraw(host);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Lens)]
+    struct PluginData {
+        name: String,
+    }
+
+    impl Model for PluginData {}
+
+    #[test]
+    fn portal_content_resolves_data_through_portal() {
+        let mut cx = Context::default();
+        let host = PortalHost::new(&mut cx).id("slot").entity();
+
+        let mut portal = Entity::null();
+        let mut label = Entity::null();
+        VStack::new(&mut cx, |cx| {
+            PluginData { name: String::from("plugin") }.build(cx);
+            portal = Portal::into(cx, "slot", |cx| {
+                label = Label::new(cx, PluginData::name).entity();
+            })
+            .entity();
+        });
+
+        assert_eq!(cx.tree.get_layout_parent(label), Some(host));
+        assert_eq!(cx.style.text.get(label).map(String::as_str), Some("plugin"));
+
+        cx.remove(portal);
+        assert!(!cx.entity_manager.is_alive(label));
+        assert_eq!(cx.tree.get_first_child(host), None);
+    }
+
+    #[test]
+    fn portal_content_is_ordered_by_priority() {
+        let mut cx = Context::default();
+        let host = PortalHost::new(&mut cx).id("slot").entity();
+
+        let first = Portal::into(&mut cx, "slot", |cx| {
+            Element::new(cx);
+        })
+        .entity();
+        let second = Portal::into(&mut cx, "slot", |cx| {
+            Element::new(cx);
+        })
+        .priority(1)
+        .entity();
+        let third = Portal::into(&mut cx, "slot", |cx| {
+            Element::new(cx);
+        })
+        .entity();
+
+        let portals = ChildIterator::new(&cx.tree, host)
+            .filter_map(|content| cx.tree.get_data_parent(content))
+            .collect::<Vec<_>>();
+        assert_eq!(portals, vec![second, first, third]);
+    }
+}
@@ -0,0 +1,391 @@
+use crate::prelude::*;
+
+/// Events for changing the active item of a [`RovingFocusGroup`].
+pub enum RovingFocusEvent {
+    /// Makes the item at the given index the active item, without focusing it.
+    SetActive(usize),
+    /// Makes the item at the given index the active item and focuses it.
+    Focus(usize),
+}
+
+// Emitted by a view made an item with `roving_focus_item()`, which propagates up to the group containing it.
+pub(crate) enum RovingFocusInternalEvent {
+    Register,
+}
+
+/// A container which makes its items a single stop in the keyboard navigation order, with the arrow keys moving
+/// focus between the items.
+///
+/// Views within the group become items with the [`roving_focus_item`](AbilityModifiers::roving_focus_item)
+/// modifier. Only the active item can be navigated to with `tab`, and the arrow keys of the orientation of the
+/// group move the focus to the previous or next item, with `home` and `end` moving it to the first and last items.
+/// Keys which activate a focused view, such as `enter` and `space`, are handled by the active item itself.
+///
+/// The active item is kept when items are added to or removed from the group. If the active item is removed, the
+/// item now at its index becomes active once an item is added or the group next handles an event. The index of the
+/// active item can be bound to with the [`active_index`](RovingFocusGroup::active_index) lens.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// RovingFocusGroup::new(cx, |cx| {
+///     Button::new(cx, |cx| Label::new(cx, "Cut")).roving_focus_item();
+///     Button::new(cx, |cx| Label::new(cx, "Copy")).roving_focus_item();
+///     Button::new(cx, |cx| Label::new(cx, "Paste")).roving_focus_item();
+/// })
+/// .role(Role::Toolbar);
+/// ```
+#[derive(Lens)]
+pub struct RovingFocusGroup {
+    active_index: usize,
+    orientation: Orientation,
+    wrap: bool,
+
+    // The items of the group in tree order, and the active item, which is `None` until the first item is added.
+    #[lens(ignore)]
+    items: Vec<Entity>,
+    #[lens(ignore)]
+    active: Option<Entity>,
+    #[lens(ignore)]
+    on_change: Option<Box<dyn Fn(&mut EventContext, usize)>>,
+}
+
+impl RovingFocusGroup {
+    /// Creates a new roving focus group with the given content, whose items are arranged horizontally.
+    pub fn new(cx: &mut Context, content: impl FnOnce(&mut Context)) -> Handle<Self> {
+        Self {
+            active_index: 0,
+            orientation: Orientation::Horizontal,
+            wrap: true,
+            items: Vec::new(),
+            active: None,
+            on_change: None,
+        }
+        .build(cx, content)
+    }
+
+    // Drops the items which have been removed and puts the rest in tree order, then picks the active item again.
+    fn update_items(&mut self, cx: &mut EventContext) {
+        let items = std::mem::take(&mut self.items);
+        self.items =
+            cx.current().branch_iter(cx.tree).filter(|entity| items.contains(entity)).collect();
+
+        if self.items.is_empty() {
+            self.active = None;
+            return;
+        }
+
+        match self.active.and_then(|active| self.items.iter().position(|item| *item == active)) {
+            Some(index) => self.active_index = index,
+            None => {
+                self.active_index = self.active_index.min(self.items.len() - 1);
+                self.active = Some(self.items[self.active_index]);
+            }
+        }
+
+        // Only the active item is in the navigation order.
+        for item in self.items.iter() {
+            if let Some(abilities) = cx.style.abilities.get_mut(*item) {
+                abilities.set(Abilities::NAVIGABLE, Some(*item) == self.active);
+            }
+        }
+    }
+
+    fn set_active(&mut self, cx: &mut EventContext, index: usize) {
+        if let Some(item) = self.items.get(index).copied() {
+            self.active_index = index;
+            self.active = Some(item);
+            self.update_items(cx);
+        }
+    }
+
+    fn focus_item(&mut self, cx: &mut EventContext, index: usize) {
+        self.set_active(cx, index);
+        if let Some(item) = self.active {
+            cx.with_current(item, |cx| cx.focus_with_visibility(true));
+        }
+    }
+
+    // Returns the index of the item which is, or contains, the given entity.
+    fn item_containing(&self, cx: &EventContext, entity: Entity) -> Option<usize> {
+        self.items
+            .iter()
+            .position(|item| *item == entity || entity.is_descendant_of(cx.tree, *item))
+    }
+
+    // Returns the index of the item to focus for a key pressed on the item at the given index.
+    fn navigate(&self, code: Code, index: usize) -> Option<usize> {
+        let last = self.items.len().checked_sub(1)?;
+        let (prev, next) = match self.orientation {
+            Orientation::Horizontal => (Code::ArrowLeft, Code::ArrowRight),
+            Orientation::Vertical => (Code::ArrowUp, Code::ArrowDown),
+        };
+
+        match code {
+            Code::Home => Some(0),
+            Code::End => Some(last),
+            code if code == prev => match index.checked_sub(1) {
+                Some(prev) => Some(prev),
+                None => self.wrap.then_some(last),
+            },
+            code if code == next => {
+                if index < last {
+                    Some(index + 1)
+                } else {
+                    self.wrap.then_some(0)
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+impl View for RovingFocusGroup {
+    fn element(&self) -> Option<&'static str> {
+        Some("roving-focus-group")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|roving_focus_event, meta| match roving_focus_event {
+            RovingFocusInternalEvent::Register => {
+                if !self.items.contains(&meta.origin) {
+                    self.items.push(meta.origin);
+                }
+                self.update_items(cx);
+                meta.consume();
+            }
+        });
+
+        event.map(|roving_focus_event, meta| {
+            self.update_items(cx);
+            match roving_focus_event {
+                RovingFocusEvent::SetActive(index) => self.set_active(cx, *index),
+                RovingFocusEvent::Focus(index) => self.focus_item(cx, *index),
+            }
+            meta.consume();
+        });
+
+        event.map(|window_event, meta| match window_event {
+            // Only keys pressed while an item itself is focused move the focus, so the views within an item keep
+            // their own use of the arrow keys.
+            WindowEvent::KeyDown(code, _) => {
+                self.update_items(cx);
+                let focused = cx.focused();
+                let Some(index) = self.items.iter().position(|item| *item == focused) else {
+                    return;
+                };
+
+                if let Some(next) = self.navigate(*code, index) {
+                    self.focus_item(cx, next);
+                    if next != index {
+                        if let Some(callback) = &self.on_change {
+                            (callback)(cx, next);
+                        }
+                    }
+                    meta.consume();
+                }
+            }
+
+            // An item focused with the mouse, or with `tab`, becomes the active item.
+            WindowEvent::PressDown { .. } | WindowEvent::FocusIn => {
+                self.update_items(cx);
+                if let Some(index) = self.item_containing(cx, meta.target) {
+                    self.set_active(cx, index);
+                }
+            }
+
+            _ => {}
+        });
+    }
+}
+
+impl Handle<'_, RovingFocusGroup> {
+    /// Sets the orientation of the group, which decides the arrow keys which move the focus.
+    pub fn orientation<U: Into<Orientation>>(self, orientation: impl Res<U>) -> Self {
+        self.bind(orientation, |handle, orientation| {
+            let orientation = orientation.get(&handle).into();
+            handle.modify(|group: &mut RovingFocusGroup| group.orientation = orientation);
+        })
+    }
+
+    /// Sets whether moving past the last item focuses the first item, and the reverse. Defaults to true.
+    pub fn wrap<U: Into<bool>>(self, wrap: impl Res<U>) -> Self {
+        self.bind(wrap, |handle, wrap| {
+            let wrap = wrap.get(&handle).into();
+            handle.modify(|group: &mut RovingFocusGroup| group.wrap = wrap);
+        })
+    }
+
+    /// Sets the index of the active item, without focusing it.
+    pub fn with_active_index<U: Into<usize>>(mut self, index: impl Res<U>) -> Self {
+        let entity = self.entity();
+        index.set_or_bind(self.context(), entity, |cx, index| {
+            let index = index.get(cx).into();
+            cx.emit(RovingFocusEvent::SetActive(index));
+        });
+
+        self
+    }
+
+    /// Sets a callback which is called with the index of the active item when the user moves the focus to another
+    /// item with the keyboard.
+    pub fn on_change(self, callback: impl Fn(&mut EventContext, usize) + 'static) -> Self {
+        self.modify(|group: &mut RovingFocusGroup| group.on_change = Some(Box::new(callback)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::events::EventManager;
+
+    fn press(cx: &mut Context, event_manager: &mut EventManager, code: Code) {
+        cx.emit_custom(Event::new(WindowEvent::KeyDown(code, None)).target(Entity::root()));
+        cx.emit_custom(Event::new(WindowEvent::KeyUp(code, None)).target(Entity::root()));
+        event_manager.flush_events(cx, |_| {});
+    }
+
+    fn is_navigable(cx: &Context, entity: Entity) -> bool {
+        cx.style
+            .abilities
+            .get(entity)
+            .is_some_and(|abilities| abilities.contains(Abilities::NAVIGABLE))
+    }
+
+    fn active_index(cx: &Context, group: Entity) -> usize {
+        let view = cx.views.get(&group).and_then(|view| view.downcast_ref::<RovingFocusGroup>());
+        view.unwrap().active_index
+    }
+
+    // Builds a group of three items, returning the group, the items and the indices of the active item as it
+    // changes.
+    fn build_group(
+        cx: &mut Context,
+        orientation: Orientation,
+        wrap: bool,
+    ) -> (Entity, Vec<Entity>, Rc<RefCell<Vec<usize>>>) {
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let log = changes.clone();
+        let mut items = Vec::new();
+        let group = RovingFocusGroup::new(cx, |cx| {
+            for _ in 0..3 {
+                items.push(Element::new(cx).roving_focus_item().entity());
+            }
+            // Views which aren't items are skipped.
+            Element::new(cx).focusable(true);
+        })
+        .orientation(orientation)
+        .wrap(wrap)
+        .on_change(move |_, index| log.borrow_mut().push(index))
+        .entity();
+
+        (group, items, changes)
+    }
+
+    // Presses each key in turn, returning the index of the focused item after each one.
+    fn press_keys(
+        cx: &mut Context,
+        event_manager: &mut EventManager,
+        items: &[Entity],
+        codes: &[Code],
+    ) -> Vec<usize> {
+        codes
+            .iter()
+            .map(|code| {
+                press(cx, event_manager, *code);
+                items.iter().position(|item| *item == cx.focused()).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn only_the_active_item_is_navigable() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+        let (group, items, changes) = build_group(&mut cx, Orientation::Horizontal, true);
+        event_manager.flush_events(&mut cx, |_| {});
+
+        assert!(is_navigable(&cx, items[0]));
+        assert!(!is_navigable(&cx, items[1]) && !is_navigable(&cx, items[2]));
+
+        cx.emit_custom(Event::new(RovingFocusEvent::SetActive(2)).direct(group));
+        event_manager.flush_events(&mut cx, |_| {});
+        assert!(is_navigable(&cx, items[2]));
+        assert!(!is_navigable(&cx, items[0]));
+        assert_eq!(active_index(&cx, group), 2);
+        assert!(changes.borrow().is_empty());
+    }
+
+    #[test]
+    fn arrow_keys_move_focus_between_items() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+        let (group, items, changes) = build_group(&mut cx, Orientation::Horizontal, true);
+
+        cx.emit_custom(Event::new(RovingFocusEvent::Focus(0)).direct(group));
+        event_manager.flush_events(&mut cx, |_| {});
+        assert_eq!(cx.focused(), items[0]);
+
+        // Moving past the last item wraps around, and the keys of the other orientation are ignored.
+        let codes = [
+            Code::ArrowRight,
+            Code::ArrowRight,
+            Code::ArrowRight,
+            Code::ArrowLeft,
+            Code::End,
+            Code::Home,
+            Code::ArrowDown,
+        ];
+        let focused = press_keys(&mut cx, &mut event_manager, &items, &codes);
+        assert_eq!(focused, [1, 2, 0, 2, 2, 0, 0]);
+        assert_eq!(*changes.borrow(), [1, 2, 0, 2, 0]);
+        assert!(is_navigable(&cx, items[0]));
+        assert!(!is_navigable(&cx, items[2]));
+    }
+
+    #[test]
+    fn vertical_group_without_wrapping() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+        let (group, items, _) = build_group(&mut cx, Orientation::Vertical, false);
+
+        cx.emit_custom(Event::new(RovingFocusEvent::Focus(0)).direct(group));
+        event_manager.flush_events(&mut cx, |_| {});
+
+        let codes =
+            [Code::ArrowUp, Code::ArrowDown, Code::ArrowRight, Code::ArrowDown, Code::ArrowDown];
+        let focused = press_keys(&mut cx, &mut event_manager, &items, &codes);
+        assert_eq!(focused, [0, 1, 1, 2, 2]);
+    }
+
+    #[test]
+    fn active_item_is_kept_when_items_change() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+        let (group, items, _) = build_group(&mut cx, Orientation::Horizontal, true);
+        cx.emit_custom(Event::new(RovingFocusEvent::SetActive(1)).direct(group));
+        event_manager.flush_events(&mut cx, |_| {});
+
+        // Removing an item before the active item keeps the same item active.
+        cx.remove(items[0]);
+        let first_added =
+            cx.with_current(group, |cx| Element::new(cx).roving_focus_item().entity());
+        event_manager.flush_events(&mut cx, |_| {});
+        assert!(is_navigable(&cx, items[1]));
+        assert!(!is_navigable(&cx, first_added));
+        assert_eq!(active_index(&cx, group), 0);
+
+        // Removing the active item makes the item now at its index active.
+        cx.remove(items[1]);
+        let second_added =
+            cx.with_current(group, |cx| Element::new(cx).roving_focus_item().entity());
+        event_manager.flush_events(&mut cx, |_| {});
+        assert!(is_navigable(&cx, items[2]));
+        assert!(!is_navigable(&cx, first_added) && !is_navigable(&cx, second_added));
+        assert_eq!(active_index(&cx, group), 0);
+    }
+}
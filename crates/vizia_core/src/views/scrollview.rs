@@ -1,10 +1,13 @@
 use std::sync::Arc;
 
+use accesskit::{ActionData, ActionRequest};
+
 use crate::binding::RatioLens;
 use crate::prelude::*;
 
-pub(crate) const SCROLL_SENSITIVITY: f32 = 20.0;
-
+/// Events which scroll a [`ScrollView`], or any view with `overflow: scroll` or `overflow: auto`, programmatically.
+///
+/// The progress of a scroll position is the fraction of the distance the content can be scrolled along an axis.
 pub enum ScrollEvent {
     /// Sets the progress of scroll position between 0 and 1 for the x axis
     SetX(f32),
@@ -40,8 +43,14 @@ pub struct ScrollView {
 
     pub scroll_to_cursor: bool,
 
+    /// The distance scrolled by one tick of a mouse wheel, or `None` to use the default of the interaction settings.
+    pub scroll_speed: Option<ScrollSpeed>,
+
     pub show_horizontal_scrollbar: bool,
     pub show_vertical_scrollbar: bool,
+
+    /// Whether indicators are shown at each edge of the scrollview with more content beyond it.
+    pub edge_indicators: bool,
 }
 
 impl ScrollView {
@@ -51,6 +60,7 @@ impl ScrollView {
     {
         Self {
             scroll_to_cursor: false,
+            scroll_speed: None,
             scroll_x: 0.0,
             scroll_y: 0.0,
             on_scroll: None,
@@ -60,10 +70,21 @@ impl ScrollView {
             container_height: 0.0,
             show_horizontal_scrollbar: true,
             show_vertical_scrollbar: true,
+            edge_indicators: false,
         }
         .build(cx, move |cx| {
             ScrollContent::new(cx, content);
 
+            Binding::new(cx, ScrollView::edge_indicators, |cx, edge_indicators| {
+                if edge_indicators.get(cx) {
+                    for edge in
+                        [ScrollEdge::Top, ScrollEdge::Bottom, ScrollEdge::Left, ScrollEdge::Right]
+                    {
+                        ScrollEdgeIndicator::new(cx, edge);
+                    }
+                }
+            });
+
             Binding::new(cx, ScrollView::show_vertical_scrollbar, |cx, show_scrollbar| {
                 if show_scrollbar.get(cx) {
                     Scrollbar::new(
@@ -108,6 +129,21 @@ impl ScrollView {
             "v-scroll",
             ScrollView::root.map(|data| data.container_height < data.inner_height),
         )
+        .role(Role::ScrollView)
+    }
+
+    /// Returns true if there is more content beyond an edge of the scrollview.
+    pub fn has_content_beyond(&self, edge: ScrollEdge) -> bool {
+        // Overflow of less than a pixel, such as from rounding, can't be scrolled to.
+        let overflow_x = self.inner_width - self.container_width >= 1.0;
+        let overflow_y = self.inner_height - self.container_height >= 1.0;
+
+        match edge {
+            ScrollEdge::Top => overflow_y && self.scroll_y > 0.0,
+            ScrollEdge::Bottom => overflow_y && self.scroll_y < 1.0,
+            ScrollEdge::Left => overflow_x && self.scroll_x > 0.0,
+            ScrollEdge::Right => overflow_x && self.scroll_x < 1.0,
+        }
     }
 
     fn reset(&mut self) {
@@ -119,6 +155,40 @@ impl ScrollView {
             self.scroll_y = 0.0;
         }
     }
+
+    // Scrolls by the given distances in physical pixels, returning whether the view could scroll along either axis.
+    fn scroll_by(&self, cx: &mut EventContext, x: f32, y: f32) -> bool {
+        let mut scrolled = false;
+
+        // What percentage of the negative space does this cross?
+        if x != 0.0 && self.inner_width > self.container_width {
+            let negative_space = self.inner_width - self.container_width;
+            cx.emit(ScrollEvent::ScrollX(x / negative_space));
+            scrolled = true;
+        }
+
+        if y != 0.0 && self.inner_height > self.container_height {
+            let negative_space = self.inner_height - self.container_height;
+            cx.emit(ScrollEvent::ScrollY(y / negative_space));
+            scrolled = true;
+        }
+
+        scrolled
+    }
+
+    // Scrolls by the distance of a number of mouse wheel ticks along each axis.
+    fn scroll_by_ticks(&self, cx: &mut EventContext, x: f32, y: f32) -> bool {
+        let settings = cx.interaction_settings();
+        let (x, y) = scroll_distance(
+            (x, y),
+            ScrollUnit::Lines,
+            self.scroll_speed.unwrap_or(settings.scroll_speed),
+            settings.scroll_line_height,
+        );
+
+        let scale_factor = cx.scale_factor();
+        self.scroll_by(cx, x * scale_factor, y * scale_factor)
+    }
 }
 
 impl View for ScrollView {
@@ -199,6 +269,8 @@ impl View for ScrollView {
                 }
             }
 
+            cx.style.needs_access_update(cx.current);
+
             // Prevent scroll events propagating to any parent scrollviews.
             // TODO: This might be desired behavior when the scrollview is scrolled all the way.
             meta.consume();
@@ -248,25 +320,54 @@ impl View for ScrollView {
 
             WindowEvent::MouseScroll(x, y) => {
                 cx.set_active(true);
-                let (x, y) = if cx.modifiers.shift() { (-*y, -*x) } else { (-*x, -*y) };
-
-                // What percentage of the negative space does this cross?
-                if x != 0.0 && self.inner_width > self.container_width {
-                    let negative_space = self.inner_width - self.container_width;
-                    if negative_space != 0.0 {
-                        let logical_delta = x * SCROLL_SENSITIVITY / negative_space;
-                        cx.emit(ScrollEvent::ScrollX(logical_delta));
-                    }
+
+                // A scroll view which can only scroll horizontally is scrolled by a vertical wheel.
+                let horizontal_primary = self.inner_width > self.container_width
+                    && self.inner_height <= self.container_height;
+                let (x, y) = scroll_axes((-*x, -*y), cx.modifiers.shift(), horizontal_primary);
+
+                let settings = cx.interaction_settings();
+                let (x, y) = scroll_distance(
+                    (x, y),
+                    cx.mouse.scroll_unit,
+                    self.scroll_speed.unwrap_or(settings.scroll_speed),
+                    settings.scroll_line_height,
+                );
+
+                let scale_factor = cx.scale_factor();
+                if self.scroll_by(cx, x * scale_factor, y * scale_factor) {
                     // Prevent event propagating to ancestor scrollviews.
                     meta.consume();
                 }
-                if y != 0.0 && self.inner_height > self.container_height {
-                    let negative_space = self.inner_height - self.container_height;
-                    if negative_space != 0.0 {
-                        let logical_delta = y * SCROLL_SENSITIVITY / negative_space;
-                        cx.emit(ScrollEvent::ScrollY(logical_delta));
+            }
+
+            // Scroll actions from assistive technologies scroll by the same distance as a mouse wheel tick.
+            WindowEvent::ActionRequest(request) => {
+                let scrolled = match request.action {
+                    Action::ScrollUp => self.scroll_by_ticks(cx, 0.0, -1.0),
+                    Action::ScrollDown => self.scroll_by_ticks(cx, 0.0, 1.0),
+                    Action::ScrollLeft => self.scroll_by_ticks(cx, -1.0, 0.0),
+                    Action::ScrollRight => self.scroll_by_ticks(cx, 1.0, 0.0),
+                    Action::SetScrollOffset => {
+                        if let ActionRequest {
+                            data: Some(ActionData::SetScrollOffset(point)),
+                            ..
+                        } = request
+                        {
+                            let (x, y) = scroll_offset_progress(
+                                (point.x as f32, point.y as f32),
+                                (self.inner_width, self.inner_height),
+                                (self.container_width, self.container_height),
+                            );
+                            cx.emit(ScrollEvent::SetX(x));
+                            cx.emit(ScrollEvent::SetY(y));
+                        }
+                        true
                     }
-                    // Prevent event propagating to ancestor scrollviews.
+                    _ => false,
+                };
+
+                if scrolled {
                     meta.consume();
                 }
             }
@@ -278,6 +379,40 @@ impl View for ScrollView {
             _ => {}
         });
     }
+
+    fn accessibility(&self, _cx: &mut AccessContext, node: &mut AccessNode) {
+        let max_x = (self.inner_width - self.container_width).max(0.0);
+        let max_y = (self.inner_height - self.container_height).max(0.0);
+
+        node.set_scroll_x((self.scroll_x * max_x) as f64);
+        node.set_scroll_x_min(0.0);
+        node.set_scroll_x_max(max_x as f64);
+        node.set_scroll_y((self.scroll_y * max_y) as f64);
+        node.set_scroll_y_min(0.0);
+        node.set_scroll_y_max(max_y as f64);
+
+        if max_x > 0.0 || max_y > 0.0 {
+            node.add_action(Action::SetScrollOffset);
+        }
+
+        if max_x > 0.0 {
+            if self.scroll_x > 0.0 {
+                node.add_action(Action::ScrollLeft);
+            }
+            if self.scroll_x < 1.0 {
+                node.add_action(Action::ScrollRight);
+            }
+        }
+
+        if max_y > 0.0 {
+            if self.scroll_y > 0.0 {
+                node.add_action(Action::ScrollUp);
+            }
+            if self.scroll_y < 1.0 {
+                node.add_action(Action::ScrollDown);
+            }
+        }
+    }
 }
 
 impl Handle<'_, ScrollView> {
@@ -293,6 +428,16 @@ impl Handle<'_, ScrollView> {
         self.modify(|scrollview: &mut ScrollView| scrollview.scroll_to_cursor = scroll_to_cursor)
     }
 
+    /// Sets the distance scrolled by one tick of a mouse wheel, overriding the
+    /// [`scroll_speed`](InteractionSettings::scroll_speed) of the interaction settings. Precise devices such as
+    /// trackpads scroll by the distance they report regardless of the speed.
+    pub fn scroll_speed(self, speed: impl Res<ScrollSpeed>) -> Self {
+        self.bind(speed, |handle, speed| {
+            let speed = speed.get(&handle);
+            handle.modify(|scrollview| scrollview.scroll_speed = Some(speed));
+        })
+    }
+
     pub fn scroll_x(self, scrollx: impl Res<f32>) -> Self {
         self.bind(scrollx, |handle, scrollx| {
             let sx = scrollx.get(&handle);
@@ -320,6 +465,116 @@ impl Handle<'_, ScrollView> {
             handle.modify(|scrollview| scrollview.show_vertical_scrollbar = s);
         })
     }
+
+    /// Sets whether an indicator, such as a shadow, is shown at each edge of the scrollview with more content beyond
+    /// it. The indicators are `scroll-edge` elements with a class for their edge, `top`, `bottom`, `left` or `right`,
+    /// and the `visible` class while there is more content beyond their edge.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// ScrollView::new(cx, |cx| {
+    ///     for i in 0..100 {
+    ///         Label::new(cx, i);
+    ///     }
+    /// })
+    /// .edge_indicators(true);
+    /// ```
+    pub fn edge_indicators(self, flag: impl Res<bool>) -> Self {
+        self.bind(flag, |handle, edge_indicators| {
+            let edge_indicators = edge_indicators.get(&handle);
+            handle.modify(|scrollview| scrollview.edge_indicators = edge_indicators);
+        })
+    }
+}
+
+/// An edge of a [`ScrollView`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollEdge {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl ScrollEdge {
+    fn class(&self) -> &'static str {
+        match self {
+            ScrollEdge::Top => "top",
+            ScrollEdge::Bottom => "bottom",
+            ScrollEdge::Left => "left",
+            ScrollEdge::Right => "right",
+        }
+    }
+}
+
+// An indicator shown at an edge of a scrollview while there is more content beyond it, which is drawn over the
+// content without taking part in layout or hit testing.
+struct ScrollEdgeIndicator {}
+
+impl ScrollEdgeIndicator {
+    fn new(cx: &mut Context, edge: ScrollEdge) -> Handle<Self> {
+        Self {}
+            .build(cx, |_| {})
+            .class(edge.class())
+            .position_type(PositionType::Absolute)
+            .pointer_events(PointerEvents::None)
+            .toggle_class(
+                "visible",
+                ScrollView::root.map(move |data| data.has_content_beyond(edge)),
+            )
+    }
+}
+
+impl View for ScrollEdgeIndicator {
+    fn element(&self) -> Option<&'static str> {
+        Some("scroll-edge")
+    }
+}
+
+// Returns the horizontal and vertical scroll deltas. Holding shift swaps the axes, as does a scroll view which can
+// only scroll horizontally, in which case holding shift scrolls vertically instead.
+pub(crate) fn scroll_axes(delta: (f32, f32), shift: bool, horizontal_primary: bool) -> (f32, f32) {
+    if shift != horizontal_primary {
+        (delta.1, delta.0)
+    } else {
+        delta
+    }
+}
+
+// Converts the deltas of a scroll event, in lines, to the distances to scroll in logical pixels. The deltas of precise
+// devices were converted from pixels using the line height, so they aren't scaled by the speed of a wheel tick.
+pub(crate) fn scroll_distance(
+    delta: (f32, f32),
+    unit: ScrollUnit,
+    speed: ScrollSpeed,
+    line_height: f32,
+) -> (f32, f32) {
+    let distance = match unit {
+        ScrollUnit::Lines => speed.tick_distance(line_height),
+        ScrollUnit::Pixels => line_height,
+    };
+
+    (delta.0 * distance, delta.1 * distance)
+}
+
+// Converts a scroll offset in physical pixels to the scroll progress along each axis, which is zero along an axis
+// which can't be scrolled.
+fn scroll_offset_progress(
+    offset: (f32, f32),
+    inner: (f32, f32),
+    container: (f32, f32),
+) -> (f32, f32) {
+    let progress = |offset: f32, inner: f32, container: f32| {
+        if inner > container {
+            (offset / (inner - container)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    };
+
+    (progress(offset.0, inner.0, container.0), progress(offset.1, inner.1, container.1))
 }
 
 struct ScrollContent {}
@@ -351,3 +606,88 @@ impl View for ScrollContent {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wheel_ticks_scroll_by_the_speed() {
+        let lines = scroll_distance((0.0, 2.0), ScrollUnit::Lines, ScrollSpeed::Lines(3.0), 20.0);
+        assert_eq!(lines, (0.0, 120.0));
+
+        let pixels =
+            scroll_distance((1.0, -1.0), ScrollUnit::Lines, ScrollSpeed::Pixels(50.0), 20.0);
+        assert_eq!(pixels, (50.0, -50.0));
+    }
+
+    #[test]
+    fn precise_deltas_ignore_the_speed() {
+        // A trackpad which scrolled 30px reports one and a half lines of 20px.
+        let delta = (0.0, 1.5);
+        assert_eq!(
+            scroll_distance(delta, ScrollUnit::Pixels, ScrollSpeed::Lines(3.0), 20.0),
+            (0.0, 30.0)
+        );
+        assert_eq!(
+            scroll_distance(delta, ScrollUnit::Pixels, ScrollSpeed::Pixels(100.0), 20.0),
+            (0.0, 30.0)
+        );
+    }
+
+    #[test]
+    fn shift_swaps_scroll_axes() {
+        assert_eq!(scroll_axes((0.0, 1.0), false, false), (0.0, 1.0));
+        assert_eq!(scroll_axes((0.0, 1.0), true, false), (1.0, 0.0));
+        assert_eq!(scroll_axes((0.0, 1.0), false, true), (1.0, 0.0));
+        assert_eq!(scroll_axes((0.0, 1.0), true, true), (0.0, 1.0));
+    }
+
+    fn scrollview(scroll: (f32, f32), inner: (f32, f32)) -> ScrollView {
+        ScrollView {
+            scroll_x: scroll.0,
+            scroll_y: scroll.1,
+            on_scroll: None,
+            inner_width: inner.0,
+            inner_height: inner.1,
+            container_width: 100.0,
+            container_height: 100.0,
+            scroll_to_cursor: false,
+            scroll_speed: None,
+            show_horizontal_scrollbar: true,
+            show_vertical_scrollbar: true,
+            edge_indicators: true,
+        }
+    }
+
+    #[test]
+    fn edges_with_content_beyond_them() {
+        let top = scrollview((0.0, 0.0), (100.0, 300.0));
+        assert!(!top.has_content_beyond(ScrollEdge::Top));
+        assert!(top.has_content_beyond(ScrollEdge::Bottom));
+        assert!(!top.has_content_beyond(ScrollEdge::Left));
+        assert!(!top.has_content_beyond(ScrollEdge::Right));
+
+        let scrolled = scrollview((0.5, 1.0), (300.0, 300.0));
+        assert!(scrolled.has_content_beyond(ScrollEdge::Top));
+        assert!(!scrolled.has_content_beyond(ScrollEdge::Bottom));
+        assert!(scrolled.has_content_beyond(ScrollEdge::Left));
+        assert!(scrolled.has_content_beyond(ScrollEdge::Right));
+
+        // Content which overflows by less than a pixel can't be scrolled.
+        assert!(!scrollview((0.0, 0.0), (100.5, 100.0)).has_content_beyond(ScrollEdge::Right));
+    }
+
+    #[test]
+    fn scroll_offset_is_converted_to_progress() {
+        assert_eq!(
+            scroll_offset_progress((50.0, 300.0), (200.0, 500.0), (100.0, 100.0)),
+            (0.5, 0.75)
+        );
+        // Offsets are clamped to the scrollable range, and an axis which doesn't overflow can't be scrolled.
+        assert_eq!(
+            scroll_offset_progress((-10.0, 900.0), (50.0, 500.0), (100.0, 100.0)),
+            (0.0, 1.0)
+        );
+    }
+}
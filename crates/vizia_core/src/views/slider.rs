@@ -8,6 +8,7 @@ use crate::prelude::*;
 enum SliderEventInternal {
     SetThumbSize(f32, f32),
     SetRange(Range<f32>),
+    SetParam(NumericParam),
     SetKeyboardFraction(f32),
 }
 
@@ -16,8 +17,7 @@ pub struct SliderDataInternal {
     pub orientation: Orientation,
     pub size: f32,
     pub thumb_size: f32,
-    pub range: Range<f32>,
-    pub step: f32,
+    pub param: NumericParam,
     pub keyboard_fraction: f32,
 }
 
@@ -50,6 +50,22 @@ pub struct SliderDataInternal {
 ///     });
 /// ```
 ///
+/// ## Two-way Slider
+/// A slider bound with a [`two_way()`](crate::binding::LensExt::two_way) lens writes the value to the data directly,
+/// without the need for an `on_changing` callback.
+/// ```
+/// # use vizia_core::prelude::*;
+/// # use vizia_derive::*;
+/// # let mut cx = &mut Context::default();
+/// # #[derive(Lens, Default)]
+/// # pub struct AppData {
+/// #     value: f32,
+/// # }
+/// # impl Model for AppData {}
+/// # AppData::default().build(cx);
+/// Slider::new(cx, AppData::value.two_way());
+/// ```
+///
 /// ## Slider with Label
 /// ```
 /// # use vizia_core::prelude::*;
@@ -108,8 +124,7 @@ where
                 orientation: Orientation::Horizontal,
                 thumb_size: 0.0,
                 size: 0.0,
-                range: 0.0..1.0,
-                step: 0.01,
+                param: NumericParam::new(0.0, 1.0).step(0.01),
                 keyboard_fraction: 0.1,
             },
 
@@ -122,13 +137,12 @@ where
                     let thumb_size = slider_data.thumb_size;
                     let orientation = slider_data.orientation;
                     let size = slider_data.size;
-                    let range = slider_data.range;
+                    let param = slider_data.param;
 
                     // Active track
+                    let track_param = param.clone();
                     Element::new(cx).class("active").bind(lens, move |handle, value| {
-                        let val = value.get(&handle);
-
-                        let normal_val = (val - range.start) / (range.end - range.start);
+                        let normal_val = track_param.value_to_normalized(value.get(&handle));
                         let min = thumb_size / size;
                         let max = 1.0;
                         let dx = min + normal_val * (max - min);
@@ -160,8 +174,7 @@ where
                             }
                         })
                         .bind(lens, move |handle, value| {
-                            let val = value.get(&handle);
-                            let normal_val = (val - range.start) / (range.end - range.start);
+                            let normal_val = param.value_to_normalized(value.get(&handle));
                             let px = normal_val * (1.0 - (thumb_size / size));
                             if orientation == Orientation::Horizontal {
                                 handle
@@ -201,8 +214,7 @@ where
                 orientation: Orientation::Horizontal,
                 thumb_size: 0.0,
                 size: 0.0,
-                range: 0.0..1.0,
-                step: 0.01,
+                param: NumericParam::new(0.0, 1.0).step(0.01),
                 keyboard_fraction: 0.1,
             },
 
@@ -215,15 +227,27 @@ where
     }
 }
 
+impl<L: Lens<Target = f32>> Slider<L> {
+    // Writes the value through the lens, if the lens is two-way, and triggers the `on_changing` callback.
+    fn change(&self, cx: &mut EventContext, value: f32) {
+        cx.write_lens(self.lens, value);
+        if let Some(callback) = &self.on_changing {
+            (callback)(cx, value);
+        }
+    }
+}
+
 impl<L: Lens<Target = f32>> View for Slider<L> {
     fn element(&self) -> Option<&'static str> {
         Some("slider")
     }
 
     fn accessibility(&self, _cx: &mut AccessContext, node: &mut AccessNode) {
-        node.set_numeric_value_step(self.internal.step as f64);
-        node.set_min_numeric_value(self.internal.range.start as f64);
-        node.set_max_numeric_value(self.internal.range.end as f64);
+        if let Some(step) = self.internal.param.get_step() {
+            node.set_numeric_value_step(step as f64);
+        }
+        node.set_min_numeric_value(self.internal.param.min() as f64);
+        node.set_max_numeric_value(self.internal.param.max() as f64);
     }
 
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
@@ -239,7 +263,11 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
             },
 
             SliderEventInternal::SetRange(range) => {
-                self.internal.range = range.clone();
+                self.internal.param = self.internal.param.clone().range(range.clone());
+            }
+
+            SliderEventInternal::SetParam(param) => {
+                self.internal.param = param.clone();
             }
 
             SliderEventInternal::SetKeyboardFraction(keyboard_fraction) => {
@@ -272,9 +300,6 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
                     });
 
                     let thumb_size = self.internal.thumb_size;
-                    let min = self.internal.range.start;
-                    let max = self.internal.range.end;
-                    let step = self.internal.step;
 
                     let current = cx.current();
                     let width = cx.cache.get_width(current);
@@ -282,7 +307,7 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
                     let posx = cx.cache.get_posx(current);
                     let posy = cx.cache.get_posy(current);
 
-                    let mut dx = match self.internal.orientation {
+                    let dx = match self.internal.orientation {
                         Orientation::Horizontal => {
                             (cx.mouse.left.pos_down.0 - posx - thumb_size / 2.0)
                                 / (width - thumb_size)
@@ -294,18 +319,10 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
                         }
                     };
 
-                    dx = dx.clamp(0.0, 1.0);
-
-                    let mut val = min + dx * (max - min);
-
-                    val = step * (val / step).ceil();
-                    val = val.clamp(min, max);
+                    let param = &self.internal.param;
+                    let val = param.snap(param.normalized_to_value(dx.clamp(0.0, 1.0)));
 
-                    if let Some(callback) = self.on_changing.take() {
-                        (callback)(cx, val);
-
-                        self.on_changing = Some(callback);
-                    }
+                    self.change(cx, val);
                 }
             }
 
@@ -322,17 +339,13 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
                 if self.is_dragging {
                     let thumb_size = self.internal.thumb_size;
 
-                    let min = self.internal.range.start;
-                    let max = self.internal.range.end;
-                    let step = self.internal.step;
-
                     let current = cx.current();
                     let width = cx.cache.get_width(current);
                     let height = cx.cache.get_height(current);
                     let posx = cx.cache.get_posx(current);
                     let posy = cx.cache.get_posy(current);
 
-                    let mut dx = match self.internal.orientation {
+                    let dx = match self.internal.orientation {
                         Orientation::Horizontal => {
                             (*x - posx - thumb_size / 2.0) / (width - thumb_size)
                         }
@@ -342,77 +355,38 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
                         }
                     };
 
-                    dx = dx.clamp(0.0, 1.0);
+                    let param = &self.internal.param;
+                    let val = param.snap(param.normalized_to_value(dx.clamp(0.0, 1.0)));
 
-                    let mut val = min + dx * (max - min);
-
-                    val = step * (val / step).ceil();
-                    val = val.clamp(min, max);
-
-                    if let Some(callback) = &self.on_changing {
-                        (callback)(cx, val);
-                    }
+                    self.change(cx, val);
                 }
             }
 
             WindowEvent::KeyDown(Code::ArrowUp | Code::ArrowRight, _) => {
-                let min = self.internal.range.start;
-                let max = self.internal.range.end;
-                let step = self.internal.step;
-                let mut val = self.lens.get(cx) + step;
-                // val = step * (val / step).ceil();
-                val = val.clamp(min, max);
-                if let Some(callback) = &self.on_changing {
-                    (callback)(cx, val);
-                }
+                let val = self.internal.param.step_by(self.lens.get(cx), 1);
+                self.change(cx, val);
             }
 
             WindowEvent::KeyDown(Code::ArrowDown | Code::ArrowLeft, _) => {
-                let min = self.internal.range.start;
-                let max = self.internal.range.end;
-                let step = self.internal.step;
-                let mut val = self.lens.get(cx) - step;
-                // val = step * (val / step).ceil();
-                val = val.clamp(min, max);
-                if let Some(callback) = &self.on_changing {
-                    (callback)(cx, val);
-                }
+                let val = self.internal.param.step_by(self.lens.get(cx), -1);
+                self.change(cx, val);
             }
 
             WindowEvent::ActionRequest(action) => match action.action {
                 Action::Increment => {
-                    let min = self.internal.range.start;
-                    let max = self.internal.range.end;
-                    let step = self.internal.step;
-                    let mut val = self.lens.get(cx) + step;
-                    val = step * (val / step).ceil();
-                    val = val.clamp(min, max);
-                    if let Some(callback) = &self.on_changing {
-                        (callback)(cx, val);
-                    }
+                    let val = self.internal.param.step_by(self.lens.get(cx), 1);
+                    self.change(cx, val);
                 }
 
                 Action::Decrement => {
-                    let min = self.internal.range.start;
-                    let max = self.internal.range.end;
-                    let step = self.internal.step;
-                    let mut val = self.lens.get(cx) - step;
-                    val = step * (val / step).ceil();
-                    val = val.clamp(min, max);
-                    if let Some(callback) = &self.on_changing {
-                        (callback)(cx, val);
-                    }
+                    let val = self.internal.param.step_by(self.lens.get(cx), -1);
+                    self.change(cx, val);
                 }
 
                 Action::SetValue => {
                     if let Some(ActionData::NumericValue(val)) = action.data {
-                        let min = self.internal.range.start;
-                        let max = self.internal.range.end;
-                        let mut v = val as f32;
-                        v = v.clamp(min, max);
-                        if let Some(callback) = &self.on_changing {
-                            (callback)(cx, v);
-                        }
+                        let val = self.internal.param.constrain(val as f32);
+                        self.change(cx, val);
                     }
                 }
 
@@ -482,7 +456,33 @@ impl<L: Lens> Handle<'_, Slider<L>> {
     }
 
     pub fn step(self, step: f32) -> Self {
-        self.modify(|slider: &mut Slider<L>| slider.internal.step = step)
+        self.modify(|slider: &mut Slider<L>| {
+            slider.internal.param = slider.internal.param.clone().step(step)
+        })
+    }
+
+    /// Sets the parameter of the slider, which determines its range, step and how its value is mapped along the
+    /// track, replacing the range and step set by [`range`](Self::range) and [`step`](Self::step).
+    ///
+    /// # Example
+    /// A slider which gives more of its track to lower frequencies:
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_derive::*;
+    /// # let mut cx = &mut Context::default();
+    /// # #[derive(Lens, Default)]
+    /// # pub struct AppData {
+    /// #     cutoff: f32,
+    /// # }
+    /// # impl Model for AppData {}
+    /// # AppData::default().build(cx);
+    /// Slider::new(cx, AppData::cutoff)
+    ///     .param(NumericParam::new(20.0, 20_000.0).scaling(ValueScaling::Frequency).step(1.0));
+    /// ```
+    pub fn param(self, param: NumericParam) -> Self {
+        self.cx.emit_to(self.entity, SliderEventInternal::SetParam(param));
+
+        self
     }
 
     /// Sets the fraction of a slider that a press of an arrow key will change.
@@ -542,12 +542,11 @@ impl NamedSlider {
                             let slider_data = slider_data.get(cx);
                             let thumb_size = slider_data.thumb_size;
                             let size = slider_data.size;
-                            let range = slider_data.range;
+                            let param = slider_data.param;
 
                             // Active track
                             Element::new(cx).class("active").bind(lens, move |handle, value| {
-                                let val = value.get(&handle);
-                                let normal_val = (val - range.start) / (range.end - range.start);
+                                let normal_val = param.value_to_normalized(value.get(&handle));
                                 let min = thumb_size / size;
                                 let max = 1.0;
                                 let dx = min + normal_val * (max - min);
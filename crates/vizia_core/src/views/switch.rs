@@ -1,3 +1,4 @@
+use super::checkbox::toggle_lens;
 use crate::prelude::*;
 
 /// A Switch used to display and toggle a boolean state.
@@ -53,6 +54,28 @@ use crate::prelude::*;
 /// Switch::new(cx, AppData::value).on_toggle(|cx| cx.emit(AppEvent::ToggleValue));
 /// ```
 ///
+/// ## Two-way switch
+///
+/// A Switch bound with a [`two_way()`](crate::binding::LensExt::two_way) lens toggles the data directly
+/// when pressed, without the need for an event.
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # #[derive(Lens)]
+/// # struct AppData {
+/// #     value: bool,
+/// # }
+/// #
+/// # impl Model for AppData {}
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// # AppData { value: false }.build(cx);
+/// #
+/// Switch::new(cx, AppData::value.two_way());
+/// ```
+///
 /// ## Switch with a label
 ///
 /// A Switch is usually used with a label next to it describing what data the Switch
@@ -81,6 +104,7 @@ use crate::prelude::*;
 /// ```
 pub struct Switch {
     on_toggle: Option<Box<dyn Fn(&mut EventContext)>>,
+    toggle_lens: Box<dyn Fn(&mut EventContext)>,
 }
 
 impl Switch {
@@ -105,7 +129,7 @@ impl Switch {
     /// Switch::new(cx, AppData::value);
     /// ```
     pub fn new(cx: &mut Context, checked: impl Lens<Target = bool>) -> Handle<Self> {
-        Self { on_toggle: None }
+        Self { on_toggle: None, toggle_lens: toggle_lens(checked) }
             .build(cx, |cx| {
                 Element::new(cx)
                     .class("switch-handle-bg")
@@ -165,6 +189,7 @@ impl View for Switch {
             WindowEvent::Press { mouse } => {
                 let over = if *mouse { cx.mouse.left.pressed } else { cx.focused() };
                 if over == cx.current() && meta.target == cx.current() && !cx.is_disabled() {
+                    (self.toggle_lens)(cx);
                     if let Some(callback) = &self.on_toggle {
                         (callback)(cx);
                     }
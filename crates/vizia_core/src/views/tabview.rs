@@ -27,23 +27,37 @@ impl TabView {
                 let content2 = content.clone();
                 // Tab headers
                 ScrollView::new(cx, move |cx| {
-                    //VStack::new(cx, move |cx| {
-                    Binding::new(cx, lens.map(|list| list.len()), move |cx, list_length| {
-                        let list_length = list_length.get(cx);
-                        for index in 0..list_length {
-                            let l = lens.idx(index);
-                            let builder = (content2)(cx, l).header;
-                            TabHeader::new(cx, index, builder)
-                                .bind(TabView::selected_index, move |handle, selected_index| {
-                                    let selected_index = selected_index.get(handle.cx);
-                                    handle.checked(selected_index == index);
-                                })
-                                .toggle_class("vertical", TabView::is_vertical);
-                        }
+                    RovingFocusGroup::new(cx, move |cx| {
+                        Binding::new(cx, lens.map(|list| list.len()), move |cx, list_length| {
+                            let list_length = list_length.get(cx);
+                            for index in 0..list_length {
+                                let l = lens.idx(index);
+                                let builder = (content2)(cx, l).header;
+                                TabHeader::new(cx, index, builder)
+                                    .roving_focus_item()
+                                    .bind(TabView::selected_index, move |handle, selected_index| {
+                                        let selected_index = selected_index.get(handle.cx);
+                                        let entity = handle.entity();
+                                        handle.cx.style.needs_access_update(entity);
+                                        handle.checked(selected_index == index);
+                                    })
+                                    .toggle_class("vertical", TabView::is_vertical);
+                            }
+                        })
                     })
-                    //})
-                    //.toggle_class("vertical", TabView::is_vertical)
-                    //.class("tabview-tabheader-wrapper");
+                    .orientation(TabView::is_vertical.map(|is_vertical| {
+                        if *is_vertical {
+                            Orientation::Vertical
+                        } else {
+                            Orientation::Horizontal
+                        }
+                    }))
+                    // The selected tab follows the focus as it's moved with the arrow keys.
+                    .with_active_index(TabView::selected_index)
+                    .on_change(|cx, index| cx.emit(TabEvent::SetSelected(index)))
+                    .role(Role::TabList)
+                    .class("tabview-tabheader-wrapper")
+                    .toggle_class("vertical", TabView::is_vertical);
                 })
                 .class("tabview-header")
                 .z_index(1)
@@ -128,7 +142,7 @@ impl TabHeader {
     where
         F: 'static + Fn(&mut Context),
     {
-        Self { index }.build(cx, |cx| (content)(cx))
+        Self { index }.build(cx, |cx| (content)(cx)).role(Role::Tab)
     }
 }
 
@@ -137,12 +151,27 @@ impl View for TabHeader {
         Some("tabheader")
     }
 
+    fn accessibility(&self, cx: &mut AccessContext, node: &mut AccessNode) {
+        let selected = cx
+            .style
+            .pseudo_classes
+            .get(cx.current)
+            .is_some_and(|pseudo_classes| pseudo_classes.contains(PseudoClassFlags::CHECKED));
+        node.set_selected(selected);
+        node.add_action(Action::Default);
+    }
+
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
-        event.map(|window_event, _meta| match window_event {
+        event.map(|window_event, meta| match window_event {
             WindowEvent::PressDown { mouse: _ } => {
                 cx.emit(TabEvent::SetSelected(self.index));
             }
 
+            WindowEvent::ActionRequest(request) if request.action == Action::Default => {
+                cx.emit(TabEvent::SetSelected(self.index));
+                meta.consume();
+            }
+
             _ => {}
         });
     }
@@ -179,3 +208,76 @@ impl View for TabBar {
         Some("tabbar")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+    use crate::systems::binding_system;
+
+    #[derive(Lens)]
+    struct Tabs {
+        tabs: Vec<String>,
+    }
+
+    impl Model for Tabs {}
+
+    fn press(cx: &mut Context, event_manager: &mut EventManager, code: Code) {
+        cx.emit_custom(Event::new(WindowEvent::KeyDown(code, None)).target(Entity::root()));
+        cx.emit_custom(Event::new(WindowEvent::KeyUp(code, None)).target(Entity::root()));
+        event_manager.flush_events(cx, |_| {});
+        binding_system(cx);
+    }
+
+    fn selected_index(cx: &Context, tabview: Entity) -> usize {
+        let view = cx.views.get(&tabview).and_then(|view| view.downcast_ref::<TabView>());
+        view.unwrap().selected_index
+    }
+
+    #[test]
+    fn arrow_keys_select_tabs() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+        Tabs { tabs: vec![String::from("One"), String::from("Two"), String::from("Three")] }
+            .build(&mut cx);
+        let tabview = TabView::new(&mut cx, Tabs::tabs, |_, _| {
+            TabPair::new(
+                |cx| {
+                    Label::new(cx, "Tab");
+                },
+                |cx| {
+                    Element::new(cx);
+                },
+            )
+        })
+        .entity();
+        event_manager.flush_events(&mut cx, |_| {});
+
+        let headers = tabview
+            .branch_iter(&cx.tree)
+            .filter(|entity| {
+                cx.views.get(entity).and_then(|view| view.element()) == Some("tabheader")
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(headers.len(), 3);
+
+        // The tab headers are a single stop in the navigation order, with the selection following the focus.
+        press(&mut cx, &mut event_manager, Code::Tab);
+        assert_eq!(cx.focused(), headers[0]);
+
+        press(&mut cx, &mut event_manager, Code::ArrowRight);
+        assert_eq!(cx.focused(), headers[1]);
+        assert_eq!(selected_index(&cx, tabview), 1);
+
+        press(&mut cx, &mut event_manager, Code::End);
+        assert_eq!(cx.focused(), headers[2]);
+        assert_eq!(selected_index(&cx, tabview), 2);
+
+        press(&mut cx, &mut event_manager, Code::Tab);
+        assert_eq!(cx.focused(), headers[2]);
+
+        press(&mut cx, &mut event_manager, Code::ArrowRight);
+        assert_eq!(cx.focused(), headers[0]);
+        assert_eq!(selected_index(&cx, tabview), 0);
+    }
+}
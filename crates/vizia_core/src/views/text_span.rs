@@ -0,0 +1,27 @@
+use crate::prelude::*;
+
+/// A span of text within a rich [`Label`], which can be styled separately from the rest of the text.
+pub struct TextSpan {}
+
+impl TextSpan {
+    pub fn new<'a>(
+        cx: &'a mut Context,
+        text: &str,
+        children: impl Fn(&mut Context),
+    ) -> Handle<'a, Self> {
+        Self {}
+            .build(cx, |cx| {
+                cx.style.text_span.insert(cx.current(), true);
+                children(cx);
+            })
+            .text(text)
+            .display(Display::None)
+            .pointer_events(PointerEvents::None)
+    }
+}
+
+impl View for TextSpan {
+    fn element(&self) -> Option<&'static str> {
+        Some("text-span")
+    }
+}
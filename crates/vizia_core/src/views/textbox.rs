@@ -1,16 +1,22 @@
 // use crate::accessibility::IntoNode;
+use crate::layout::baseline::text_top;
 use crate::prelude::*;
 
+use std::cell::Cell;
+
 use crate::text::{
-    apply_movement, offset_for_delete_backwards, Direction, EditableText, Movement, Selection,
-    VerticalMovement,
+    apply_movement, enforce_text_bounds, ensure_visible, offset_for_delete_backwards, Direction,
+    EditableText, Movement, Selection, VerticalMovement,
 };
 // use crate::views::scrollview::SCROLL_SENSITIVITY;
 use accesskit::{ActionData, ActionRequest};
 use skia_safe::textlayout::{RectHeightStyle, RectWidthStyle};
-use skia_safe::{Paint, PaintStyle, Rect};
+use skia_safe::{ClipOp, Paint, PaintStyle, Rect};
 use unicode_segmentation::UnicodeSegmentation;
 
+// The space kept between the caret and the edges of the textbox when the text scrolls to follow it, in logical pixels.
+const CARET_MARGIN: f32 = 8.0;
+
 /// Events for modifying a textbox.
 pub enum TextEvent {
     /// Insert a string of text into the textbox.
@@ -53,19 +59,54 @@ pub enum TextEvent {
     ToggleCaret,
 }
 
+/// Determines whether the text of a [`Textbox`] is selected when the textbox gains focus, so that typing replaces it.
+///
+/// Focusing a textbox by clicking on it places the caret at the click position unless the text is always selected,
+/// and focusing it from code places the caret at the end of the text. Editing started by emitting
+/// [`TextEvent::StartEdit`] directly always selects the text.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SelectOnFocus {
+    /// Selects the text when the textbox is focused with the keyboard, such as with the tab key.
+    #[default]
+    Keyboard,
+    /// Selects the text however the textbox is focused.
+    Always,
+    /// Never selects the text when the textbox is focused.
+    Never,
+}
+
+// How the textbox gained focus, which determines the selection made when editing starts.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum FocusSource {
+    Keyboard,
+    Pointer,
+    Programmatic,
+}
+
 /// The `Textbox` view provides an input control for editing a value as a string.
 ///
 /// The textbox takes a lens to some value, which must be a type which can convert to and from a `String`,
 /// as determined by the `ToString` and `FromStr` traits. The value type is used for validation and returned by
 /// the `on_submit` callback, which is triggered when the textbox is submitted with the enter key or when the textbox
 /// loses keyboard focus.
+///
+/// When bound with a [`two_way()`](crate::binding::LensExt::two_way) lens, the submitted value is also written to the
+/// bound data. Changes to the bound data while the text is being edited don't replace the text, which is instead
+/// updated when editing ends.
 #[derive(Lens)]
 pub struct Textbox<L: Lens> {
     lens: L,
     #[lens(ignore)]
     kind: TextboxKind,
     edit: bool,
-    transform: (f32, f32),
+    // Whether the bound data changed while the text was being edited, so the text is replaced when editing ends.
+    stale: bool,
+    // The scroll offset of the text in physical pixels, which is updated when drawing as the text is laid out by then.
+    #[lens(ignore)]
+    transform: Cell<(f32, f32)>,
+    // Whether the text should scroll to keep the caret visible the next time it is drawn.
+    #[lens(ignore)]
+    scroll_to_caret: Cell<bool>,
     on_edit: Option<Box<dyn Fn(&mut EventContext, String) + Send + Sync>>,
     on_submit: Option<Box<dyn Fn(&mut EventContext, L::Target, bool) + Send + Sync>>,
     on_blur: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
@@ -75,6 +116,11 @@ pub struct Textbox<L: Lens> {
     show_caret: bool,
     caret_timer: Timer,
     selection: Selection,
+    #[lens(ignore)]
+    select_on_focus: SelectOnFocus,
+    // How the textbox gained focus, if editing is being started because it gained focus.
+    #[lens(ignore)]
+    focus_source: Option<FocusSource>,
 }
 
 // Determines whether the enter key submits the text or inserts a new line.
@@ -150,7 +196,9 @@ where
             lens,
             kind,
             edit: false,
-            transform: (0.0, 0.0),
+            stale: false,
+            transform: Cell::new((0.0, 0.0)),
+            scroll_to_caret: Cell::new(false),
             on_edit: None,
             on_submit: None,
             on_blur: None,
@@ -160,6 +208,8 @@ where
             show_caret: true,
             caret_timer,
             selection: Selection::new(0, 0),
+            select_on_focus: SelectOnFocus::default(),
+            focus_source: None,
         }
         .build(cx, move |cx| {
             cx.add_listener(move |textbox: &mut Self, cx, event| {
@@ -199,18 +249,45 @@ where
         .text_value(lens)
         .default_action_verb(DefaultActionVerb::Focus)
         .toggle_class("caret", Self::show_caret)
-        .text(lens)
+        .bind(lens, |handle, lens| {
+            let entity = handle.entity();
+            let cx = &mut EventContext::new_with_current(handle.cx, entity);
+            // Changes to the bound data don't replace the text while the user is editing it.
+            if let Some(textbox) =
+                cx.views.get_mut(&entity).and_then(|view| view.downcast_mut::<Self>())
+            {
+                if textbox.edit {
+                    textbox.stale = true;
+                    return;
+                }
+
+                textbox.transform.set((0.0, 0.0));
+            }
+
+            Self::reset_text(cx, lens);
+        })
+    }
+
+    // Replaces the text of the textbox with the bound data.
+    fn reset_text(cx: &mut EventContext, lens: L) {
+        let text = lens.get(cx).to_string_local(cx);
+        cx.style.text.insert(cx.current, text);
+        cx.style.needs_text_update(cx.current);
+        cx.needs_relayout();
+        cx.needs_redraw();
     }
 
     fn insert_text(&mut self, cx: &mut EventContext, txt: &str) {
         if let Some(text) = cx.style.text.get_mut(cx.current) {
             text.edit(self.selection.range(), txt);
             self.selection = Selection::caret(self.selection.min() + txt.len());
+            self.scroll_to_caret.set(true);
             cx.style.needs_text_update(cx.current);
         }
     }
 
     fn delete_text(&mut self, cx: &mut EventContext, movement: Movement) {
+        self.scroll_to_caret.set(true);
         if self.selection.is_caret() {
             if movement == Movement::Grapheme(Direction::Upstream) {
                 if let Some(text) = cx.style.text.get_mut(cx.current) {
@@ -249,6 +326,7 @@ where
                 let new_selection =
                     apply_movement(movement, self.selection, text, paragraph, selection);
                 self.selection = new_selection;
+                self.scroll_to_caret.set(true);
                 cx.needs_redraw();
             }
         }
@@ -258,10 +336,31 @@ where
         if let Some(text) = cx.style.text.get(cx.current) {
             self.selection.anchor = 0;
             self.selection.active = text.len();
+            self.scroll_to_caret.set(true);
             cx.needs_redraw();
         }
     }
 
+    fn move_caret_to_end(&mut self, cx: &mut EventContext) {
+        if let Some(text) = cx.style.text.get(cx.current) {
+            self.selection = Selection::caret(text.len());
+            self.scroll_to_caret.set(true);
+            cx.needs_redraw();
+        }
+    }
+
+    // Selects the text or moves the caret when editing starts, depending on how the textbox gained focus.
+    fn select_for_focus(&mut self, cx: &mut EventContext, source: Option<FocusSource>) {
+        match (source, self.select_on_focus) {
+            (None, _)
+            | (Some(_), SelectOnFocus::Always)
+            | (Some(FocusSource::Keyboard), SelectOnFocus::Keyboard) => self.select_all(cx),
+            // The caret is placed at the click position by the hit which follows.
+            (Some(FocusSource::Pointer), _) => {}
+            (Some(_), _) => self.move_caret_to_end(cx),
+        }
+    }
+
     fn select_word(&mut self, cx: &mut EventContext) {
         self.move_cursor(cx, Movement::Word(Direction::Upstream), false);
         self.move_cursor(cx, Movement::Word(Direction::Downstream), true);
@@ -298,30 +397,18 @@ where
             let padding_bottom =
                 padding_bottom.to_px(logical_parent_height, 0.0) * cx.scale_factor();
 
-            let (mut top, _) = match cx.style.alignment.get(cx.current).copied().unwrap_or_default()
-            {
-                Alignment::TopLeft => (0.0, 0.0),
-                Alignment::TopCenter => (0.0, 0.5),
-                Alignment::TopRight => (0.0, 1.0),
-                Alignment::Left => (0.5, 0.0),
-                Alignment::Center => (0.5, 0.5),
-                Alignment::Right => (0.5, 1.0),
-                Alignment::BottomLeft => (1.0, 0.0),
-                Alignment::BottomCenter => (1.0, 0.5),
-                Alignment::BottomRight => (1.0, 1.0),
-            };
+            let top = text_top(
+                cx.style,
+                cx.current,
+                paragraph,
+                bounds.height(),
+                padding_top,
+                padding_bottom,
+            );
 
-            top *= bounds.height() - padding_top - padding_bottom - paragraph.height();
-
-            // let total_height = cx.text_context.with_buffer(cx.current, |_, buffer| {
-            //     buffer.layout_runs().len() as f32 * buffer.metrics().line_height
-            // });
-
-            // let x = x - bounds.x - self.transform.0 - padding_left;
-            // let y = y - self.transform.1 - bounds.y - (bounds.h - total_height) * justify_y - padding_top;
-
-            let x = x - bounds.x - padding_left;
-            let y = y - bounds.y - padding_top - top;
+            let (tx, ty) = self.transform.get();
+            let x = x - bounds.x - tx - padding_left;
+            let y = y - bounds.y - ty - padding_top - top;
 
             (x, y)
         } else {
@@ -351,6 +438,7 @@ where
                     self.selection = Selection::caret(cursor);
                 }
 
+                self.scroll_to_caret.set(true);
                 cx.needs_redraw();
             }
         }
@@ -375,6 +463,8 @@ where
 
                 self.selection.active = cursor;
 
+                // Dragging past the edge of the textbox moves the caret to text which is scrolled out of view.
+                self.scroll_to_caret.set(true);
                 cx.needs_redraw();
             }
         }
@@ -405,10 +495,93 @@ where
         cx.stop_timer(self.caret_timer);
         if !cx.is_read_only() {
             self.show_caret = true;
+            // The timer is shared by all textboxes, so the interval is updated in case the settings have changed.
+            let interval = cx.interaction_settings().caret_blink_interval;
+            cx.timers[self.caret_timer.0].interval = interval;
             cx.start_timer(self.caret_timer);
         }
     }
 
+    // Returns the bounds of the textbox without its padding, which the text is visible within.
+    fn text_viewport(&self, cx: &DrawContext) -> BoundingBox {
+        let padding = |units: Units| match units {
+            Units::Pixels(val) => val,
+            _ => 0.0,
+        };
+
+        cx.bounds().shrink_sides(
+            padding(cx.padding_left()),
+            padding(cx.padding_top()),
+            padding(cx.padding_right()),
+            padding(cx.padding_bottom()),
+        )
+    }
+
+    // Scrolls the text to keep the caret visible if it moved, and clamps the scroll offset to the extent of the text,
+    // returning the new offset.
+    fn update_transform(&self, cx: &DrawContext, viewport: BoundingBox) -> (f32, f32) {
+        let Some(paragraph) = cx.text_context.text_paragraphs.get(cx.current) else {
+            return (0.0, 0.0);
+        };
+
+        let bounds = cx.bounds();
+        let top = text_top(
+            cx.style,
+            cx.current,
+            paragraph,
+            bounds.h,
+            viewport.y - bounds.y,
+            bounds.bottom() - viewport.bottom(),
+        );
+        let origin = (viewport.x, viewport.y + top);
+
+        let mut transform = self.transform.get();
+
+        if self.scroll_to_caret.replace(false) {
+            if let Some(text) = cx.style.text.get(cx.current) {
+                let current = text.current_grapheme_offset(self.selection.active);
+                let rects = paragraph.get_rects_for_range(
+                    current..current + 1,
+                    RectHeightStyle::Tight,
+                    RectWidthStyle::Tight,
+                );
+
+                if let Some(caret) = rects.first() {
+                    let margin = CARET_MARGIN * cx.scale_factor();
+                    let caret_bounds = BoundingBox {
+                        x: origin.0 + caret.rect.left - margin,
+                        y: origin.1 + caret.rect.top,
+                        w: 1.0 + 2.0 * margin,
+                        h: caret.rect.height(),
+                    };
+                    transform = ensure_visible(&caret_bounds, &viewport, transform);
+                }
+            }
+        }
+
+        // The text includes the width of the caret so that the caret at the end of the text stays visible.
+        let (left, right) = paragraph.get_line_metrics().iter().fold(
+            (f32::MAX, f32::MIN),
+            |(left, right), line| {
+                (left.min(line.left as f32), right.max((line.left + line.width) as f32))
+            },
+        );
+        let text_bounds = BoundingBox {
+            x: origin.0 + left.min(right),
+            y: origin.1,
+            w: (right - left).max(0.0) + 1.0,
+            h: paragraph.height(),
+        };
+        transform = enforce_text_bounds(&text_bounds, &viewport, transform);
+
+        if self.kind == TextboxKind::SingleLine {
+            transform.1 = 0.0;
+        }
+
+        self.transform.set(transform);
+        transform
+    }
+
     pub fn draw_selection(&self, cx: &mut DrawContext, canvas: &Canvas) {
         if !self.selection.is_caret() {
             if let Some(paragraph) = cx.text_context.text_paragraphs.get(cx.current) {
@@ -425,20 +598,6 @@ where
                     for cursor_rect in cursor_rects {
                         let bounds = cx.bounds();
 
-                        let alignment = cx.alignment();
-
-                        let (mut top, left) = match alignment {
-                            Alignment::TopLeft => (0.0, 0.0),
-                            Alignment::TopCenter => (0.0, 0.5),
-                            Alignment::TopRight => (0.0, 1.0),
-                            Alignment::Left => (0.5, 0.0),
-                            Alignment::Center => (0.5, 0.5),
-                            Alignment::Right => (0.5, 1.0),
-                            Alignment::BottomLeft => (1.0, 0.0),
-                            Alignment::BottomCenter => (1.0, 0.5),
-                            Alignment::BottomRight => (1.0, 1.0),
-                        };
-
                         let padding_top = match cx.padding_top() {
                             Units::Pixels(val) => val,
                             _ => 0.0,
@@ -449,14 +608,21 @@ where
                             _ => 0.0,
                         };
 
-                        top *= bounds.height() - padding_top - padding_bottom - paragraph.height();
+                        let top = text_top(
+                            cx.style,
+                            cx.current,
+                            paragraph,
+                            bounds.height(),
+                            padding_top,
+                            padding_bottom,
+                        );
 
                         let padding_left = match cx.padding_left() {
                             Units::Pixels(val) => val,
                             _ => 0.0,
                         };
 
-                        let x = bounds.x + padding_left + cursor_rect.rect.left + left;
+                        let x = bounds.x + padding_left + cursor_rect.rect.left;
                         let y = bounds.y + padding_top + cursor_rect.rect.top + top;
 
                         let x2 = x + (cursor_rect.rect.right - cursor_rect.rect.left);
@@ -490,20 +656,6 @@ where
 
                 let cursor_rect = rects.first().unwrap();
 
-                let alignment = cx.alignment();
-
-                let (mut top, _) = match alignment {
-                    Alignment::TopLeft => (0.0, 0.0),
-                    Alignment::TopCenter => (0.0, 0.5),
-                    Alignment::TopRight => (0.0, 1.0),
-                    Alignment::Left => (0.5, 0.0),
-                    Alignment::Center => (0.5, 0.5),
-                    Alignment::Right => (0.5, 1.0),
-                    Alignment::BottomLeft => (1.0, 0.0),
-                    Alignment::BottomCenter => (1.0, 0.5),
-                    Alignment::BottomRight => (1.0, 1.0),
-                };
-
                 let padding_top = match cx.padding_top() {
                     Units::Pixels(val) => val,
                     _ => 0.0,
@@ -514,7 +666,14 @@ where
                     _ => 0.0,
                 };
 
-                top *= bounds.height() - padding_top - padding_bottom - paragraph.height();
+                let top = text_top(
+                    cx.style,
+                    cx.current,
+                    paragraph,
+                    bounds.height(),
+                    padding_top,
+                    padding_bottom,
+                );
 
                 let padding_left = match cx.padding_left() {
                     Units::Pixels(val) => val,
@@ -576,6 +735,29 @@ impl<L: Lens> Handle<'_, Textbox<L>> {
         self.modify(|textbox: &mut Textbox<L>| textbox.on_cancel = Some(Box::new(callback)))
     }
 
+    /// Sets whether the text is selected when the textbox gains focus. Defaults to [`SelectOnFocus::Keyboard`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Lens)]
+    /// # struct AppData {
+    /// #     text: String,
+    /// # }
+    /// #
+    /// # impl Model for AppData {}
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// # AppData { text: String::from("Hello World") }.build(cx);
+    /// #
+    /// Textbox::new(cx, AppData::text).select_on_focus(SelectOnFocus::Always);
+    /// ```
+    pub fn select_on_focus(self, select_on_focus: SelectOnFocus) -> Self {
+        self.modify(|textbox| textbox.select_on_focus = select_on_focus)
+    }
+
     /// Sets a validation closure which is called when the textbox is edited and sets the validity attribute to the output of the closure.
     ///
     /// If a textbox is modified with the validate modifier then the `on_submit` will not be called if the text is invalid.
@@ -770,15 +952,21 @@ where
                         cx.set_checked(true);
                         cx.lock_cursor_icon();
 
+                        // Text which is selected when clicked into isn't deselected by the hit.
+                        let select_all =
+                            !self.edit && self.select_on_focus == SelectOnFocus::Always;
                         if !self.edit {
+                            self.focus_source = Some(FocusSource::Pointer);
                             cx.emit(TextEvent::StartEdit);
                         }
                         self.reset_caret_timer(cx);
-                        cx.emit(TextEvent::Hit(
-                            cx.mouse.cursor_x,
-                            cx.mouse.cursor_y,
-                            cx.modifiers.shift(),
-                        ));
+                        if !select_all {
+                            cx.emit(TextEvent::Hit(
+                                cx.mouse.cursor_x,
+                                cx.mouse.cursor_y,
+                                cx.modifiers.shift(),
+                            ));
+                        }
                     }
                 } else {
                     cx.emit(TextEvent::Submit(false));
@@ -799,6 +987,11 @@ where
                 if cx.mouse.left.pressed != cx.current()
                     || cx.mouse.left.state == MouseButtonState::Released
                 {
+                    self.focus_source = Some(if cx.is_focus_visible() {
+                        FocusSource::Keyboard
+                    } else {
+                        FocusSource::Programmatic
+                    });
                     cx.emit(TextEvent::StartEdit);
                 }
             }
@@ -1136,6 +1329,7 @@ where
             TextEvent::SetPlaceholder(text) => self.placeholder.clone_from(text),
 
             TextEvent::StartEdit => {
+                let focus_source = self.focus_source.take();
                 if !cx.is_disabled() && !self.edit {
                     self.edit = true;
                     cx.focus_with_visibility(false);
@@ -1146,7 +1340,7 @@ where
                     let text = self.lens.get(cx);
                     let text = text.to_string_local(cx);
 
-                    self.select_all(cx);
+                    self.select_for_focus(cx, focus_source);
 
                     if let Ok(value) = &text.parse::<L::Target>() {
                         if let Some(validate) = &self.validate {
@@ -1163,6 +1357,11 @@ where
             TextEvent::EndEdit => {
                 self.deselect();
                 self.edit = false;
+                if self.stale {
+                    self.stale = false;
+                    self.transform.set((0.0, 0.0));
+                    Self::reset_text(cx, self.lens);
+                }
                 cx.set_checked(false);
                 cx.release();
                 cx.stop_timer(self.caret_timer);
@@ -1194,10 +1393,16 @@ where
             }
 
             TextEvent::Submit(reason) => {
-                if let Some(callback) = &self.on_submit {
-                    if cx.is_valid() {
-                        let text = self.clone_text(cx);
-                        if let Ok(value) = text.parse::<L::Target>() {
+                if cx.is_valid() {
+                    let text = self.clone_text(cx);
+                    if let Ok(value) = text.parse::<L::Target>() {
+                        // The text is replaced with the written data when editing ends, which may be formatted
+                        // differently to the submitted text.
+                        if cx.write_lens(self.lens, value.clone()) {
+                            self.stale = true;
+                        }
+
+                        if let Some(callback) = &self.on_submit {
                             (callback)(cx, value, *reason);
                         }
                     }
@@ -1289,18 +1494,149 @@ where
 
     // Use custom drawing for the textbox so a transform can be applied to just the text.
     fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
-        cx.draw_shadows(canvas);
         cx.draw_background(canvas);
+        cx.draw_shadows(canvas);
         cx.draw_border(canvas);
         cx.draw_outline(canvas);
-        // canvas.save();
-        // canvas.translate(self.transform.0, self.transform.1);
-        // cx.draw_text_and_selection(canvas);
+
+        let viewport = self.text_viewport(cx);
+        let (tx, ty) = self.update_transform(cx, viewport);
+
+        canvas.save();
+        // Scrolled text is clipped to the padding of the textbox. A single line isn't clipped vertically so that
+        // glyphs taller than the textbox aren't cut off.
+        let clip = if self.kind == TextboxKind::SingleLine {
+            let bounds = cx.bounds();
+            BoundingBox { y: bounds.y, h: bounds.h, ..viewport }
+        } else {
+            viewport
+        };
+        canvas.clip_rect(Rect::from(clip), ClipOp::Intersect, false);
+        canvas.translate((tx, ty));
         cx.draw_text(canvas);
         if self.edit {
             self.draw_selection(cx, canvas);
             self.draw_text_caret(cx, canvas);
         }
-        // canvas.restore();
+        canvas.restore();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+    use crate::systems::binding_system;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[derive(Lens)]
+    struct AppData {
+        text: String,
+    }
+
+    impl Model for AppData {}
+
+    // Builds a textbox containing "Hello" and returns it with a count of its edits.
+    fn build(cx: &mut Context, select_on_focus: SelectOnFocus) -> (Entity, Arc<AtomicUsize>) {
+        AppData { text: String::from("Hello") }.build(cx);
+        let edits = Arc::new(AtomicUsize::new(0));
+        let counter = edits.clone();
+        let textbox = Textbox::new(cx, AppData::text)
+            .select_on_focus(select_on_focus)
+            .on_edit(move |_, _| {
+                counter.fetch_add(1, Ordering::SeqCst);
+            })
+            .entity();
+        (textbox, edits)
+    }
+
+    fn flush(cx: &mut Context, event_manager: &mut EventManager) {
+        event_manager.flush_events(cx, |_| {});
+        binding_system(cx);
+    }
+
+    fn focus(cx: &mut Context, event_manager: &mut EventManager, textbox: Entity, visible: bool) {
+        cx.with_current(textbox, |cx| cx.focus_with_visibility(visible));
+        flush(cx, event_manager);
+    }
+
+    fn selection<L: Lens>(cx: &Context, textbox: Entity, _lens: L) -> (usize, usize) {
+        let selection = cx
+            .views
+            .get(&textbox)
+            .and_then(|view| view.downcast_ref::<Textbox<L>>())
+            .map(|textbox| textbox.selection)
+            .unwrap();
+        (selection.anchor, selection.active)
+    }
+
+    #[test]
+    fn keyboard_focus_selects_text() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+        let (textbox, _) = build(&mut cx, SelectOnFocus::Keyboard);
+
+        focus(&mut cx, &mut event_manager, textbox, true);
+        assert_eq!(selection(&cx, textbox, AppData::text), (0, 5));
+    }
+
+    #[test]
+    fn programmatic_focus_places_caret_at_end() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+        let (textbox, _) = build(&mut cx, SelectOnFocus::Keyboard);
+
+        focus(&mut cx, &mut event_manager, textbox, false);
+        assert_eq!(selection(&cx, textbox, AppData::text), (5, 5));
+    }
+
+    #[test]
+    fn select_on_focus_overrides_focus_source() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+        let (always, _) = build(&mut cx, SelectOnFocus::Always);
+        let (never, _) = build(&mut cx, SelectOnFocus::Never);
+
+        focus(&mut cx, &mut event_manager, always, false);
+        assert_eq!(selection(&cx, always, AppData::text), (0, 5));
+
+        focus(&mut cx, &mut event_manager, never, true);
+        assert_eq!(selection(&cx, never, AppData::text), (5, 5));
+    }
+
+    #[test]
+    fn clicking_does_not_select_text() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+        let (textbox, _) = build(&mut cx, SelectOnFocus::Keyboard);
+
+        cx.hovered = textbox;
+        cx.style.pseudo_classes.get_mut(textbox).unwrap().insert(PseudoClassFlags::OVER);
+        cx.emit_custom(
+            Event::new(WindowEvent::MouseDown(MouseButton::Left))
+                .target(textbox)
+                .origin(Entity::root()),
+        );
+        flush(&mut cx, &mut event_manager);
+
+        assert_eq!(cx.focused, textbox);
+        let (anchor, active) = selection(&cx, textbox, AppData::text);
+        assert_eq!(anchor, active);
+    }
+
+    #[test]
+    fn typing_over_the_selection_is_a_single_edit() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+        let (textbox, edits) = build(&mut cx, SelectOnFocus::Keyboard);
+
+        focus(&mut cx, &mut event_manager, textbox, true);
+        assert_eq!(edits.load(Ordering::SeqCst), 0);
+
+        cx.emit_custom(Event::new(WindowEvent::CharInput('a')).target(textbox));
+        flush(&mut cx, &mut event_manager);
+        assert_eq!(edits.load(Ordering::SeqCst), 1);
+        assert_eq!(cx.style.text.get(textbox).map(String::as_str), Some("a"));
     }
 }
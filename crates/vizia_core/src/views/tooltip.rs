@@ -68,7 +68,7 @@ impl Tooltip {
             });
             (content)(cx);
         })
-        .z_index(110)
+        .layer(Layer::Tooltip)
         .hoverable(false)
         .position_type(PositionType::Absolute)
         .space(Pixels(0.0))
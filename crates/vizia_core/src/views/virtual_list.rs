@@ -4,16 +4,30 @@ use crate::prelude::*;
 
 #[derive(Lens)]
 pub struct VirtualList {
+    /// The range of indices of the items which are currently realized.
+    pub visible_range: Range<usize>,
+    /// The total number of items in the list.
+    pub num_items: usize,
+
     scroll_to_cursor: bool,
+    #[lens(ignore)]
     on_change: Option<Box<dyn Fn(&mut EventContext, Range<usize>)>>,
+    // The range which was last passed to the `on_change` callback.
+    reported_range: Range<usize>,
+    report_scheduled: bool,
 }
 
 pub enum VirtualListEvent {
     SetScrollY(f32),
 }
 
+enum VirtualListInternalEvent {
+    RangeChanged(Range<usize>, usize),
+    ReportRange,
+}
+
 #[derive(Lens)]
-struct VirtualListData {
+pub(super) struct VirtualListData {
     num_items: usize,
     item_height: f32,
     visible_range: Range<usize>,
@@ -21,7 +35,7 @@ struct VirtualListData {
 }
 
 impl VirtualListData {
-    fn evaluate_index(index: usize, start: usize, end: usize) -> usize {
+    pub(super) fn evaluate_index(index: usize, start: usize, end: usize) -> usize {
         match end - start {
             0 => 0,
             len => start + (len - (start % len) + index) % len,
@@ -32,6 +46,18 @@ impl VirtualListData {
         Self::visible_range.map(move |range| Self::evaluate_index(index, range.start, range.end))
     }
 
+    // Recalculates the visible range and notifies the list if it changed.
+    fn update(&mut self, cx: &mut EventContext) {
+        let previous_range = self.visible_range.clone();
+        self.recalc(cx);
+        if self.visible_range != previous_range {
+            cx.emit(VirtualListInternalEvent::RangeChanged(
+                self.visible_range.clone(),
+                self.num_items,
+            ));
+        }
+    }
+
     fn recalc(&mut self, cx: &mut EventContext) {
         if self.num_items == 0 {
             self.visible_range = 0..0;
@@ -71,14 +97,14 @@ impl Model for VirtualListData {
         event.map(|virtual_list_event, _| match virtual_list_event {
             VirtualListEvent::SetScrollY(scroll_y) => {
                 self.scroll_y = *scroll_y;
-                self.recalc(cx);
+                self.update(cx);
             }
         });
 
         event.map(|window_event, _| match window_event {
             WindowEvent::GeometryChanged(geo) => {
                 if geo.intersects(GeoChanged::WIDTH_CHANGED | GeoChanged::HEIGHT_CHANGED) {
-                    self.recalc(cx);
+                    self.update(cx);
                 }
             }
 
@@ -117,13 +143,25 @@ impl VirtualList {
     ) -> Handle<Self> {
         let vl = cx.current;
         let num_items = list.map(list_len);
-        Self { scroll_to_cursor: true, on_change: None }.build(cx, |cx| {
+        Self {
+            visible_range: 0..0,
+            num_items: 0,
+            scroll_to_cursor: true,
+            on_change: None,
+            reported_range: 0..0,
+            report_scheduled: false,
+        }
+        .build(cx, |cx| {
             Binding::new(cx, num_items, move |cx, lens| {
                 let num_items = lens.get(cx);
 
                 let mut data =
                     VirtualListData { num_items, item_height, visible_range: 0..0, scroll_y: 0.0 };
                 data.recalc(&mut EventContext::new_with_current(cx, vl));
+                cx.emit(VirtualListInternalEvent::RangeChanged(
+                    data.visible_range.clone(),
+                    num_items,
+                ));
                 data.build(cx);
             });
 
@@ -181,6 +219,35 @@ impl View for VirtualList {
     fn element(&self) -> Option<&'static str> {
         Some("virtual-list")
     }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|virtual_list_event, meta| match virtual_list_event {
+            VirtualListInternalEvent::RangeChanged(visible_range, num_items) => {
+                self.visible_range = visible_range.clone();
+                self.num_items = *num_items;
+
+                // Coalesce changes so the callback is called at most once per frame, with the latest range.
+                if self.on_change.is_some() && !self.report_scheduled {
+                    self.report_scheduled = true;
+                    cx.schedule_emit(VirtualListInternalEvent::ReportRange, Instant::now());
+                }
+
+                meta.consume();
+            }
+
+            VirtualListInternalEvent::ReportRange => {
+                self.report_scheduled = false;
+                if self.visible_range != self.reported_range {
+                    self.reported_range = self.visible_range.clone();
+                    if let Some(callback) = &self.on_change {
+                        (callback)(cx, self.visible_range.clone());
+                    }
+                }
+
+                meta.consume();
+            }
+        });
+    }
 }
 
 impl Handle<'_, VirtualList> {
@@ -189,11 +256,28 @@ impl Handle<'_, VirtualList> {
             virtual_list.scroll_to_cursor = flag;
         })
     }
+
+    /// Sets a callback which is called with the range of realized items whenever it changes.
+    ///
+    /// The callback is called at most once per frame, including while the list is being scrolled,
+    /// so it can be used to prefetch data as the visible range approaches the end of the loaded items.
+    pub fn on_visible_range_changed(
+        self,
+        callback: impl Fn(&mut EventContext, Range<usize>) + 'static,
+    ) -> Self {
+        self.modify(|virtual_list: &mut VirtualList| {
+            virtual_list.on_change = Some(Box::new(callback))
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::events::EventManager;
+    use crate::systems::binding_system;
+    use std::cell::RefCell;
+    use std::rc::Rc;
 
     fn evaluate_indices(range: Range<usize>) -> Vec<usize> {
         (0..range.len())
@@ -224,4 +308,92 @@ mod tests {
         // Move forward by 9
         assert_eq!(evaluate_indices(9..13), [12, 9, 10, 11]);
     }
+
+    #[derive(Lens)]
+    struct AppData {
+        items: Vec<u32>,
+    }
+
+    struct SetItems(Vec<u32>);
+
+    impl Model for AppData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|SetItems(items), _| self.items.clone_from(items));
+        }
+    }
+
+    // Delivers the queued events, and then the scheduled events, which are all due as they're scheduled for the
+    // current time.
+    fn run_frame(cx: &mut Context, event_manager: &mut EventManager) {
+        event_manager.flush_events(cx, |_| {});
+        binding_system(cx);
+        while let Some(timed_event) = cx.event_schedule.pop() {
+            cx.event_queue.push_back(timed_event.event);
+        }
+        event_manager.flush_events(cx, |_| {});
+    }
+
+    #[test]
+    fn range_changes_are_reported_once_per_frame() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+        let reported = Rc::new(RefCell::new(Vec::new()));
+
+        AppData { items: (0..100).collect() }.build(&mut cx);
+        let list =
+            VirtualList::new(&mut cx, AppData::items, 20.0, |cx, _, item| Label::new(cx, item))
+                .on_visible_range_changed({
+                    let reported = reported.clone();
+                    move |_, range| reported.borrow_mut().push(range)
+                })
+                .entity();
+        run_frame(&mut cx, &mut event_manager);
+        reported.borrow_mut().clear();
+
+        for range in [5..10, 6..11] {
+            cx.emit_custom(
+                Event::new(VirtualListInternalEvent::RangeChanged(range, 100))
+                    .target(list)
+                    .origin(list),
+            );
+        }
+        event_manager.flush_events(&mut cx, |_| {});
+
+        // The lenses follow every change, while the callback waits for the scheduled report.
+        assert_eq!(cx.with_current(list, |cx| VirtualList::visible_range.get(cx)), 6..11);
+        assert_eq!(cx.with_current(list, |cx| VirtualList::num_items.get(cx)), 100);
+        assert!(reported.borrow().is_empty());
+
+        run_frame(&mut cx, &mut event_manager);
+        assert_eq!(*reported.borrow(), [6..11]);
+
+        // A range which was already reported isn't reported again.
+        cx.emit_custom(
+            Event::new(VirtualListInternalEvent::RangeChanged(6..11, 100))
+                .target(list)
+                .origin(list),
+        );
+        run_frame(&mut cx, &mut event_manager);
+        assert_eq!(*reported.borrow(), [6..11]);
+    }
+
+    #[test]
+    fn range_lenses_follow_the_number_of_items() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+
+        AppData { items: Vec::new() }.build(&mut cx);
+        let list =
+            VirtualList::new(&mut cx, AppData::items, 20.0, |cx, _, item| Label::new(cx, item))
+                .entity();
+        run_frame(&mut cx, &mut event_manager);
+        assert_eq!(cx.with_current(list, |cx| VirtualList::num_items.get(cx)), 0);
+        assert_eq!(cx.with_current(list, |cx| VirtualList::visible_range.get(cx)), 0..0);
+
+        cx.emit_custom(Event::new(SetItems((0..3).collect())).target(Entity::root()));
+        run_frame(&mut cx, &mut event_manager);
+
+        assert_eq!(cx.with_current(list, |cx| VirtualList::num_items.get(cx)), 3);
+        assert!(cx.with_current(list, |cx| VirtualList::visible_range.get(cx)).end <= 3);
+    }
 }
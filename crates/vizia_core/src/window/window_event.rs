@@ -1,8 +1,8 @@
 use std::path::PathBuf;
 
 use crate::{entity::Entity, environment::ThemeMode, layout::cache::GeoChanged};
-use vizia_input::{Code, Key, MouseButton};
-use vizia_style::CursorIcon;
+use vizia_input::{Code, Key, MouseButton, PointerType, ScrollUnit};
+use vizia_style::{Color, CursorIcon};
 use vizia_window::{WindowPosition, WindowSize};
 
 #[derive(Debug, Clone)]
@@ -29,6 +29,21 @@ impl From<PathBuf> for DropData {
 pub enum WindowEvent {
     /// Emitted when a window is closed. Can also be emitted by a view or model to close the window.
     WindowClose,
+    /// Emitted when the user asks to close a window, for example with the close button of the titlebar or a
+    /// keyboard shortcut, or when a close is requested with [`EventContext::request_close`] or
+    /// [`EventContext::request_quit`].
+    ///
+    /// The window is closed with a [`WindowClose`](WindowEvent::WindowClose) event once the request has been
+    /// handled, unless a handler emits [`CancelClose`](WindowEvent::CancelClose).
+    ///
+    /// [`EventContext::request_close`]: crate::context::EventContext::request_close
+    /// [`EventContext::request_quit`]: crate::context::EventContext::request_quit
+    CloseRequested,
+    /// Cancels the pending close request of a window, keeping it open.
+    ///
+    /// When emitted while handling a [`CloseRequested`](WindowEvent::CloseRequested) event, the request of the
+    /// window being closed is cancelled. Otherwise the request of the window containing the target is cancelled.
+    CancelClose,
     /// Emitted when a file is dragged and then dropped onto the window.
     Drop(DropData),
     /// Emitted when a mouse button is double clicked.
@@ -49,7 +64,9 @@ pub enum WindowEvent {
     },
     /// Emitted when the mouse cursor is moved
     MouseMove(f32, f32),
-    /// Emitted when the mouse scroll wheel is scrolled.
+    /// Emitted when the mouse scroll wheel is scrolled, with the horizontal and vertical deltas in lines. Precise devices
+    /// such as trackpads report fractions of a line, converted from pixels with the scroll line height of the
+    /// [`InteractionSettings`](crate::input::InteractionSettings).
     MouseScroll(f32, f32),
     /// Emitted when the mouse cursor enters the bounding box of an entity.
     MouseOver,
@@ -59,6 +76,13 @@ pub enum WindowEvent {
     MouseEnter,
     /// Emitted when the mouse cursor leaves an entity.
     MouseLeave,
+    /// Emitted by the backend before the pointer events of a different type of pointer than the previous events,
+    /// for example when switching from a mouse to a touch screen. Touch input is delivered as left mouse button
+    /// events, which only hover views while the touch is pressed.
+    PointerTypeChanged(PointerType),
+    /// Emitted by the backend before a scroll event from a device which reports its deltas in a different unit than
+    /// the previous scroll event, for example when switching from a mouse wheel to a trackpad.
+    ScrollUnitChanged(ScrollUnit),
     // Emitted when an entity gains keyboard focus.
     FocusIn,
     // Emitted when an entity loses keyboard focus.
@@ -75,6 +99,8 @@ pub enum WindowEvent {
     KeyUp(Code, Option<Key>),
     /// Emited when the system window theme has changed.
     ThemeChanged(ThemeMode),
+    /// Emitted when the accent color of the operating system has been detected or has changed.
+    AccentColorChanged(Color),
     /// Sets the mouse cursor icon.
     SetCursor(CursorIcon),
     /// Grabs the mouse cursor, preventing it from leaving the window.
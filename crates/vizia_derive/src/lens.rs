@@ -179,6 +179,12 @@ fn derive_struct(input: &syn::DeriveInput) -> Result<proc_macro2::TokenStream, s
                     ::std::option::Option::Some(LensValue::Borrowed(&source.#field_name))
                 }
             }
+
+            impl #impl_generics LensMut for #twizzled_name::#field_name #lens_ty_generics #where_clause {
+                fn view_mut<'a>(&self, source: &'a mut #struct_type #ty_generics) -> ::std::option::Option<&'a mut Self::Target> {
+                    ::std::option::Option::Some(&mut source.#field_name)
+                }
+            }
         }
     });
 
@@ -244,6 +250,12 @@ fn derive_struct(input: &syn::DeriveInput) -> Result<proc_macro2::TokenStream, s
             }
         }
 
+        impl #impl_generics LensMut for #twizzled_name::root #lens_ty_generics {
+            fn view_mut<'a>(&self, source: &'a mut Self::Source) -> ::std::option::Option<&'a mut Self::Target> {
+                ::std::option::Option::Some(source)
+            }
+        }
+
         #[allow(non_upper_case_globals)]
         #[doc(hidden)]
         impl #impl_generics #struct_type #ty_generics #where_clause {
@@ -388,6 +400,16 @@ fn derive_enum(input: &syn::DeriveInput) -> Result<proc_macro2::TokenStream, syn
                 }
             }
 
+            impl LensMut for #twizzled_name::#variant_name {
+                fn view_mut<'a>(&self, source: &'a mut Self::Source) -> Option<&'a mut Self::Target> {
+                    if let #enum_type::#variant_name(inner_value) = source {
+                        ::std::option::Option::Some(inner_value)
+                    } else {
+                        ::std::option::Option::None
+                    }
+                }
+            }
+
             impl ::std::fmt::Debug for #twizzled_name::#variant_name {
                 fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
                     f.write_str(#name)
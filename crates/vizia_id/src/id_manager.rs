@@ -83,6 +83,11 @@ where
     pub fn is_alive(&self, id: I) -> bool {
         self.generation[id.index()] == id.generation()
     }
+
+    /// Returns the number of ids which are alive.
+    pub fn alive_count(&self) -> usize {
+        self.generation.len() - self.free_list.len()
+    }
 }
 
 #[cfg(test)]
@@ -147,6 +152,19 @@ mod tests {
         id_manager.destroy(Entity::new(5, 0));
     }
 
+    /// Test for counting the ids which are alive.
+    #[test]
+    fn alive_count() {
+        let mut id_manager = IdManager::<Entity>::new();
+        let baseline = id_manager.alive_count();
+        let id = id_manager.create();
+        id_manager.create();
+        assert_eq!(id_manager.alive_count(), baseline + 2);
+
+        id_manager.destroy(id);
+        assert_eq!(id_manager.alive_count(), baseline + 1);
+    }
+
     /// Test of removing an already removed id.
     #[test]
     fn destroy_twice() {
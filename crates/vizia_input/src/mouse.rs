@@ -17,6 +17,28 @@ pub enum MouseButton {
     Forward,
 }
 
+/// The type of device which produced a pointer event.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PointerType {
+    /// A mouse, or a trackpad which moves a cursor.
+    #[default]
+    Mouse,
+    /// A finger on a touch screen. A touch only has a position while it is pressed, so it doesn't hover views.
+    Touch,
+    /// A stylus on a graphics tablet or a touch screen.
+    Pen,
+}
+
+/// The unit of the deltas reported by a scrolling device.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ScrollUnit {
+    /// A mouse wheel which scrolls by whole lines for each tick.
+    #[default]
+    Lines,
+    /// A precise device, such as a trackpad, which reports the distance to scroll in pixels.
+    Pixels,
+}
+
 /// The state of a mouse button.
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum MouseButtonState {
@@ -79,6 +101,10 @@ where
     pub right: MouseButtonData<I>,
     /// The state of the middle mouse button.
     pub middle: MouseButtonData<I>,
+    /// The type of pointer which produced the last pointer event.
+    pub pointer_type: PointerType,
+    /// The unit of the deltas of the last scroll event.
+    pub scroll_unit: ScrollUnit,
 }
 
 impl<I> Default for MouseState<I>
@@ -94,6 +120,8 @@ where
             left: MouseButtonData::default(),
             right: MouseButtonData::default(),
             middle: MouseButtonData::default(),
+            pointer_type: PointerType::default(),
+            scroll_unit: ScrollUnit::default(),
         }
     }
 }
@@ -13,7 +13,7 @@ pub use self::{
     focus_iter::FocusTreeIterator,
     layout_child_iter::{DrawChildIterator, LayoutChildIterator},
     layout_tree_iter::{DrawTreeIterator, LayoutSiblingIterator, LayoutTreeIterator},
-    parent_iter::{LayoutParentIterator, ParentIterator},
+    parent_iter::{DataParentIterator, LayoutParentIterator, ParentIterator},
     tree_depth_iter::TreeDepthIterator,
     tree_iter::{TreeBreadthIterator, TreeIterator},
     tree_tour_iter::TreeTourIterator,
@@ -67,3 +67,82 @@ where
         }
     }
 }
+
+/// Iterator for iterating through the entities which an entity resolves its data through, starting with the entity.
+///
+/// This follows the layout parents of the entity, except where a data parent was set with
+/// [`Tree::set_data_parent`].
+pub struct DataParentIterator<'a, I>
+where
+    I: GenerationalId,
+{
+    tree: &'a Tree<I>,
+    current: Option<I>,
+}
+
+impl<'a, I> DataParentIterator<'a, I>
+where
+    I: GenerationalId,
+{
+    pub fn new(tree: &'a Tree<I>, current: I) -> Self {
+        Self { tree, current: Some(current) }
+    }
+}
+
+impl<I> Iterator for DataParentIterator<'_, I>
+where
+    I: GenerationalId,
+{
+    type Item = I;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(entity) = self.current {
+            self.current = self.tree.get_data_parent(entity);
+            Some(entity)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{TreeError, TreeExt};
+    use vizia_id::{
+        impl_generational_id, GENERATIONAL_ID_GENERATION_MASK, GENERATIONAL_ID_INDEX_BITS,
+        GENERATIONAL_ID_INDEX_MASK,
+    };
+
+    #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Entity(u64);
+
+    impl_generational_id!(Entity);
+
+    #[test]
+    fn data_parent_overrides_parent() -> Result<(), TreeError> {
+        let mut t = Tree::new();
+        let r = Entity::root();
+        let [a, b, c, d] = [1, 2, 3, 4].map(|i| Entity::new(i, 0));
+        t.add(a, r)?;
+        t.add(b, a)?;
+        t.add(c, r)?;
+        t.add(d, c)?;
+
+        t.set_data_parent(c, Some(b));
+        assert!(d.data_parent_iter(&t).eq([d, c, b, a, r]));
+        assert!(d.parent_iter(&t).eq([d, c, r]));
+
+        t.set_data_parent(c, None);
+        assert!(d.data_parent_iter(&t).eq([d, c, r]));
+
+        // The data parent is reset when the entity is removed.
+        t.set_data_parent(c, Some(b));
+        t.remove(d)?;
+        t.remove(c)?;
+        t.add(c, r)?;
+        assert!(c.data_parent_iter(&t).eq([c, r]));
+
+        Ok(())
+    }
+}
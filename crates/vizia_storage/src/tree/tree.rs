@@ -14,6 +14,7 @@ where
     pub ignored: Vec<bool>,
     pub window: Vec<bool>,
     pub lock_focus_within: Vec<bool>,
+    pub data_parent: Vec<Option<I>>,
     pub changed: bool,
 }
 
@@ -31,6 +32,7 @@ where
             ignored: vec![false],
             window: vec![false],
             lock_focus_within: vec![true],
+            data_parent: vec![None],
             changed: true,
         }
     }
@@ -131,6 +133,16 @@ where
         None
     }
 
+    /// Returns the entity which the given entity resolves its data through, which is its layout parent unless it
+    /// was overridden with [`set_data_parent`](Self::set_data_parent).
+    pub fn get_data_parent(&self, entity: I) -> Option<I> {
+        self.data_parent
+            .get(entity.index())
+            .copied()
+            .flatten()
+            .or_else(|| self.get_layout_parent(entity))
+    }
+
     /// Returns the parent of an entity.
     pub fn get_parent(&self, entity: I) -> Option<I> {
         self.parent.get(entity.index()).and_then(|&parent| parent)
@@ -309,6 +321,7 @@ where
         self.ignored[entity_index] = false;
         self.window[entity_index] = false;
         self.lock_focus_within[entity_index] = false;
+        self.data_parent[entity_index] = None;
 
         // Set the changed flag
         self.changed = true;
@@ -533,6 +546,14 @@ where
         }
     }
 
+    /// Sets the entity which the given entity resolves its data through instead of its parent, for example so that
+    /// content built elsewhere in the tree can still access the models of the view which declared it.
+    pub fn set_data_parent(&mut self, entity: I, data_parent: Option<I>) {
+        if let Some(result) = self.data_parent.get_mut(entity.index()) {
+            *result = data_parent;
+        }
+    }
+
     /// Adds an entity to the tree with the specified parent.
     pub fn add(&mut self, entity: I, parent: I) -> Result<(), TreeError> {
         if entity == I::null() || parent == I::null() {
@@ -555,6 +576,7 @@ where
             self.ignored.resize(entity_index + 1, false);
             self.window.resize(entity_index + 1, false);
             self.lock_focus_within.resize(entity_index + 1, false);
+            self.data_parent.resize(entity_index + 1, None);
         }
 
         self.parent[entity_index] = Some(parent);
@@ -564,6 +586,7 @@ where
         self.ignored[entity_index] = false;
         self.window[entity_index] = false;
         self.lock_focus_within[entity_index] = false;
+        self.data_parent[entity_index] = None;
 
         // If the parent has no first child then this entity is the first child
         if self.first_child[parent_index].is_none() {
@@ -1,4 +1,4 @@
-use crate::{LayoutChildIterator, LayoutParentIterator, Tree, TreeIterator};
+use crate::{DataParentIterator, LayoutChildIterator, LayoutParentIterator, Tree, TreeIterator};
 use vizia_id::GenerationalId;
 
 /// Trait which provides methods for querying the tree.
@@ -13,6 +13,7 @@ where
     fn is_descendant_of(&self, tree: &Tree<I>, entity: I) -> bool;
 
     fn parent_iter<'a>(&self, tree: &'a Tree<I>) -> LayoutParentIterator<'a, I>;
+    fn data_parent_iter<'a>(&self, tree: &'a Tree<I>) -> DataParentIterator<'a, I>;
     fn child_iter<'a>(&self, tree: &'a Tree<I>) -> LayoutChildIterator<'a, I>;
     fn tree_iter<'a>(&self, tree: &'a Tree<I>) -> TreeIterator<'a, I>;
     fn branch_iter<'a>(&self, tree: &'a Tree<I>) -> TreeIterator<'a, I>;
@@ -64,6 +65,10 @@ where
         LayoutParentIterator::new(tree, *self)
     }
 
+    fn data_parent_iter<'a>(&self, tree: &'a Tree<Self>) -> DataParentIterator<'a, I> {
+        DataParentIterator::new(tree, *self)
+    }
+
     fn child_iter<'a>(&self, tree: &'a Tree<Self>) -> LayoutChildIterator<'a, I> {
         LayoutChildIterator::new(tree, *self)
     }
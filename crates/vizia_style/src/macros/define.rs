@@ -59,6 +59,7 @@ macro_rules! define_property {
                 $(#[$meta])*
                 $variant($inner_ty),
             )+
+            CssWide(CssWideProperty<'i>),
             Unparsed(UnparsedProperty<'i>),
             Custom(CustomProperty<'i>),
         }
@@ -71,7 +72,21 @@ macro_rules! define_property {
                 match name_ref {
                     $(
                         $str => {
-                            if let Ok(val) = <$inner_ty>::parse(input) {
+                            if let Ok(keyword) = input.try_parse(|input| {
+                                let keyword = CssWideKeyword::parse(input)?;
+                                input.expect_exhausted()?;
+                                Ok(keyword)
+                            }) {
+                                return Ok($name::CssWide(CssWideProperty { name: name.clone(), keyword }));
+                            }
+
+                            // A value which isn't entirely valid, such as one containing a `var()` reference, is
+                            // kept as an unparsed property.
+                            if let Ok(val) = input.try_parse(|input| {
+                                let val = <$inner_ty>::parse(input)?;
+                                input.expect_exhausted()?;
+                                Ok(val)
+                            }) {
                                 return Ok($name::$variant(val));
                             }
                         }
@@ -91,12 +91,15 @@ mod test {
             None
         }
 
+        // Entities with consecutive ids in the store are siblings.
         fn prev_sibling_element(&self) -> Option<Self> {
-            None
+            let entity = Entity(self.entity.0.checked_sub(1)?);
+            self.store.element.contains_key(&entity).then_some(Node { entity, store: self.store })
         }
 
         fn next_sibling_element(&self) -> Option<Self> {
-            None
+            let entity = Entity(self.entity.0 + 1);
+            self.store.element.contains_key(&entity).then_some(Node { entity, store: self.store })
         }
 
         fn is_empty(&self) -> bool {
@@ -208,7 +211,7 @@ mod test {
                     crate::PseudoClass::ReadWrite => todo!(),
                     crate::PseudoClass::PlaceHolderShown => todo!(),
                     crate::PseudoClass::Default => todo!(),
-                    crate::PseudoClass::Checked => todo!(),
+                    crate::PseudoClass::Checked => psudeo_class_flag.contains(PseudoClass::CHECKED),
                     crate::PseudoClass::Indeterminate => todo!(),
                     crate::PseudoClass::Blank => todo!(),
                     crate::PseudoClass::Valid => todo!(),
@@ -464,4 +467,109 @@ mod test {
             assert!(!result);
         }
     }
+
+    #[test]
+    fn negation_match() {
+        let mut store = Store {
+            element: HashMap::new(),
+            classes: HashMap::new(),
+            pseudo_class: HashMap::new(),
+        };
+
+        let primary = Entity(0);
+        let secondary = Entity(1);
+
+        store.element.insert(primary, String::from("button"));
+        store.element.insert(secondary, String::from("button"));
+        store.classes.insert(primary, HashSet::from([String::from("primary")]));
+
+        let primary_node = Node { entity: primary, store: &store };
+        let secondary_node = Node { entity: secondary, store: &store };
+
+        let selector_list = parse("button:not(.primary)").unwrap();
+        let mut cache = SelectorCaches::default();
+        let mut context = MatchingContext::new(
+            MatchingMode::Normal,
+            None,
+            &mut cache,
+            QuirksMode::NoQuirks,
+            NeedsSelectorFlags::No,
+            MatchingForInvalidation::No,
+        );
+
+        assert!(!matches_selector_list(&selector_list, &primary_node, &mut context));
+        assert!(matches_selector_list(&selector_list, &secondary_node, &mut context));
+    }
+
+    #[test]
+    fn sibling_combinator_match() {
+        let mut store = Store {
+            element: HashMap::new(),
+            classes: HashMap::new(),
+            pseudo_class: HashMap::new(),
+        };
+
+        let checkbox = Entity(0);
+        let label = Entity(1);
+        let note = Entity(2);
+
+        store.element.insert(checkbox, String::from("checkbox"));
+        store.element.insert(label, String::from("label"));
+        store.element.insert(note, String::from("label"));
+        store.pseudo_class.insert(checkbox, PseudoClass::CHECKED);
+        store.pseudo_class.insert(label, PseudoClass::empty());
+        store.pseudo_class.insert(note, PseudoClass::empty());
+
+        let label_node = Node { entity: label, store: &store };
+        let note_node = Node { entity: note, store: &store };
+
+        let mut cache = SelectorCaches::default();
+        let mut context = MatchingContext::new(
+            MatchingMode::Normal,
+            None,
+            &mut cache,
+            QuirksMode::NoQuirks,
+            NeedsSelectorFlags::No,
+            MatchingForInvalidation::No,
+        );
+
+        let adjacent = parse("checkbox:checked + label").unwrap();
+        assert!(matches_selector_list(&adjacent, &label_node, &mut context));
+        assert!(!matches_selector_list(&adjacent, &note_node, &mut context));
+
+        let general = parse("checkbox:checked ~ label").unwrap();
+        assert!(matches_selector_list(&general, &label_node, &mut context));
+        assert!(matches_selector_list(&general, &note_node, &mut context));
+
+        let unchecked = parse("checkbox:not(:checked) ~ label").unwrap();
+        assert!(!matches_selector_list(&unchecked, &note_node, &mut context));
+
+        let chained = parse("checkbox + label + label").unwrap();
+        assert!(matches_selector_list(&chained, &note_node, &mut context));
+        assert!(!matches_selector_list(&chained, &label_node, &mut context));
+    }
+
+    fn specificity(a: u32, b: u32, c: u32) -> u32 {
+        a << 20 | b << 10 | c
+    }
+
+    fn specificities(input: &str) -> Vec<u32> {
+        parse(input).unwrap().slice().iter().map(|selector| selector.specificity()).collect()
+    }
+
+    #[test]
+    fn selector_specificity() {
+        assert_eq!(specificities("*"), vec![specificity(0, 0, 0)]);
+        assert_eq!(specificities("button label"), vec![specificity(0, 0, 2)]);
+        assert_eq!(specificities("#save.primary:hover"), vec![specificity(1, 2, 0)]);
+        assert_eq!(
+            specificities(".a, .b > label, #c"),
+            vec![specificity(0, 1, 0), specificity(0, 1, 1), specificity(1, 0, 0)]
+        );
+
+        // A negation has the specificity of its most specific argument.
+        assert_eq!(specificities("button:not(.primary)"), vec![specificity(0, 1, 1)]);
+        assert_eq!(specificities("button:not(.primary, #save)"), vec![specificity(1, 0, 1)]);
+        assert_eq!(specificities(":not(label)"), vec![specificity(0, 0, 1)]);
+    }
 }
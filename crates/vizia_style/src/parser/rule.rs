@@ -1,7 +1,7 @@
 use crate::{
     parse_declaration, CssRule, CssRuleList, CustomParseError, DeclarationBlock, DeclarationList,
-    KeyframeListParser, KeyframesName, KeyframesRule, Location, Parse, ParserOptions,
-    SelectorParser, Selectors, StyleRule,
+    KeyframeListParser, KeyframesName, KeyframesRule, Location, MediaQueryList, MediaRule, Parse,
+    ParserOptions, SelectorParser, Selectors, StyleRule,
 };
 use cssparser::*;
 use selectors::{parser::ParseRelative, SelectorList};
@@ -42,6 +42,7 @@ impl<'a, 'i> TopLevelRuleParser<'a, 'i> {
 pub enum AtRulePrelude<'i> {
     // Property(DashedIdent<'i>),
     Keyframes(KeyframesName<'i>),
+    Media(MediaQueryList),
 }
 
 impl<'i> AtRuleParser<'i> for TopLevelRuleParser<'_, 'i> {
@@ -178,6 +179,10 @@ impl<'i> AtRuleParser<'i> for NestedRuleParser<'_, 'i> {
                 let name = input.try_parse(KeyframesName::parse)?;
                 Ok(AtRulePrelude::Keyframes(name))
             },
+            "media" => {
+                let query = MediaQueryList::parse(input)?;
+                Ok(AtRulePrelude::Media(query))
+            },
             _ => Err(input.new_error(BasicParseErrorKind::AtRuleInvalid(name)))
         }
     }
@@ -200,6 +205,12 @@ impl<'i> AtRuleParser<'i> for NestedRuleParser<'_, 'i> {
                 }));
                 Ok(())
             }
+
+            AtRulePrelude::Media(query) => {
+                let (_, rules) = self.parse_nested(input, false)?;
+                self.rules.0.push(CssRule::Media(MediaRule { query, rules, loc }));
+                Ok(())
+            }
         }
     }
 }
@@ -1,13 +1,18 @@
 use crate::{
-    define_property, Alignment, Angle, BackgroundImage, BackgroundSize, BlendMode, Border,
-    BorderStyle, BorderWidth, ClipPath, Color, CornerRadius, CornerShape, CursorIcon,
-    CustomParseError, CustomProperty, Display, Filter, FontFamily, FontSize, FontSlant,
-    FontVariation, FontWeight, FontWidth, LayoutType, Length, LengthOrPercentage, LineClamp,
-    Opacity, Outline, Overflow, Parse, PointerEvents, Position, PositionType, Rect, Scale, Shadow,
-    TextAlign, TextDecoration, TextDecorationLine, TextDecorationStyle, TextOverflow, TextStroke,
-    TextStrokeStyle, Transform, Transition, Translate, Units, UnparsedProperty, Visibility,
+    define_property, Alignment, Angle, AspectRatio, Background, BackgroundImage, BackgroundRepeat,
+    BackgroundSize, BlendMode, Border, BorderStyle, BorderStyleKeyword, BorderWidth,
+    BorderWidthValue, ClipPath, Color, ColorOrGradient, CornerRadius, CornerRadiusValue,
+    CornerShape, CssWideKeyword, CssWideProperty, CursorIcon, CustomParseError, CustomProperty,
+    Display, Duration, Elevation, Filter, FontFamily, FontFeatureSettings, FontSize, FontSlant,
+    FontVariation, FontWeight, FontWidth, Gap, GridPlacement, KeyframeAnimation, LayoutType,
+    LayoutUnits, Length, LengthOrPercentage, LetterSpacing, LineClamp, LineHeight, Opacity,
+    Outline, Overflow, Parse, PointerEvents, Position, PositionType, PressEffect, Rect, Scale,
+    Shadow, StrokeLinecap, StrokeLinejoin, TextAlign, TextDecoration, TextDecorationLine,
+    TextDecorationStyle, TextOverflow, TextRendering, TextStroke, TextStrokeStyle, TextTransform,
+    Transform, Transition, Translate, Units, UnparsedProperty, VerticalAlign, Visibility,
+    WhiteSpace, WordSpacing,
 };
-use cssparser::Parser;
+use cssparser::{CowRcStr, Parser, ParserInput};
 
 define_property! {
     pub enum Property<'i> {
@@ -20,32 +25,43 @@ define_property! {
         "clip-path": ClipPath(ClipPath),
         "opacity": Opacity(Opacity),
         "z-index": ZIndex(i32),
+        "mix-blend-mode": MixBlendMode(BlendMode),
+        // An alias of `mix-blend-mode`.
         "blend-mode": BlendMode(BlendMode),
 
         // Positioning
         "layout-type": LayoutType(LayoutType),
         "position-type": PositionType(PositionType),
 
+        // Grid
+        "grid-template-columns": GridTemplateColumns(Vec<Units>),
+        "grid-template-rows": GridTemplateRows(Vec<Units>),
+        "grid-column": GridColumn(GridPlacement),
+        "grid-row": GridRow(GridPlacement),
+
         "alignment": Alignment(Alignment),
+        "align-baseline": AlignBaseline(bool),
+        "baseline-offset": BaselineOffset(Units),
 
         // Position and Size
-        "space": Space(Units),
-        "left": Left(Units),
-        "width": Width(Units),
-        "right": Right(Units),
-        "top": Top(Units),
-        "size": Size(Units),
-        "height": Height(Units),
-        "bottom": Bottom(Units),
+        "space": Space(LayoutUnits),
+        "left": Left(LayoutUnits),
+        "width": Width(LayoutUnits),
+        "right": Right(LayoutUnits),
+        "top": Top(LayoutUnits),
+        "size": Size(LayoutUnits),
+        "height": Height(LayoutUnits),
+        "aspect-ratio": AspectRatio(AspectRatio),
+        "bottom": Bottom(LayoutUnits),
 
         // Constraints
-        "min-size": MinSize(Units),
-        "min-width": MinWidth(Units),
-        "min-height": MinHeight(Units),
+        "min-size": MinSize(LayoutUnits),
+        "min-width": MinWidth(LayoutUnits),
+        "min-height": MinHeight(LayoutUnits),
 
-        "max-size": MaxSize(Units),
-        "max-width": MaxWidth(Units),
-        "max-height": MaxHeight(Units),
+        "max-size": MaxSize(LayoutUnits),
+        "max-width": MaxWidth(LayoutUnits),
+        "max-height": MaxHeight(LayoutUnits),
 
         "min-gap": MinGap(Units),
         "min-horizontal-gap": MinHorizontalGap(Units),
@@ -56,26 +72,28 @@ define_property! {
         "max-vertical-gap": MaxVerticalGap(Units),
 
         // Padding
-        "padding": Padding(Units),
-        "padding-left": PaddingLeft(Units),
-        "padding-right": PaddingRight(Units),
-        "padding-top": PaddingTop(Units),
-        "padding-bottom": PaddingBottom(Units),
+        "padding": Padding(LayoutUnits),
+        "padding-left": PaddingLeft(LayoutUnits),
+        "padding-right": PaddingRight(LayoutUnits),
+        "padding-top": PaddingTop(LayoutUnits),
+        "padding-bottom": PaddingBottom(LayoutUnits),
         "vertical-gap": VerticalGap(Units),
         "horizontal-gap": HorizontalGap(Units),
-        "gap": Gap(Units),
+        "gap": Gap(Gap),
+        // The CSS names of `vertical-gap` and `horizontal-gap`.
+        "row-gap": RowGap(Units),
+        "column-gap": ColumnGap(Units),
         // ----- Border -----
 
         // Border Shorthand
         "border": Border(Border),
 
         // Border Color
-        "border-color": BorderColor(Color),
-        // TODO: Support coloring individual borders.
-        // "border-top-color": BorderTopColor(Color),
-        // "border-right-color": BorderRightColor(Color),
-        // "border-bottom-color": BorderBottomColor(Color),
-        // "border-left-color": BorderLeftColor(Color),
+        "border-color": BorderColor(ColorOrGradient),
+        "border-top-color": BorderTopColor(Color),
+        "border-right-color": BorderRightColor(Color),
+        "border-bottom-color": BorderBottomColor(Color),
+        "border-left-color": BorderLeftColor(Color),
 
         // Corner Shape
         "corner-shape": CornerShape(Rect<CornerShape>),
@@ -86,25 +104,24 @@ define_property! {
 
         // Corner Radius
         "corner-radius": CornerRadius(CornerRadius),
-        "corner-top-left-radius": CornerTopLeftRadius(LengthOrPercentage),
-        "corner-top-right-radius": CornerTopRightRadius(LengthOrPercentage),
-        "corner-bottom-left-radius": CornerBottomLeftRadius(LengthOrPercentage),
-        "corner-bottom-right-radius": CornerBottomRightRadius(LengthOrPercentage),
+        "corner-top-left-radius": CornerTopLeftRadius(CornerRadiusValue),
+        "corner-top-right-radius": CornerTopRightRadius(CornerRadiusValue),
+        "corner-bottom-left-radius": CornerBottomLeftRadius(CornerRadiusValue),
+        "corner-bottom-right-radius": CornerBottomRightRadius(CornerRadiusValue),
 
         // Border Style
-        // TODO: Support styling borders.
         "border-style": BorderStyle(BorderStyle),
-        // "border-top-style": BorderTopStyle(BorderStyleKeyword),
-        // "border-right-style": BorderRightStyle(BorderStyleKeyword),
-        // "border-bottom-style": BorderBottomStyle(BorderStyleKeyword),
-        // "border-left-style": BorderLeftStyle(BorderStyleKeyword),
+        "border-top-style": BorderTopStyle(BorderStyleKeyword),
+        "border-right-style": BorderRightStyle(BorderStyleKeyword),
+        "border-bottom-style": BorderBottomStyle(BorderStyleKeyword),
+        "border-left-style": BorderLeftStyle(BorderStyleKeyword),
 
         // Border Width
         "border-width": BorderWidth(BorderWidth),
-        // "border-top-width": BorderTopWidth(BorderWidthValue),
-        // "border-right-width": BorderRightWidth(BorderWidthValue),
-        // "border-bottom-width": BorderBottomWidth(BorderWidthValue),
-        // "border-left-width": BorderLeftWidth(BorderWidthValue),
+        "border-top-width": BorderTopWidth(BorderWidthValue),
+        "border-right-width": BorderRightWidth(BorderWidthValue),
+        "border-bottom-width": BorderBottomWidth(BorderWidthValue),
+        "border-left-width": BorderLeftWidth(BorderWidthValue),
 
 
         // ----- Outline -----
@@ -113,7 +130,7 @@ define_property! {
         "outline": Outline(Outline),
 
         // Outline Color
-        "outline-color": OutlineColor(Color),
+        "outline-color": OutlineColor(ColorOrGradient),
         // TODO: Support coloring individual outlines.
         // "outline-top-color": OutlineTopColor(Color),
         // "outline-right-color": OutlineRightColor(Color),
@@ -121,8 +138,7 @@ define_property! {
         // "outline-left-color": OutlineLeftColor(Color),
 
         // Outline Style
-        // TODO: Support styling outlines.
-        // "outline-style": OutlineStyle(BorderStyle),
+        "outline-style": OutlineStyle(BorderStyleKeyword),
         // "outline-top-style": OutlineTopStyle(BorderStyleKeyword),
         // "outline-right-style": OutlineRightStyle(BorderStyleKeyword),
         // "outline-bottom-style": OutlineBottomStyle(BorderStyleKeyword),
@@ -136,32 +152,62 @@ define_property! {
         // "outline-left-width": OutlineLeftWidth(BorderWidthValue),
         "outline-offset": OutlineOffset(LengthOrPercentage),
 
+        // Focus Ring
+        "focus-ring": FocusRing(bool),
+        "focus-ring-color": FocusRingColor(Color),
+        "focus-ring-width": FocusRingWidth(LengthOrPercentage),
+        "focus-ring-offset": FocusRingOffset(LengthOrPercentage),
+
+        // Press Effect
+        "press-effect": PressEffect(PressEffect),
+        "ripple-color": RippleColor(Color),
+        "ripple-duration": RippleDuration(Duration),
+
         // Background
         "background-color": BackgroundColor(Color),
+        "background": Background(Background<'i>),
         "background-image": BackgroundImage(Vec<BackgroundImage<'i>>),
+        "background-position": BackgroundPosition(Vec<Position>),
         "background-size": BackgroundSize(Vec<BackgroundSize>),
+        "background-repeat": BackgroundRepeat(Vec<BackgroundRepeat>),
 
         "fill": Fill(Color),
+        "stroke": Stroke(Color),
+        "stroke-width": StrokeWidth(Length),
+        "stroke-linecap": StrokeLinecap(StrokeLinecap),
+        "stroke-linejoin": StrokeLinejoin(StrokeLinejoin),
 
         // Text
         "font-size": FontSize(FontSize),
-        "color": FontColor(Color),
+        "color": FontColor(ColorOrGradient),
         "font-family": FontFamily(Vec<FontFamily<'i>>),
         "font-variation-settings": FontVariationSettings(Vec<FontVariation>),
+        "font-feature-settings": FontFeatureSettings(FontFeatureSettings),
         "font-weight": FontWeight(FontWeight),
         "font-slant": FontSlant(FontSlant),
         "font-width": FontWidth(FontWidth),
         "selection-color": SelectionColor(Color), // TODO: Remove this once we have the pseudoselector version.
         "caret-color": CaretColor(Color),
+        "white-space": WhiteSpace(WhiteSpace),
+        // Kept for compatibility, where `true` is `white-space: pre-wrap` and `false` is `white-space: pre`.
         "text-wrap": TextWrap(bool),
         "text-align": TextAlign(TextAlign),
+        "vertical-align": VerticalAlign(VerticalAlign),
         "text-overflow": TextOverflow(TextOverflow),
         "line-clamp": LineClamp(LineClamp),
+        "line-height": LineHeight(LineHeight),
+        "text-transform": TextTransform(TextTransform),
+        "text-rendering": TextRendering(TextRendering),
         "text-decoration": TextDecoration(TextDecoration),
         "text-decoration-line": TextDecorationLine(TextDecorationLine),
+        "text-decoration-style": TextDecorationStyle(TextDecorationStyle),
+        "text-decoration-color": TextDecorationColor(Color),
+        "text-decoration-thickness": TextDecorationThickness(LengthOrPercentage),
         "text-stroke": TextStroke(TextStroke),
         "text-stroke-width": TextStrokeWidth(Length),
         "text-stroke-style": TextStrokeStyle(TextStrokeStyle),
+        "letter-spacing": LetterSpacing(LetterSpacing),
+        "word-spacing": WordSpacing(WordSpacing),
         "underline-style": UnderlineStyle(TextDecorationStyle),
         "underline-thickness": UnderlineThickness(LengthOrPercentage),
         "underline-color": UnderlineColor(Color),
@@ -174,12 +220,15 @@ define_property! {
 
         // Shadow
         "shadow": Shadow(Vec<Shadow>),
+        "elevation": Elevation(Elevation),
 
-        // Backdrop Filter
-        "backdrop-filter": BackdropFilter(Filter),
+        // Filter
+        "filter": Filter(Vec<Filter>),
+        "backdrop-filter": BackdropFilter(Vec<Filter>),
 
         // Animations
         "transition": Transition(Vec<Transition>),
+        "animation": Animation(Vec<KeyframeAnimation>),
 
         // Transform
         "transform": Transform(Vec<Transform>),
@@ -194,12 +243,132 @@ define_property! {
     }
 }
 
+impl<'i> Property<'i> {
+    /// Parses the value of a single declaration, such as `red` for a `background-color` declaration.
+    ///
+    /// Returns `None` if the value isn't valid for the property, or if the property isn't known.
+    pub fn parse_declaration(name: &'i str, value: &'i str) -> Option<Self> {
+        let mut input = ParserInput::new(value);
+        let mut parser = Parser::new(&mut input);
+        let property = Property::parse_value(CowRcStr::from(name), &mut parser).ok()?;
+        if !parser.is_exhausted() {
+            return None;
+        }
+
+        match property {
+            Property::Unparsed(_) | Property::Custom(_) => None,
+            property => Some(property),
+        }
+    }
+
+    /// Returns true if the property with the given name is inherited, so a view which doesn't set it takes the value
+    /// of its parent.
+    pub fn is_inherited(name: &str) -> bool {
+        matches!(
+            name,
+            "color"
+                | "font-family"
+                | "font-size"
+                | "font-weight"
+                | "font-slant"
+                | "font-width"
+                | "font-variation-settings"
+                | "font-feature-settings"
+                | "line-height"
+                | "text-transform"
+                | "text-rendering"
+                | "letter-spacing"
+                | "word-spacing"
+                | "text-decoration"
+                | "text-decoration-line"
+                | "text-decoration-style"
+                | "text-decoration-color"
+                | "text-decoration-thickness"
+                | "text-stroke"
+                | "text-stroke-width"
+                | "text-stroke-style"
+                | "caret-color"
+                | "selection-color"
+                | "focus-ring-color"
+                | "focus-ring-width"
+                | "focus-ring-offset"
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use cssparser::{CowRcStr, ParserInput};
 
     use super::*;
 
+    #[test]
+    fn parse_declaration() {
+        assert_eq!(
+            Property::parse_declaration("background-color", "red"),
+            Some(Property::BackgroundColor(Color::rgb(255, 0, 0)))
+        );
+        assert_eq!(Property::parse_declaration("background-color", "red blue"), None);
+        assert_eq!(Property::parse_declaration("width", "red"), None);
+        assert!(matches!(
+            Property::parse_declaration("width", "calc(100% - 20px)"),
+            Some(Property::Width(LayoutUnits::Calc(_)))
+        ));
+        assert_eq!(Property::parse_declaration("not-a-property", "red"), None);
+    }
+
+    #[test]
+    fn parse_css_wide_keywords() {
+        let keyword = |name, keyword| {
+            Some(Property::CssWide(CssWideProperty { name: CowRcStr::from(name), keyword }))
+        };
+
+        assert_eq!(
+            Property::parse_declaration("color", "inherit"),
+            keyword("color", CssWideKeyword::Inherit)
+        );
+        assert_eq!(
+            Property::parse_declaration("background-color", "INITIAL"),
+            keyword("background-color", CssWideKeyword::Initial)
+        );
+        assert_eq!(
+            Property::parse_declaration("border", "unset"),
+            keyword("border", CssWideKeyword::Unset)
+        );
+        assert_eq!(Property::parse_declaration("color", "inherit red"), None);
+        assert_eq!(Property::parse_declaration("not-a-property", "inherit"), None);
+    }
+
+    #[test]
+    fn parse_border_longhands() {
+        assert_eq!(
+            Property::parse_declaration("border-bottom-width", "thin"),
+            Some(Property::BorderBottomWidth(BorderWidthValue(Length::px(1.0).into())))
+        );
+        assert_eq!(
+            Property::parse_declaration("border-left-color", "red"),
+            Some(Property::BorderLeftColor(Color::rgb(255, 0, 0)))
+        );
+        assert_eq!(
+            Property::parse_declaration("border-top-style", "dashed"),
+            Some(Property::BorderTopStyle(BorderStyleKeyword::Dashed))
+        );
+        assert_eq!(Property::parse_declaration("border-right-style", "dashed dotted"), None);
+    }
+
+    #[test]
+    fn parse_filters() {
+        assert_eq!(
+            Property::parse_declaration("filter", "grayscale(100%) blur(2px)"),
+            Some(Property::Filter(vec![Filter::Grayscale(1.0), Filter::Blur(Length::px(2.0))]))
+        );
+        assert_eq!(
+            Property::parse_declaration("backdrop-filter", "none"),
+            Some(Property::BackdropFilter(vec![]))
+        );
+        assert_eq!(Property::parse_declaration("filter", "none blur(2px)"), None);
+    }
+
     #[test]
     fn parse_property() {
         let mut parser_input = ParserInput::new("red");
@@ -28,6 +28,8 @@ pub enum PseudoClass {
     Optional,
     UserValid,
     UserInvalid,
+    Dragging,
+    ActiveDescendant,
 
     Lang(Vec<String>),
     Dir(Direction),
@@ -63,6 +65,8 @@ impl ToCss for PseudoClass {
             PseudoClass::Optional => dest.write_str(":optional"),
             PseudoClass::UserValid => dest.write_str(":user-valid"),
             PseudoClass::UserInvalid => dest.write_str(":user-invalid"),
+            PseudoClass::Dragging => dest.write_str(":dragging"),
+            PseudoClass::ActiveDescendant => dest.write_str(":active-descendant"),
             PseudoClass::Lang(ref _lang) => dest.write_str(":lang()"),
             PseudoClass::Dir(_) => dest.write_str(":dir()"),
             PseudoClass::Custom(_) => dest.write_str(":custom"),
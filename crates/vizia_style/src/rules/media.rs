@@ -0,0 +1,198 @@
+use cssparser::*;
+
+use crate::{define_enum, CssRuleList, CustomParseError, Location, Parse};
+
+define_enum! {
+    /// The color scheme of an application, compared with by the `prefers-color-scheme` media feature.
+    #[derive(Default)]
+    pub enum ColorScheme {
+        #[default]
+        "light": Light,
+        "dark": Dark,
+    }
+}
+
+/// The state of a window which media queries are evaluated against.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct MediaState {
+    /// The width of the window in logical pixels.
+    pub width: f32,
+    /// The height of the window in logical pixels.
+    pub height: f32,
+    /// The color scheme of the application.
+    pub color_scheme: ColorScheme,
+}
+
+/// A feature of a media query, such as `(min-width: 600px)`. Sizes are in logical pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MediaFeature {
+    MinWidth(f32),
+    MaxWidth(f32),
+    MinHeight(f32),
+    MaxHeight(f32),
+    PrefersColorScheme(ColorScheme),
+}
+
+impl MediaFeature {
+    /// Returns true if the feature matches the given state.
+    pub fn matches(&self, state: &MediaState) -> bool {
+        match *self {
+            MediaFeature::MinWidth(width) => state.width >= width,
+            MediaFeature::MaxWidth(width) => state.width <= width,
+            MediaFeature::MinHeight(height) => state.height >= height,
+            MediaFeature::MaxHeight(height) => state.height <= height,
+            MediaFeature::PrefersColorScheme(color_scheme) => state.color_scheme == color_scheme,
+        }
+    }
+}
+
+// Parses a length in pixels, which is the only unit allowed in the size features.
+fn parse_pixels<'i>(
+    input: &mut Parser<'i, '_>,
+) -> Result<f32, ParseError<'i, CustomParseError<'i>>> {
+    let location = input.current_source_location();
+    match input.next()? {
+        Token::Dimension { value, unit, .. } if unit.eq_ignore_ascii_case("px") => Ok(*value),
+        Token::Number { value, .. } if *value == 0.0 => Ok(0.0),
+        token => Err(location.new_unexpected_token_error(token.clone())),
+    }
+}
+
+impl<'i> Parse<'i> for MediaFeature {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        input.expect_parenthesis_block()?;
+        input.parse_nested_block(|input| {
+            let location = input.current_source_location();
+            let name = input.expect_ident_cloned()?;
+            input.expect_colon()?;
+
+            match_ignore_ascii_case! { &*name,
+                "min-width" => Ok(MediaFeature::MinWidth(parse_pixels(input)?)),
+                "max-width" => Ok(MediaFeature::MaxWidth(parse_pixels(input)?)),
+                "min-height" => Ok(MediaFeature::MinHeight(parse_pixels(input)?)),
+                "max-height" => Ok(MediaFeature::MaxHeight(parse_pixels(input)?)),
+                "prefers-color-scheme" => Ok(MediaFeature::PrefersColorScheme(ColorScheme::parse(input)?)),
+                _ => Err(location.new_unexpected_token_error(Token::Ident(name.clone()))),
+            }
+        })
+    }
+}
+
+/// A media query, such as `screen and (max-width: 600px)`, which matches when all of its features match.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MediaQuery {
+    pub features: Vec<MediaFeature>,
+}
+
+impl MediaQuery {
+    /// Returns true if all of the features of the query match the given state.
+    pub fn matches(&self, state: &MediaState) -> bool {
+        self.features.iter().all(|feature| feature.matches(state))
+    }
+}
+
+impl<'i> Parse<'i> for MediaQuery {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        // Windows are the only media, so the `all` and `screen` media types match any window.
+        let has_media_type = input
+            .try_parse(|input| {
+                let location = input.current_source_location();
+                let ident = input.expect_ident()?;
+                match_ignore_ascii_case! { ident,
+                    "all" | "screen" => Ok(()),
+                    _ => Err(location.new_unexpected_token_error(Token::Ident(ident.clone()))),
+                }
+            })
+            .is_ok();
+
+        let mut features = Vec::new();
+        if !has_media_type || input.try_parse(|input| input.expect_ident_matching("and")).is_ok() {
+            features.push(MediaFeature::parse(input)?);
+            while input.try_parse(|input| input.expect_ident_matching("and")).is_ok() {
+                features.push(MediaFeature::parse(input)?);
+            }
+        }
+
+        Ok(MediaQuery { features })
+    }
+}
+
+/// A comma separated list of media queries, which matches when any of the queries match.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MediaQueryList(pub Vec<MediaQuery>);
+
+impl MediaQueryList {
+    /// Returns true if any of the queries in the list match the given state.
+    pub fn matches(&self, state: &MediaState) -> bool {
+        self.0.iter().any(|query| query.matches(state))
+    }
+}
+
+impl<'i> Parse<'i> for MediaQueryList {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        Ok(MediaQueryList(input.parse_comma_separated(MediaQuery::parse)?))
+    }
+}
+
+/// A `@media` rule, whose rules apply while its media queries match.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MediaRule<'i> {
+    pub query: MediaQueryList,
+    pub rules: CssRuleList<'i>,
+    pub loc: Location,
+}
+
+#[cfg(test)]
+mod tests_media {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        MediaQueryList, assert_media_query_list,
+
+        custom {
+            success {
+                "(max-width: 600px)" => MediaQueryList(vec![
+                    MediaQuery { features: vec![MediaFeature::MaxWidth(600.0)] },
+                ]),
+                "screen and (min-width: 200px) and (max-height: 0)" => MediaQueryList(vec![
+                    MediaQuery { features: vec![MediaFeature::MinWidth(200.0), MediaFeature::MaxHeight(0.0)] },
+                ]),
+                "all" => MediaQueryList(vec![MediaQuery { features: vec![] }]),
+                "(prefers-color-scheme: dark), (min-height: 10px)" => MediaQueryList(vec![
+                    MediaQuery { features: vec![MediaFeature::PrefersColorScheme(ColorScheme::Dark)] },
+                    MediaQuery { features: vec![MediaFeature::MinHeight(10.0)] },
+                ]),
+            }
+
+            failure {
+                "(max-width: 600em)",
+                "(width: 600px)",
+                "(prefers-color-scheme: blue)",
+                "print and (max-width: 600px)",
+                "(max-width 600px)",
+            }
+        }
+    }
+
+    #[test]
+    fn media_query_matches() {
+        let state = MediaState { width: 500.0, height: 300.0, color_scheme: ColorScheme::Dark };
+        let query = |features| MediaQuery { features };
+
+        assert!(query(vec![MediaFeature::MaxWidth(600.0)]).matches(&state));
+        assert!(query(vec![MediaFeature::MinWidth(500.0), MediaFeature::MaxWidth(500.0)])
+            .matches(&state));
+        assert!(!query(vec![MediaFeature::MaxWidth(600.0), MediaFeature::MinHeight(400.0)])
+            .matches(&state));
+        assert!(query(vec![MediaFeature::PrefersColorScheme(ColorScheme::Dark)]).matches(&state));
+        assert!(!query(vec![MediaFeature::PrefersColorScheme(ColorScheme::Light)]).matches(&state));
+        assert!(query(vec![]).matches(&state));
+
+        let list = MediaQueryList(vec![
+            query(vec![MediaFeature::MinWidth(800.0)]),
+            query(vec![MediaFeature::MaxHeight(300.0)]),
+        ]);
+        assert!(list.matches(&state));
+    }
+}
@@ -112,6 +112,8 @@ impl<'i> selectors::parser::Parser<'i> for SelectorParser<'_, 'i> {
             "optional" => Optional,
             "user-valid" => UserValid,
             "user-invalid" => UserInvalid,
+            "dragging" => Dragging,
+            "active-descendant" => ActiveDescendant,
 
             _ => Custom(name.to_string())
 
@@ -0,0 +1,250 @@
+use crate::{
+    duration::Duration, AnimationDirection, AnimationFillMode, CustomParseError, EasingFunction,
+    KeyframesName, Parse,
+};
+use cssparser::{ParseError, ParseErrorKind, Parser, Token};
+
+/// The number of times an animation repeats.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnimationIterationCount {
+    /// The animation repeats the given number of times, which can be fractional to stop part way through an iteration.
+    Count(f32),
+    /// The animation repeats until it's stopped.
+    Infinite,
+}
+
+impl Default for AnimationIterationCount {
+    fn default() -> Self {
+        AnimationIterationCount::Count(1.0)
+    }
+}
+
+impl AnimationIterationCount {
+    /// Returns the number of iterations, which is infinite for an animation which repeats until it's stopped.
+    pub fn as_f32(&self) -> f32 {
+        match self {
+            AnimationIterationCount::Count(count) => *count,
+            AnimationIterationCount::Infinite => f32::INFINITY,
+        }
+    }
+}
+
+impl<'i> Parse<'i> for AnimationIterationCount {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let location = input.current_source_location();
+        match input.next()? {
+            Token::Number { value, .. } if *value >= 0.0 => {
+                Ok(AnimationIterationCount::Count(*value))
+            }
+            Token::Ident(ident) if ident.eq_ignore_ascii_case("infinite") => {
+                Ok(AnimationIterationCount::Infinite)
+            }
+            token => Err(location.new_unexpected_token_error(token.clone())),
+        }
+    }
+}
+
+/// An animation of the keyframes of a `@keyframes` rule, set by the `animation` property.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyframeAnimation {
+    /// The name of the `@keyframes` rule, or `None` for an animation which plays nothing.
+    pub name: Option<String>,
+    /// The duration of each iteration of the animation.
+    pub duration: Duration,
+    /// The timing function applied between each pair of keyframes.
+    pub timing_function: EasingFunction,
+    /// The number of times the animation repeats.
+    pub iteration_count: AnimationIterationCount,
+    /// The direction the keyframes are played in.
+    pub direction: AnimationDirection,
+    /// The delay before the animation starts.
+    pub delay: Duration,
+    /// Whether the values of the animation apply during its delay and after it finishes.
+    pub fill_mode: AnimationFillMode,
+}
+
+impl Default for KeyframeAnimation {
+    fn default() -> Self {
+        Self {
+            name: None,
+            duration: Duration::ZERO,
+            timing_function: EasingFunction::Ease,
+            iteration_count: AnimationIterationCount::default(),
+            direction: AnimationDirection::default(),
+            delay: Duration::ZERO,
+            fill_mode: AnimationFillMode::default(),
+        }
+    }
+}
+
+impl KeyframeAnimation {
+    /// Creates a new animation of the `@keyframes` rule with the given name, which plays once.
+    pub fn new(name: impl Into<String>, duration: Duration) -> Self {
+        Self { name: Some(name.into()), duration, ..Default::default() }
+    }
+}
+
+impl<'i> Parse<'i> for KeyframeAnimation {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let location = input.current_source_location();
+
+        let mut name = None;
+        let mut duration = None;
+        let mut delay = None;
+        let mut timing_function = None;
+        let mut iteration_count = None;
+        let mut direction = None;
+        let mut fill_mode = None;
+
+        // The values can be in any order, except that the first time is the duration and the second is the delay.
+        loop {
+            if duration.is_none() {
+                if let Ok(value) = input.try_parse(Duration::parse) {
+                    duration = Some(value);
+                    continue;
+                }
+            } else if delay.is_none() {
+                if let Ok(value) = input.try_parse(Duration::parse) {
+                    delay = Some(value);
+                    continue;
+                }
+            }
+
+            if timing_function.is_none() {
+                if let Ok(value) = input.try_parse(EasingFunction::parse) {
+                    timing_function = Some(value);
+                    continue;
+                }
+            }
+
+            if iteration_count.is_none() {
+                if let Ok(value) = input.try_parse(AnimationIterationCount::parse) {
+                    iteration_count = Some(value);
+                    continue;
+                }
+            }
+
+            if direction.is_none() {
+                if let Ok(value) = input.try_parse(AnimationDirection::parse) {
+                    direction = Some(value);
+                    continue;
+                }
+            }
+
+            if fill_mode.is_none() {
+                if let Ok(value) = input.try_parse(AnimationFillMode::parse) {
+                    fill_mode = Some(value);
+                    continue;
+                }
+            }
+
+            if name.is_none() {
+                if let Ok(value) = input.try_parse(KeyframesName::parse) {
+                    name = Some(value.as_string());
+                    continue;
+                }
+            }
+
+            break;
+        }
+
+        if !input.is_exhausted() {
+            return Err(ParseError {
+                kind: ParseErrorKind::Custom(CustomParseError::InvalidDeclaration),
+                location,
+            });
+        }
+
+        let default = KeyframeAnimation::default();
+        Ok(KeyframeAnimation {
+            name,
+            duration: duration.unwrap_or(default.duration),
+            timing_function: timing_function.unwrap_or(default.timing_function),
+            iteration_count: iteration_count.unwrap_or(default.iteration_count),
+            direction: direction.unwrap_or(default.direction),
+            delay: delay.unwrap_or(default.delay),
+            fill_mode: fill_mode.unwrap_or(default.fill_mode),
+        })
+    }
+}
+
+impl<'i> Parse<'i> for Vec<KeyframeAnimation> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        input.parse_comma_separated(KeyframeAnimation::parse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        AnimationIterationCount, assert_iteration_count,
+
+        custom {
+            success {
+                "3" => AnimationIterationCount::Count(3.0),
+                "0.5" => AnimationIterationCount::Count(0.5),
+                "infinite" => AnimationIterationCount::Infinite,
+            }
+
+            failure {
+                "-1",
+                "forever",
+                "2s",
+            }
+        }
+    }
+
+    assert_parse! {
+        KeyframeAnimation, assert_keyframe_animation,
+
+        custom {
+            success {
+                "fade 2s" => KeyframeAnimation::new("fade", Duration::from_secs(2)),
+                "pulse 500ms linear 1s infinite alternate both" => KeyframeAnimation {
+                    timing_function: EasingFunction::Linear,
+                    iteration_count: AnimationIterationCount::Infinite,
+                    direction: AnimationDirection::Alternate,
+                    delay: Duration::from_secs(1),
+                    fill_mode: AnimationFillMode::Both,
+                    ..KeyframeAnimation::new("pulse", Duration::from_millis(500))
+                },
+                "3 reverse forwards 1s \"spin\"" => KeyframeAnimation {
+                    iteration_count: AnimationIterationCount::Count(3.0),
+                    direction: AnimationDirection::Reverse,
+                    fill_mode: AnimationFillMode::Forwards,
+                    ..KeyframeAnimation::new("spin", Duration::from_secs(1))
+                },
+                "none" => KeyframeAnimation::default(),
+            }
+
+            failure {
+                "fade 1s 2s 3s",
+                "fade spin 1s",
+                "fade 1s,",
+            }
+        }
+    }
+
+    assert_parse! {
+        Vec<KeyframeAnimation>, assert_keyframe_animations,
+
+        custom {
+            success {
+                "fade 1s, spin 2s 3" => vec![
+                    KeyframeAnimation::new("fade", Duration::from_secs(1)),
+                    KeyframeAnimation {
+                        iteration_count: AnimationIterationCount::Count(3.0),
+                        ..KeyframeAnimation::new("spin", Duration::from_secs(2))
+                    },
+                ],
+            }
+
+            failure {
+                "fade 1s, 2s 3s 4s",
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{define_enum, Parse};
+
+define_enum! {
+    /// Whether an animation plays its keyframes forwards, backwards, or alternates between the two on each iteration.
+    #[derive(Default)]
+    pub enum AnimationDirection {
+        /// Each iteration plays from the first keyframe to the last.
+        #[default]
+        "normal": Normal,
+        /// Each iteration plays from the last keyframe to the first.
+        "reverse": Reverse,
+        /// The first iteration plays forwards, and the direction changes on each iteration after it.
+        "alternate": Alternate,
+        /// The first iteration plays backwards, and the direction changes on each iteration after it.
+        "alternate-reverse": AlternateReverse,
+    }
+}
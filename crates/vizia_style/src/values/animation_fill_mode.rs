@@ -0,0 +1,29 @@
+use crate::{define_enum, Parse};
+
+define_enum! {
+    /// Whether the values of an animation apply before it starts, during its delay, and after it finishes.
+    #[derive(Default)]
+    pub enum AnimationFillMode {
+        /// The values of the animation only apply while it plays.
+        #[default]
+        "none": None,
+        /// The values of the last keyframe played continue to apply after the animation finishes.
+        "forwards": Forwards,
+        /// The values of the first keyframe apply during the delay of the animation.
+        "backwards": Backwards,
+        /// The values of the animation apply both during its delay and after it finishes.
+        "both": Both,
+    }
+}
+
+impl AnimationFillMode {
+    /// Returns true if the values of the animation apply during its delay.
+    pub fn fills_backwards(&self) -> bool {
+        matches!(self, AnimationFillMode::Backwards | AnimationFillMode::Both)
+    }
+
+    /// Returns true if the values of the animation apply after it finishes.
+    pub fn fills_forwards(&self) -> bool {
+        matches!(self, AnimationFillMode::Forwards | AnimationFillMode::Both)
+    }
+}
@@ -0,0 +1,107 @@
+use cssparser::*;
+
+use crate::{CustomParseError, Parse};
+
+/// The preferred ratio of the width to the height of a view, which derives the size of an axis from the other.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub enum AspectRatio {
+    /// The view has no preferred ratio.
+    #[default]
+    Auto,
+    /// The view prefers a ratio of the given width to the given height.
+    Ratio(f32, f32),
+}
+
+impl AspectRatio {
+    /// Returns the width divided by the height, or `None` if there is no preferred ratio or either of them is zero.
+    pub fn ratio(&self) -> Option<f32> {
+        match *self {
+            AspectRatio::Ratio(width, height) if width > 0.0 && height > 0.0 => {
+                Some(width / height)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'i> Parse<'i> for AspectRatio {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+            return Ok(AspectRatio::Auto);
+        }
+
+        let location = input.current_source_location();
+        let width = input.expect_number()?;
+        let height = if input.try_parse(|input| input.expect_delim('/')).is_ok() {
+            input.expect_number()?
+        } else {
+            1.0
+        };
+
+        if width < 0.0 || height < 0.0 {
+            return Err(location.new_custom_error(CustomParseError::InvalidValue));
+        }
+
+        Ok(AspectRatio::Ratio(width, height))
+    }
+}
+
+impl From<f32> for AspectRatio {
+    fn from(ratio: f32) -> Self {
+        AspectRatio::Ratio(ratio, 1.0)
+    }
+}
+
+impl From<f64> for AspectRatio {
+    fn from(ratio: f64) -> Self {
+        AspectRatio::Ratio(ratio as f32, 1.0)
+    }
+}
+
+impl From<(f32, f32)> for AspectRatio {
+    fn from((width, height): (f32, f32)) -> Self {
+        AspectRatio::Ratio(width, height)
+    }
+}
+
+impl From<&str> for AspectRatio {
+    fn from(s: &str) -> Self {
+        let mut input = ParserInput::new(s);
+        let mut parser = Parser::new(&mut input);
+        AspectRatio::parse(&mut parser).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        AspectRatio, parse_aspect_ratio,
+
+        custom {
+            success {
+                "auto" => AspectRatio::Auto,
+                "1.5" => AspectRatio::Ratio(1.5, 1.0),
+                "16 / 9" => AspectRatio::Ratio(16.0, 9.0),
+                "4/3" => AspectRatio::Ratio(4.0, 3.0),
+            }
+
+            failure {
+                "test",
+                "-1",
+                "16 / -9",
+                "16 /",
+                "16px",
+            }
+        }
+    }
+
+    #[test]
+    fn zero_ratio_is_ignored() {
+        assert_eq!(AspectRatio::Ratio(16.0, 9.0).ratio(), Some(16.0 / 9.0));
+        assert_eq!(AspectRatio::Ratio(0.0, 1.0).ratio(), None);
+        assert_eq!(AspectRatio::Auto.ratio(), None);
+    }
+}
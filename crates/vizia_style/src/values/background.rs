@@ -0,0 +1,298 @@
+use cssparser::*;
+
+use crate::{
+    define_enum, BackgroundImage, BackgroundSize, Color, CustomParseError, Parse, Position,
+};
+
+define_enum! {
+    /// Determines whether a background image is repeated along an axis.
+    #[derive(Default)]
+    pub enum BackgroundRepeatKeyword {
+        /// The image is repeated to fill the background.
+        #[default]
+        "repeat": Repeat,
+        /// The image is drawn once.
+        "no-repeat": NoRepeat,
+        /// The image is repeated as many times as it fits without being clipped, with the first and last images
+        /// touching the edges of the background and the space left over shared evenly between the images.
+        "space": Space,
+        /// The image is scaled so that a whole number of images fills the background, and then repeated.
+        "round": Round,
+    }
+}
+
+// Spaced images are drawn one at a time, so only rounded images, which are scaled before they are drawn, are repeated
+// by a tile mode.
+impl From<BackgroundRepeatKeyword> for skia_safe::TileMode {
+    fn from(value: BackgroundRepeatKeyword) -> Self {
+        match value {
+            BackgroundRepeatKeyword::Repeat | BackgroundRepeatKeyword::Round => {
+                skia_safe::TileMode::Repeat
+            }
+            BackgroundRepeatKeyword::NoRepeat | BackgroundRepeatKeyword::Space => {
+                skia_safe::TileMode::Decal
+            }
+        }
+    }
+}
+
+/// Determines whether a background image is repeated horizontally and vertically.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BackgroundRepeat {
+    /// Whether the image is repeated horizontally.
+    pub x: BackgroundRepeatKeyword,
+    /// Whether the image is repeated vertically.
+    pub y: BackgroundRepeatKeyword,
+}
+
+impl BackgroundRepeat {
+    /// Creates a new background repeat which repeats an image in the same way along both axes.
+    pub fn new(repeat: BackgroundRepeatKeyword) -> Self {
+        Self { x: repeat, y: repeat }
+    }
+}
+
+impl<'i> Parse<'i> for BackgroundRepeat {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("repeat-x")).is_ok() {
+            return Ok(BackgroundRepeat {
+                x: BackgroundRepeatKeyword::Repeat,
+                y: BackgroundRepeatKeyword::NoRepeat,
+            });
+        }
+
+        if input.try_parse(|input| input.expect_ident_matching("repeat-y")).is_ok() {
+            return Ok(BackgroundRepeat {
+                x: BackgroundRepeatKeyword::NoRepeat,
+                y: BackgroundRepeatKeyword::Repeat,
+            });
+        }
+
+        let x = BackgroundRepeatKeyword::parse(input)?;
+        let y = input.try_parse(BackgroundRepeatKeyword::parse).unwrap_or(x);
+        Ok(BackgroundRepeat { x, y })
+    }
+}
+
+impl<'i> Parse<'i> for Vec<BackgroundRepeat> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        input.parse_comma_separated(BackgroundRepeat::parse)
+    }
+}
+
+impl From<BackgroundRepeatKeyword> for BackgroundRepeat {
+    fn from(repeat: BackgroundRepeatKeyword) -> Self {
+        BackgroundRepeat::new(repeat)
+    }
+}
+
+impl From<&str> for BackgroundRepeat {
+    fn from(s: &str) -> Self {
+        let mut input = ParserInput::new(s);
+        let mut parser = Parser::new(&mut input);
+        BackgroundRepeat::parse(&mut parser).unwrap_or_default()
+    }
+}
+
+/// A layer of the `background` shorthand property.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackgroundLayer<'i> {
+    /// The image or gradient of the layer.
+    pub image: BackgroundImage<'i>,
+    /// The position of the image within the background.
+    pub position: Position,
+    /// The size of the image.
+    pub size: BackgroundSize,
+    /// Whether the image is repeated to fill the background.
+    pub repeat: BackgroundRepeat,
+}
+
+/// The `background` shorthand property, which sets the background color along with the image, position, size and
+/// repeat of each background layer.
+///
+/// Layers are separated by commas, with the first layer drawn on top. The color can only be given in the last
+/// layer, and is drawn below all of the layers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Background<'i> {
+    /// The layers of the background, from top to bottom.
+    pub layers: Vec<BackgroundLayer<'i>>,
+    /// The background color.
+    pub color: Option<Color>,
+}
+
+impl<'i> Parse<'i> for Background<'i> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let layers = input.parse_comma_separated(parse_layer)?;
+
+        let num_layers = layers.len();
+        if layers.iter().take(num_layers - 1).any(|(_, color)| color.is_some()) {
+            return Err(input.new_custom_error(CustomParseError::InvalidValue));
+        }
+
+        let color = layers.last().and_then(|(_, color)| *color);
+        Ok(Background { layers: layers.into_iter().map(|(layer, _)| layer).collect(), color })
+    }
+}
+
+// Parses a layer of the background shorthand, whose components can be in any order, along with a color.
+fn parse_layer<'i, 't>(
+    input: &mut Parser<'i, 't>,
+) -> Result<(BackgroundLayer<'i>, Option<Color>), ParseError<'i, CustomParseError<'i>>> {
+    let mut image = None;
+    let mut position = None;
+    let mut size = None;
+    let mut repeat = None;
+    let mut color = None;
+
+    loop {
+        if image.is_none() {
+            if let Ok(value) = input.try_parse(BackgroundImage::parse) {
+                image = Some(value);
+                continue;
+            }
+        }
+
+        if position.is_none() {
+            if let Ok(value) = input.try_parse(Position::parse) {
+                position = Some(value);
+
+                // The size can only follow the position.
+                size = input
+                    .try_parse(|input| {
+                        input.expect_delim('/')?;
+                        BackgroundSize::parse(input)
+                    })
+                    .ok();
+                continue;
+            }
+        }
+
+        if repeat.is_none() {
+            if let Ok(value) = input.try_parse(BackgroundRepeat::parse) {
+                repeat = Some(value);
+                continue;
+            }
+        }
+
+        if color.is_none() {
+            if let Ok(value) = input.try_parse(Color::parse) {
+                color = Some(value);
+                continue;
+            }
+        }
+
+        break;
+    }
+
+    if image.is_none() && position.is_none() && repeat.is_none() && color.is_none() {
+        return Err(input.new_custom_error(CustomParseError::InvalidDeclaration));
+    }
+
+    Ok((
+        BackgroundLayer {
+            image: image.unwrap_or(BackgroundImage::None),
+            position: position.unwrap_or_default(),
+            size: size.unwrap_or_default(),
+            repeat: repeat.unwrap_or_default(),
+        },
+        color,
+    ))
+}
+
+#[cfg(test)]
+mod tests_background {
+    use super::*;
+    use crate::tests::assert_parse;
+    use crate::{
+        Gradient, HorizontalPosition, HorizontalPositionKeyword, LengthOrPercentage,
+        LengthPercentageOrAuto, LineDirection, LinearGradient, Url, VerticalPosition,
+        VerticalPositionKeyword,
+    };
+
+    assert_parse! {
+        BackgroundRepeat, parse_background_repeat,
+
+        custom {
+            success {
+                "repeat" => BackgroundRepeat::new(BackgroundRepeatKeyword::Repeat),
+                "no-repeat" => BackgroundRepeat::new(BackgroundRepeatKeyword::NoRepeat),
+                "repeat-x" => BackgroundRepeat {
+                    x: BackgroundRepeatKeyword::Repeat,
+                    y: BackgroundRepeatKeyword::NoRepeat,
+                },
+                "no-repeat repeat" => BackgroundRepeat {
+                    x: BackgroundRepeatKeyword::NoRepeat,
+                    y: BackgroundRepeatKeyword::Repeat,
+                },
+                "space" => BackgroundRepeat::new(BackgroundRepeatKeyword::Space),
+                "round space" => BackgroundRepeat {
+                    x: BackgroundRepeatKeyword::Round,
+                    y: BackgroundRepeatKeyword::Space,
+                },
+            }
+
+            failure {
+                "spaced",
+                "10px",
+            }
+        }
+    }
+
+    assert_parse! {
+        Background, parse_background,
+
+        custom {
+            success {
+                "red" => Background { layers: vec![BackgroundLayer {
+                    image: BackgroundImage::None,
+                    position: Position::default(),
+                    size: BackgroundSize::default(),
+                    repeat: BackgroundRepeat::default(),
+                }], color: Some(Color::rgb(255, 0, 0)) },
+                "url(image.png) no-repeat right center / contain, linear-gradient(red, blue) blue" => Background {
+                    layers: vec![
+                        BackgroundLayer {
+                            image: BackgroundImage::Url(Url { url: "image.png".into() }),
+                            position: Position::new(
+                                HorizontalPosition::Side(HorizontalPositionKeyword::Right),
+                                VerticalPosition::Center,
+                            ),
+                            size: BackgroundSize::Contain,
+                            repeat: BackgroundRepeat::new(BackgroundRepeatKeyword::NoRepeat),
+                        },
+                        BackgroundLayer {
+                            image: BackgroundImage::from(Gradient::Linear(LinearGradient {
+                                direction: LineDirection::Vertical(VerticalPositionKeyword::Bottom),
+                                stops: vec![Color::rgb(255, 0, 0).into(), Color::rgb(0, 0, 255).into()],
+                            })),
+                            position: Position::default(),
+                            size: BackgroundSize::default(),
+                            repeat: BackgroundRepeat::default(),
+                        },
+                    ],
+                    color: Some(Color::rgb(0, 0, 255)),
+                },
+                "url(image.png) 10px 50% / 20px" => Background {
+                    layers: vec![BackgroundLayer {
+                        image: BackgroundImage::Url(Url { url: "image.png".into() }),
+                        position: Position::new(
+                            HorizontalPosition::Length(LengthOrPercentage::px(10.0)),
+                            VerticalPosition::Length(LengthOrPercentage::Percentage(50.0)),
+                        ),
+                        size: BackgroundSize::Explicit {
+                            width: LengthPercentageOrAuto::LengthPercentage(LengthOrPercentage::px(20.0)),
+                            height: LengthPercentageOrAuto::Auto,
+                        },
+                        repeat: BackgroundRepeat::default(),
+                    }],
+                    color: None,
+                },
+            }
+
+            failure {
+                "red, url(image.png)",
+                "url(image.png) url(other.png)",
+            }
+        }
+    }
+}
@@ -1,33 +1,123 @@
-use crate::{macros::impl_parse, LengthOrPercentage, Parse, Rect};
+use crate::{
+    CustomParseError, Length, LengthOrPercentage, LengthValue, Parse, Percentage, Rect, Units,
+};
+use cssparser::*;
+
+/// Defines the horizontal and vertical radii of the ellipse used to round a corner of a rectangle.
+///
+/// A percentage radius is relative to the shorter side of the rectangle, so that `50%` rounds the ends of a
+/// rectangle into semicircles, making a pill, or a square into a circle. When the two radii differ, the horizontal
+/// radius is relative to the width and the vertical radius to the height.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct CornerRadiusValue {
+    /// The horizontal radius of the corner.
+    pub x: LengthOrPercentage,
+    /// The vertical radius of the corner.
+    pub y: LengthOrPercentage,
+}
+
+impl CornerRadiusValue {
+    pub fn new(x: impl Into<LengthOrPercentage>, y: impl Into<LengthOrPercentage>) -> Self {
+        Self { x: x.into(), y: y.into() }
+    }
+}
+
+impl<'i> Parse<'i> for CornerRadiusValue {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let x = LengthOrPercentage::parse(input)?;
+        let y = input.try_parse(LengthOrPercentage::parse).unwrap_or_else(|_| x.clone());
+
+        Ok(Self { x, y })
+    }
+}
+
+impl<T: Into<LengthOrPercentage>> From<T> for CornerRadiusValue {
+    fn from(value: T) -> Self {
+        let radius: LengthOrPercentage = value.into();
+        CornerRadiusValue::new(radius.clone(), radius)
+    }
+}
+
+impl<T1: Into<LengthOrPercentage>, T2: Into<LengthOrPercentage>> From<(T1, T2)>
+    for CornerRadiusValue
+{
+    fn from(value: (T1, T2)) -> Self {
+        CornerRadiusValue::new(value.0, value.1)
+    }
+}
+
 /// Defines the border radius of every corner of a rectangle.
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CornerRadius {
     /// The border radius of the top-left corner.
-    pub top_left: LengthOrPercentage,
+    pub top_left: CornerRadiusValue,
     /// The border radius of the top-right corner.
-    pub top_right: LengthOrPercentage,
+    pub top_right: CornerRadiusValue,
     /// The border radius of the bottom-right corner.
-    pub bottom_right: LengthOrPercentage,
+    pub bottom_right: CornerRadiusValue,
     /// The border radius of the bottom-left corner.
-    pub bottom_left: LengthOrPercentage,
+    pub bottom_left: CornerRadiusValue,
 }
 
 impl CornerRadius {
     pub fn new(
-        top_left: LengthOrPercentage,
-        top_right: LengthOrPercentage,
-        bottom_right: LengthOrPercentage,
-        bottom_left: LengthOrPercentage,
+        top_left: impl Into<CornerRadiusValue>,
+        top_right: impl Into<CornerRadiusValue>,
+        bottom_right: impl Into<CornerRadiusValue>,
+        bottom_left: impl Into<CornerRadiusValue>,
     ) -> Self {
-        Self { top_left, top_right, bottom_right, bottom_left }
+        Self {
+            top_left: top_left.into(),
+            top_right: top_right.into(),
+            bottom_right: bottom_right.into(),
+            bottom_left: bottom_left.into(),
+        }
     }
 }
 
-impl_parse! {
-    CornerRadius,
+// Parses one to four radii, which are assigned to the corners in the same way as the sides of a `Rect`.
+fn parse_radii<'i>(
+    input: &mut Parser<'i, '_>,
+) -> Result<Rect<LengthOrPercentage>, ParseError<'i, CustomParseError<'i>>> {
+    let first = LengthOrPercentage::parse(input)?;
+    let Ok(second) = input.try_parse(LengthOrPercentage::parse) else {
+        return Ok(Rect(first.clone(), first.clone(), first.clone(), first));
+    };
+
+    let Ok(third) = input.try_parse(LengthOrPercentage::parse) else {
+        return Ok(Rect::from((first, second)));
+    };
+
+    let Ok(fourth) = input.try_parse(LengthOrPercentage::parse) else {
+        return Ok(Rect::from((first, second, third)));
+    };
+
+    Ok(Rect(first, second, third, fourth))
+}
+
+impl<'i> Parse<'i> for CornerRadius {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let location = input.current_source_location();
+        let horizontal = parse_radii(input)?;
+        let vertical = if input.try_parse(|input| input.expect_delim('/')).is_ok() {
+            parse_radii(input)?
+        } else {
+            horizontal.clone()
+        };
 
-    try_parse {
-        Rect<LengthOrPercentage>,
+        if !input.is_exhausted() {
+            return Err(cssparser::ParseError {
+                kind: cssparser::ParseErrorKind::Custom(CustomParseError::InvalidDeclaration),
+                location,
+            });
+        }
+
+        Ok(CornerRadius::new(
+            (horizontal.0, vertical.0),
+            (horizontal.1, vertical.1),
+            (horizontal.2, vertical.2),
+            (horizontal.3, vertical.3),
+        ))
     }
 }
 
@@ -37,56 +127,46 @@ impl From<Rect<LengthOrPercentage>> for CornerRadius {
     }
 }
 
-// impl From<LengthOrPercentage> for CornerRadius {
-//     fn from(length: LengthOrPercentage) -> Self {
-//         CornerRadius::new(length.clone(), length.clone(), length.clone(), length.clone())
-//     }
-// }
-
-// impl From<Length> for CornerRadius {
-//     fn from(length: Length) -> Self {
-//         CornerRadius::new(
-//             length.clone().into(),
-//             length.clone().into(),
-//             length.clone().into(),
-//             length.clone().into(),
-//         )
-//     }
-// }
-
-// impl From<LengthValue> for CornerRadius {
-//     fn from(length: LengthValue) -> Self {
-//         CornerRadius::new(
-//             length.clone().into(),
-//             length.clone().into(),
-//             length.clone().into(),
-//             length.clone().into(),
-//         )
-//     }
-// }
-
-// impl From<Units> for CornerRadius {
-//     fn from(value: Units) -> Self {
-//         let length: LengthOrPercentage = value.into();
-//         CornerRadius::new(length.clone(), length.clone(), length.clone(), length.clone())
-//     }
-// }
-
-// impl From<&str> for CornerRadius {
-//     fn from(s: &str) -> Self {
-//         let mut input = ParserInput::new(&s);
-//         let mut parser = Parser::new(&mut input);
-//         CornerRadius::parse(&mut parser).unwrap_or_default()
-//     }
-// }
-
-impl<T: Into<LengthOrPercentage>> From<T> for CornerRadius {
-    fn from(value: T) -> Self {
-        let length: LengthOrPercentage = value.into();
+impl From<LengthOrPercentage> for CornerRadius {
+    fn from(length: LengthOrPercentage) -> Self {
         CornerRadius::new(length.clone(), length.clone(), length.clone(), length)
     }
 }
 
+impl From<Length> for CornerRadius {
+    fn from(length: Length) -> Self {
+        LengthOrPercentage::from(length).into()
+    }
+}
+
+impl From<LengthValue> for CornerRadius {
+    fn from(length: LengthValue) -> Self {
+        LengthOrPercentage::from(length).into()
+    }
+}
+
+impl From<Percentage> for CornerRadius {
+    fn from(percentage: Percentage) -> Self {
+        LengthOrPercentage::from(percentage).into()
+    }
+}
+
+impl From<Units> for CornerRadius {
+    fn from(units: Units) -> Self {
+        LengthOrPercentage::from(units).into()
+    }
+}
+
+/// Parses the same values as the `corner-radius` property, such as `"50%"` or `"4px 4px 0px 0px"`, and falls back to
+/// square corners if the string can't be parsed.
+impl From<&str> for CornerRadius {
+    fn from(s: &str) -> Self {
+        let mut input = ParserInput::new(s);
+        let mut parser = Parser::new(&mut input);
+        CornerRadius::parse(&mut parser).unwrap_or_default()
+    }
+}
+
 impl<T1: Into<LengthOrPercentage>, T2: Into<LengthOrPercentage>> From<(T1, T2)> for CornerRadius {
     fn from(value: (T1, T2)) -> Self {
         let length1: LengthOrPercentage = value.0.into();
@@ -126,7 +206,6 @@ impl<
 mod tests {
     use super::*;
     use crate::tests::assert_parse;
-    use crate::Length;
 
     assert_parse! {
         CornerRadius, assert_border_radius,
@@ -136,11 +215,53 @@ mod tests {
             "10px 20px" =>  CornerRadius::new(LengthOrPercentage::Length(Length::px(10.0)), LengthOrPercentage::Length(Length::px(20.0)), LengthOrPercentage::Length(Length::px(10.0)), LengthOrPercentage::Length(Length::px(20.0))),
             "10px 20px 30px" =>  CornerRadius::new(LengthOrPercentage::Length(Length::px(10.0)), LengthOrPercentage::Length(Length::px(20.0)), LengthOrPercentage::Length(Length::px(30.0)), LengthOrPercentage::Length(Length::px(20.0))),
             "10px 20px 30px 40px" =>  CornerRadius::new(LengthOrPercentage::Length(Length::px(10.0)), LengthOrPercentage::Length(Length::px(20.0)), LengthOrPercentage::Length(Length::px(30.0)), LengthOrPercentage::Length(Length::px(40.0))),
+            "10px 20px / 5px" => CornerRadius::new(CornerRadiusValue::new(Length::px(10.0), Length::px(5.0)), CornerRadiusValue::new(Length::px(20.0), Length::px(5.0)), CornerRadiusValue::new(Length::px(10.0), Length::px(5.0)), CornerRadiusValue::new(Length::px(20.0), Length::px(5.0))),
+            "10px 20px 30px 40px / 50%" => CornerRadius::new(CornerRadiusValue::new(Length::px(10.0), LengthOrPercentage::Percentage(50.0)), CornerRadiusValue::new(Length::px(20.0), LengthOrPercentage::Percentage(50.0)), CornerRadiusValue::new(Length::px(30.0), LengthOrPercentage::Percentage(50.0)), CornerRadiusValue::new(Length::px(40.0), LengthOrPercentage::Percentage(50.0))),
         }
 
         failure {
             "px",
             "10px 20px 30px 40px 50px",
+            "10px /",
+            "/ 10px",
+            "10px / 20px 30px 40px 50px 60px",
         }
     }
+
+    assert_parse! {
+        CornerRadiusValue, assert_corner_radius_value,
+
+        success {
+            "10px" => CornerRadiusValue::new(Length::px(10.0), Length::px(10.0)),
+            "10px 50%" => CornerRadiusValue::new(Length::px(10.0), LengthOrPercentage::Percentage(50.0)),
+        }
+
+        failure {
+            "px",
+        }
+    }
+
+    #[test]
+    fn corner_radius_from_values() {
+        let half = CornerRadiusValue::from(LengthOrPercentage::Percentage(50.0));
+        let pill = CornerRadius::new(half.clone(), half.clone(), half.clone(), half);
+        assert_eq!(CornerRadius::from("50%"), pill);
+        assert_eq!(CornerRadius::from(Percentage(50.0)), pill);
+        assert_eq!(CornerRadius::from(Units::Percentage(50.0)), pill);
+
+        let top =
+            CornerRadius::new(Length::px(4.0), Length::px(4.0), Length::px(0.0), Length::px(0.0));
+        assert_eq!(CornerRadius::from("4px 4px 0px 0px"), top);
+        assert_eq!(
+            CornerRadius::from((
+                Units::Pixels(4.0),
+                Units::Pixels(4.0),
+                Units::Pixels(0.0),
+                Units::Pixels(0.0)
+            )),
+            top
+        );
+
+        assert_eq!(CornerRadius::from("red"), CornerRadius::default());
+    }
 }
@@ -333,3 +333,149 @@ impl<V: std::cmp::PartialOrd<f32>> std::cmp::PartialOrd<f32> for Calc<V> {
         }
     }
 }
+
+impl<V> Calc<V> {
+    /// Evaluates the expression, using the given function to resolve each value to a number.
+    pub fn resolve(&self, resolve_value: &impl Fn(&V) -> f32) -> f32 {
+        match self {
+            Calc::Value(value) => resolve_value(value),
+            Calc::Number(number) => *number,
+            Calc::Sum(a, b) => a.resolve(resolve_value) + b.resolve(resolve_value),
+            Calc::Product(number, calc) => number * calc.resolve(resolve_value),
+            Calc::Function(function) => match &**function {
+                MathFunction::Calc(calc) => calc.resolve(resolve_value),
+                MathFunction::Min(args) => {
+                    args.iter().map(|arg| arg.resolve(resolve_value)).fold(f32::INFINITY, f32::min)
+                }
+                MathFunction::Max(args) => args
+                    .iter()
+                    .map(|arg| arg.resolve(resolve_value))
+                    .fold(f32::NEG_INFINITY, f32::max),
+                // The minimum wins over the maximum if they are in the wrong order.
+                MathFunction::Clamp(min, center, max) => center
+                    .resolve(resolve_value)
+                    .min(max.resolve(resolve_value))
+                    .max(min.resolve(resolve_value)),
+            },
+        }
+    }
+}
+
+impl<
+        V: ToCss
+            + Clone
+            + std::ops::Mul<f32, Output = V>
+            + std::cmp::PartialOrd<f32>
+            + std::fmt::Debug,
+    > Calc<V>
+{
+    // Writes the terms of a sum which follow its first term, each preceded by its operator.
+    fn write_terms<W>(&self, dest: &mut W) -> std::fmt::Result
+    where
+        W: std::fmt::Write,
+    {
+        match self {
+            Calc::Sum(a, b) => {
+                a.write_terms(dest)?;
+                b.write_terms(dest)
+            }
+
+            Calc::Product(number, calc) if *number < 0.0 => {
+                dest.write_str(" - ")?;
+                Calc::Product(-number, calc.clone()).write_expression(dest)
+            }
+
+            term if *term < 0.0 => {
+                dest.write_str(" - ")?;
+                (term.clone() * -1.0).write_expression(dest)
+            }
+
+            term => {
+                dest.write_str(" + ")?;
+                term.write_expression(dest)
+            }
+        }
+    }
+
+    // Writes the expression without the `calc()` around it.
+    fn write_expression<W>(&self, dest: &mut W) -> std::fmt::Result
+    where
+        W: std::fmt::Write,
+    {
+        match self {
+            Calc::Sum(a, b) => {
+                a.write_expression(dest)?;
+                b.write_terms(dest)
+            }
+
+            Calc::Product(number, calc) => {
+                write!(dest, "{} * ", number)?;
+                if let Calc::Sum(..) = **calc {
+                    dest.write_char('(')?;
+                    calc.write_expression(dest)?;
+                    dest.write_char(')')
+                } else {
+                    calc.write_expression(dest)
+                }
+            }
+
+            _ => self.to_css(dest),
+        }
+    }
+}
+
+impl<
+        V: ToCss
+            + Clone
+            + std::ops::Mul<f32, Output = V>
+            + std::cmp::PartialOrd<f32>
+            + std::fmt::Debug,
+    > ToCss for Calc<V>
+{
+    fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+    where
+        W: std::fmt::Write,
+    {
+        match self {
+            Calc::Value(value) => value.to_css(dest),
+            Calc::Number(number) => write!(dest, "{}", number),
+            Calc::Function(function) => function.to_css(dest),
+            _ => {
+                dest.write_str("calc(")?;
+                self.write_expression(dest)?;
+                dest.write_char(')')
+            }
+        }
+    }
+}
+
+impl<
+        V: ToCss
+            + Clone
+            + std::ops::Mul<f32, Output = V>
+            + std::cmp::PartialOrd<f32>
+            + std::fmt::Debug,
+    > ToCss for MathFunction<V>
+{
+    fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+    where
+        W: std::fmt::Write,
+    {
+        let (name, args) = match self {
+            MathFunction::Calc(calc) => ("calc", vec![calc]),
+            MathFunction::Min(args) => ("min", args.iter().collect()),
+            MathFunction::Max(args) => ("max", args.iter().collect()),
+            MathFunction::Clamp(min, center, max) => ("clamp", vec![min, center, max]),
+        };
+
+        dest.write_str(name)?;
+        dest.write_char('(')?;
+        for (index, arg) in args.into_iter().enumerate() {
+            if index > 0 {
+                dest.write_str(", ")?;
+            }
+            arg.write_expression(dest)?;
+        }
+        dest.write_char(')')
+    }
+}
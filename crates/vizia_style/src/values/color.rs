@@ -1,16 +1,21 @@
-use crate::{macros::impl_parse, Parse};
-use cssparser::{Parser, ParserInput};
+use crate::{Angle, ColorSpace, CustomParseError, Parse};
+use cssparser::{match_ignore_ascii_case, ParseError, Parser, ParserInput, Token};
 
 /// A color value.
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum Color {
     /// The 'currentcolor' keyword.
     CurrentColor,
+    /// The 'accent-color' keyword, which resolves to the accent color of the operating system.
+    AccentColor,
     /// A RGBA color value.
     RGBA(RGBA),
 }
 
 impl Color {
+    /// The color which the `accent-color` keyword resolves to until the accent color of the operating system is known.
+    pub const DEFAULT_ACCENT: RGBA = RGBA::rgb(81, 175, 239);
+
     /// Creates a new RGBA from RGB values
     #[must_use]
     pub const fn rgb(red: u8, green: u8, blue: u8) -> Self {
@@ -23,49 +28,219 @@ impl Color {
         Self::RGBA(RGBA::rgba(red, green, blue, alpha))
     }
 
-    /// Return a new [RGBA] from the Color
+    /// Return a new [RGBA] from the Color.
+    ///
+    /// The `currentcolor` and `accent-color` keywords depend on the view and the application they are used in, so they
+    /// are resolved when the view is drawn, and here return transparent black and the default accent color.
     pub fn get_rgba(&self) -> RGBA {
         match self {
             Color::CurrentColor => RGBA::rgba(0, 0, 0, 0),
+            Color::AccentColor => Color::DEFAULT_ACCENT,
             Color::RGBA(rgba) => *rgba,
         }
     }
 
     pub fn r(&self) -> u8 {
-        match self {
-            Color::CurrentColor => 0,
-            Color::RGBA(col) => col.r(),
-        }
+        self.get_rgba().r()
     }
 
     pub fn g(&self) -> u8 {
-        match self {
-            Color::CurrentColor => 0,
-            Color::RGBA(col) => col.g(),
-        }
+        self.get_rgba().g()
     }
 
     pub fn b(&self) -> u8 {
-        match self {
-            Color::CurrentColor => 0,
-            Color::RGBA(col) => col.b(),
-        }
+        self.get_rgba().b()
     }
 
     pub fn a(&self) -> u8 {
-        match self {
-            Color::CurrentColor => 0,
-            Color::RGBA(col) => col.a(),
+        self.get_rgba().a()
+    }
+    /// Mixes this color with another in the given color space, where `t` is the proportion of `other`.
+    ///
+    /// See [`RGBA::mix`].
+    pub fn mix(&self, other: &Color, t: f32, color_space: ColorSpace) -> Self {
+        Color::RGBA(self.get_rgba().mix(&other.get_rgba(), t, color_space))
+    }
+}
+
+impl<'i> Parse<'i> for Color {
+    fn parse<'t>(
+        input: &mut Parser<'i, 't>,
+    ) -> Result<Self, cssparser::ParseError<'i, CustomParseError<'i>>> {
+        let location = input.current_source_location();
+
+        if input.try_parse(|input| input.expect_ident_matching("accent-color")).is_ok() {
+            return Ok(Color::AccentColor);
+        }
+
+        if let Ok(color) = input.try_parse(parse_color_function) {
+            return Ok(color);
+        }
+
+        if let Ok(color) = input.try_parse(<cssparser_color::Color>::parse) {
+            return Ok(color.into());
         }
+
+        Err(cssparser::ParseError {
+            kind: cssparser::ParseErrorKind::Custom(CustomParseError::InvalidDeclaration),
+            location,
+        })
     }
 }
 
-impl_parse! {
-    Color,
+// Parses the color functions which are converted to sRGB when they're parsed, rather than by `cssparser_color`.
+fn parse_color_function<'i>(
+    input: &mut Parser<'i, '_>,
+) -> Result<Color, ParseError<'i, CustomParseError<'i>>> {
+    let function = input.expect_function()?.clone();
+
+    input.parse_nested_block(|input| {
+        let location = input.current_source_location();
+        match_ignore_ascii_case! { &function,
+            "lab" => parse_lab(input, false),
+            "lch" => parse_lab(input, true),
+            "oklab" => parse_oklab(input, false),
+            "oklch" => parse_oklab(input, true),
+            "color-mix" => parse_color_mix(input),
+            _ => Err(location.new_unexpected_token_error(Token::Ident(function))),
+        }
+    })
+}
 
-    try_parse {
-        cssparser_color::Color,
+// Parses the components of `lab()`, or of `lch()` if `polar` is true. The lightness is a percentage, and 100% of a or
+// b is 125 and of the chroma is 150.
+fn parse_lab<'i>(
+    input: &mut Parser<'i, '_>,
+    polar: bool,
+) -> Result<Color, ParseError<'i, CustomParseError<'i>>> {
+    let lightness = parse_component(input, 100.0)?;
+    let [a, b] = if polar {
+        let chroma = parse_component(input, 150.0)?.max(0.0);
+        let (sin, cos) = parse_hue(input)?.to_radians().sin_cos();
+        [chroma * cos, chroma * sin]
+    } else {
+        [parse_component(input, 125.0)?, parse_component(input, 125.0)?]
+    };
+    let alpha = parse_alpha(input)?;
+
+    let oklab = linear_srgb_to_oklab(lab_to_linear_srgb([lightness, a, b]));
+    Ok(Color::RGBA(RGBA::from_oklch_gamut_mapped(oklab_to_oklch(oklab), alpha)))
+}
+
+// Parses the components of `oklab()`, or of `oklch()` if `polar` is true. The lightness is a number from 0 to 1, and
+// 100% of a, b or the chroma is 0.4.
+fn parse_oklab<'i>(
+    input: &mut Parser<'i, '_>,
+    polar: bool,
+) -> Result<Color, ParseError<'i, CustomParseError<'i>>> {
+    let lightness = parse_component(input, 1.0)?;
+    let lch = if polar {
+        [lightness, parse_component(input, 0.4)?.max(0.0), parse_hue(input)?]
+    } else {
+        oklab_to_oklch([lightness, parse_component(input, 0.4)?, parse_component(input, 0.4)?])
+    };
+    let alpha = parse_alpha(input)?;
+
+    Ok(Color::RGBA(RGBA::from_oklch_gamut_mapped(lch, alpha)))
+}
+
+// Parses a component of a color function, which is a number, a percentage of the given reference range, or `none`,
+// which is zero.
+fn parse_component<'i>(
+    input: &mut Parser<'i, '_>,
+    percentage_reference: f32,
+) -> Result<f32, ParseError<'i, CustomParseError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+        return Ok(0.0);
+    }
+
+    let location = input.current_source_location();
+    match *input.next()? {
+        Token::Number { value, .. } => Ok(value),
+        Token::Percentage { unit_value, .. } => Ok(unit_value * percentage_reference),
+        ref token => Err(location.new_unexpected_token_error(token.clone())),
+    }
+}
+
+// Parses a hue in degrees, which is a number, an angle or `none`.
+fn parse_hue<'i>(input: &mut Parser<'i, '_>) -> Result<f32, ParseError<'i, CustomParseError<'i>>> {
+    if let Ok(angle) = input.try_parse(Angle::parse) {
+        return Ok(angle.to_degrees());
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+        return Ok(0.0);
+    }
+
+    Ok(input.expect_number()?)
+}
+
+// Parses the alpha after a `/`, which is fully opaque if omitted.
+fn parse_alpha<'i>(input: &mut Parser<'i, '_>) -> Result<u8, ParseError<'i, CustomParseError<'i>>> {
+    if input.try_parse(|input| input.expect_delim('/')).is_err() {
+        return Ok(255);
     }
+
+    Ok(unit_to_u8(parse_component(input, 1.0)?))
+}
+
+// Parses the arguments of `color-mix(in <color-space>, <color> [<percentage>], <color> [<percentage>])`.
+//
+// The colors are mixed when they're parsed, so `currentcolor` and `accent-color`, which are resolved later, can't be
+// mixed.
+fn parse_color_mix<'i>(
+    input: &mut Parser<'i, '_>,
+) -> Result<Color, ParseError<'i, CustomParseError<'i>>> {
+    input.expect_ident_matching("in")?;
+    let color_space = ColorSpace::parse(input)?;
+    input.expect_comma()?;
+    let (first, first_percentage) = parse_mix_component(input)?;
+    input.expect_comma()?;
+    let (second, second_percentage) = parse_mix_component(input)?;
+
+    // A missing percentage is the remainder of the other. Percentages which don't sum to 100% are scaled so that
+    // they do, and a sum below 100% makes the mix transparent by the difference.
+    let (first_percentage, second_percentage) = match (first_percentage, second_percentage) {
+        (None, None) => (0.5, 0.5),
+        (Some(first), None) => (first, 1.0 - first),
+        (None, Some(second)) => (1.0 - second, second),
+        (Some(first), Some(second)) => (first, second),
+    };
+
+    let sum = first_percentage + second_percentage;
+    if sum <= 0.0 {
+        return Err(input.new_custom_error(CustomParseError::InvalidValue));
+    }
+
+    let mut mixed = first.mix(&second, second_percentage / sum, color_space);
+    if sum < 1.0 {
+        mixed.alpha = (mixed.alpha as f32 * sum).round() as u8;
+    }
+
+    Ok(Color::RGBA(mixed))
+}
+
+// Parses a color of `color-mix()` and its optional percentage, which can be given before or after it.
+fn parse_mix_component<'i>(
+    input: &mut Parser<'i, '_>,
+) -> Result<(RGBA, Option<f32>), ParseError<'i, CustomParseError<'i>>> {
+    let mut percentage = input.try_parse(|input| input.expect_percentage()).ok();
+
+    let location = input.current_source_location();
+    let color = match Color::parse(input)? {
+        Color::RGBA(rgba) => rgba,
+        _ => return Err(location.new_custom_error(CustomParseError::InvalidValue)),
+    };
+
+    if percentage.is_none() {
+        percentage = input.try_parse(|input| input.expect_percentage()).ok();
+    }
+
+    if percentage.is_some_and(|percentage| !(0.0..=1.0).contains(&percentage)) {
+        return Err(location.new_custom_error(CustomParseError::InvalidValue));
+    }
+
+    Ok((color, percentage))
 }
 
 impl From<RGBA> for Color {
@@ -90,6 +265,20 @@ impl Default for Color {
     }
 }
 
+// Colors are written in a form which can be parsed back, such as when a color is used as the value of a custom
+// property.
+impl std::fmt::Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Color::CurrentColor => write!(f, "currentcolor"),
+            Color::AccentColor => write!(f, "accent-color"),
+            Color::RGBA(rgba) => {
+                write!(f, "#{:02x}{:02x}{:02x}{:02x}", rgba.red, rgba.green, rgba.blue, rgba.alpha)
+            }
+        }
+    }
+}
+
 impl Color {
     pub const fn black() -> Self {
         Self::RGBA(RGBA::BLACK)
@@ -774,6 +963,246 @@ impl RGBA {
     pub fn a(&self) -> u8 {
         self.alpha
     }
+
+    /// Returns the red, green, and blue components in linear sRGB, in the range 0.0 to 1.0.
+    pub fn to_linear_srgb(&self) -> [f32; 3] {
+        [self.red, self.green, self.blue].map(|c| srgb_to_linear(c as f32 / 255.0))
+    }
+
+    /// Creates a new RGBA from linear sRGB components and an alpha value.
+    ///
+    /// Components outside of the sRGB gamut are clamped.
+    pub fn from_linear_srgb(rgb: [f32; 3], alpha: u8) -> Self {
+        let [r, g, b] = rgb.map(|c| unit_to_u8(linear_to_srgb(c)));
+        Self::rgba(r, g, b, alpha)
+    }
+
+    /// Returns the lightness, a, and b components of the color in the Oklab color space.
+    pub fn to_oklab(&self) -> [f32; 3] {
+        linear_srgb_to_oklab(self.to_linear_srgb())
+    }
+
+    /// Creates a new RGBA from Oklab components and an alpha value.
+    ///
+    /// Components outside of the sRGB gamut are clamped.
+    pub fn from_oklab(lab: [f32; 3], alpha: u8) -> Self {
+        Self::from_linear_srgb(oklab_to_linear_srgb(lab), alpha)
+    }
+
+    /// Returns the lightness, chroma, and hue in degrees of the color in the Oklch color space.
+    pub fn to_oklch(&self) -> [f32; 3] {
+        oklab_to_oklch(self.to_oklab())
+    }
+
+    /// Creates a new RGBA from Oklch components, with the hue in degrees, and an alpha value.
+    ///
+    /// Components outside of the sRGB gamut are clamped.
+    pub fn from_oklch(lch: [f32; 3], alpha: u8) -> Self {
+        Self::from_oklab(oklch_to_oklab(lch), alpha)
+    }
+
+    /// Creates a new RGBA from Oklch components, with the hue in degrees, and an alpha value.
+    ///
+    /// A color outside of the sRGB gamut has its chroma reduced until it's just within the gamut, which keeps its
+    /// lightness and hue, using the gamut mapping algorithm of CSS Color 4.
+    pub fn from_oklch_gamut_mapped(lch: [f32; 3], alpha: u8) -> Self {
+        let [lightness, chroma, hue] = lch;
+        if lightness >= 1.0 {
+            return Self::rgba(255, 255, 255, alpha);
+        }
+        if lightness <= 0.0 {
+            return Self::rgba(0, 0, 0, alpha);
+        }
+
+        // A difference in Oklab which is just noticeable, and the precision of the search for the chroma.
+        const JND: f32 = 0.02;
+        const EPSILON: f32 = 0.0001;
+
+        let to_linear_srgb =
+            |chroma: f32| oklab_to_linear_srgb(oklch_to_oklab([lightness, chroma, hue]));
+        let clip = |rgb: [f32; 3]| rgb.map(|c| c.clamp(0.0, 1.0));
+        let in_gamut = |rgb: [f32; 3]| rgb.iter().all(|c| (-EPSILON..=1.0 + EPSILON).contains(c));
+        let delta_e = |a: [f32; 3], b: [f32; 3]| {
+            let (a, b) = (linear_srgb_to_oklab(a), linear_srgb_to_oklab(b));
+            ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt()
+        };
+
+        let current = to_linear_srgb(chroma);
+        let mut clipped = clip(current);
+        if in_gamut(current) || delta_e(clipped, current) < JND {
+            return Self::from_linear_srgb(clipped, alpha);
+        }
+
+        // Search for the chroma whose clipped color is closest to, but not noticeably different from, the color.
+        let (mut min, mut max, mut min_in_gamut) = (0.0, chroma, true);
+        while max - min > EPSILON {
+            let chroma = (min + max) / 2.0;
+            let current = to_linear_srgb(chroma);
+            if min_in_gamut && in_gamut(current) {
+                min = chroma;
+                continue;
+            }
+
+            clipped = clip(current);
+            let error = delta_e(clipped, current);
+            if error < JND {
+                if JND - error < EPSILON {
+                    break;
+                }
+
+                min_in_gamut = false;
+                min = chroma;
+            } else {
+                max = chroma;
+            }
+        }
+
+        Self::from_linear_srgb(clipped, alpha)
+    }
+
+    /// Mixes this color with another in the given color space, where `t` is the proportion of `other`.
+    ///
+    /// The alpha component is always interpolated linearly and independently of the color components.
+    pub fn mix(&self, other: &RGBA, t: f32, color_space: ColorSpace) -> Self {
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+        let lerp3 = |a: [f32; 3], b: [f32; 3]| [0, 1, 2].map(|i| lerp(a[i], b[i]));
+
+        let alpha = lerp(self.alpha as f32, other.alpha as f32).round().clamp(0.0, 255.0) as u8;
+
+        match color_space {
+            ColorSpace::Srgb => {
+                let [r, g, b] = lerp3(
+                    [self.red, self.green, self.blue].map(|c| c as f32),
+                    [other.red, other.green, other.blue].map(|c| c as f32),
+                )
+                .map(|c| c.round().clamp(0.0, 255.0) as u8);
+                Self::rgba(r, g, b, alpha)
+            }
+
+            ColorSpace::LinearSrgb => {
+                Self::from_linear_srgb(lerp3(self.to_linear_srgb(), other.to_linear_srgb()), alpha)
+            }
+
+            ColorSpace::Oklab => Self::from_oklab(lerp3(self.to_oklab(), other.to_oklab()), alpha),
+
+            ColorSpace::Oklch => {
+                let [l1, c1, mut h1] = self.to_oklch();
+                let [l2, c2, mut h2] = other.to_oklch();
+
+                // The hue of an achromatic color is meaningless, so take the hue of the other color.
+                if c1 < ACHROMATIC_CHROMA {
+                    h1 = h2;
+                }
+                if c2 < ACHROMATIC_CHROMA {
+                    h2 = h1;
+                }
+
+                // Interpolate along the shorter arc of the hue circle.
+                let mut delta = h2 - h1;
+                if delta > 180.0 {
+                    delta -= 360.0;
+                } else if delta < -180.0 {
+                    delta += 360.0;
+                }
+
+                Self::from_oklch([lerp(l1, l2), lerp(c1, c2), h1 + delta * t], alpha)
+            }
+        }
+    }
+}
+
+fn linear_srgb_to_oklab(rgb: [f32; 3]) -> [f32; 3] {
+    let [r, g, b] = rgb;
+
+    let l = (0.4122215 * r + 0.5363325 * g + 0.05144599 * b).cbrt();
+    let m = (0.2119035 * r + 0.6806996 * g + 0.107397 * b).cbrt();
+    let s = (0.08830246 * r + 0.2817188 * g + 0.6299787 * b).cbrt();
+
+    [
+        0.2104543 * l + 0.7936178 * m - 0.00407205 * s,
+        1.977998 * l - 2.428592 * m + 0.4505937 * s,
+        0.02590404 * l + 0.7827718 * m - 0.8086758 * s,
+    ]
+}
+
+fn oklab_to_linear_srgb(lab: [f32; 3]) -> [f32; 3] {
+    let [lightness, a, b] = lab;
+
+    let l = (lightness + 0.3963378 * a + 0.2158038 * b).powi(3);
+    let m = (lightness - 0.1055613 * a - 0.06385417 * b).powi(3);
+    let s = (lightness - 0.08948418 * a - 1.291486 * b).powi(3);
+
+    [
+        4.076742 * l - 3.307712 * m + 0.2309699 * s,
+        -1.268438 * l + 2.609757 * m - 0.3413194 * s,
+        -0.00419609 * l - 0.7034186 * m + 1.707615 * s,
+    ]
+}
+
+fn oklab_to_oklch(lab: [f32; 3]) -> [f32; 3] {
+    let [lightness, a, b] = lab;
+    [lightness, a.hypot(b), b.atan2(a).to_degrees().rem_euclid(360.0)]
+}
+
+fn oklch_to_oklab(lch: [f32; 3]) -> [f32; 3] {
+    let [lightness, chroma, hue] = lch;
+    let (sin, cos) = hue.to_radians().sin_cos();
+    [lightness, chroma * cos, chroma * sin]
+}
+
+// Converts a CIE Lab color, relative to the D50 white point, to linear sRGB, which may be outside of the gamut.
+fn lab_to_linear_srgb(lab: [f32; 3]) -> [f32; 3] {
+    const KAPPA: f32 = 24389.0 / 27.0;
+    const EPSILON: f32 = 216.0 / 24389.0;
+    const D50_WHITE: [f32; 3] = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+    // The Bradford chromatic adaptation from the D50 to the D65 white point of sRGB.
+    const D50_TO_D65: [[f32; 3]; 3] = [
+        [0.955473421, -0.0230984549, 0.0632592432],
+        [-0.0283697093, 1.0099954, 0.0210414412],
+        [0.0123140149, -0.0205076493, 1.33036593],
+    ];
+    const XYZ_TO_LINEAR_SRGB: [[f32; 3]; 3] = [
+        [3.2409699, -1.5373832, -0.4986108],
+        [-0.9692436, 1.8759675, 0.0415551],
+        [0.0556301, -0.203977, 1.0569715],
+    ];
+
+    let [lightness, a, b] = lab;
+    let fy = (lightness + 16.0) / 116.0;
+    let fx = a / 500.0 + fy;
+    let fz = fy - b / 200.0;
+
+    let inverse = |f: f32| if f.powi(3) > EPSILON { f.powi(3) } else { (116.0 * f - 16.0) / KAPPA };
+    let y = if lightness > KAPPA * EPSILON { fy.powi(3) } else { lightness / KAPPA };
+    let xyz = [inverse(fx) * D50_WHITE[0], y, inverse(fz) * D50_WHITE[2]];
+
+    let multiply = |matrix: [[f32; 3]; 3], v: [f32; 3]| {
+        matrix.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+    };
+    multiply(XYZ_TO_LINEAR_SRGB, multiply(D50_TO_D65, xyz))
+}
+
+// Chroma below which an Oklch color is treated as having no hue.
+const ACHROMATIC_CHROMA: f32 = 1e-4;
+
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        12.92 * c
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+fn unit_to_u8(c: f32) -> u8 {
+    (c.clamp(0.0, 1.0) * 255.0).round() as u8
 }
 
 fn hue(mut h: f32, m1: f32, m2: f32) -> f32 {
@@ -842,12 +1271,177 @@ mod tests {
             "#123456" => Color::rgb(18, 52, 86),
             "rgba(12, 34, 56, 0.3)" => Color::rgba(12, 34, 56, 77),
             "red" => Color::rgb(255, 0, 0),
+            "accent-color" => Color::AccentColor,
+            "Accent-Color" => Color::AccentColor,
+            "transparent" => Color::rgba(0, 0, 0, 0),
+            "currentColor" => Color::CurrentColor,
+            "lab(100% 0 0)" => Color::rgb(255, 255, 255),
+            "lab(0 0 0 / 50%)" => Color::rgba(0, 0, 0, 128),
+            "oklch(none none none)" => Color::rgb(0, 0, 0),
+            "OKLCH(100% 0.1 120deg / 0.2)" => Color::rgba(255, 255, 255, 51),
+            "color-mix(in srgb, red 20%, blue)" => Color::rgb(51, 0, 204),
+            "color-mix(in srgb, 80% blue, red)" => Color::rgb(51, 0, 204),
+            "color-mix(in srgb, red 20%, blue 20%)" => Color::rgba(128, 0, 128, 102),
         }
 
         failure {
             "0",
             "#000000000",
             "#FFFFFFFFF",
+            "lab(50% 0)",
+            "lab(50% 0 0 0)",
+            "oklch(50% 0.1 10px)",
+            "color-mix(in srgb, red)",
+            "color-mix(srgb, red, blue)",
+            "color-mix(in srgb, red 0%, blue 0%)",
+            "color-mix(in srgb, red 120%, blue)",
+            "color-mix(in srgb, currentcolor, blue)",
+        }
+    }
+
+    fn parse(css: &str) -> RGBA {
+        let mut input = ParserInput::new(css);
+        let mut parser = Parser::new(&mut input);
+        Color::parse(&mut parser).unwrap().get_rgba()
+    }
+
+    // The reference values are from the examples of CSS Color 4.
+    #[test]
+    fn lab_and_lch() {
+        assert_rgba_near(parse("lab(29.2345% 39.3825 20.0664)"), RGBA::rgb(125, 35, 41));
+        assert_rgba_near(parse("lch(29.2345% 44.2 27)"), RGBA::rgb(125, 35, 41));
+        assert_rgba_near(parse("lch(29.2345% 29.4667% 0.4712rad)"), RGBA::rgb(125, 35, 41));
+        assert_rgba_near(parse("lab(50% 0 0)"), RGBA::rgb(119, 119, 119));
+    }
+
+    #[test]
+    fn oklab_and_oklch() {
+        assert_rgba_near(parse("oklch(62.796% 0.25768 29.234)"), RGBA::RED);
+        assert_rgba_near(parse("oklab(0.45201 -0.03246 -0.31153)"), RGBA::BLUE);
+        assert_rgba_near(parse("oklab(45.201% -8.115% -77.8825%)"), RGBA::BLUE);
+        assert_rgba_near(parse("oklch(0.86644 0.29483 142.49535)"), RGBA::LIME);
+    }
+
+    #[test]
+    fn out_of_gamut_colors_keep_their_lightness_and_hue() {
+        let mapped = parse("oklch(70% 0.4 150)");
+        assert_rgba_near(mapped, RGBA::rgb(0, 194, 72));
+
+        // Clamping the components instead shifts the lightness and hue.
+        assert_ne!(mapped, RGBA::from_oklch([0.7, 0.4, 150.0], 255));
+
+        let [lightness, chroma, hue] = mapped.to_oklch();
+        assert!((lightness - 0.7).abs() < 0.02);
+        assert!(chroma < 0.4);
+        assert!((hue - 150.0).abs() < 5.0);
+
+        // Colors lighter than white or darker than black are mapped to them.
+        assert_eq!(parse("oklch(120% 0.3 40)"), RGBA::WHITE);
+        assert_eq!(parse("lab(-10% 20 20)"), RGBA::BLACK);
+    }
+
+    #[test]
+    fn color_mix() {
+        assert_eq!(
+            parse("color-mix(in oklab, red, blue)"),
+            RGBA::RED.mix(&RGBA::BLUE, 0.5, ColorSpace::Oklab)
+        );
+        assert_eq!(
+            parse("color-mix(in oklch, red 75%, oklch(45.2% 0.313 264))"),
+            RGBA::RED.mix(&parse("oklch(45.2% 0.313 264)"), 0.25, ColorSpace::Oklch)
+        );
+        assert_eq!(
+            parse("color-mix(in srgb-linear, red 60%, lime 60%)"),
+            RGBA::RED.mix(&RGBA::LIME, 0.5, ColorSpace::LinearSrgb)
+        );
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for color in [Color::rgba(12, 34, 56, 77), Color::AccentColor, Color::CurrentColor] {
+            assert_eq!(Color::from(color.to_string().as_str()), color);
+        }
+    }
+
+    fn assert_near(actual: [f32; 3], expected: [f32; 3]) {
+        for (a, e) in actual.iter().zip(expected.iter()) {
+            assert!((a - e).abs() < 1e-3, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    fn assert_rgba_near(actual: RGBA, expected: RGBA) {
+        let channels = |c: RGBA| [c.red, c.green, c.blue, c.alpha];
+        for (a, e) in channels(actual).iter().zip(channels(expected).iter()) {
+            assert!(a.abs_diff(*e) <= 1, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn linear_srgb() {
+        assert_near(RGBA::rgb(128, 0, 255).to_linear_srgb(), [0.21586, 0.0, 1.0]);
+        assert_eq!(RGBA::from_linear_srgb([0.21586, 0.0, 1.0], 255), RGBA::rgb(128, 0, 255));
+    }
+
+    #[test]
+    fn oklab() {
+        assert_near(RGBA::RED.to_oklab(), [0.62796, 0.22486, 0.12585]);
+        assert_near(RGBA::BLUE.to_oklab(), [0.45201, -0.03246, -0.31153]);
+        assert_near(RGBA::WHITE.to_oklab(), [1.0, 0.0, 0.0]);
+        assert_near(RGBA::BLACK.to_oklab(), [0.0, 0.0, 0.0]);
+
+        for color in [RGBA::RED, RGBA::TEAL, RGBA::rgba(12, 200, 99, 40)] {
+            assert_eq!(RGBA::from_oklab(color.to_oklab(), color.alpha), color);
+        }
+    }
+
+    #[test]
+    fn oklch() {
+        assert_near(RGBA::RED.to_oklch(), [0.62796, 0.25768, 29.23389]);
+        assert_near(RGBA::BLUE.to_oklch(), [0.45201, 0.31321, 264.05202]);
+
+        for color in [RGBA::RED, RGBA::TEAL, RGBA::rgba(12, 200, 99, 40)] {
+            assert_eq!(RGBA::from_oklch(color.to_oklch(), color.alpha), color);
+        }
+    }
+
+    #[test]
+    fn mix() {
+        assert_eq!(RGBA::RED.mix(&RGBA::LIME, 0.5, ColorSpace::Srgb), RGBA::rgb(128, 128, 0));
+        assert_rgba_near(
+            RGBA::RED.mix(&RGBA::LIME, 0.5, ColorSpace::LinearSrgb),
+            RGBA::rgb(188, 188, 0),
+        );
+        assert_rgba_near(
+            RGBA::RED.mix(&RGBA::LIME, 0.5, ColorSpace::Oklab),
+            RGBA::rgb(208, 168, 0),
+        );
+        assert_rgba_near(
+            RGBA::RED.mix(&RGBA::BLUE, 0.5, ColorSpace::Oklab),
+            RGBA::rgb(140, 83, 162),
+        );
+        assert_rgba_near(
+            RGBA::RED.mix(&RGBA::BLUE, 0.5, ColorSpace::Oklch),
+            RGBA::rgb(186, 0, 194),
+        );
+
+        // Mixing with an achromatic color keeps the hue of the other color.
+        assert_eq!(RGBA::WHITE.mix(&RGBA::RED, 0.0, ColorSpace::Oklch), RGBA::WHITE);
+        assert_eq!(RGBA::WHITE.mix(&RGBA::RED, 1.0, ColorSpace::Oklch), RGBA::RED);
+    }
+
+    #[test]
+    fn mix_alpha_independently() {
+        let transparent_red = RGBA::rgba(255, 0, 0, 0);
+        for color_space in
+            [ColorSpace::Srgb, ColorSpace::LinearSrgb, ColorSpace::Oklab, ColorSpace::Oklch]
+        {
+            let mixed = transparent_red.mix(&RGBA::BLUE, 0.5, color_space);
+            let opaque = RGBA::RED.mix(&RGBA::BLUE, 0.5, color_space);
+            assert_eq!(mixed.alpha, 128);
+            assert_eq!(
+                [mixed.red, mixed.green, mixed.blue],
+                [opaque.red, opaque.green, opaque.blue]
+            );
         }
     }
 }
@@ -0,0 +1,120 @@
+use cssparser::*;
+
+use crate::{Color, ConicGradient, CustomParseError, Gradient, LinearGradient, Parse, RGBA};
+
+/// A solid color or a gradient, used to paint borders, outlines, and text.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColorOrGradient {
+    Color(Color),
+    Gradient(Box<Gradient>),
+}
+
+impl ColorOrGradient {
+    /// Returns the color, or for a gradient the color of its first stop.
+    pub fn color(&self) -> Color {
+        match self {
+            ColorOrGradient::Color(color) => *color,
+            ColorOrGradient::Gradient(gradient) => match gradient.as_ref() {
+                Gradient::Linear(linear) => linear.stops.first().map(|stop| stop.color),
+                Gradient::Radial(radial) => radial.stops.first().map(|stop| stop.color),
+                Gradient::Conic(conic) => conic.stops.first().map(|stop| stop.color),
+                Gradient::None => None,
+            }
+            .unwrap_or_default(),
+        }
+    }
+
+    /// Returns the gradient, if this is a gradient.
+    pub fn gradient(&self) -> Option<&Gradient> {
+        match self {
+            ColorOrGradient::Color(_) => None,
+            ColorOrGradient::Gradient(gradient) => Some(gradient.as_ref()),
+        }
+    }
+}
+
+impl Default for ColorOrGradient {
+    fn default() -> Self {
+        ColorOrGradient::Color(Color::default())
+    }
+}
+
+impl<'i> Parse<'i> for ColorOrGradient {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if let Ok(gradient) = input.try_parse(Gradient::parse) {
+            return Ok(ColorOrGradient::Gradient(Box::new(gradient)));
+        }
+
+        Color::parse(input).map(ColorOrGradient::Color)
+    }
+}
+
+impl From<Color> for ColorOrGradient {
+    fn from(color: Color) -> Self {
+        ColorOrGradient::Color(color)
+    }
+}
+
+impl From<RGBA> for ColorOrGradient {
+    fn from(rgba: RGBA) -> Self {
+        ColorOrGradient::Color(rgba.into())
+    }
+}
+
+impl From<Gradient> for ColorOrGradient {
+    fn from(gradient: Gradient) -> Self {
+        ColorOrGradient::Gradient(Box::new(gradient))
+    }
+}
+
+impl From<LinearGradient> for ColorOrGradient {
+    fn from(linear_gradient: LinearGradient) -> Self {
+        ColorOrGradient::Gradient(Box::new(Gradient::Linear(linear_gradient)))
+    }
+}
+
+impl From<ConicGradient> for ColorOrGradient {
+    fn from(conic_gradient: ConicGradient) -> Self {
+        ColorOrGradient::Gradient(Box::new(Gradient::Conic(conic_gradient)))
+    }
+}
+
+impl From<&str> for ColorOrGradient {
+    fn from(s: &str) -> Self {
+        let mut input = ParserInput::new(s);
+        let mut parser = Parser::new(&mut input);
+        ColorOrGradient::parse(&mut parser).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+    use crate::{HorizontalPositionKeyword, LineDirection};
+
+    assert_parse! {
+        ColorOrGradient, parse_color_or_gradient,
+
+        custom {
+            success {
+                "red" => ColorOrGradient::Color(Color::rgb(255, 0, 0)),
+                "linear-gradient(to right, red, blue)" => ColorOrGradient::from(LinearGradient {
+                    direction: LineDirection::Horizontal(HorizontalPositionKeyword::Right),
+                    stops: vec![Color::rgb(255, 0, 0).into(), Color::rgb(0, 0, 255).into()],
+                }),
+            }
+
+            failure {
+                "test",
+                "123",
+            }
+        }
+    }
+
+    #[test]
+    fn converts_from_colors() {
+        let rgba = RGBA { red: 255, green: 0, blue: 0, alpha: 255 };
+        assert_eq!(ColorOrGradient::from(rgba), ColorOrGradient::Color(Color::rgb(255, 0, 0)));
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{define_enum, Parse};
+
+define_enum! {
+    /// The color space in which colors are interpolated during transitions and animations.
+    #[derive(Default)]
+    pub enum ColorSpace {
+        /// Interpolates the gamma encoded sRGB components.
+        #[default]
+        "srgb": Srgb,
+        /// Interpolates the linear light sRGB components.
+        "srgb-linear": LinearSrgb,
+        /// Interpolates in the perceptually uniform Oklab color space.
+        "oklab": Oklab,
+        /// Interpolates lightness, chroma, and hue in the Oklch color space, taking the shorter path around the hue circle.
+        "oklch": Oklch,
+    }
+}
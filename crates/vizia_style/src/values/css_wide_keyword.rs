@@ -0,0 +1,22 @@
+use cssparser::CowRcStr;
+
+use crate::{define_enum, Parse};
+
+define_enum! {
+    /// A keyword which every property accepts, which sets it to a value from the cascade rather than a concrete value.
+    pub enum CssWideKeyword {
+        /// The initial value of the property, which is the value it has when it isn't set.
+        "initial": Initial,
+        /// The computed value of the property for the parent of the view.
+        "inherit": Inherit,
+        /// Inherits the value for an inherited property, or sets the initial value for any other property.
+        "unset": Unset,
+    }
+}
+
+/// A declaration which sets a property to a CSS-wide keyword, such as `color: inherit`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CssWideProperty<'i> {
+    pub name: CowRcStr<'i>,
+    pub keyword: CssWideKeyword,
+}
@@ -127,7 +127,9 @@ impl<'i> TokenList<'i> {
                             Ok(TokenOrValue::Var(var))
                         })?;
                         tokens.push(var);
-                        last_is_delim = true;
+                        // Whitespace after a variable is kept so that its value isn't joined to the next token
+                        // when it's substituted.
+                        last_is_delim = false;
                         last_is_whitespace = false;
                     } else {
                         tokens.push(Token::Function(f).into());
@@ -192,6 +194,95 @@ impl<'i> TokenList<'i> {
     }
 }
 
+impl<'i> TokenList<'i> {
+    /// Parses a list of tokens from a string, such as the value of a custom property.
+    pub fn parse_string(value: &'i str) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let mut input = ParserInput::new(value);
+        let mut parser = Parser::new(&mut input);
+        TokenList::parse(&mut parser)
+    }
+
+    /// Returns true if the tokens contain a `var()` reference.
+    pub fn has_variables(&self) -> bool {
+        self.0.iter().any(|token| matches!(token, TokenOrValue::Var(_)))
+    }
+
+    /// Serializes the tokens with each `var()` reference replaced by the value of the variable, which is looked up
+    /// by name with `lookup`. Variables referenced by the value of a variable are replaced in turn.
+    ///
+    /// The fallback of a reference is used if the variable isn't defined or refers back to itself. Returns `None`
+    /// if a reference has neither a value nor a fallback.
+    pub fn substitute_variables<'a>(
+        &self,
+        lookup: &impl Fn(&str) -> Option<&'a str>,
+    ) -> Option<String> {
+        let mut dest = String::new();
+        self.substitute_into(&mut dest, lookup, &mut Vec::new())?;
+        Some(dest)
+    }
+
+    fn substitute_into<'a>(
+        &self,
+        dest: &mut String,
+        lookup: &impl Fn(&str) -> Option<&'a str>,
+        stack: &mut Vec<String>,
+    ) -> Option<()> {
+        for token in self.0.iter() {
+            match token {
+                TokenOrValue::Var(variable) => {
+                    let name = variable.name.0.as_ref();
+                    let value = lookup(name)
+                        .filter(|_| !stack.iter().any(|outer| outer == name))
+                        .and_then(|value| {
+                            let tokens = TokenList::parse_string(value).ok()?;
+                            stack.push(name.to_owned());
+                            let mut value = String::new();
+                            let result = tokens.substitute_into(&mut value, lookup, stack);
+                            stack.pop();
+                            result.map(|_| value)
+                        });
+
+                    match (value, &variable.fallback) {
+                        (Some(value), _) => dest.push_str(&value),
+                        (None, Some(fallback)) => fallback.substitute_into(dest, lookup, stack)?,
+                        (None, None) => return None,
+                    }
+                }
+
+                token => token.to_css(dest).ok()?,
+            }
+        }
+
+        Some(())
+    }
+}
+
+impl ToCss for TokenList<'_> {
+    fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+    where
+        W: std::fmt::Write,
+    {
+        for token in self.0.iter() {
+            token.to_css(dest)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ToCss for TokenOrValue<'_> {
+    fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+    where
+        W: std::fmt::Write,
+    {
+        match self {
+            TokenOrValue::Token(token) => token.to_css(dest),
+            TokenOrValue::Color(color) => write!(dest, "{}", crate::Color::from(color.clone())),
+            TokenOrValue::Var(variable) => variable.to_css(dest),
+        }
+    }
+}
+
 #[inline]
 fn try_parse_color_token<'i>(
     f: &CowRcStr<'i>,
@@ -236,11 +327,61 @@ impl<'i> Variable<'i> {
     }
 }
 
+impl ToCss for Variable<'_> {
+    fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+    where
+        W: std::fmt::Write,
+    {
+        dest.write_str("var(")?;
+        dest.write_str(&self.name.0)?;
+        if let Some(fallback) = &self.fallback {
+            dest.write_str(", ")?;
+            fallback.to_css(dest)?;
+        }
+        dest.write_str(")")
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use cssparser::CowRcStr;
+    use cssparser::{CowRcStr, ToCss};
+
+    use crate::{CustomProperty, TokenList};
 
-    use crate::CustomProperty;
+    fn lookup(name: &str) -> Option<&'static str> {
+        match name {
+            "--accent" => Some("#ff0000"),
+            "--border" => Some("2px solid var(--accent)"),
+            "--loop" => Some("var(--loop)"),
+            _ => None,
+        }
+    }
+
+    fn substitute(value: &str) -> Option<String> {
+        TokenList::parse_string(value).unwrap().substitute_variables(&lookup)
+    }
+
+    #[test]
+    fn serialize_variables() {
+        let tokens = TokenList::parse_string("1px  solid var(--accent, rgb(0, 0, 255))").unwrap();
+        assert!(tokens.has_variables());
+        assert_eq!(tokens.to_css_string(), "1px solid var(--accent, #0000ffff)");
+        assert!(!TokenList::parse_string("1px solid red").unwrap().has_variables());
+    }
+
+    #[test]
+    fn substitute_variables() {
+        assert_eq!(substitute("var(--accent)").as_deref(), Some("#ff0000"));
+        assert_eq!(substitute("var(--border)").as_deref(), Some("2px solid #ff0000"));
+        assert_eq!(
+            substitute("1px var(--missing, dashed) blue").as_deref(),
+            Some("1px dashed blue")
+        );
+        assert_eq!(substitute("var(--missing, var(--accent))").as_deref(), Some("#ff0000"));
+        assert_eq!(substitute("var(--missing)"), None);
+        assert_eq!(substitute("var(--loop)"), None);
+        assert_eq!(substitute("var(--loop, 4px)").as_deref(), Some("4px"));
+    }
 
     #[test]
     fn parse_custom_ident() {
@@ -1,5 +1,5 @@
 use crate::{Calc, CustomParseError, Parse, Percentage, TryAdd};
-use cssparser::{ParseError, Parser};
+use cssparser::{ParseError, Parser, ToCss};
 
 /// A generic type that allows any kind of dimension and percentage to be
 /// used standalone or mixed within a calc() expression.
@@ -222,3 +222,23 @@ impl<D: std::cmp::PartialOrd<D>> std::cmp::PartialOrd<DimensionPercentage<D>>
         }
     }
 }
+
+impl<
+        D: ToCss
+            + Clone
+            + std::ops::Mul<f32, Output = D>
+            + std::cmp::PartialOrd<f32>
+            + std::fmt::Debug,
+    > ToCss for DimensionPercentage<D>
+{
+    fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+    where
+        W: std::fmt::Write,
+    {
+        match self {
+            DimensionPercentage::Dimension(dimension) => dimension.to_css(dest),
+            DimensionPercentage::Percentage(percentage) => write!(dest, "{}%", percentage.0),
+            DimensionPercentage::Calc(calc) => calc.to_css(dest),
+        }
+    }
+}
@@ -1,6 +1,35 @@
 use crate::{CustomParseError, Parse};
 use cssparser::*;
 
+/// Where the jumps of a [`steps`](EasingFunction::Steps) easing function occur.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StepPosition {
+    /// The first jump happens at the start of the animation.
+    JumpStart,
+    /// The last jump happens at the end of the animation.
+    #[default]
+    JumpEnd,
+    /// There are no jumps at the start or end of the animation, so it holds its start and end values for a step
+    /// each.
+    JumpNone,
+    /// There are jumps at both the start and end of the animation.
+    JumpBoth,
+}
+
+impl<'i> Parse<'i> for StepPosition {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let location = input.current_source_location();
+        let ident = input.expect_ident()?;
+        Ok(match_ignore_ascii_case! { ident,
+            "jump-start" | "start" => StepPosition::JumpStart,
+            "jump-end" | "end" => StepPosition::JumpEnd,
+            "jump-none" => StepPosition::JumpNone,
+            "jump-both" => StepPosition::JumpBoth,
+            _ => return Err(location.new_unexpected_token_error(Token::Ident(ident.clone()))),
+        })
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub enum EasingFunction {
     #[default]
@@ -9,8 +38,26 @@ pub enum EasingFunction {
     EaseIn,
     EaseOut,
     EaseInOut,
+    /// A cubic Bézier curve from (0, 0) to (1, 1) with the control points (x1, y1) and (x2, y2), where x1 and x2 are
+    /// between 0 and 1.
     CubicBezier(f32, f32, f32, f32),
-    // TODO: Steps
+    /// A step function which divides the animation into a number of equal intervals.
+    Steps(u32, StepPosition),
+    /// A damped spring with a mass of one, which moves with the given initial velocity, in distances per second.
+    ///
+    /// A spring runs until it comes to rest, which may overshoot the end value, rather than for a fixed duration.
+    Spring {
+        stiffness: f32,
+        damping: f32,
+        velocity: f32,
+    },
+}
+
+impl EasingFunction {
+    /// Creates a spring easing which starts at rest.
+    pub fn spring(stiffness: f32, damping: f32) -> Self {
+        EasingFunction::Spring { stiffness, damping, velocity: 0.0 }
+    }
 }
 
 impl<'i> Parse<'i> for EasingFunction {
@@ -23,8 +70,8 @@ impl<'i> Parse<'i> for EasingFunction {
               "ease-in" => EasingFunction::EaseIn,
               "ease-out" => EasingFunction::EaseOut,
               "ease-in-out" => EasingFunction::EaseInOut,
-            //   "step-start" => EasingFunction::Steps { count: 1, position: StepPosition::Start },
-            //   "step-end" => EasingFunction::Steps { count: 1, position: StepPosition::End },
+              "step-start" => EasingFunction::Steps(1, StepPosition::JumpStart),
+              "step-end" => EasingFunction::Steps(1, StepPosition::JumpEnd),
               _ => return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
             };
             return Ok(keyword);
@@ -34,7 +81,6 @@ impl<'i> Parse<'i> for EasingFunction {
         input.parse_nested_block(|input| {
             match_ignore_ascii_case! { &function,
               "cubic-bezier" => {
-                // let x1 = CSSNumber::parse(input)?;
                 let x1 = input.try_parse(|input| input.expect_number())?;
                 input.expect_comma()?;
                 let y1 = input.try_parse(|input| input.expect_number())?;
@@ -42,18 +88,83 @@ impl<'i> Parse<'i> for EasingFunction {
                 let x2 = input.try_parse(|input| input.expect_number())?;
                 input.expect_comma()?;
                 let y2 = input.try_parse(|input| input.expect_number())?;
-                Ok(EasingFunction::CubicBezier(x1, y1, x2, y2))
+
+                // The curve must be a function of time, so the control points can't go back in time.
+                if (0.0..=1.0).contains(&x1) && (0.0..=1.0).contains(&x2) {
+                    Ok(EasingFunction::CubicBezier(x1, y1, x2, y2))
+                } else {
+                    Err(location.new_unexpected_token_error(Token::Ident(function.clone())))
+                }
+              },
+              "spring" => {
+                let stiffness = input.expect_number()?;
+                input.expect_comma()?;
+                let damping = input.expect_number()?;
+                let velocity = input.try_parse(|input| {
+                    input.expect_comma()?;
+                    input.expect_number()
+                }).unwrap_or_default();
+
+                if stiffness > 0.0 && damping >= 0.0 {
+                    Ok(EasingFunction::Spring { stiffness, damping, velocity })
+                } else {
+                    Err(location.new_unexpected_token_error(Token::Ident(function.clone())))
+                }
+              },
+              "steps" => {
+                let count = input.expect_integer()?;
+                let position = input.try_parse(|input| {
+                    input.expect_comma()?;
+                    StepPosition::parse(input)
+                }).unwrap_or_default();
+
+                // Without jumps at either end there must be at least two steps to move between.
+                let min_count = if position == StepPosition::JumpNone { 2 } else { 1 };
+                if count >= min_count {
+                    Ok(EasingFunction::Steps(count as u32, position))
+                } else {
+                    Err(location.new_unexpected_token_error(Token::Ident(function.clone())))
+                }
               },
-            //   "steps" => {
-            //     let count = CSSInteger::parse(input)?;
-            //     let position = input.try_parse(|input| {
-            //       input.expect_comma()?;
-            //       StepPosition::parse(input)
-            //     }).unwrap_or_default();
-            //     Ok(EasingFunction::Steps { count, position })
-            //   },
               _ => Err(location.new_unexpected_token_error(Token::Ident(function.clone())))
             }
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        EasingFunction, assert_easing_function,
+
+        custom {
+            success {
+                "ease-in-out" => EasingFunction::EaseInOut,
+                "step-start" => EasingFunction::Steps(1, StepPosition::JumpStart),
+                "step-end" => EasingFunction::Steps(1, StepPosition::JumpEnd),
+                "cubic-bezier(0.4, 0, 0.2, 1)" => EasingFunction::CubicBezier(0.4, 0.0, 0.2, 1.0),
+                "cubic-bezier(0, -0.5, 1, 1.5)" => EasingFunction::CubicBezier(0.0, -0.5, 1.0, 1.5),
+                "steps(4)" => EasingFunction::Steps(4, StepPosition::JumpEnd),
+                "steps(4, end)" => EasingFunction::Steps(4, StepPosition::JumpEnd),
+                "steps(4, start)" => EasingFunction::Steps(4, StepPosition::JumpStart),
+                "steps(1, jump-start)" => EasingFunction::Steps(1, StepPosition::JumpStart),
+                "steps(3, jump-both)" => EasingFunction::Steps(3, StepPosition::JumpBoth),
+                "steps(2, jump-none)" => EasingFunction::Steps(2, StepPosition::JumpNone),
+            }
+
+            failure {
+                "cubic-bezier(1.2, 0, 0.2, 1)",
+                "cubic-bezier(0.4, 0, -0.1, 1)",
+                "cubic-bezier(0.4, 0, 0.2)",
+                "steps(0)",
+                "steps(1.5)",
+                "steps(1, jump-none)",
+                "steps(4, middle)",
+                "step-middle",
+            }
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use cssparser::{Parser, ParserInput};
+
+use crate::{define_enum, impl_parse, Parse};
+
+define_enum! {
+    /// A named level of elevation.
+    pub enum ElevationLevel {
+        /// No elevation, equivalent to `0`.
+        "none": None,
+        /// Slightly raised content, such as a card, equivalent to `1`.
+        "low": Low,
+        /// Content raised above its surroundings, such as a menu, equivalent to `3`.
+        "medium": Medium,
+        /// The most raised content, such as a dialog, equivalent to `5`.
+        "high": High,
+    }
+}
+
+/// How far a view is raised above the surface behind it, which is shown by shadows derived from a built-in table
+/// of levels from 0 to 5.
+///
+/// A fractional elevation, such as the elevation in the middle of a transition, is shown by shadows between those of
+/// the levels either side of it.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Elevation(pub f32);
+
+impl_parse! {
+    Elevation,
+
+    try_parse {
+        ElevationLevel,
+        u32,
+    }
+}
+
+impl From<ElevationLevel> for Elevation {
+    fn from(level: ElevationLevel) -> Self {
+        match level {
+            ElevationLevel::None => Elevation(0.0),
+            ElevationLevel::Low => Elevation(1.0),
+            ElevationLevel::Medium => Elevation(3.0),
+            ElevationLevel::High => Elevation(5.0),
+        }
+    }
+}
+
+impl From<&str> for Elevation {
+    fn from(s: &str) -> Self {
+        let mut input = ParserInput::new(s);
+        let mut parser = Parser::new(&mut input);
+        Elevation::parse(&mut parser).unwrap_or_default()
+    }
+}
+
+impl From<u32> for Elevation {
+    fn from(level: u32) -> Self {
+        Elevation(level as f32)
+    }
+}
+
+impl From<i32> for Elevation {
+    fn from(level: i32) -> Self {
+        Elevation(level.max(0) as f32)
+    }
+}
+
+impl From<f32> for Elevation {
+    fn from(level: f32) -> Self {
+        Elevation(level.max(0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests_elevation {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        Elevation, parse_elevation,
+
+        custom {
+            success {
+                "0" => Elevation(0.0),
+                "2" => Elevation(2.0),
+                "none" => Elevation(0.0),
+                "medium" => Elevation(3.0),
+                "high" => Elevation(5.0),
+            }
+
+            failure {
+                "-1",
+                "1.5",
+                "2px",
+                "raised",
+            }
+        }
+    }
+}
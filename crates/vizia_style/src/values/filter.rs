@@ -0,0 +1,185 @@
+use crate::{Angle, CustomParseError, Length, Parse, PercentageOrNumber, Shadow};
+use cssparser::*;
+
+/// A filter function, which is applied to the content of a view by the `filter` property, or to what's behind it by
+/// the `backdrop-filter` property.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Filter {
+    /// Blurs the content with a gaussian blur of the given radius.
+    Blur(Length),
+    /// Scales the brightness of the content, where 0.0 is black and 1.0 leaves it unchanged.
+    Brightness(f32),
+    /// Scales the contrast of the content, where 0.0 is mid gray and 1.0 leaves it unchanged.
+    Contrast(f32),
+    /// Converts the content to grayscale by the given amount, from 0.0 to 1.0.
+    Grayscale(f32),
+    /// Scales the saturation of the content, where 0.0 is grayscale and 1.0 leaves it unchanged.
+    Saturate(f32),
+    /// Rotates the hue of the content by the given angle.
+    HueRotate(Angle),
+    /// Draws a blurred shadow of the shape of the content beneath it.
+    DropShadow(Shadow),
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Filter::Blur(Length::default())
+    }
+}
+
+// Parses the optional amount of a filter function, as a number or a percentage, which defaults to 100%.
+fn parse_amount<'i>(
+    input: &mut Parser<'i, '_>,
+) -> Result<f32, ParseError<'i, CustomParseError<'i>>> {
+    if input.is_exhausted() {
+        return Ok(1.0);
+    }
+
+    let amount = PercentageOrNumber::parse(input)?.to_factor();
+    if amount < 0.0 {
+        return Err(input.new_custom_error(CustomParseError::InvalidValue));
+    }
+
+    Ok(amount)
+}
+
+fn parse_zero<'i>(input: &mut Parser<'i, '_>) -> Result<(), ParseError<'i, CustomParseError<'i>>> {
+    let location = input.current_source_location();
+    if input.expect_number()? != 0.0 {
+        return Err(location.new_custom_error(CustomParseError::InvalidValue));
+    }
+
+    Ok(())
+}
+
+impl<'i> Parse<'i> for Filter {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let function = input.expect_function()?.clone();
+
+        input.parse_nested_block(|input| {
+            let location = input.current_source_location();
+            match_ignore_ascii_case! { &function,
+                "blur" => {
+                    Ok(Filter::Blur(input.try_parse(Length::parse).unwrap_or(Length::px(0.0))))
+                },
+
+                "brightness" => parse_amount(input).map(Filter::Brightness),
+                "contrast" => parse_amount(input).map(Filter::Contrast),
+                "grayscale" => parse_amount(input).map(|amount| Filter::Grayscale(amount.min(1.0))),
+                "saturate" => parse_amount(input).map(Filter::Saturate),
+
+                // A unitless zero is allowed for the angle.
+                "hue-rotate" => {
+                    if input.is_exhausted() || input.try_parse(parse_zero).is_ok() {
+                        return Ok(Filter::HueRotate(Angle::Deg(0.0)));
+                    }
+
+                    Ok(Filter::HueRotate(Angle::parse(input)?))
+                },
+
+                // A drop shadow has no spread and can't be inset.
+                "drop-shadow" => {
+                    let shadow = Shadow::parse(input)?;
+                    if shadow.spread_radius.is_some() || shadow.inset {
+                        return Err(location.new_custom_error(CustomParseError::InvalidValue));
+                    }
+
+                    Ok(Filter::DropShadow(shadow))
+                },
+
+                _ => {
+                    Err(location.new_unexpected_token_error(Token::Ident(function)))
+                }
+            }
+        })
+    }
+}
+
+/// Parses a list of filter functions separated by whitespace, or `none` for an empty list.
+impl<'i> Parse<'i> for Vec<Filter> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+            return Ok(Vec::new());
+        }
+
+        let mut filters = vec![Filter::parse(input)?];
+        loop {
+            if input.is_exhausted() {
+                return Ok(filters);
+            }
+
+            input.skip_whitespace();
+            let location = input.current_source_location();
+
+            if let Ok(filter) = input.try_parse(Filter::parse) {
+                filters.push(filter);
+            } else {
+                return Err(location.new_custom_error(CustomParseError::InvalidDeclaration));
+            }
+        }
+    }
+}
+
+impl From<Filter> for Vec<Filter> {
+    fn from(filter: Filter) -> Self {
+        vec![filter]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::assert_parse, Color};
+
+    assert_parse! {
+        Filter, parse_filter,
+
+        custom {
+            success {
+                "blur(4px)" => Filter::Blur(Length::px(4.0)),
+                "blur()" => Filter::Blur(Length::px(0.0)),
+                "brightness(150%)" => Filter::Brightness(1.5),
+                "contrast(0.5)" => Filter::Contrast(0.5),
+                "grayscale()" => Filter::Grayscale(1.0),
+                "grayscale(200%)" => Filter::Grayscale(1.0),
+                "saturate(2)" => Filter::Saturate(2.0),
+                "hue-rotate(90deg)" => Filter::HueRotate(Angle::Deg(90.0)),
+                "hue-rotate(0)" => Filter::HueRotate(Angle::Deg(0.0)),
+                "drop-shadow(2px 4px 6px red)" => Filter::DropShadow(Shadow::new(
+                    Length::px(2.0),
+                    Length::px(4.0),
+                    Some(Length::px(6.0)),
+                    None,
+                    Some(Color::rgb(255, 0, 0)),
+                    false,
+                )),
+            }
+
+            failure {
+                "test",
+                "blur",
+                "brightness(-1)",
+                "hue-rotate(10px)",
+                "drop-shadow(2px 4px 6px 8px red)",
+                "drop-shadow(inset 2px 4px)",
+                "sepia(1)",
+            }
+        }
+    }
+
+    assert_parse! {
+        Vec<Filter>, parse_filter_list,
+
+        custom {
+            success {
+                "none" => vec![],
+                "grayscale(1) blur(2px)" => vec![Filter::Grayscale(1.0), Filter::Blur(Length::px(2.0))],
+            }
+
+            failure {
+                "test",
+                "blur(2px) none",
+            }
+        }
+    }
+}
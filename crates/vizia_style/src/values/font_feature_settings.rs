@@ -0,0 +1,134 @@
+use cssparser::*;
+use skia_safe::FourByteTag;
+
+use crate::{CustomParseError, Parse};
+
+/// An OpenType feature of a font and its value, such as tabular figures, `"tnum"`, or ligatures, `"liga" off`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct FontFeature {
+    /// The tag of the feature.
+    pub tag: FourByteTag,
+    /// The value of the feature, which is `1` when it's on and `0` when it's off, or the index of an alternate glyph
+    /// for features which select one.
+    pub value: u32,
+}
+
+impl FontFeature {
+    /// Creates a font feature which sets the feature with the given tag to a value.
+    pub fn new(tag: &[u8; 4], value: u32) -> Self {
+        Self { tag: u32::from_be_bytes(*tag).into(), value }
+    }
+
+    /// Returns the tag of the feature as text.
+    pub fn name(&self) -> String {
+        u32::from(self.tag).to_be_bytes().iter().map(|byte| *byte as char).collect()
+    }
+}
+
+impl<'i> Parse<'i> for FontFeature {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        // The tag is quoted in CSS, but may also be written without quotes, such as in `font_feature_settings("tnum")`.
+        let tag = match input.try_parse(FourByteTag::parse) {
+            Ok(tag) => tag,
+            Err(_) => {
+                let location = input.current_source_location();
+                let ident = input.expect_ident_cloned()?;
+                let bytes = <[u8; 4]>::try_from(ident.as_bytes())
+                    .map_err(|_| location.new_custom_error(CustomParseError::InvalidValue))?;
+                u32::from_be_bytes(bytes).into()
+            }
+        };
+
+        let value = if let Ok(value) = input.try_parse(|input| input.expect_integer()) {
+            u32::try_from(value).map_err(|_| input.new_custom_error(CustomParseError::InvalidValue))?
+        } else if let Ok(on) = input.try_parse(|input| {
+            let location = input.current_source_location();
+            let ident = input.expect_ident_cloned()?;
+            match_ignore_ascii_case! { &ident,
+                "on" => Ok(true),
+                "off" => Ok(false),
+                _ => Err(location.new_unexpected_token_error::<CustomParseError<'i>>(Token::Ident(ident.clone()))),
+            }
+        }) {
+            on as u32
+        } else {
+            1
+        };
+
+        Ok(FontFeature { tag, value })
+    }
+}
+
+/// The OpenType features of the font of a view which are turned on or off, in addition to those the font uses by
+/// default.
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct FontFeatureSettings(pub Vec<FontFeature>);
+
+impl<'i> Parse<'i> for FontFeatureSettings {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+            return Ok(FontFeatureSettings::default());
+        }
+
+        input.parse_comma_separated(FontFeature::parse).map(FontFeatureSettings)
+    }
+}
+
+impl From<&str> for FontFeatureSettings {
+    fn from(s: &str) -> Self {
+        let mut input = ParserInput::new(s);
+        let mut parser = Parser::new(&mut input);
+        FontFeatureSettings::parse(&mut parser).unwrap_or_default()
+    }
+}
+
+impl From<FontFeature> for FontFeatureSettings {
+    fn from(feature: FontFeature) -> Self {
+        FontFeatureSettings(vec![feature])
+    }
+}
+
+impl From<Vec<FontFeature>> for FontFeatureSettings {
+    fn from(features: Vec<FontFeature>) -> Self {
+        FontFeatureSettings(features)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        FontFeatureSettings, assert_font_feature_settings,
+
+        custom {
+            success {
+                "normal" => FontFeatureSettings(vec![]),
+                "\"tnum\"" => FontFeatureSettings(vec![FontFeature::new(b"tnum", 1)]),
+                "\"liga\" off, \"dlig\" on" => FontFeatureSettings(vec![
+                    FontFeature::new(b"liga", 0),
+                    FontFeature::new(b"dlig", 1),
+                ]),
+                "\"ss02\" 2" => FontFeatureSettings(vec![FontFeature::new(b"ss02", 2)]),
+                "tnum, liga 0" => FontFeatureSettings(vec![
+                    FontFeature::new(b"tnum", 1),
+                    FontFeature::new(b"liga", 0),
+                ]),
+            }
+
+            failure {
+                "\"tnum\" -1",
+                "\"tabular\"",
+                "\"tnum\" maybe",
+                "\"tnum\" \"liga\"",
+            }
+        }
+    }
+
+    #[test]
+    fn feature_names() {
+        assert_eq!(FontFeature::new(b"tnum", 1).name(), "tnum");
+        assert_eq!(FontFeatureSettings::from("\"liga\" off").0, vec![FontFeature::new(b"liga", 0)]);
+    }
+}
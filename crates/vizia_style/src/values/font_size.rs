@@ -1,17 +1,28 @@
 use cssparser::*;
 
-use crate::{macros::impl_parse, FontSizeKeyword, Parse};
+use crate::{CustomParseError, FontSizeKeyword, Length, Parse};
 
-/// A font size value.
+/// A font size value, in pixels.
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct FontSize(pub f32);
 
-impl_parse! {
-    FontSize,
+impl<'i> Parse<'i> for FontSize {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if let Ok(keyword) = input.try_parse(FontSizeKeyword::parse) {
+            return Ok(keyword.into());
+        }
+
+        if let Ok(number) = input.try_parse(f32::parse) {
+            return Ok(FontSize(number));
+        }
 
-    try_parse {
-        FontSizeKeyword,
-        f32,
+        // An absolute length, or a calculation of absolute lengths such as `clamp(12px, 14pt, 20px)`.
+        let location = input.current_source_location();
+        let length = Length::parse(input)?;
+        length
+            .to_px()
+            .map(FontSize)
+            .ok_or_else(|| location.new_custom_error(CustomParseError::InvalidValue))
     }
 }
 
@@ -94,5 +105,20 @@ mod tests {
         number {
             FontSize,
         }
+
+        custom {
+            success {
+                "16px" => FontSize(16.0),
+                "calc(10px + 4px)" => FontSize(14.0),
+                "clamp(12px, 24px, 20px)" => FontSize(20.0),
+                "max(12px, min(16px, 24px))" => FontSize(16.0),
+            }
+
+            failure {
+                "2em",
+                "clamp(12px, 2vw, 20px)",
+                "50%",
+            }
+        }
     }
 }
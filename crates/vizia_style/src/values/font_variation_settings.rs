@@ -3,9 +3,17 @@ use skia_safe::{font_arguments::variation_position::Coordinate, FourByteTag};
 
 use crate::{CustomParseError, Parse};
 
+/// The value of an axis of a variable font, such as its weight, `"wght"`, or its slant, `"slnt"`.
 #[derive(Copy, Clone, PartialEq, Default, Debug)]
 pub struct FontVariation(pub Coordinate);
 
+impl FontVariation {
+    /// Creates a font variation which sets the axis with the given tag to a value.
+    pub fn new(axis: &[u8; 4], value: f32) -> Self {
+        Self(Coordinate { axis: u32::from_be_bytes(*axis).into(), value })
+    }
+}
+
 impl<'i> Parse<'i> for FontVariation {
     fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
         let location = input.current_source_location();
@@ -26,6 +34,11 @@ impl<'i> Parse<'i> for FontVariation {
 
 impl<'i> Parse<'i> for Vec<FontVariation> {
     fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        // The font is drawn with the default value of each axis.
+        if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+            return Ok(Vec::new());
+        }
+
         input.parse_comma_separated(FontVariation::parse)
     }
 }
@@ -93,6 +106,11 @@ mod tests {
                     FontVariation (Coordinate { axis: ('w', 'd', 't', 'h').into(), value: 125.0 }),
                     FontVariation (Coordinate { axis: ('w', 'g', 'h', 't').into(), value: 400.0 }),
                 ],
+                "normal" => vec![],
+                r#""wght" 650, "slnt" -10"# => vec![
+                    FontVariation::new(b"wght", 650.0),
+                    FontVariation::new(b"slnt", -10.0),
+                ],
             }
 
             failure {
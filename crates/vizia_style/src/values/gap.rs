@@ -0,0 +1,75 @@
+use cssparser::{Parser, ParserInput};
+
+use crate::{impl_parse, traits::Parse, Units};
+
+/// The space between the rows and between the columns of the children of a view, which is only inserted between
+/// adjacent children and not before the first or after the last.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Gap {
+    /// The space between children stacked vertically, which is the vertical gap.
+    pub row: Units,
+    /// The space between children placed side by side, which is the horizontal gap.
+    pub column: Units,
+}
+
+impl Gap {
+    /// Creates a new gap from the row and column gaps.
+    pub fn new(row: impl Into<Units>, column: impl Into<Units>) -> Self {
+        Self { row: row.into(), column: column.into() }
+    }
+}
+
+impl_parse! {
+    Gap,
+
+    custom {
+        |input| {
+            let row = Units::parse(input)?;
+            let column = input.try_parse(Units::parse).unwrap_or(row);
+            Ok(Gap { row, column })
+        }
+    }
+}
+
+impl From<Units> for Gap {
+    fn from(gap: Units) -> Self {
+        Gap { row: gap, column: gap }
+    }
+}
+
+impl<T1: Into<Units>, T2: Into<Units>> From<(T1, T2)> for Gap {
+    fn from((row, column): (T1, T2)) -> Self {
+        Gap::new(row, column)
+    }
+}
+
+impl From<&str> for Gap {
+    fn from(s: &str) -> Self {
+        let mut input = ParserInput::new(s);
+        let mut parser = Parser::new(&mut input);
+        Gap::parse(&mut parser).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+    use crate::Units::*;
+
+    assert_parse! {
+        Gap, parse_gap,
+
+        success {
+            "8px" => Gap::new(Pixels(8.0), Pixels(8.0)),
+            "8px 4px" => Gap::new(Pixels(8.0), Pixels(4.0)),
+            "10% 1s" => Gap::new(Percentage(10.0), Stretch(1.0)),
+            "auto 2px" => Gap::new(Auto, Pixels(2.0)),
+        }
+
+        failure {
+            "test",
+            "red 2px",
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    Angle, Color, CustomParseError, HorizontalPositionKeyword, LengthOrPercentage, Parse, Position,
-    VerticalPositionKeyword,
+    Angle, AngleOrPercentage, Color, CustomParseError, HorizontalPositionKeyword,
+    LengthOrPercentage, Parse, Position, VerticalPositionKeyword,
 };
 use cssparser::*;
 
@@ -10,6 +10,7 @@ pub enum Gradient {
     None,
     Linear(LinearGradient),
     Radial(RadialGradient),
+    Conic(ConicGradient),
 }
 
 impl From<LinearGradient> for Gradient {
@@ -18,6 +19,12 @@ impl From<LinearGradient> for Gradient {
     }
 }
 
+impl From<ConicGradient> for Gradient {
+    fn from(conic_gradient: ConicGradient) -> Self {
+        Gradient::Conic(conic_gradient)
+    }
+}
+
 impl<'i> Parse<'i> for Gradient {
     fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
         let location = input.current_source_location();
@@ -26,6 +33,8 @@ impl<'i> Parse<'i> for Gradient {
             match_ignore_ascii_case! { &func,
               "linear-gradient" => Ok(Gradient::Linear(LinearGradient::parse(input)?)),
               "radial-gradient" => Ok(Gradient::Radial(RadialGradient::parse(input)?)),
+              "conic-gradient" => Ok(Gradient::Conic(ConicGradient::parse(input, false)?)),
+              "repeating-conic-gradient" => Ok(Gradient::Conic(ConicGradient::parse(input, true)?)),
               _ => Err(location.new_unexpected_token_error(cssparser::Token::Ident(func.clone())))
             }
         })
@@ -201,6 +210,63 @@ impl<'i> RadialGradient {
     }
 }
 
+/// A gradient which transitions between colors around a center point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConicGradient {
+    /// The angle of the start of the gradient, measured clockwise from the top.
+    pub from: Angle,
+    /// The center of the gradient.
+    pub position: Position,
+    /// The color stops of the gradient, positioned by their angle from the start of the gradient.
+    pub stops: Vec<ColorStop<AngleOrPercentage>>,
+    /// Whether the color stops repeat around the center.
+    pub repeating: bool,
+}
+
+impl Default for ConicGradient {
+    fn default() -> Self {
+        ConicGradient {
+            from: Angle::default(),
+            position: Position::center(),
+            stops: Vec::new(),
+            repeating: false,
+        }
+    }
+}
+
+impl<'i> ConicGradient {
+    fn parse<'t>(
+        input: &mut Parser<'i, 't>,
+        repeating: bool,
+    ) -> Result<ConicGradient, ParseError<'i, CustomParseError<'i>>> {
+        let from = input
+            .try_parse(|input| {
+                input.expect_ident_matching("from")?;
+                Angle::parse(input)
+            })
+            .ok();
+
+        let position = input
+            .try_parse(|input| {
+                input.expect_ident_matching("at")?;
+                Position::parse(input)
+            })
+            .ok();
+
+        if from.is_some() || position.is_some() {
+            input.expect_comma()?;
+        }
+
+        let stops = parse_items(input)?;
+        Ok(ConicGradient {
+            from: from.unwrap_or_default(),
+            position: position.unwrap_or(Position::center()),
+            stops,
+            repeating,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ColorStop<D> {
     pub color: Color,
@@ -226,3 +292,48 @@ impl<D> From<(Color, D)> for ColorStop<D> {
         ColorStop { color: value.0, position: Some(value.1) }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+    use crate::{HorizontalPosition, VerticalPosition};
+
+    assert_parse! {
+        Gradient, parse_gradient,
+
+        custom {
+            success {
+                "conic-gradient(red, blue)" => Gradient::Conic(ConicGradient {
+                    stops: vec![Color::rgb(255, 0, 0).into(), Color::rgb(0, 0, 255).into()],
+                    ..Default::default()
+                }),
+                "conic-gradient(from 90deg at left top, red 25%, blue 0.5turn)" => Gradient::Conic(ConicGradient {
+                    from: Angle::Deg(90.0),
+                    position: Position::new(
+                        HorizontalPosition::Side(HorizontalPositionKeyword::Left),
+                        VerticalPosition::Side(VerticalPositionKeyword::Top),
+                    ),
+                    stops: vec![
+                        (Color::rgb(255, 0, 0), AngleOrPercentage::Percentage(25.0)).into(),
+                        (Color::rgb(0, 0, 255), AngleOrPercentage::Angle(Angle::Turn(0.5))).into(),
+                    ],
+                    repeating: false,
+                }),
+                "repeating-conic-gradient(red 0%, blue 10%)" => Gradient::Conic(ConicGradient {
+                    stops: vec![
+                        (Color::rgb(255, 0, 0), AngleOrPercentage::Percentage(0.0)).into(),
+                        (Color::rgb(0, 0, 255), AngleOrPercentage::Percentage(10.0)).into(),
+                    ],
+                    repeating: true,
+                    ..Default::default()
+                }),
+            }
+
+            failure {
+                "conic-gradient(from 90deg red, blue)",
+                "conic-gradient(red 10px, blue)",
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use cssparser::*;
+
+use crate::{CustomParseError, Parse, Units};
+
+// Parses the size of a grid track, which is any units or a fraction of the free space written with `fr`, which is
+// the same as a stretch factor.
+fn parse_track<'i>(
+    input: &mut Parser<'i, '_>,
+) -> Result<Units, ParseError<'i, CustomParseError<'i>>> {
+    if let Ok(fraction) = input.try_parse(|input| {
+        let location = input.current_source_location();
+        match input.next()? {
+            Token::Dimension { value, unit, .. } if unit.eq_ignore_ascii_case("fr") => Ok(*value),
+            token => Err(location.new_unexpected_token_error(token.clone())),
+        }
+    }) {
+        return Ok(Units::Stretch(fraction));
+    }
+
+    Units::parse(input)
+}
+
+/// The sizes of the rows or columns of a grid, separated by whitespace.
+impl<'i> Parse<'i> for Vec<Units> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let mut tracks = vec![parse_track(input)?];
+        while let Ok(track) = input.try_parse(parse_track) {
+            tracks.push(track);
+        }
+
+        Ok(tracks)
+    }
+}
+
+/// The placement of a view within the rows or the columns of a grid.
+///
+/// In CSS the placement is given by grid lines, which are numbered from one, as in `grid-column: 2 / span 3` or
+/// `grid-row: 1 / 3`, and is converted to the index of the first track, which is numbered from zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridPlacement {
+    /// The index of the first row or column the view occupies, or `None` to place it in the next free cell.
+    pub start: Option<usize>,
+    /// The number of rows or columns the view occupies.
+    pub span: usize,
+}
+
+impl Default for GridPlacement {
+    fn default() -> Self {
+        Self { start: None, span: 1 }
+    }
+}
+
+impl GridPlacement {
+    /// Creates a placement starting at the given index which spans the given number of tracks.
+    pub fn new(start: usize, span: usize) -> Self {
+        Self { start: Some(start), span }
+    }
+}
+
+// Parses a grid line, which is numbered from one.
+fn parse_line<'i>(
+    input: &mut Parser<'i, '_>,
+) -> Result<usize, ParseError<'i, CustomParseError<'i>>> {
+    let location = input.current_source_location();
+    match input.expect_integer()? {
+        line if line >= 1 => Ok(line as usize),
+        _ => Err(location.new_custom_error(CustomParseError::InvalidValue)),
+    }
+}
+
+fn parse_span<'i>(
+    input: &mut Parser<'i, '_>,
+) -> Result<usize, ParseError<'i, CustomParseError<'i>>> {
+    input.expect_ident_matching("span")?;
+    parse_line(input)
+}
+
+impl<'i> Parse<'i> for GridPlacement {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if let Ok(span) = input.try_parse(parse_span) {
+            return Ok(GridPlacement { start: None, span });
+        }
+
+        let start = parse_line(input)?;
+        if input.try_parse(|input| input.expect_delim('/')).is_err() {
+            return Ok(GridPlacement::new(start - 1, 1));
+        }
+
+        if let Ok(span) = input.try_parse(parse_span) {
+            return Ok(GridPlacement::new(start - 1, span));
+        }
+
+        // The lines may be given in either order, and a view between the same line occupies a single track.
+        let end = parse_line(input)?;
+        Ok(GridPlacement::new(start.min(end) - 1, start.abs_diff(end).max(1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+    use crate::Units::*;
+
+    assert_parse! {
+        Vec<Units>, parse_grid_tracks,
+
+        success {
+            "100px" => vec![Pixels(100.0)],
+            "1fr 2fr" => vec![Stretch(1.0), Stretch(2.0)],
+            "auto 50% 1s 20px" => vec![Auto, Percentage(50.0), Stretch(1.0), Pixels(20.0)],
+        }
+
+        failure {
+            "",
+            "test",
+        }
+    }
+
+    assert_parse! {
+        GridPlacement, parse_grid_placement,
+
+        success {
+            "1" => GridPlacement::new(0, 1),
+            "2 / span 3" => GridPlacement::new(1, 3),
+            "1 / 3" => GridPlacement::new(0, 2),
+            "3 / 1" => GridPlacement::new(0, 2),
+            "2 / 2" => GridPlacement::new(1, 1),
+            "span 2" => GridPlacement { start: None, span: 2 },
+        }
+
+        failure {
+            "0",
+            "-1",
+            "span 0",
+            "1 /",
+            "auto",
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use cssparser::*;
+
+use crate::{CustomParseError, DimensionPercentage, LengthContext, LengthValue, Parse, Units};
+
+/// A length or percentage which can be mixed within a `calc()` expression, such as `calc(100% - 20px)`.
+pub type LengthPercentage = DimensionPercentage<LengthValue>;
+
+impl LengthPercentage {
+    /// Returns the amount of pixels of the length, resolving percentages, relative lengths and calculations against
+    /// the given context.
+    pub fn resolve(&self, context: &LengthContext) -> f32 {
+        match self {
+            DimensionPercentage::Dimension(length) => length.resolve(context),
+            DimensionPercentage::Percentage(percentage) => {
+                percentage.0 / 100.0 * context.percentage_basis
+            }
+            DimensionPercentage::Calc(calc) => calc.resolve(&|value| value.resolve(context)),
+        }
+    }
+}
+
+/// The value of a property which determines the size, position or padding of a view.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LayoutUnits {
+    /// Units which are resolved by layout.
+    Units(Units),
+    /// A length which is resolved against the size of the parent and the font size of the view when the view is
+    /// laid out, such as `calc(100% - 20px)` or `2em`.
+    Calc(LengthPercentage),
+}
+
+impl Default for LayoutUnits {
+    fn default() -> Self {
+        LayoutUnits::Units(Units::default())
+    }
+}
+
+impl<'i> Parse<'i> for LayoutUnits {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if let Ok(units) = input.try_parse(Units::parse) {
+            return Ok(LayoutUnits::Units(units));
+        }
+
+        LengthPercentage::parse(input).map(LayoutUnits::from)
+    }
+}
+
+impl From<Units> for LayoutUnits {
+    fn from(units: Units) -> Self {
+        LayoutUnits::Units(units)
+    }
+}
+
+// A calculation which reduces to a percentage or an absolute length is resolved by layout like any other units.
+impl From<LengthPercentage> for LayoutUnits {
+    fn from(length: LengthPercentage) -> Self {
+        match length {
+            DimensionPercentage::Percentage(percentage) => {
+                LayoutUnits::Units(Units::Percentage(percentage.0))
+            }
+            DimensionPercentage::Dimension(length) if length.to_px().is_some() => {
+                LayoutUnits::Units(Units::Pixels(length.to_px().unwrap_or_default()))
+            }
+            length => LayoutUnits::Calc(length),
+        }
+    }
+}
+
+impl ToCss for LayoutUnits {
+    fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+    where
+        W: std::fmt::Write,
+    {
+        match self {
+            LayoutUnits::Units(Units::Pixels(pixels)) => write!(dest, "{}px", pixels),
+            LayoutUnits::Units(Units::Percentage(percentage)) => write!(dest, "{}%", percentage),
+            LayoutUnits::Units(Units::Stretch(factor)) => write!(dest, "{}s", factor),
+            LayoutUnits::Units(Units::Auto) => dest.write_str("auto"),
+            LayoutUnits::Calc(length) => length.to_css(dest),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+    use crate::{Calc, MathFunction, Percentage};
+
+    fn px(value: f32) -> LengthPercentage {
+        DimensionPercentage::Dimension(LengthValue::Px(value))
+    }
+
+    fn em(value: f32) -> LengthPercentage {
+        DimensionPercentage::Dimension(LengthValue::Em(value))
+    }
+
+    fn percent(value: f32) -> LengthPercentage {
+        DimensionPercentage::Percentage(Percentage(value))
+    }
+
+    fn calc(a: LengthPercentage, b: LengthPercentage) -> LayoutUnits {
+        LayoutUnits::Calc(DimensionPercentage::Calc(Box::new(Calc::Function(Box::new(
+            MathFunction::Calc(Calc::Sum(
+                Box::new(Calc::Value(Box::new(a))),
+                Box::new(Calc::Value(Box::new(b))),
+            )),
+        )))))
+    }
+
+    // Parses and serializes a value, checking that the serialized value is serialized in the same way once parsed.
+    fn round_trip(css: &str) -> String {
+        let serialize = |css: &str| {
+            let mut input = ParserInput::new(css);
+            let mut parser = Parser::new(&mut input);
+            LayoutUnits::parse(&mut parser).unwrap().to_css_string()
+        };
+
+        let serialized = serialize(css);
+        assert_eq!(serialize(&serialized), serialized, "{}", css);
+        serialized
+    }
+
+    assert_parse! {
+        LayoutUnits, parse_layout_units,
+
+        success {
+            "auto" => LayoutUnits::Units(Units::Auto),
+            "20px" => LayoutUnits::Units(Units::Pixels(20.0)),
+            "50%" => LayoutUnits::Units(Units::Percentage(50.0)),
+            "1s" => LayoutUnits::Units(Units::Stretch(1.0)),
+            "2em" => LayoutUnits::Calc(em(2.0)),
+            "calc(10px * 2)" => LayoutUnits::Units(Units::Pixels(20.0)),
+            "calc(25% + 25%)" => LayoutUnits::Units(Units::Percentage(50.0)),
+            "calc(100% - 20px)" => calc(percent(100.0), px(-20.0)),
+            "calc((100% - 20px) / 2)" => calc(percent(50.0), px(-10.0)),
+            "calc(50% + 2 * 1em)" => calc(percent(50.0), em(2.0)),
+        }
+
+        failure {
+            "calc(100% * 20px)",
+            "calc(100% / 0)",
+            "red",
+        }
+    }
+
+    #[test]
+    fn serialize_round_trips() {
+        assert_eq!(round_trip("calc(100% - 20px)"), "calc(100% - 20px)");
+        assert_eq!(round_trip("calc(50% + calc(1em - 4px))"), "calc(50% + 1em - 4px)");
+        assert_eq!(round_trip("calc(100% - (10px + 2em) * 2)"), "calc(100% - 20px - 4em)");
+        assert_eq!(round_trip("min(100%, 200px)"), "min(100%, 200px)");
+        assert_eq!(round_trip("clamp(10px, 50% - 1em, 300px)"), "clamp(10px, 50% - 1em, 300px)");
+        assert_eq!(
+            round_trip("calc(100% - 2 * max(1em, 10px))"),
+            "calc(100% - 2 * max(1em, 10px))"
+        );
+        assert_eq!(round_trip("2em"), "2em");
+        assert_eq!(round_trip("50%"), "50%");
+    }
+
+    #[test]
+    fn resolve_calculations() {
+        let context =
+            LengthContext { percentage_basis: 200.0, font_size: 10.0, ..Default::default() };
+        let resolve = |css: &str| {
+            let mut input = ParserInput::new(css);
+            let mut parser = Parser::new(&mut input);
+            LengthPercentage::parse(&mut parser).unwrap().resolve(&context)
+        };
+
+        assert_eq!(resolve("calc(100% - 20px)"), 180.0);
+        assert_eq!(resolve("calc((100% - 20px) / 2 + 1em)"), 100.0);
+        assert_eq!(resolve("calc(50% * 3 - 2em * 2)"), 260.0);
+        assert_eq!(resolve("min(100%, 150px)"), 150.0);
+        assert_eq!(resolve("max(10%, 2em)"), 20.0);
+        assert_eq!(resolve("clamp(50px, 10%, 100px)"), 50.0);
+    }
+
+    #[test]
+    fn resolve_nested_math_functions() {
+        let resolve = |css: &str, parent_size: f32| {
+            let context = LengthContext {
+                percentage_basis: parent_size,
+                font_size: 10.0,
+                ..Default::default()
+            };
+            let mut input = ParserInput::new(css);
+            let mut parser = Parser::new(&mut input);
+            LengthPercentage::parse(&mut parser).unwrap().resolve(&context)
+        };
+
+        assert_eq!(resolve("clamp(200px, 50%, 600px)", 300.0), 200.0);
+        assert_eq!(resolve("clamp(200px, 50%, 600px)", 800.0), 400.0);
+        assert_eq!(resolve("clamp(200px, 50%, 600px)", 2000.0), 600.0);
+
+        let nested = "clamp(20px, min(50%, 40em), max(300px, 10% + 2em))";
+        assert_eq!(resolve(nested, 100.0), 50.0);
+        assert_eq!(resolve(nested, 1000.0), 300.0);
+        assert_eq!(resolve(nested, 4000.0), 420.0);
+        assert_eq!(resolve("calc(100% - 2 * max(1em, min(5%, 30px)))", 1000.0), 940.0);
+    }
+}
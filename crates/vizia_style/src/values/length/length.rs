@@ -1,10 +1,12 @@
+use std::cell::Cell;
+
 use morphorm::Units;
 
 use crate::{
     calc::Calc,
     impl_parse,
     traits::{Parse, TryAdd},
-    LengthValue,
+    LengthContext, LengthValue,
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -67,10 +69,31 @@ impl Length {
         Length::Value(LengthValue::Px(px))
     }
 
+    /// Returns the amount of pixels of an absolute length, including calculations of absolute lengths such as
+    /// `max(4px, 2pt)`, or `None` if the length is relative.
     pub fn to_px(&self) -> Option<f32> {
         match self {
             Length::Value(a) => a.to_px(),
-            _ => None,
+            Length::Calc(calc) => {
+                let absolute = Cell::new(true);
+                let px = calc.resolve(&|length| {
+                    length.to_px().unwrap_or_else(|| {
+                        absolute.set(false);
+                        0.0
+                    })
+                });
+
+                absolute.get().then_some(px)
+            }
+        }
+    }
+
+    /// Returns the amount of pixels of the length, resolving relative lengths and calculations against the given
+    /// context.
+    pub fn resolve(&self, context: &LengthContext) -> f32 {
+        match self {
+            Length::Value(value) => value.resolve(context),
+            Length::Calc(calc) => calc.resolve(&|length| length.resolve(context)),
         }
     }
 
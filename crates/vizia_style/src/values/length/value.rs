@@ -1,3 +1,5 @@
+use cssparser::ToCss;
+
 use crate::{impl_parse, Parse, TryAdd};
 
 /// A length value.
@@ -152,6 +154,48 @@ impl LengthValue {
             Vmax(value) => (*value, "vmax"),
         }
     }
+
+    /// Returns the amount of pixels of the length, resolving lengths relative to the font size or the viewport
+    /// against the given context.
+    pub fn resolve(&self, context: &LengthContext) -> f32 {
+        use LengthValue::*;
+        match self {
+            Em(value) => value * context.font_size,
+            // The height of an `x` and the width of a `0` are approximated as half of the font size.
+            Ex(value) | Ch(value) => value * context.font_size * 0.5,
+            Rem(value) => value * context.root_font_size,
+            Vw(value) => value * context.viewport_width / 100.0,
+            Vh(value) => value * context.viewport_height / 100.0,
+            Vmin(value) => value * context.viewport_width.min(context.viewport_height) / 100.0,
+            Vmax(value) => value * context.viewport_width.max(context.viewport_height) / 100.0,
+            length => length.to_px().unwrap_or_default(),
+        }
+    }
+}
+
+impl ToCss for LengthValue {
+    fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+    where
+        W: std::fmt::Write,
+    {
+        let (value, unit) = self.to_unit_value();
+        write!(dest, "{}{}", value, unit)
+    }
+}
+
+/// The sizes which relative lengths are resolved against, in pixels.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct LengthContext {
+    /// The size which percentages are relative to.
+    pub percentage_basis: f32,
+    /// The font size of the element, which `em`, `ex` and `ch` lengths are relative to.
+    pub font_size: f32,
+    /// The font size of the root element, which `rem` lengths are relative to.
+    pub root_font_size: f32,
+    /// The width of the viewport.
+    pub viewport_width: f32,
+    /// The height of the viewport.
+    pub viewport_height: f32,
 }
 
 impl TryAdd<LengthValue> for LengthValue {
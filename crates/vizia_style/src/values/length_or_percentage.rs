@@ -1,4 +1,7 @@
-use crate::{macros::impl_parse, Length, LengthValue, Parse, Percentage};
+use crate::{
+    CustomParseError, DimensionPercentage, Length, LengthContext, LengthPercentage, LengthValue,
+    Parse, Percentage,
+};
 use cssparser::*;
 use morphorm::Units;
 
@@ -7,6 +10,8 @@ use morphorm::Units;
 pub enum LengthOrPercentage {
     Length(Length),
     Percentage(f32),
+    /// A calculation which mixes lengths and percentages, such as `clamp(4px, 10%, 1em)`.
+    Calc(LengthPercentage),
 }
 
 impl Default for LengthOrPercentage {
@@ -20,27 +25,13 @@ impl LengthOrPercentage {
     // > dpi, font_size, size of 0 char, viewport size, min of bounds
     pub fn to_pixels(&self, min_bounds: f32, scale: f32) -> f32 {
         match self {
-            LengthOrPercentage::Length(length) => {
-                match length {
-                    Length::Value(val) => {
-                        if let LengthValue::Px(pixels) = val {
-                            return *pixels * scale;
-                        }
-                    }
-
-                    // TODO
-                    Length::Calc(_l) => {
-                        todo!();
-                    }
-                }
-            }
+            // TODO - Resolve font and viewport relative lengths.
+            LengthOrPercentage::Length(length) => length.resolve(&LengthContext::default()) * scale,
 
-            LengthOrPercentage::Percentage(val) => {
-                return (val / 100.0) * min_bounds;
-            }
-        }
+            LengthOrPercentage::Percentage(val) => (val / 100.0) * min_bounds,
 
-        0.0
+            LengthOrPercentage::Calc(length) => resolve_scaled(length, min_bounds, scale),
+        }
     }
 
     pub fn px(val: f32) -> Self {
@@ -48,12 +39,37 @@ impl LengthOrPercentage {
     }
 }
 
-impl_parse! {
-    LengthOrPercentage,
+// Resolves the lengths of a calculation in pixels multiplied by the scale, with percentages of the bounds.
+fn resolve_scaled(length: &LengthPercentage, min_bounds: f32, scale: f32) -> f32 {
+    match length {
+        DimensionPercentage::Dimension(length) => length.resolve(&LengthContext::default()) * scale,
+        DimensionPercentage::Percentage(percentage) => percentage.0 / 100.0 * min_bounds,
+        DimensionPercentage::Calc(calc) => {
+            calc.resolve(&|value| resolve_scaled(value, min_bounds, scale))
+        }
+    }
+}
+
+impl<'i> Parse<'i> for LengthOrPercentage {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if let Ok(length) = input.try_parse(Length::parse) {
+            return Ok(LengthOrPercentage::Length(length));
+        }
+
+        if let Ok(percentage) = input.try_parse(Percentage::parse) {
+            return Ok(LengthOrPercentage::Percentage(percentage.0));
+        }
 
-    try_parse {
-        Length,
-        Percentage,
+        // A calculation which can't be reduced to a length or a percentage, such as `calc(50% - 4px)`.
+        Ok(match LengthPercentage::parse(input)? {
+            DimensionPercentage::Dimension(value) => {
+                LengthOrPercentage::Length(Length::Value(value))
+            }
+            DimensionPercentage::Percentage(percentage) => {
+                LengthOrPercentage::Percentage(percentage.0)
+            }
+            length => LengthOrPercentage::Calc(length),
+        })
     }
 }
 
@@ -109,4 +125,30 @@ mod tests {
             LengthOrPercentage::Length,
         }
     }
+
+    fn parse(css: &str) -> LengthOrPercentage {
+        let mut input = ParserInput::new(css);
+        let mut parser = Parser::new(&mut input);
+        LengthOrPercentage::parse(&mut parser).unwrap()
+    }
+
+    #[test]
+    fn parse_math_functions() {
+        assert_eq!(parse("min(10px, 20px)").to_pixels(100.0, 1.0), 10.0);
+        assert_eq!(parse("calc(50% + 10%)"), LengthOrPercentage::Percentage(60.0));
+        assert!(matches!(parse("clamp(4px, 10%, 20px)"), LengthOrPercentage::Calc(_)));
+        assert!(matches!(parse("max(min(50%, 40px), 1em)"), LengthOrPercentage::Calc(_)));
+    }
+
+    #[test]
+    fn resolve_math_functions() {
+        let length = parse("clamp(4px, 10%, 20px)");
+        assert_eq!(length.to_pixels(20.0, 1.0), 4.0);
+        assert_eq!(length.to_pixels(100.0, 1.0), 10.0);
+        assert_eq!(length.to_pixels(400.0, 1.0), 20.0);
+
+        // Lengths are scaled while percentages are of the scaled bounds.
+        assert_eq!(length.to_pixels(400.0, 2.0), 40.0);
+        assert_eq!(parse("max(min(50%, 40px), 4px)").to_pixels(50.0, 2.0), 25.0);
+    }
 }
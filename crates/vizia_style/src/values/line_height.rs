@@ -0,0 +1,107 @@
+use crate::{impl_parse, Length, Parse, Percentage};
+
+/// The height of each line of text.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum LineHeight {
+    /// The line height given by the metrics of the font.
+    #[default]
+    Normal,
+    /// A multiple of the font size.
+    Number(f32),
+    /// A fixed line height.
+    Length(Length),
+    /// A percentage of the font size.
+    Percentage(f32),
+}
+
+impl LineHeight {
+    /// Returns the line height as a multiple of the font size, or `None` if the line height is given by the font.
+    ///
+    /// A line height smaller than the font size gives a multiple less than one, which causes lines to overlap.
+    pub fn to_factor(&self, font_size: f32) -> Option<f32> {
+        match self {
+            LineHeight::Normal => None,
+            LineHeight::Number(number) => Some(*number),
+            LineHeight::Length(length) => {
+                length.to_px().filter(|_| font_size > 0.0).map(|px| px / font_size)
+            }
+            LineHeight::Percentage(percentage) => Some(percentage / 100.0),
+        }
+    }
+}
+
+impl_parse! {
+    LineHeight,
+
+    custom {
+        |input| {
+            if input.try_parse(|i| i.expect_ident_matching("normal")).is_ok() {
+                return Ok(LineHeight::Normal);
+            }
+
+            if let Ok(percentage) = input.try_parse(Percentage::parse) {
+                return Ok(LineHeight::Percentage(percentage.0));
+            }
+
+            if let Ok(number) = input.try_parse(f32::parse) {
+                return Ok(LineHeight::Number(number));
+            }
+
+            Length::parse(input).map(LineHeight::Length)
+        }
+    }
+}
+
+impl From<f32> for LineHeight {
+    fn from(number: f32) -> Self {
+        LineHeight::Number(number)
+    }
+}
+
+impl From<Length> for LineHeight {
+    fn from(length: Length) -> Self {
+        LineHeight::Length(length)
+    }
+}
+
+impl From<Percentage> for LineHeight {
+    fn from(percentage: Percentage) -> Self {
+        LineHeight::Percentage(percentage.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        LineHeight, parse_line_height,
+
+        custom {
+            success {
+                "normal" => LineHeight::Normal,
+                "1.5" => LineHeight::Number(1.5),
+                "150%" => LineHeight::Percentage(150.0),
+                "24px" => LineHeight::Length(Length::px(24.0)),
+            }
+            failure {
+                "tall",
+            }
+        }
+    }
+
+    #[test]
+    fn line_height_is_a_multiple_of_the_font_size() {
+        assert_eq!(LineHeight::Normal.to_factor(16.0), None);
+        assert_eq!(LineHeight::Number(1.5).to_factor(16.0), Some(1.5));
+        assert_eq!(LineHeight::Percentage(150.0).to_factor(16.0), Some(1.5));
+        assert_eq!(LineHeight::Length(Length::px(24.0)).to_factor(16.0), Some(1.5));
+    }
+
+    #[test]
+    fn line_height_smaller_than_the_font_size_is_not_clamped() {
+        assert_eq!(LineHeight::Length(Length::px(8.0)).to_factor(16.0), Some(0.5));
+        assert_eq!(LineHeight::Number(0.8).to_factor(16.0), Some(0.8));
+    }
+}
@@ -1,7 +1,12 @@
 pub mod alignment;
 pub mod alpha;
 pub mod angle;
-pub mod backdrop_filter;
+pub mod angle_or_percentage;
+pub mod animation;
+pub mod animation_direction;
+pub mod animation_fill_mode;
+pub mod aspect_ratio;
+pub mod background;
 pub mod background_size;
 pub mod basic;
 pub mod blend_mode;
@@ -9,6 +14,9 @@ pub mod border;
 pub mod calc;
 pub mod clip;
 pub mod color;
+pub mod color_or_gradient;
+pub mod color_space;
+pub mod css_wide_keyword;
 pub mod cursor_icon;
 pub mod custom;
 pub mod dimension_percentage;
@@ -16,7 +24,10 @@ pub mod direction;
 pub mod display;
 pub mod duration;
 pub mod easing;
+pub mod elevation;
+pub mod filter;
 pub mod font_family;
+pub mod font_feature_settings;
 pub mod font_size;
 pub mod font_size_keyword;
 pub mod font_slant;
@@ -24,14 +35,18 @@ pub mod font_variation_settings;
 pub mod font_weight;
 pub mod font_weight_keyword;
 pub mod font_width;
+pub mod gap;
 pub mod gradient;
+pub mod grid;
 pub mod horizontal_position_keyword;
 pub mod image;
 pub mod keywords;
 pub mod layout_type;
+pub mod layout_units;
 pub mod length;
 pub mod length_or_percentage;
 pub mod length_percentage_auto;
+pub mod line_height;
 pub mod matrix;
 pub mod number_or_percentage;
 pub mod opacity;
@@ -39,28 +54,41 @@ pub mod outline;
 pub mod overflow;
 pub mod pointer_events;
 pub mod position;
+pub mod press_effect;
 pub mod position_type;
 pub mod rect;
 pub mod rotate;
 pub mod scale;
 pub mod shadow;
 pub mod stretch;
+pub mod stroke_linecap;
+pub mod stroke_linejoin;
 pub mod text_align;
 pub mod text_decoration;
 pub mod text_overflow;
+pub mod text_rendering;
+pub mod text_spacing;
 pub mod text_stroke;
+pub mod text_transform;
 pub mod transform;
 pub mod transition;
 pub mod translate;
 pub mod units;
 pub mod url;
+pub mod vertical_align;
 pub mod vertical_position_keyword;
 pub mod visibility;
+pub mod white_space;
 
 pub use alignment::*;
 pub use alpha::*;
 pub use angle::*;
-pub use backdrop_filter::*;
+pub use angle_or_percentage::*;
+pub use animation::*;
+pub use animation_direction::*;
+pub use animation_fill_mode::*;
+pub use aspect_ratio::*;
+pub use background::*;
 pub use background_size::*;
 pub use basic::*;
 pub use blend_mode::*;
@@ -68,6 +96,9 @@ pub use border::*;
 pub use calc::*;
 pub use clip::*;
 pub use color::*;
+pub use color_or_gradient::*;
+pub use color_space::*;
+pub use css_wide_keyword::*;
 pub use cursor_icon::*;
 pub use custom::*;
 pub use dimension_percentage::*;
@@ -75,7 +106,10 @@ pub use direction::*;
 pub use display::*;
 pub use duration::*;
 pub use easing::*;
+pub use elevation::*;
+pub use filter::*;
 pub use font_family::*;
+pub use font_feature_settings::*;
 pub use font_size::*;
 pub use font_size_keyword::*;
 pub use font_slant::*;
@@ -83,14 +117,18 @@ pub use font_variation_settings::*;
 pub use font_weight::*;
 pub use font_weight_keyword::*;
 pub use font_width::*;
+pub use gap::*;
+pub use grid::*;
 pub use gradient::*;
 pub use horizontal_position_keyword::*;
 pub use image::*;
 pub use keywords::*;
 pub use layout_type::*;
+pub use layout_units::*;
 pub use length::*;
 pub use length_or_percentage::*;
 pub use length_percentage_auto::*;
+pub use line_height::*;
 pub use matrix::*;
 pub use number_or_percentage::*;
 pub use opacity::*;
@@ -98,20 +136,28 @@ pub use outline::*;
 pub use overflow::*;
 pub use pointer_events::*;
 pub use position::*;
+pub use press_effect::*;
 pub use position_type::*;
 pub use rect::*;
 pub use rotate::*;
 pub use scale::*;
 pub use shadow::*;
 pub use stretch::*;
+pub use stroke_linecap::*;
+pub use stroke_linejoin::*;
 pub use text_align::*;
 pub use text_decoration::*;
 pub use text_overflow::*;
+pub use text_rendering::*;
+pub use text_spacing::*;
 pub use text_stroke::*;
+pub use text_transform::*;
 pub use transform::*;
 pub use transition::*;
 pub use translate::*;
 pub use units::*;
 pub use url::*;
+pub use vertical_align::*;
 pub use vertical_position_keyword::*;
 pub use visibility::*;
+pub use white_space::*;
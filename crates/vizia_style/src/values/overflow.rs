@@ -11,9 +11,33 @@ define_enum! {
         "hidden": Hidden,
         // /// The overflow is clipped and the content can't be scrolled at all.
         // "clip": Clip,
-        // /// The overflow is clipped and scrollbars appears to view the rest of the content.
-        // "scroll": Scroll,
-        // /// The overflow is clipped and a scrollbar is automatically added on the axis where the overflow happens.
-        // "auto": Auto,
+        /// The overflow is clipped and a scrollbar is always shown to scroll to the rest of the content.
+        "scroll": Scroll,
+        /// The overflow is clipped and a scrollbar is shown when the content overflows, to scroll to the rest of it.
+        "auto": Auto,
+    }
+}
+
+impl Overflow {
+    /// Returns true if the content can be scrolled with the mouse wheel and a scrollbar.
+    pub fn is_scrollable(&self) -> bool {
+        matches!(self, Overflow::Scroll | Overflow::Auto)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        Overflow, assert_overflow,
+
+        ident {
+            "visible" => Overflow::Visible,
+            "hidden" => Overflow::Hidden,
+            "scroll" => Overflow::Scroll,
+            "auto" => Overflow::Auto,
+        }
     }
 }
@@ -60,24 +60,27 @@ impl<'i> Parse<'i> for Position {
 
             // If it didn't work, assume the first actually represents a y position,
             // and the next is an x position. e.g. `center left` rather than `left center`.
-            let x =
-                input.try_parse(HorizontalPosition::parse).unwrap_or(HorizontalPosition::Center);
+            // A single value is centered vertically.
+            let x = input.try_parse(HorizontalPosition::parse).unwrap_or(x);
             let y: PositionComponent<VerticalPositionKeyword> = VerticalPosition::Center;
             Ok(Position { x, y })
         } else if let Ok(y) = input.try_parse(VerticalPosition::parse) {
-            // Try parsing a horizontal position next.
-            if let Ok(x) = input.try_parse(HorizontalPosition::parse) {
-                return Ok(Position { x, y });
-            } else {
-                return Ok(Position { x: HorizontalPosition::Center, y: VerticalPosition::Center });
-            }
+            // Try parsing a horizontal position next, or center horizontally if there isn't one.
+            let x =
+                input.try_parse(HorizontalPosition::parse).unwrap_or(HorizontalPosition::Center);
+            Ok(Position { x, y })
         } else {
-            // Return default or return an error?
-            return Ok(Position { x: HorizontalPosition::Center, y: VerticalPosition::Center });
+            Err(input.new_error_for_next_token())
         }
     }
 }
 
+impl<'i> Parse<'i> for Vec<Position> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        input.parse_comma_separated(Position::parse)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum PositionComponent<S: Copy + Into<LengthOrPercentage>> {
     /// `center`
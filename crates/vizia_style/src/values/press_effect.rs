@@ -0,0 +1,23 @@
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines the feedback drawn over a view while it's pressed, in addition to its `:active` style.
+    #[derive(Default)]
+    pub enum PressEffect {
+        /// No feedback is drawn.
+        #[default]
+        "none": None,
+        /// A circle expands from where the view was pressed, and fades out once it's released.
+        "ripple": Ripple,
+    }
+}
+
+impl From<bool> for PressEffect {
+    fn from(boolean: bool) -> Self {
+        if boolean {
+            PressEffect::Ripple
+        } else {
+            PressEffect::None
+        }
+    }
+}
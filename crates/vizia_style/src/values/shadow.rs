@@ -41,12 +41,46 @@ impl Shadow {
 
 impl<'i> Parse<'i> for Shadow {
     fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
-        let x_offset = Length::parse(input)?;
-        let y_offset = Length::parse(input)?;
-        let blur_radius = input.try_parse(Length::parse).ok();
-        let spread_radius = input.try_parse(Length::parse).ok();
-        let color = input.try_parse(Color::parse).ok();
-        let inset = input.try_parse(InsetKeyword::parse).map(|_| true).unwrap_or(false);
+        // The offsets and radii, the color and the `inset` keyword can be given in any order.
+        let mut lengths = None;
+        let mut color = None;
+        let mut inset = false;
+        loop {
+            if !inset && input.try_parse(InsetKeyword::parse).is_ok() {
+                inset = true;
+                continue;
+            }
+
+            if lengths.is_none() {
+                if let Ok(value) = input.try_parse(|input| {
+                    let x_offset = Length::parse(input)?;
+                    let y_offset = Length::parse(input)?;
+                    let blur_radius = input.try_parse(Length::parse).ok();
+                    let spread_radius = input.try_parse(Length::parse).ok();
+                    if blur_radius.as_ref().and_then(Length::to_px).is_some_and(|px| px < 0.0) {
+                        return Err(input.new_custom_error(CustomParseError::InvalidValue));
+                    }
+
+                    Ok((x_offset, y_offset, blur_radius, spread_radius))
+                }) {
+                    lengths = Some(value);
+                    continue;
+                }
+            }
+
+            if color.is_none() {
+                if let Ok(value) = input.try_parse(Color::parse) {
+                    color = Some(value);
+                    continue;
+                }
+            }
+
+            break;
+        }
+
+        let Some((x_offset, y_offset, blur_radius, spread_radius)) = lengths else {
+            return Err(input.new_custom_error(CustomParseError::InvalidDeclaration));
+        };
 
         Ok(Shadow::new(x_offset, y_offset, blur_radius, spread_radius, color, inset))
     }
@@ -58,6 +92,12 @@ impl<'i> Parse<'i> for Vec<Shadow> {
     }
 }
 
+impl From<Shadow> for Vec<Shadow> {
+    fn from(shadow: Shadow) -> Self {
+        vec![shadow]
+    }
+}
+
 impl From<&str> for Shadow {
     fn from(s: &str) -> Self {
         let mut input = ParserInput::new(s);
@@ -92,11 +132,30 @@ mod tests {
                     Some(Color::rgb(255, 0, 0)),
                     true,
                 ),
+                "inset red 10px 20px 30px" => Shadow::new(
+                    Length::px(10.0),
+                    Length::px(20.0),
+                    Some(Length::px(30.0)),
+                    None,
+                    Some(Color::rgb(255, 0, 0)),
+                    true,
+                ),
+                "red 10px 20px inset" => Shadow::new(
+                    Length::px(10.0),
+                    Length::px(20.0),
+                    None,
+                    None,
+                    Some(Color::rgb(255, 0, 0)),
+                    true,
+                ),
             }
 
             failure {
                 "test",
                 "123",
+                "10px",
+                "inset",
+                "10px 20px -5px",
             }
         }
     }
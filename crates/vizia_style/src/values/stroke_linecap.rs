@@ -0,0 +1,27 @@
+use skia_safe::paint::Cap;
+
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines the shape drawn at the ends of open subpaths when they are stroked.
+    #[derive(Default)]
+    pub enum StrokeLinecap {
+        /// The stroke ends flat at the end point.
+        #[default]
+        "butt": Butt,
+        /// The stroke ends with a semicircle around the end point.
+        "round": Round,
+        /// The stroke ends with a square extending past the end point by half the stroke width.
+        "square": Square,
+    }
+}
+
+impl From<StrokeLinecap> for Cap {
+    fn from(value: StrokeLinecap) -> Self {
+        match value {
+            StrokeLinecap::Butt => Cap::Butt,
+            StrokeLinecap::Round => Cap::Round,
+            StrokeLinecap::Square => Cap::Square,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use skia_safe::paint::Join;
+
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines the shape drawn at the corners of paths when they are stroked.
+    #[derive(Default)]
+    pub enum StrokeLinejoin {
+        /// The outer edges of the stroke are extended to meet at a sharp corner.
+        #[default]
+        "miter": Miter,
+        /// The corner is rounded off with a circular arc.
+        "round": Round,
+        /// The corner is cut off with a straight line.
+        "bevel": Bevel,
+    }
+}
+
+impl From<StrokeLinejoin> for Join {
+    fn from(value: StrokeLinejoin) -> Self {
+        match value {
+            StrokeLinejoin::Miter => Join::Miter,
+            StrokeLinejoin::Round => Join::Round,
+            StrokeLinejoin::Bevel => Join::Bevel,
+        }
+    }
+}
@@ -1,16 +1,27 @@
-use crate::{define_enum, CustomParseError, LengthOrPercentage, Parse};
+use crate::{define_enum, Color, CustomParseError, LengthOrPercentage, Parse};
 use bitflags::bitflags;
 use cssparser::*;
-use cssparser_color::Color;
 
-#[derive(Debug, Clone, PartialEq)]
+/// The lines drawn on text, such as an underline, and how they are drawn.
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct TextDecoration {
+    /// The lines drawn on the text.
     pub line: TextDecorationLine,
+    /// The style of the lines.
     pub style: TextDecorationStyle,
+    /// The thickness of the lines, where a percentage is of the font size. A thickness of zero uses the thickness
+    /// given by the font.
     pub thickness: LengthOrPercentage,
+    /// The color of the lines. Defaults to the color of the text.
     pub color: Color,
 }
 
+impl From<TextDecorationLine> for TextDecoration {
+    fn from(line: TextDecorationLine) -> Self {
+        TextDecoration { line, ..Default::default() }
+    }
+}
+
 impl<'i> Parse<'i> for TextDecoration {
     fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
         let mut line = None;
@@ -50,6 +61,10 @@ impl<'i> Parse<'i> for TextDecoration {
             break;
         }
 
+        if line.is_none() && style.is_none() && thickness.is_none() && color.is_none() {
+            return Err(input.new_custom_error(CustomParseError::InvalidDeclaration));
+        }
+
         Ok(TextDecoration {
             line: line.unwrap_or_default(),
             style: style.unwrap_or_default(),
@@ -162,3 +177,34 @@ impl From<TextDecorationStyle> for skia_safe::textlayout::TextDecorationStyle {
         }
     }
 }
+
+#[cfg(test)]
+mod tests_text_decoration {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        TextDecoration, text_decoration,
+
+        success {
+            "underline" => TextDecoration::from(TextDecorationLine::Underline),
+            "line-through dashed" => TextDecoration {
+                line: TextDecorationLine::Strikethrough,
+                style: TextDecorationStyle::Dashed,
+                ..Default::default()
+            },
+            "underline wavy 2px red" => TextDecoration {
+                line: TextDecorationLine::Underline,
+                style: TextDecorationStyle::Wavy,
+                thickness: LengthOrPercentage::px(2.0),
+                color: Color::rgb(255, 0, 0),
+            },
+        }
+
+        failure {
+            "",
+            "invalid",
+            "/",
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use cssparser::*;
+
+use crate::{CustomParseError, Length, Parse};
+
+/// Determines whether text too small to be read is painted with its glyphs or with a cheap approximation.
+///
+/// Only the painting of the text changes, so the text is laid out the same either way.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum TextRendering {
+    /// The text is painted with its glyphs.
+    #[default]
+    Auto,
+    /// Text with a font size, after transforms are applied, of less than the given number of logical pixels is
+    /// painted as a bar along each line, at a reduced opacity, rather than with its glyphs.
+    GreekBelow(f32),
+    /// The text is always painted with its glyphs, even within a view which greeks small text.
+    NeverGreek,
+}
+
+impl TextRendering {
+    /// Returns true if text with the given font size, after transforms are applied, should be greeked.
+    pub fn should_greek(&self, font_size: f32) -> bool {
+        match self {
+            TextRendering::GreekBelow(threshold) => font_size < *threshold,
+            TextRendering::Auto | TextRendering::NeverGreek => false,
+        }
+    }
+}
+
+impl<'i> Parse<'i> for TextRendering {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let location = input.current_source_location();
+        if let Ok(ident) = input.try_parse(|input| input.expect_ident_cloned()) {
+            return match_ignore_ascii_case! { &ident,
+                "auto" => Ok(TextRendering::Auto),
+                "never-greek" => Ok(TextRendering::NeverGreek),
+                _ => Err(location.new_unexpected_token_error(Token::Ident(ident.clone()))),
+            };
+        }
+
+        input.expect_function_matching("greek-below")?;
+        input.parse_nested_block(|input| match Length::parse(input)?.to_px() {
+            Some(threshold) if threshold > 0.0 => Ok(TextRendering::GreekBelow(threshold)),
+            _ => Err(input.new_custom_error(CustomParseError::InvalidValue)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        TextRendering, assert_text_rendering,
+
+        custom {
+            success {
+                "auto" => TextRendering::Auto,
+                "never-greek" => TextRendering::NeverGreek,
+                "NEVER-GREEK" => TextRendering::NeverGreek,
+                "greek-below(6px)" => TextRendering::GreekBelow(6.0),
+                "greek-below(0.5px)" => TextRendering::GreekBelow(0.5),
+            }
+
+            failure {
+                "greek",
+                "greek-below(6em)",
+                "greek-below(-1px)",
+                "greek-below(0px)",
+                "greek-below()",
+                "greek-above(6px)",
+            }
+        }
+    }
+
+    #[test]
+    fn greeks_text_below_the_threshold() {
+        assert!(TextRendering::GreekBelow(6.0).should_greek(5.9));
+        assert!(!TextRendering::GreekBelow(6.0).should_greek(6.0));
+        assert!(!TextRendering::Auto.should_greek(1.0));
+        assert!(!TextRendering::NeverGreek.should_greek(1.0));
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{impl_parse, Length, Parse};
+
+/// The additional spacing between the letters of text.
+///
+/// Parsed from `normal`, which adds no spacing, or from a length, which may be negative to bring letters closer
+/// together.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LetterSpacing(pub Length);
+
+/// The additional spacing between the words of text.
+///
+/// Parsed from `normal`, which adds no spacing, or from a length.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordSpacing(pub Length);
+
+impl_parse! {
+    LetterSpacing,
+
+    custom {
+        |input| {
+            if input.try_parse(|i| i.expect_ident_matching("normal")).is_ok() {
+                return Ok(LetterSpacing::default());
+            }
+
+            Length::parse(input).map(LetterSpacing)
+        }
+    }
+}
+
+impl_parse! {
+    WordSpacing,
+
+    custom {
+        |input| {
+            if input.try_parse(|i| i.expect_ident_matching("normal")).is_ok() {
+                return Ok(WordSpacing::default());
+            }
+
+            Length::parse(input).map(WordSpacing)
+        }
+    }
+}
+
+impl Default for LetterSpacing {
+    fn default() -> Self {
+        LetterSpacing(Length::zero())
+    }
+}
+
+impl Default for WordSpacing {
+    fn default() -> Self {
+        WordSpacing(Length::zero())
+    }
+}
+
+impl From<Length> for LetterSpacing {
+    fn from(length: Length) -> Self {
+        LetterSpacing(length)
+    }
+}
+
+impl From<f32> for LetterSpacing {
+    fn from(px: f32) -> Self {
+        LetterSpacing(Length::px(px))
+    }
+}
+
+impl From<Length> for WordSpacing {
+    fn from(length: Length) -> Self {
+        WordSpacing(length)
+    }
+}
+
+impl From<f32> for WordSpacing {
+    fn from(px: f32) -> Self {
+        WordSpacing(Length::px(px))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        LetterSpacing, parse_letter_spacing,
+
+        custom {
+            success {
+                "normal" => LetterSpacing(Length::zero()),
+                "2px" => LetterSpacing(Length::px(2.0)),
+                "-0.5px" => LetterSpacing(Length::px(-0.5)),
+            }
+            failure {
+                "wide",
+            }
+        }
+    }
+
+    assert_parse! {
+        WordSpacing, parse_word_spacing,
+
+        custom {
+            success {
+                "normal" => WordSpacing(Length::zero()),
+                "4px" => WordSpacing(Length::px(4.0)),
+            }
+            failure {
+                "wide",
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::borrow::Cow;
+
+use crate::{define_enum, Parse};
+
+define_enum! {
+    /// Determines the case of the text as it is displayed, without changing the text itself.
+    #[derive(Default)]
+    pub enum TextTransform {
+        /// The text is displayed as it is.
+        #[default]
+        "none": None,
+        /// Every letter is displayed in uppercase.
+        "uppercase": Uppercase,
+        /// Every letter is displayed in lowercase.
+        "lowercase": Lowercase,
+        /// The first letter of each word is displayed in uppercase.
+        "capitalize": Capitalize,
+    }
+}
+
+impl TextTransform {
+    /// Returns the text as it is displayed with the transform.
+    pub fn apply<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        match self {
+            TextTransform::None => Cow::Borrowed(text),
+            TextTransform::Uppercase => Cow::Owned(text.to_uppercase()),
+            TextTransform::Lowercase => Cow::Owned(text.to_lowercase()),
+            TextTransform::Capitalize => {
+                let mut capitalized = String::with_capacity(text.len());
+                let mut in_word = false;
+                for c in text.chars() {
+                    if !in_word && c.is_alphabetic() {
+                        capitalized.extend(c.to_uppercase());
+                    } else {
+                        capitalized.push(c);
+                    }
+
+                    // An apostrophe within a word, such as in "don't", doesn't start a new word.
+                    in_word = c.is_alphanumeric() || (in_word && matches!(c, '\'' | '\u{2019}'));
+                }
+
+                Cow::Owned(capitalized)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_text_transform {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        TextTransform, assert_text_transform,
+
+        ident {
+            "none" => TextTransform::None,
+            "uppercase" => TextTransform::Uppercase,
+            "lowercase" => TextTransform::Lowercase,
+            "capitalize" => TextTransform::Capitalize,
+        }
+    }
+
+    #[test]
+    fn capitalize_uppercases_the_first_letter_of_each_word() {
+        let capitalize = |text| TextTransform::Capitalize.apply(text).into_owned();
+
+        assert_eq!(capitalize("hello world"), "Hello World");
+        assert_eq!(capitalize("don't stop-motion"), "Don't Stop-Motion");
+        assert_eq!(capitalize("élan 3d"), "Élan 3d");
+        assert_eq!(capitalize("  (über)"), "  (Über)");
+    }
+
+    #[test]
+    fn case_transforms_are_unicode_aware() {
+        assert_eq!(TextTransform::Uppercase.apply("straße"), "STRASSE");
+        assert_eq!(TextTransform::Lowercase.apply("ΑΒΓ"), "αβγ");
+        assert!(matches!(TextTransform::None.apply("Text"), Cow::Borrowed("Text")));
+    }
+}
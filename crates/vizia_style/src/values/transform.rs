@@ -1,4 +1,6 @@
-use crate::{Angle, CustomParseError, LengthOrPercentage, Matrix, Parse, PercentageOrNumber};
+use crate::{
+    Angle, CustomParseError, Length, LengthOrPercentage, Matrix, Parse, PercentageOrNumber,
+};
 use cssparser::{match_ignore_ascii_case, ParseError, Parser, Token};
 
 /// An individual transform function.
@@ -10,6 +12,8 @@ pub enum Transform {
     TranslateX(LengthOrPercentage),
     /// A translation in the Y direction.
     TranslateY(LengthOrPercentage),
+    /// A 3D translation, where the translation in the Z direction only has an effect after a perspective transform.
+    Translate3d((LengthOrPercentage, LengthOrPercentage, Length)),
     /// A 2D scale.
     Scale((PercentageOrNumber, PercentageOrNumber)),
     /// A scale in the X direction.
@@ -18,18 +22,19 @@ pub enum Transform {
     ScaleY(PercentageOrNumber),
     /// A 2D rotation.
     Rotate(Angle),
-    // /// A rotation around the X axis.
-    // RotateX(Angle),
-    // /// A rotation around the Y axis.
-    // RotateY(Angle),
+    /// A rotation around the X axis, which is only foreshortened after a perspective transform.
+    RotateX(Angle),
+    /// A rotation around the Y axis, which is only foreshortened after a perspective transform.
+    RotateY(Angle),
     /// A 2D skew.
     Skew(Angle, Angle),
     /// A skew along the X axis.
     SkewX(Angle),
     /// A skew along the Y axis.
     SkewY(Angle),
-    // /// A perspective transform.
-    // Perspective(Length),
+    /// A perspective transform, with the distance from the viewer to the plane of the view, which is treated as 1px
+    /// if it's smaller.
+    Perspective(Length),
     /// A 2D matrix transform.
     Matrix(Matrix<f32>),
 }
@@ -56,6 +61,14 @@ impl<'i> Parse<'i> for Transform {
                     let y = LengthOrPercentage::parse(input)?;
                     Ok(Transform::TranslateY(y))
                 },
+                "translate3d" => {
+                    let x = LengthOrPercentage::parse(input)?;
+                    input.expect_comma()?;
+                    let y = LengthOrPercentage::parse(input)?;
+                    input.expect_comma()?;
+                    let z = Length::parse(input)?;
+                    Ok(Transform::Translate3d((x, y, z)))
+                },
                 "scale" => {
                     let x = PercentageOrNumber::parse(input)?;
                     input.expect_comma()?;
@@ -74,14 +87,14 @@ impl<'i> Parse<'i> for Transform {
                     let angle = Angle::parse(input)?;
                     Ok(Transform::Rotate(angle))
                 },
-                // "rotatex" => {
-                //     let x = Angle::parse(input)?;
-                //     Ok(Transform::RotateX(x))
-                // },
-                // "rotatey" => {
-                //     let y = Angle::parse(input)?;
-                //     Ok(Transform::RotateY(y))
-                // },
+                "rotatex" => {
+                    let x = Angle::parse(input)?;
+                    Ok(Transform::RotateX(x))
+                },
+                "rotatey" => {
+                    let y = Angle::parse(input)?;
+                    Ok(Transform::RotateY(y))
+                },
                 "skew" => {
                     let x = Angle::parse(input)?;
                     input.expect_comma()?;
@@ -96,10 +109,13 @@ impl<'i> Parse<'i> for Transform {
                     let y = Angle::parse(input)?;
                     Ok(Transform::SkewY(y))
                 },
-                // "perspective" => {
-                //     let length = Length::parse(input)?;
-                //     Ok(Transform::Perspective(length))
-                // },
+                "perspective" => {
+                    let length = Length::parse(input)?;
+                    if length.to_px().is_some_and(|px| px < 0.0) {
+                        return Err(location.new_custom_error(CustomParseError::InvalidValue));
+                    }
+                    Ok(Transform::Perspective(length))
+                },
                 "matrix" => {
                     let matrix = Matrix::parse(input)?;
                     Ok(Transform::Matrix(matrix))
@@ -145,7 +161,6 @@ impl From<Transform> for Vec<Transform> {
 mod tests {
     use super::*;
     use crate::tests::assert_parse;
-    use crate::Length;
 
     assert_parse! {
         Transform, assert_transform,
@@ -161,14 +176,15 @@ mod tests {
                 "scaley(50%)" => Transform::ScaleY(PercentageOrNumber::Percentage(50.0)),
 
                 "rotate(50deg)" => Transform::Rotate(Angle::Deg(50.0)),
-                // "rotatex(30grad)" => Transform::RotateX(Angle::Grad(30.0)),
-                // "rotatey(20turn)" => Transform::RotateY(Angle::Turn(20.0)),
+                "rotatex(30grad)" => Transform::RotateX(Angle::Grad(30.0)),
+                "rotatey(20turn)" => Transform::RotateY(Angle::Turn(20.0)),
 
                 "skew(60rad, 70turn)" => Transform::Skew(Angle::Rad(60.0), Angle::Turn(70.0)),
                 "skewx(90grad)" => Transform::SkewX(Angle::Grad(90.0)),
                 "skewy(120deg)" => Transform::SkewY(Angle::Deg(120.0)),
 
-                // "perspective(20px)" => Transform::Perspective(Length::px(20.0)),
+                "perspective(20px)" => Transform::Perspective(Length::px(20.0)),
+                "translate3d(10px, 50%, -20px)" => Transform::Translate3d((LengthOrPercentage::Length(Length::px(10.0)), LengthOrPercentage::Percentage(50.0), Length::px(-20.0))),
                 "matrix(1, 2, 3, 4, 5, 6)" => Transform::Matrix(Matrix::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0)),
             }
 
@@ -179,6 +195,9 @@ mod tests {
                 "scale(30%)",
                 "skewx(20px)",
                 "translate(5in)",
+                "perspective(-10px)",
+                "translate3d(10px, 20px, 50%)",
+                "translate3d(10px, 20px)",
                 "abc",
             }
         }
@@ -197,6 +216,12 @@ mod tests {
                         Transform::Skew(Angle::Turn(60.0), Angle::Rad(70.0)),
                         Transform::Matrix(Matrix::new(10.0, 20.0, 30.0, 40.0, 50.0, 60.0)),
                     ],
+                "perspective(200px) rotatey(30deg) translate3d(0px, 0px, 10px)" =>
+                    vec![
+                        Transform::Perspective(Length::px(200.0)),
+                        Transform::RotateY(Angle::Deg(30.0)),
+                        Transform::Translate3d((LengthOrPercentage::Length(Length::px(0.0)), LengthOrPercentage::Length(Length::px(0.0)), Length::px(10.0))),
+                    ],
             }
 
             failure {
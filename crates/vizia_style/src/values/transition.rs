@@ -1,4 +1,4 @@
-use crate::{duration::Duration, CustomParseError, EasingFunction, Ident, Parse};
+use crate::{duration::Duration, ColorSpace, CustomParseError, EasingFunction, Ident, Parse};
 use cssparser::{ParseError, ParseErrorKind, Parser};
 
 /// Defines a transition that allows to change property values smoothly, over a given duration.
@@ -12,6 +12,8 @@ pub struct Transition {
     pub delay: Option<Duration>,
 
     pub timing_function: Option<EasingFunction>,
+    /// The color space used to interpolate colors, overriding the application default.
+    pub color_space: Option<ColorSpace>,
 }
 
 impl Transition {
@@ -22,7 +24,13 @@ impl Transition {
         delay: Option<Duration>,
         timing_function: Option<EasingFunction>,
     ) -> Self {
-        Self { property, duration, delay, timing_function }
+        Self { property, duration, delay, timing_function, color_space: None }
+    }
+
+    /// Sets the color space used to interpolate colors during the transition.
+    pub fn with_color_space(mut self, color_space: ColorSpace) -> Self {
+        self.color_space = Some(color_space);
+        self
     }
 }
 
@@ -30,24 +38,71 @@ impl<'i> Parse<'i> for Transition {
     fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
         let location = input.current_source_location();
 
-        let property = Ident::parse(input)?.into();
-        let duration = Duration::parse(input)?;
-        let delay = input.try_parse(Duration::parse).ok();
-        let timing_function = input.try_parse(EasingFunction::parse).ok();
+        // The components can be in any order, with the first time being the duration and the second the delay.
+        let mut property = None;
+        let mut duration = None;
+        let mut delay = None;
+        let mut timing_function = None;
+        let mut color_space = None;
+        loop {
+            if delay.is_none() {
+                if let Ok(time) = input.try_parse(Duration::parse) {
+                    if duration.is_none() {
+                        duration = Some(time);
+                    } else {
+                        delay = Some(time);
+                    }
+                    continue;
+                }
+            }
+
+            if timing_function.is_none() {
+                if let Ok(easing) = input.try_parse(EasingFunction::parse) {
+                    timing_function = Some(easing);
+                    continue;
+                }
+            }
+
+            if color_space.is_none() {
+                if let Ok(space) = input.try_parse(ColorSpace::parse) {
+                    color_space = Some(space);
+                    continue;
+                }
+            }
+
+            if property.is_none() {
+                if let Ok(ident) = input.try_parse(Ident::parse) {
+                    property = Some(ident.into());
+                    continue;
+                }
+            }
+
+            break;
+        }
+
+        match (property, duration) {
+            (Some(property), Some(duration)) if input.is_exhausted() => {
+                Ok(Self { property, duration, delay, timing_function, color_space })
+            }
 
-        if input.is_exhausted() {
-            Ok(Self { property, duration, delay, timing_function })
-        } else {
-            Err(ParseError {
+            _ => Err(ParseError {
                 kind: ParseErrorKind::Custom(CustomParseError::InvalidDeclaration),
                 location,
-            })
+            }),
         }
     }
 }
 
 impl<'i> Parse<'i> for Vec<Transition> {
     fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let none = input.try_parse(|input| {
+            input.expect_ident_matching("none")?;
+            input.expect_exhausted()
+        });
+        if none.is_ok() {
+            return Ok(Vec::new());
+        }
+
         input.parse_comma_separated(Transition::parse)
     }
 }
@@ -56,6 +111,7 @@ impl<'i> Parse<'i> for Vec<Transition> {
 mod tests {
     use super::*;
     use crate::tests::assert_parse;
+    use crate::StepPosition;
 
     assert_parse! {
         Transition, assert_transition,
@@ -65,11 +121,28 @@ mod tests {
                 "width 2s" => Transition::new(String::from("width"), Duration::from_secs(2), None, None),
                 "height 2s 1s" => Transition::new(String::from("height"), Duration::from_secs(2), Some(Duration::from_secs(1)), None),
                 "color 200ms linear" => Transition::new(String::from("color"), Duration::from_millis(200), None, Some(EasingFunction::Linear)),
+                "background-color 200ms ease oklab" => Transition::new(String::from("background-color"), Duration::from_millis(200), None, Some(EasingFunction::Ease)).with_color_space(ColorSpace::Oklab),
+                "color 1s 2s srgb-linear" => Transition::new(String::from("color"), Duration::from_secs(1), Some(Duration::from_secs(2)), None).with_color_space(ColorSpace::LinearSrgb),
+                "transform 1s spring(170, 26)" => Transition::new(String::from("transform"), Duration::from_secs(1), None, Some(EasingFunction::spring(170.0, 26.0))),
+                "opacity 0s spring(300, 10, -2.5)" => Transition::new(String::from("opacity"), Duration::ZERO, None, Some(EasingFunction::Spring { stiffness: 300.0, damping: 10.0, velocity: -2.5 })),
+                "opacity 300ms cubic-bezier(0.4, 0, 0.2, 1)" => Transition::new(String::from("opacity"), Duration::from_millis(300), None, Some(EasingFunction::CubicBezier(0.4, 0.0, 0.2, 1.0))),
+                "opacity 1s steps(4, jump-both)" => Transition::new(String::from("opacity"), Duration::from_secs(1), None, Some(EasingFunction::Steps(4, StepPosition::JumpBoth))),
+                "1s 2s height" => Transition::new(String::from("height"), Duration::from_secs(1), Some(Duration::from_secs(2)), None),
+                "ease-out 300ms transform" => Transition::new(String::from("transform"), Duration::from_millis(300), None, Some(EasingFunction::EaseOut)),
+                "color 1s oklab ease" => Transition::new(String::from("color"), Duration::from_secs(1), None, Some(EasingFunction::Ease)).with_color_space(ColorSpace::Oklab),
+                "all 100ms" => Transition::new(String::from("all"), Duration::from_millis(100), None, None),
             }
 
             failure {
                 "height 2s 1s 1s",
-                "1s 2s height",
+                "height",
+                "2s",
+                "height width 2s",
+                "color 1s ease linear",
+                "transform 1s spring(170)",
+                "transform 1s spring(0, 26)",
+                "transform 1s spring(170, -1)",
+                "opacity 1s steps(0)",
             }
         }
     }
@@ -84,10 +157,17 @@ mod tests {
                     Transition::new(String::from("width"), Duration::from_secs(3), Some(Duration::from_secs(4)), None),
                     Transition::new(String::from("rotation"), Duration::from_secs(5), Some(Duration::from_secs(6)), None),
                 ],
+                "background-color 150ms ease, transform 300ms ease-out" => vec![
+                    Transition::new(String::from("background-color"), Duration::from_millis(150), None, Some(EasingFunction::Ease)),
+                    Transition::new(String::from("transform"), Duration::from_millis(300), None, Some(EasingFunction::EaseOut)),
+                ],
+                "none" => vec![],
             }
 
             failure {
                 "height, width, rotation",
+                "none, height 1s",
+                "height 1s,",
             }
         }
     }
@@ -0,0 +1,33 @@
+use crate::{define_enum, Parse};
+
+define_enum! {
+    /// Determines where the text of a view is placed vertically within the view, inside of its padding.
+    pub enum VerticalAlign {
+        /// The text is placed at the top of the view.
+        "top": Top,
+        /// The text is centered vertically within the view.
+        "center": Center,
+        /// The text is placed at the bottom of the view.
+        "bottom": Bottom,
+        /// The first baseline of the text is placed at the baseline offset of the view, which is the baseline it's
+        /// aligned to in a row, or the text is placed at the top if the view has no baseline offset.
+        "baseline": Baseline,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        VerticalAlign, assert_vertical_align,
+
+        ident {
+            "top" => VerticalAlign::Top,
+            "center" => VerticalAlign::Center,
+            "bottom" => VerticalAlign::Bottom,
+            "baseline" => VerticalAlign::Baseline,
+        }
+    }
+}
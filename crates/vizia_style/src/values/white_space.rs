@@ -0,0 +1,68 @@
+use std::borrow::Cow;
+
+use crate::{define_enum, Parse};
+
+define_enum! {
+    /// Determines whether line breaks in the text are kept and whether lines wrap to the width of the view.
+    #[derive(Default)]
+    pub enum WhiteSpace {
+        /// Line breaks and tabs are displayed as spaces and lines wrap.
+        "normal": Normal,
+        /// Line breaks and tabs are displayed as spaces and the text is displayed on a single line.
+        "nowrap": NoWrap,
+        /// Line breaks are kept and lines only break at them.
+        "pre": Pre,
+        /// Line breaks are kept and lines also wrap, which is how text has always been displayed.
+        #[default]
+        "pre-wrap": PreWrap,
+    }
+}
+
+impl WhiteSpace {
+    /// Returns true if lines wrap to the width of the view.
+    pub fn wraps(&self) -> bool {
+        matches!(self, WhiteSpace::Normal | WhiteSpace::PreWrap)
+    }
+
+    /// Returns true if line breaks in the text start a new line.
+    pub fn preserves_line_breaks(&self) -> bool {
+        matches!(self, WhiteSpace::Pre | WhiteSpace::PreWrap)
+    }
+
+    /// Returns the text as it is laid out, with line breaks and tabs replaced by spaces if they aren't kept.
+    ///
+    /// Each replaced character is a single byte, as is the space, so indices into the text stay the same.
+    pub fn apply<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if self.preserves_line_breaks() || !text.contains(['\n', '\r', '\t']) {
+            return Cow::Borrowed(text);
+        }
+
+        Cow::Owned(text.replace(['\n', '\r', '\t'], " "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        WhiteSpace, assert_white_space,
+
+        ident {
+            "normal" => WhiteSpace::Normal,
+            "nowrap" => WhiteSpace::NoWrap,
+            "pre" => WhiteSpace::Pre,
+            "pre-wrap" => WhiteSpace::PreWrap,
+        }
+    }
+
+    #[test]
+    fn collapses_line_breaks_without_changing_length() {
+        let text = "one\ntwo\r\n\tthree";
+        assert_eq!(WhiteSpace::NoWrap.apply(text), "one two   three");
+        assert_eq!(WhiteSpace::Normal.apply(text).len(), text.len());
+        assert!(matches!(WhiteSpace::Pre.apply(text), Cow::Borrowed(_)));
+        assert!(matches!(WhiteSpace::Normal.apply("one two"), Cow::Borrowed(_)));
+    }
+}
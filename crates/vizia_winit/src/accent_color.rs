@@ -0,0 +1,56 @@
+use vizia_core::prelude::*;
+
+/// Returns the accent color of the platform, or `None` if it is unavailable.
+#[cfg(target_os = "windows")]
+pub(crate) fn platform_accent_color() -> Option<Color> {
+    use std::ffi::c_void;
+    use windows_sys::Win32::System::Registry::{RegGetValueW, HKEY_CURRENT_USER, RRF_RT_REG_DWORD};
+
+    let subkey = "Software\\Microsoft\\Windows\\DWM\0".encode_utf16().collect::<Vec<_>>();
+    let value = "AccentColor\0".encode_utf16().collect::<Vec<_>>();
+
+    let mut data = 0u32;
+    let mut size = std::mem::size_of::<u32>() as u32;
+    let status = unsafe {
+        RegGetValueW(
+            HKEY_CURRENT_USER,
+            subkey.as_ptr(),
+            value.as_ptr(),
+            RRF_RT_REG_DWORD,
+            std::ptr::null_mut(),
+            &mut data as *mut u32 as *mut c_void,
+            &mut size,
+        )
+    };
+
+    if status != 0 {
+        return None;
+    }
+
+    // The color is stored as 0xAABBGGRR, where the alpha is unrelated to the opacity of the accent color.
+    let [red, green, blue, _] = data.to_le_bytes();
+    Some(Color::rgb(red, green, blue))
+}
+
+#[cfg(target_os = "macos")]
+pub(crate) fn platform_accent_color() -> Option<Color> {
+    use objc2_app_kit::{NSColor, NSColorSpace};
+
+    unsafe {
+        let accent_color =
+            NSColor::controlAccentColor().colorUsingColorSpace(&NSColorSpace::sRGBColorSpace())?;
+        let component = |value: f64| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Some(Color::rgba(
+            component(accent_color.redComponent()),
+            component(accent_color.greenComponent()),
+            component(accent_color.blueComponent()),
+            component(accent_color.alphaComponent()),
+        ))
+    }
+}
+
+// Other platforms have no common accent color setting, so the default accent color of the theme is used.
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+pub(crate) fn platform_accent_color() -> Option<Color> {
+    None
+}
@@ -1,7 +1,9 @@
 #[cfg(target_os = "windows")]
 use crate::window::set_cloak;
 use crate::{
+    accent_color::platform_accent_color,
     convert::{winit_key_code_to_code, winit_key_to_key},
+    interaction::platform_interaction_settings,
     window::{WinState, Window},
     window_modifiers::WindowModifiers,
 };
@@ -101,6 +103,15 @@ pub struct Application {
     event_loop_proxy: EventLoopProxy<UserEvent>,
     windows: HashMap<WindowId, WinState>,
     window_ids: HashMap<Entity, WindowId>,
+    // The type of pointer which produced the last pointer event sent to the context.
+    pointer_type: PointerType,
+    // The unit of the deltas of the last scroll event sent to the context.
+    scroll_unit: ScrollUnit,
+    // The touch which is currently acting as the pointer.
+    touch_id: Option<u64>,
+    // Whether the default menu of macOS, whose quit item quits without sending close requests, is shown.
+    #[allow(dead_code)]
+    default_menu: bool,
 }
 
 pub struct WinitEventProxy(EventLoopProxy<UserEvent>);
@@ -117,17 +128,56 @@ impl EventProxy for WinitEventProxy {
 
 impl Application {
     pub fn new<F>(content: F) -> Self
+    where
+        F: 'static + FnOnce(&mut Context),
+    {
+        Self::build(content, true)
+    }
+
+    /// Creates an application without the default menu of macOS, so that quitting with Cmd+Q sends a
+    /// [`WindowEvent::CloseRequested`] event to each window which can be cancelled with
+    /// [`WindowEvent::CancelClose`], like closing the windows with their titlebar buttons.
+    ///
+    /// The quit item of the default menu quits the application without sending close requests. On other platforms
+    /// this is the same as [`Application::new`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::without_default_menu(|cx| {
+    ///     // Content goes here
+    /// })
+    /// .run();
+    /// ```
+    pub fn without_default_menu<F>(content: F) -> Self
+    where
+        F: 'static + FnOnce(&mut Context),
+    {
+        Self::build(content, false)
+    }
+
+    fn build<F>(content: F, default_menu: bool) -> Self
     where
         F: 'static + FnOnce(&mut Context),
     {
         let context = Context::new();
 
-        let event_loop =
-            EventLoop::<UserEvent>::with_user_event().build().expect("Failed to create event loop");
+        #[allow(unused_mut)]
+        let mut event_loop_builder = EventLoop::<UserEvent>::with_user_event();
+
+        #[cfg(target_os = "macos")]
+        {
+            use winit::platform::macos::EventLoopBuilderExtMacOS;
+            event_loop_builder.with_default_menu(default_menu);
+        }
+
+        let event_loop = event_loop_builder.build().expect("Failed to create event loop");
 
         let mut cx = BackendContext::new(context);
         let event_proxy_obj = event_loop.create_proxy();
         cx.set_event_proxy(Box::new(WinitEventProxy(event_proxy_obj)));
+        *cx.context().interaction_settings_mut() = platform_interaction_settings();
 
         cx.renegotiate_language();
         cx.0.remove_user_themes();
@@ -145,6 +195,10 @@ impl Application {
             event_loop_proxy: proxy,
             windows: HashMap::new(),
             window_ids: HashMap::new(),
+            pointer_type: PointerType::Mouse,
+            scroll_unit: ScrollUnit::Lines,
+            touch_id: None,
+            default_menu,
         }
     }
 
@@ -278,6 +332,10 @@ impl ApplicationHandler<UserEvent> for Application {
                 self.cx.emit_origin(WindowEvent::ThemeChanged(theme));
             }
 
+            if let Some(accent_color) = platform_accent_color() {
+                self.cx.emit_origin(WindowEvent::AccentColorChanged(accent_color));
+            }
+
             self.cx.0.remove_user_themes();
 
             // Create any subwindows
@@ -360,7 +418,10 @@ impl ApplicationHandler<UserEvent> for Application {
                 self.cx.set_window_position(window.entity, position.x as f32, position.y as f32);
             }
 
-            winit::event::WindowEvent::CloseRequested | winit::event::WindowEvent::Destroyed => {
+            winit::event::WindowEvent::CloseRequested => {
+                self.cx.emit_window_event(window.entity, WindowEvent::CloseRequested);
+            }
+            winit::event::WindowEvent::Destroyed => {
                 let window_entity = window.entity;
                 self.cx.emit_window_event(window_entity, WindowEvent::WindowClose);
             }
@@ -373,6 +434,17 @@ impl ApplicationHandler<UserEvent> for Application {
             winit::event::WindowEvent::Focused(is_focused) => {
                 self.cx.emit_window_event(window.entity, WindowEvent::WindowFocused(is_focused));
 
+                // The platforms don't notify about accent color changes through winit, so the accent color is read
+                // again whenever the user returns to the application, such as after changing their settings.
+                if is_focused {
+                    if let Some(accent_color) = platform_accent_color() {
+                        self.cx.emit_window_event(
+                            window.entity,
+                            WindowEvent::AccentColorChanged(accent_color),
+                        );
+                    }
+                }
+
                 self.cx.0.window_has_focus = is_focused;
                 // #[cfg(feature = "accesskit")]
                 // accesskit.update_if_active(|| TreeUpdate {
@@ -404,6 +476,17 @@ impl ApplicationHandler<UserEvent> for Application {
                     }
                 }
 
+                // Without the default menu of macOS, which would quit the application without asking, quitting can be
+                // cancelled in response to the close requests.
+                #[cfg(target_os = "macos")]
+                if !self.default_menu
+                    && event.state == ElementState::Pressed
+                    && code == Code::KeyQ
+                    && self.cx.modifiers().contains(Modifiers::SUPER)
+                {
+                    self.cx.request_quit();
+                }
+
                 let event = match event.state {
                     winit::event::ElementState::Pressed => WindowEvent::KeyDown(code, key),
                     winit::event::ElementState::Released => WindowEvent::KeyUp(code, key),
@@ -425,6 +508,17 @@ impl ApplicationHandler<UserEvent> for Application {
             }
             winit::event::WindowEvent::Ime(_) => {}
             winit::event::WindowEvent::CursorMoved { device_id: _, position } => {
+                // Mouse events synthesized from a touch by the platform are ignored while the touch is pressed.
+                if self.touch_id.is_some() {
+                    return;
+                }
+
+                set_pointer_type(
+                    &mut self.cx,
+                    &mut self.pointer_type,
+                    window.entity,
+                    PointerType::Mouse,
+                );
                 self.cx.emit_window_event(
                     window.entity,
                     WindowEvent::MouseMove(position.x as f32, position.y as f32),
@@ -442,13 +536,27 @@ impl ApplicationHandler<UserEvent> for Application {
             winit::event::WindowEvent::MouseWheel { device_id: _, delta, phase: _ } => {
                 let out_event = match delta {
                     winit::event::MouseScrollDelta::LineDelta(x, y) => {
+                        set_scroll_unit(
+                            &mut self.cx,
+                            &mut self.scroll_unit,
+                            window.entity,
+                            ScrollUnit::Lines,
+                        );
                         WindowEvent::MouseScroll(x, y)
                     }
                     winit::event::MouseScrollDelta::PixelDelta(pos) => {
-                        WindowEvent::MouseScroll(
-                            pos.x as f32 / 20.0,
-                            pos.y as f32 / 20.0, // this number calibrated for wayland
-                        )
+                        set_scroll_unit(
+                            &mut self.cx,
+                            &mut self.scroll_unit,
+                            window.entity,
+                            ScrollUnit::Pixels,
+                        );
+                        // Pixel deltas are converted to fractions of a line so that views which scroll by lines
+                        // handle them too.
+                        let pos = pos.to_logical::<f32>(window.window().scale_factor());
+                        let line_height =
+                            self.cx.context().interaction_settings().scroll_line_height;
+                        WindowEvent::MouseScroll(pos.x / line_height, pos.y / line_height)
                     }
                 };
 
@@ -456,6 +564,16 @@ impl ApplicationHandler<UserEvent> for Application {
                 window.window().request_redraw();
             }
             winit::event::WindowEvent::MouseInput { device_id: _, state, button } => {
+                if self.touch_id.is_some() {
+                    return;
+                }
+
+                set_pointer_type(
+                    &mut self.cx,
+                    &mut self.pointer_type,
+                    window.entity,
+                    PointerType::Mouse,
+                );
                 let button = match button {
                     winit::event::MouseButton::Left => MouseButton::Left,
                     winit::event::MouseButton::Right => MouseButton::Right,
@@ -473,6 +591,53 @@ impl ApplicationHandler<UserEvent> for Application {
                 self.cx.emit_window_event(window.entity, event);
                 window.window().request_redraw();
             }
+            winit::event::WindowEvent::Touch(touch) => {
+                // Only the first touch of a gesture acts as the pointer, as the left mouse button.
+                if self.touch_id.is_some_and(|touch_id| touch_id != touch.id) {
+                    return;
+                }
+
+                set_pointer_type(
+                    &mut self.cx,
+                    &mut self.pointer_type,
+                    window.entity,
+                    PointerType::Touch,
+                );
+
+                let (x, y) = (touch.location.x as f32, touch.location.y as f32);
+                match touch.phase {
+                    winit::event::TouchPhase::Started => {
+                        self.touch_id = Some(touch.id);
+                        self.cx.emit_window_event(window.entity, WindowEvent::MouseMove(x, y));
+                        self.cx.emit_window_event(
+                            window.entity,
+                            WindowEvent::MouseDown(MouseButton::Left),
+                        );
+                    }
+                    winit::event::TouchPhase::Moved => {
+                        self.cx.emit_window_event(window.entity, WindowEvent::MouseMove(x, y));
+                    }
+                    winit::event::TouchPhase::Ended => {
+                        self.touch_id = None;
+                        self.cx.emit_window_event(window.entity, WindowEvent::MouseMove(x, y));
+                        self.cx.emit_window_event(
+                            window.entity,
+                            WindowEvent::MouseUp(MouseButton::Left),
+                        );
+                    }
+                    // A cancelled touch leaves the window first so that releasing it doesn't press the view below.
+                    winit::event::TouchPhase::Cancelled => {
+                        self.touch_id = None;
+                        self.cx.emit_window_event(window.entity, WindowEvent::MouseLeave);
+                        self.cx.emit_window_event(
+                            window.entity,
+                            WindowEvent::MouseUp(MouseButton::Left),
+                        );
+                    }
+                }
+
+                window.window().request_redraw();
+            }
 
             winit::event::WindowEvent::ScaleFactorChanged {
                 scale_factor,
@@ -487,6 +652,13 @@ impl ApplicationHandler<UserEvent> for Application {
                     winit::window::Theme::Dark => ThemeMode::DarkMode,
                 };
                 self.cx.emit_window_event(window.entity, WindowEvent::ThemeChanged(theme));
+
+                if let Some(accent_color) = platform_accent_color() {
+                    self.cx.emit_window_event(
+                        window.entity,
+                        WindowEvent::AccentColorChanged(accent_color),
+                    );
+                }
             }
             winit::event::WindowEvent::Occluded(_) => {}
             winit::event::WindowEvent::RedrawRequested => {
@@ -625,11 +797,12 @@ impl ApplicationHandler<UserEvent> for Application {
 }
 
 impl WindowModifiers for Application {
-    fn title<T: ToString>(mut self, title: impl Res<T>) -> Self {
-        self.window_description.title = title.get(&self.cx.0).to_string();
+    fn title<T: ToStringLocalized>(mut self, title: impl Res<T>) -> Self {
+        self.window_description.title = title.get(&self.cx.0).to_string_local(&self.cx.0);
 
         title.set_or_bind(&mut self.cx.0, Entity::root(), |cx, title| {
-            cx.emit(WindowEvent::SetTitle(title.get(cx).to_string()));
+            let title = title.get(cx).to_string_local(cx);
+            cx.emit(WindowEvent::SetTitle(title));
         });
 
         self
@@ -759,6 +932,32 @@ impl WindowModifiers for Application {
     }
 }
 
+// Notifies the context when the pointer events start coming from a different type of pointer.
+fn set_pointer_type(
+    cx: &mut BackendContext,
+    current: &mut PointerType,
+    window_entity: Entity,
+    pointer_type: PointerType,
+) {
+    if *current != pointer_type {
+        *current = pointer_type;
+        cx.emit_window_event(window_entity, WindowEvent::PointerTypeChanged(pointer_type));
+    }
+}
+
+// Notifies the context when the scroll events start coming from a device which reports deltas in a different unit.
+fn set_scroll_unit(
+    cx: &mut BackendContext,
+    current: &mut ScrollUnit,
+    window_entity: Entity,
+    scroll_unit: ScrollUnit,
+) {
+    if *current != scroll_unit {
+        *current = scroll_unit;
+        cx.emit_window_event(window_entity, WindowEvent::ScrollUnitChanged(scroll_unit));
+    }
+}
+
 fn apply_window_description(description: &WindowDescription) -> WindowAttributes {
     let mut window_attributes = winit::window::Window::default_attributes();
 
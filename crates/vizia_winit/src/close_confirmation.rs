@@ -0,0 +1,116 @@
+use vizia_core::prelude::*;
+
+use crate::{window::Window, window_modifiers::WindowModifiers};
+
+/// Asks the user to confirm closing a window which would otherwise lose work.
+///
+/// When a close is requested for the window containing the confirmation, for example with the close button of
+/// the titlebar, the request is cancelled while `needs_confirmation` is true and a modal popup window is shown
+/// with the given message. The window is closed if the user confirms.
+///
+/// # Example
+/// ```no_run
+/// # use vizia_core::prelude::*;
+/// # use vizia_winit::application::Application;
+/// # use vizia_winit::close_confirmation::CloseConfirmation;
+/// #[derive(Lens)]
+/// struct AppData {
+///     unsaved_changes: bool,
+/// }
+///
+/// impl Model for AppData {}
+///
+/// Application::new(|cx| {
+///     AppData { unsaved_changes: true }.build(cx);
+///
+///     CloseConfirmation::new(cx, AppData::unsaved_changes, "Discard unsaved changes?");
+/// })
+/// .run();
+/// ```
+#[derive(Lens)]
+pub struct CloseConfirmation {
+    is_open: bool,
+    #[lens(ignore)]
+    needs_confirmation: Box<dyn Fn(&EventContext) -> bool>,
+}
+
+enum CloseConfirmationEvent {
+    Confirm,
+    Dismiss,
+}
+
+impl CloseConfirmation {
+    pub fn new<T: ToStringLocalized + 'static>(
+        cx: &mut Context,
+        needs_confirmation: impl Lens<Target = bool>,
+        message: impl Res<T> + Clone + 'static,
+    ) {
+        // The window's models receive its close requests before they propagate further up the tree.
+        let window = cx.parent_window();
+        cx.with_current(window, |cx| {
+            Self {
+                is_open: false,
+                needs_confirmation: Box::new(move |cx| needs_confirmation.get(cx)),
+            }
+            .build(cx);
+
+            Binding::new(cx, CloseConfirmation::is_open, move |cx, is_open| {
+                if is_open.get(cx) {
+                    let message = message.clone();
+                    Window::popup(cx, true, move |cx| {
+                        VStack::new(cx, |cx| {
+                            Label::new(cx, message.clone())
+                                .width(Stretch(1.0))
+                                .alignment(Alignment::Center);
+                            HStack::new(cx, |cx| {
+                                Button::new(cx, |cx| Label::new(cx, "Close"))
+                                    .on_press(|cx| cx.emit(CloseConfirmationEvent::Confirm))
+                                    .class("accent");
+
+                                Button::new(cx, |cx| Label::new(cx, "Cancel"))
+                                    .on_press(|cx| cx.emit(CloseConfirmationEvent::Dismiss));
+                            })
+                            .horizontal_gap(Pixels(10.0))
+                            .size(Auto);
+                        })
+                        .alignment(Alignment::Center)
+                        .vertical_gap(Pixels(20.0));
+                    })
+                    .on_close(|cx| cx.emit(CloseConfirmationEvent::Dismiss))
+                    .title("Close window?")
+                    .inner_size((400, 160));
+                }
+            });
+        });
+    }
+}
+
+impl Model for CloseConfirmation {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| {
+            // Close requests of child windows also propagate up to this window, so they are ignored.
+            if let WindowEvent::CloseRequested = window_event {
+                if meta.origin == cx.current() && (self.needs_confirmation)(cx) {
+                    cx.emit(WindowEvent::CancelClose);
+                    self.is_open = true;
+                }
+            }
+        });
+
+        event.map(|confirmation_event, meta| {
+            match confirmation_event {
+                CloseConfirmationEvent::Confirm => {
+                    self.is_open = false;
+                    let window = cx.current();
+                    cx.emit_to(window, WindowEvent::WindowClose);
+                }
+
+                CloseConfirmationEvent::Dismiss => {
+                    self.is_open = false;
+                }
+            }
+
+            meta.consume();
+        });
+    }
+}
@@ -0,0 +1,134 @@
+use vizia_core::prelude::*;
+
+/// Returns the interaction settings of the platform, using the defaults for any which are unavailable.
+pub(crate) fn platform_interaction_settings() -> InteractionSettings {
+    let mut settings = InteractionSettings::default();
+    read_platform_settings(&mut settings);
+    settings
+}
+
+#[cfg(target_os = "windows")]
+fn read_platform_settings(settings: &mut InteractionSettings) {
+    use windows_sys::Win32::UI::{
+        Input::KeyboardAndMouse::GetDoubleClickTime,
+        WindowsAndMessaging::{GetCaretBlinkTime, GetSystemMetrics, SM_CXDRAG, SM_CYDRAG},
+    };
+
+    unsafe {
+        let double_click_time = GetDoubleClickTime();
+        if double_click_time > 0 {
+            settings.double_click_interval = Duration::from_millis(double_click_time as u64);
+        }
+
+        // The blink time is `u32::MAX` when the caret doesn't blink, which the settings can't represent.
+        let caret_blink_time = GetCaretBlinkTime();
+        if caret_blink_time > 0 && caret_blink_time != u32::MAX {
+            settings.caret_blink_interval = Duration::from_millis(caret_blink_time as u64);
+        }
+
+        let drag_threshold = GetSystemMetrics(SM_CXDRAG).max(GetSystemMetrics(SM_CYDRAG));
+        if drag_threshold > 0 {
+            settings.drag_threshold = drag_threshold as f32;
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn read_platform_settings(settings: &mut InteractionSettings) {
+    use objc2_app_kit::NSEvent;
+
+    let double_click_interval = unsafe { NSEvent::doubleClickInterval() };
+    if double_click_interval > 0.0 {
+        settings.double_click_interval = Duration::from_secs_f64(double_click_interval);
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn read_platform_settings(settings: &mut InteractionSettings) {
+    // Without a dependency on GTK, the settings are read from the GTK configuration files of the user.
+    let Some(config_dir) =
+        std::env::var_os("XDG_CONFIG_HOME").map(std::path::PathBuf::from).or_else(|| {
+            std::env::var_os("HOME").map(|home| std::path::Path::new(&home).join(".config"))
+        })
+    else {
+        return;
+    };
+
+    for version in ["gtk-3.0", "gtk-4.0"] {
+        let Ok(contents) = std::fs::read_to_string(config_dir.join(version).join("settings.ini"))
+        else {
+            continue;
+        };
+
+        apply_gtk_settings(settings, &contents);
+    }
+}
+
+/// Applies the interaction settings found in the contents of a GTK `settings.ini` file.
+#[cfg(any(test, all(unix, not(target_os = "macos"))))]
+fn apply_gtk_settings(settings: &mut InteractionSettings, contents: &str) {
+    for line in contents.lines() {
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+
+        let Ok(value) = value.trim().parse::<u64>() else {
+            continue;
+        };
+
+        match key.trim() {
+            "gtk-double-click-time" if value > 0 => {
+                settings.double_click_interval = Duration::from_millis(value);
+            }
+
+            // GTK specifies the length of a whole blink cycle, while the caret is toggled every interval.
+            "gtk-cursor-blink-time" if value > 1 => {
+                settings.caret_blink_interval = Duration::from_millis(value / 2);
+            }
+
+            "gtk-dnd-drag-threshold" if value > 0 => {
+                settings.drag_threshold = value as f32;
+            }
+
+            _ => {}
+        }
+    }
+}
+
+#[cfg(not(any(unix, target_os = "windows")))]
+fn read_platform_settings(_settings: &mut InteractionSettings) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gtk_settings_are_applied() {
+        let mut settings = InteractionSettings::default();
+        apply_gtk_settings(
+            &mut settings,
+            "[Settings]\n\
+             gtk-theme-name=Adwaita\n\
+             gtk-double-click-time = 250\n\
+             gtk-cursor-blink-time=1000\n\
+             gtk-dnd-drag-threshold=12\n",
+        );
+
+        assert_eq!(settings.double_click_interval, Duration::from_millis(250));
+        assert_eq!(settings.caret_blink_interval, Duration::from_millis(500));
+        assert_eq!(settings.drag_threshold, 12.0);
+    }
+
+    #[test]
+    fn invalid_gtk_settings_keep_the_defaults() {
+        let mut settings = InteractionSettings::default();
+        apply_gtk_settings(
+            &mut settings,
+            "gtk-double-click-time=0\n\
+             gtk-cursor-blink-time=fast\n\
+             gtk-dnd-drag-threshold\n",
+        );
+
+        assert_eq!(settings, InteractionSettings::default());
+    }
+}
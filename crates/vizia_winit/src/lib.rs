@@ -1,5 +1,8 @@
+mod accent_color;
 pub mod application;
+pub mod close_confirmation;
 mod convert;
+mod interaction;
 pub mod window;
 pub mod window_modifiers;
 
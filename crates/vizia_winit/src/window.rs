@@ -447,7 +447,9 @@ impl View for Window {
             }
 
             WindowEvent::SetTitle(title) => {
-                self.window().set_title(title);
+                if let Some(window) = &self.window {
+                    window.set_title(title);
+                }
             }
 
             WindowEvent::SetSize(size) => {
@@ -565,12 +567,17 @@ impl WindowModifiers for Handle<'_, Window> {
         self.modify(|window| window.on_create = Some(Box::new(callback)))
     }
 
-    fn title<T: ToString>(mut self, title: impl Res<T>) -> Self {
+    fn title<T: ToStringLocalized>(mut self, title: impl Res<T>) -> Self {
         let entity = self.entity();
-        let title = title.get(&self).to_string();
-        if let Some(win_state) = self.context().windows.get_mut(&entity) {
-            win_state.window_description.title = title;
-        }
+        title.set_or_bind(self.context(), entity, move |cx, title| {
+            let title = title.get(cx).to_string_local(cx);
+            if let Some(win_state) = cx.windows.get_mut(&entity) {
+                win_state.window_description.title = title.clone();
+            }
+
+            // The window may not have been created yet, in which case the title is taken from the description.
+            cx.emit_to(entity, WindowEvent::SetTitle(title));
+        });
 
         self
     }
@@ -711,3 +718,29 @@ impl WindowModifiers for Handle<'_, Window> {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vizia_core::events::EventManager;
+
+    #[test]
+    fn localized_title_is_translated_again_on_locale_change() {
+        let mut cx = Context::default();
+        let mut event_manager = EventManager::new();
+
+        cx.add_translation("en-US".parse().unwrap(), "title = Editor");
+        cx.add_translation("fr".parse().unwrap(), "title = Éditeur");
+        cx.emit(EnvironmentEvent::SetLocale("en-US".parse().unwrap()));
+        event_manager.flush_events(&mut cx, |_| {});
+
+        let window = Window::new(&mut cx, |_| {}).title(Localized::new("title")).entity();
+        // The title is set on the description before the window is created.
+        event_manager.flush_events(&mut cx, |_| {});
+        assert_eq!(cx.windows[&window].window_description.title, "Editor");
+
+        cx.emit(EnvironmentEvent::SetLocale("fr".parse().unwrap()));
+        event_manager.flush_events(&mut cx, |_| {});
+        assert_eq!(cx.windows[&window].window_description.title, "Éditeur");
+    }
+}
@@ -1,4 +1,4 @@
-use vizia_core::{binding::Res, context::EventContext};
+use vizia_core::{binding::Res, context::EventContext, localization::ToStringLocalized};
 use vizia_window::{WindowButtons, WindowPosition, WindowSize};
 
 /// Modifiers for setting the properties of a window.
@@ -7,6 +7,8 @@ pub trait WindowModifiers {
     fn on_create(self, callback: impl Fn(&mut EventContext) + 'static) -> Self;
     /// Sets the title of the window to the given value. Accepts a type, or lens to a type, which implements `ToString`.
     ///
+    /// A [`Localized`](vizia_core::localization::Localized) title is translated again when the locale changes.
+    ///
     /// # Example
     /// ```no_run
     /// # use vizia_core::prelude::*;
@@ -17,7 +19,7 @@ pub trait WindowModifiers {
     /// .title("Vizia Application")
     /// .run();
     /// ```
-    fn title<T: ToString>(self, title: impl Res<T>) -> Self;
+    fn title<T: ToStringLocalized>(self, title: impl Res<T>) -> Self;
     /// Sets the inner size of the window to the given value. Accepts a value, or lens, which can be converted to a [`WindowSize`].
     ///
     /// The inner size is the window area excluding the window borders.
@@ -0,0 +1,112 @@
+use vizia::prelude::*;
+
+const STYLE: &str = r#"
+    .spotlight {
+        border-width: 2px;
+        border-color: #3b82f6;
+        corner-radius: 6px;
+    }
+
+    .coach-mark {
+        width: 220px;
+        height: auto;
+        padding: 12px;
+        gap: 8px;
+        background-color: #1e293b;
+        corner-radius: 6px;
+        shadow: 0px 4px 12px #00000060;
+    }
+
+    .coach-mark label {
+        color: white;
+        width: 1s;
+    }
+"#;
+
+// The id of the view highlighted by each step of the onboarding, with the text shown next to it.
+const STEPS: [(&str, &str); 3] = [
+    ("new", "Create a new document from scratch."),
+    ("open", "Open a document you worked on before."),
+    ("share", "Share the document with your team."),
+];
+
+#[derive(Lens)]
+struct Onboarding {
+    step: Option<usize>,
+}
+
+enum OnboardingEvent {
+    Next,
+    Restart,
+}
+
+impl Model for Onboarding {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|onboarding_event, _| match onboarding_event {
+            OnboardingEvent::Next => {
+                self.step = self.step.map(|step| step + 1).filter(|step| *step < STEPS.len());
+            }
+
+            OnboardingEvent::Restart => self.step = Some(0),
+        });
+    }
+}
+
+fn main() -> Result<(), ApplicationError> {
+    Application::new(|cx| {
+        cx.add_stylesheet(STYLE).expect("Failed to add stylesheet");
+
+        Onboarding { step: Some(0) }.build(cx);
+
+        VStack::new(cx, |cx| {
+            HStack::new(cx, |cx| {
+                Button::new(cx, |cx| Label::new(cx, "New")).id("new");
+                Button::new(cx, |cx| Label::new(cx, "Open")).id("open");
+                Element::new(cx).width(Stretch(1.0));
+                Button::new(cx, |cx| Label::new(cx, "Share")).id("share");
+            })
+            .height(Auto)
+            .horizontal_gap(Pixels(8.0));
+
+            Button::new(cx, |cx| Label::new(cx, "Restart tour"))
+                .on_press(|cx| cx.emit(OnboardingEvent::Restart));
+        })
+        .padding(Pixels(20.0))
+        .vertical_gap(Pixels(20.0));
+
+        // The coach mark is built at the root, far from the buttons it points at, and anchored to them by id.
+        Binding::new(cx, Onboarding::step, |cx, step| {
+            let Some(step) = step.get(cx) else {
+                return;
+            };
+            let (target, text) = STEPS[step];
+
+            Element::new(cx)
+                .class("spotlight")
+                .hoverable(false)
+                .anchor_to(target, AnchorSpec { match_size: true, ..Default::default() });
+
+            VStack::new(cx, |cx| {
+                Label::new(cx, text);
+                Button::new(cx, |cx| {
+                    Label::new(cx, if step + 1 < STEPS.len() { "Next" } else { "Done" })
+                })
+                .on_press(|cx| cx.emit(OnboardingEvent::Next));
+            })
+            .class("coach-mark")
+            .z_index(1)
+            .anchor_to(
+                target,
+                AnchorSpec {
+                    their: AnchorPoint::BottomLeft,
+                    mine: AnchorPoint::TopLeft,
+                    offset: (0.0, 8.0),
+                    ..Default::default()
+                },
+            );
+        });
+    })
+    .title("Coach Mark")
+    .inner_size((600, 300))
+    .run()
+}
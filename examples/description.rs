@@ -0,0 +1,46 @@
+//! Builds the views of the app from a layout file.
+//!
+//! Run with the path to a layout file to load it instead of the built-in layout:
+//! `cargo run --example description --features description -- path/to/layout.json`
+
+use vizia::prelude::*;
+
+const LAYOUT: &str = include_str!("resources/layouts/settings.json");
+
+pub struct AppData;
+
+impl Model for AppData {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        // The views of a layout file are found by their id to respond to their events.
+        event.map(|window_event, meta| {
+            if let WindowEvent::Press { .. } = window_event {
+                if Some(meta.target) == cx.resolve_entity_identifier("save") {
+                    println!("Save pressed");
+                } else if Some(meta.target) == cx.resolve_entity_identifier("cancel") {
+                    println!("Cancel pressed");
+                }
+            }
+        });
+    }
+}
+
+fn main() -> Result<(), ApplicationError> {
+    let layout = match std::env::args().nth(1) {
+        Some(path) => std::fs::read_to_string(path).expect("Failed to read layout file"),
+        None => LAYOUT.to_string(),
+    };
+
+    let description: ViewDescription =
+        serde_json::from_str(&layout).expect("Failed to parse layout file");
+
+    Application::new(move |cx| {
+        AppData.build(cx);
+
+        if let Err(error) = cx.build_from_description(&description) {
+            Label::new(cx, format!("Failed to build layout: {}", error));
+        }
+    })
+    .title("Description")
+    .inner_size((400, 300))
+    .run()
+}
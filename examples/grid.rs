@@ -0,0 +1,87 @@
+use vizia::prelude::*;
+
+const STYLE: &str = r#"
+    .form {
+        layout-type: grid;
+        grid-template-columns: 120px 1fr;
+        grid-template-rows: 40px 32px 32px 32px 1fr;
+        gap: 8px 12px;
+        padding: 16px;
+    }
+
+    .form .title {
+        grid-column: 1 / span 2;
+        font-size: 20;
+    }
+
+    .form .notes {
+        grid-column: 2;
+        grid-row: 5;
+    }
+
+    .cell {
+        background-color: #4e7ac7;
+        corner-radius: 4px;
+        padding: 8px;
+        color: white;
+    }
+"#;
+
+#[derive(Lens)]
+pub struct AppData {
+    name: String,
+    email: String,
+    theme: String,
+}
+
+impl Model for AppData {}
+
+fn main() -> Result<(), ApplicationError> {
+    Application::new(|cx| {
+        cx.add_stylesheet(STYLE).expect("Failed to add stylesheet");
+
+        AppData {
+            name: String::from("Ada"),
+            email: String::from("ada@example.com"),
+            theme: String::from("Dark"),
+        }
+        .build(cx);
+
+        HStack::new(cx, |cx| {
+            // The labels and fields fill the cells of the form in order, after the title which spans both columns.
+            VStack::new(cx, |cx| {
+                Label::new(cx, "Settings").class("title");
+                Label::new(cx, "Name");
+                Textbox::new(cx, AppData::name);
+                Label::new(cx, "Email");
+                Textbox::new(cx, AppData::email);
+                Label::new(cx, "Theme");
+                Textbox::new(cx, AppData::theme);
+                Label::new(cx, "Notes");
+                Element::new(cx).class("cell").class("notes");
+            })
+            .class("form");
+
+            // The same placement can be set with modifiers, with rows and columns indexed from zero.
+            VStack::new(cx, |cx| {
+                Label::new(cx, "Spans two columns").class("cell").column_start(0).column_span(2);
+                Label::new(cx, "Spans two rows")
+                    .class("cell")
+                    .column_start(2)
+                    .row_start(0)
+                    .row_span(2);
+                Label::new(cx, "Next free cell").class("cell");
+                Label::new(cx, "Next free cell").class("cell");
+                Label::new(cx, "Spans the last row").class("cell").row_start(2).column_span(3);
+            })
+            .layout_type(LayoutType::Grid)
+            .grid_columns(vec![Stretch(1.0), Stretch(1.0), Pixels(120.0)])
+            .grid_rows(vec![Pixels(60.0), Pixels(60.0), Stretch(1.0)])
+            .gap(Pixels(8.0))
+            .padding(Pixels(16.0));
+        });
+    })
+    .title("Grid")
+    .inner_size((800, 400))
+    .run()
+}
@@ -48,12 +48,12 @@ pub struct AppData {
 
 impl Model for AppData {
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
-        event.map(|window_event, meta| {
-            // Intercept WindowClose event to show a dialog if not 'saved'.
-            if let WindowEvent::WindowClose = window_event {
+        event.map(|window_event, _| {
+            // Cancel the close request to show a dialog if not 'saved'.
+            if let WindowEvent::CloseRequested = window_event {
                 if !self.is_saved {
                     self.show_dialog = true;
-                    meta.consume();
+                    cx.emit(WindowEvent::CancelClose);
                 }
             }
         });
@@ -89,8 +89,7 @@ fn main() -> Result<(), ApplicationError> {
         AppData { is_saved: false, show_dialog: false }.build(cx);
 
         HStack::new(cx, |cx| {
-            Button::new(cx, |cx| Label::new(cx, "Close"))
-                .on_press(|cx| cx.emit(WindowEvent::WindowClose));
+            Button::new(cx, |cx| Label::new(cx, "Close")).on_press(|cx| cx.request_close());
             Button::new(cx, |cx| Label::new(cx, "Save")).on_press(|cx| cx.emit(AppEvent::Save));
         })
         .horizontal_gap(Pixels(10.0))
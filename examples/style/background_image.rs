@@ -14,6 +14,20 @@ const STYLE: &str = r#"
     height: auto;
 }
 
+.layered {
+    background: url("sample.png") no-repeat right bottom / 50%, linear-gradient(to right, #3f87a6, #ebf8e1) 0 0 / 40px repeat, gray;
+    width: 600px;
+    height: 300px;
+}
+
+.spaced {
+    background-image: url("sample.png");
+    background-size: 120px auto;
+    background-repeat: space round;
+    width: 600px;
+    height: 200px;
+}
+
 .web-image {
     background-image: url("https://download.samplelib.com/png/sample-bumblebee-400x300.png");
     width: auto;
@@ -65,6 +79,8 @@ fn main() -> Result<(), ApplicationError> {
 
         Element::new(cx).class("auto-size").background_color(Color::red());
         Element::new(cx).class("fixed-size");
+        Element::new(cx).class("layered");
+        Element::new(cx).class("spaced");
         // Element::new(cx).class("web-image");
         // Image::new(cx, "https://download.samplelib.com/png/sample-bumblebee-400x300.png");
         // Label::new(cx, "Wait for the image to load :)");
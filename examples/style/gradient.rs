@@ -33,6 +33,10 @@ const STYLE: &str = r#"
     .grad2 {
         background-image: radial-gradient(cyan 0%, transparent 20%, salmon 40%);
     }
+
+    .grad3 {
+        background-image: conic-gradient(from 0.25turn at 50% 30%, #f69d3c, #3f87a6 180deg, #ebf8e1);
+    }
 "#;
 
 fn main() -> Result<(), ApplicationError> {
@@ -40,6 +44,7 @@ fn main() -> Result<(), ApplicationError> {
         cx.add_stylesheet(STYLE).expect("Failed to add stylesheet");
         // Element::new(cx).class("linear-gradient");
         // Element::new(cx).class("grad2").width(Pixels(200.0));
+        // Element::new(cx).class("grad3");
 
         Element::new(cx)
             // .background_gradient(
@@ -132,6 +132,19 @@ const STYLE: &str = r#"
         shadow: 10px 10px 16px blue inset, 20px 20px 16px red inset, 30px 30px 16px green inset;
         transition: shadow 200ms;
     }
+
+    .elevation {
+        elevation: low;
+        transition: elevation 200ms;
+    }
+
+    .elevation:hover {
+        elevation: 4;
+    }
+
+    .elevation:active {
+        elevation: 1;
+    }
 "#;
 
 fn main() -> Result<(), ApplicationError> {
@@ -157,6 +170,13 @@ fn main() -> Result<(), ApplicationError> {
             Element::new(cx).class("shadow-inset");
         });
 
+        HStack::new(cx, |cx| {
+            for level in 0..=5 {
+                Element::new(cx).elevation(level);
+            }
+            Element::new(cx).class("elevation");
+        });
+
         Element::new(cx)
             .shadow(ShadowBuilder::new().x_offset(5.0).y_offset(5.0).color(Color::black()))
             .shadow(Shadow::new(
@@ -0,0 +1,59 @@
+mod helpers;
+use helpers::*;
+use vizia::prelude::*;
+
+const ROWS: usize = 5;
+const COLS: usize = 4;
+
+#[derive(Lens)]
+pub struct AppData {
+    cells: Vec<Vec<String>>,
+}
+
+pub enum AppEvent {
+    SetCell(usize, usize, String),
+}
+
+impl Model for AppData {
+    fn event(&mut self, _cx: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::SetCell(row, col, value) => self.cells[*row][*col].clone_from(value),
+        });
+    }
+}
+
+fn main() -> Result<(), ApplicationError> {
+    Application::new(|cx| {
+        AppData {
+            cells: (0..ROWS)
+                .map(|row| {
+                    (0..COLS)
+                        .map(|col| format!("{}{}", (b'A' + col as u8) as char, row + 1))
+                        .collect()
+                })
+                .collect(),
+        }
+        .build(cx);
+
+        ExamplePage::vertical(cx, |cx| {
+            CellGrid::new(cx, ROWS, COLS, |cx| {
+                VStack::new(cx, |cx| {
+                    for row in 0..ROWS {
+                        HStack::new(cx, |cx| {
+                            for col in 0..COLS {
+                                Editable::new(cx, row, col, AppData::cells.idx(row).idx(col))
+                                    .on_commit(|cx, row, col, value| {
+                                        cx.emit(AppEvent::SetCell(row, col, value))
+                                    });
+                            }
+                        })
+                        .size(Auto);
+                    }
+                })
+                .size(Auto);
+            });
+        });
+    })
+    .title("Cell Grid")
+    .run()
+}
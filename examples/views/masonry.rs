@@ -0,0 +1,39 @@
+mod helpers;
+use helpers::*;
+use vizia::prelude::*;
+
+#[derive(Lens)]
+pub struct AppData {
+    tiles: Vec<(u32, f32)>,
+}
+
+impl Model for AppData {}
+
+fn main() -> Result<(), ApplicationError> {
+    Application::new(|cx| {
+        let tiles =
+            (0..500u32).map(|index| (index, [1.5, 0.75, 1.0, 0.5][index as usize % 4])).collect();
+        AppData { tiles }.build(cx);
+
+        ExamplePage::new(cx, |cx| {
+            Masonry::new(
+                cx,
+                AppData::tiles,
+                |(_, aspect_ratio)| *aspect_ratio,
+                |cx, index, tile| {
+                    Label::new(cx, tile.map(|(index, _)| *index))
+                        .background_color(Color::rgb(200, 200, 220))
+                        .corner_radius(Pixels(4.0))
+                        .toggle_class("dark", index % 2 == 0)
+                },
+            )
+            .min_column_width(120.0)
+            .animate_layout(Duration::from_millis(200))
+            .horizontal_gap(Pixels(8.0))
+            .vertical_gap(Pixels(8.0))
+            .size(Stretch(1.0));
+        });
+    })
+    .title("Masonry")
+    .run()
+}
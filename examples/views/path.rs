@@ -0,0 +1,69 @@
+mod helpers;
+use helpers::*;
+use vizia::prelude::*;
+
+const STYLE: &str = r#"
+    path {
+        size: 48px;
+    }
+
+    .checkmark {
+        stroke: #4caf50;
+        stroke-width: 4px;
+        stroke-linecap: round;
+        stroke-linejoin: round;
+    }
+
+    .triangle {
+        fill: #2196f3;
+        stroke: #0d47a1;
+        stroke-width: 2px;
+        stroke-linejoin: bevel;
+    }
+
+    .triangle:hover {
+        fill: #64b5f6;
+    }
+
+    .star {
+        fill: #ffc107;
+        transition: fill 100ms;
+    }
+
+    .star:hover {
+        fill: #ff9800;
+    }
+"#;
+
+fn main() -> Result<(), ApplicationError> {
+    Application::new(|cx| {
+        cx.add_stylesheet(STYLE).expect("Failed to add stylesheet");
+
+        ExamplePage::new(cx, |cx| {
+            HStack::new(cx, |cx| {
+                Path::new(cx, "M 2 12 L 9 19 L 22 5")
+                    .view_box(BoundingBox::from_min_max(0.0, 0.0, 24.0, 24.0))
+                    .class("checkmark");
+
+                Path::with_commands(cx, |path| {
+                    path.move_to(0.0, 10.0).line_to(5.0, 0.0).line_to(10.0, 10.0).close();
+                })
+                .hit_test_path(true)
+                .class("triangle");
+
+                Path::new(
+                    cx,
+                    "M 12 2 L 15 9 L 22 9 L 16.5 13.5 L 18.5 21 L 12 16.5 L 5.5 21 L 7.5 13.5 L 2 9 L 9 9 Z",
+                )
+                .preserve_aspect_ratio(false)
+                .hit_test_path(true)
+                .class("star");
+            })
+            .size(Auto)
+            .horizontal_gap(Pixels(20.0));
+        });
+    })
+    .title("Path")
+    .inner_size((400, 200))
+    .run()
+}
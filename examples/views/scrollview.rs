@@ -45,6 +45,7 @@ fn main() -> Result<(), ApplicationError> {
                         .width(Pixels(1000.0))
                         .height(Pixels(1000.0));
                 })
+                .edge_indicators(true)
                 .size(Pixels(300.0))
                 .class("bg-default");
             })
@@ -19,6 +19,7 @@ pub mod prelude {
     #[cfg(all(not(feature = "baseview"), feature = "winit"))]
     pub use vizia_winit::{
         application::{Application, ApplicationError},
+        close_confirmation::CloseConfirmation,
         window::Window,
         window_modifiers::WindowModifiers,
         ModifyWindow,